@@ -0,0 +1,100 @@
+//! Diff command - Compare two extraction results
+//!
+//! This module compares two `ExtractionResult` JSON files, as written by
+//! `kreuzberg extract --format json`, and prints the differences.
+
+use anyhow::{Context, Result};
+use kreuzberg::ExtractionResult;
+use kreuzberg::diff::{DiffOptions, diff_results_with_options};
+use std::path::Path;
+
+use crate::OutputFormat;
+
+/// Execute diff command
+pub fn diff_command(old: &Path, new: &Path, context_lines: usize, format: OutputFormat) -> Result<()> {
+    let old_result = read_result(old)?;
+    let new_result = read_result(new)?;
+
+    let diff = diff_results_with_options(&old_result, &new_result, &DiffOptions { context_lines });
+
+    match format {
+        OutputFormat::Text => {
+            print!("{}", diff);
+        }
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&diff).context("Failed to serialize diff report to JSON")?
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn read_result(path: &Path) -> Result<ExtractionResult> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read extraction result '{}'", path.display()))?;
+    // Extraction output repeats `metadata.output_format` (the deprecated mirror in `additional`),
+    // which typed deserialization rejects as a duplicate field; going through `Value` keeps one.
+    let value: serde_json::Value =
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse '{}' as JSON", path.display()))?;
+    serde_json::from_value(value).with_context(|| {
+        format!(
+            "Failed to parse '{}' as an extraction result. Use the JSON output of 'kreuzberg extract --format json'.",
+            path.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_result(dir: &Path, name: &str, content: &str) -> std::path::PathBuf {
+        let result = ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".into(),
+            ..Default::default()
+        };
+        let path = dir.join(name);
+        std::fs::write(&path, serde_json::to_string(&result).unwrap()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_diff_command_reads_results() {
+        let dir = tempdir().unwrap();
+        let old = write_result(dir.path(), "old.json", "a\nb");
+        let new = write_result(dir.path(), "new.json", "a\nc");
+
+        assert!(diff_command(&old, &new, 3, OutputFormat::Text).is_ok());
+        assert!(diff_command(&old, &new, 3, OutputFormat::Json).is_ok());
+    }
+
+    #[test]
+    fn test_diff_command_accepts_legacy_output_format_key() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("extract.json");
+        std::fs::write(
+            &path,
+            r#"{"content": "a", "mime_type": "text/plain", "metadata": {"output_format": "plain", "output_format": "plain"}, "tables": []}"#,
+        )
+        .unwrap();
+
+        let result = read_result(&path).unwrap();
+        assert_eq!(result.metadata.output_format.as_deref(), Some("plain"));
+    }
+
+    #[test]
+    fn test_diff_command_rejects_invalid_json() {
+        let dir = tempdir().unwrap();
+        let old = write_result(dir.path(), "old.json", "a");
+        let bad = dir.path().join("bad.json");
+        std::fs::write(&bad, "[1, 2, 3]").unwrap();
+
+        let err = diff_command(&old, &bad, 3, OutputFormat::Text).unwrap_err();
+        assert!(err.to_string().contains("Failed to parse"));
+    }
+}
@@ -9,7 +9,7 @@ use kreuzberg::{
 };
 use std::path::PathBuf;
 
-use crate::{ContentOutputFormatArg, OutputFormat};
+use crate::{ChunkerTypeArg, ContentOutputFormatArg, OutputFormat};
 
 /// Execute single document extraction command
 pub fn extract_command(
@@ -93,6 +93,7 @@ pub fn apply_extraction_overrides(
     chunk: Option<bool>,
     chunk_size: Option<usize>,
     chunk_overlap: Option<usize>,
+    chunker_type: Option<ChunkerTypeArg>,
     quality: Option<bool>,
     detect_language: Option<bool>,
     output_format: Option<ContentOutputFormatArg>,
@@ -150,9 +151,12 @@ pub fn apply_extraction_overrides(
                 max_characters,
                 overlap,
                 trim: true,
-                chunker_type: kreuzberg::chunking::ChunkerType::Text,
+                chunker_type: chunker_type.map(Into::into).unwrap_or_default(),
                 embedding: None,
                 preset: None,
+                sizing: Default::default(),
+                prepend_heading_context: false,
+                semantic: Default::default(),
             });
         } else {
             config.chunking = None;
@@ -164,6 +168,9 @@ pub fn apply_extraction_overrides(
         if let Some(overlap) = chunk_overlap {
             chunking.overlap = overlap;
         }
+        if let Some(chunker_type) = chunker_type {
+            chunking.chunker_type = chunker_type.into();
+        }
     }
     if let Some(quality_flag) = quality {
         config.enable_quality_processing = quality_flag;
@@ -215,6 +222,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let ocr = config.ocr.unwrap();
         assert_eq!(ocr.backend, "tesseract");
@@ -238,6 +246,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let ocr = config.ocr.unwrap();
         assert_eq!(ocr.backend, "paddle-ocr");
@@ -261,6 +270,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let ocr = config.ocr.unwrap();
         assert_eq!(ocr.backend, "easyocr");
@@ -284,6 +294,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let ocr = config.ocr.unwrap();
         assert_eq!(ocr.backend, "tesseract");
@@ -307,6 +318,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let ocr = config.ocr.unwrap();
         assert_eq!(ocr.backend, "paddle-ocr");
@@ -330,6 +342,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         // No OCR config exists, so --ocr-language alone doesn't create one
         assert!(config.ocr.is_none());
@@ -362,6 +375,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let ocr = config.ocr.unwrap();
         assert_eq!(ocr.backend, "tesseract");
@@ -385,7 +399,49 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         assert!(config.ocr.is_none());
     }
+
+    #[test]
+    fn test_chunker_type_override() {
+        let mut config = ExtractionConfig::default();
+        apply_extraction_overrides(
+            &mut config,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            Some(ChunkerTypeArg::Semantic),
+            None,
+            None,
+            None,
+            None,
+        );
+        let chunking = config.chunking.as_ref().unwrap();
+        assert_eq!(chunking.chunker_type, kreuzberg::ChunkerType::Semantic);
+
+        apply_extraction_overrides(
+            &mut config,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ChunkerTypeArg::Markdown),
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(config.chunking.unwrap().chunker_type, kreuzberg::ChunkerType::Markdown);
+    }
 }
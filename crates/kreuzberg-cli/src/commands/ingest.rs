@@ -0,0 +1,79 @@
+//! Ingest command - Extract a directory tree into JSONL records
+//!
+//! This module walks a directory, extracts every matching document and writes
+//! one record per file. A checkpoint file lets an interrupted run resume.
+
+use anyhow::{Context, Result};
+use kreuzberg::{ExtractionConfig, IngestOptions, IngestSummary, ingest_directory_sync};
+use std::path::Path;
+
+use crate::OutputFormat;
+
+/// Execute ingest command
+pub fn ingest_command(
+    dir: &Path,
+    options: &IngestOptions,
+    config: &ExtractionConfig,
+    format: OutputFormat,
+) -> Result<()> {
+    let summary = ingest_directory_sync(dir, options, config)
+        .with_context(|| format!("Failed to ingest directory '{}'", dir.display()))?;
+
+    match format {
+        OutputFormat::Text => print!("{}", summary_text(&summary)),
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&summary).context("Failed to serialize ingest summary to JSON")?
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn summary_text(summary: &IngestSummary) -> String {
+    format!(
+        "Matched: {}\nExtracted: {}\nFailed: {}\nUnchanged: {}\nFiltered: {}\n",
+        summary.matched, summary.succeeded, summary.failed, summary.unchanged, summary.filtered
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kreuzberg::IngestOutput;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_ingest_command_writes_records() {
+        let input = tempdir().unwrap();
+        std::fs::write(input.path().join("a.txt"), "alpha").unwrap();
+        let out = tempdir().unwrap();
+        let jsonl = out.path().join("out.jsonl");
+
+        let mut options = IngestOptions::new(IngestOutput::JsonlFile(jsonl.clone()));
+        options.checkpoint = Some(out.path().join("out.jsonl.checkpoint"));
+        let config = ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        };
+
+        assert!(ingest_command(input.path(), &options, &config, OutputFormat::Json).is_ok());
+        assert_eq!(std::fs::read_to_string(&jsonl).unwrap().lines().count(), 1);
+
+        assert!(ingest_command(input.path(), &options, &config, OutputFormat::Text).is_ok());
+        assert_eq!(std::fs::read_to_string(&jsonl).unwrap().lines().count(), 1);
+    }
+
+    #[test]
+    fn test_summary_text() {
+        let summary = IngestSummary {
+            matched: 3,
+            succeeded: 2,
+            failed: 1,
+            ..Default::default()
+        };
+        assert!(summary_text(&summary).starts_with("Matched: 3\nExtracted: 2\nFailed: 1\n"));
+    }
+}
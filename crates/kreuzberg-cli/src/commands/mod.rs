@@ -2,20 +2,32 @@
 //!
 //! This module organizes the CLI commands into focused submodules:
 //! - `extract` - Document extraction commands
+//! - `diff` - Extraction result comparison
+//! - `ingest` - Resumable directory ingestion
+//! - `watch` - Re-extraction of changed files
+//! - `searchable_pdf` - OCR text layers for scanned documents
 //! - `cache` - Cache management operations
 //! - `server` - API and MCP server commands
 //! - `config` - Configuration loading and discovery
 
 pub mod cache;
 pub mod config;
+pub mod diff;
 pub mod extract;
+pub mod ingest;
+pub mod searchable_pdf;
 pub mod server;
+pub mod watch;
 
 // Re-export command functions for convenience
 pub use cache::{clear_command, stats_command};
 pub use config::load_config;
+pub use diff::diff_command;
 pub use extract::{apply_extraction_overrides, batch_command, extract_command};
+pub use ingest::ingest_command;
+pub use searchable_pdf::searchable_pdf_command;
 #[cfg(feature = "mcp")]
 pub use server::mcp_command;
 #[cfg(feature = "api")]
 pub use server::serve_command;
+pub use watch::watch_command;
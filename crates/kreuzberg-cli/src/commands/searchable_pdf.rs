@@ -0,0 +1,53 @@
+//! Searchable PDF command - Add an OCR text layer to scans
+//!
+//! This module OCRs a scanned PDF or image and writes a PDF/A document that
+//! keeps the original page images and adds an invisible, searchable text layer.
+
+use anyhow::{Context, Result};
+use kreuzberg::{ExtractionConfig, create_searchable_pdf_file_sync};
+use std::path::{Path, PathBuf};
+
+/// Execute searchable-pdf command
+pub fn searchable_pdf_command(input: &Path, output: &Path, config: &ExtractionConfig) -> Result<()> {
+    let pdf = create_searchable_pdf_file_sync(input, None, config)
+        .with_context(|| format!("Failed to create a searchable PDF from '{}'", input.display()))?;
+
+    std::fs::write(output, pdf).with_context(|| format!("Failed to write '{}'", output.display()))?;
+    tracing::info!("Wrote searchable PDF to {}", output.display());
+    Ok(())
+}
+
+/// Output path used when `--output` is not given: `<stem>.searchable.pdf` next to the input.
+pub fn default_output_path(input: &Path) -> PathBuf {
+    input.with_extension("searchable.pdf")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_output_path_sits_next_to_input() {
+        assert_eq!(
+            default_output_path(Path::new("scans/invoice.pdf")),
+            PathBuf::from("scans/invoice.searchable.pdf")
+        );
+        assert_eq!(
+            default_output_path(Path::new("receipt.png")),
+            PathBuf::from("receipt.searchable.pdf")
+        );
+    }
+
+    #[test]
+    fn test_searchable_pdf_command_rejects_text_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("notes.txt");
+        std::fs::write(&input, "plain text").unwrap();
+        let output = dir.path().join("notes.searchable.pdf");
+
+        let result = searchable_pdf_command(&input, &output, &ExtractionConfig::default());
+
+        assert!(result.is_err());
+        assert!(!output.exists());
+    }
+}
@@ -0,0 +1,84 @@
+//! Watch command - Re-extract documents as they change
+//!
+//! This module watches a directory and reports added, updated and removed
+//! documents with their extraction results, as NDJSON on stdout or by posting
+//! each event to a webhook.
+
+use anyhow::{Context, Result};
+use kreuzberg::{ExtractionConfig, WatchEvent, WatchOptions, watch_directory_sync};
+use std::io::Write;
+use std::ops::ControlFlow;
+use std::path::Path;
+
+/// Execute watch command
+///
+/// Runs until interrupted. A failed webhook delivery is logged and the event dropped.
+pub fn watch_command(
+    dir: &Path,
+    options: &WatchOptions,
+    config: &ExtractionConfig,
+    webhook: Option<&str>,
+) -> Result<()> {
+    tracing::info!("Watching {} for changes", dir.display());
+    let mut stdout = std::io::stdout().lock();
+
+    watch_directory_sync(dir, options, config, |event| match webhook {
+        Some(url) => {
+            if let Err(e) = post_event(url, &event) {
+                tracing::warn!("Webhook delivery for {} failed: {:#}", event.path, e);
+            }
+            ControlFlow::Continue(())
+        }
+        None => match write_event(&mut stdout, &event) {
+            Ok(()) => ControlFlow::Continue(()),
+            // stdout is closed, e.g. the reading end of a pipe exited
+            Err(e) => {
+                tracing::warn!("Stopping watch, failed to write event: {:#}", e);
+                ControlFlow::Break(())
+            }
+        },
+    })
+    .with_context(|| format!("Failed to watch directory '{}'", dir.display()))
+}
+
+fn write_event(out: &mut impl Write, event: &WatchEvent) -> Result<()> {
+    serde_json::to_writer(&mut *out, event).context("Failed to serialize watch event to JSON")?;
+    out.write_all(b"\n")?;
+    out.flush()?;
+    Ok(())
+}
+
+fn post_event(url: &str, event: &WatchEvent) -> Result<()> {
+    ureq::post(url)
+        .send_json(event)
+        .with_context(|| format!("POST {} failed", url))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kreuzberg::WatchEventKind;
+
+    #[test]
+    fn test_write_event_ndjson() {
+        let event = WatchEvent {
+            event: WatchEventKind::Removed,
+            path: "notes/a.txt".to_string(),
+            mime_type: None,
+            sha256: None,
+            result: None,
+            error: None,
+        };
+        let mut out = Vec::new();
+        write_event(&mut out, &event).unwrap();
+        write_event(&mut out, &event).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert_eq!(
+            text.lines().next().unwrap(),
+            r#"{"event":"removed","path":"notes/a.txt"}"#
+        );
+    }
+}
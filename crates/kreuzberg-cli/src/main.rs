@@ -8,7 +8,11 @@
 //! The CLI is built using `clap` for argument parsing and provides five main commands:
 //! - `extract`: Extract text/data from a single document
 //! - `batch`: Process multiple documents in parallel
+//! - `ingest`: Extract a directory tree into JSONL records, resumably
+//! - `watch`: Re-extract documents in a directory as they change
+//! - `searchable-pdf`: Add an invisible OCR text layer to a scanned PDF or image
 //! - `detect`: Identify MIME type of a file
+//! - `diff`: Compare two extraction results
 //! - `cache`: Manage cache (clear, stats)
 //! - `serve`: Start API server (requires `api` feature)
 //! - `version`: Show version information
@@ -47,8 +51,20 @@
 //! # Batch processing
 //! kreuzberg batch *.pdf --output-format json
 //!
+//! # Ingest a directory tree, resuming where a previous run stopped
+//! kreuzberg ingest ./share --output share.jsonl --include '*.pdf'
+//!
+//! # Stream change events for a directory as NDJSON
+//! kreuzberg watch ./knowledge-base --include '*.pdf'
+//!
+//! # Make a scanned PDF searchable
+//! kreuzberg searchable-pdf scan.pdf --output scan.searchable.pdf
+//!
 //! # Detect MIME type
 //! kreuzberg detect unknown-file.bin
+//!
+//! # Compare two extraction results
+//! kreuzberg diff before.json after.json
 //! ```
 
 #![deny(unsafe_code)]
@@ -62,8 +78,13 @@ use clap::{Parser, Subcommand};
 use commands::mcp_command;
 #[cfg(feature = "api")]
 use commands::serve_command;
-use commands::{apply_extraction_overrides, batch_command, clear_command, extract_command, load_config, stats_command};
-use kreuzberg::{OutputFormat as ContentOutputFormat, detect_mime_type};
+use commands::{
+    apply_extraction_overrides, batch_command, clear_command, diff_command, extract_command, ingest_command,
+    load_config, searchable_pdf_command, stats_command, watch_command,
+};
+use kreuzberg::{
+    ChunkerType, IngestOptions, IngestOutput, OutputFormat as ContentOutputFormat, WatchOptions, detect_mime_type,
+};
 use serde_json::json;
 use std::path::{Path, PathBuf};
 use tracing_subscriber::EnvFilter;
@@ -142,6 +163,10 @@ enum Commands {
         #[arg(long)]
         chunk_overlap: Option<usize>,
 
+        /// Chunker to use (overrides config file). `semantic` requires the embeddings feature.
+        #[arg(long, value_enum)]
+        chunker_type: Option<ChunkerTypeArg>,
+
         /// Enable quality processing (overrides config file)
         #[arg(long)]
         quality: Option<bool>,
@@ -229,6 +254,209 @@ enum Commands {
         content_format: Option<ContentOutputFormatArg>,
     },
 
+    /// Extract every document below a directory into JSON records
+    ///
+    /// Records go to a JSONL file (--output) or to one `<path>.json` file per document (--output-dir).
+    /// Ingested files are recorded in a checkpoint and skipped on later runs unless they changed.
+    Ingest {
+        /// Directory to ingest
+        dir: PathBuf,
+
+        /// JSONL file to append records to
+        #[arg(short, long, conflicts_with = "output_dir", required_unless_present = "output_dir")]
+        output: Option<PathBuf>,
+
+        /// Directory to write one record file per document to
+        #[arg(long)]
+        output_dir: Option<PathBuf>,
+
+        /// Checkpoint file [default: <output>.checkpoint, or .checkpoint.jsonl in --output-dir]
+        #[arg(long)]
+        checkpoint: Option<PathBuf>,
+
+        /// Ignore and do not write a checkpoint
+        #[arg(long, conflicts_with = "checkpoint")]
+        no_checkpoint: bool,
+
+        /// Glob of files to ingest, matched against the file name or, if it contains '/', the relative path (repeatable)
+        #[arg(long)]
+        include: Vec<String>,
+
+        /// Glob of files or directories to skip (repeatable)
+        #[arg(long)]
+        exclude: Vec<String>,
+
+        /// MIME type to ingest, e.g. application/pdf or text/* (repeatable)
+        #[arg(long = "mime-type")]
+        mime_types: Vec<String>,
+
+        /// Skip files smaller than this many bytes
+        #[arg(long)]
+        min_size: Option<u64>,
+
+        /// Skip files larger than this many bytes
+        #[arg(long)]
+        max_size: Option<u64>,
+
+        /// Follow symbolic links
+        #[arg(long)]
+        follow_symlinks: bool,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Inline JSON configuration. Applied after config file but before individual flags.
+        #[arg(long)]
+        config_json: Option<String>,
+
+        /// Base64-encoded JSON configuration. Useful for shell environments where quotes are problematic.
+        #[arg(long)]
+        config_json_base64: Option<String>,
+
+        /// Output format for the run summary (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+
+        /// Enable OCR (overrides config file)
+        #[arg(long)]
+        ocr: Option<bool>,
+
+        /// OCR backend to use when --ocr is enabled (tesseract, paddle-ocr, easyocr)
+        #[arg(long)]
+        ocr_backend: Option<String>,
+
+        /// OCR language code. Tesseract: ISO 639-3 (eng, fra, deu). PaddleOCR: flexible (en, ch, french, korean).
+        #[arg(long)]
+        ocr_language: Option<String>,
+
+        /// Force OCR even if text extraction succeeds (overrides config file)
+        #[arg(long)]
+        force_ocr: Option<bool>,
+
+        /// Disable caching (overrides config file)
+        #[arg(long)]
+        no_cache: Option<bool>,
+
+        /// Enable quality processing (overrides config file)
+        #[arg(long)]
+        quality: Option<bool>,
+
+        /// Content output format (plain, markdown, djot, html).
+        #[arg(long, value_enum)]
+        output_format: Option<ContentOutputFormatArg>,
+    },
+
+    /// Watch a directory and re-extract documents as they change
+    ///
+    /// Emits one JSON event per added, updated or removed document, as NDJSON on stdout
+    /// or as a POST to --webhook. Runs until interrupted.
+    Watch {
+        /// Directory to watch
+        dir: PathBuf,
+
+        /// URL to POST each event to instead of printing it
+        #[arg(long)]
+        webhook: Option<String>,
+
+        /// Milliseconds without further changes before a file is processed
+        #[arg(long, default_value = "500")]
+        debounce_ms: u64,
+
+        /// Also emit `added` events for documents already in the directory
+        #[arg(long)]
+        initial_scan: bool,
+
+        /// Glob of files to watch, matched against the file name or, if it contains '/', the relative path (repeatable)
+        #[arg(long)]
+        include: Vec<String>,
+
+        /// Glob of files or directories to ignore (repeatable)
+        #[arg(long)]
+        exclude: Vec<String>,
+
+        /// MIME type to watch, e.g. application/pdf or text/* (repeatable)
+        #[arg(long = "mime-type")]
+        mime_types: Vec<String>,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Inline JSON configuration. Applied after config file but before individual flags.
+        #[arg(long)]
+        config_json: Option<String>,
+
+        /// Base64-encoded JSON configuration. Useful for shell environments where quotes are problematic.
+        #[arg(long)]
+        config_json_base64: Option<String>,
+
+        /// Enable OCR (overrides config file)
+        #[arg(long)]
+        ocr: Option<bool>,
+
+        /// OCR backend to use when --ocr is enabled (tesseract, paddle-ocr, easyocr)
+        #[arg(long)]
+        ocr_backend: Option<String>,
+
+        /// OCR language code. Tesseract: ISO 639-3 (eng, fra, deu). PaddleOCR: flexible (en, ch, french, korean).
+        #[arg(long)]
+        ocr_language: Option<String>,
+
+        /// Force OCR even if text extraction succeeds (overrides config file)
+        #[arg(long)]
+        force_ocr: Option<bool>,
+
+        /// Disable caching (overrides config file)
+        #[arg(long)]
+        no_cache: Option<bool>,
+
+        /// Enable quality processing (overrides config file)
+        #[arg(long)]
+        quality: Option<bool>,
+
+        /// Content output format (plain, markdown, djot, html).
+        #[arg(long, value_enum)]
+        output_format: Option<ContentOutputFormatArg>,
+    },
+
+    /// Write a searchable PDF from a scanned PDF or image
+    ///
+    /// Keeps the original page images and adds an invisible OCR text layer, producing a
+    /// PDF/A-2b document that can be searched and copied from.
+    SearchablePdf {
+        /// Path to the scanned PDF or image
+        path: PathBuf,
+
+        /// Where to write the PDF (defaults to <name>.searchable.pdf next to the input)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Inline JSON configuration. Applied after config file but before individual flags.
+        #[arg(long)]
+        config_json: Option<String>,
+
+        /// Base64-encoded JSON configuration. Useful for shell environments where quotes are problematic.
+        #[arg(long)]
+        config_json_base64: Option<String>,
+
+        /// OCR backend to use (tesseract, paddle-ocr, easyocr)
+        #[arg(long)]
+        ocr_backend: Option<String>,
+
+        /// OCR language code. Tesseract: ISO 639-3 (eng, fra, deu). PaddleOCR: flexible (en, ch, french, korean).
+        #[arg(long)]
+        ocr_language: Option<String>,
+
+        /// Disable caching (overrides config file)
+        #[arg(long)]
+        no_cache: Option<bool>,
+    },
+
     /// Detect MIME type of a file
     Detect {
         /// Path to the file
@@ -239,6 +467,26 @@ enum Commands {
         format: OutputFormat,
     },
 
+    /// Compare two extraction results
+    ///
+    /// Both files must contain an extraction result as written by `extract --format json`.
+    /// Reports text, table cell, metadata and chunk differences.
+    Diff {
+        /// Extraction result to compare from
+        old: PathBuf,
+
+        /// Extraction result to compare to
+        new: PathBuf,
+
+        /// Unchanged lines shown around each text change
+        #[arg(long, default_value = "3")]
+        context: usize,
+
+        /// Output format (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+    },
+
     /// Show version information
     Version {
         /// Output format (text or json)
@@ -353,6 +601,36 @@ enum ContentOutputFormatArg {
     Djot,
     /// HTML format
     Html,
+    /// ALTO XML from OCR results (images with OCR)
+    Alto,
+    /// PAGE XML from OCR results (single-page images with OCR)
+    PageXml,
+    /// hOCR from OCR results (images with OCR)
+    Hocr,
+}
+
+/// Chunker used when chunking is enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum ChunkerTypeArg {
+    /// Split on whitespace and punctuation (default)
+    Text,
+    /// Markdown-aware splitting
+    Markdown,
+    /// Split along the sections of the document structure
+    Structure,
+    /// Split where the embedding similarity of neighbouring sentences drops
+    Semantic,
+}
+
+impl From<ChunkerTypeArg> for ChunkerType {
+    fn from(arg: ChunkerTypeArg) -> Self {
+        match arg {
+            ChunkerTypeArg::Text => ChunkerType::Text,
+            ChunkerTypeArg::Markdown => ChunkerType::Markdown,
+            ChunkerTypeArg::Structure => ChunkerType::Structure,
+            ChunkerTypeArg::Semantic => ChunkerType::Semantic,
+        }
+    }
 }
 
 impl From<ContentOutputFormatArg> for ContentOutputFormat {
@@ -362,6 +640,9 @@ impl From<ContentOutputFormatArg> for ContentOutputFormat {
             ContentOutputFormatArg::Markdown => ContentOutputFormat::Markdown,
             ContentOutputFormatArg::Djot => ContentOutputFormat::Djot,
             ContentOutputFormatArg::Html => ContentOutputFormat::Html,
+            ContentOutputFormatArg::Alto => ContentOutputFormat::Alto,
+            ContentOutputFormatArg::PageXml => ContentOutputFormat::PageXml,
+            ContentOutputFormatArg::Hocr => ContentOutputFormat::Hocr,
         }
     }
 }
@@ -491,6 +772,32 @@ fn merge_json_into_config(
     Ok(merged_config)
 }
 
+/// Applies `--config-json` or `--config-json-base64` on top of the loaded config.
+fn apply_inline_config(
+    config: &mut kreuzberg::ExtractionConfig,
+    config_json: Option<String>,
+    config_json_base64: Option<String>,
+) -> Result<()> {
+    if let Some(json_str) = config_json {
+        let json_value: serde_json::Value =
+            serde_json::from_str(&json_str).context("Failed to parse --config-json as JSON")?;
+        // Merge inline JSON with file config
+        *config =
+            merge_json_into_config(config, json_value).context("Failed to merge --config-json with file config")?;
+    } else if let Some(base64_str) = config_json_base64 {
+        let json_bytes = STANDARD
+            .decode(&base64_str)
+            .context("Failed to decode base64 in --config-json-base64")?;
+        let json_str = String::from_utf8(json_bytes).context("Base64-decoded content is not valid UTF-8")?;
+        let json_value: serde_json::Value =
+            serde_json::from_str(&json_str).context("Failed to parse decoded --config-json-base64 as JSON")?;
+        // Merge inline JSON with file config
+        *config = merge_json_into_config(config, json_value)
+            .context("Failed to merge --config-json-base64 with file config")?;
+    }
+    Ok(())
+}
+
 fn main() -> Result<()> {
     let _ = tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
@@ -515,6 +822,7 @@ fn main() -> Result<()> {
             chunk,
             chunk_size,
             chunk_overlap,
+            chunker_type,
             quality,
             detect_language,
             output_format,
@@ -524,25 +832,7 @@ fn main() -> Result<()> {
             validate_chunk_params(chunk_size, chunk_overlap)?;
 
             let mut config = load_config(config_path)?;
-
-            // Apply inline JSON config if provided (merge with file config)
-            if let Some(json_str) = config_json {
-                let json_value: serde_json::Value =
-                    serde_json::from_str(&json_str).context("Failed to parse --config-json as JSON")?;
-                // Merge inline JSON with file config
-                config = merge_json_into_config(&config, json_value)
-                    .context("Failed to merge --config-json with file config")?;
-            } else if let Some(base64_str) = config_json_base64 {
-                let json_bytes = STANDARD
-                    .decode(&base64_str)
-                    .context("Failed to decode base64 in --config-json-base64")?;
-                let json_str = String::from_utf8(json_bytes).context("Base64-decoded content is not valid UTF-8")?;
-                let json_value: serde_json::Value =
-                    serde_json::from_str(&json_str).context("Failed to parse decoded --config-json-base64 as JSON")?;
-                // Merge inline JSON with file config
-                config = merge_json_into_config(&config, json_value)
-                    .context("Failed to merge --config-json-base64 with file config")?;
-            }
+            apply_inline_config(&mut config, config_json, config_json_base64)?;
 
             apply_extraction_overrides(
                 &mut config,
@@ -554,6 +844,7 @@ fn main() -> Result<()> {
                 chunk,
                 chunk_size,
                 chunk_overlap,
+                chunker_type,
                 quality,
                 detect_language,
                 output_format,
@@ -581,25 +872,7 @@ fn main() -> Result<()> {
             validate_batch_paths(&paths)?;
 
             let mut config = load_config(config_path)?;
-
-            // Apply inline JSON config if provided (merge with file config)
-            if let Some(json_str) = config_json {
-                let json_value: serde_json::Value =
-                    serde_json::from_str(&json_str).context("Failed to parse --config-json as JSON")?;
-                // Merge inline JSON with file config
-                config = merge_json_into_config(&config, json_value)
-                    .context("Failed to merge --config-json with file config")?;
-            } else if let Some(base64_str) = config_json_base64 {
-                let json_bytes = STANDARD
-                    .decode(&base64_str)
-                    .context("Failed to decode base64 in --config-json-base64")?;
-                let json_str = String::from_utf8(json_bytes).context("Base64-decoded content is not valid UTF-8")?;
-                let json_value: serde_json::Value =
-                    serde_json::from_str(&json_str).context("Failed to parse decoded --config-json-base64 as JSON")?;
-                // Merge inline JSON with file config
-                config = merge_json_into_config(&config, json_value)
-                    .context("Failed to merge --config-json-base64 with file config")?;
-            }
+            apply_inline_config(&mut config, config_json, config_json_base64)?;
 
             apply_extraction_overrides(
                 &mut config,
@@ -611,6 +884,7 @@ fn main() -> Result<()> {
                 None,
                 None,
                 None,
+                None,
                 quality,
                 None,
                 output_format,
@@ -620,6 +894,174 @@ fn main() -> Result<()> {
             batch_command(paths, config, format)?;
         }
 
+        Commands::Ingest {
+            dir,
+            output,
+            output_dir,
+            checkpoint,
+            no_checkpoint,
+            include,
+            exclude,
+            mime_types,
+            min_size,
+            max_size,
+            follow_symlinks,
+            config: config_path,
+            config_json,
+            config_json_base64,
+            format,
+            ocr,
+            ocr_backend,
+            ocr_language,
+            force_ocr,
+            no_cache,
+            quality,
+            output_format,
+        } => {
+            if !dir.is_dir() {
+                anyhow::bail!(
+                    "Directory not found: '{}'. Please provide a path to an existing directory.",
+                    dir.display()
+                );
+            }
+
+            let mut config = load_config(config_path)?;
+            apply_inline_config(&mut config, config_json, config_json_base64)?;
+            apply_extraction_overrides(
+                &mut config,
+                ocr,
+                ocr_backend.as_deref(),
+                ocr_language.as_deref(),
+                force_ocr,
+                no_cache,
+                None,
+                None,
+                None,
+                None,
+                quality,
+                None,
+                output_format,
+                None,
+            );
+
+            let (output, default_checkpoint) = match (output, output_dir) {
+                (Some(file), _) => {
+                    let mut checkpoint = file.clone().into_os_string();
+                    checkpoint.push(".checkpoint");
+                    (IngestOutput::JsonlFile(file), PathBuf::from(checkpoint))
+                }
+                (None, Some(dir)) => {
+                    let checkpoint = dir.join(".checkpoint.jsonl");
+                    (IngestOutput::Directory(dir), checkpoint)
+                }
+                (None, None) => anyhow::bail!("Either --output or --output-dir is required"),
+            };
+
+            let mut options = IngestOptions::new(output);
+            options.checkpoint = (!no_checkpoint).then(|| checkpoint.unwrap_or(default_checkpoint));
+            options.include = include;
+            options.exclude = exclude;
+            options.mime_types = mime_types;
+            options.min_size = min_size;
+            options.max_size = max_size;
+            options.follow_symlinks = follow_symlinks;
+
+            ingest_command(&dir, &options, &config, format)?;
+        }
+
+        Commands::Watch {
+            dir,
+            webhook,
+            debounce_ms,
+            initial_scan,
+            include,
+            exclude,
+            mime_types,
+            config: config_path,
+            config_json,
+            config_json_base64,
+            ocr,
+            ocr_backend,
+            ocr_language,
+            force_ocr,
+            no_cache,
+            quality,
+            output_format,
+        } => {
+            if !dir.is_dir() {
+                anyhow::bail!(
+                    "Directory not found: '{}'. Please provide a path to an existing directory.",
+                    dir.display()
+                );
+            }
+
+            let mut config = load_config(config_path)?;
+            apply_inline_config(&mut config, config_json, config_json_base64)?;
+            apply_extraction_overrides(
+                &mut config,
+                ocr,
+                ocr_backend.as_deref(),
+                ocr_language.as_deref(),
+                force_ocr,
+                no_cache,
+                None,
+                None,
+                None,
+                None,
+                quality,
+                None,
+                output_format,
+                None,
+            );
+
+            let options = WatchOptions {
+                debounce: std::time::Duration::from_millis(debounce_ms),
+                initial_scan,
+                include,
+                exclude,
+                mime_types,
+            };
+
+            watch_command(&dir, &options, &config, webhook.as_deref())?;
+        }
+
+        Commands::SearchablePdf {
+            path,
+            output,
+            config: config_path,
+            config_json,
+            config_json_base64,
+            ocr_backend,
+            ocr_language,
+            no_cache,
+        } => {
+            validate_file_exists(&path)?;
+
+            let mut config = load_config(config_path)?;
+            apply_inline_config(&mut config, config_json, config_json_base64)?;
+            // OCR always runs here; only replace a configured backend when one is requested.
+            let enable_ocr = (config.ocr.is_none() || ocr_backend.is_some()).then_some(true);
+            apply_extraction_overrides(
+                &mut config,
+                enable_ocr,
+                ocr_backend.as_deref(),
+                ocr_language.as_deref(),
+                None,
+                no_cache,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            let output = output.unwrap_or_else(|| commands::searchable_pdf::default_output_path(&path));
+            searchable_pdf_command(&path, &output, &config)?;
+        }
+
         Commands::Detect { path, format } => {
             validate_file_exists(&path)?;
 
@@ -649,6 +1091,17 @@ fn main() -> Result<()> {
             }
         }
 
+        Commands::Diff {
+            old,
+            new,
+            context,
+            format,
+        } => {
+            validate_file_exists(&old)?;
+            validate_file_exists(&new)?;
+            diff_command(&old, &new, context, format)?;
+        }
+
         Commands::Version { format } => {
             let version = env!("CARGO_PKG_VERSION");
             let name = env!("CARGO_PKG_NAME");
@@ -1,4 +1,4 @@
-//! Integration tests for CLI commands (extract, detect, batch).
+//! Integration tests for CLI commands (extract, detect, batch, diff).
 //!
 //! These tests verify that the CLI commands work correctly end-to-end,
 //! including input validation, file processing, and output formatting.
@@ -397,6 +397,107 @@ fn test_batch_with_missing_file() {
     );
 }
 
+#[test]
+fn test_diff_extraction_results() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str(), "--format", "json"])
+        .output()
+        .expect("Failed to execute extract command");
+    assert!(output.status.success());
+
+    let mut result: serde_json::Value = serde_json::from_slice(&output.stdout).expect("extract output should be JSON");
+    let dir = tempdir().unwrap();
+    let old_path = dir.path().join("old.json");
+    std::fs::write(&old_path, serde_json::to_string(&result).unwrap()).unwrap();
+    result["content"] = serde_json::Value::String(format!("{}\nAppended line", result["content"].as_str().unwrap()));
+    let new_path = dir.path().join("new.json");
+    std::fs::write(&new_path, serde_json::to_string(&result).unwrap()).unwrap();
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "diff",
+            old_path.to_str().unwrap(),
+            new_path.to_str().unwrap(),
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("Failed to execute diff command");
+    assert!(
+        output.status.success(),
+        "Diff command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).expect("diff output should be JSON");
+    assert_eq!(diff["text"]["lines_added"], 1);
+    assert_eq!(diff["tables"].as_array().unwrap().len(), 0);
+    assert_eq!(diff["metadata"].as_array().unwrap().len(), 0);
+
+    let output = Command::new(get_binary_path())
+        .args(["diff", old_path.to_str().unwrap(), old_path.to_str().unwrap()])
+        .output()
+        .expect("Failed to execute diff command");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Results are identical"));
+}
+
+#[test]
+fn test_ingest_directory_resumes() {
+    build_binary();
+
+    let input = tempdir().unwrap();
+    std::fs::create_dir(input.path().join("notes")).unwrap();
+    std::fs::write(input.path().join("a.txt"), "first document").unwrap();
+    std::fs::write(input.path().join("notes/b.txt"), "second document").unwrap();
+    let out = tempdir().unwrap();
+    let jsonl = out.path().join("records.jsonl");
+
+    let ingest = || {
+        let output = Command::new(get_binary_path())
+            .args([
+                "ingest",
+                input.path().to_str().unwrap(),
+                "--output",
+                jsonl.to_str().unwrap(),
+                "--format",
+                "json",
+            ])
+            .output()
+            .expect("Failed to execute ingest command");
+        assert!(
+            output.status.success(),
+            "Ingest command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).expect("ingest summary should be JSON")
+    };
+
+    let summary = ingest();
+    assert_eq!(summary["succeeded"], 2);
+    assert!(out.path().join("records.jsonl.checkpoint").exists());
+
+    let summary = ingest();
+    assert_eq!(summary["succeeded"], 0);
+    assert_eq!(summary["unchanged"], 2);
+
+    let records = std::fs::read_to_string(&jsonl).unwrap();
+    let mut paths: Vec<String> = records
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["path"].to_string())
+        .collect();
+    paths.sort();
+    assert_eq!(paths, vec!["\"a.txt\"", "\"notes/b.txt\""]);
+}
+
 #[test]
 fn test_extract_help() {
     build_binary();
@@ -53,6 +53,11 @@ fn create_test_result(content_size: usize, chunk_count: usize) -> ExtractionResu
                             total_chunks: chunk_count,
                             first_page: Some(1 + (i / 10)),
                             last_page: Some(1 + (i / 10)),
+                            heading_path: Vec::new(),
+                            element_types: Vec::new(),
+                            node_ids: Vec::new(),
+                            language: None,
+                            language_confidence: None,
                         },
                     }
                 })
@@ -67,6 +72,7 @@ fn create_test_result(content_size: usize, chunk_count: usize) -> ExtractionResu
         mime_type: Cow::Borrowed("application/pdf"),
         metadata,
         detected_languages: Some(vec!["en".to_string(), "de".to_string()]),
+        language_spans: None,
         chunks,
         ..Default::default()
     }
@@ -63,10 +63,12 @@ pub fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*
         metadata,
         tables,
         detected_languages,
+        language_spans: _,
         chunks,
         images,
         pages,
         djot_content: _,
+        children: _,
         elements,
         ocr_elements,
         document,
@@ -421,6 +423,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -431,6 +434,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         };
 
         let c_result = to_c_extraction_result(result);
@@ -465,6 +469,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -475,6 +480,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         };
 
         let c_result = to_c_extraction_result(result);
@@ -519,6 +525,7 @@ mod tests {
             metadata,
             tables: vec![],
             detected_languages: Some(vec!["en".to_string(), "de".to_string()]),
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -529,6 +536,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         };
 
         let c_result = to_c_extraction_result(result);
@@ -581,6 +589,8 @@ mod tests {
             ],
             markdown: "| Col1 | Col2 |\n|------|------|\n| A1 | A2 |\n| B1 | B2 |".to_string(),
             page_number: 1,
+            grid: None,
+            page_end: None,
         };
 
         let chunk = Chunk {
@@ -594,6 +604,11 @@ mod tests {
                 total_chunks: 1,
                 first_page: Some(1),
                 last_page: Some(1),
+                heading_path: Vec::new(),
+                element_types: Vec::new(),
+                node_ids: Vec::new(),
+                language: None,
+                language_confidence: None,
             },
         };
 
@@ -603,6 +618,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![table],
             detected_languages: None,
+            language_spans: None,
             chunks: Some(vec![chunk]),
             images: None,
             pages: None,
@@ -613,6 +629,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         };
 
         let c_result = to_c_extraction_result(result);
@@ -164,6 +164,7 @@ impl OcrBackend for FfiOcrBackend {
             metadata: kreuzberg::types::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -174,6 +175,7 @@ impl OcrBackend for FfiOcrBackend {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         })
     }
 
@@ -394,6 +394,7 @@ mod tests {
             metadata,
             tables: vec![],
             detected_languages: Some(vec!["en".to_string(), "de".to_string()]),
+            language_spans: None,
             chunks: Some(vec![
                 kreuzberg::types::Chunk {
                     content: "Chunk 1".to_string(),
@@ -406,6 +407,11 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        heading_path: Vec::new(),
+                        element_types: Vec::new(),
+                        node_ids: Vec::new(),
+                        language: None,
+                        language_confidence: None,
                     },
                 },
                 kreuzberg::types::Chunk {
@@ -419,6 +425,11 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        heading_path: Vec::new(),
+                        element_types: Vec::new(),
+                        node_ids: Vec::new(),
+                        language: None,
+                        language_confidence: None,
                     },
                 },
             ]),
@@ -431,6 +442,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         }
     }
 
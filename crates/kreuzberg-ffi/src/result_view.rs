@@ -424,6 +424,7 @@ mod tests {
             metadata,
             tables: vec![],
             detected_languages: Some(vec!["en".to_string(), "de".to_string()]),
+            language_spans: None,
             chunks: Some(vec![
                 kreuzberg::types::Chunk {
                     content: "Chunk 1".to_string(),
@@ -436,6 +437,11 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        heading_path: Vec::new(),
+                        element_types: Vec::new(),
+                        node_ids: Vec::new(),
+                        language: None,
+                        language_confidence: None,
                     },
                 },
                 kreuzberg::types::Chunk {
@@ -449,6 +455,11 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        heading_path: Vec::new(),
+                        element_types: Vec::new(),
+                        node_ids: Vec::new(),
+                        language: None,
+                        language_confidence: None,
                     },
                 },
             ]),
@@ -461,6 +472,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         }
     }
 
@@ -722,6 +734,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -732,6 +745,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         };
 
         let result_ptr = &result as *const ExtractionResult;
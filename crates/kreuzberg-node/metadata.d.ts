@@ -264,6 +264,10 @@ export interface ImagePreprocessingMetadata {
 	calculatedDpi?: number;
 	skippedResize: boolean;
 	resizeError?: string;
+	stepsApplied?: string[];
+	detectedOrientation?: number;
+	skewAngle?: number;
+	binarizationThreshold?: number;
 }
 
 export interface ErrorMetadata {
@@ -184,14 +184,24 @@ impl From<JsTesseractConfig> for RustTesseractConfig {
 /// - `preset`: Use a named preset (e.g., "balanced", "fast", "quality", "multilingual")
 /// - `fastembed`: Use a FastEmbed model with custom dimensions
 /// - `custom`: Use a custom ONNX model
+/// - `openai`: Use an OpenAI-compatible embeddings API
+/// - `ollama`: Use an Ollama server
 #[napi(object)]
 pub struct JsEmbeddingModelType {
-    /// Type of model: "preset", "fastembed", or "custom"
+    /// Type of model: "preset", "fastembed", "custom", "openai", or "ollama"
     pub model_type: String,
-    /// For preset: preset name; for fastembed/custom: model ID
+    /// For preset: preset name; for fastembed/custom: model ID; for openai/ollama: model name
     pub value: String,
-    /// Number of dimensions (only for fastembed/custom)
+    /// Number of dimensions (for fastembed/custom; optional expected size for openai/ollama)
     pub dimensions: Option<u32>,
+    /// API base URL (only for openai/ollama)
+    pub base_url: Option<String>,
+    /// Environment variable holding the API key (only for openai/ollama)
+    pub api_key_env: Option<String>,
+    /// Retries for failed requests (only for openai/ollama, default: 3)
+    pub max_retries: Option<u32>,
+    /// Request timeout in seconds (only for openai/ollama, default: 60)
+    pub timeout_secs: Option<u32>,
 }
 
 impl From<JsEmbeddingModelType> for RustEmbeddingModelType {
@@ -206,6 +216,22 @@ impl From<JsEmbeddingModelType> for RustEmbeddingModelType {
                 model_id: val.value,
                 dimensions: val.dimensions.unwrap_or(512) as usize,
             },
+            "openai" => RustEmbeddingModelType::OpenAi {
+                base_url: val.base_url.unwrap_or_else(|| "https://api.openai.com/v1".to_string()),
+                model: val.value,
+                api_key_env: val.api_key_env,
+                dimensions: val.dimensions.map(|d| d as usize),
+                max_retries: val.max_retries.unwrap_or(3),
+                timeout_secs: val.timeout_secs.unwrap_or(60) as u64,
+            },
+            "ollama" => RustEmbeddingModelType::Ollama {
+                base_url: val.base_url.unwrap_or_else(|| "http://localhost:11434".to_string()),
+                model: val.value,
+                api_key_env: val.api_key_env,
+                dimensions: val.dimensions.map(|d| d as usize),
+                max_retries: val.max_retries.unwrap_or(3),
+                timeout_secs: val.timeout_secs.unwrap_or(60) as u64,
+            },
             _ => RustEmbeddingModelType::Preset {
                 name: "balanced".to_string(),
             },
@@ -261,6 +287,9 @@ impl From<JsChunkingConfig> for RustChunkingConfig {
             chunker_type: ChunkerType::Text,
             embedding: val.embedding.map(Into::into),
             preset: val.preset,
+            sizing: Default::default(),
+            prepend_heading_context: false,
+            semantic: Default::default(),
         }
     }
 }
@@ -322,6 +351,7 @@ pub struct JsPdfConfig {
     pub passwords: Option<Vec<String>>,
     pub extract_metadata: Option<bool>,
     pub hierarchy: Option<JsHierarchyConfig>,
+    pub extract_embedded_files: Option<bool>,
 }
 
 impl From<JsPdfConfig> for RustPdfConfig {
@@ -331,6 +361,7 @@ impl From<JsPdfConfig> for RustPdfConfig {
             passwords: val.passwords,
             extract_metadata: val.extract_metadata.unwrap_or(true),
             hierarchy: val.hierarchy.map(|h| h.into()),
+            extract_embedded_files: val.extract_embedded_files.unwrap_or(false),
         }
     }
 }
@@ -1028,6 +1059,10 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
                 .unwrap_or_default(),
             include_document_structure: val.include_document_structure.unwrap_or(false),
             security_limits: None,
+            archive_options: None,
+            docx_options: None,
+            email_options: None,
+            cache_backend: Default::default(),
         })
     }
 }
@@ -1083,21 +1118,65 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                 max_chars: Some(chunk.max_characters as u32),
                 max_overlap: Some(chunk.overlap as u32),
                 embedding: chunk.embedding.map(|emb| JsEmbeddingConfig {
-                    model: Some(JsEmbeddingModelType {
-                        model_type: match emb.model {
-                            RustEmbeddingModelType::Preset { .. } => "preset".to_string(),
-                            RustEmbeddingModelType::FastEmbed { .. } => "fastembed".to_string(),
-                            RustEmbeddingModelType::Custom { .. } => "custom".to_string(),
+                    model: Some(match emb.model {
+                        RustEmbeddingModelType::Preset { name } => JsEmbeddingModelType {
+                            model_type: "preset".to_string(),
+                            value: name,
+                            dimensions: None,
+                            base_url: None,
+                            api_key_env: None,
+                            max_retries: None,
+                            timeout_secs: None,
+                        },
+                        RustEmbeddingModelType::FastEmbed { model, dimensions } => JsEmbeddingModelType {
+                            model_type: "fastembed".to_string(),
+                            value: model,
+                            dimensions: Some(dimensions as u32),
+                            base_url: None,
+                            api_key_env: None,
+                            max_retries: None,
+                            timeout_secs: None,
+                        },
+                        RustEmbeddingModelType::Custom { model_id, dimensions } => JsEmbeddingModelType {
+                            model_type: "custom".to_string(),
+                            value: model_id,
+                            dimensions: Some(dimensions as u32),
+                            base_url: None,
+                            api_key_env: None,
+                            max_retries: None,
+                            timeout_secs: None,
                         },
-                        value: match &emb.model {
-                            RustEmbeddingModelType::Preset { name } => name.clone(),
-                            RustEmbeddingModelType::FastEmbed { model, .. } => model.clone(),
-                            RustEmbeddingModelType::Custom { model_id, .. } => model_id.clone(),
+                        RustEmbeddingModelType::OpenAi {
+                            base_url,
+                            model,
+                            api_key_env,
+                            dimensions,
+                            max_retries,
+                            timeout_secs,
+                        } => JsEmbeddingModelType {
+                            model_type: "openai".to_string(),
+                            value: model,
+                            dimensions: dimensions.map(|d| d as u32),
+                            base_url: Some(base_url),
+                            api_key_env,
+                            max_retries: Some(max_retries),
+                            timeout_secs: Some(timeout_secs as u32),
                         },
-                        dimensions: match emb.model {
-                            RustEmbeddingModelType::FastEmbed { dimensions, .. } => Some(dimensions as u32),
-                            RustEmbeddingModelType::Custom { dimensions, .. } => Some(dimensions as u32),
-                            _ => None,
+                        RustEmbeddingModelType::Ollama {
+                            base_url,
+                            model,
+                            api_key_env,
+                            dimensions,
+                            max_retries,
+                            timeout_secs,
+                        } => JsEmbeddingModelType {
+                            model_type: "ollama".to_string(),
+                            value: model,
+                            dimensions: dimensions.map(|d| d as u32),
+                            base_url: Some(base_url),
+                            api_key_env,
+                            max_retries: Some(max_retries),
+                            timeout_secs: Some(timeout_secs as u32),
                         },
                     }),
                     normalize: Some(emb.normalize),
@@ -1125,6 +1204,7 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                     include_bbox: Some(h.include_bbox),
                     ocr_coverage_threshold: h.ocr_coverage_threshold.map(|v| v as f64),
                 }),
+                extract_embedded_files: Some(pdf.extract_embedded_files),
             }),
             token_reduction: val.token_reduction.map(|tr| JsTokenReductionConfig {
                 mode: Some(tr.mode),
@@ -136,6 +136,7 @@ impl RustOcrBackend for JsOcrBackend {
             metadata,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -146,6 +147,7 @@ impl RustOcrBackend for JsOcrBackend {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: vec![],
+            children: None,
         })
     }
 
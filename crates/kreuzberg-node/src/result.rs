@@ -35,6 +35,9 @@ pub struct JsPageContent {
     pub images: Vec<JsExtractedImage>,
     pub hierarchy: Option<JsPageHierarchy>,
     pub is_blank: Option<bool>,
+    pub language: Option<String>,
+    pub language_confidence: Option<f64>,
+    pub text_source: Option<String>,
 }
 
 #[napi(object)]
@@ -71,6 +74,18 @@ pub struct JsChunkMetadata {
     pub total_chunks: u32,
     pub first_page: Option<u32>,
     pub last_page: Option<u32>,
+    pub language: Option<String>,
+    pub language_confidence: Option<f64>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct JsLanguageSpan {
+    pub byte_start: u32,
+    pub byte_end: u32,
+    pub language: String,
+    pub script: String,
+    pub confidence: f64,
 }
 
 #[napi(object)]
@@ -152,6 +167,7 @@ pub struct JsExtractionResult {
     pub metadata: serde_json::Value,
     pub tables: Vec<JsTable>,
     pub detected_languages: Option<Vec<String>>,
+    pub language_spans: Option<Vec<JsLanguageSpan>>,
     pub chunks: Option<Vec<JsChunk>>,
     #[serde(skip)]
     pub images: Option<Vec<JsExtractedImage>>,
@@ -287,6 +303,13 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     images: page_images,
                     hierarchy,
                     is_blank: page.is_blank,
+                    language: page.language,
+                    language_confidence: page.language_confidence,
+                    text_source: page.text_source.and_then(|source| {
+                        serde_json::to_value(source)
+                            .ok()
+                            .and_then(|v| v.as_str().map(String::from))
+                    }),
                 });
             }
             Some(js_pages)
@@ -388,6 +411,22 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                 })
                 .collect(),
             detected_languages: val.detected_languages,
+            language_spans: match val.language_spans {
+                Some(spans) => {
+                    let mut js_spans = Vec::with_capacity(spans.len());
+                    for span in spans {
+                        js_spans.push(JsLanguageSpan {
+                            byte_start: usize_to_u32(span.byte_start, "language_spans[].byte_start")?,
+                            byte_end: usize_to_u32(span.byte_end, "language_spans[].byte_end")?,
+                            language: span.language,
+                            script: span.script,
+                            confidence: span.confidence,
+                        });
+                    }
+                    Some(js_spans)
+                }
+                None => None,
+            },
             chunks: if let Some(chunks) = val.chunks {
                 let mut js_chunks = Vec::with_capacity(chunks.len());
                 for chunk in chunks {
@@ -402,6 +441,8 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                         total_chunks: usize_to_u32(chunk.metadata.total_chunks, "chunks[].metadata.total_chunks")?,
                         first_page: chunk.metadata.first_page.map(|p| p as u32),
                         last_page: chunk.metadata.last_page.map(|p| p as u32),
+                        language: chunk.metadata.language,
+                        language_confidence: chunk.metadata.language_confidence,
                     };
 
                     let embedding = chunk
@@ -578,6 +619,11 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                         total_chunks: chunk.metadata.total_chunks as usize,
                         first_page: chunk.metadata.first_page.map(|v| v as usize),
                         last_page: chunk.metadata.last_page.map(|v| v as usize),
+                        heading_path: Vec::new(),
+                        element_types: Vec::new(),
+                        node_ids: Vec::new(),
+                        language: chunk.metadata.language,
+                        language_confidence: chunk.metadata.language_confidence,
                     },
                 });
             }
@@ -599,9 +645,23 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number as usize,
+                    grid: None,
+                    page_end: None,
                 })
                 .collect(),
             detected_languages: val.detected_languages,
+            language_spans: val.language_spans.map(|spans| {
+                spans
+                    .into_iter()
+                    .map(|span| kreuzberg::LanguageSpan {
+                        byte_start: span.byte_start as usize,
+                        byte_end: span.byte_end as usize,
+                        language: span.language,
+                        script: span.script,
+                        confidence: span.confidence,
+                    })
+                    .collect()
+            }),
             chunks,
             images,
             pages: None,
@@ -664,6 +724,7 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     message: w.message,
                 })
                 .collect(),
+            children: None,
         })
     }
 }
@@ -14,6 +14,7 @@ import type {
 	ElementType,
 	ExtractedImage,
 	ExtractionResult,
+	LanguageSpan,
 	PageContent,
 	Table,
 } from "../types.js";
@@ -104,6 +105,10 @@ function convertChunk(rawChunk: unknown): Chunk {
 			firstPage: ((metadata["first_page"] ?? metadata["firstPage"]) as number | null) ?? null,
 			// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
 			lastPage: ((metadata["last_page"] ?? metadata["lastPage"]) as number | null) ?? null,
+			// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+			language: (metadata["language"] as string | null | undefined) ?? null,
+			// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+			languageConfidence: ((metadata["language_confidence"] ?? metadata["languageConfidence"]) as number | null) ?? null,
 		},
 	};
 }
@@ -231,6 +236,12 @@ function convertPageContent(rawPage: unknown): PageContent {
 		images: Array.isArray(page["images"]) ? (page["images"] as unknown[]).map((image) => convertImage(image)) : [],
 		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
 		isBlank: (page["isBlank"] as boolean | null | undefined) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		language: (page["language"] as string | null | undefined) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		languageConfidence: (page["languageConfidence"] as number | null | undefined) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		textSource: (page["textSource"] as "text_layer" | "ocr" | null | undefined) ?? null,
 	};
 }
 
@@ -274,6 +285,8 @@ function convertResult(rawResult: unknown): ExtractionResult {
 		tables: Array.isArray(result["tables"]) ? (result["tables"] as Table[]) : [],
 		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
 		detectedLanguages: Array.isArray(result["detectedLanguages"]) ? (result["detectedLanguages"] as string[]) : null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		languageSpans: Array.isArray(result["languageSpans"]) ? (result["languageSpans"] as LanguageSpan[]) : null,
 		chunks: null,
 		images: null,
 		elements: null,
@@ -76,6 +76,7 @@ export type {
 	ImageExtractionConfig,
 	KeywordConfig,
 	LanguageDetectionConfig,
+	LanguageSpan,
 	OcrBackendProtocol,
 	OcrConfig,
 	PageContent,
@@ -642,8 +642,9 @@ export interface ExtractionConfig {
 	 * - "markdown": Markdown formatted output
 	 * - "djot": Djot markup format
 	 * - "html": HTML formatted output
+	 * - "alto", "pagexml", "hocr": OCR layout XML built from OCR elements (images with OCR)
 	 */
-	outputFormat?: "plain" | "markdown" | "djot" | "html";
+	outputFormat?: "plain" | "markdown" | "djot" | "html" | "alto" | "pagexml" | "hocr";
 
 	/**
 	 * Result structure format. Default: "unified".
@@ -806,6 +807,10 @@ export interface ImagePreprocessingMetadata {
 	calculatedDpi?: number | null;
 	skippedResize?: boolean;
 	resizeError?: string | null;
+	stepsApplied?: string[];
+	detectedOrientation?: number | null;
+	skewAngle?: number | null;
+	binarizationThreshold?: number | null;
 }
 
 export interface ErrorMetadata {
@@ -900,6 +905,10 @@ export interface ChunkMetadata {
 	firstPage?: number | null;
 	/** Last page number this chunk spans (1-indexed, only when page tracking enabled) */
 	lastPage?: number | null;
+	/** Dominant language of this chunk (ISO 639-3 code, only when language detection enabled) */
+	language?: string | null;
+	/** Share of this chunk's text in the dominant language (0.0-1.0) */
+	languageConfidence?: number | null;
 }
 
 /**
@@ -979,6 +988,31 @@ export interface PageContent {
 
 	/** Whether this page is blank (contains no meaningful content) */
 	isBlank?: boolean | null;
+
+	/** Dominant language of this page (ISO 639-3 code, only when language detection enabled) */
+	language?: string | null;
+
+	/** Share of this page's text in the dominant language (0.0-1.0) */
+	languageConfidence?: number | null;
+
+	/** Whether this page's text came from the PDF text layer or from OCR */
+	textSource?: "text_layer" | "ocr" | null;
+}
+
+/**
+ * Contiguous run of text in a single language and script.
+ */
+export interface LanguageSpan {
+	/** Byte offset where the span starts in the content */
+	byteStart: number;
+	/** Byte offset where the span ends in the content */
+	byteEnd: number;
+	/** ISO 639-3 language code */
+	language: string;
+	/** Script name (e.g., 'Latin', 'Cyrillic') */
+	script: string;
+	/** Detection confidence (0.0-1.0) */
+	confidence: number;
 }
 
 /**
@@ -1166,6 +1200,9 @@ export interface ExtractionResult {
 	/** Detected languages in the document (ISO 639-1 codes, e.g., ['en', 'de']), null if detection disabled */
 	detectedLanguages: string[] | null;
 
+	/** Per-language runs of the content with byte offsets (if language detection was enabled), null otherwise */
+	languageSpans?: LanguageSpan[] | null;
+
 	/** Document chunks for RAG/vector databases (if chunking was enabled), null otherwise */
 	chunks: Chunk[] | null;
 
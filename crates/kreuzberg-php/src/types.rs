@@ -1048,5 +1048,7 @@ pub(crate) fn php_array_to_table(arr: &ext_php_rs::types::ZendHashTable) -> PhpR
         cells,
         markdown,
         page_number,
+        grid: None,
+        page_end: None,
     })
 }
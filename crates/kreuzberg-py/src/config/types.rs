@@ -117,9 +117,12 @@ impl ExtractionConfig {
                         "markdown" => kreuzberg::core::config::formats::OutputFormat::Markdown,
                         "djot" => kreuzberg::core::config::formats::OutputFormat::Djot,
                         "html" => kreuzberg::core::config::formats::OutputFormat::Html,
+                        "alto" => kreuzberg::core::config::formats::OutputFormat::Alto,
+                        "pagexml" => kreuzberg::core::config::formats::OutputFormat::PageXml,
+                        "hocr" => kreuzberg::core::config::formats::OutputFormat::Hocr,
                         other => {
                             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                                "Invalid output_format: {}. Must be 'plain', 'markdown', 'djot', or 'html'",
+                                "Invalid output_format: {}. Must be 'plain', 'markdown', 'djot', 'html', 'alto', 'pagexml', or 'hocr'",
                                 other
                             )));
                         }
@@ -128,6 +131,10 @@ impl ExtractionConfig {
                     kreuzberg::core::config::formats::OutputFormat::Plain
                 },
                 security_limits: None,
+                archive_options: None,
+                docx_options: None,
+                email_options: None,
+                cache_backend: Default::default(),
             },
             html_options_dict,
         })
@@ -311,6 +318,9 @@ impl ExtractionConfig {
             kreuzberg::core::config::formats::OutputFormat::Djot => "djot".to_string(),
             kreuzberg::core::config::formats::OutputFormat::Html => "html".to_string(),
             kreuzberg::core::config::formats::OutputFormat::Structured => "structured".to_string(),
+            kreuzberg::core::config::formats::OutputFormat::Alto => "alto".to_string(),
+            kreuzberg::core::config::formats::OutputFormat::PageXml => "pagexml".to_string(),
+            kreuzberg::core::config::formats::OutputFormat::Hocr => "hocr".to_string(),
         }
     }
 
@@ -322,6 +332,9 @@ impl ExtractionConfig {
             "djot" => kreuzberg::core::config::formats::OutputFormat::Djot,
             "html" => kreuzberg::core::config::formats::OutputFormat::Html,
             "structured" | "json" => kreuzberg::core::config::formats::OutputFormat::Structured,
+            "alto" => kreuzberg::core::config::formats::OutputFormat::Alto,
+            "pagexml" => kreuzberg::core::config::formats::OutputFormat::PageXml,
+            "hocr" => kreuzberg::core::config::formats::OutputFormat::Hocr,
             _ => kreuzberg::core::config::formats::OutputFormat::Plain, // Default on invalid
         };
     }
@@ -516,6 +529,9 @@ impl OcrConfig {
 ///     >>> model = EmbeddingModelType.fastembed("BGEBaseENV15", 768)
 ///     >>> # Use a custom model
 ///     >>> model = EmbeddingModelType.custom("my-model", 512)
+///     >>> # Use a remote embeddings API
+///     >>> model = EmbeddingModelType.openai("https://api.openai.com/v1", "text-embedding-3-small", api_key_env="OPENAI_API_KEY")
+///     >>> model = EmbeddingModelType.ollama("nomic-embed-text")
 #[pyclass(name = "EmbeddingModelType", module = "kreuzberg")]
 #[derive(Clone)]
 pub struct EmbeddingModelType {
@@ -548,6 +564,52 @@ impl EmbeddingModelType {
         }
     }
 
+    /// Create a model type for an OpenAI-compatible embeddings API.
+    #[staticmethod]
+    #[pyo3(signature = (base_url, model, api_key_env=None, dimensions=None, max_retries=3, timeout_secs=60))]
+    fn openai(
+        base_url: String,
+        model: String,
+        api_key_env: Option<String>,
+        dimensions: Option<usize>,
+        max_retries: u32,
+        timeout_secs: u64,
+    ) -> Self {
+        Self {
+            inner: kreuzberg::EmbeddingModelType::OpenAi {
+                base_url,
+                model,
+                api_key_env,
+                dimensions,
+                max_retries,
+                timeout_secs,
+            },
+        }
+    }
+
+    /// Create a model type for an Ollama server.
+    #[staticmethod]
+    #[pyo3(signature = (model, base_url="http://localhost:11434".to_string(), api_key_env=None, dimensions=None, max_retries=3, timeout_secs=60))]
+    fn ollama(
+        model: String,
+        base_url: String,
+        api_key_env: Option<String>,
+        dimensions: Option<usize>,
+        max_retries: u32,
+        timeout_secs: u64,
+    ) -> Self {
+        Self {
+            inner: kreuzberg::EmbeddingModelType::Ollama {
+                base_url,
+                model,
+                api_key_env,
+                dimensions,
+                max_retries,
+                timeout_secs,
+            },
+        }
+    }
+
     fn __repr__(&self) -> String {
         match &self.inner {
             kreuzberg::EmbeddingModelType::Preset { name } => format!("EmbeddingModelType.preset('{}')", name),
@@ -557,6 +619,12 @@ impl EmbeddingModelType {
             kreuzberg::EmbeddingModelType::Custom { model_id, dimensions } => {
                 format!("EmbeddingModelType.custom('{}', {})", model_id, dimensions)
             }
+            kreuzberg::EmbeddingModelType::OpenAi { base_url, model, .. } => {
+                format!("EmbeddingModelType.openai('{}', '{}')", base_url, model)
+            }
+            kreuzberg::EmbeddingModelType::Ollama { base_url, model, .. } => {
+                format!("EmbeddingModelType.ollama('{}', '{}')", model, base_url)
+            }
         }
     }
 }
@@ -689,6 +757,9 @@ impl ChunkingConfig {
                 chunker_type: kreuzberg::ChunkerType::Text,
                 embedding: embedding.map(Into::into),
                 preset,
+                sizing: Default::default(),
+                prepend_heading_context: false,
+                semantic: Default::default(),
             },
         }
     }
@@ -872,12 +943,13 @@ pub struct PdfConfig {
 #[pymethods]
 impl PdfConfig {
     #[new]
-    #[pyo3(signature = (extract_images=None, passwords=None, extract_metadata=None, hierarchy=None))]
+    #[pyo3(signature = (extract_images=None, passwords=None, extract_metadata=None, hierarchy=None, extract_embedded_files=None))]
     fn new(
         extract_images: Option<bool>,
         passwords: Option<Vec<String>>,
         extract_metadata: Option<bool>,
         hierarchy: Option<HierarchyConfig>,
+        extract_embedded_files: Option<bool>,
     ) -> Self {
         Self {
             inner: kreuzberg::PdfConfig {
@@ -885,6 +957,7 @@ impl PdfConfig {
                 passwords,
                 extract_metadata: extract_metadata.unwrap_or(true),
                 hierarchy: hierarchy.map(|h| h.inner),
+                extract_embedded_files: extract_embedded_files.unwrap_or(false),
             },
         }
     }
@@ -929,6 +1002,16 @@ impl PdfConfig {
         self.inner.hierarchy = value.map(|h| h.inner);
     }
 
+    #[getter]
+    fn extract_embedded_files(&self) -> bool {
+        self.inner.extract_embedded_files
+    }
+
+    #[setter]
+    fn set_extract_embedded_files(&mut self, value: bool) {
+        self.inner.extract_embedded_files = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "PdfConfig(extract_images={}, extract_metadata={}, passwords={})",
@@ -381,6 +381,7 @@ fn dict_to_extraction_result(_py: Python<'_>, dict: &Bound<'_, PyAny>) -> Result
         },
         tables,
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         pages: None,
@@ -391,6 +392,7 @@ fn dict_to_extraction_result(_py: Python<'_>, dict: &Bound<'_, PyAny>) -> Result
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: vec![],
+        children: None,
     })
 }
 
@@ -546,6 +548,8 @@ fn extract_tables(obj: &Bound<'_, PyAny>) -> Result<Vec<kreuzberg::types::Table>
             cells,
             markdown,
             page_number,
+            grid: None,
+            page_end: None,
         });
     }
 
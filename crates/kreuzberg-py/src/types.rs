@@ -423,6 +423,12 @@ impl ExtractionResult {
                 if let Some(last_page) = chunk.metadata.last_page {
                     chunk_metadata_dict.set_item("last_page", last_page)?;
                 }
+                if let Some(language) = &chunk.metadata.language {
+                    chunk_metadata_dict.set_item("language", language)?;
+                }
+                if let Some(confidence) = chunk.metadata.language_confidence {
+                    chunk_metadata_dict.set_item("language_confidence", confidence)?;
+                }
 
                 let py_chunk = PyChunk {
                     content: chunk.content,
@@ -483,6 +489,19 @@ impl ExtractionResult {
                 if let Some(is_blank) = page.is_blank {
                     page_dict.set_item("is_blank", is_blank)?;
                 }
+                if let Some(language) = &page.language {
+                    page_dict.set_item("language", language)?;
+                }
+                if let Some(confidence) = page.language_confidence {
+                    page_dict.set_item("language_confidence", confidence)?;
+                }
+                if let Some(source) = page.text_source
+                    && let Some(source) = serde_json::to_value(source)
+                        .ok()
+                        .and_then(|v| v.as_str().map(String::from))
+                {
+                    page_dict.set_item("text_source", source)?;
+                }
 
                 page_list.append(page_dict)?;
             }
@@ -656,6 +675,7 @@ mod tests {
                 metadata: kreuzberg::Metadata::default(),
                 tables: Vec::new(),
                 detected_languages: Some(vec!["en".to_string()]),
+                language_spans: None,
                 chunks: None,
                 images: None,
                 pages: None,
@@ -669,6 +689,7 @@ mod tests {
                     source: "test".to_string(),
                     message: "test warning".to_string(),
                 }],
+                children: None,
             };
 
             let py_result =
@@ -217,8 +217,9 @@ export interface ExtractionConfig {
 	 * - "markdown": Markdown formatted output
 	 * - "djot": Djot markup format
 	 * - "html": HTML formatted output
+	 * - "alto", "pagexml", "hocr": OCR layout XML built from OCR elements (images with OCR)
 	 */
-	outputFormat?: "plain" | "markdown" | "djot" | "html";
+	outputFormat?: "plain" | "markdown" | "djot" | "html" | "alto" | "pagexml" | "hocr";
 	/**
 	 * Result structure format.
 	 * Controls whether results are returned in unified format or element-based format.
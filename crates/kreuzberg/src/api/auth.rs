@@ -0,0 +1,546 @@
+//! Authentication, authorization and per-client quotas.
+//!
+//! Requests are authenticated with a static API key or an HMAC-signed JWT,
+//! passed as `Authorization: Bearer <credential>` or `X-API-Key: <key>`.
+//! Each authenticated client (API key or JWT subject) has its own request
+//! rate bucket and concurrency limit.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::core::server_config::{ApiScope, AuthConfig, JwtAlgorithm, JwtConfig, QuotaConfig};
+use crate::{KreuzbergError, Result};
+
+use super::types::ErrorResponse;
+
+/// Header carrying an API key as an alternative to `Authorization: Bearer`.
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// An authenticated client, available to handlers as a request extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedClient {
+    /// Stable client identifier: the key name, `key-<hash prefix>` or `jwt:<subject>`
+    pub id: String,
+    /// Granted scopes
+    pub scopes: Vec<ApiScope>,
+}
+
+struct KeyEntry {
+    client: AuthenticatedClient,
+    quota: QuotaConfig,
+}
+
+/// Validates credentials and enforces per-client quotas.
+pub(crate) struct Authenticator {
+    /// API keys indexed by the SHA-256 digest of the key
+    keys: HashMap<[u8; 32], KeyEntry>,
+    jwt: Option<JwtConfig>,
+    default_quota: QuotaConfig,
+    clients: Mutex<HashMap<String, ClientState>>,
+}
+
+impl Authenticator {
+    /// Build an authenticator from the server's auth settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if the API key file cannot be read,
+    /// a key is empty, or the JWT secret is empty.
+    pub(crate) fn new(config: &AuthConfig) -> Result<Self> {
+        if config.jwt.as_ref().is_some_and(|jwt| jwt.secret.is_empty()) {
+            return Err(KreuzbergError::validation("JWT secret must not be empty"));
+        }
+
+        let keys = config
+            .resolve_api_keys()?
+            .into_iter()
+            .map(|key| {
+                let digest: [u8; 32] = Sha256::digest(key.key.as_bytes()).into();
+                let id = key.name.unwrap_or_else(|| format!("key-{}", hex::encode(&digest[..4])));
+                let entry = KeyEntry {
+                    client: AuthenticatedClient { id, scopes: key.scopes },
+                    quota: key.quota.unwrap_or(config.default_quota),
+                };
+                (digest, entry)
+            })
+            .collect();
+
+        Ok(Self {
+            keys,
+            jwt: config.jwt.clone(),
+            default_quota: config.default_quota,
+            clients: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// An authenticator that accepts no credentials, used when the configuration is invalid.
+    pub(crate) fn deny_all() -> Self {
+        Self {
+            keys: HashMap::new(),
+            jwt: None,
+            default_quota: QuotaConfig::default(),
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Resolve a credential to a client and its quota.
+    fn authenticate(&self, credential: &str) -> std::result::Result<(AuthenticatedClient, QuotaConfig), AuthError> {
+        let digest: [u8; 32] = Sha256::digest(credential.as_bytes()).into();
+        if let Some(entry) = self.keys.get(&digest) {
+            return Ok((entry.client.clone(), entry.quota));
+        }
+
+        match &self.jwt {
+            Some(jwt) if credential.split('.').count() == 3 => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+                let client = validate_jwt(credential, jwt, now)?;
+                Ok((client, self.default_quota))
+            }
+            _ => Err(AuthError::Unauthenticated("Invalid API key".to_string())),
+        }
+    }
+
+    /// Admit a request of `client`, returning the concurrency permit to hold while it runs.
+    fn admit(
+        &self,
+        client: &AuthenticatedClient,
+        quota: QuotaConfig,
+    ) -> std::result::Result<Option<OwnedSemaphorePermit>, AuthError> {
+        let mut clients = self.clients.lock();
+        let state = clients
+            .entry(client.id.clone())
+            .or_insert_with(|| ClientState::new(quota));
+
+        let permit = match &state.concurrency {
+            Some(semaphore) => {
+                Some(
+                    Arc::clone(semaphore)
+                        .try_acquire_owned()
+                        .map_err(|_| AuthError::QuotaExceeded {
+                            message: "Too many concurrent requests".to_string(),
+                            retry_after: Duration::from_secs(1),
+                        })?,
+                )
+            }
+            None => None,
+        };
+
+        if let Some(bucket) = state.rate.as_mut() {
+            bucket
+                .try_take(Instant::now())
+                .map_err(|retry_after| AuthError::QuotaExceeded {
+                    message: "Rate limit exceeded".to_string(),
+                    retry_after,
+                })?;
+        }
+
+        Ok(permit)
+    }
+}
+
+/// Per-client quota state.
+struct ClientState {
+    rate: Option<TokenBucket>,
+    concurrency: Option<Arc<Semaphore>>,
+}
+
+impl ClientState {
+    fn new(quota: QuotaConfig) -> Self {
+        Self {
+            rate: quota
+                .requests_per_minute
+                .filter(|&rpm| rpm > 0)
+                .map(|rpm| TokenBucket::new(rpm, Instant::now())),
+            concurrency: quota
+                .max_concurrent_requests
+                .map(|limit| Arc::new(Semaphore::new(limit))),
+        }
+    }
+}
+
+/// Token bucket holding up to `requests_per_minute` tokens, refilled continuously.
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(requests_per_minute: u32, now: Instant) -> Self {
+        let capacity = f64::from(requests_per_minute);
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: capacity / 60.0,
+            last_refill: now,
+        }
+    }
+
+    /// Take a token, or return how long to wait until one is available.
+    fn try_take(&mut self, now: Instant) -> std::result::Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.refill_per_sec))
+        }
+    }
+}
+
+/// Validate an HMAC-signed JWT and return the client it identifies.
+fn validate_jwt(token: &str, config: &JwtConfig, now: u64) -> std::result::Result<AuthenticatedClient, AuthError> {
+    let invalid = |reason: &str| AuthError::Unauthenticated(format!("Invalid token: {}", reason));
+
+    let mut parts = token.split('.');
+    let (Some(header_b64), Some(payload_b64), Some(signature_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid("malformed"));
+    };
+
+    let header: serde_json::Value = decode_segment(header_b64).ok_or_else(|| invalid("malformed header"))?;
+    if header.get("alg").and_then(|alg| alg.as_str()) != Some(config.algorithm.as_str()) {
+        return Err(invalid("unexpected signing algorithm"));
+    }
+
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|_| invalid("malformed signature"))?;
+    let signing_input = &token[..header_b64.len() + 1 + payload_b64.len()];
+    if !verify_signature(config, signing_input.as_bytes(), &signature) {
+        return Err(invalid("signature mismatch"));
+    }
+
+    let claims: serde_json::Value = decode_segment(payload_b64).ok_or_else(|| invalid("malformed claims"))?;
+    let leeway = config.leeway_seconds;
+
+    if let Some(exp) = claims.get("exp") {
+        let exp = exp.as_u64().ok_or_else(|| invalid("malformed exp claim"))?;
+        if now > exp.saturating_add(leeway) {
+            return Err(invalid("expired"));
+        }
+    }
+    if let Some(nbf) = claims.get("nbf") {
+        let nbf = nbf.as_u64().ok_or_else(|| invalid("malformed nbf claim"))?;
+        if now.saturating_add(leeway) < nbf {
+            return Err(invalid("not yet valid"));
+        }
+    }
+    if let Some(issuer) = &config.issuer
+        && claims.get("iss").and_then(|iss| iss.as_str()) != Some(issuer.as_str())
+    {
+        return Err(invalid("unexpected issuer"));
+    }
+    if let Some(audience) = &config.audience {
+        let matches = match claims.get("aud") {
+            Some(serde_json::Value::String(aud)) => aud == audience,
+            Some(serde_json::Value::Array(auds)) => auds.iter().any(|aud| aud.as_str() == Some(audience.as_str())),
+            _ => false,
+        };
+        if !matches {
+            return Err(invalid("unexpected audience"));
+        }
+    }
+
+    let id = match claims.get("sub").and_then(|sub| sub.as_str()) {
+        Some(sub) if !sub.is_empty() => format!("jwt:{}", sub),
+        _ => format!("jwt-{}", hex::encode(&Sha256::digest(token.as_bytes())[..4])),
+    };
+
+    let mut scopes: Vec<ApiScope> = match claims.get(config.scope_claim.as_str()) {
+        Some(serde_json::Value::String(scopes)) => scopes.split_whitespace().filter_map(|s| s.parse().ok()).collect(),
+        Some(serde_json::Value::Array(scopes)) => scopes
+            .iter()
+            .filter_map(|s| s.as_str().and_then(|s| s.parse().ok()))
+            .collect(),
+        _ => Vec::new(),
+    };
+    if scopes.is_empty() {
+        scopes.push(ApiScope::User);
+    }
+
+    Ok(AuthenticatedClient { id, scopes })
+}
+
+fn decode_segment(segment: &str) -> Option<serde_json::Value> {
+    let bytes = URL_SAFE_NO_PAD.decode(segment).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+fn verify_signature(config: &JwtConfig, signing_input: &[u8], signature: &[u8]) -> bool {
+    fn verify<M: Mac + hmac::digest::KeyInit>(secret: &[u8], input: &[u8], signature: &[u8]) -> bool {
+        match <M as hmac::digest::KeyInit>::new_from_slice(secret) {
+            Ok(mut mac) => {
+                mac.update(input);
+                mac.verify_slice(signature).is_ok()
+            }
+            Err(_) => false,
+        }
+    }
+
+    let secret = config.secret.as_bytes();
+    match config.algorithm {
+        JwtAlgorithm::HS256 => verify::<Hmac<Sha256>>(secret, signing_input, signature),
+        JwtAlgorithm::HS384 => verify::<Hmac<Sha384>>(secret, signing_input, signature),
+        JwtAlgorithm::HS512 => verify::<Hmac<Sha512>>(secret, signing_input, signature),
+    }
+}
+
+/// Extract the credential from `Authorization: Bearer` or `X-API-Key`.
+fn credential_from_headers(headers: &HeaderMap) -> Option<&str> {
+    if let Some(value) = headers.get(header::AUTHORIZATION).and_then(|v| v.to_str().ok()) {
+        let (scheme, credential) = value.trim().split_once(' ')?;
+        return scheme
+            .eq_ignore_ascii_case("bearer")
+            .then(|| credential.trim())
+            .filter(|c| !c.is_empty());
+    }
+
+    headers
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+}
+
+/// Authentication failure, rendered as a JSON error response.
+#[derive(Debug)]
+enum AuthError {
+    /// Missing or invalid credentials (401)
+    Unauthenticated(String),
+    /// Valid credentials without the required scope (403)
+    Forbidden(String),
+    /// Rate or concurrency quota exhausted (429)
+    QuotaExceeded { message: String, retry_after: Duration },
+}
+
+impl IntoResponse for AuthError {
+    fn into_response(self) -> Response {
+        let (status, error_type, message) = match &self {
+            AuthError::Unauthenticated(message) => (StatusCode::UNAUTHORIZED, "AuthenticationError", message),
+            AuthError::Forbidden(message) => (StatusCode::FORBIDDEN, "AuthorizationError", message),
+            AuthError::QuotaExceeded { message, .. } => (StatusCode::TOO_MANY_REQUESTS, "RateLimitError", message),
+        };
+
+        let body = ErrorResponse {
+            error_type: error_type.to_string(),
+            message: message.clone(),
+            traceback: None,
+            status_code: status.as_u16(),
+        };
+        let mut response = (status, Json(body)).into_response();
+
+        match self {
+            AuthError::Unauthenticated(_) => {
+                response
+                    .headers_mut()
+                    .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+            }
+            AuthError::QuotaExceeded { retry_after, .. } => {
+                let seconds = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+                response
+                    .headers_mut()
+                    .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+            }
+            AuthError::Forbidden(_) => {}
+        }
+
+        response
+    }
+}
+
+/// State of the authentication middleware for one group of routes.
+#[derive(Clone)]
+pub(crate) struct AuthGuard {
+    pub(crate) authenticator: Arc<Authenticator>,
+    pub(crate) required_scope: ApiScope,
+}
+
+/// Middleware authenticating the request and enforcing scope and quota.
+///
+/// The concurrency permit is held until the handler has produced its response.
+pub(crate) async fn auth_middleware(State(guard): State<AuthGuard>, mut request: Request, next: Next) -> Response {
+    let Some(credential) = credential_from_headers(request.headers()) else {
+        return AuthError::Unauthenticated("Missing credentials".to_string()).into_response();
+    };
+
+    let (client, quota) = match guard.authenticator.authenticate(credential) {
+        Ok(resolved) => resolved,
+        Err(error) => return error.into_response(),
+    };
+
+    if !guard.required_scope.is_granted_by(&client.scopes) {
+        tracing::warn!(client = %client.id, "Rejected request lacking the required scope");
+        return AuthError::Forbidden("Insufficient scope for this endpoint".to_string()).into_response();
+    }
+
+    let permit = match guard.authenticator.admit(&client, quota) {
+        Ok(permit) => permit,
+        Err(error) => {
+            tracing::debug!(client = %client.id, "Rejected request over quota");
+            return error.into_response();
+        }
+    };
+
+    request.extensions_mut().insert(client);
+    let response = next.run(request).await;
+    drop(permit);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::server_config::ApiKeyConfig;
+
+    fn sign(claims: serde_json::Value, secret: &str, alg: &str) -> String {
+        let header = URL_SAFE_NO_PAD.encode(serde_json::json!({ "alg": alg, "typ": "JWT" }).to_string());
+        let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+        let input = format!("{}.{}", header, payload);
+        let mut mac = <Hmac<Sha256> as hmac::digest::KeyInit>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(input.as_bytes());
+        format!("{}.{}", input, URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()))
+    }
+
+    fn jwt_config() -> JwtConfig {
+        JwtConfig {
+            issuer: Some("issuer".to_string()),
+            audience: Some("kreuzberg".to_string()),
+            ..JwtConfig::new("secret")
+        }
+    }
+
+    #[test]
+    fn test_validate_jwt_accepts_valid_token() {
+        let token = sign(
+            serde_json::json!({
+                "sub": "alice",
+                "iss": "issuer",
+                "aud": ["other", "kreuzberg"],
+                "exp": 2_000,
+                "scope": "user admin",
+            }),
+            "secret",
+            "HS256",
+        );
+
+        let client = validate_jwt(&token, &jwt_config(), 1_000).unwrap();
+
+        assert_eq!(client.id, "jwt:alice");
+        assert_eq!(client.scopes, vec![ApiScope::User, ApiScope::Admin]);
+    }
+
+    #[test]
+    fn test_validate_jwt_defaults_to_user_scope() {
+        let token = sign(
+            serde_json::json!({ "sub": "bob", "iss": "issuer", "aud": "kreuzberg" }),
+            "secret",
+            "HS256",
+        );
+
+        let client = validate_jwt(&token, &jwt_config(), 1_000).unwrap();
+        assert_eq!(client.scopes, vec![ApiScope::User]);
+    }
+
+    #[test]
+    fn test_validate_jwt_rejects_invalid_tokens() {
+        let claims = serde_json::json!({ "sub": "alice", "iss": "issuer", "aud": "kreuzberg", "exp": 2_000 });
+        let config = jwt_config();
+
+        assert!(validate_jwt(&sign(claims.clone(), "wrong", "HS256"), &config, 1_000).is_err());
+        assert!(validate_jwt(&sign(claims.clone(), "secret", "none"), &config, 1_000).is_err());
+        assert!(validate_jwt(&sign(claims.clone(), "secret", "HS256"), &config, 2_061).is_err());
+        assert!(validate_jwt(&sign(claims, "secret", "HS256"), &config, 2_060).is_ok());
+
+        let wrong_issuer = serde_json::json!({ "sub": "alice", "iss": "other", "aud": "kreuzberg" });
+        assert!(validate_jwt(&sign(wrong_issuer, "secret", "HS256"), &config, 1_000).is_err());
+
+        let not_yet_valid = serde_json::json!({ "iss": "issuer", "aud": "kreuzberg", "nbf": 5_000 });
+        assert!(validate_jwt(&sign(not_yet_valid, "secret", "HS256"), &config, 1_000).is_err());
+
+        assert!(validate_jwt("a.b.c", &config, 1_000).is_err());
+    }
+
+    #[test]
+    fn test_token_bucket_refills() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2, start);
+
+        assert!(bucket.try_take(start).is_ok());
+        assert!(bucket.try_take(start).is_ok());
+        let retry_after = bucket.try_take(start).unwrap_err();
+        assert!(retry_after <= Duration::from_secs(30));
+
+        assert!(bucket.try_take(start + Duration::from_secs(30)).is_ok());
+    }
+
+    #[test]
+    fn test_authenticator_enforces_concurrency() {
+        let config = AuthConfig {
+            api_keys: vec![ApiKeyConfig::new("key", vec![ApiScope::User])],
+            default_quota: QuotaConfig {
+                requests_per_minute: None,
+                max_concurrent_requests: Some(1),
+            },
+            ..Default::default()
+        };
+        let authenticator = Authenticator::new(&config).unwrap();
+
+        let (client, quota) = authenticator.authenticate("key").unwrap();
+        let permit = authenticator.admit(&client, quota).unwrap();
+        assert!(authenticator.admit(&client, quota).is_err());
+
+        drop(permit);
+        assert!(authenticator.admit(&client, quota).is_ok());
+    }
+
+    #[test]
+    fn test_authenticator_rejects_unknown_key() {
+        let config = AuthConfig {
+            api_keys: vec![ApiKeyConfig::new("key", vec![ApiScope::User])],
+            ..Default::default()
+        };
+        let authenticator = Authenticator::new(&config).unwrap();
+
+        assert!(authenticator.authenticate("other").is_err());
+        assert!(Authenticator::deny_all().authenticate("key").is_err());
+    }
+
+    #[test]
+    fn test_credential_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(credential_from_headers(&headers), None);
+
+        headers.insert(API_KEY_HEADER, HeaderValue::from_static("abc"));
+        assert_eq!(credential_from_headers(&headers), Some("abc"));
+
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer xyz"));
+        assert_eq!(credential_from_headers(&headers), Some("xyz"));
+
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Basic xyz"));
+        assert_eq!(credential_from_headers(&headers), None);
+    }
+}
@@ -26,6 +26,7 @@ use crate::{Result, core::ServerConfig};
 /// - The config file path is provided but cannot be read
 /// - The config file contains invalid server configuration
 /// - Environment variable overrides contain invalid values
+/// - The configured API key file cannot be read
 ///
 /// # Examples
 ///
@@ -52,8 +53,11 @@ pub fn load_server_config(config_path: Option<&std::path::Path>) -> Result<Serve
     // Apply environment variable overrides with proper logging
     config.apply_env_overrides()?;
 
+    // Fail early on unreadable key files instead of rejecting every request later
+    let api_keys = config.auth.resolve_api_keys()?;
+
     tracing::info!(
-        "Server configuration loaded: host={}, port={}, request_body_limit={} MB, multipart_field_limit={} MB, CORS={}",
+        "Server configuration loaded: host={}, port={}, request_body_limit={} MB, multipart_field_limit={} MB, CORS={}, auth={}",
         config.host,
         config.port,
         config.max_request_body_mb(),
@@ -62,6 +66,15 @@ pub fn load_server_config(config_path: Option<&std::path::Path>) -> Result<Serve
             "allow all origins".to_string()
         } else {
             format!("{} specific origins", config.cors_origins.len())
+        },
+        if config.auth.is_enabled() {
+            format!(
+                "{} API key(s){}",
+                api_keys.len(),
+                if config.auth.jwt.is_some() { " + JWT" } else { "" }
+            )
+        } else {
+            "disabled".to_string()
         }
     );
 
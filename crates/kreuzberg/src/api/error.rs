@@ -143,6 +143,19 @@ impl ApiError {
     pub fn internal(error: KreuzbergError) -> Self {
         Self::new(StatusCode::INTERNAL_SERVER_ERROR, error)
     }
+
+    /// Create an error with an explicit error type name.
+    pub fn with_type(status: StatusCode, error_type: &str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: ErrorResponse {
+                error_type: error_type.to_string(),
+                message: message.into(),
+                traceback: None,
+                status_code: status.as_u16(),
+            },
+        }
+    }
 }
 
 impl IntoResponse for ApiError {
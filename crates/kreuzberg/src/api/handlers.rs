@@ -1,14 +1,21 @@
 //! API request handlers.
 
-use axum::{Json, extract::State};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
 
 use crate::{batch_extract_bytes, cache, extract_bytes};
 
 use super::{
+    auth::AuthenticatedClient,
     error::{ApiError, JsonApi, MultipartApi},
+    stream::stream_extraction,
     types::{
         ApiState, CacheClearResponse, CacheStatsResponse, ChunkRequest, ChunkResponse, EmbedRequest, EmbedResponse,
-        ExtractResponse, HealthResponse, InfoResponse,
+        ExtractResponse, HealthResponse, InfoResponse, JobResponse,
     },
 };
 
@@ -103,10 +110,165 @@ pub async fn info_handler() -> Json<InfoResponse> {
 )]
 pub async fn extract_handler(
     State(state): State<ApiState>,
-    MultipartApi(mut multipart): MultipartApi,
+    MultipartApi(multipart): MultipartApi,
 ) -> Result<Json<ExtractResponse>, ApiError> {
+    let ExtractForm { files, config, .. } = read_extract_form(&state, multipart).await?;
+
+    #[cfg(feature = "otel")]
+    tracing::Span::current().record("files_count", files.len());
+
+    // Use provided config or fall back to default from state
+    let final_config = config.as_ref().unwrap_or(&state.default_config);
+
+    if files.len() == 1 {
+        let (data, mime_type) = files
+            .into_iter()
+            .next()
+            .expect("files.len() == 1 guarantees one element exists");
+        let result = extract_bytes(&data, mime_type.as_str(), final_config).await?;
+        return Ok(Json(vec![result]));
+    }
+
+    let results = batch_extract_bytes(files, final_config).await?;
+    Ok(Json(results))
+}
+
+/// Streaming extraction endpoint handler.
+///
+/// POST /extract/stream
+///
+/// Accepts the same multipart form as `/extract` with exactly one file and
+/// responds with server-sent events instead of a single JSON body:
+///
+/// - `page`: a [`PageContent`](crate::types::PageContent) as soon as the page is
+///   extracted. For PDFs that fall back to OCR, pages are sent again with the OCR
+///   text; the later event for a page number replaces the earlier one.
+/// - `chunk`: one event per chunk when chunking is configured.
+/// - `metadata`: the extraction result without pages and chunks; always last.
+/// - `error`: an error response if the extraction fails; ends the stream.
+///
+/// Closing the connection cancels the remaining extraction work.
+#[utoipa::path(
+    post,
+    path = "/extract/stream",
+    tag = "extraction",
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Stream of page, chunk and metadata events", content_type = "text/event-stream"),
+        (status = 400, description = "Bad request", body = crate::api::types::ErrorResponse),
+        (status = 413, description = "Payload too large", body = crate::api::types::ErrorResponse),
+    )
+)]
+#[cfg_attr(
+    feature = "otel",
+    tracing::instrument(name = "api.extract_stream", skip(state, multipart))
+)]
+pub async fn extract_stream_handler(
+    State(state): State<ApiState>,
+    MultipartApi(multipart): MultipartApi,
+) -> Result<impl IntoResponse, ApiError> {
+    let ExtractForm { files, config, .. } = read_extract_form(&state, multipart).await?;
+
+    if files.len() != 1 {
+        return Err(ApiError::validation(crate::error::KreuzbergError::validation(
+            "Streaming extraction accepts exactly one file",
+        )));
+    }
+    let (data, mime_type) = files
+        .into_iter()
+        .next()
+        .expect("files.len() == 1 guarantees one element exists");
+    let config = config.unwrap_or_else(|| (*state.default_config).clone());
+
+    Ok(stream_extraction(data, mime_type, config))
+}
+
+/// Searchable PDF endpoint handler.
+///
+/// POST /searchable-pdf
+///
+/// Accepts the same multipart form as `/extract` with exactly one scanned PDF or
+/// image and responds with a PDF/A-2b document that keeps the page images and
+/// adds an invisible OCR text layer. OCR settings come from the `config` field.
+#[utoipa::path(
+    post,
+    path = "/searchable-pdf",
+    tag = "extraction",
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Searchable PDF document", content_type = "application/pdf"),
+        (status = 400, description = "Bad request", body = crate::api::types::ErrorResponse),
+        (status = 413, description = "Payload too large", body = crate::api::types::ErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::api::types::ErrorResponse),
+    )
+)]
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+#[cfg_attr(
+    feature = "otel",
+    tracing::instrument(name = "api.searchable_pdf", skip(state, multipart))
+)]
+pub async fn searchable_pdf_handler(
+    State(state): State<ApiState>,
+    MultipartApi(multipart): MultipartApi,
+) -> Result<impl IntoResponse, ApiError> {
+    let ExtractForm { files, config, .. } = read_extract_form(&state, multipart).await?;
+
+    if files.len() != 1 {
+        return Err(ApiError::validation(crate::error::KreuzbergError::validation(
+            "Searchable PDF generation accepts exactly one file",
+        )));
+    }
+    let (data, mime_type) = files
+        .into_iter()
+        .next()
+        .expect("files.len() == 1 guarantees one element exists");
+    let config = config.as_ref().unwrap_or(&state.default_config);
+
+    let pdf = crate::create_searchable_pdf(&data, &mime_type, config).await?;
+    Ok(([(header::CONTENT_TYPE, "application/pdf")], pdf))
+}
+
+/// Searchable PDF endpoint handler (when the pdf or ocr feature is disabled).
+///
+/// Returns an error indicating searchable PDF output is not available.
+#[utoipa::path(
+    post,
+    path = "/searchable-pdf",
+    tag = "extraction",
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Searchable PDF document", content_type = "application/pdf"),
+        (status = 400, description = "Bad request", body = crate::api::types::ErrorResponse),
+        (status = 413, description = "Payload too large", body = crate::api::types::ErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::api::types::ErrorResponse),
+    )
+)]
+#[cfg(not(all(feature = "pdf", feature = "ocr")))]
+pub async fn searchable_pdf_handler(
+    State(_state): State<ApiState>,
+    MultipartApi(_multipart): MultipartApi,
+) -> Result<axum::response::Response, ApiError> {
+    Err(ApiError::internal(crate::error::KreuzbergError::MissingDependency(
+        "Searchable PDF output requires the pdf and ocr features. Rebuild with --features pdf,ocr".to_string(),
+    )))
+}
+
+/// Fields of an extraction upload shared by `/extract` and `/jobs`.
+struct ExtractForm {
+    /// File contents and MIME types
+    files: Vec<(Vec<u8>, String)>,
+    config: Option<crate::core::config::ExtractionConfig>,
+    webhook_url: Option<String>,
+}
+
+/// Read the multipart fields of an extraction upload.
+///
+/// Recognizes `files`, `config`, `output_format` and `webhook_url`; other
+/// fields are ignored. Fails if no file was uploaded.
+async fn read_extract_form(state: &ApiState, mut multipart: axum::extract::Multipart) -> Result<ExtractForm, ApiError> {
     let mut files = Vec::new();
     let mut config: Option<crate::core::config::ExtractionConfig> = None;
+    let mut webhook_url = None;
 
     while let Some(field) = multipart
         .next_field()
@@ -117,7 +279,6 @@ pub async fn extract_handler(
 
         match field_name.as_str() {
             "files" => {
-                let file_name = field.file_name().map(|s| s.to_string());
                 let content_type = field.content_type().map(|s| s.to_string());
                 let data = field
                     .bytes()
@@ -126,7 +287,7 @@ pub async fn extract_handler(
 
                 let mime_type = content_type.unwrap_or_else(|| "application/octet-stream".to_string());
 
-                files.push((data.to_vec(), mime_type, file_name));
+                files.push((data.to_vec(), mime_type));
             }
             "config" => {
                 let config_str = field
@@ -154,14 +315,24 @@ pub async fn extract_handler(
                     "markdown" => crate::core::config::OutputFormat::Markdown,
                     "djot" => crate::core::config::OutputFormat::Djot,
                     "html" => crate::core::config::OutputFormat::Html,
+                    "alto" => crate::core::config::OutputFormat::Alto,
+                    "pagexml" => crate::core::config::OutputFormat::PageXml,
+                    "hocr" => crate::core::config::OutputFormat::Hocr,
                     _ => {
                         return Err(ApiError::validation(crate::error::KreuzbergError::validation(format!(
-                            "Invalid output_format: '{}'. Valid values: 'plain', 'markdown', 'djot', 'html'",
+                            "Invalid output_format: '{}'. Valid values: 'plain', 'markdown', 'djot', 'html', 'alto', 'pagexml', 'hocr'",
                             format_str
                         ))));
                     }
                 };
             }
+            "webhook_url" => {
+                let url = field
+                    .text()
+                    .await
+                    .map_err(|e| ApiError::validation(crate::error::KreuzbergError::validation(e.to_string())))?;
+                webhook_url = Some(url.trim().to_string()).filter(|url| !url.is_empty());
+            }
             _ => {}
         }
     }
@@ -172,31 +343,165 @@ pub async fn extract_handler(
         )));
     }
 
-    #[cfg(feature = "otel")]
-    tracing::Span::current().record("files_count", files.len());
+    // The server decides where results are cached, not the client
+    if let (Some(config), Some(backend)) = (config.as_mut(), state.cache_backend) {
+        config.cache_backend = backend;
+    }
 
-    // Use provided config or fall back to default from state
-    let final_config = config.as_ref().unwrap_or(&state.default_config);
+    Ok(ExtractForm {
+        files,
+        config,
+        webhook_url,
+    })
+}
 
-    if files.len() == 1 {
-        let (data, mime_type, _file_name) = files
-            .into_iter()
-            .next()
-            .expect("files.len() == 1 guarantees one element exists");
-        let result = extract_bytes(&data, mime_type.as_str(), final_config).await?;
-        return Ok(Json(vec![result]));
+/// Job submission endpoint handler.
+///
+/// POST /jobs
+///
+/// Accepts the same multipart form as `/extract`, plus an optional
+/// `webhook_url` that receives the final job status as a JSON `POST` once the
+/// job completes or fails. Returns `202 Accepted` with the queued job; poll
+/// `GET /jobs/{id}` for progress and fetch the result from `GET /jobs/{id}/result`.
+///
+/// # Errors
+///
+/// Returns `400` for invalid uploads or webhook URLs and `503` when the job queue is full.
+#[utoipa::path(
+    post,
+    path = "/jobs",
+    tag = "jobs",
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 202, description = "Job queued", body = JobResponse),
+        (status = 400, description = "Bad request", body = crate::api::types::ErrorResponse),
+        (status = 413, description = "Payload too large", body = crate::api::types::ErrorResponse),
+        (status = 503, description = "Job queue is full", body = crate::api::types::ErrorResponse),
+    )
+)]
+#[cfg_attr(
+    feature = "otel",
+    tracing::instrument(name = "api.jobs.submit", skip(state, client, multipart))
+)]
+pub async fn submit_job_handler(
+    State(state): State<ApiState>,
+    client: Option<Extension<AuthenticatedClient>>,
+    MultipartApi(multipart): MultipartApi,
+) -> Result<impl IntoResponse, ApiError> {
+    let ExtractForm {
+        files,
+        config,
+        webhook_url,
+    } = read_extract_form(&state, multipart).await?;
+
+    if let Some(url) = &webhook_url {
+        let parsed = reqwest::Url::parse(url).map_err(|e| {
+            ApiError::validation(crate::error::KreuzbergError::validation(format!(
+                "Invalid webhook_url '{}': {}",
+                url, e
+            )))
+        })?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(ApiError::validation(crate::error::KreuzbergError::validation(
+                "webhook_url must use http or https",
+            )));
+        }
     }
 
-    let files_data: Vec<(Vec<u8>, String)> = files.into_iter().map(|(data, mime, _name)| (data, mime)).collect();
+    let config = config.unwrap_or_else(|| (*state.default_config).clone());
+    let owner = client.map(|Extension(client)| client.id);
+    let job = state.jobs.submit(files, config, owner, webhook_url)?;
 
-    let results = batch_extract_bytes(files_data, final_config).await?;
-    Ok(Json(results))
+    Ok((
+        StatusCode::ACCEPTED,
+        [(header::LOCATION, format!("/jobs/{}", job.id))],
+        Json(job),
+    ))
+}
+
+/// Job status endpoint handler.
+///
+/// GET /jobs/{id}
+#[utoipa::path(
+    get,
+    path = "/jobs/{id}",
+    tag = "jobs",
+    params(("id" = String, Path, description = "Job identifier")),
+    responses(
+        (status = 200, description = "Job status", body = JobResponse),
+        (status = 404, description = "Job not found", body = crate::api::types::ErrorResponse),
+    )
+)]
+#[cfg_attr(feature = "otel", tracing::instrument(name = "api.jobs.status", skip(state, client)))]
+pub async fn job_status_handler(
+    State(state): State<ApiState>,
+    client: Option<Extension<AuthenticatedClient>>,
+    Path(id): Path<String>,
+) -> Result<Json<JobResponse>, ApiError> {
+    let requester = client.as_ref().map(|Extension(client)| client.id.as_str());
+    Ok(Json(state.jobs.status(&id, requester)?))
+}
+
+/// Job result endpoint handler.
+///
+/// GET /jobs/{id}/result
+///
+/// Returns the extraction results in the same format as `/extract`.
+#[utoipa::path(
+    get,
+    path = "/jobs/{id}/result",
+    tag = "jobs",
+    params(("id" = String, Path, description = "Job identifier")),
+    responses(
+        (status = 200, description = "Extraction results", body = ExtractResponse),
+        (status = 404, description = "Job not found", body = crate::api::types::ErrorResponse),
+        (status = 409, description = "Job has not completed successfully", body = crate::api::types::ErrorResponse),
+    )
+)]
+#[cfg_attr(feature = "otel", tracing::instrument(name = "api.jobs.result", skip(state, client)))]
+pub async fn job_result_handler(
+    State(state): State<ApiState>,
+    client: Option<Extension<AuthenticatedClient>>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let requester = client.as_ref().map(|Extension(client)| client.id.as_str());
+    let body = state.jobs.result(&id, requester).await?;
+    Ok(([(header::CONTENT_TYPE, "application/json")], body))
+}
+
+/// Job cancellation endpoint handler.
+///
+/// DELETE /jobs/{id}
+///
+/// Cancels a queued or running job. Finished jobs are deleted together with
+/// their stored result.
+#[utoipa::path(
+    delete,
+    path = "/jobs/{id}",
+    tag = "jobs",
+    params(("id" = String, Path, description = "Job identifier")),
+    responses(
+        (status = 200, description = "Job cancelled or deleted", body = JobResponse),
+        (status = 404, description = "Job not found", body = crate::api::types::ErrorResponse),
+    )
+)]
+#[cfg_attr(feature = "otel", tracing::instrument(name = "api.jobs.cancel", skip(state, client)))]
+pub async fn cancel_job_handler(
+    State(state): State<ApiState>,
+    client: Option<Extension<AuthenticatedClient>>,
+    Path(id): Path<String>,
+) -> Result<Json<JobResponse>, ApiError> {
+    let requester = client.as_ref().map(|Extension(client)| client.id.as_str());
+    Ok(Json(state.jobs.cancel(&id, requester)?))
 }
 
 /// Cache stats endpoint handler.
 ///
 /// GET /cache/stats
 ///
+/// Reports the files in the cache directory and the backend, hits and misses
+/// of the extraction result cache.
+///
 /// # Errors
 ///
 /// Returns `ApiError::Internal` if:
@@ -212,8 +517,8 @@ pub async fn extract_handler(
         (status = 500, description = "Internal server error", body = crate::api::types::ErrorResponse),
     )
 )]
-#[cfg_attr(feature = "otel", tracing::instrument(name = "api.cache_stats"))]
-pub async fn cache_stats_handler() -> Result<Json<CacheStatsResponse>, ApiError> {
+#[cfg_attr(feature = "otel", tracing::instrument(name = "api.cache_stats", skip(state)))]
+pub async fn cache_stats_handler(State(state): State<ApiState>) -> Result<Json<CacheStatsResponse>, ApiError> {
     let cache_dir = std::env::current_dir()
         .map_err(|e| {
             ApiError::internal(crate::error::KreuzbergError::Other(format!(
@@ -231,6 +536,11 @@ pub async fn cache_stats_handler() -> Result<Json<CacheStatsResponse>, ApiError>
     })?;
 
     let stats = cache::get_cache_metadata(cache_dir_str).map_err(ApiError::internal)?;
+    let backend = state.default_config.cache_backend;
+    let results = match cache::result_cache(backend) {
+        Some(result_cache) => Some(result_cache.stats().map_err(ApiError::internal)?),
+        None => None,
+    };
 
     Ok(Json(CacheStatsResponse {
         directory: cache_dir.to_string_lossy().to_string(),
@@ -239,13 +549,37 @@ pub async fn cache_stats_handler() -> Result<Json<CacheStatsResponse>, ApiError>
         available_space_mb: stats.available_space_mb,
         oldest_file_age_days: stats.oldest_file_age_days,
         newest_file_age_days: stats.newest_file_age_days,
+        backend,
+        hits: results.as_ref().map_or(0, |results| results.hits),
+        misses: results.as_ref().map_or(0, |results| results.misses),
     }))
 }
 
+/// Prometheus metrics endpoint handler.
+///
+/// GET /metrics
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "health",
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text exposition format", body = String, content_type = "text/plain"),
+    )
+)]
+pub async fn metrics_handler() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+        crate::metrics::render(),
+    )
+}
+
 /// Cache clear endpoint handler.
 ///
 /// DELETE /cache/clear
 ///
+/// Removes the files in the cache directory and all extraction results cached
+/// by the configured result cache backend.
+///
 /// # Errors
 ///
 /// Returns `ApiError::Internal` if:
@@ -261,8 +595,8 @@ pub async fn cache_stats_handler() -> Result<Json<CacheStatsResponse>, ApiError>
         (status = 500, description = "Internal server error", body = crate::api::types::ErrorResponse),
     )
 )]
-#[cfg_attr(feature = "otel", tracing::instrument(name = "api.cache_clear"))]
-pub async fn cache_clear_handler() -> Result<Json<CacheClearResponse>, ApiError> {
+#[cfg_attr(feature = "otel", tracing::instrument(name = "api.cache_clear", skip(state)))]
+pub async fn cache_clear_handler(State(state): State<ApiState>) -> Result<Json<CacheClearResponse>, ApiError> {
     let cache_dir = std::env::current_dir()
         .map_err(|e| {
             ApiError::internal(crate::error::KreuzbergError::Other(format!(
@@ -279,7 +613,12 @@ pub async fn cache_clear_handler() -> Result<Json<CacheClearResponse>, ApiError>
         )))
     })?;
 
-    let (removed_files, freed_mb) = cache::clear_cache_directory(cache_dir_str).map_err(ApiError::internal)?;
+    let (mut removed_files, mut freed_mb) = cache::clear_cache_directory(cache_dir_str).map_err(ApiError::internal)?;
+    if let Some(result_cache) = cache::result_cache(state.default_config.cache_backend) {
+        let (removed_results, freed_results_mb) = result_cache.clear().map_err(ApiError::internal)?;
+        removed_files += removed_results;
+        freed_mb += freed_results_mb;
+    }
 
     Ok(Json(CacheClearResponse {
         directory: cache_dir.to_string_lossy().to_string(),
@@ -364,6 +703,11 @@ pub async fn embed_handler(JsonApi(request): JsonApi<EmbedRequest>) -> Result<Js
                 total_chunks: request.texts.len(),
                 first_page: None,
                 last_page: None,
+                heading_path: Vec::new(),
+                element_types: Vec::new(),
+                node_ids: Vec::new(),
+                language: None,
+                language_confidence: None,
             },
         })
         .collect();
@@ -390,6 +734,8 @@ pub async fn embed_handler(JsonApi(request): JsonApi<EmbedRequest>) -> Result<Js
         crate::core::config::EmbeddingModelType::Preset { name } => name.clone(),
         #[cfg(feature = "embeddings")]
         crate::core::config::EmbeddingModelType::FastEmbed { model, .. } => model.clone(),
+        crate::core::config::EmbeddingModelType::OpenAi { model, .. }
+        | crate::core::config::EmbeddingModelType::Ollama { model, .. } => model.clone(),
         crate::core::config::EmbeddingModelType::Custom { .. } => "custom".to_string(),
     };
 
@@ -467,9 +813,10 @@ pub async fn chunk_handler(JsonApi(request): JsonApi<ChunkRequest>) -> Result<Js
     let chunker_type = match request.chunker_type.to_lowercase().as_str() {
         "text" => ChunkerType::Text,
         "markdown" => ChunkerType::Markdown,
+        "semantic" => ChunkerType::Semantic,
         other => {
             return Err(ApiError::validation(crate::error::KreuzbergError::validation(format!(
-                "Invalid chunker_type: '{}'. Valid values: 'text', 'markdown'",
+                "Invalid chunker_type: '{}'. Valid values: 'text', 'markdown', 'semantic'",
                 other
             ))));
         }
@@ -493,12 +840,18 @@ pub async fn chunk_handler(JsonApi(request): JsonApi<ChunkRequest>) -> Result<Js
         overlap,
         trim: cfg.trim.unwrap_or(true),
         chunker_type,
-        embedding: None,
+        embedding: cfg.embedding,
         preset: None,
+        sizing: cfg.sizing.unwrap_or_default(),
+        prepend_heading_context: false,
+        semantic: cfg.semantic.unwrap_or_default(),
     };
 
     // Perform chunking - convert any remaining errors to validation errors since they're likely config issues
     let result = chunk_text(&request.text, &config, None).map_err(|e| {
+        if matches!(e, crate::error::KreuzbergError::Validation { .. }) {
+            return ApiError::validation(e);
+        }
         // Check if error message indicates a configuration issue
         let msg = e.to_string();
         if msg.contains("configuration") || msg.contains("overlap") || msg.contains("capacity") {
@@ -519,6 +872,7 @@ pub async fn chunk_handler(JsonApi(request): JsonApi<ChunkRequest>) -> Result<Js
             content: chunk.content,
             byte_start: chunk.metadata.byte_start,
             byte_end: chunk.metadata.byte_end,
+            token_count: chunk.metadata.token_count,
             chunk_index: chunk.metadata.chunk_index,
             total_chunks: chunk.metadata.total_chunks,
             first_page: chunk.metadata.first_page,
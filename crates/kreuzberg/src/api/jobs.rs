@@ -0,0 +1,597 @@
+//! Asynchronous extraction jobs.
+//!
+//! Jobs are accepted by `POST /jobs`, queued on a bounded channel and processed
+//! by a fixed number of worker tasks, which are started with the first
+//! submission. Each job record is written to the jobs directory whenever its
+//! state changes, and the extraction results are stored next to it, so
+//! finished jobs remain available after a restart.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use axum::http::StatusCode;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Notify, mpsc};
+
+use crate::core::server_config::JobsConfig;
+use crate::{ExtractionConfig, extract_bytes};
+
+use super::error::ApiError;
+use super::types::{JobResponse, JobStatus};
+
+/// A submitted job waiting for a worker.
+pub(crate) struct QueuedJob {
+    pub id: String,
+    /// File contents and MIME types
+    pub files: Vec<(Vec<u8>, String)>,
+    pub config: ExtractionConfig,
+}
+
+/// Job record as stored on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredJob {
+    #[serde(flatten)]
+    job: JobResponse,
+    /// Client that submitted the job, when authentication is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    webhook_url: Option<String>,
+}
+
+struct JobEntry {
+    stored: StoredJob,
+    cancel: Arc<Notify>,
+}
+
+/// Job queue, worker pool and job store.
+pub(crate) struct JobManager {
+    jobs: Mutex<HashMap<String, JobEntry>>,
+    sender: mpsc::Sender<QueuedJob>,
+    /// Taken when the workers are started
+    receiver: Mutex<Option<mpsc::Receiver<QueuedJob>>>,
+    workers: usize,
+    directory: PathBuf,
+    retention: Duration,
+    http: Option<reqwest::Client>,
+}
+
+impl fmt::Debug for JobManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JobManager")
+            .field("workers", &self.workers)
+            .field("directory", &self.directory)
+            .finish_non_exhaustive()
+    }
+}
+
+impl JobManager {
+    /// Create the manager and load the jobs stored in the jobs directory.
+    ///
+    /// Stored jobs that were still queued or running are marked as failed,
+    /// and finished jobs past the retention period are removed.
+    pub(crate) fn new(config: &JobsConfig) -> Self {
+        let (sender, receiver) = mpsc::channel(config.max_queued_jobs.max(1));
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.webhook_timeout_secs))
+            .build()
+            .map_err(|e| tracing::warn!("Failed to create webhook client, webhooks are disabled: {}", e))
+            .ok();
+
+        let manager = Self {
+            jobs: Mutex::new(HashMap::new()),
+            sender,
+            receiver: Mutex::new(Some(receiver)),
+            workers: config.workers.max(1),
+            directory: config.resolved_directory(),
+            retention: Duration::from_secs(config.retention_hours.saturating_mul(3600)),
+            http,
+        };
+        manager.load_stored_jobs();
+        manager
+    }
+
+    /// Queue a job for extraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `503 Service Unavailable` if the queue is full.
+    pub(crate) fn submit(
+        self: &Arc<Self>,
+        files: Vec<(Vec<u8>, String)>,
+        config: ExtractionConfig,
+        owner: Option<String>,
+        webhook_url: Option<String>,
+    ) -> Result<JobResponse, ApiError> {
+        self.start_workers();
+        self.purge_expired();
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let stored = StoredJob {
+            job: JobResponse {
+                id: id.clone(),
+                status: JobStatus::Queued,
+                total_files: files.len(),
+                processed_files: 0,
+                progress: 0.0,
+                created_at: now_secs(),
+                started_at: None,
+                finished_at: None,
+                error: None,
+            },
+            owner,
+            webhook_url,
+        };
+        let response = stored.job.clone();
+
+        let mut jobs = self.jobs.lock();
+        self.sender
+            .try_send(QueuedJob {
+                id: id.clone(),
+                files,
+                config,
+            })
+            .map_err(|_| {
+                ApiError::with_type(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "QueueFullError",
+                    "Job queue is full, retry later",
+                )
+            })?;
+        self.persist(&stored);
+        jobs.insert(
+            id,
+            JobEntry {
+                stored,
+                cancel: Arc::new(Notify::new()),
+            },
+        );
+
+        Ok(response)
+    }
+
+    /// Current status of a job.
+    pub(crate) fn status(&self, id: &str, requester: Option<&str>) -> Result<JobResponse, ApiError> {
+        let jobs = self.jobs.lock();
+        let entry = visible_entry(&jobs, id, requester)?;
+        Ok(entry.stored.job.clone())
+    }
+
+    /// Serialized results of a completed job.
+    ///
+    /// # Errors
+    ///
+    /// Returns `404` for unknown jobs and `409 Conflict` for jobs that have not completed.
+    pub(crate) async fn result(&self, id: &str, requester: Option<&str>) -> Result<Vec<u8>, ApiError> {
+        let job = self.status(id, requester)?;
+        match job.status {
+            JobStatus::Completed => {}
+            JobStatus::Failed => {
+                return Err(ApiError::with_type(
+                    StatusCode::CONFLICT,
+                    "JobFailedError",
+                    format!("Job failed: {}", job.error.unwrap_or_default()),
+                ));
+            }
+            status => {
+                return Err(ApiError::with_type(
+                    StatusCode::CONFLICT,
+                    "JobNotFinishedError",
+                    format!("Job has no result (status: {})", status_name(status)),
+                ));
+            }
+        }
+
+        tokio::fs::read(self.result_path(id)).await.map_err(|e| {
+            ApiError::with_type(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "IOError",
+                format!("Failed to read job result: {}", e),
+            )
+        })
+    }
+
+    /// Cancel a queued or running job, or delete a finished one.
+    pub(crate) fn cancel(&self, id: &str, requester: Option<&str>) -> Result<JobResponse, ApiError> {
+        let mut jobs = self.jobs.lock();
+        let finished = visible_entry(&jobs, id, requester)?.stored.job.status.is_finished();
+
+        if finished {
+            let entry = jobs.remove(id).expect("entry exists");
+            self.remove_files(id);
+            return Ok(entry.stored.job);
+        }
+
+        let entry = jobs.get_mut(id).expect("entry exists");
+        entry.stored.job.status = JobStatus::Cancelled;
+        entry.stored.job.finished_at = Some(now_secs());
+        entry.cancel.notify_one();
+        self.persist(&entry.stored);
+        Ok(entry.stored.job.clone())
+    }
+
+    fn start_workers(self: &Arc<Self>) {
+        let Some(receiver) = self.receiver.lock().take() else {
+            return;
+        };
+
+        let receiver = Arc::new(tokio::sync::Mutex::new(receiver));
+        for _ in 0..self.workers {
+            tokio::spawn(worker(Arc::downgrade(self), Arc::clone(&receiver)));
+        }
+    }
+
+    async fn run(&self, job: QueuedJob) {
+        let cancel = {
+            let mut jobs = self.jobs.lock();
+            let Some(entry) = jobs.get_mut(&job.id) else {
+                return;
+            };
+            if entry.stored.job.status != JobStatus::Queued {
+                return;
+            }
+            entry.stored.job.status = JobStatus::Running;
+            entry.stored.job.started_at = Some(now_secs());
+            self.persist(&entry.stored);
+            Arc::clone(&entry.cancel)
+        };
+
+        let mut results = Vec::with_capacity(job.files.len());
+        for (data, mime_type) in &job.files {
+            let outcome = tokio::select! {
+                biased;
+                _ = cancel.notified() => return,
+                outcome = extract_bytes(data, mime_type, &job.config) => outcome,
+            };
+
+            match outcome {
+                Ok(result) => {
+                    results.push(result);
+                    if let Some(entry) = self.jobs.lock().get_mut(&job.id) {
+                        let stored = &mut entry.stored.job;
+                        stored.processed_files = results.len();
+                        stored.progress = results.len() as f64 / stored.total_files as f64;
+                    }
+                }
+                Err(e) => {
+                    self.finish(&job.id, Err(e.to_string())).await;
+                    return;
+                }
+            }
+        }
+
+        let stored = match serde_json::to_vec(&results) {
+            Ok(json) => tokio::fs::write(self.result_path(&job.id), json)
+                .await
+                .map_err(|e| format!("Failed to store job result: {}", e)),
+            Err(e) => Err(format!("Failed to serialize job result: {}", e)),
+        };
+        self.finish(&job.id, stored).await;
+    }
+
+    /// Record the outcome of a job and notify its webhook.
+    async fn finish(&self, id: &str, outcome: Result<(), String>) {
+        let finished = {
+            let mut jobs = self.jobs.lock();
+            let Some(entry) = jobs.get_mut(id) else {
+                return;
+            };
+            if entry.stored.job.status != JobStatus::Running {
+                return;
+            }
+
+            let job = &mut entry.stored.job;
+            job.finished_at = Some(now_secs());
+            match outcome {
+                Ok(()) => job.status = JobStatus::Completed,
+                Err(error) => {
+                    tracing::warn!(job = %id, "Extraction job failed: {}", error);
+                    job.status = JobStatus::Failed;
+                    job.error = Some(error);
+                }
+            }
+            self.persist(&entry.stored);
+            entry.stored.clone()
+        };
+
+        if let (Some(url), Some(http)) = (&finished.webhook_url, &self.http) {
+            match http.post(url).json(&finished.job).send().await {
+                Ok(response) if response.status().is_success() => {}
+                Ok(response) => {
+                    tracing::warn!(job = %id, "Job webhook {} returned {}", url, response.status())
+                }
+                Err(e) => tracing::warn!(job = %id, "Job webhook {} failed: {}", url, e),
+            }
+        }
+    }
+
+    fn load_stored_jobs(&self) {
+        let Ok(entries) = std::fs::read_dir(&self.directory) else {
+            return;
+        };
+
+        let mut jobs = self.jobs.lock();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !file_name.ends_with(".json") || file_name.ends_with(".result.json") {
+                continue;
+            }
+
+            let mut stored: StoredJob = match std::fs::read(&path)
+                .ok()
+                .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            {
+                Some(stored) => stored,
+                None => {
+                    tracing::warn!("Skipping unreadable job record {}", path.display());
+                    continue;
+                }
+            };
+
+            if !stored.job.status.is_finished() {
+                stored.job.status = JobStatus::Failed;
+                stored.job.finished_at = Some(now_secs());
+                stored.job.error = Some("Interrupted by server restart".to_string());
+                self.persist(&stored);
+            }
+
+            jobs.insert(
+                stored.job.id.clone(),
+                JobEntry {
+                    stored,
+                    cancel: Arc::new(Notify::new()),
+                },
+            );
+        }
+
+        if !jobs.is_empty() {
+            tracing::info!("Loaded {} stored job(s) from {}", jobs.len(), self.directory.display());
+        }
+        drop(jobs);
+        self.purge_expired();
+    }
+
+    /// Remove finished jobs older than the retention period.
+    fn purge_expired(&self) {
+        let cutoff = now_secs().saturating_sub(self.retention.as_secs());
+        let mut jobs = self.jobs.lock();
+        let expired: Vec<String> = jobs
+            .iter()
+            .filter(|(_, entry)| entry.stored.job.finished_at.is_some_and(|finished| finished < cutoff))
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        for id in expired {
+            jobs.remove(&id);
+            self.remove_files(&id);
+        }
+    }
+
+    fn record_path(&self, id: &str) -> PathBuf {
+        self.directory.join(format!("{}.json", id))
+    }
+
+    fn result_path(&self, id: &str) -> PathBuf {
+        self.directory.join(format!("{}.result.json", id))
+    }
+
+    /// Write a job record, replacing the previous one atomically.
+    fn persist(&self, stored: &StoredJob) {
+        let path = self.record_path(&stored.job.id);
+        let write = || -> std::io::Result<()> {
+            std::fs::create_dir_all(&self.directory)?;
+            let tmp = path.with_extension("json.tmp");
+            std::fs::write(&tmp, serde_json::to_vec(stored)?)?;
+            std::fs::rename(&tmp, &path)
+        };
+        if let Err(e) = write() {
+            tracing::warn!(job = %stored.job.id, "Failed to persist job record {}: {}", path.display(), e);
+        }
+    }
+
+    fn remove_files(&self, id: &str) {
+        for path in [self.record_path(id), self.result_path(id)] {
+            remove_if_exists(&path);
+        }
+    }
+}
+
+async fn worker(manager: Weak<JobManager>, receiver: Arc<tokio::sync::Mutex<mpsc::Receiver<QueuedJob>>>) {
+    loop {
+        let job = receiver.lock().await.recv().await;
+        let (Some(job), Some(manager)) = (job, manager.upgrade()) else {
+            return;
+        };
+        manager.run(job).await;
+    }
+}
+
+/// Look up a job, hiding jobs of other clients.
+fn visible_entry<'a>(
+    jobs: &'a HashMap<String, JobEntry>,
+    id: &str,
+    requester: Option<&str>,
+) -> Result<&'a JobEntry, ApiError> {
+    jobs.get(id)
+        .filter(|entry| entry.stored.owner.is_none() || entry.stored.owner.as_deref() == requester)
+        .ok_or_else(|| {
+            ApiError::with_type(
+                StatusCode::NOT_FOUND,
+                "NotFoundError",
+                format!("Job '{}' not found", id),
+            )
+        })
+}
+
+fn status_name(status: JobStatus) -> &'static str {
+    match status {
+        JobStatus::Queued => "queued",
+        JobStatus::Running => "running",
+        JobStatus::Completed => "completed",
+        JobStatus::Failed => "failed",
+        JobStatus::Cancelled => "cancelled",
+    }
+}
+
+fn remove_if_exists(path: &Path) {
+    if let Err(e) = std::fs::remove_file(path)
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
+        tracing::debug!("Failed to remove {}: {}", path.display(), e);
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager(directory: &Path) -> Arc<JobManager> {
+        Arc::new(JobManager::new(&JobsConfig {
+            directory: Some(directory.to_path_buf()),
+            ..Default::default()
+        }))
+    }
+
+    fn text_file() -> Vec<(Vec<u8>, String)> {
+        vec![(b"hello jobs".to_vec(), "text/plain".to_string())]
+    }
+
+    async fn wait_until_finished(manager: &JobManager, id: &str) -> JobResponse {
+        for _ in 0..200 {
+            let job = manager.status(id, None).unwrap();
+            if job.status.is_finished() {
+                return job;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("job {} did not finish", id);
+    }
+
+    #[tokio::test]
+    async fn test_job_completes_and_stores_result() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager(dir.path());
+
+        let job = manager
+            .submit(text_file(), ExtractionConfig::default(), None, None)
+            .unwrap();
+        let finished = wait_until_finished(&manager, &job.id).await;
+
+        assert_eq!(finished.status, JobStatus::Completed);
+        assert_eq!(finished.processed_files, 1);
+        assert_eq!(finished.progress, 1.0);
+        let result = manager.result(&job.id, None).await.unwrap();
+        assert!(String::from_utf8(result).unwrap().contains("hello jobs"));
+    }
+
+    #[tokio::test]
+    async fn test_jobs_are_private_to_owner() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager(dir.path());
+
+        let job = manager
+            .submit(
+                text_file(),
+                ExtractionConfig::default(),
+                Some("alice".to_string()),
+                None,
+            )
+            .unwrap();
+
+        assert!(manager.status(&job.id, Some("alice")).is_ok());
+        assert_eq!(
+            manager.status(&job.id, Some("bob")).unwrap_err().status,
+            StatusCode::NOT_FOUND
+        );
+        assert!(manager.cancel(&job.id, None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_restart_keeps_finished_and_fails_interrupted_jobs() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = manager(dir.path());
+        let job = first
+            .submit(text_file(), ExtractionConfig::default(), None, None)
+            .unwrap();
+        wait_until_finished(&first, &job.id).await;
+
+        let interrupted = StoredJob {
+            job: JobResponse {
+                status: JobStatus::Running,
+                id: "interrupted".to_string(),
+                ..first.status(&job.id, None).unwrap()
+            },
+            owner: None,
+            webhook_url: None,
+        };
+        first.persist(&interrupted);
+        drop(first);
+
+        let second = manager(dir.path());
+        assert_eq!(second.status(&job.id, None).unwrap().status, JobStatus::Completed);
+        assert!(second.result(&job.id, None).await.is_ok());
+
+        let restored = second.status("interrupted", None).unwrap();
+        assert_eq!(restored.status, JobStatus::Failed);
+        assert_eq!(restored.error.as_deref(), Some("Interrupted by server restart"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_finished_job_removes_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager(dir.path());
+        let job = manager
+            .submit(text_file(), ExtractionConfig::default(), None, None)
+            .unwrap();
+        wait_until_finished(&manager, &job.id).await;
+
+        manager.cancel(&job.id, None).unwrap();
+
+        assert!(manager.status(&job.id, None).is_err());
+        assert!(!manager.record_path(&job.id).exists());
+        assert!(!manager.result_path(&job.id).exists());
+    }
+
+    #[test]
+    fn test_expired_jobs_are_purged_on_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let record = StoredJob {
+            job: JobResponse {
+                id: "old".to_string(),
+                status: JobStatus::Completed,
+                total_files: 1,
+                processed_files: 1,
+                progress: 1.0,
+                created_at: 1,
+                started_at: Some(1),
+                finished_at: Some(2),
+                error: None,
+            },
+            owner: None,
+            webhook_url: None,
+        };
+        std::fs::write(dir.path().join("old.json"), serde_json::to_vec(&record).unwrap()).unwrap();
+
+        let manager = JobManager::new(&JobsConfig {
+            directory: Some(dir.path().to_path_buf()),
+            ..Default::default()
+        });
+
+        assert!(manager.status("old", None).is_err());
+        assert!(!dir.path().join("old.json").exists());
+    }
+}
@@ -0,0 +1,35 @@
+//! HTTP request metrics for the Prometheus `/metrics` endpoint.
+
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+
+/// Route label for requests that matched no route, to keep label cardinality bounded.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Middleware counting requests and recording their latency per route template.
+///
+/// Routes are labelled with their template (e.g. `/jobs/{id}`), never the raw path.
+pub(crate) async fn track_http_metrics(request: Request, next: Next) -> Response {
+    let started = Instant::now();
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+
+    let response = next.run(request).await;
+
+    crate::metrics::metrics().record_http_request(
+        method.as_str(),
+        &route,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    response
+}
@@ -6,12 +6,28 @@
 //! # Endpoints
 //!
 //! - `POST /extract` - Extract text from uploaded files (multipart form data)
+//! - `POST /extract/stream` - Extract one file, streaming pages, chunks and metadata as server-sent events
 //! - `POST /embed` - Generate embeddings for text (JSON body with texts array)
 //! - `POST /chunk` - Chunk text into smaller pieces (JSON body with text and config)
 //! - `GET /health` - Health check endpoint
 //! - `GET /info` - Server information
 //! - `GET /cache/stats` - Get cache statistics
+//! - `GET /metrics` - Prometheus metrics (requests, extraction latency, OCR pages, caches)
 //! - `DELETE /cache/clear` - Clear all cached files
+//! - `POST /jobs` - Queue an extraction job (same form as `/extract`, plus `webhook_url`)
+//! - `GET /jobs/{id}` - Job status and progress
+//! - `GET /jobs/{id}/result` - Results of a completed job
+//! - `DELETE /jobs/{id}` - Cancel a job or delete a finished one
+//!
+//! # Authentication
+//!
+//! Authentication is configured through [`ServerConfig::auth`](crate::core::ServerConfig).
+//! Once API keys or a JWT secret are set, clients must send
+//! `Authorization: Bearer <key or token>` (or `X-API-Key: <key>`) on every
+//! endpoint except `/health` and `/openapi.json`. `/cache/clear` additionally
+//! requires the `admin` scope. Per-client request rates and concurrency are
+//! limited by [`QuotaConfig`](crate::core::QuotaConfig); exhausted quotas yield
+//! `429 Too Many Requests` with a `Retry-After` header.
 //!
 //! # Examples
 //!
@@ -70,6 +86,9 @@
 //! # Cache statistics
 //! curl http://localhost:8000/cache/stats
 //!
+//! # Prometheus metrics
+//! curl http://localhost:8000/metrics
+//!
 //! # Clear cache
 //! curl -X DELETE http://localhost:8000/cache/clear
 //!
@@ -84,20 +103,25 @@
 //!      -d '{"text":"Long text to chunk...","chunker_type":"text"}'
 //! ```
 
+mod auth;
 mod config;
 mod error;
 mod handlers;
+mod jobs;
+mod metrics;
 #[cfg(feature = "api")]
 pub mod openapi;
 mod router;
 mod startup;
+mod stream;
 mod types;
 
+pub use auth::AuthenticatedClient;
 pub use config::load_server_config;
 pub use error::ApiError;
 pub use router::{create_router, create_router_with_limits, create_router_with_limits_and_server_config};
 pub use startup::{serve, serve_default, serve_with_config, serve_with_config_and_limits, serve_with_server_config};
 pub use types::{
     ApiSizeLimits, ApiState, CacheClearResponse, CacheStatsResponse, ChunkRequest, ChunkResponse, EmbedRequest,
-    EmbedResponse, ErrorResponse, ExtractResponse, HealthResponse, InfoResponse,
+    EmbedResponse, ErrorResponse, ExtractResponse, HealthResponse, InfoResponse, JobResponse, JobStatus,
 };
@@ -33,10 +33,17 @@ use utoipa::OpenApi;
         crate::api::handlers::health_handler,
         crate::api::handlers::info_handler,
         crate::api::handlers::extract_handler,
+        crate::api::handlers::extract_stream_handler,
+        crate::api::handlers::searchable_pdf_handler,
         crate::api::handlers::cache_stats_handler,
+        crate::api::handlers::metrics_handler,
         crate::api::handlers::cache_clear_handler,
         crate::api::handlers::embed_handler,
         crate::api::handlers::chunk_handler,
+        crate::api::handlers::submit_job_handler,
+        crate::api::handlers::job_status_handler,
+        crate::api::handlers::job_result_handler,
+        crate::api::handlers::cancel_job_handler,
     ),
     components(
         schemas(
@@ -45,6 +52,7 @@ use utoipa::OpenApi;
             crate::api::types::InfoResponse,
             crate::api::types::ErrorResponse,
             crate::api::types::CacheStatsResponse,
+            crate::core::config::CacheBackend,
             crate::api::types::CacheClearResponse,
             crate::api::types::EmbedRequest,
             crate::api::types::EmbedResponse,
@@ -52,7 +60,13 @@ use utoipa::OpenApi;
             crate::api::types::ChunkResponse,
             crate::api::types::ChunkItem,
             crate::api::types::ChunkingConfigRequest,
+            crate::core::config::ChunkSizing,
+            crate::core::config::TokenizerFormat,
+            crate::core::config::SemanticChunkingConfig,
+            crate::core::config::SemanticThreshold,
             crate::api::types::ChunkingConfigResponse,
+            crate::api::types::JobResponse,
+            crate::api::types::JobStatus,
             crate::types::extraction::ExtractionResult,
             crate::types::extraction::Chunk,
             crate::types::extraction::ChunkMetadata,
@@ -73,7 +87,8 @@ use utoipa::OpenApi;
         (name = "extraction", description = "Document extraction endpoints"),
         (name = "cache", description = "Cache management endpoints"),
         (name = "embeddings", description = "Text embedding generation"),
-        (name = "chunking", description = "Text chunking operations")
+        (name = "chunking", description = "Text chunking operations"),
+        (name = "jobs", description = "Asynchronous extraction jobs")
     )
 )]
 pub struct ApiDoc;
@@ -133,6 +148,7 @@ mod tests {
         assert!(schema.contains("/info"));
         // Extraction
         assert!(schema.contains("/extract"));
+        assert!(schema.contains("/searchable-pdf"));
         // Cache
         assert!(schema.contains("/cache/stats"));
         assert!(schema.contains("/cache/clear"));
@@ -140,6 +156,8 @@ mod tests {
         assert!(schema.contains("/embed"));
         // Chunking
         assert!(schema.contains("/chunk"));
+        // Jobs
+        assert!(schema.contains("/jobs/{id}/result"));
     }
 
     #[test]
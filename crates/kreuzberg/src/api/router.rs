@@ -5,6 +5,7 @@ use std::sync::Arc;
 use axum::{
     Router,
     extract::DefaultBodyLimit,
+    middleware,
     routing::{delete, get, post},
 };
 use tower_http::{
@@ -13,13 +14,20 @@ use tower_http::{
     trace::TraceLayer,
 };
 
-use crate::{ExtractionConfig, core::ServerConfig};
+use crate::{
+    ExtractionConfig,
+    core::{ApiScope, ServerConfig},
+};
 
 use super::{
+    auth::{AuthGuard, Authenticator, auth_middleware},
     handlers::{
-        cache_clear_handler, cache_stats_handler, chunk_handler, embed_handler, extract_handler, health_handler,
-        info_handler,
+        cache_clear_handler, cache_stats_handler, cancel_job_handler, chunk_handler, embed_handler, extract_handler,
+        extract_stream_handler, health_handler, info_handler, job_result_handler, job_status_handler, metrics_handler,
+        searchable_pdf_handler, submit_job_handler,
     },
+    jobs::JobManager,
+    metrics::track_http_metrics,
     types::{ApiSizeLimits, ApiState},
 };
 
@@ -88,7 +96,13 @@ pub fn create_router_with_limits(config: ExtractionConfig, limits: ApiSizeLimits
 
 /// Create the API router with custom size limits and server configuration.
 ///
-/// This function provides full control over request limits, CORS, and server settings via ServerConfig.
+/// This function provides full control over request limits, CORS, authentication and server
+/// settings via ServerConfig.
+///
+/// When `server_config.auth` configures API keys or a JWT secret, every route except
+/// `/health` and `/openapi.json` requires credentials, and `/cache/clear` requires the
+/// `admin` scope. An unreadable API key file makes the router reject all authenticated
+/// routes rather than fall back to open access.
 ///
 /// # Arguments
 ///
@@ -119,8 +133,18 @@ pub fn create_router_with_limits_and_server_config(
     limits: ApiSizeLimits,
     server_config: ServerConfig,
 ) -> Router {
+    crate::metrics::metrics()
+        .set_max_concurrent_extractions(crate::core::extractor::max_concurrent_extractions(&config));
+
+    let mut config = config;
+    if let Some(backend) = server_config.cache_backend {
+        config.cache_backend = backend;
+    }
+
     let state = ApiState {
         default_config: Arc::new(config),
+        jobs: Arc::new(JobManager::new(&server_config.jobs)),
+        cache_backend: server_config.cache_backend,
     };
 
     // CORS configuration based on ServerConfig
@@ -153,14 +177,53 @@ pub fn create_router_with_limits_and_server_config(
         }
     };
 
-    let mut router = Router::new()
+    let mut user_routes = Router::new()
         .route("/extract", post(extract_handler))
+        .route("/extract/stream", post(extract_stream_handler))
+        .route("/searchable-pdf", post(searchable_pdf_handler))
         .route("/embed", post(embed_handler))
         .route("/chunk", post(chunk_handler))
-        .route("/health", get(health_handler))
         .route("/info", get(info_handler))
         .route("/cache/stats", get(cache_stats_handler))
-        .route("/cache/clear", delete(cache_clear_handler));
+        .route("/metrics", get(metrics_handler))
+        .route("/jobs", post(submit_job_handler))
+        .route("/jobs/{id}", get(job_status_handler).delete(cancel_job_handler))
+        .route("/jobs/{id}/result", get(job_result_handler));
+    let mut admin_routes = Router::new().route("/cache/clear", delete(cache_clear_handler));
+
+    // Authentication; /health and /openapi.json stay public
+    if server_config.auth.is_enabled() {
+        let authenticator = match Authenticator::new(&server_config.auth) {
+            Ok(authenticator) => authenticator,
+            Err(e) => {
+                tracing::error!("Invalid authentication configuration, rejecting all requests: {}", e);
+                Authenticator::deny_all()
+            }
+        };
+        let authenticator = Arc::new(authenticator);
+
+        user_routes = user_routes.route_layer(middleware::from_fn_with_state(
+            AuthGuard {
+                authenticator: Arc::clone(&authenticator),
+                required_scope: ApiScope::User,
+            },
+            auth_middleware,
+        ));
+        admin_routes = admin_routes.route_layer(middleware::from_fn_with_state(
+            AuthGuard {
+                authenticator,
+                required_scope: ApiScope::Admin,
+            },
+            auth_middleware,
+        ));
+    } else {
+        tracing::warn!(
+            "API authentication is disabled. Set KREUZBERG_API_KEYS or configure [auth] \
+             before exposing the server beyond localhost."
+        );
+    }
+
+    let mut router = user_routes.merge(admin_routes).route("/health", get(health_handler));
 
     // Add OpenAPI schema endpoint if API feature is enabled
     #[cfg(feature = "api")]
@@ -172,6 +235,7 @@ pub fn create_router_with_limits_and_server_config(
         .layer(DefaultBodyLimit::max(limits.max_request_body_bytes))
         .layer(RequestBodyLimitLayer::new(limits.max_request_body_bytes))
         .layer(cors_layer)
+        .layer(middleware::from_fn(track_http_metrics))
         .layer(TraceLayer::new_for_http())
         .with_state(state)
 }
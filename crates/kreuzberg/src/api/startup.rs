@@ -226,6 +226,11 @@ pub async fn serve_with_server_config(extraction_config: ExtractionConfig, serve
         server_config.max_multipart_field_bytes,
     );
 
+    // Reject invalid auth settings at startup rather than serving with every route locked
+    if server_config.auth.is_enabled() {
+        super::auth::Authenticator::new(&server_config.auth)?;
+    }
+
     let addr = SocketAddr::new(ip, server_config.port);
     let app = create_router_with_limits_and_server_config(extraction_config, limits, server_config.clone());
 
@@ -0,0 +1,117 @@
+//! Server-sent events for the streaming extraction endpoint.
+
+use std::convert::Infallible;
+
+use axum::response::sse::{Event, KeepAlive, Sse};
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio::task::AbortHandle;
+use tokio_stream::{Stream, StreamExt, wrappers::ReceiverStream};
+
+use crate::core::config::{ExtractionConfig, PageConfig};
+use crate::core::page_stream::{PageSink, with_page_sink};
+use crate::error::KreuzbergError;
+use crate::extract_bytes;
+
+use super::error::ApiError;
+
+/// Events buffered for a slow client before page forwarding waits.
+const EVENT_BUFFER: usize = 16;
+
+/// Aborts the extraction task when the event forwarder stops early.
+struct AbortOnDrop(AbortHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Extract a document and stream its results as server-sent events.
+///
+/// Emits `page` events as pages finish (native PDF text and PDF OCR report
+/// pages while the extraction runs; other formats send their pages once done),
+/// then one `chunk` event per chunk, then a final `metadata` event with the
+/// remaining extraction result. Failures are reported as an `error` event.
+/// Dropping the response cancels the extraction.
+pub(crate) fn stream_extraction(
+    data: Vec<u8>,
+    mime_type: String,
+    mut config: ExtractionConfig,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    // Pages are only reported when per-page tracking is enabled
+    config.pages = Some(PageConfig {
+        extract_pages: true,
+        ..config.pages.unwrap_or_default()
+    });
+
+    let (events, receiver) = mpsc::channel(EVENT_BUFFER);
+    tokio::spawn(forward_events(data, mime_type, config, events));
+
+    Sse::new(ReceiverStream::new(receiver).map(Ok)).keep_alive(KeepAlive::default())
+}
+
+async fn forward_events(data: Vec<u8>, mime_type: String, config: ExtractionConfig, events: mpsc::Sender<Event>) {
+    let (page_sender, mut pages) = mpsc::unbounded_channel();
+    let extraction = tokio::spawn(with_page_sink(PageSink::new(page_sender), async move {
+        extract_bytes(&data, &mime_type, &config).await
+    }));
+    let _abort_on_disconnect = AbortOnDrop(extraction.abort_handle());
+
+    // The page channel closes when the extraction finishes and drops its sink
+    let mut streamed_pages = 0usize;
+    loop {
+        let page = tokio::select! {
+            _ = events.closed() => return,
+            page = pages.recv() => page,
+        };
+        let Some(page) = page else { break };
+        streamed_pages += 1;
+        if !send(&events, "page", &page).await {
+            return;
+        }
+    }
+
+    let outcome = tokio::select! {
+        _ = events.closed() => return,
+        outcome = extraction => outcome,
+    };
+    let mut result = match outcome {
+        Ok(Ok(result)) => result,
+        Ok(Err(error)) => {
+            send(&events, "error", &ApiError::from(error).body).await;
+            return;
+        }
+        Err(join_error) => {
+            let error = KreuzbergError::Other(format!("Extraction task failed: {}", join_error));
+            send(&events, "error", &ApiError::internal(error).body).await;
+            return;
+        }
+    };
+
+    let pages = result.pages.take().unwrap_or_default();
+    if streamed_pages == 0 {
+        for page in &pages {
+            if !send(&events, "page", page).await {
+                return;
+            }
+        }
+    }
+    for chunk in result.chunks.take().unwrap_or_default() {
+        if !send(&events, "chunk", &chunk).await {
+            return;
+        }
+    }
+    send(&events, "metadata", &result).await;
+}
+
+/// Send one JSON event; returns `false` once the client has disconnected.
+async fn send<T: Serialize>(events: &mpsc::Sender<Event>, name: &str, data: &T) -> bool {
+    match Event::default().event(name).json_data(data) {
+        Ok(event) => events.send(event).await.is_ok(),
+        Err(e) => {
+            tracing::error!("Failed to serialize {} event: {}", name, e);
+            true
+        }
+    }
+}
@@ -3,7 +3,9 @@
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-use crate::{ExtractionConfig, types::ExtractionResult};
+use crate::{CacheBackend, ExtractionConfig, types::ExtractionResult};
+
+use super::jobs::JobManager;
 
 /// API server size limit configuration.
 ///
@@ -178,6 +180,62 @@ pub struct ErrorResponse {
 pub struct ApiState {
     /// Default extraction configuration
     pub default_config: Arc<ExtractionConfig>,
+    /// Asynchronous extraction jobs
+    pub(crate) jobs: Arc<JobManager>,
+    /// Result cache backend forced on every request by the server configuration
+    pub(crate) cache_backend: Option<CacheBackend>,
+}
+
+/// Lifecycle state of an extraction job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Waiting for a worker
+    Queued,
+    /// Being extracted
+    Running,
+    /// Finished successfully; the result is available
+    Completed,
+    /// Extraction failed or was interrupted
+    Failed,
+    /// Cancelled by the client
+    Cancelled,
+}
+
+impl JobStatus {
+    /// Whether the job has reached a final state.
+    pub fn is_finished(self) -> bool {
+        matches!(self, JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled)
+    }
+}
+
+/// Status of an extraction job.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct JobResponse {
+    /// Job identifier
+    #[cfg_attr(feature = "api", schema(example = "0b9c2f3e-8a41-4d6b-9f7e-2c5d1a3b4e6f"))]
+    pub id: String,
+    /// Current state
+    pub status: JobStatus,
+    /// Number of uploaded files
+    pub total_files: usize,
+    /// Number of files extracted so far
+    pub processed_files: usize,
+    /// Fraction of files extracted (0.0 to 1.0)
+    pub progress: f64,
+    /// Submission time (Unix seconds)
+    pub created_at: u64,
+    /// Start of processing (Unix seconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<u64>,
+    /// Completion, failure or cancellation time (Unix seconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<u64>,
+    /// Error message of a failed job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 /// Cache statistics response.
@@ -197,6 +255,12 @@ pub struct CacheStatsResponse {
     pub oldest_file_age_days: f64,
     /// Age of newest file in days
     pub newest_file_age_days: f64,
+    /// Storage backend of the extraction result cache
+    pub backend: CacheBackend,
+    /// Extraction result cache hits since the server started
+    pub hits: u64,
+    /// Extraction result cache misses since the server started
+    pub misses: u64,
 }
 
 /// Cache clear response.
@@ -255,9 +319,9 @@ pub struct ChunkRequest {
     /// Optional chunking configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config: Option<ChunkingConfigRequest>,
-    /// Chunker type (text or markdown)
+    /// Chunker type (text, markdown or semantic)
     #[serde(default = "default_chunker_type")]
-    #[cfg_attr(feature = "api", schema(example = "text", pattern = "^(text|markdown)$"))]
+    #[cfg_attr(feature = "api", schema(example = "text", pattern = "^(text|markdown|semantic)$"))]
     pub chunker_type: String,
 }
 
@@ -273,6 +337,16 @@ pub struct ChunkingConfigRequest {
     pub overlap: Option<usize>,
     /// Whether to trim whitespace
     pub trim: Option<bool>,
+    /// Unit for `max_characters` and `overlap` (default: characters)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sizing: Option<crate::core::config::ChunkSizing>,
+    /// Threshold and minimum size for the semantic chunker
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub semantic: Option<crate::core::config::SemanticChunkingConfig>,
+    /// Embedding model for the semantic chunker (default: "balanced" preset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "api", schema(value_type = Option<Object>))]
+    pub embedding: Option<crate::core::config::EmbeddingConfig>,
 }
 
 /// Chunk response with chunks and metadata.
@@ -302,6 +376,9 @@ pub struct ChunkItem {
     pub byte_start: usize,
     /// Byte offset end position
     pub byte_end: usize,
+    /// Number of tokens (present when chunk sizes are measured in tokens)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_count: Option<usize>,
     /// Index of this chunk (0-based)
     pub chunk_index: usize,
     /// Total number of chunks
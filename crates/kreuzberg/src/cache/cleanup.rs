@@ -19,6 +19,8 @@ pub(super) fn scan_cache_directory(cache_dir: &str) -> Result<CacheScanResult> {
                 available_space_mb: get_available_disk_space(cache_dir)?,
                 oldest_file_age_days: 0.0,
                 newest_file_age_days: 0.0,
+                hits: 0,
+                misses: 0,
             },
             entries: Vec::new(),
         });
@@ -88,6 +90,8 @@ pub(super) fn scan_cache_directory(cache_dir: &str) -> Result<CacheScanResult> {
             available_space_mb: get_available_disk_space(cache_dir)?,
             oldest_file_age_days: oldest_age,
             newest_file_age_days: newest_age,
+            hits: 0,
+            misses: 0,
         },
         entries,
     })
@@ -1,10 +1,14 @@
 //! Generic cache implementation with lock poisoning recovery.
 //!
 //! This module provides a thread-safe caching system with automatic cleanup,
-//! processing locks, and validation capabilities.
+//! processing locks, and validation capabilities on top of pluggable
+//! [`storage`] backends, and the content-addressed [`ResultCache`] used for
+//! finished extraction results.
 
 mod cleanup;
 mod core;
+mod results;
+pub mod storage;
 mod utilities;
 
 // Re-export all public types and functions for backward compatibility
@@ -12,6 +16,9 @@ pub use cleanup::{
     batch_cleanup_caches, cleanup_cache, clear_cache_directory, get_cache_metadata, is_cache_valid, smart_cleanup_cache,
 };
 pub use core::{CacheStats, GenericCache};
+pub use results::{ResultCache, result_cache};
+pub(crate) use results::{bytes_slot, digest_file, file_slot};
+pub use storage::{CacheLimits, CacheStorage, StoredEntry};
 pub use utilities::{
     fast_hash, filter_old_cache_entries, generate_cache_key, get_available_disk_space, sort_cache_by_access_time,
     validate_cache_key,
@@ -342,4 +349,88 @@ mod tests {
         assert_eq!(cache.cache_type(), "test");
         assert!(cache.cache_dir().to_string_lossy().contains("test"));
     }
+
+    fn limits() -> CacheLimits {
+        CacheLimits {
+            max_age_days: 30.0,
+            max_size_mb: 500.0,
+            min_free_space_mb: 0.0,
+        }
+    }
+
+    fn assert_backend_behaves(backend: crate::core::config::CacheBackend) {
+        use std::io::Write;
+
+        let temp_dir = tempdir().unwrap();
+        let cache = GenericCache::with_backend(
+            backend,
+            "test".to_string(),
+            Some(temp_dir.path().to_str().unwrap().to_string()),
+            limits(),
+        )
+        .unwrap();
+
+        let source_file = temp_dir.path().join("source.txt");
+        std::fs::write(&source_file, b"original content").unwrap();
+        let source = source_file.to_str().unwrap();
+
+        cache.set("key1", b"data1".to_vec(), Some(source)).unwrap();
+        cache.set("key2", b"data2".to_vec(), None).unwrap();
+        assert_eq!(cache.get("key1", Some(source)).unwrap(), Some(b"data1".to_vec()));
+        assert_eq!(cache.get("missing", None).unwrap(), None);
+
+        let mut f = std::fs::OpenOptions::new().append(true).open(&source_file).unwrap();
+        f.write_all(b" and more").unwrap();
+        drop(f);
+        assert_eq!(cache.get("key1", Some(source)).unwrap(), None);
+
+        let stats = cache.get_stats().unwrap();
+        assert_eq!(stats.total_files, 2);
+        assert_eq!((stats.hits, stats.misses), (1, 2));
+
+        cache.remove("key1").unwrap();
+        assert_eq!(cache.clear().unwrap().0, 1);
+        assert_eq!(cache.get("key2", None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_generic_cache_filesystem_backend() {
+        assert_backend_behaves(crate::core::config::CacheBackend::Filesystem);
+    }
+
+    #[test]
+    fn test_generic_cache_memory_backend() {
+        assert_backend_behaves(crate::core::config::CacheBackend::Memory);
+    }
+
+    #[cfg(feature = "cache-sqlite")]
+    #[test]
+    fn test_generic_cache_sqlite_backend() {
+        assert_backend_behaves(crate::core::config::CacheBackend::Sqlite);
+    }
+
+    #[cfg(not(feature = "cache-sqlite"))]
+    #[test]
+    fn test_generic_cache_sqlite_backend_requires_feature() {
+        let temp_dir = tempdir().unwrap();
+        let result = GenericCache::with_backend(
+            crate::core::config::CacheBackend::Sqlite,
+            "test".to_string(),
+            Some(temp_dir.path().to_str().unwrap().to_string()),
+            limits(),
+        );
+        assert!(result.err().unwrap().to_string().contains("cache-sqlite"));
+    }
+
+    #[test]
+    fn test_generic_cache_custom_storage() {
+        let cache = GenericCache::with_storage(
+            "custom".to_string(),
+            std::path::PathBuf::from("unused"),
+            Box::new(storage::MemoryStorage::new(1.0)),
+            limits(),
+        );
+        cache.set("key", b"data".to_vec(), None).unwrap();
+        assert_eq!(cache.get("key", None).unwrap(), Some(b"data".to_vec()));
+    }
 }
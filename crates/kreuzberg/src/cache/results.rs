@@ -0,0 +1,440 @@
+//! Content-addressed cache for finished extraction results.
+//!
+//! Entries are keyed by a SHA-256 digest of the document bytes combined with a
+//! canonical hash of the output-relevant [`ExtractionConfig`] fields, the MIME
+//! type, the extractor and post-processing plugins in use, and the crate version.
+//! Re-extracting an unchanged document with the same configuration therefore
+//! returns the stored result instead of redoing the work, while any change to
+//! the content, configuration or plugins produces a different key.
+//!
+//! The cache lives in `$KREUZBERG_CACHE_DIR/extractions`, or
+//! `.kreuzberg/extractions` under the current directory, stored by the backend
+//! chosen with [`ExtractionConfig::cache_backend`], and is only consulted when
+//! [`ExtractionConfig::use_cache`] is enabled.
+
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+
+use super::core::{CacheStats, GenericCache};
+use super::storage::CacheLimits;
+use crate::core::config::{CacheBackend, ExtractionConfig};
+use crate::error::Result;
+use crate::types::ExtractionResult;
+
+/// Cache type name, also the subdirectory holding the entries.
+const CACHE_TYPE: &str = "extractions";
+const MAX_AGE_DAYS: f64 = 30.0;
+const MAX_CACHE_SIZE_MB: f64 = 1024.0;
+/// The memory backend competes with extraction for RAM, so it is kept smaller.
+const MAX_MEMORY_CACHE_SIZE_MB: f64 = 256.0;
+const MIN_FREE_SPACE_MB: f64 = 1000.0;
+
+/// Configuration fields that do not influence the extracted output.
+const IGNORED_CONFIG_FIELDS: &[&str] = &["use_cache", "cache_backend", "max_concurrent_extractions"];
+
+/// Deprecated metadata key mirroring `Metadata::output_format`.
+const LEGACY_OUTPUT_FORMAT_KEY: &str = "output_format";
+
+/// Process-wide result caches, one per [`CacheBackend`], opened on first use.
+static RESULT_CACHES: [Lazy<Option<ResultCache>>; 3] = [
+    Lazy::new(|| open_shared(CacheBackend::Filesystem)),
+    Lazy::new(|| open_shared(CacheBackend::Sqlite)),
+    Lazy::new(|| open_shared(CacheBackend::Memory)),
+];
+
+fn open_shared(backend: CacheBackend) -> Option<ResultCache> {
+    let cache_dir = std::env::var("KREUZBERG_CACHE_DIR").ok();
+    match ResultCache::with_backend(backend, cache_dir) {
+        Ok(cache) => Some(cache),
+        Err(e) => {
+            tracing::warn!("Extraction result cache ({} backend) disabled: {}", backend, e);
+            None
+        }
+    }
+}
+
+/// The process-wide extraction result cache using `backend`.
+///
+/// Returns `None` if the cache could not be opened, e.g. because the cache
+/// directory could not be created.
+pub fn result_cache(backend: CacheBackend) -> Option<&'static ResultCache> {
+    RESULT_CACHES[backend.index()].as_ref()
+}
+
+/// Persistent cache of [`ExtractionResult`]s keyed by content and configuration.
+pub struct ResultCache {
+    cache: GenericCache,
+}
+
+impl ResultCache {
+    /// Open a filesystem result cache under `cache_dir` (or `.kreuzberg` in the current directory).
+    pub fn new(cache_dir: Option<String>) -> Result<Self> {
+        Self::with_backend(CacheBackend::Filesystem, cache_dir)
+    }
+
+    /// Open a result cache under `cache_dir` using the given storage backend.
+    pub fn with_backend(backend: CacheBackend, cache_dir: Option<String>) -> Result<Self> {
+        let max_size_mb = match backend {
+            CacheBackend::Memory => MAX_MEMORY_CACHE_SIZE_MB,
+            CacheBackend::Filesystem | CacheBackend::Sqlite => MAX_CACHE_SIZE_MB,
+        };
+        let limits = CacheLimits {
+            max_age_days: MAX_AGE_DAYS,
+            max_size_mb,
+            min_free_space_mb: MIN_FREE_SPACE_MB,
+        };
+        let cache = GenericCache::with_backend(backend, CACHE_TYPE.to_string(), cache_dir, limits)?;
+        Ok(Self { cache })
+    }
+
+    /// Directory holding the cache entries.
+    pub fn cache_dir(&self) -> &Path {
+        self.cache.cache_dir()
+    }
+
+    /// Entry statistics together with the hits and misses recorded by this process.
+    pub fn stats(&self) -> Result<CacheStats> {
+        self.cache.get_stats()
+    }
+
+    /// Remove all cached results, returning the number of entries and megabytes freed.
+    pub fn clear(&self) -> Result<(usize, f64)> {
+        self.cache.clear()
+    }
+
+    /// Slot for a document with the given content digest, or `None` if the
+    /// configuration cannot be fingerprinted.
+    fn slot(
+        &self,
+        content_digest: &str,
+        mime_type: &str,
+        extractor: &str,
+        config: &ExtractionConfig,
+    ) -> Option<CacheSlot<'_>> {
+        match cache_key(content_digest, mime_type, extractor, config) {
+            Ok(key) => Some(CacheSlot { cache: self, key }),
+            Err(e) => {
+                tracing::debug!("Skipping result cache, configuration cannot be hashed: {}", e);
+                None
+            }
+        }
+    }
+}
+
+/// The cache entry a single extraction reads from and writes to.
+pub(crate) struct CacheSlot<'a> {
+    cache: &'a ResultCache,
+    key: String,
+}
+
+impl CacheSlot<'_> {
+    /// The cached result, if one exists and can be decoded.
+    pub(crate) fn get(&self) -> Option<ExtractionResult> {
+        let bytes = match self.cache.cache.get(&self.key, None) {
+            Ok(bytes) => bytes?,
+            Err(e) => {
+                tracing::debug!("Result cache lookup failed: {}", e);
+                return None;
+            }
+        };
+
+        match rmp_serde::from_slice::<ExtractionResult>(&bytes) {
+            Ok(mut result) => {
+                if let Some(format) = result.metadata.output_format.clone() {
+                    result
+                        .metadata
+                        .additional
+                        .entry(Cow::Borrowed(LEGACY_OUTPUT_FORMAT_KEY))
+                        .or_insert(serde_json::Value::String(format));
+                }
+                Some(result)
+            }
+            Err(e) => {
+                tracing::debug!("Discarding undecodable result cache entry: {}", e);
+                // Best-effort removal, the entry is rewritten after extraction ~keep
+                let _ = self.cache.cache.remove(&self.key);
+                None
+            }
+        }
+    }
+
+    /// Store a freshly extracted result.
+    ///
+    /// Failures are logged and otherwise ignored; the cache is an optimisation.
+    pub(crate) fn store(&self, result: &mut ExtractionResult) {
+        // The deprecated mirror of `output_format` would serialize as a duplicate field
+        let legacy_format = result.metadata.additional.remove(LEGACY_OUTPUT_FORMAT_KEY);
+        let encoded = rmp_serde::to_vec_named(&*result);
+        if let Some(value) = legacy_format {
+            result
+                .metadata
+                .additional
+                .insert(Cow::Borrowed(LEGACY_OUTPUT_FORMAT_KEY), value);
+        }
+
+        let encoded = match encoded {
+            Ok(encoded) => encoded,
+            Err(e) => {
+                tracing::debug!("Result not cached, serialization failed: {}", e);
+                return;
+            }
+        };
+        // Some format metadata does not survive a round trip; never store what cannot be read back
+        if let Err(e) = rmp_serde::from_slice::<ExtractionResult>(&encoded) {
+            tracing::debug!("Result not cached, it does not deserialize: {}", e);
+            return;
+        }
+
+        if let Err(e) = self.cache.cache.set(&self.key, encoded, None) {
+            tracing::debug!("Failed to write result cache entry: {}", e);
+        }
+    }
+}
+
+/// Cache slot for extracting `content`, if result caching is enabled.
+pub(crate) fn bytes_slot(
+    content: &[u8],
+    mime_type: &str,
+    extractor: &str,
+    config: &ExtractionConfig,
+) -> Option<CacheSlot<'static>> {
+    if !config.use_cache {
+        return None;
+    }
+    let cache = result_cache(config.cache_backend)?;
+    cache.slot(&hex::encode(Sha256::digest(content)), mime_type, extractor, config)
+}
+
+/// Cache slot for extracting the file at `path`, if result caching is enabled.
+pub(crate) fn file_slot(
+    path: &Path,
+    mime_type: &str,
+    extractor: &str,
+    config: &ExtractionConfig,
+) -> Option<CacheSlot<'static>> {
+    if !config.use_cache || !path.is_file() {
+        return None;
+    }
+    let cache = result_cache(config.cache_backend)?;
+    match digest_file(path) {
+        Ok(digest) => cache.slot(&digest, mime_type, extractor, config),
+        Err(e) => {
+            tracing::debug!("Skipping result cache, failed to hash {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Hex SHA-256 of a file's contents, read in 64 KiB blocks.
+pub(crate) fn digest_file(path: &Path) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Compute the cache key for a document.
+///
+/// The configuration is hashed as JSON with object keys sorted, so that field
+/// order and map iteration order never change the key.
+fn cache_key(content_digest: &str, mime_type: &str, extractor: &str, config: &ExtractionConfig) -> Result<String> {
+    let mut config = serde_json::to_value(config)?;
+    if let Some(fields) = config.as_object_mut() {
+        for field in IGNORED_CONFIG_FIELDS {
+            fields.remove(*field);
+        }
+    }
+    config.sort_all_objects();
+
+    let mut hasher = Sha256::new();
+    for part in [
+        env!("CARGO_PKG_VERSION"),
+        content_digest,
+        mime_type,
+        extractor,
+        &config.to_string(),
+        &plugin_fingerprint(),
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Names of the registered post-processors and validators, which shape or gate every result.
+fn plugin_fingerprint() -> String {
+    let mut names = crate::plugins::registry::get_post_processor_registry()
+        .read()
+        .map(|registry| registry.list())
+        .unwrap_or_default();
+    names.extend(
+        crate::plugins::registry::get_validator_registry()
+            .read()
+            .map(|registry| registry.list())
+            .unwrap_or_default(),
+    );
+    names.sort();
+    names.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::ChunkingConfig;
+    use crate::types::Metadata;
+    use tempfile::tempdir;
+
+    fn result(content: &str) -> ExtractionResult {
+        let mut metadata = Metadata {
+            output_format: Some("plain".to_string()),
+            ..Default::default()
+        };
+        metadata.additional.insert(
+            Cow::Borrowed(LEGACY_OUTPUT_FORMAT_KEY),
+            serde_json::Value::String("plain".to_string()),
+        );
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata,
+            ..Default::default()
+        }
+    }
+
+    fn key(config: &ExtractionConfig) -> String {
+        cache_key("digest", "text/plain", "plain-text-extractor@1", config).unwrap()
+    }
+
+    #[test]
+    fn test_key_ignores_non_output_fields() {
+        let config = ExtractionConfig::default();
+        let tuned = ExtractionConfig {
+            use_cache: false,
+            cache_backend: CacheBackend::Memory,
+            max_concurrent_extractions: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(key(&config), key(&tuned));
+        assert_eq!(key(&config).len(), 64);
+    }
+
+    #[test]
+    fn test_key_changes_with_inputs() {
+        let config = ExtractionConfig::default();
+        let chunked = ExtractionConfig {
+            chunking: Some(ChunkingConfig::default()),
+            ..Default::default()
+        };
+        let base = key(&config);
+
+        assert_ne!(base, key(&chunked));
+        assert_ne!(
+            base,
+            cache_key("other", "text/plain", "plain-text-extractor@1", &config).unwrap()
+        );
+        assert_ne!(
+            base,
+            cache_key("digest", "text/markdown", "plain-text-extractor@1", &config).unwrap()
+        );
+        assert_ne!(
+            base,
+            cache_key("digest", "text/plain", "plain-text-extractor@2", &config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_round_trip_restores_legacy_output_format() {
+        let dir = tempdir().unwrap();
+        let cache = ResultCache::new(Some(dir.path().to_string_lossy().into_owned())).unwrap();
+        let slot = cache
+            .slot(
+                "digest",
+                "text/plain",
+                "plain-text-extractor@1",
+                &ExtractionConfig::default(),
+            )
+            .unwrap();
+
+        assert!(slot.get().is_none());
+
+        let mut original = result("cached text");
+        slot.store(&mut original);
+        assert!(original.metadata.additional.contains_key(LEGACY_OUTPUT_FORMAT_KEY));
+
+        let cached = slot.get().expect("result should be cached");
+        assert_eq!(cached.content, "cached text");
+        assert_eq!(cached.metadata.output_format.as_deref(), Some("plain"));
+        assert_eq!(
+            cached.metadata.additional.get(LEGACY_OUTPUT_FORMAT_KEY),
+            Some(&serde_json::Value::String("plain".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_stats_count_hits_and_misses() {
+        let dir = tempdir().unwrap();
+        let cache = ResultCache::new(Some(dir.path().to_string_lossy().into_owned())).unwrap();
+        let slot = cache
+            .slot(
+                "digest",
+                "text/plain",
+                "plain-text-extractor@1",
+                &ExtractionConfig::default(),
+            )
+            .unwrap();
+
+        assert!(slot.get().is_none());
+        slot.store(&mut result("text"));
+        assert!(slot.get().is_some());
+        assert!(slot.get().is_some());
+
+        let stats = cache.stats().unwrap();
+        assert_eq!(stats.total_files, 1);
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 1);
+
+        assert_eq!(cache.clear().unwrap().0, 1);
+        assert!(slot.get().is_none());
+    }
+
+    #[test]
+    fn test_disabled_by_config() {
+        let config = ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        };
+        assert!(bytes_slot(b"content", "text/plain", "plain-text-extractor@1", &config).is_none());
+    }
+
+    #[test]
+    fn test_memory_backend_round_trip() {
+        let dir = tempdir().unwrap();
+        let cache =
+            ResultCache::with_backend(CacheBackend::Memory, Some(dir.path().to_string_lossy().into_owned())).unwrap();
+        let slot = cache
+            .slot(
+                "digest",
+                "text/plain",
+                "plain-text-extractor@1",
+                &ExtractionConfig::default(),
+            )
+            .unwrap();
+
+        slot.store(&mut result("in memory"));
+        assert_eq!(slot.get().unwrap().content, "in memory");
+        assert!(!cache.cache_dir().exists(), "nothing is written to disk");
+        assert_eq!(cache.clear().unwrap().0, 1);
+    }
+}
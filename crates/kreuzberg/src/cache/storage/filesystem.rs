@@ -0,0 +1,263 @@
+//! Filesystem cache storage: one file per entry in the cache directory.
+//!
+//! Entries are stored as `<key>.msgpack`, with an optional 16-byte `<key>.meta`
+//! file holding the little-endian size and modification time of the source
+//! file. The entry's modification time is its storage time, which is what the
+//! directory-scan based cleanup in [`super::super::cleanup`] relies on.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::{CacheLimits, CacheStorage, SourceStamp, StoredEntry};
+use crate::cache::cleanup::{get_cache_metadata, smart_cleanup_cache};
+use crate::cache::core::CacheStats;
+use crate::error::{KreuzbergError, Result};
+
+/// Cache storage keeping each entry in its own file.
+pub struct FilesystemStorage {
+    dir: PathBuf,
+    /// Tracks entry files being deleted to prevent read-during-delete race conditions
+    deleting_files: Mutex<HashSet<PathBuf>>,
+}
+
+impl FilesystemStorage {
+    /// Store entries in `dir`, creating it if needed.
+    pub fn new(dir: PathBuf) -> Result<Self> {
+        fs::create_dir_all(&dir)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to create cache directory: {}", e)))?;
+        Ok(Self {
+            dir,
+            deleting_files: Mutex::new(HashSet::new()),
+        })
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.msgpack", key))
+    }
+
+    fn metadata_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.meta", key))
+    }
+
+    fn dir_str(&self) -> Result<&str> {
+        self.dir
+            .to_str()
+            .ok_or_else(|| KreuzbergError::validation("Cache directory path contains invalid UTF-8".to_string()))
+    }
+
+    /// Read the source stamp of an entry.
+    ///
+    /// `Ok(None)` means the entry has no stamp; `Err(())` means the stamp file
+    /// exists but cannot be used, which makes the whole entry unusable.
+    fn read_stamp(&self, key: &str) -> std::result::Result<Option<SourceStamp>, ()> {
+        match fs::read(self.metadata_path(key)) {
+            Ok(bytes) => {
+                let bytes: [u8; 16] = bytes.try_into().map_err(|_| ())?;
+                let (size, mtime) = bytes.split_at(8);
+                Ok(Some(SourceStamp {
+                    size: u64::from_le_bytes(size.try_into().map_err(|_| ())?),
+                    mtime_secs: u64::from_le_bytes(mtime.try_into().map_err(|_| ())?),
+                }))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(_) => Err(()),
+        }
+    }
+
+    /// Mark a file path as being deleted to prevent concurrent reads.
+    ///
+    /// # TOCTOU Race Condition
+    ///
+    /// There is a Time-Of-Check-To-Time-Of-Use (TOCTOU) race condition between:
+    /// 1. Iterating directory entries in `clear()` (getting path/metadata)
+    /// 2. Marking the file for deletion here
+    /// 3. Actually deleting the file
+    ///
+    /// **Race scenario:**
+    /// - Thread A: Begins iterating in `clear()`, gets path
+    /// - Thread B: Calls `get()`, checks `deleting_files` (not marked yet), proceeds
+    /// - Thread A: Calls `mark_for_deletion()` here
+    /// - Thread A: Deletes file with `fs::remove_file()`
+    /// - Thread B: Tries to read file, but it's already deleted
+    ///
+    /// **Why this is acceptable:**
+    /// - Cache operations are best-effort optimizations, not critical
+    /// - `get()` already handles file read failures gracefully (treats as cache miss)
+    /// - The worst case is a failed read → cache miss → recomputation
+    /// - No data corruption or invariant violations occur
+    /// - Alternative (atomic operation) would require complex locking impacting performance
+    fn mark_for_deletion(&self, path: &Path) -> Result<()> {
+        let mut deleting = self
+            .deleting_files
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
+        deleting.insert(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Remove a file path from the deletion set
+    fn unmark_deletion(&self, path: &Path) -> Result<()> {
+        let mut deleting = self
+            .deleting_files
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
+        deleting.remove(path);
+        Ok(())
+    }
+}
+
+impl CacheStorage for FilesystemStorage {
+    fn get(&self, key: &str) -> Result<Option<StoredEntry>> {
+        let path = self.entry_path(key);
+
+        {
+            let deleting = self
+                .deleting_files
+                .lock()
+                .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
+            if deleting.contains(&path) {
+                return Ok(None);
+            }
+        }
+
+        let stored_at = match fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+            Ok(modified) => modified,
+            Err(_) => return Ok(None),
+        };
+        let Ok(source) = self.read_stamp(key) else {
+            return Ok(None);
+        };
+
+        match fs::read(&path) {
+            Ok(data) => Ok(Some(StoredEntry {
+                data,
+                stored_at,
+                source,
+            })),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(_) => {
+                // Best-effort cleanup of corrupted cache files ~keep
+                if let Err(e) = self.remove(key) {
+                    tracing::debug!("Failed to remove corrupted cache entry: {}", e);
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    fn set(&self, key: &str, data: &[u8], source: Option<SourceStamp>) -> Result<()> {
+        fs::write(self.entry_path(key), data)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to write cache file: {}", e)))?;
+
+        let metadata_path = self.metadata_path(key);
+        match source {
+            Some(stamp) => {
+                let mut bytes = Vec::with_capacity(16);
+                bytes.extend_from_slice(&stamp.size.to_le_bytes());
+                bytes.extend_from_slice(&stamp.mtime_secs.to_le_bytes());
+                // Cache metadata write failure - safe to ignore, cache is optional fallback ~keep
+                let _ = fs::write(metadata_path, bytes);
+            }
+            None => {
+                // A stamp left over from an earlier entry must not apply to this one ~keep
+                let _ = fs::remove_file(metadata_path);
+            }
+        }
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        for path in [self.entry_path(key), self.metadata_path(key)] {
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => {
+                    return Err(KreuzbergError::cache(format!(
+                        "Failed to remove cache file {}: {}",
+                        path.display(),
+                        e
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(usize, f64)> {
+        if !self.dir.exists() {
+            return Ok((0, 0.0));
+        }
+
+        let mut removed_count = 0;
+        let mut removed_size = 0.0;
+
+        let read_dir = fs::read_dir(&self.dir)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
+
+        for entry in read_dir {
+            let entry = match entry {
+                Ok(e) => e,
+                Err(e) => {
+                    tracing::debug!("Error reading entry: {}", e);
+                    continue;
+                }
+            };
+
+            let metadata = match entry.metadata() {
+                Ok(m) if m.is_file() => m,
+                _ => continue,
+            };
+
+            let path = entry.path();
+            match path.extension().and_then(|s| s.to_str()) {
+                Some("msgpack") => {}
+                Some("meta") => {
+                    // Stamps are not entries; drop them without counting ~keep
+                    let _ = fs::remove_file(&path);
+                    continue;
+                }
+                _ => continue,
+            }
+
+            let size_mb = super::bytes_to_mb(metadata.len());
+
+            // Mark file for deletion to prevent concurrent access ~keep
+            if let Err(e) = self.mark_for_deletion(&path) {
+                tracing::debug!("Failed to mark file for deletion: {} (continuing anyway)", e);
+            }
+
+            match fs::remove_file(&path) {
+                Ok(_) => {
+                    removed_count += 1;
+                    removed_size += size_mb;
+                }
+                Err(e) => {
+                    tracing::debug!("Failed to remove {:?}: {}", path, e);
+                }
+            }
+
+            // Unmark after the deletion attempt; a failed deletion may be retried ~keep
+            if let Err(e) = self.unmark_deletion(&path) {
+                tracing::debug!("Failed to unmark file after deletion: {} (non-critical)", e);
+            }
+        }
+
+        Ok((removed_count, removed_size))
+    }
+
+    fn stats(&self) -> Result<CacheStats> {
+        get_cache_metadata(self.dir_str()?)
+    }
+
+    fn cleanup(&self, limits: &CacheLimits) -> Result<(usize, f64)> {
+        smart_cleanup_cache(
+            self.dir_str()?,
+            limits.max_age_days,
+            limits.max_size_mb,
+            limits.min_free_space_mb,
+        )
+    }
+}
@@ -0,0 +1,243 @@
+//! In-memory cache storage with least-recently-used eviction.
+//!
+//! Meant for short-lived server processes: nothing touches the disk and the
+//! store never grows past its byte capacity, evicting the entries read or
+//! written longest ago first.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use super::{CacheLimits, CacheStorage, SourceStamp, StoredEntry, age_days, bytes_to_mb};
+use crate::cache::core::CacheStats;
+use crate::error::{KreuzbergError, Result};
+
+/// Bounded in-process cache storage.
+pub struct MemoryStorage {
+    capacity_bytes: u64,
+    state: Mutex<LruState>,
+}
+
+#[derive(Default)]
+struct LruState {
+    entries: HashMap<String, (StoredEntry, u64)>,
+    /// Entry keys by last access tick, oldest first
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    total_bytes: u64,
+}
+
+impl LruState {
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some((_, last_used)) = self.entries.get_mut(key) {
+            self.recency.remove(last_used);
+            *last_used = tick;
+            self.recency.insert(tick, key.to_string());
+        }
+    }
+
+    fn insert(&mut self, key: &str, entry: StoredEntry) {
+        self.remove(key);
+        self.tick += 1;
+        self.total_bytes += entry.data.len() as u64;
+        self.recency.insert(self.tick, key.to_string());
+        self.entries.insert(key.to_string(), (entry, self.tick));
+    }
+
+    fn remove(&mut self, key: &str) -> Option<u64> {
+        let (entry, last_used) = self.entries.remove(key)?;
+        self.recency.remove(&last_used);
+        let size = entry.data.len() as u64;
+        self.total_bytes -= size;
+        Some(size)
+    }
+
+    /// Evict least recently used entries until at most `max_bytes` are held.
+    fn shrink_to(&mut self, max_bytes: u64) -> (usize, u64) {
+        let mut removed = (0, 0);
+        while self.total_bytes > max_bytes {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            if let Some((entry, _)) = self.entries.remove(&key) {
+                let size = entry.data.len() as u64;
+                self.total_bytes -= size;
+                removed.0 += 1;
+                removed.1 += size;
+            }
+        }
+        removed
+    }
+}
+
+impl MemoryStorage {
+    /// Create an empty store holding at most `capacity_mb` megabytes of entries.
+    pub fn new(capacity_mb: f64) -> Self {
+        Self {
+            capacity_bytes: (capacity_mb.max(0.0) * 1024.0 * 1024.0) as u64,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, LruState>> {
+        self.state
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Memory cache mutex poisoned: {}", e)))
+    }
+}
+
+impl CacheStorage for MemoryStorage {
+    fn get(&self, key: &str) -> Result<Option<StoredEntry>> {
+        let mut state = self.lock()?;
+        state.touch(key);
+        Ok(state.entries.get(key).map(|(entry, _)| entry.clone()))
+    }
+
+    fn set(&self, key: &str, data: &[u8], source: Option<SourceStamp>) -> Result<()> {
+        let mut state = self.lock()?;
+        if data.len() as u64 > self.capacity_bytes {
+            // An entry larger than the whole store would only evict everything else ~keep
+            state.remove(key);
+            return Ok(());
+        }
+        state.insert(
+            key,
+            StoredEntry {
+                data: data.to_vec(),
+                stored_at: SystemTime::now(),
+                source,
+            },
+        );
+        state.shrink_to(self.capacity_bytes);
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        self.lock()?.remove(key);
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(usize, f64)> {
+        let mut state = self.lock()?;
+        let removed = (state.entries.len(), bytes_to_mb(state.total_bytes));
+        *state = LruState::default();
+        Ok(removed)
+    }
+
+    fn stats(&self) -> Result<CacheStats> {
+        let state = self.lock()?;
+        let now = SystemTime::now();
+        let ages = state.entries.values().map(|(entry, _)| age_days(entry.stored_at, now));
+        let (oldest, newest) = ages.fold((0.0f64, f64::INFINITY), |(oldest, newest), age| {
+            (oldest.max(age), newest.min(age))
+        });
+
+        Ok(CacheStats {
+            total_files: state.entries.len(),
+            total_size_mb: bytes_to_mb(state.total_bytes),
+            available_space_mb: bytes_to_mb(self.capacity_bytes.saturating_sub(state.total_bytes)),
+            oldest_file_age_days: oldest,
+            newest_file_age_days: if state.entries.is_empty() { 0.0 } else { newest },
+            hits: 0,
+            misses: 0,
+        })
+    }
+
+    fn cleanup(&self, limits: &CacheLimits) -> Result<(usize, f64)> {
+        let mut state = self.lock()?;
+        let now = SystemTime::now();
+        let expired: Vec<String> = state
+            .entries
+            .iter()
+            .filter(|(_, (entry, _))| age_days(entry.stored_at, now) > limits.max_age_days)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        let mut removed_count = 0;
+        let mut removed_bytes = 0;
+        for key in expired {
+            if let Some(size) = state.remove(&key) {
+                removed_count += 1;
+                removed_bytes += size;
+            }
+        }
+
+        let max_bytes = (limits.max_size_mb.max(0.0) * 1024.0 * 1024.0) as u64;
+        let (count, bytes) = state.shrink_to(max_bytes.min(self.capacity_bytes));
+        Ok((removed_count + count, bytes_to_mb(removed_bytes + bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: CacheLimits = CacheLimits {
+        max_age_days: 30.0,
+        max_size_mb: 1.0,
+        min_free_space_mb: 0.0,
+    };
+
+    #[test]
+    fn test_memory_storage_round_trip() {
+        let storage = MemoryStorage::new(1.0);
+        let stamp = SourceStamp { size: 3, mtime_secs: 7 };
+        storage.set("key", b"data", Some(stamp)).unwrap();
+
+        let entry = storage.get("key").unwrap().unwrap();
+        assert_eq!(entry.data, b"data");
+        assert_eq!(entry.source, Some(stamp));
+
+        storage.remove("key").unwrap();
+        assert!(storage.get("key").unwrap().is_none());
+        assert_eq!(storage.stats().unwrap().total_files, 0);
+    }
+
+    #[test]
+    fn test_memory_storage_evicts_least_recently_used() {
+        let kilobyte = vec![0u8; 1024];
+        let storage = MemoryStorage::new(3.0 / 1024.0);
+        storage.set("a", &kilobyte, None).unwrap();
+        storage.set("b", &kilobyte, None).unwrap();
+        storage.set("c", &kilobyte, None).unwrap();
+
+        assert!(storage.get("a").unwrap().is_some());
+        storage.set("d", &kilobyte, None).unwrap();
+
+        assert!(storage.get("b").unwrap().is_none(), "b was used least recently");
+        for key in ["a", "c", "d"] {
+            assert!(storage.get(key).unwrap().is_some(), "{} should be kept", key);
+        }
+        let stats = storage.stats().unwrap();
+        assert_eq!(stats.total_files, 3);
+        assert_eq!(stats.available_space_mb, 0.0);
+    }
+
+    #[test]
+    fn test_memory_storage_skips_oversized_entries() {
+        let storage = MemoryStorage::new(1.0 / 1024.0);
+        storage.set("small", b"small", None).unwrap();
+        storage.set("large", &vec![0u8; 2048], None).unwrap();
+
+        assert!(storage.get("large").unwrap().is_none());
+        assert!(storage.get("small").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_memory_storage_clear_and_cleanup() {
+        let storage = MemoryStorage::new(4.0);
+        storage.set("a", &vec![0u8; 1024 * 1024], None).unwrap();
+        storage.set("b", &vec![0u8; 1024 * 1024], None).unwrap();
+
+        let (removed, freed) = storage.cleanup(&LIMITS).unwrap();
+        assert_eq!(removed, 1);
+        assert_eq!(freed, 1.0);
+        assert!(storage.get("b").unwrap().is_some());
+
+        let (removed, freed) = storage.clear().unwrap();
+        assert_eq!((removed, freed), (1, 1.0));
+        assert!(storage.get("b").unwrap().is_none());
+    }
+}
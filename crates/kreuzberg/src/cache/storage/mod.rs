@@ -0,0 +1,102 @@
+//! Storage backends behind [`GenericCache`](super::GenericCache).
+//!
+//! A [`CacheStorage`] only stores and evicts opaque entries. Freshness checks
+//! (entry age, source file changes), processing locks and hit/miss accounting
+//! stay in `GenericCache`, so every backend behaves the same from the caller's
+//! point of view.
+//!
+//! - [`FilesystemStorage`]: one `<key>.msgpack` file per entry, cleaned up by
+//!   directory scans.
+//! - [`SqliteStorage`]: a single database file with size-bounded LRU eviction
+//!   (`cache-sqlite` feature).
+//! - [`MemoryStorage`]: a bounded in-process LRU.
+
+mod filesystem;
+mod memory;
+#[cfg(feature = "cache-sqlite")]
+mod sqlite;
+
+pub use filesystem::FilesystemStorage;
+pub use memory::MemoryStorage;
+#[cfg(feature = "cache-sqlite")]
+pub use sqlite::SqliteStorage;
+
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::core::CacheStats;
+use crate::error::Result;
+
+/// Size and modification time of the file an entry was derived from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceStamp {
+    pub size: u64,
+    pub mtime_secs: u64,
+}
+
+impl SourceStamp {
+    /// Stamp of the file at `path`, or `None` if it cannot be read.
+    pub fn of(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        let mtime_secs = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some(Self {
+            size: metadata.len(),
+            mtime_secs,
+        })
+    }
+}
+
+/// A stored cache entry.
+#[derive(Debug, Clone)]
+pub struct StoredEntry {
+    pub data: Vec<u8>,
+    /// When the entry was written
+    pub stored_at: SystemTime,
+    /// Stamp of the source file, if the entry was stored with one
+    pub source: Option<SourceStamp>,
+}
+
+/// Age and size limits enforced by [`CacheStorage::cleanup`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CacheLimits {
+    pub max_age_days: f64,
+    pub max_size_mb: f64,
+    pub min_free_space_mb: f64,
+}
+
+/// Key-value store holding cache entries.
+///
+/// Implementations must be safe to share between threads. Read failures of
+/// individual entries should be reported as misses rather than errors, since
+/// the cache is only an optimisation.
+pub trait CacheStorage: Send + Sync {
+    /// Read the entry stored under `key`.
+    fn get(&self, key: &str) -> Result<Option<StoredEntry>>;
+
+    /// Store `data` under `key`, replacing any previous entry.
+    fn set(&self, key: &str, data: &[u8], source: Option<SourceStamp>) -> Result<()>;
+
+    /// Remove the entry stored under `key`, if any.
+    fn remove(&self, key: &str) -> Result<()>;
+
+    /// Remove all entries, returning the number of entries and megabytes freed.
+    fn clear(&self) -> Result<(usize, f64)>;
+
+    /// Entry count, size and age statistics. Hit and miss counters are left at zero.
+    fn stats(&self) -> Result<CacheStats>;
+
+    /// Evict expired entries and shrink the store to its limits, returning the
+    /// number of entries and megabytes freed.
+    fn cleanup(&self, limits: &CacheLimits) -> Result<(usize, f64)>;
+}
+
+pub(crate) fn age_days(since: SystemTime, now: SystemTime) -> f64 {
+    now.duration_since(since)
+        .map(|elapsed| elapsed.as_secs_f64() / (24.0 * 3600.0))
+        .unwrap_or(0.0)
+}
+
+pub(crate) fn bytes_to_mb(bytes: u64) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
@@ -0,0 +1,330 @@
+//! SQLite cache storage: all entries in a single database file.
+//!
+//! Keeping entries in one file avoids the per-entry files and directory scans of
+//! [`FilesystemStorage`](super::FilesystemStorage), which are slow on network
+//! filesystems and in containers. Every read records an access time, and
+//! cleanup evicts expired entries first and then the least recently used ones
+//! until the store fits its size limit.
+
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rusqlite::{Connection, OptionalExtension, params};
+
+use super::{CacheLimits, CacheStorage, SourceStamp, StoredEntry, bytes_to_mb};
+use crate::cache::core::CacheStats;
+use crate::cache::utilities::get_available_disk_space;
+use crate::error::{KreuzbergError, Result};
+
+/// File name of the database inside the cache directory.
+const DATABASE_FILE: &str = "cache.sqlite";
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS entries (
+        key TEXT PRIMARY KEY NOT NULL,
+        data BLOB NOT NULL,
+        size INTEGER NOT NULL,
+        stored_at INTEGER NOT NULL,
+        accessed_at INTEGER NOT NULL,
+        source_size INTEGER,
+        source_mtime INTEGER
+    );
+    CREATE INDEX IF NOT EXISTS entries_accessed_at ON entries (accessed_at);
+";
+
+/// Cache storage backed by a single SQLite database.
+pub struct SqliteStorage {
+    dir: PathBuf,
+    connection: Mutex<Connection>,
+}
+
+fn sqlite_error(e: rusqlite::Error) -> KreuzbergError {
+    KreuzbergError::cache(format!("SQLite cache error: {}", e))
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+fn from_millis(millis: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis.max(0) as u64)
+}
+
+impl SqliteStorage {
+    /// Open or create the database in `dir`.
+    pub fn new(dir: PathBuf) -> Result<Self> {
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to create cache directory: {}", e)))?;
+
+        let connection = Connection::open(dir.join(DATABASE_FILE)).map_err(sqlite_error)?;
+        // WAL lets concurrent processes read while one writes ~keep
+        connection
+            .pragma_update(None, "journal_mode", "WAL")
+            .map_err(sqlite_error)?;
+        connection
+            .pragma_update(None, "synchronous", "NORMAL")
+            .map_err(sqlite_error)?;
+        connection.busy_timeout(Duration::from_secs(5)).map_err(sqlite_error)?;
+        connection.execute_batch(SCHEMA).map_err(sqlite_error)?;
+
+        Ok(Self {
+            dir,
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// Path of the database file.
+    pub fn database_path(&self) -> PathBuf {
+        self.dir.join(DATABASE_FILE)
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Connection>> {
+        self.connection
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("SQLite cache mutex poisoned: {}", e)))
+    }
+
+    fn available_space_mb(&self) -> Result<f64> {
+        get_available_disk_space(&self.dir.to_string_lossy())
+    }
+}
+
+fn total_bytes(connection: &Connection) -> Result<u64> {
+    let total: i64 = connection
+        .query_row("SELECT COALESCE(SUM(size), 0) FROM entries", [], |row| row.get(0))
+        .map_err(sqlite_error)?;
+    Ok(total.max(0) as u64)
+}
+
+impl CacheStorage for SqliteStorage {
+    fn get(&self, key: &str) -> Result<Option<StoredEntry>> {
+        let connection = self.lock()?;
+        let row = connection
+            .query_row(
+                "SELECT data, stored_at, source_size, source_mtime FROM entries WHERE key = ?1",
+                params![key],
+                |row| {
+                    let source_size: Option<i64> = row.get(2)?;
+                    let source_mtime: Option<i64> = row.get(3)?;
+                    Ok(StoredEntry {
+                        data: row.get(0)?,
+                        stored_at: from_millis(row.get(1)?),
+                        source: source_size.zip(source_mtime).map(|(size, mtime)| SourceStamp {
+                            size: size as u64,
+                            mtime_secs: mtime as u64,
+                        }),
+                    })
+                },
+            )
+            .optional()
+            .map_err(sqlite_error)?;
+
+        if row.is_some() {
+            connection
+                .execute(
+                    "UPDATE entries SET accessed_at = ?1 WHERE key = ?2",
+                    params![now_millis(), key],
+                )
+                .map_err(sqlite_error)?;
+        }
+        Ok(row)
+    }
+
+    fn set(&self, key: &str, data: &[u8], source: Option<SourceStamp>) -> Result<()> {
+        let now = now_millis();
+        self.lock()?
+            .execute(
+                "INSERT OR REPLACE INTO entries
+                     (key, data, size, stored_at, accessed_at, source_size, source_mtime)
+                 VALUES (?1, ?2, ?3, ?4, ?4, ?5, ?6)",
+                params![
+                    key,
+                    data,
+                    data.len() as i64,
+                    now,
+                    source.map(|s| s.size as i64),
+                    source.map(|s| s.mtime_secs as i64),
+                ],
+            )
+            .map_err(sqlite_error)?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        self.lock()?
+            .execute("DELETE FROM entries WHERE key = ?1", params![key])
+            .map_err(sqlite_error)?;
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(usize, f64)> {
+        let connection = self.lock()?;
+        let freed = total_bytes(&connection)?;
+        let removed = connection.execute("DELETE FROM entries", []).map_err(sqlite_error)?;
+        // Space freed by deleted rows is only reused, never returned, without a vacuum ~keep
+        if let Err(e) = connection.execute_batch("VACUUM") {
+            tracing::debug!("Failed to vacuum SQLite cache: {}", e);
+        }
+        Ok((removed, bytes_to_mb(freed)))
+    }
+
+    fn stats(&self) -> Result<CacheStats> {
+        let (count, size, oldest, newest) = self
+            .lock()?
+            .query_row(
+                "SELECT COUNT(*), COALESCE(SUM(size), 0), MIN(stored_at), MAX(stored_at) FROM entries",
+                [],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, i64>(1)?,
+                        row.get::<_, Option<i64>>(2)?,
+                        row.get::<_, Option<i64>>(3)?,
+                    ))
+                },
+            )
+            .map_err(sqlite_error)?;
+
+        let now = now_millis();
+        let age_days = |stored: Option<i64>| stored.map_or(0.0, |ms| (now - ms).max(0) as f64 / 86_400_000.0);
+        Ok(CacheStats {
+            total_files: count.max(0) as usize,
+            total_size_mb: bytes_to_mb(size.max(0) as u64),
+            available_space_mb: self.available_space_mb()?,
+            oldest_file_age_days: age_days(oldest),
+            newest_file_age_days: age_days(newest),
+            hits: 0,
+            misses: 0,
+        })
+    }
+
+    fn cleanup(&self, limits: &CacheLimits) -> Result<(usize, f64)> {
+        let low_on_space = self.available_space_mb()? < limits.min_free_space_mb;
+        let connection = self.lock()?;
+
+        let before = total_bytes(&connection)?;
+        let cutoff = now_millis() - (limits.max_age_days * 86_400_000.0) as i64;
+        let mut removed = connection
+            .execute("DELETE FROM entries WHERE stored_at < ?1", params![cutoff])
+            .map_err(sqlite_error)?;
+
+        let max_bytes = (limits.max_size_mb.max(0.0) * 1024.0 * 1024.0) as u64;
+        let mut remaining = total_bytes(&connection)?;
+        if remaining > max_bytes || low_on_space {
+            let target_ratio = if low_on_space { 0.5 } else { 0.8 };
+            let target = (max_bytes as f64 * target_ratio) as u64;
+
+            let mut statement = connection
+                .prepare("SELECT key, size FROM entries ORDER BY accessed_at ASC")
+                .map_err(sqlite_error)?;
+            let mut rows = statement.query([]).map_err(sqlite_error)?;
+            let mut evicted = Vec::new();
+            while remaining > target
+                && let Some(row) = rows.next().map_err(sqlite_error)?
+            {
+                let key: String = row.get(0).map_err(sqlite_error)?;
+                let size: i64 = row.get(1).map_err(sqlite_error)?;
+                remaining = remaining.saturating_sub(size.max(0) as u64);
+                evicted.push(key);
+            }
+            drop(rows);
+
+            for key in evicted {
+                removed += connection
+                    .execute("DELETE FROM entries WHERE key = ?1", params![key])
+                    .map_err(sqlite_error)?;
+            }
+        }
+
+        let after = total_bytes(&connection)?;
+        Ok((removed, bytes_to_mb(before.saturating_sub(after))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_sqlite_storage_round_trip() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteStorage::new(dir.path().to_path_buf()).unwrap();
+        assert!(storage.database_path().is_file());
+
+        let stamp = SourceStamp {
+            size: 12,
+            mtime_secs: 34,
+        };
+        storage.set("key", b"data", Some(stamp)).unwrap();
+        storage.set("plain", b"other", None).unwrap();
+
+        let entry = storage.get("key").unwrap().unwrap();
+        assert_eq!(entry.data, b"data");
+        assert_eq!(entry.source, Some(stamp));
+        assert_eq!(storage.get("plain").unwrap().unwrap().source, None);
+        assert!(storage.get("missing").unwrap().is_none());
+
+        storage.remove("key").unwrap();
+        assert!(storage.get("key").unwrap().is_none());
+        assert_eq!(storage.stats().unwrap().total_files, 1);
+    }
+
+    #[test]
+    fn test_sqlite_storage_persists_across_reopen() {
+        let dir = tempdir().unwrap();
+        SqliteStorage::new(dir.path().to_path_buf())
+            .unwrap()
+            .set("key", b"data", None)
+            .unwrap();
+
+        let reopened = SqliteStorage::new(dir.path().to_path_buf()).unwrap();
+        assert_eq!(reopened.get("key").unwrap().unwrap().data, b"data");
+        assert_eq!(reopened.clear().unwrap().0, 1);
+        assert!(reopened.get("key").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_sqlite_storage_cleanup_evicts_least_recently_used() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteStorage::new(dir.path().to_path_buf()).unwrap();
+        let half_megabyte = vec![0u8; 512 * 1024];
+        for key in ["a", "b", "c", "d"] {
+            storage.set(key, &half_megabyte, None).unwrap();
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        storage.get("a").unwrap();
+
+        let limits = CacheLimits {
+            max_age_days: 30.0,
+            max_size_mb: 1.0,
+            min_free_space_mb: 0.0,
+        };
+        let (removed, freed) = storage.cleanup(&limits).unwrap();
+
+        assert_eq!(removed, 3);
+        assert_eq!(freed, 1.5);
+        assert!(storage.get("a").unwrap().is_some(), "recently read entry is kept");
+        assert!(storage.get("d").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_sqlite_storage_cleanup_removes_expired() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteStorage::new(dir.path().to_path_buf()).unwrap();
+        storage.set("old", b"data", None).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+
+        let limits = CacheLimits {
+            max_age_days: 0.000_000_1,
+            max_size_mb: 100.0,
+            min_free_space_mb: 0.0,
+        };
+        assert_eq!(storage.cleanup(&limits).unwrap().0, 1);
+        assert_eq!(storage.stats().unwrap().total_files, 0);
+    }
+}
@@ -80,6 +80,59 @@ where
     Ok(chunks)
 }
 
+/// Build chunks from text segments with exact byte offsets.
+///
+/// Used when the splitter reports where each segment starts, so offsets do not
+/// have to be reconstructed from the overlap. `count_tokens` fills in
+/// `ChunkMetadata::token_count` for every chunk, or returns `None` when sizes
+/// are not measured in tokens.
+///
+/// # Errors
+///
+/// Returns an error if page boundary calculation fails.
+pub fn build_indexed_chunks<'a, I>(
+    indexed_chunks: I,
+    page_boundaries: Option<&[PageBoundary]>,
+    count_tokens: impl Fn(&str) -> Option<usize>,
+) -> Result<Vec<Chunk>>
+where
+    I: IntoIterator<Item = (usize, &'a str)>,
+{
+    let indexed_chunks: Vec<(usize, &str)> = indexed_chunks.into_iter().collect();
+    let total_chunks = indexed_chunks.len();
+
+    indexed_chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, (byte_start, chunk_text))| {
+            let byte_end = byte_start + chunk_text.len();
+            let (first_page, last_page) = match page_boundaries {
+                Some(boundaries) => calculate_page_range(byte_start, byte_end, boundaries)?,
+                None => (None, None),
+            };
+
+            Ok(Chunk {
+                content: chunk_text.to_string(),
+                embedding: None,
+                metadata: ChunkMetadata {
+                    byte_start,
+                    byte_end,
+                    token_count: count_tokens(chunk_text),
+                    chunk_index: index,
+                    total_chunks,
+                    first_page,
+                    last_page,
+                    heading_path: Vec::new(),
+                    element_types: Vec::new(),
+                    node_ids: Vec::new(),
+                    language: None,
+                    language_confidence: None,
+                },
+            })
+        })
+        .collect()
+}
+
 /// Build a single chunk with metadata.
 ///
 /// # Arguments
@@ -138,6 +191,11 @@ fn build_single_chunk(
             total_chunks,
             first_page,
             last_page,
+            heading_path: Vec::new(),
+            element_types: Vec::new(),
+            node_ids: Vec::new(),
+            language: None,
+            language_confidence: None,
         },
     })
 }
@@ -274,6 +332,32 @@ mod tests {
         assert_eq!(offset, 17); // Last chunk, no overlap subtracted
     }
 
+    #[test]
+    fn test_build_indexed_chunks_uses_offsets_and_counts_tokens() {
+        let boundaries = vec![
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 10,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 10,
+                byte_end: 20,
+                page_number: 2,
+            },
+        ];
+        let chunks = vec![(0, "one two"), (4, "two three"), (14, "four")];
+        let result =
+            build_indexed_chunks(chunks, Some(&boundaries), |text| Some(text.split_whitespace().count())).unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[1].metadata.byte_start, 4);
+        assert_eq!(result[1].metadata.byte_end, 13);
+        assert_eq!(result[1].metadata.token_count, Some(2));
+        assert_eq!(result[1].metadata.total_chunks, 3);
+        assert_eq!(result[2].metadata.first_page, Some(2));
+    }
+
     #[test]
     fn test_build_chunks_no_overlap() {
         let text_chunks = vec!["AAAAA", "BBBBB", "CCCCC"];
@@ -3,7 +3,9 @@
 use serde::{Deserialize, Serialize};
 
 // Re-export ChunkingConfig and ChunkerType from core config (canonical location)
-pub use crate::core::config::processing::{ChunkerType, ChunkingConfig};
+pub use crate::core::config::processing::{
+    ChunkSizing, ChunkerType, ChunkingConfig, SemanticChunkingConfig, SemanticThreshold, TokenizerFormat,
+};
 
 /// Result of a text chunking operation.
 ///
@@ -3,12 +3,14 @@
 //! This module implements the main chunking algorithms and provides the primary
 //! public API functions for splitting text into chunks.
 
+use crate::core::config::ChunkSizing;
 use crate::error::Result;
-use crate::types::PageBoundary;
+use crate::types::{Chunk, PageBoundary};
 use text_splitter::{MarkdownSplitter, TextSplitter};
 
 use super::builder::{build_chunk_config, build_chunks};
 use super::config::{ChunkerType, ChunkingConfig, ChunkingResult};
+use super::semantic::chunk_semantic;
 use super::validation::validate_utf8_boundaries;
 
 /// Split text into chunks with optional page boundary tracking.
@@ -16,6 +18,17 @@ use super::validation::validate_utf8_boundaries;
 /// This is the primary API function for chunking text. It supports both plain text
 /// and Markdown with configurable chunk size, overlap, and page boundary mapping.
 ///
+/// With [`ChunkSizing::Tokens`], sizes and overlaps are measured in tokens and
+/// every chunk's `token_count` is filled in.
+///
+/// Plain text carries no document tree, so `ChunkerType::Structure` is treated
+/// as `Markdown` here; use [`chunk_document_structure`](super::chunk_document_structure)
+/// to chunk along sections.
+///
+/// `ChunkerType::Semantic` embeds every sentence with the model of
+/// `config.embedding` (the default preset if unset) and requires the
+/// `embeddings` feature; see [`chunk_semantic_with`](super::chunk_semantic_with).
+///
 /// # Arguments
 ///
 /// * `text` - The text to split into chunks
@@ -59,25 +72,70 @@ pub fn chunk_text(
         validate_utf8_boundaries(text, boundaries)?;
     }
 
-    let chunk_config = build_chunk_config(config.max_characters, config.overlap, config.trim)?;
+    let chunks = match config.sizing {
+        _ if config.chunker_type == ChunkerType::Semantic => chunk_semantic(text, config, page_boundaries)?,
+        ChunkSizing::Characters => {
+            let chunk_config = build_chunk_config(config.max_characters, config.overlap, config.trim)?;
+
+            let text_chunks: Vec<&str> = match config.chunker_type {
+                ChunkerType::Text | ChunkerType::Semantic => {
+                    let splitter = TextSplitter::new(chunk_config);
+                    splitter.chunks(text).collect()
+                }
+                ChunkerType::Markdown | ChunkerType::Structure => {
+                    let splitter = MarkdownSplitter::new(chunk_config);
+                    splitter.chunks(text).collect()
+                }
+            };
 
-    let text_chunks: Vec<&str> = match config.chunker_type {
-        ChunkerType::Text => {
-            let splitter = TextSplitter::new(chunk_config);
-            splitter.chunks(text).collect()
-        }
-        ChunkerType::Markdown => {
-            let splitter = MarkdownSplitter::new(chunk_config);
-            splitter.chunks(text).collect()
+            build_chunks(text_chunks.into_iter(), config.overlap, page_boundaries)?
         }
+        ChunkSizing::Tokens { .. } => chunk_by_tokens(text, config, page_boundaries)?,
     };
-
-    let chunks = build_chunks(text_chunks.into_iter(), config.overlap, page_boundaries)?;
     let chunk_count = chunks.len();
 
     Ok(ChunkingResult { chunks, chunk_count })
 }
 
+/// Split text with sizes measured by the tokenizer configured in `config.sizing`.
+#[cfg(feature = "chunking-tokens")]
+fn chunk_by_tokens(
+    text: &str,
+    config: &ChunkingConfig,
+    page_boundaries: Option<&[PageBoundary]>,
+) -> Result<Vec<Chunk>> {
+    use super::builder::build_indexed_chunks;
+    use super::tokenizer::token_counter;
+
+    let Some(counter) = token_counter(&config.sizing)? else {
+        return Err(crate::KreuzbergError::validation(
+            "Token sizing requires a tokenizer".to_string(),
+        ));
+    };
+    let chunk_config =
+        build_chunk_config(config.max_characters, config.overlap, config.trim)?.with_sizer(counter.as_ref());
+
+    let indexed_chunks: Vec<(usize, &str)> = match config.chunker_type {
+        ChunkerType::Text | ChunkerType::Semantic => TextSplitter::new(chunk_config).chunk_indices(text).collect(),
+        ChunkerType::Markdown | ChunkerType::Structure => {
+            MarkdownSplitter::new(chunk_config).chunk_indices(text).collect()
+        }
+    };
+
+    build_indexed_chunks(indexed_chunks, page_boundaries, |chunk| Some(counter.count(chunk)))
+}
+
+#[cfg(not(feature = "chunking-tokens"))]
+fn chunk_by_tokens(
+    _text: &str,
+    _config: &ChunkingConfig,
+    _page_boundaries: Option<&[PageBoundary]>,
+) -> Result<Vec<Chunk>> {
+    Err(crate::KreuzbergError::validation(
+        "Token-based chunk sizing requires the 'chunking-tokens' feature".to_string(),
+    ))
+}
+
 /// Chunk text with explicit type specification.
 ///
 /// This is a convenience function that constructs a ChunkingConfig from individual
@@ -120,6 +178,9 @@ pub fn chunk_text_with_type(
         chunker_type,
         embedding: None,
         preset: None,
+        sizing: ChunkSizing::Characters,
+        prepend_heading_context: false,
+        semantic: Default::default(),
     };
     chunk_text(text, &config, None)
 }
@@ -181,6 +242,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "This is a short text.";
         let result = chunk_text(text, &config, None).unwrap();
@@ -198,6 +262,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -215,6 +282,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "abcdefghijklmnopqrstuvwxyz0123456789";
         let result = chunk_text(text, &config, None).unwrap();
@@ -240,6 +310,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let markdown = "# Title\n\nParagraph one.\n\n## Section\n\nParagraph two.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -256,6 +329,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let markdown = "# Code Example\n\n```python\nprint('hello')\n```\n\nSome text after code.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -272,6 +348,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let markdown = "Check out [this link](https://example.com) for more info.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -288,6 +367,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "  Leading and trailing spaces  should be trimmed  ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -304,6 +386,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "  Text with spaces  ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -320,6 +405,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let result = chunk_text("Some text", &config, None);
         assert!(result.is_err());
@@ -359,6 +447,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let texts = vec!["First text", "Second text", "Third text"];
         let results = chunk_texts_batch(&texts, &config).unwrap();
@@ -375,6 +466,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let texts = vec![
             "Short",
@@ -397,6 +491,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let texts = vec!["Text one", "Text two"];
         let result = chunk_texts_batch(&texts, &config);
@@ -421,6 +518,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "a".repeat(1000);
         let result = chunk_text(&text, &config, None).unwrap();
@@ -437,6 +537,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Line one\nLine two\nLine three\nLine four\nLine five";
         let result = chunk_text(text, &config, None).unwrap();
@@ -452,6 +555,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let markdown = "# List Example\n\n- Item 1\n- Item 2\n- Item 3\n\nMore text.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -468,6 +574,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let markdown = "# Table\n\n| Col1 | Col2 |\n|------|------|\n| A    | B    |\n| C    | D    |";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -484,6 +593,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Special chars: @#$%^&*()[]{}|\\<>?/~`";
         let result = chunk_text(text, &config, None).unwrap();
@@ -500,6 +612,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Unicode: 你好世界 🌍 café résumé";
         let result = chunk_text(text, &config, None).unwrap();
@@ -517,6 +632,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "日本語のテキストです。これは長い文章で、複数のチャンクに分割されるべきです。";
         let result = chunk_text(text, &config, None).unwrap();
@@ -532,6 +650,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "English text mixed with 中文文本 and some français";
         let result = chunk_text(text, &config, None).unwrap();
@@ -547,6 +668,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE FFFFF";
         let result = chunk_text(text, &config, None).unwrap();
@@ -601,6 +725,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE FFFFF";
         let result = chunk_text(text, &config, None).unwrap();
@@ -629,6 +756,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "0123456789 ABCDEFGHIJ KLMNOPQRST UVWXYZ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -665,6 +795,9 @@ mod tests {
                 chunker_type: ChunkerType::Text,
                 embedding: None,
                 preset: None,
+                sizing: ChunkSizing::Characters,
+                prepend_heading_context: false,
+                semantic: Default::default(),
             };
             let text = "Word ".repeat(30);
             let result = chunk_text(&text, &config, None).unwrap();
@@ -699,6 +832,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE";
         let result = chunk_text(text, &config, None).unwrap();
@@ -728,6 +864,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Page one content here. Page two starts here and continues.";
 
@@ -762,6 +901,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "This is some test content that should be split into multiple chunks.";
 
@@ -783,6 +925,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Some text content here.";
         let boundaries: Vec<PageBoundary> = vec![];
@@ -803,6 +948,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "0123456789 AAAAAAAAAA 1111111111 BBBBBBBBBB 2222222222";
 
@@ -841,6 +989,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Page one content here. Page two content.";
 
@@ -866,6 +1017,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Page one content here. Page two content.";
 
@@ -898,6 +1052,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Page one content here. Page two content.";
 
@@ -930,6 +1087,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "First page content here.Second page content here.Third page.";
 
@@ -967,6 +1127,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "All content on single page fits in one chunk.";
 
@@ -991,6 +1154,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "AAAAA BBBBB CCCCC DDDDD";
 
@@ -1026,6 +1192,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "Page One Content Here.Page Two.";
 
@@ -1058,6 +1227,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         let text = "0123456789ABCDEFGHIJ";
 
@@ -1090,4 +1262,102 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "chunking-tokens")]
+    fn token_config(tokenizer: std::path::PathBuf, max_tokens: usize, overlap: usize) -> ChunkingConfig {
+        ChunkingConfig {
+            max_characters: max_tokens,
+            overlap,
+            sizing: ChunkSizing::Tokens {
+                tokenizer,
+                format: crate::core::config::TokenizerFormat::Auto,
+                pattern: None,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "chunking-tokens")]
+    #[test]
+    fn test_chunk_by_tiktoken_tokens() {
+        use crate::chunking::tokenizer::test_files::write_tiktoken_file;
+
+        let dir = tempfile::tempdir().unwrap();
+        let config = token_config(write_tiktoken_file(dir.path()), 4, 0);
+        let text = "hello world hello world hello world hello world";
+
+        let result = chunk_text(text, &config, None).unwrap();
+
+        assert_eq!(result.chunk_count, 2);
+        for chunk in &result.chunks {
+            assert_eq!(chunk.metadata.token_count, Some(4));
+            assert_eq!(&text[chunk.metadata.byte_start..chunk.metadata.byte_end], chunk.content);
+        }
+    }
+
+    #[cfg(feature = "chunking-tokens")]
+    #[test]
+    fn test_chunk_by_huggingface_tokens_with_overlap_and_pages() {
+        use crate::chunking::tokenizer::test_files::write_huggingface_file;
+
+        let dir = tempfile::tempdir().unwrap();
+        let config = token_config(write_huggingface_file(dir.path()), 3, 1);
+        let text = "hello world hello world hello world";
+        let boundaries = vec![
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 17,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 17,
+                byte_end: text.len(),
+                page_number: 2,
+            },
+        ];
+
+        let result = chunk_text(text, &config, Some(&boundaries)).unwrap();
+
+        assert!(result.chunk_count > 1);
+        for chunk in &result.chunks {
+            assert!(chunk.metadata.token_count.unwrap() <= 3);
+            assert_eq!(&text[chunk.metadata.byte_start..chunk.metadata.byte_end], chunk.content);
+        }
+        for pair in result.chunks.windows(2) {
+            assert!(
+                pair[1].metadata.byte_start < pair[0].metadata.byte_end,
+                "chunks overlap"
+            );
+        }
+        assert_eq!(result.chunks.last().unwrap().metadata.last_page, Some(2));
+    }
+
+    #[cfg(feature = "chunking-tokens")]
+    #[test]
+    fn test_chunk_by_tokens_missing_tokenizer() {
+        let config = token_config("/nonexistent/tokenizer.json".into(), 100, 10);
+        let result = chunk_text("Some text", &config, None);
+        assert!(matches!(result.unwrap_err(), KreuzbergError::Validation { .. }));
+    }
+
+    #[cfg(not(feature = "chunking-tokens"))]
+    #[test]
+    fn test_chunk_by_tokens_requires_feature() {
+        let config = ChunkingConfig {
+            sizing: ChunkSizing::Tokens {
+                tokenizer: "tokenizer.json".into(),
+                format: Default::default(),
+                pattern: None,
+            },
+            ..Default::default()
+        };
+        let err = chunk_text("Some text", &config, None).unwrap_err();
+        assert!(err.to_string().contains("chunking-tokens"));
+    }
+
+    #[test]
+    fn test_chunk_by_characters_leaves_token_count_empty() {
+        let result = chunk_text("Some text", &ChunkingConfig::default(), None).unwrap();
+        assert_eq!(result.chunks[0].metadata.token_count, None);
+    }
 }
@@ -0,0 +1,66 @@
+//! Size measurement shared by the structure and semantic chunkers.
+
+use text_splitter::TextSplitter;
+
+use crate::core::config::ChunkSizing;
+use crate::error::Result;
+
+use super::builder::build_chunk_config;
+
+/// Size measurement in the configured unit.
+pub(crate) enum Measure {
+    Characters,
+    #[cfg(feature = "chunking-tokens")]
+    Tokens(std::sync::Arc<super::tokenizer::TokenCounter>),
+}
+
+impl Measure {
+    pub(crate) fn new(sizing: &ChunkSizing) -> Result<Self> {
+        match sizing {
+            ChunkSizing::Characters => Ok(Measure::Characters),
+            #[cfg(feature = "chunking-tokens")]
+            ChunkSizing::Tokens { .. } => Ok(super::tokenizer::token_counter(sizing)?
+                .map(Measure::Tokens)
+                .unwrap_or(Measure::Characters)),
+            #[cfg(not(feature = "chunking-tokens"))]
+            ChunkSizing::Tokens { .. } => Err(crate::KreuzbergError::validation(
+                "Token-based chunk sizing requires the 'chunking-tokens' feature".to_string(),
+            )),
+        }
+    }
+
+    pub(crate) fn size(&self, text: &str) -> usize {
+        match self {
+            Measure::Characters => text.chars().count(),
+            #[cfg(feature = "chunking-tokens")]
+            Measure::Tokens(counter) => counter.count(text),
+        }
+    }
+
+    #[cfg_attr(not(feature = "chunking-tokens"), allow(unused_variables))]
+    pub(crate) fn token_count(&self, text: &str) -> Option<usize> {
+        match self {
+            Measure::Characters => None,
+            #[cfg(feature = "chunking-tokens")]
+            Measure::Tokens(counter) => Some(counter.count(text)),
+        }
+    }
+
+    /// Split `text` into pieces of at most `capacity` units, with their byte offsets.
+    pub(crate) fn split<'t>(
+        &self,
+        text: &'t str,
+        capacity: usize,
+        overlap: usize,
+        trim: bool,
+    ) -> Result<Vec<(usize, &'t str)>> {
+        let chunk_config = build_chunk_config(capacity, overlap, trim)?;
+        Ok(match self {
+            Measure::Characters => TextSplitter::new(chunk_config).chunk_indices(text).collect(),
+            #[cfg(feature = "chunking-tokens")]
+            Measure::Tokens(counter) => TextSplitter::new(chunk_config.with_sizer(counter.as_ref()))
+                .chunk_indices(text)
+                .collect(),
+        })
+    }
+}
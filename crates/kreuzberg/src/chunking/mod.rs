@@ -15,6 +15,9 @@
 //!
 //! - **Text**: Generic text splitter, splits on whitespace and punctuation
 //! - **Markdown**: Markdown-aware splitter, preserves formatting and structure
+//! - **Structure**: Splits along the sections of the document tree and records
+//!   the heading path of each chunk
+//! - **Semantic**: Splits between sentences where embedding similarity drops
 //!
 //! # Example
 //!
@@ -55,14 +58,26 @@ pub mod boundaries;
 mod builder;
 pub mod config;
 pub mod core;
+mod measure;
 pub mod processor;
+pub mod semantic;
+pub mod structure;
+#[cfg(feature = "chunking-tokens")]
+pub mod tokenizer;
 pub mod validation;
 
 // Re-export submodule types and functions
 pub use boundaries::{calculate_page_range, validate_page_boundaries};
-pub use config::{ChunkerType, ChunkingConfig, ChunkingResult}; // ChunkingConfig re-exported from core::config::processing
+pub use config::{
+    ChunkSizing, ChunkerType, ChunkingConfig, ChunkingResult, SemanticChunkingConfig, SemanticThreshold,
+    TokenizerFormat,
+}; // ChunkingConfig re-exported from core::config::processing
 pub use core::{chunk_text, chunk_text_with_type, chunk_texts_batch};
 pub use processor::ChunkingProcessor;
+pub use semantic::chunk_semantic_with;
+pub use structure::{chunk_document_structure, chunk_extraction_result};
+#[cfg(feature = "chunking-tokens")]
+pub use tokenizer::{TokenCounter, token_counter};
 pub use validation::{ADAPTIVE_VALIDATION_THRESHOLD, precompute_utf8_boundaries, validate_utf8_boundaries};
 
 use crate::error::Result;
@@ -54,8 +54,13 @@ impl PostProcessor for ChunkingProcessor {
             None => return Ok(()),
         };
 
-        let chunking_result = crate::chunking::chunk_text(&result.content, chunking_config, None)
-            .map_err(|e| KreuzbergError::Other(format!("Chunking failed: {}", e)))?;
+        let chunking_result = match chunking_config.chunker_type {
+            crate::chunking::ChunkerType::Structure => {
+                crate::chunking::chunk_extraction_result(result, chunking_config)
+            }
+            _ => crate::chunking::chunk_text(&result.content, chunking_config, None),
+        }
+        .map_err(|e| KreuzbergError::Other(format!("Chunking failed: {}", e)))?;
         result.chunks = Some(chunking_result.chunks);
 
         Ok(())
@@ -93,6 +98,9 @@ mod tests {
                 chunker_type: crate::chunking::ChunkerType::Text,
                 embedding: None,
                 preset: None,
+                sizing: Default::default(),
+                prepend_heading_context: false,
+                semantic: Default::default(),
             }),
             ..Default::default()
         };
@@ -103,6 +111,7 @@ mod tests {
 	            metadata: Metadata::default(),
 	            tables: vec![],
 	            detected_languages: None,
+	            language_spans: None,
 	            chunks: None,
 	            images: None,
             djot_content: None,
@@ -114,6 +123,7 @@ mod tests {
 	            extracted_keywords: None,
 	            quality_score: None,
 	            processing_warnings: Vec::new(),
+	            children: None,
 	        };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -134,6 +144,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -145,6 +156,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -177,6 +189,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -188,6 +201,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let config_with_chunking = ExtractionConfig {
@@ -198,6 +212,9 @@ mod tests {
                 chunker_type: crate::chunking::ChunkerType::Text,
                 embedding: None,
                 preset: None,
+                sizing: Default::default(),
+                prepend_heading_context: false,
+                semantic: Default::default(),
             }),
             ..Default::default()
         };
@@ -217,6 +234,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -228,6 +246,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let long_result = ExtractionResult {
@@ -236,6 +255,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -247,6 +267,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -0,0 +1,387 @@
+//! Semantic chunking driven by sentence embeddings.
+//!
+//! The text is split into sentences, every sentence is embedded, and a chunk
+//! boundary is placed between two neighbouring sentences when the cosine
+//! distance of their embeddings exceeds the configured threshold:
+//!
+//! - `Percentile`: the given percentile of all distances in the text.
+//! - `StandardDeviation`: the mean distance plus the given number of standard deviations.
+//!
+//! Size guards are applied on top. A chunk never grows beyond `max_characters`;
+//! a single sentence that is larger on its own is split with the text splitter.
+//! Chunks smaller than `semantic.min_characters` are merged into the previous
+//! chunk when the result still fits. Semantic chunks do not overlap.
+
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::core::config::SemanticThreshold;
+use crate::error::{KreuzbergError, Result};
+use crate::types::{Chunk, PageBoundary};
+
+use super::builder::build_indexed_chunks;
+use super::config::{ChunkingConfig, ChunkingResult};
+use super::measure::Measure;
+use super::validation::validate_utf8_boundaries;
+
+/// Split `text` semantically, embedding sentences with the model of `config.embedding`.
+#[cfg(feature = "embeddings")]
+pub(crate) fn chunk_semantic(
+    text: &str,
+    config: &ChunkingConfig,
+    page_boundaries: Option<&[PageBoundary]>,
+) -> Result<Vec<Chunk>> {
+    let embedding = config.embedding.clone().unwrap_or_default();
+    semantic_chunks(text, config, page_boundaries, |sentences| {
+        crate::embeddings::embed_texts(
+            sentences.iter().map(|sentence| sentence.to_string()).collect(),
+            &embedding,
+        )
+    })
+}
+
+#[cfg(not(feature = "embeddings"))]
+pub(crate) fn chunk_semantic(
+    _text: &str,
+    _config: &ChunkingConfig,
+    _page_boundaries: Option<&[PageBoundary]>,
+) -> Result<Vec<Chunk>> {
+    Err(KreuzbergError::validation(
+        "Semantic chunking requires the 'embeddings' feature".to_string(),
+    ))
+}
+
+/// Split `text` semantically with a caller-provided embedding function.
+///
+/// `embed` receives all sentences of the text at once and must return one
+/// vector per sentence. Use this to chunk with embeddings from a model other
+/// than the built-in fastembed presets.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the threshold or size configuration
+/// is invalid, or if `embed` returns the wrong number of vectors. Errors from
+/// `embed` are passed through.
+pub fn chunk_semantic_with<F>(
+    text: &str,
+    config: &ChunkingConfig,
+    page_boundaries: Option<&[PageBoundary]>,
+    embed: F,
+) -> Result<ChunkingResult>
+where
+    F: FnOnce(&[&str]) -> Result<Vec<Vec<f32>>>,
+{
+    if let Some(boundaries) = page_boundaries {
+        validate_utf8_boundaries(text, boundaries)?;
+    }
+
+    let chunks = semantic_chunks(text, config, page_boundaries, embed)?;
+    let chunk_count = chunks.len();
+    Ok(ChunkingResult { chunks, chunk_count })
+}
+
+fn semantic_chunks<F>(
+    text: &str,
+    config: &ChunkingConfig,
+    page_boundaries: Option<&[PageBoundary]>,
+    embed: F,
+) -> Result<Vec<Chunk>>
+where
+    F: FnOnce(&[&str]) -> Result<Vec<Vec<f32>>>,
+{
+    validate_threshold(config.semantic.threshold)?;
+    if config.max_characters == 0 {
+        return Err(KreuzbergError::validation(
+            "Invalid chunking configuration: max_characters must be greater than 0".to_string(),
+        ));
+    }
+
+    let measure = Measure::new(&config.sizing)?;
+    let sentences = split_sentences(text);
+    if sentences.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let texts: Vec<&str> = sentences.iter().map(|(_, sentence)| *sentence).collect();
+    let embeddings = embed(&texts)?;
+    if embeddings.len() != sentences.len() {
+        return Err(KreuzbergError::validation(format!(
+            "Embedding model returned {} vectors for {} sentences",
+            embeddings.len(),
+            sentences.len()
+        )));
+    }
+
+    let distances: Vec<f64> = embeddings
+        .windows(2)
+        .map(|pair| cosine_distance(&pair[0], &pair[1]))
+        .collect();
+    let threshold = breakpoint_threshold(&distances, config.semantic.threshold);
+
+    // Text from the first to the last sentence of a group, including the whitespace between them
+    let span = |group: &Range<usize>| -> (usize, &str) {
+        let start = sentences[group.start].0;
+        let (last_start, last) = sentences[group.end - 1];
+        (start, &text[start..last_start + last.len()])
+    };
+    let max = config.max_characters;
+    let min = config.semantic.min_characters;
+
+    let mut groups: Vec<Range<usize>> = Vec::new();
+    let mut current = 0..1;
+    for index in 1..sentences.len() {
+        let extended = current.start..index + 1;
+        if distances[index - 1] > threshold || measure.size(span(&extended).1) > max {
+            groups.push(current);
+            current = index..index + 1;
+        } else {
+            current = extended;
+        }
+    }
+    groups.push(current);
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(groups.len());
+    for group in groups {
+        if let Some(previous) = merged.last_mut() {
+            let combined = previous.start..group.end;
+            let too_small = measure.size(span(previous).1) < min || measure.size(span(&group).1) < min;
+            if too_small && measure.size(span(&combined).1) <= max {
+                *previous = combined;
+                continue;
+            }
+        }
+        merged.push(group);
+    }
+
+    let mut pieces: Vec<(usize, &str)> = Vec::with_capacity(merged.len());
+    for group in &merged {
+        let (start, piece) = span(group);
+        if measure.size(piece) > max {
+            // Only a single sentence can exceed the limit here
+            pieces.extend(
+                measure
+                    .split(piece, max, 0, true)?
+                    .into_iter()
+                    .map(|(offset, part)| (start + offset, part)),
+            );
+        } else {
+            pieces.push((start, piece));
+        }
+    }
+
+    build_indexed_chunks(pieces, page_boundaries, |chunk| measure.token_count(chunk))
+}
+
+/// Sentences of `text` (Unicode sentence boundaries), trimmed, with their byte offsets.
+fn split_sentences(text: &str) -> Vec<(usize, &str)> {
+    text.split_sentence_bound_indices()
+        .filter_map(|(start, sentence)| {
+            let trimmed = sentence.trim();
+            let leading = sentence.len() - sentence.trim_start().len();
+            (!trimmed.is_empty()).then_some((start + leading, trimmed))
+        })
+        .collect()
+}
+
+fn validate_threshold(threshold: SemanticThreshold) -> Result<()> {
+    match threshold {
+        SemanticThreshold::Percentile { value } if !(0.0..=100.0).contains(&value) => {
+            Err(KreuzbergError::validation(format!(
+                "Invalid semantic threshold: percentile must be between 0 and 100, got {}",
+                value
+            )))
+        }
+        SemanticThreshold::StandardDeviation { value } if !value.is_finite() => Err(KreuzbergError::validation(
+            "Invalid semantic threshold: standard deviation multiplier must be finite".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Cosine distance (1 - cosine similarity); zero vectors are treated as unrelated.
+fn cosine_distance(a: &[f32], b: &[f32]) -> f64 {
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f64, 0.0f64, 0.0f64);
+    for (&x, &y) in a.iter().zip(b) {
+        let (x, y) = (f64::from(x), f64::from(y));
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 1.0;
+    }
+    1.0 - dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// Distance above which a new chunk starts.
+fn breakpoint_threshold(distances: &[f64], threshold: SemanticThreshold) -> f64 {
+    if distances.is_empty() {
+        return f64::INFINITY;
+    }
+
+    match threshold {
+        SemanticThreshold::Percentile { value } => {
+            let mut sorted = distances.to_vec();
+            sorted.sort_by(f64::total_cmp);
+            let rank = value / 100.0 * (sorted.len() - 1) as f64;
+            let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+        }
+        SemanticThreshold::StandardDeviation { value } => {
+            let count = distances.len() as f64;
+            let mean = distances.iter().sum::<f64>() / count;
+            let variance = distances.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / count;
+            mean + value * variance.sqrt()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunking::ChunkerType;
+    use crate::core::config::SemanticChunkingConfig;
+
+    const TEXT: &str = "Cats purr softly. Cats sleep a lot. Cats chase mice. \
+                        Stocks rose today. Stock markets closed higher. Stock traders cheered.";
+
+    /// One axis per topic, so sentences about the same topic are identical.
+    fn topic_embeddings(sentences: &[&str]) -> Result<Vec<Vec<f32>>> {
+        Ok(sentences
+            .iter()
+            .map(|sentence| {
+                if sentence.contains("Cat") {
+                    vec![1.0, 0.0]
+                } else if sentence.contains("Stock") {
+                    vec![0.0, 1.0]
+                } else {
+                    vec![0.5, 0.5]
+                }
+            })
+            .collect())
+    }
+
+    fn semantic_config(max_characters: usize, threshold: SemanticThreshold, min_characters: usize) -> ChunkingConfig {
+        ChunkingConfig {
+            max_characters,
+            chunker_type: ChunkerType::Semantic,
+            semantic: SemanticChunkingConfig {
+                threshold,
+                min_characters,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_splits_where_topic_changes() {
+        let config = semantic_config(1000, SemanticThreshold::default(), 0);
+        let result = chunk_semantic_with(TEXT, &config, None, topic_embeddings).unwrap();
+
+        assert_eq!(result.chunk_count, 2);
+        assert_eq!(
+            result.chunks[0].content,
+            "Cats purr softly. Cats sleep a lot. Cats chase mice."
+        );
+        assert_eq!(
+            result.chunks[1].content,
+            "Stocks rose today. Stock markets closed higher. Stock traders cheered."
+        );
+        for chunk in &result.chunks {
+            assert_eq!(&TEXT[chunk.metadata.byte_start..chunk.metadata.byte_end], chunk.content);
+            assert_eq!(chunk.metadata.token_count, None);
+            assert_eq!(chunk.metadata.total_chunks, 2);
+        }
+    }
+
+    #[test]
+    fn test_standard_deviation_threshold() {
+        let config = semantic_config(1000, SemanticThreshold::StandardDeviation { value: 1.0 }, 0);
+        let result = chunk_semantic_with(TEXT, &config, None, topic_embeddings).unwrap();
+        assert_eq!(result.chunk_count, 2);
+        assert!(result.chunks[1].content.starts_with("Stocks rose today."));
+    }
+
+    #[test]
+    fn test_max_size_guard() {
+        let config = semantic_config(40, SemanticThreshold::default(), 0);
+        let text = format!(
+            "{} This one sentence is far longer than forty characters in total.",
+            TEXT
+        );
+        let result = chunk_semantic_with(&text, &config, None, topic_embeddings).unwrap();
+
+        assert!(result.chunk_count > 2);
+        for chunk in &result.chunks {
+            assert!(
+                chunk.content.chars().count() <= 40,
+                "chunk too large: {:?}",
+                chunk.content
+            );
+            assert_eq!(&text[chunk.metadata.byte_start..chunk.metadata.byte_end], chunk.content);
+        }
+    }
+
+    #[test]
+    fn test_small_groups_are_merged() {
+        let text = "Cats purr. Stocks rose. Cats nap. Cats sleep.";
+        let config = semantic_config(1000, SemanticThreshold::Percentile { value: 10.0 }, 0);
+        let unmerged = chunk_semantic_with(text, &config, None, topic_embeddings).unwrap();
+        assert_eq!(unmerged.chunk_count, 3);
+
+        let config = semantic_config(1000, SemanticThreshold::Percentile { value: 10.0 }, 20);
+        let merged = chunk_semantic_with(text, &config, None, topic_embeddings).unwrap();
+        assert_eq!(merged.chunk_count, 2);
+        assert_eq!(merged.chunks[0].content, "Cats purr. Stocks rose.");
+    }
+
+    #[test]
+    fn test_page_boundaries() {
+        let boundary = TEXT.find("Stocks").unwrap();
+        let boundaries = vec![
+            PageBoundary {
+                byte_start: 0,
+                byte_end: boundary,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: boundary,
+                byte_end: TEXT.len(),
+                page_number: 2,
+            },
+        ];
+        let config = semantic_config(1000, SemanticThreshold::default(), 0);
+        let result = chunk_semantic_with(TEXT, &config, Some(&boundaries), topic_embeddings).unwrap();
+        assert_eq!(result.chunks[0].metadata.first_page, Some(1));
+        assert_eq!(result.chunks[1].metadata.first_page, Some(2));
+    }
+
+    #[test]
+    fn test_invalid_configuration() {
+        let config = semantic_config(1000, SemanticThreshold::Percentile { value: 150.0 }, 0);
+        assert!(matches!(
+            chunk_semantic_with(TEXT, &config, None, topic_embeddings),
+            Err(KreuzbergError::Validation { .. })
+        ));
+
+        let config = semantic_config(1000, SemanticThreshold::default(), 0);
+        let result = chunk_semantic_with(TEXT, &config, None, |_| Ok(vec![vec![1.0]]));
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_empty_text() {
+        let config = semantic_config(1000, SemanticThreshold::default(), 0);
+        let result = chunk_semantic_with("  \n ", &config, None, |_| panic!("nothing to embed")).unwrap();
+        assert_eq!(result.chunk_count, 0);
+    }
+
+    #[cfg(not(feature = "embeddings"))]
+    #[test]
+    fn test_requires_embeddings_feature() {
+        let config = semantic_config(1000, SemanticThreshold::default(), 0);
+        let result = crate::chunking::chunk_text(TEXT, &config, None);
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+    }
+}
@@ -0,0 +1,663 @@
+//! Structure-aware chunking over a [`DocumentStructure`] tree.
+//!
+//! Instead of splitting the flat content string, this chunker walks the document
+//! tree in reading order and packs whole nodes into chunks:
+//!
+//! - A heading (`Title`, `Heading`, or a `Group` carrying a heading) always starts
+//!   a new chunk, so chunks never span section boundaries.
+//! - Tables and code blocks are never split, even if they exceed the size limit.
+//! - Other nodes larger than the limit are split with the text splitter, using
+//!   the configured overlap.
+//!
+//! Each chunk records the heading path of its section, and the ids and node types
+//! of the nodes it was built from. Running headers and footers are skipped.
+
+use std::borrow::Cow;
+
+use crate::error::Result;
+use crate::types::{Chunk, ChunkMetadata, DocumentStructure, ExtractionResult, NodeContent, NodeIndex, TableGrid};
+
+use super::config::{ChunkingConfig, ChunkingResult};
+use super::measure::Measure;
+
+/// Separator placed between the nodes of a chunk.
+const NODE_SEPARATOR: &str = "\n\n";
+
+/// Separator placed between headings of the prepended heading path.
+const HEADING_SEPARATOR: &str = " > ";
+
+/// Chunk an extraction result along its document structure.
+///
+/// Uses `result.document` when the extractor already produced a tree, and
+/// derives one from the result otherwise.
+pub fn chunk_extraction_result(result: &ExtractionResult, config: &ChunkingConfig) -> Result<ChunkingResult> {
+    let structure = match &result.document {
+        Some(document) => Cow::Borrowed(document),
+        None => Cow::Owned(crate::extraction::transform::transform_to_document_structure(result)),
+    };
+    chunk_document_structure(&structure, &result.content, config)
+}
+
+/// Split a document tree into chunks that follow its sections.
+///
+/// `content` is the document's text content; chunk byte offsets point into it
+/// wherever the text of a node can be located there.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the size configuration is invalid or
+/// the configured tokenizer cannot be loaded.
+pub fn chunk_document_structure(
+    structure: &DocumentStructure,
+    content: &str,
+    config: &ChunkingConfig,
+) -> Result<ChunkingResult> {
+    let measure = Measure::new(&config.sizing)?;
+    let sections = collect_sections(structure);
+
+    let mut locator = Locator { content, cursor: 0 };
+    let mut chunks = Vec::new();
+    for section in &sections {
+        let blocks: Vec<Block<'_>> = section
+            .blocks
+            .iter()
+            .map(|block| Block {
+                span: locator.locate(&block.text),
+                ..block.clone()
+            })
+            .collect();
+        pack_section(
+            &section.heading_path,
+            &blocks,
+            config,
+            &measure,
+            locator.cursor,
+            &mut chunks,
+        )?;
+    }
+
+    let total_chunks = chunks.len();
+    for (index, chunk) in chunks.iter_mut().enumerate() {
+        chunk.metadata.chunk_index = index;
+        chunk.metadata.total_chunks = total_chunks;
+    }
+
+    Ok(ChunkingResult {
+        chunk_count: total_chunks,
+        chunks,
+    })
+}
+
+/// A node rendered as text, with the metadata the chunk needs.
+#[derive(Clone)]
+struct Block<'a> {
+    text: String,
+    node_id: &'a crate::types::NodeId,
+    element_type: &'static str,
+    first_page: Option<u32>,
+    last_page: Option<u32>,
+    /// Tables and code are emitted whole, never split
+    atomic: bool,
+    /// Byte range of `text` in the content; `exact` if the whole text was found
+    span: Option<Span>,
+}
+
+#[derive(Clone, Copy)]
+struct Span {
+    start: usize,
+    end: usize,
+    exact: bool,
+}
+
+struct Section<'a> {
+    heading_path: Vec<String>,
+    blocks: Vec<Block<'a>>,
+}
+
+/// Walk the body of the tree in reading order and group nodes by section.
+fn collect_sections(structure: &DocumentStructure) -> Vec<Section<'_>> {
+    let mut walker = SectionWalker {
+        structure,
+        headings: Vec::new(),
+        sections: vec![Section {
+            heading_path: Vec::new(),
+            blocks: Vec::new(),
+        }],
+    };
+    for (index, _) in structure.body_roots() {
+        walker.visit(index);
+    }
+
+    walker.sections.retain(|section| !section.blocks.is_empty());
+    walker.sections
+}
+
+struct SectionWalker<'a> {
+    structure: &'a DocumentStructure,
+    /// Open headings as (level, text); titles use level 0
+    headings: Vec<(u8, String)>,
+    sections: Vec<Section<'a>>,
+}
+
+impl<'a> SectionWalker<'a> {
+    fn visit(&mut self, index: NodeIndex) {
+        let Some(node) = self.structure.get(index) else {
+            return;
+        };
+        if !matches!(
+            node.content_layer,
+            crate::types::ContentLayer::Body | crate::types::ContentLayer::Footnote
+        ) {
+            return;
+        }
+
+        let heading = match &node.content {
+            NodeContent::Title { text } => Some((0, text.as_str())),
+            NodeContent::Heading { level, text } => Some((*level, text.as_str())),
+            NodeContent::Group {
+                heading_level: Some(level),
+                heading_text: Some(text),
+                ..
+            } => Some((*level, text.as_str())),
+            _ => None,
+        };
+
+        if let Some((level, text)) = heading {
+            while self.headings.last().is_some_and(|(open, _)| *open >= level) {
+                self.headings.pop();
+            }
+            self.headings.push((level, text.trim().to_string()));
+            self.sections.push(Section {
+                heading_path: self.headings.iter().map(|(_, text)| text.clone()).collect(),
+                blocks: Vec::new(),
+            });
+        }
+
+        let rendered = match &node.content {
+            NodeContent::Title { text } | NodeContent::Heading { text, .. } => Some(text.clone()),
+            NodeContent::Group {
+                heading_text: Some(text),
+                heading_level: Some(_),
+                ..
+            } => Some(text.clone()),
+            NodeContent::Paragraph { text } | NodeContent::Formula { text } | NodeContent::Footnote { text } => {
+                Some(text.clone())
+            }
+            NodeContent::ListItem { text } => Some(format!("- {}", text.trim())),
+            NodeContent::Code { text, .. } => Some(text.clone()),
+            NodeContent::Table { grid } => Some(render_table(grid)),
+            NodeContent::Image { description, .. } => description.clone(),
+            NodeContent::List { .. } | NodeContent::Quote | NodeContent::Group { .. } | NodeContent::PageBreak => None,
+        };
+
+        if let Some(text) = rendered.filter(|text| !text.trim().is_empty())
+            && let Some(section) = self.sections.last_mut()
+        {
+            section.blocks.push(Block {
+                text: text.trim().to_string(),
+                node_id: &node.id,
+                element_type: node.content.node_type_str(),
+                first_page: node.page,
+                last_page: node.page_end.or(node.page),
+                atomic: matches!(node.content, NodeContent::Table { .. } | NodeContent::Code { .. }),
+                span: None,
+            });
+        }
+
+        for child in &node.children {
+            self.visit(*child);
+        }
+    }
+}
+
+/// Render a table grid as pipe-separated rows.
+fn render_table(grid: &TableGrid) -> String {
+    let mut rows = vec![vec![String::new(); grid.cols as usize]; grid.rows as usize];
+    for cell in &grid.cells {
+        if let Some(slot) = rows
+            .get_mut(cell.row as usize)
+            .and_then(|row| row.get_mut(cell.col as usize))
+        {
+            *slot = cell.content.trim().to_string();
+        }
+    }
+    rows.iter()
+        .map(|row| format!("| {} |", row.join(" | ")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Finds node text in the content, moving forward through the document.
+struct Locator<'a> {
+    content: &'a str,
+    cursor: usize,
+}
+
+impl Locator<'_> {
+    fn locate(&mut self, text: &str) -> Option<Span> {
+        // Rendered tables and list markers rarely match the content verbatim,
+        // so fall back to the first line when the whole text is not found ~keep
+        let probe = text.lines().next()?.trim();
+        if probe.is_empty() {
+            return None;
+        }
+        let remaining = &self.content[self.cursor..];
+        let (start, exact) = match remaining.find(text) {
+            Some(offset) => (self.cursor + offset, true),
+            None => (self.cursor + remaining.find(probe)?, false),
+        };
+        let end = start + if exact { text.len() } else { probe.len() };
+        self.cursor = end;
+        Some(Span { start, end, exact })
+    }
+}
+
+/// Pack the blocks of one section into chunks appended to `chunks`.
+fn pack_section(
+    heading_path: &[String],
+    blocks: &[Block<'_>],
+    config: &ChunkingConfig,
+    measure: &Measure,
+    fallback_offset: usize,
+    chunks: &mut Vec<Chunk>,
+) -> Result<()> {
+    let prefix = if config.prepend_heading_context && !heading_path.is_empty() {
+        format!("{}{}", heading_path.join(HEADING_SEPARATOR), NODE_SEPARATOR)
+    } else {
+        String::new()
+    };
+    // The heading path counts towards the limit, but never takes more than half of it ~keep
+    let capacity = config
+        .max_characters
+        .saturating_sub(measure.size(&prefix))
+        .max(config.max_characters / 2)
+        .max(1);
+
+    let mut pending: Vec<Block<'_>> = Vec::new();
+    let mut pending_text = String::new();
+    for block in blocks {
+        let candidate = if pending_text.is_empty() {
+            block.text.clone()
+        } else {
+            format!("{}{}{}", pending_text, NODE_SEPARATOR, block.text)
+        };
+        if measure.size(&candidate) <= capacity {
+            pending.push(block.clone());
+            pending_text = candidate;
+            continue;
+        }
+
+        if !pending.is_empty() {
+            chunks.push(assemble(
+                &prefix,
+                &pending_text,
+                &pending,
+                heading_path,
+                measure,
+                fallback_offset,
+            ));
+            pending.clear();
+            pending_text.clear();
+        }
+
+        if block.atomic || measure.size(&block.text) <= capacity {
+            pending.push(block.clone());
+            pending_text = block.text.clone();
+            continue;
+        }
+
+        for (offset, piece) in measure.split(&block.text, capacity, config.overlap.min(capacity / 2), config.trim)? {
+            let span = block.span.map(|span| {
+                if span.exact {
+                    Span {
+                        start: span.start + offset,
+                        end: span.start + offset + piece.len(),
+                        exact: true,
+                    }
+                } else {
+                    span
+                }
+            });
+            let piece_block = Block {
+                text: piece.to_string(),
+                span,
+                ..block.clone()
+            };
+            chunks.push(assemble(
+                &prefix,
+                piece,
+                std::slice::from_ref(&piece_block),
+                heading_path,
+                measure,
+                fallback_offset,
+            ));
+        }
+    }
+
+    if !pending.is_empty() {
+        chunks.push(assemble(
+            &prefix,
+            &pending_text,
+            &pending,
+            heading_path,
+            measure,
+            fallback_offset,
+        ));
+    }
+    Ok(())
+}
+
+fn assemble(
+    prefix: &str,
+    text: &str,
+    blocks: &[Block<'_>],
+    heading_path: &[String],
+    measure: &Measure,
+    fallback_offset: usize,
+) -> Chunk {
+    let content = format!("{}{}", prefix, text);
+    let spans = blocks.iter().filter_map(|block| block.span);
+    let byte_start = spans.clone().map(|span| span.start).min().unwrap_or(fallback_offset);
+    let byte_end = spans.map(|span| span.end).max().unwrap_or(byte_start);
+
+    Chunk {
+        embedding: None,
+        metadata: ChunkMetadata {
+            byte_start,
+            byte_end,
+            token_count: measure.token_count(&content),
+            chunk_index: 0,
+            total_chunks: 0,
+            first_page: blocks
+                .iter()
+                .filter_map(|block| block.first_page)
+                .min()
+                .map(|p| p as usize),
+            last_page: blocks
+                .iter()
+                .filter_map(|block| block.last_page)
+                .max()
+                .map(|p| p as usize),
+            heading_path: heading_path.to_vec(),
+            element_types: blocks.iter().map(|block| block.element_type.to_string()).collect(),
+            node_ids: blocks.iter().map(|block| block.node_id.clone()).collect(),
+            language: None,
+            language_confidence: None,
+        },
+        content,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::document_structure::GridCell;
+    use crate::types::{ContentLayer, DocumentNode, NodeId};
+
+    struct Builder {
+        doc: DocumentStructure,
+    }
+
+    impl Builder {
+        fn new() -> Self {
+            Self {
+                doc: DocumentStructure::new(),
+            }
+        }
+
+        fn push(&mut self, parent: Option<NodeIndex>, content: NodeContent, page: u32) -> NodeIndex {
+            let index = self.doc.len() as u32;
+            let id = NodeId::generate(content.node_type_str(), content.text().unwrap_or(""), Some(page), index);
+            let node_index = self.doc.push_node(DocumentNode {
+                id,
+                content,
+                parent: None,
+                children: vec![],
+                content_layer: ContentLayer::Body,
+                page: Some(page),
+                page_end: None,
+                bbox: None,
+                annotations: vec![],
+            });
+            if let Some(parent) = parent {
+                self.doc.add_child(parent, node_index);
+            }
+            node_index
+        }
+
+        fn section(&mut self, parent: Option<NodeIndex>, level: u8, text: &str, page: u32) -> NodeIndex {
+            self.push(
+                parent,
+                NodeContent::Group {
+                    label: None,
+                    heading_level: Some(level),
+                    heading_text: Some(text.to_string()),
+                },
+                page,
+            )
+        }
+
+        fn paragraph(&mut self, parent: Option<NodeIndex>, text: &str, page: u32) -> NodeIndex {
+            self.push(parent, NodeContent::Paragraph { text: text.to_string() }, page)
+        }
+    }
+
+    fn config(max_characters: usize) -> ChunkingConfig {
+        ChunkingConfig {
+            max_characters,
+            overlap: 0,
+            chunker_type: crate::core::config::ChunkerType::Structure,
+            ..Default::default()
+        }
+    }
+
+    fn table(rows: u32, cols: u32) -> NodeContent {
+        let cells = (0..rows)
+            .flat_map(|row| {
+                (0..cols).map(move |col| GridCell {
+                    content: format!("cell {} {}", row, col),
+                    row,
+                    col,
+                    row_span: 1,
+                    col_span: 1,
+                    is_header: row == 0,
+                    bbox: None,
+                })
+            })
+            .collect();
+        NodeContent::Table {
+            grid: TableGrid { rows, cols, cells },
+        }
+    }
+
+    #[test]
+    fn test_chunks_follow_sections_with_heading_path() {
+        let mut b = Builder::new();
+        let intro = b.section(None, 1, "Introduction", 1);
+        b.paragraph(Some(intro), "Opening words.", 1);
+        let background = b.section(Some(intro), 2, "Background", 1);
+        b.paragraph(Some(background), "Some history.", 2);
+        let methods = b.section(None, 1, "Methods", 2);
+        b.paragraph(Some(methods), "How it was done.", 2);
+
+        let content = "Introduction\n\nOpening words.\n\nBackground\n\nSome history.\n\nMethods\n\nHow it was done.";
+        let result = chunk_document_structure(&b.doc, content, &config(1000)).unwrap();
+
+        assert_eq!(result.chunk_count, 3);
+        let paths: Vec<Vec<String>> = result.chunks.iter().map(|c| c.metadata.heading_path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                vec!["Introduction".to_string()],
+                vec!["Introduction".to_string(), "Background".to_string()],
+                vec!["Methods".to_string()],
+            ]
+        );
+
+        let first = &result.chunks[0];
+        assert_eq!(first.content, "Introduction\n\nOpening words.");
+        assert_eq!(first.metadata.element_types, vec!["group", "paragraph"]);
+        assert_eq!(first.metadata.node_ids.len(), 2);
+        assert_eq!(first.metadata.node_ids[0], b.doc.nodes[0].id);
+        assert_eq!(
+            &content[first.metadata.byte_start..first.metadata.byte_end],
+            first.content
+        );
+
+        let second = &result.chunks[1];
+        assert_eq!(
+            (second.metadata.first_page, second.metadata.last_page),
+            (Some(1), Some(2))
+        );
+        assert_eq!(result.chunks[2].metadata.chunk_index, 2);
+        assert_eq!(result.chunks[2].metadata.total_chunks, 3);
+    }
+
+    #[test]
+    fn test_small_nodes_are_packed_and_large_paragraphs_split() {
+        let mut b = Builder::new();
+        let section = b.section(None, 1, "Notes", 1);
+        b.paragraph(Some(section), "Alpha.", 1);
+        b.paragraph(Some(section), "Beta.", 1);
+        b.paragraph(Some(section), &"word ".repeat(30), 1);
+
+        let result = chunk_document_structure(&b.doc, "", &config(40)).unwrap();
+
+        assert_eq!(result.chunks[0].content, "Notes\n\nAlpha.\n\nBeta.");
+        assert!(result.chunk_count > 2);
+        for chunk in &result.chunks[1..] {
+            assert!(chunk.content.chars().count() <= 40);
+            assert_eq!(chunk.metadata.element_types, vec!["paragraph"]);
+            assert_eq!(chunk.metadata.heading_path, vec!["Notes".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_tables_and_code_are_never_split() {
+        let mut b = Builder::new();
+        let section = b.section(None, 1, "Data", 1);
+        b.push(Some(section), table(4, 3), 1);
+        b.push(
+            Some(section),
+            NodeContent::Code {
+                text: "fn main() {\n    println!(\"a long line of code\");\n}".to_string(),
+                language: Some("rust".to_string()),
+            },
+            1,
+        );
+
+        let result = chunk_document_structure(&b.doc, "", &config(30)).unwrap();
+
+        let table_chunk = result
+            .chunks
+            .iter()
+            .find(|c| c.metadata.element_types == ["table"])
+            .unwrap();
+        assert_eq!(table_chunk.content.lines().count(), 4);
+        assert!(table_chunk.content.starts_with("| cell 0 0 | cell 0 1 | cell 0 2 |"));
+
+        let code_chunk = result
+            .chunks
+            .iter()
+            .find(|c| c.metadata.element_types == ["code"])
+            .unwrap();
+        assert!(code_chunk.content.ends_with('}'));
+        assert!(code_chunk.content.starts_with("fn main()"));
+    }
+
+    #[test]
+    fn test_prepend_heading_context() {
+        let mut b = Builder::new();
+        let chapter = b.section(None, 1, "Chapter 1", 1);
+        let section = b.section(Some(chapter), 2, "Setup", 1);
+        b.paragraph(Some(section), "Install the tools.", 1);
+
+        let mut config = config(1000);
+        config.prepend_heading_context = true;
+        let result = chunk_document_structure(&b.doc, "", &config).unwrap();
+
+        let last = result.chunks.last().unwrap();
+        assert_eq!(last.content, "Chapter 1 > Setup\n\nSetup\n\nInstall the tools.");
+        assert_eq!(last.metadata.heading_path, vec!["Chapter 1", "Setup"]);
+    }
+
+    #[test]
+    fn test_flat_headings_build_heading_path() {
+        let mut b = Builder::new();
+        b.push(
+            None,
+            NodeContent::Title {
+                text: "Manual".to_string(),
+            },
+            1,
+        );
+        b.push(
+            None,
+            NodeContent::Heading {
+                level: 1,
+                text: "Usage".to_string(),
+            },
+            1,
+        );
+        b.paragraph(None, "Run it.", 1);
+        b.push(
+            None,
+            NodeContent::Heading {
+                level: 2,
+                text: "Flags".to_string(),
+            },
+            1,
+        );
+        b.paragraph(None, "Pass --help.", 1);
+        b.push(
+            None,
+            NodeContent::Heading {
+                level: 1,
+                text: "FAQ".to_string(),
+            },
+            2,
+        );
+        b.paragraph(None, "Ask away.", 2);
+
+        let result = chunk_document_structure(&b.doc, "", &config(1000)).unwrap();
+        let paths: Vec<String> = result
+            .chunks
+            .iter()
+            .map(|c| c.metadata.heading_path.join("/"))
+            .collect();
+        assert_eq!(
+            paths,
+            vec!["Manual", "Manual/Usage", "Manual/Usage/Flags", "Manual/FAQ"]
+        );
+    }
+
+    #[test]
+    fn test_furniture_is_skipped() {
+        let mut b = Builder::new();
+        let header = b.paragraph(None, "Running header", 1);
+        b.doc.nodes[header.0 as usize].content_layer = ContentLayer::Header;
+        b.paragraph(None, "Body text.", 1);
+
+        let result = chunk_document_structure(&b.doc, "", &config(1000)).unwrap();
+        assert_eq!(result.chunk_count, 1);
+        assert_eq!(result.chunks[0].content, "Body text.");
+        assert!(result.chunks[0].metadata.heading_path.is_empty());
+    }
+
+    #[test]
+    fn test_chunk_extraction_result_uses_document() {
+        let mut b = Builder::new();
+        let section = b.section(None, 1, "Only", 1);
+        b.paragraph(Some(section), "Content.", 1);
+        let result = ExtractionResult {
+            content: "Only\n\nContent.".to_string(),
+            document: Some(b.doc),
+            ..Default::default()
+        };
+
+        let chunks = chunk_extraction_result(&result, &config(1000)).unwrap();
+        assert_eq!(chunks.chunk_count, 1);
+        assert_eq!(chunks.chunks[0].metadata.heading_path, vec!["Only"]);
+        assert_eq!(chunks.chunks[0].metadata.byte_end, result.content.len());
+    }
+}
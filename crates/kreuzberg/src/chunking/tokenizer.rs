@@ -0,0 +1,311 @@
+//! Token counting for token-based chunk sizing.
+//!
+//! Chunk sizes can be measured in tokens of a local tokenizer instead of
+//! characters. Two file formats are supported:
+//!
+//! - HuggingFace `tokenizer.json`, loaded with the `tokenizers` crate.
+//! - tiktoken BPE rank files (`cl100k_base.tiktoken` and friends), one
+//!   `<base64 token> <rank>` pair per line, counted with a regex pre-tokenizer
+//!   followed by byte-pair merging.
+//!
+//! Loaded tokenizers are cached per file, so repeated chunking with the same
+//! configuration only reads the file once.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use once_cell::sync::Lazy;
+use text_splitter::ChunkSizer;
+
+use crate::core::config::{ChunkSizing, TokenizerFormat};
+use crate::error::{KreuzbergError, Result};
+
+/// Pre-tokenization pattern of the `cl100k_base` encoding.
+pub const CL100K_PATTERN: &str = r"(?i:'s|'t|'re|'ve|'m|'ll|'d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+(?!\S)|\s+";
+
+static COUNTER_CACHE: Lazy<RwLock<HashMap<ChunkSizing, Arc<TokenCounter>>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Counts tokens of a local tokenizer.
+pub enum TokenCounter {
+    /// HuggingFace tokenizer loaded from `tokenizer.json`
+    HuggingFace(Box<tokenizers::Tokenizer>),
+    /// tiktoken-style byte-pair encoding
+    Tiktoken(TiktokenBpe),
+}
+
+/// Byte-pair encoding ranks with the regex used to split text before merging.
+pub struct TiktokenBpe {
+    ranks: HashMap<Vec<u8>, u32>,
+    pattern: fancy_regex::Regex,
+}
+
+impl TokenCounter {
+    /// Number of tokens in `text`, without special tokens.
+    pub fn count(&self, text: &str) -> usize {
+        match self {
+            TokenCounter::HuggingFace(tokenizer) => match tokenizer.encode_fast(text, false) {
+                Ok(encoding) => encoding.len(),
+                Err(e) => {
+                    // Overestimating keeps chunks within the limit of the real tokenizer ~keep
+                    tracing::debug!("Tokenizer failed, counting characters instead: {}", e);
+                    text.chars().count()
+                }
+            },
+            TokenCounter::Tiktoken(bpe) => bpe.count(text),
+        }
+    }
+}
+
+impl ChunkSizer for TokenCounter {
+    fn size(&self, chunk: &str) -> usize {
+        self.count(chunk)
+    }
+}
+
+impl TiktokenBpe {
+    /// Parse a tiktoken BPE file with one `<base64 token> <rank>` pair per line.
+    pub fn parse(contents: &str, pattern: &str) -> Result<Self> {
+        let mut ranks = HashMap::new();
+        for (line_number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let invalid =
+                || KreuzbergError::validation(format!("Invalid tiktoken BPE entry on line {}", line_number + 1));
+            let (token, rank) = line.split_once(' ').ok_or_else(invalid)?;
+            let token = STANDARD.decode(token).map_err(|_| invalid())?;
+            let rank = rank.trim().parse::<u32>().map_err(|_| invalid())?;
+            ranks.insert(token, rank);
+        }
+
+        if ranks.is_empty() {
+            return Err(KreuzbergError::validation("tiktoken BPE file contains no tokens"));
+        }
+
+        let pattern = fancy_regex::Regex::new(pattern)
+            .map_err(|e| KreuzbergError::validation(format!("Invalid tokenizer pattern: {}", e)))?;
+        Ok(Self { ranks, pattern })
+    }
+
+    fn count(&self, text: &str) -> usize {
+        let mut total = 0;
+        let mut last_end = 0;
+        for piece in self.pattern.find_iter(text) {
+            let Ok(piece) = piece else {
+                // Backtracking limit hit; count the rest byte by byte rather than fail ~keep
+                return total + text.len() - last_end;
+            };
+            last_end = piece.end();
+            let bytes = piece.as_str().as_bytes();
+            total += if self.ranks.contains_key(bytes) {
+                1
+            } else {
+                self.merged_len(bytes)
+            };
+        }
+        total
+    }
+
+    /// Number of tokens left after repeatedly merging the lowest-ranked adjacent pair.
+    fn merged_len(&self, piece: &[u8]) -> usize {
+        if piece.len() <= 1 {
+            return piece.len();
+        }
+
+        // Token boundaries within `piece`; token i spans bounds[i]..bounds[i + 1] ~keep
+        let mut bounds: Vec<usize> = (0..=piece.len()).collect();
+        loop {
+            let best = (0..bounds.len() - 2)
+                .filter_map(|i| self.ranks.get(&piece[bounds[i]..bounds[i + 2]]).map(|&rank| (rank, i)))
+                .min();
+            match best {
+                Some((_, i)) => {
+                    bounds.remove(i + 1);
+                }
+                None => break,
+            }
+            if bounds.len() == 2 {
+                break;
+            }
+        }
+        bounds.len() - 1
+    }
+}
+
+/// Load the token counter for `sizing`, or `None` for character sizing.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the tokenizer file cannot be read or parsed.
+pub fn token_counter(sizing: &ChunkSizing) -> Result<Option<Arc<TokenCounter>>> {
+    let ChunkSizing::Tokens {
+        tokenizer,
+        format,
+        pattern,
+    } = sizing
+    else {
+        return Ok(None);
+    };
+
+    {
+        let cache = COUNTER_CACHE
+            .read()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Tokenizer cache lock poisoned: {}", e)))?;
+        if let Some(counter) = cache.get(sizing) {
+            return Ok(Some(Arc::clone(counter)));
+        }
+    }
+
+    let counter = Arc::new(load(tokenizer, *format, pattern.as_deref())?);
+    COUNTER_CACHE
+        .write()
+        .map_err(|e| KreuzbergError::LockPoisoned(format!("Tokenizer cache lock poisoned: {}", e)))?
+        .insert(sizing.clone(), Arc::clone(&counter));
+    Ok(Some(counter))
+}
+
+fn load(path: &Path, format: TokenizerFormat, pattern: Option<&str>) -> Result<TokenCounter> {
+    let is_json = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    let format = match format {
+        TokenizerFormat::Auto if is_json => TokenizerFormat::HuggingFace,
+        TokenizerFormat::Auto => TokenizerFormat::Tiktoken,
+        explicit => explicit,
+    };
+
+    match format {
+        TokenizerFormat::HuggingFace => tokenizers::Tokenizer::from_file(path)
+            .map(|tokenizer| TokenCounter::HuggingFace(Box::new(tokenizer)))
+            .map_err(|e| KreuzbergError::validation(format!("Failed to load tokenizer '{}': {}", path.display(), e))),
+        _ => {
+            let contents = std::fs::read_to_string(path).map_err(|e| {
+                KreuzbergError::validation(format!("Failed to read tokenizer '{}': {}", path.display(), e))
+            })?;
+            TiktokenBpe::parse(&contents, pattern.unwrap_or(CL100K_PATTERN)).map(TokenCounter::Tiktoken)
+        }
+    }
+}
+
+/// Tokenizer files shared by the chunking tests.
+#[cfg(test)]
+pub(crate) mod test_files {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Single bytes, plus merges spelling "hello", " world" and " hello".
+    pub(crate) fn write_tiktoken_file(dir: &Path) -> PathBuf {
+        let mut lines = Vec::new();
+        for byte in 0u8..=255 {
+            lines.push(format!("{} {}", STANDARD.encode([byte]), byte as u32));
+        }
+        for (rank, token) in [
+            "he", "ll", "hell", "hello", " w", "or", " wor", "ld", " world", " hello",
+        ]
+        .iter()
+        .enumerate()
+        {
+            lines.push(format!("{} {}", STANDARD.encode(token), 256 + rank));
+        }
+        let path = dir.join("test.tiktoken");
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        path
+    }
+
+    pub(crate) fn write_huggingface_file(dir: &Path) -> PathBuf {
+        let json = r#"{
+            "version": "1.0",
+            "truncation": null,
+            "padding": null,
+            "added_tokens": [],
+            "normalizer": null,
+            "pre_tokenizer": {"type": "Whitespace"},
+            "post_processor": null,
+            "decoder": null,
+            "model": {"type": "WordLevel", "vocab": {"[UNK]": 0, "hello": 1, "world": 2}, "unk_token": "[UNK]"}
+        }"#;
+        let path = dir.join("tokenizer.json");
+        std::fs::write(&path, json).unwrap();
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_files::{write_huggingface_file, write_tiktoken_file};
+    use super::*;
+    use std::path::PathBuf;
+
+    fn tokens(tokenizer: PathBuf) -> ChunkSizing {
+        ChunkSizing::Tokens {
+            tokenizer,
+            format: TokenizerFormat::Auto,
+            pattern: None,
+        }
+    }
+
+    #[test]
+    fn test_characters_have_no_counter() {
+        assert!(token_counter(&ChunkSizing::Characters).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_tiktoken_counts_merged_tokens() {
+        let dir = tempfile::tempdir().unwrap();
+        let counter = token_counter(&tokens(write_tiktoken_file(dir.path())))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(counter.count("hello world"), 2);
+        assert_eq!(counter.count("hello"), 1);
+        // "xyz" has no merges, so every byte is its own token
+        assert_eq!(counter.count("xyz"), 3);
+        assert_eq!(counter.count(""), 0);
+    }
+
+    #[test]
+    fn test_huggingface_counts_tokens() {
+        let dir = tempfile::tempdir().unwrap();
+        let counter = token_counter(&tokens(write_huggingface_file(dir.path())))
+            .unwrap()
+            .unwrap();
+
+        assert!(matches!(*counter, TokenCounter::HuggingFace(_)));
+        assert_eq!(counter.count("hello world hello"), 3);
+        assert_eq!(counter.count("unknown words"), 2);
+    }
+
+    #[test]
+    fn test_counter_is_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let sizing = tokens(write_tiktoken_file(dir.path()));
+        let first = token_counter(&sizing).unwrap().unwrap();
+        let second = token_counter(&sizing).unwrap().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn test_invalid_tokenizer_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = token_counter(&tokens(dir.path().join("missing.tiktoken")));
+        assert!(matches!(missing, Err(KreuzbergError::Validation { .. })));
+
+        let garbage = dir.path().join("garbage.tiktoken");
+        std::fs::write(&garbage, "not base64!! x\n").unwrap();
+        assert!(matches!(
+            token_counter(&tokens(garbage)),
+            Err(KreuzbergError::Validation { .. })
+        ));
+
+        let bad_json = dir.path().join("bad.json");
+        std::fs::write(&bad_json, "{}").unwrap();
+        assert!(matches!(
+            token_counter(&tokens(bad_json)),
+            Err(KreuzbergError::Validation { .. })
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+//! Archive-specific configuration.
+//!
+//! Controls how ZIP, TAR, 7z and GZIP archives are unpacked.
+
+use serde::{Deserialize, Serialize};
+
+/// Archive extraction configuration.
+///
+/// By default, archives are listed and only members with common text
+/// extensions are read as UTF-8. With `recursive` enabled, every member is
+/// MIME-detected and extracted through the extractor registry (nested archives
+/// included), and each member is returned in `ExtractionResult.children`.
+///
+/// Recursion is bounded by `SecurityLimits`: `max_archive_depth` caps the
+/// nesting depth, while `max_archive_size` and `max_files_in_archive` cap the
+/// total uncompressed size and member count across all nesting levels.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchiveConfig {
+    /// Extract every member through the extractor registry
+    #[serde(default)]
+    pub recursive: bool,
+}
@@ -0,0 +1,95 @@
+//! Cache storage backend selection.
+//!
+//! This module defines the `CacheBackend` enum choosing where cached
+//! extraction results are stored.
+
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Storage backend for the extraction result cache.
+///
+/// - `Filesystem` keeps one file per entry in the cache directory.
+/// - `Sqlite` keeps all entries in a single database file with size-bounded LRU
+///   eviction (requires the `cache-sqlite` feature).
+/// - `Memory` keeps a bounded LRU in process memory; nothing outlives the process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum CacheBackend {
+    /// One file per entry under the cache directory (default)
+    #[default]
+    Filesystem,
+    /// Single SQLite database file under the cache directory
+    Sqlite,
+    /// Bounded in-memory LRU
+    Memory,
+}
+
+impl CacheBackend {
+    /// All backends, in declaration order.
+    pub const ALL: [CacheBackend; 3] = [CacheBackend::Filesystem, CacheBackend::Sqlite, CacheBackend::Memory];
+
+    /// Position of the backend in [`CacheBackend::ALL`].
+    pub(crate) fn index(self) -> usize {
+        match self {
+            CacheBackend::Filesystem => 0,
+            CacheBackend::Sqlite => 1,
+            CacheBackend::Memory => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for CacheBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CacheBackend::Filesystem => write!(f, "filesystem"),
+            CacheBackend::Sqlite => write!(f, "sqlite"),
+            CacheBackend::Memory => write!(f, "memory"),
+        }
+    }
+}
+
+impl FromStr for CacheBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "filesystem" | "fs" | "file" => Ok(CacheBackend::Filesystem),
+            "sqlite" => Ok(CacheBackend::Sqlite),
+            "memory" | "mem" => Ok(CacheBackend::Memory),
+            _ => Err(format!(
+                "Invalid cache backend: '{}'. Valid backends: filesystem, sqlite, memory",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_backend_from_str() {
+        assert_eq!("filesystem".parse::<CacheBackend>().unwrap(), CacheBackend::Filesystem);
+        assert_eq!("FS".parse::<CacheBackend>().unwrap(), CacheBackend::Filesystem);
+        assert_eq!("SQLite".parse::<CacheBackend>().unwrap(), CacheBackend::Sqlite);
+        assert_eq!("memory".parse::<CacheBackend>().unwrap(), CacheBackend::Memory);
+        assert!(
+            "redis"
+                .parse::<CacheBackend>()
+                .unwrap_err()
+                .contains("Invalid cache backend")
+        );
+    }
+
+    #[test]
+    fn test_cache_backend_serde_roundtrip() {
+        for backend in CacheBackend::ALL {
+            let json = serde_json::to_string(&backend).unwrap();
+            assert_eq!(json, format!("\"{}\"", backend));
+            assert_eq!(serde_json::from_str::<CacheBackend>(&json).unwrap(), backend);
+            assert_eq!(CacheBackend::ALL[backend.index()], backend);
+        }
+    }
+}
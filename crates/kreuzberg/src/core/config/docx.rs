@@ -0,0 +1,31 @@
+//! DOCX-specific configuration.
+//!
+//! Controls how tracked changes (`w:ins`, `w:del`, `w:moveFrom`, `w:moveTo`)
+//! are rendered in the extracted text.
+
+use serde::{Deserialize, Serialize};
+
+/// DOCX extraction configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DocxConfig {
+    /// How tracked changes are resolved (default: accept)
+    #[serde(default)]
+    pub revision_mode: RevisionMode,
+}
+
+/// Rendering of tracked changes in DOCX documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RevisionMode {
+    /// Output the document as if all changes were accepted: insertions and
+    /// move destinations are kept, deletions and move sources are dropped
+    #[default]
+    Accept,
+    /// Output the document as if all changes were rejected: deletions and
+    /// move sources are kept, insertions and move destinations are dropped
+    Reject,
+    /// Keep both sides and mark them inline with CriticMarkup
+    /// (`{++inserted++}`, `{--deleted--}`); the document structure carries
+    /// matching `Insertion`/`Deletion` annotations
+    Markup,
+}
@@ -0,0 +1,62 @@
+//! Email-specific configuration.
+//!
+//! Controls how attachments of .eml and .msg messages are handled, and how
+//! mbox mailboxes are split into messages.
+
+use serde::{Deserialize, Serialize};
+
+/// Email extraction configuration.
+///
+/// By default, attachments are only listed by name. With `extract_attachments`
+/// enabled, every attachment is MIME-detected and extracted through the
+/// extractor registry, and returned in `ExtractionResult.children` and
+/// `EmailExtractionResult.children`.
+///
+/// Attached messages (.eml/.msg) are extracted like any other attachment, and
+/// their own attachments are expanded in turn until `max_attachment_depth`
+/// message levels have been processed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailConfig {
+    /// Extract attachment contents through the extractor registry
+    #[serde(default)]
+    pub extract_attachments: bool,
+
+    /// Number of message levels whose attachments are extracted (default: 3).
+    ///
+    /// `1` extracts only the attachments of the top-level message; attached
+    /// messages are still extracted, but their own attachments are only listed.
+    #[serde(default = "default_max_attachment_depth")]
+    pub max_attachment_depth: usize,
+
+    /// Quoting convention of mbox files (default: mboxrd)
+    #[serde(default)]
+    pub mbox_format: MboxFormat,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            extract_attachments: false,
+            max_attachment_depth: default_max_attachment_depth(),
+            mbox_format: MboxFormat::default(),
+        }
+    }
+}
+
+/// Quoting convention used for body lines that start with `From `.
+///
+/// Both variants split messages on `From ` separator lines; they differ in how
+/// quoted body lines are restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MboxFormat {
+    /// `>From `, `>>From `, ... lose one leading `>` (reversible quoting)
+    #[default]
+    Mboxrd,
+    /// Only `>From ` is unquoted; deeper quoting is kept as-is
+    Mboxo,
+}
+
+fn default_max_attachment_depth() -> usize {
+    3
+}
@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::super::cache::CacheBackend;
 use super::super::formats::OutputFormat;
 use super::super::ocr::OcrConfig;
 use super::super::page::PageConfig;
@@ -33,6 +34,10 @@ pub struct ExtractionConfig {
     #[serde(default = "default_true")]
     pub use_cache: bool,
 
+    /// Storage backend of the extraction result cache
+    #[serde(default)]
+    pub cache_backend: CacheBackend,
+
     /// Enable quality post-processing
     #[serde(default = "default_true")]
     pub enable_quality_processing: bool,
@@ -58,6 +63,21 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub pdf_options: Option<super::super::pdf::PdfConfig>,
 
+    /// Archive-specific options (None = use defaults)
+    #[cfg(feature = "archives")]
+    #[serde(default)]
+    pub archive_options: Option<super::super::archive::ArchiveConfig>,
+
+    /// DOCX-specific options (None = use defaults)
+    #[cfg(feature = "office")]
+    #[serde(default)]
+    pub docx_options: Option<super::super::docx::DocxConfig>,
+
+    /// Email-specific options (None = use defaults)
+    #[cfg(feature = "email")]
+    #[serde(default)]
+    pub email_options: Option<super::super::email::EmailConfig>,
+
     /// Token reduction configuration (None = no token reduction)
     #[serde(default)]
     pub token_reduction: Option<TokenReductionConfig>,
@@ -140,6 +160,7 @@ impl Default for ExtractionConfig {
     fn default() -> Self {
         Self {
             use_cache: true,
+            cache_backend: CacheBackend::default(),
             enable_quality_processing: true,
             ocr: None,
             force_ocr: false,
@@ -147,6 +168,12 @@ impl Default for ExtractionConfig {
             images: None,
             #[cfg(feature = "pdf")]
             pdf_options: None,
+            #[cfg(feature = "archives")]
+            archive_options: None,
+            #[cfg(feature = "office")]
+            docx_options: None,
+            #[cfg(feature = "email")]
+            email_options: None,
             token_reduction: None,
             language_detection: None,
             pages: None,
@@ -184,6 +211,18 @@ impl ExtractionConfig {
 
         ocr_enabled || image_extraction_enabled
     }
+
+    /// Output format to apply to the result content.
+    ///
+    /// This is `output_format`, except that an OCR layout format (ALTO, PAGE XML,
+    /// hOCR) set in `ocr.output_format` is used while `output_format` is left at
+    /// its `Plain` default.
+    pub fn effective_output_format(&self) -> OutputFormat {
+        match self.ocr.as_ref().and_then(|ocr| ocr.output_format) {
+            Some(format) if format.is_ocr_layout() && self.output_format == OutputFormat::Plain => format,
+            _ => self.output_format,
+        }
+    }
 }
 
 fn default_true() -> bool {
@@ -100,6 +100,9 @@ impl ExtractionConfig {
                     chunker_type: super::super::processing::ChunkerType::Text,
                     embedding: None,
                     preset: None,
+                    sizing: super::super::processing::ChunkSizing::Characters,
+                    prepend_heading_context: false,
+                    semantic: Default::default(),
                 });
             }
 
@@ -128,6 +131,9 @@ impl ExtractionConfig {
                     chunker_type: super::super::processing::ChunkerType::Text,
                     embedding: None,
                     preset: None,
+                    sizing: super::super::processing::ChunkSizing::Characters,
+                    prepend_heading_context: false,
+                    semantic: Default::default(),
                 });
             }
 
@@ -13,7 +13,8 @@ use std::str::FromStr;
 /// When set to `Markdown`, `Djot`, or `Html`, the output will be formatted
 /// accordingly. `Plain` returns the raw extracted text.
 /// `Structured` returns JSON with full OCR element data including bounding
-/// boxes and confidence scores.
+/// boxes and confidence scores. `Alto`, `PageXml` and `Hocr` serialize the OCR
+/// elements of image extractions to layout XML.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
@@ -39,6 +40,19 @@ pub enum OutputFormat {
     /// - Building custom document viewers
     /// - Extracting maximum information from OCR results
     Structured,
+    /// ALTO XML v4 built from OCR elements.
+    Alto,
+    /// PRImA PAGE XML built from OCR elements (single-page inputs).
+    PageXml,
+    /// hOCR built from OCR elements.
+    Hocr,
+}
+
+impl OutputFormat {
+    /// Whether this format is serialized from OCR element geometry.
+    pub fn is_ocr_layout(&self) -> bool {
+        matches!(self, OutputFormat::Alto | OutputFormat::PageXml | OutputFormat::Hocr)
+    }
 }
 
 impl std::fmt::Display for OutputFormat {
@@ -49,6 +63,9 @@ impl std::fmt::Display for OutputFormat {
             OutputFormat::Djot => write!(f, "djot"),
             OutputFormat::Html => write!(f, "html"),
             OutputFormat::Structured => write!(f, "structured"),
+            OutputFormat::Alto => write!(f, "alto"),
+            OutputFormat::PageXml => write!(f, "pagexml"),
+            OutputFormat::Hocr => write!(f, "hocr"),
         }
     }
 }
@@ -63,8 +80,11 @@ impl FromStr for OutputFormat {
             "djot" => Ok(OutputFormat::Djot),
             "html" => Ok(OutputFormat::Html),
             "structured" | "json" => Ok(OutputFormat::Structured),
+            "alto" => Ok(OutputFormat::Alto),
+            "pagexml" | "page-xml" | "page" => Ok(OutputFormat::PageXml),
+            "hocr" => Ok(OutputFormat::Hocr),
             _ => Err(format!(
-                "Invalid output format: '{}'. Valid formats: plain, text, markdown, md, djot, html, structured, json",
+                "Invalid output format: '{}'. Valid formats: plain, text, markdown, md, djot, html, structured, json, alto, pagexml, hocr",
                 s
             )),
         }
@@ -113,6 +133,18 @@ mod tests {
         assert_eq!("JSON".parse::<OutputFormat>().unwrap(), OutputFormat::Structured);
     }
 
+    #[test]
+    fn test_output_format_from_str_ocr_layout() {
+        assert_eq!("alto".parse::<OutputFormat>().unwrap(), OutputFormat::Alto);
+        assert_eq!("ALTO".parse::<OutputFormat>().unwrap(), OutputFormat::Alto);
+        assert_eq!("pagexml".parse::<OutputFormat>().unwrap(), OutputFormat::PageXml);
+        assert_eq!("page-xml".parse::<OutputFormat>().unwrap(), OutputFormat::PageXml);
+        assert_eq!("PAGE".parse::<OutputFormat>().unwrap(), OutputFormat::PageXml);
+        assert_eq!("hocr".parse::<OutputFormat>().unwrap(), OutputFormat::Hocr);
+        assert!(OutputFormat::Hocr.is_ocr_layout());
+        assert!(!OutputFormat::Html.is_ocr_layout());
+    }
+
     #[test]
     fn test_output_format_from_str_invalid() {
         let result = "invalid".parse::<OutputFormat>();
@@ -129,6 +161,9 @@ mod tests {
         assert_eq!(OutputFormat::Djot.to_string(), "djot");
         assert_eq!(OutputFormat::Html.to_string(), "html");
         assert_eq!(OutputFormat::Structured.to_string(), "structured");
+        assert_eq!(OutputFormat::Alto.to_string(), "alto");
+        assert_eq!(OutputFormat::PageXml.to_string(), "pagexml");
+        assert_eq!(OutputFormat::Hocr.to_string(), "hocr");
     }
 
     #[test]
@@ -145,6 +180,9 @@ mod tests {
             OutputFormat::Djot,
             OutputFormat::Html,
             OutputFormat::Structured,
+            OutputFormat::Alto,
+            OutputFormat::PageXml,
+            OutputFormat::Hocr,
         ] {
             let json = serde_json::to_string(&format).unwrap();
             let deserialized: OutputFormat = serde_json::from_str(&json).unwrap();
@@ -162,5 +200,6 @@ mod tests {
             serde_json::to_string(&OutputFormat::Structured).unwrap(),
             "\"structured\""
         );
+        assert_eq!(serde_json::to_string(&OutputFormat::PageXml).unwrap(), "\"pagexml\"");
     }
 }
@@ -3,6 +3,13 @@
 //! This module provides utilities for loading extraction configuration from various
 //! sources (TOML, YAML, JSON) and discovering configuration files in the project hierarchy.
 
+#[cfg(feature = "archives")]
+pub mod archive;
+pub mod cache;
+#[cfg(feature = "office")]
+pub mod docx;
+#[cfg(feature = "email")]
+pub mod email;
 pub mod extraction;
 pub mod formats;
 pub mod ocr;
@@ -11,10 +18,20 @@ pub mod pdf;
 pub mod processing;
 
 // Re-export main types for backward compatibility
+#[cfg(feature = "archives")]
+pub use archive::ArchiveConfig;
+pub use cache::CacheBackend;
+#[cfg(feature = "office")]
+pub use docx::{DocxConfig, RevisionMode};
+#[cfg(feature = "email")]
+pub use email::{EmailConfig, MboxFormat};
 pub use extraction::{ExtractionConfig, ImageExtractionConfig, LanguageDetectionConfig, TokenReductionConfig};
 pub use formats::OutputFormat;
 pub use ocr::OcrConfig;
 pub use page::PageConfig;
 #[cfg(feature = "pdf")]
 pub use pdf::{HierarchyConfig, PdfConfig};
-pub use processing::{ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, PostProcessorConfig};
+pub use processing::{
+    ChunkSizing, ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, PostProcessorConfig,
+    SemanticChunkingConfig, SemanticThreshold, TokenizerFormat,
+};
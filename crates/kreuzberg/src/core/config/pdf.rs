@@ -24,6 +24,11 @@ pub struct PdfConfig {
     /// Hierarchy extraction configuration (None = hierarchy extraction disabled)
     #[serde(default)]
     pub hierarchy: Option<HierarchyConfig>,
+
+    /// Extract embedded files through the extractor registry and return them
+    /// in `ExtractionResult.children` (files are always listed in the metadata)
+    #[serde(default)]
+    pub extract_embedded_files: bool,
 }
 
 /// Hierarchy extraction configuration for PDF text structure analysis.
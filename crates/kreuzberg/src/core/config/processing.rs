@@ -13,11 +13,104 @@ use std::path::PathBuf;
 ///
 /// * `Text` - Generic text splitter, splits on whitespace and punctuation
 /// * `Markdown` - Markdown-aware splitter, preserves formatting and structure
+/// * `Structure` - Splits on section boundaries of the `DocumentStructure` tree,
+///   never splits tables or code blocks, and records the heading path of each chunk
+/// * `Semantic` - Splits between sentences where the embedding similarity of
+///   neighbouring sentences drops, using the model of `ChunkingConfig::embedding`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum ChunkerType {
     #[default]
     Text,
     Markdown,
+    Structure,
+    Semantic,
+}
+
+/// Unit in which chunk sizes and overlaps are measured.
+///
+/// Serialized with a `unit` tag, e.g. `{"unit": "characters"}` or
+/// `{"unit": "tokens", "tokenizer": "/models/tokenizer.json"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(tag = "unit", rename_all = "lowercase")]
+pub enum ChunkSizing {
+    /// Count Unicode characters (default)
+    #[default]
+    Characters,
+    /// Count tokens of a local tokenizer (requires the `chunking-tokens` feature)
+    Tokens {
+        /// Path to a HuggingFace `tokenizer.json` or a tiktoken BPE ranks file
+        #[cfg_attr(feature = "api", schema(value_type = String))]
+        tokenizer: PathBuf,
+        /// Tokenizer file format; detected from the file extension by default
+        #[serde(default)]
+        format: TokenizerFormat,
+        /// Pre-tokenization regex for tiktoken files (defaults to the `cl100k_base` pattern)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+    },
+}
+
+/// Format of a tokenizer file used for token-based chunk sizing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TokenizerFormat {
+    /// `.json` files are HuggingFace tokenizers, anything else is tiktoken
+    #[default]
+    Auto,
+    /// HuggingFace `tokenizer.json`
+    HuggingFace,
+    /// tiktoken BPE file with one `<base64 token> <rank>` pair per line
+    Tiktoken,
+}
+
+/// Settings of the semantic chunker.
+///
+/// Chunks never exceed `ChunkingConfig::max_characters`; groups of sentences
+/// smaller than `min_characters` are merged into a neighbouring chunk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct SemanticChunkingConfig {
+    /// How the similarity drop that starts a new chunk is chosen
+    #[serde(default)]
+    pub threshold: SemanticThreshold,
+
+    /// Minimum chunk size, in the unit of `ChunkingConfig::sizing`
+    ///
+    /// Default: 100
+    #[serde(default = "default_semantic_min_size", alias = "min_chars")]
+    pub min_characters: usize,
+}
+
+impl Default for SemanticChunkingConfig {
+    fn default() -> Self {
+        Self {
+            threshold: SemanticThreshold::default(),
+            min_characters: default_semantic_min_size(),
+        }
+    }
+}
+
+/// Breakpoint threshold of the semantic chunker.
+///
+/// Both variants work on the cosine distance between the embeddings of
+/// adjacent sentences; a chunk ends where the distance exceeds the threshold.
+/// Serialized with a `type` tag, e.g. `{"type": "percentile", "value": 95}`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SemanticThreshold {
+    /// Percentile (0-100) of all distances in the text
+    Percentile { value: f64 },
+    /// Number of standard deviations above the mean distance
+    StandardDeviation { value: f64 },
+}
+
+impl Default for SemanticThreshold {
+    fn default() -> Self {
+        Self::Percentile { value: 95.0 }
+    }
 }
 
 /// Post-processor configuration.
@@ -75,15 +168,18 @@ impl Default for PostProcessorConfig {
 ///
 /// Configures text chunking for document content, including chunk size,
 /// overlap, trimming behavior, and optional embeddings.
+///
+/// `max_characters` and `overlap` are measured in the unit selected by
+/// `sizing`: characters by default, or tokens of a local tokenizer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChunkingConfig {
-    /// Maximum characters per chunk
+    /// Maximum chunk size, in characters or tokens depending on `sizing`
     ///
     /// Default: 1000
     #[serde(default = "default_chunk_size", rename = "max_chars", alias = "max_characters")]
     pub max_characters: usize,
 
-    /// Overlap between chunks in characters
+    /// Overlap between chunks, in the same unit as `max_characters`
     ///
     /// Default: 200
     #[serde(default = "default_chunk_overlap", rename = "max_overlap", alias = "overlap")]
@@ -95,7 +191,7 @@ pub struct ChunkingConfig {
     #[serde(default = "default_trim")]
     pub trim: bool,
 
-    /// Type of chunker to use (Text or Markdown)
+    /// Type of chunker to use (Text, Markdown, Structure or Semantic)
     ///
     /// Default: Text
     #[serde(default = "default_chunker_type")]
@@ -108,6 +204,22 @@ pub struct ChunkingConfig {
     /// Use a preset configuration (overrides individual settings if provided)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preset: Option<String>,
+
+    /// Unit used to measure chunk sizes and overlaps
+    ///
+    /// Default: characters
+    #[serde(default)]
+    pub sizing: ChunkSizing,
+
+    /// Prepend the heading path ("Chapter > Section") to each chunk's text
+    ///
+    /// Only used by the `Structure` chunker. Default: false
+    #[serde(default)]
+    pub prepend_heading_context: bool,
+
+    /// Breakpoint threshold and minimum size of the `Semantic` chunker
+    #[serde(default)]
+    pub semantic: SemanticChunkingConfig,
 }
 
 impl Default for ChunkingConfig {
@@ -119,14 +231,17 @@ impl Default for ChunkingConfig {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: SemanticChunkingConfig::default(),
         }
     }
 }
 
 /// Embedding configuration for text chunks.
 ///
-/// Configures embedding generation using ONNX models via fastembed-rs.
-/// Requires the `embeddings` feature to be enabled.
+/// Configures embedding generation using local ONNX models via fastembed-rs or a
+/// remote OpenAI-compatible or Ollama API. Requires the `embeddings` feature to be enabled.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmbeddingConfig {
     /// The embedding model to use (defaults to "balanced" preset if not specified)
@@ -180,6 +295,48 @@ pub enum EmbeddingModelType {
 
     /// Use a custom ONNX model from HuggingFace
     Custom { model_id: String, dimensions: usize },
+
+    /// Use an OpenAI-compatible embeddings API (`POST {base_url}/embeddings`)
+    #[serde(rename = "openai")]
+    OpenAi {
+        /// API base URL, e.g. `https://api.openai.com/v1`
+        base_url: String,
+        /// Model name sent with every request
+        model: String,
+        /// Environment variable holding the API key, sent as a bearer token
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        api_key_env: Option<String>,
+        /// Expected vector size; responses of any other size are rejected
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dimensions: Option<usize>,
+        /// Retries for connection errors, timeouts, 429 and 5xx responses
+        #[serde(default = "default_max_retries")]
+        max_retries: u32,
+        /// Timeout of a single request in seconds
+        #[serde(default = "default_request_timeout_secs")]
+        timeout_secs: u64,
+    },
+
+    /// Use an Ollama server (`POST {base_url}/api/embed`)
+    Ollama {
+        /// Server URL (default: `http://localhost:11434`)
+        #[serde(default = "default_ollama_url")]
+        base_url: String,
+        /// Model name, e.g. `nomic-embed-text`
+        model: String,
+        /// Environment variable holding an API key for servers behind an authenticating proxy
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        api_key_env: Option<String>,
+        /// Expected vector size; responses of any other size are rejected
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dimensions: Option<usize>,
+        /// Retries for connection errors, timeouts, 429 and 5xx responses
+        #[serde(default = "default_max_retries")]
+        max_retries: u32,
+        /// Timeout of a single request in seconds
+        #[serde(default = "default_request_timeout_secs")]
+        timeout_secs: u64,
+    },
 }
 
 fn default_true() -> bool {
@@ -194,6 +351,10 @@ fn default_chunk_overlap() -> usize {
     200
 }
 
+fn default_semantic_min_size() -> usize {
+    100
+}
+
 fn default_trim() -> bool {
     true
 }
@@ -210,6 +371,18 @@ fn default_batch_size() -> usize {
     32
 }
 
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_request_timeout_secs() -> u64 {
+    60
+}
+
+fn default_ollama_url() -> String {
+    "http://localhost:11434".to_string()
+}
+
 fn default_model() -> EmbeddingModelType {
     EmbeddingModelType::Preset {
         name: "balanced".to_string(),
@@ -255,6 +428,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: ChunkSizing::Characters,
+            prepend_heading_context: false,
+            semantic: Default::default(),
         };
         assert_eq!(config.max_characters, 1000);
         assert_eq!(config.overlap, 200);
@@ -262,6 +438,43 @@ mod tests {
         assert_eq!(config.chunker_type, ChunkerType::Text);
     }
 
+    #[test]
+    fn test_chunk_sizing_serde() {
+        let config: ChunkingConfig = serde_json::from_str(r#"{"max_chars": 256}"#).unwrap();
+        assert_eq!(config.sizing, ChunkSizing::Characters);
+
+        let json = r#"{"max_chars": 256, "sizing": {"unit": "tokens", "tokenizer": "/models/tokenizer.json"}}"#;
+        let config: ChunkingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.sizing,
+            ChunkSizing::Tokens {
+                tokenizer: PathBuf::from("/models/tokenizer.json"),
+                format: TokenizerFormat::Auto,
+                pattern: None,
+            }
+        );
+
+        let value = serde_json::to_value(&config.sizing).unwrap();
+        assert_eq!(value["unit"], "tokens");
+        assert_eq!(value["format"], "auto");
+    }
+
+    #[test]
+    fn test_semantic_config_serde() {
+        let config: ChunkingConfig = serde_json::from_str(r#"{"chunker_type": "Semantic"}"#).unwrap();
+        assert_eq!(config.chunker_type, ChunkerType::Semantic);
+        assert_eq!(config.semantic, SemanticChunkingConfig::default());
+        assert_eq!(config.semantic.threshold, SemanticThreshold::Percentile { value: 95.0 });
+
+        let json = r#"{"semantic": {"threshold": {"type": "standard_deviation", "value": 1.5}, "min_chars": 50}}"#;
+        let config: ChunkingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.semantic.threshold,
+            SemanticThreshold::StandardDeviation { value: 1.5 }
+        );
+        assert_eq!(config.semantic.min_characters, 50);
+    }
+
     #[test]
     fn test_embedding_config_default() {
         let config = EmbeddingConfig::default();
@@ -373,4 +586,37 @@ mod tests {
             _ => panic!("Expected Custom variant"),
         }
     }
+
+    #[test]
+    fn test_embedding_model_type_remote_deserialization() {
+        let json = r#"{"type": "openai", "base_url": "http://localhost:8080/v1", "model": "embed-small", "api_key_env": "EMBED_KEY"}"#;
+        match serde_json::from_str::<EmbeddingModelType>(json).unwrap() {
+            EmbeddingModelType::OpenAi {
+                base_url,
+                api_key_env,
+                dimensions,
+                max_retries,
+                timeout_secs,
+                ..
+            } => {
+                assert_eq!(base_url, "http://localhost:8080/v1");
+                assert_eq!(api_key_env.as_deref(), Some("EMBED_KEY"));
+                assert_eq!(dimensions, None);
+                assert_eq!(max_retries, 3);
+                assert_eq!(timeout_secs, 60);
+            }
+            _ => panic!("Expected OpenAi variant"),
+        }
+
+        let json = r#"{"type": "ollama", "model": "nomic-embed-text", "dimensions": 768}"#;
+        match serde_json::from_str::<EmbeddingModelType>(json).unwrap() {
+            EmbeddingModelType::Ollama {
+                base_url, dimensions, ..
+            } => {
+                assert_eq!(base_url, "http://localhost:11434");
+                assert_eq!(dimensions, Some(768));
+            }
+            _ => panic!("Expected Ollama variant"),
+        }
+    }
 }
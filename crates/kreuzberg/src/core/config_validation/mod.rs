@@ -149,6 +149,12 @@ mod tests {
         assert!(validate_language_code("*").is_ok());
     }
 
+    #[test]
+    fn test_validate_language_code_auto_keyword() {
+        assert!(validate_language_code("auto").is_ok());
+        assert!(validate_language_code("AUTO").is_ok());
+    }
+
     #[test]
     fn test_validate_language_code_invalid() {
         let result = validate_language_code("invalid");
@@ -190,6 +196,8 @@ mod tests {
     fn test_validate_output_format_valid() {
         assert!(validate_output_format("text").is_ok());
         assert!(validate_output_format("markdown").is_ok());
+        assert!(validate_output_format("alto").is_ok());
+        assert!(validate_output_format("hocr").is_ok());
     }
 
     #[test]
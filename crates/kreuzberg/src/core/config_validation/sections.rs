@@ -90,9 +90,12 @@ const VALID_TESSERACT_PSM: &[i32] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12,
 const VALID_TESSERACT_OEM: &[i32] = &[0, 1, 2, 3];
 
 /// Valid output formats for document extraction.
-/// Supports plain text, markdown, djot, and HTML output formats.
+/// Supports plain text, markdown, djot, and HTML output formats, plus the OCR
+/// layout formats ALTO, PAGE XML and hOCR.
 /// Also accepts aliases: "text" for "plain", "md" for "markdown".
-const VALID_OUTPUT_FORMATS: &[&str] = &["plain", "text", "markdown", "md", "djot", "html"];
+const VALID_OUTPUT_FORMATS: &[&str] = &[
+    "plain", "text", "markdown", "md", "djot", "html", "alto", "pagexml", "hocr",
+];
 
 /// Validate a binarization method string.
 ///
@@ -226,8 +229,9 @@ pub fn validate_ocr_backend(backend: &str) -> Result<()> {
 pub fn validate_language_code(code: &str) -> Result<()> {
     let code_lower = code.to_lowercase();
 
-    // Accept "all" and "*" as special values to auto-detect installed languages
-    if code_lower == "all" || code_lower == "*" {
+    // Accept "all" and "*" as special values to auto-detect installed languages,
+    // and "auto" to pick the OCR language per page from the recognized text
+    if code_lower == "all" || code_lower == "*" || code_lower == "auto" {
         return Ok(());
     }
 
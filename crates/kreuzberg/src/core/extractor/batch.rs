@@ -14,6 +14,13 @@ use std::time::Instant;
 use super::bytes::extract_bytes;
 use super::file::extract_file;
 
+/// Concurrency limit of batch extraction: `max_concurrent_extractions` or `num_cpus * 1.5`.
+pub(crate) fn max_concurrent_extractions(config: &ExtractionConfig) -> usize {
+    config
+        .max_concurrent_extractions
+        .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize)
+}
+
 /// Extract content from multiple files concurrently.
 ///
 /// This function processes multiple files in parallel, automatically managing
@@ -69,9 +76,8 @@ pub async fn batch_extract_file(
 
     let config_arc = Arc::new(config.clone());
 
-    let max_concurrent = config_arc
-        .max_concurrent_extractions
-        .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
+    let max_concurrent = max_concurrent_extractions(&config_arc);
+    crate::metrics::metrics().set_max_concurrent_extractions(max_concurrent);
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
 
     let mut tasks = JoinSet::new();
@@ -124,6 +130,7 @@ pub async fn batch_extract_file(
                     metadata,
                     tables: vec![],
                     detected_languages: None,
+                    language_spans: None,
                     chunks: None,
                     images: None,
                     djot_content: None,
@@ -135,6 +142,7 @@ pub async fn batch_extract_file(
                     extracted_keywords: None,
                     quality_score: None,
                     processing_warnings: Vec::new(),
+                    children: None,
                 });
             }
             Err(join_err) => {
@@ -200,9 +208,8 @@ pub async fn batch_extract_bytes(
 
     let config_arc = Arc::new(config.clone());
 
-    let max_concurrent = config_arc
-        .max_concurrent_extractions
-        .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
+    let max_concurrent = max_concurrent_extractions(&config_arc);
+    crate::metrics::metrics().set_max_concurrent_extractions(max_concurrent);
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
 
     let mut tasks = JoinSet::new();
@@ -255,6 +262,7 @@ pub async fn batch_extract_bytes(
                     metadata,
                     tables: vec![],
                     detected_languages: None,
+                    language_spans: None,
                     chunks: None,
                     images: None,
                     djot_content: None,
@@ -266,6 +274,7 @@ pub async fn batch_extract_bytes(
                     extracted_keywords: None,
                     quality_score: None,
                     processing_warnings: Vec::new(),
+                    children: None,
                 });
             }
             Err(join_err) => {
@@ -22,8 +22,10 @@ use super::file::record_error;
 /// 1. Validate MIME type
 /// 2. Handle legacy format conversion if needed
 /// 3. Select appropriate extractor from registry
-/// 4. Extract content
-/// 5. Run post-processing pipeline
+/// 4. Check cache for existing result (if caching enabled)
+/// 5. Extract content
+/// 6. Run post-processing pipeline
+/// 7. Store result in cache (if caching enabled)
 ///
 /// # Arguments
 ///
@@ -11,6 +11,7 @@ use crate::KreuzbergError;
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::core::mime::{LEGACY_POWERPOINT_MIME_TYPE, LEGACY_WORD_MIME_TYPE};
+use crate::plugins::DocumentExtractor;
 use crate::types::ExtractionResult;
 use std::path::Path;
 
@@ -87,7 +88,7 @@ pub(in crate::core::extractor) fn record_error(error: &KreuzbergError) {
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the file to extract
+/// * `path` - Path to the file to extract (with the `email` feature, a Maildir directory is accepted too)
 /// * `mime_type` - Optional MIME type override. If None, will be auto-detected
 /// * `config` - Extraction configuration
 ///
@@ -137,6 +138,12 @@ pub async fn extract_file(
     let result = async {
         io::validate_file_exists(path)?;
 
+        #[cfg(feature = "email")]
+        if path.is_dir() && crate::extraction::mailbox::is_maildir(path) {
+            let result = crate::extractors::extract_maildir(path, config).await?;
+            return crate::core::pipeline::run_pipeline(result, config).await;
+        }
+
         let detected_mime = mime::detect_or_validate(Some(path), mime_type)?;
 
         // Native DOC/PPT extractors are registered in the plugin registry.
@@ -183,8 +190,23 @@ pub(in crate::core::extractor) async fn extract_file_with_extractor(
     crate::extractors::ensure_initialized()?;
 
     let extractor = get_extractor(mime_type)?;
-    let mut result = extractor.extract_file(path, mime_type, config).await?;
-    result = crate::core::pipeline::run_pipeline(result, config).await?;
+    let cache_slot = crate::cache::file_slot(path, mime_type, &extractor_id(extractor.as_ref()), config);
+    if let Some(result) = cache_slot.as_ref().and_then(|slot| slot.get()) {
+        return Ok(result);
+    }
+
+    let timer = crate::metrics::metrics().start_extraction(extractor.name(), mime_type);
+    let result = async {
+        let result = extractor.extract_file(path, mime_type, config).await?;
+        crate::core::pipeline::run_pipeline(result, config).await
+    }
+    .await;
+    timer.finish(&result);
+
+    let mut result = result?;
+    if let Some(slot) = cache_slot {
+        slot.store(&mut result);
+    }
     Ok(result)
 }
 
@@ -196,7 +218,27 @@ pub(in crate::core::extractor) async fn extract_bytes_with_extractor(
     crate::extractors::ensure_initialized()?;
 
     let extractor = get_extractor(mime_type)?;
-    let mut result = extractor.extract_bytes(content, mime_type, config).await?;
-    result = crate::core::pipeline::run_pipeline(result, config).await?;
+    let cache_slot = crate::cache::bytes_slot(content, mime_type, &extractor_id(extractor.as_ref()), config);
+    if let Some(result) = cache_slot.as_ref().and_then(|slot| slot.get()) {
+        return Ok(result);
+    }
+
+    let timer = crate::metrics::metrics().start_extraction(extractor.name(), mime_type);
+    let result = async {
+        let result = extractor.extract_bytes(content, mime_type, config).await?;
+        crate::core::pipeline::run_pipeline(result, config).await
+    }
+    .await;
+    timer.finish(&result);
+
+    let mut result = result?;
+    if let Some(slot) = cache_slot {
+        slot.store(&mut result);
+    }
     Ok(result)
 }
+
+/// Identify an extractor and its version for result cache keys.
+fn extractor_id(extractor: &dyn DocumentExtractor) -> String {
+    format!("{}@{}", extractor.name(), extractor.version())
+}
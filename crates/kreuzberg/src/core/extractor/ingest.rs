@@ -0,0 +1,735 @@
+//! Recursive directory ingestion.
+//!
+//! [`ingest_directory`] walks a directory tree, keeps the files that pass the
+//! glob, MIME type and size filters, extracts them concurrently and writes one
+//! JSON record per file, either as lines of a JSONL file or as one record file
+//! per input below an output directory.
+//!
+//! With a checkpoint file every written file is recorded by path, modification
+//! time and SHA-256. A re-run skips files whose modification time is unchanged,
+//! and files whose modification time changed but whose content hashes the
+//! same, so an interrupted ingestion resumes where it stopped.
+
+use crate::cache::digest_file;
+use crate::core::config::ExtractionConfig;
+use crate::core::mime::{detect_mime_type, validate_mime_type};
+use crate::types::{ErrorMetadata, ExtractionResult};
+use crate::{KreuzbergError, Result};
+use glob::{MatchOptions, Pattern};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Instant, UNIX_EPOCH};
+use walkdir::WalkDir;
+
+use super::batch::max_concurrent_extractions;
+use super::file::extract_file;
+
+/// Where [`ingest_directory`] writes its records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IngestOutput {
+    /// Append one JSON line per file to this file.
+    JsonlFile(PathBuf),
+    /// Write `<relative path>.json` for each file below this directory.
+    Directory(PathBuf),
+}
+
+/// Options for [`ingest_directory`].
+///
+/// Glob patterns without a `/` are matched against the file name, other
+/// patterns against the path relative to the ingested directory, where `**`
+/// matches any number of directories. Exclude patterns also prune directories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IngestOptions {
+    /// Where records are written
+    pub output: IngestOutput,
+    /// File recording ingested files; they are skipped on later runs unless changed
+    pub checkpoint: Option<PathBuf>,
+    /// Glob patterns of files to ingest (all files when empty)
+    pub include: Vec<String>,
+    /// Glob patterns of files and directories to skip
+    pub exclude: Vec<String>,
+    /// MIME types to ingest, `type/*` matching a whole type (all supported types when empty)
+    pub mime_types: Vec<String>,
+    /// Skip files smaller than this many bytes
+    pub min_size: Option<u64>,
+    /// Skip files larger than this many bytes
+    pub max_size: Option<u64>,
+    /// Follow symbolic links while walking
+    pub follow_symlinks: bool,
+}
+
+impl IngestOptions {
+    /// Options that ingest every supported file below the directory into `output`.
+    pub fn new(output: IngestOutput) -> Self {
+        Self {
+            output,
+            checkpoint: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            mime_types: Vec::new(),
+            min_size: None,
+            max_size: None,
+            follow_symlinks: false,
+        }
+    }
+}
+
+/// One ingested file, as written to the output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestRecord {
+    /// Path relative to the ingested directory, `/`-separated
+    pub path: String,
+    /// File size in bytes
+    pub size: u64,
+    /// Modification time in nanoseconds since the Unix epoch
+    pub modified_ns: u64,
+    /// Hex SHA-256 of the file content, absent if the file could not be read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Detected MIME type
+    pub mime_type: String,
+    /// Extraction result, absent if extraction failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<ExtractionResult>,
+    /// Extraction error, absent if extraction succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorMetadata>,
+}
+
+/// Counts reported by [`ingest_directory`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IngestSummary {
+    /// Files that passed the filters
+    pub matched: usize,
+    /// Files extracted and written
+    pub succeeded: usize,
+    /// Files written with an error record; they are retried on the next run
+    pub failed: usize,
+    /// Files skipped because the checkpoint shows them unchanged
+    pub unchanged: usize,
+    /// Files skipped by the glob, MIME type or size filters, or because no extractor supports them
+    pub filtered: usize,
+}
+
+/// Extract every matching file below `root` and write one record per file.
+///
+/// Files are extracted concurrently, bounded like [`batch_extract_file`](super::batch_extract_file).
+/// Records are written as soon as a file finishes, so output order follows
+/// completion order. Failed extractions are written with an `error` instead of
+/// a `result` and are not added to the checkpoint.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if `root` is not a directory or a
+/// pattern is invalid, and `KreuzbergError::Io` if the output or checkpoint
+/// cannot be written.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::{IngestOptions, IngestOutput, ingest_directory};
+/// use kreuzberg::ExtractionConfig;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let mut options = IngestOptions::new(IngestOutput::JsonlFile("share.jsonl".into()));
+/// options.checkpoint = Some("share.checkpoint".into());
+/// options.include = vec!["*.pdf".to_string(), "*.docx".to_string()];
+/// options.exclude = vec!["archive".to_string()];
+///
+/// let summary = ingest_directory("/mnt/share", &options, &ExtractionConfig::default()).await?;
+/// println!("{} extracted, {} unchanged", summary.succeeded, summary.unchanged);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn ingest_directory(
+    root: impl AsRef<Path>,
+    options: &IngestOptions,
+    config: &ExtractionConfig,
+) -> Result<IngestSummary> {
+    use tokio::task::JoinSet;
+
+    let root = root.as_ref();
+    if !root.is_dir() {
+        return Err(KreuzbergError::validation(format!(
+            "Ingest path is not a directory: {}",
+            root.display()
+        )));
+    }
+    let root = root.canonicalize()?;
+    let filter = FileFilter::new(&options.include, &options.exclude, &options.mime_types)?;
+
+    let mut sink = RecordSink::open(&options.output)?;
+    let mut checkpoint = options.checkpoint.as_deref().map(Checkpoint::open).transpose()?;
+    let mut own_paths = vec![sink.path().canonicalize()?];
+    if let Some(path) = &options.checkpoint {
+        own_paths.push(path.canonicalize()?);
+    }
+
+    let mut summary = IngestSummary::default();
+    let candidates = discover(&root, options, &filter, &own_paths, &mut summary);
+
+    let config = Arc::new(config.clone());
+    let max_concurrent = max_concurrent_extractions(&config);
+    let mut pending = candidates.into_iter();
+    let mut tasks = JoinSet::new();
+
+    loop {
+        while tasks.len() < max_concurrent {
+            let Some(candidate) = pending.next() else {
+                break;
+            };
+            let previous = checkpoint.as_ref().and_then(|c| c.get(&candidate.relative));
+            if previous.is_some_and(|p| p.size == candidate.size && p.modified_ns == candidate.modified_ns) {
+                summary.unchanged += 1;
+                continue;
+            }
+            let known_digest = previous.map(|p| p.sha256.clone());
+            tasks.spawn(ingest_file(candidate, known_digest, Arc::clone(&config)));
+        }
+
+        let Some(joined) = tasks.join_next().await else {
+            break;
+        };
+        let outcome = joined.map_err(|e| KreuzbergError::Other(format!("Task panicked: {}", e)))?;
+
+        match outcome {
+            Outcome::Unchanged { candidate, digest } => {
+                summary.unchanged += 1;
+                if let Some(checkpoint) = checkpoint.as_mut() {
+                    checkpoint.record(candidate.entry(digest))?;
+                }
+            }
+            Outcome::Extracted {
+                candidate,
+                digest,
+                result,
+            } => {
+                let succeeded = result.is_ok() && digest.is_some();
+                let entry = digest.clone().map(|d| candidate.entry(d));
+                sink.write(&candidate.record(digest, *result))?;
+                if succeeded {
+                    summary.succeeded += 1;
+                    if let (Some(checkpoint), Some(entry)) = (checkpoint.as_mut(), entry) {
+                        checkpoint.record(entry)?;
+                    }
+                } else {
+                    summary.failed += 1;
+                }
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// A file selected for ingestion.
+struct Candidate {
+    path: PathBuf,
+    relative: String,
+    size: u64,
+    modified_ns: u64,
+    mime_type: String,
+}
+
+impl Candidate {
+    fn entry(&self, sha256: String) -> CheckpointEntry {
+        CheckpointEntry {
+            path: self.relative.clone(),
+            size: self.size,
+            modified_ns: self.modified_ns,
+            sha256,
+        }
+    }
+
+    fn record(self, sha256: Option<String>, result: Result<ExtractionResult>) -> IngestRecord {
+        let (result, error) = split_result(result);
+        IngestRecord {
+            path: self.relative,
+            size: self.size,
+            modified_ns: self.modified_ns,
+            sha256,
+            mime_type: self.mime_type,
+            result,
+            error,
+        }
+    }
+}
+
+/// Split an extraction outcome into the `result` and `error` fields of a record.
+pub(super) fn split_result(result: Result<ExtractionResult>) -> (Option<ExtractionResult>, Option<ErrorMetadata>) {
+    match result {
+        Ok(mut result) => {
+            // The deprecated mirror of `metadata.output_format` would serialize as a duplicate key
+            result.metadata.additional.remove("output_format");
+            (Some(result), None)
+        }
+        Err(e) => (
+            None,
+            Some(ErrorMetadata {
+                error_type: format!("{:?}", e),
+                message: e.to_string(),
+            }),
+        ),
+    }
+}
+
+enum Outcome {
+    /// The content hashes as recorded in the checkpoint.
+    Unchanged { candidate: Candidate, digest: String },
+    /// The file was extracted, or could not be read (`digest` is `None`).
+    Extracted {
+        candidate: Candidate,
+        digest: Option<String>,
+        result: Box<Result<ExtractionResult>>,
+    },
+}
+
+async fn ingest_file(candidate: Candidate, known_digest: Option<String>, config: Arc<ExtractionConfig>) -> Outcome {
+    let path = candidate.path.clone();
+    let digest = match tokio::task::spawn_blocking(move || digest_file(&path)).await {
+        Ok(Ok(digest)) => digest,
+        Ok(Err(e)) => {
+            return Outcome::Extracted {
+                candidate,
+                digest: None,
+                result: Box::new(Err(e.into())),
+            };
+        }
+        Err(e) => {
+            return Outcome::Extracted {
+                candidate,
+                digest: None,
+                result: Box::new(Err(KreuzbergError::Other(format!("Task panicked: {}", e)))),
+            };
+        }
+    };
+
+    if known_digest.as_deref() == Some(digest.as_str()) {
+        return Outcome::Unchanged { candidate, digest };
+    }
+
+    let start = Instant::now();
+    let mut result = crate::core::batch_mode::with_batch_mode(async {
+        extract_file(&candidate.path, Some(&candidate.mime_type), &config).await
+    })
+    .await;
+    if let Ok(result) = result.as_mut() {
+        result.metadata.extraction_duration_ms = Some(start.elapsed().as_millis() as u64);
+    }
+
+    Outcome::Extracted {
+        candidate,
+        digest: Some(digest),
+        result: Box::new(result),
+    }
+}
+
+/// Walk `root` and return the files that pass the filters, in path order.
+fn discover(
+    root: &Path,
+    options: &IngestOptions,
+    filter: &FileFilter,
+    own_paths: &[PathBuf],
+    summary: &mut IngestSummary,
+) -> Vec<Candidate> {
+    let walker = WalkDir::new(root)
+        .follow_links(options.follow_symlinks)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !(filter.is_excluded(&relative_path(root, entry.path()))
+                    || own_paths.iter().any(|own| entry.path().starts_with(own)))
+        });
+
+    let mut candidates = Vec::new();
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                tracing::warn!("Skipping unreadable path during ingestion: {}", e);
+                continue;
+            }
+        };
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = relative_path(root, entry.path());
+        if !filter.is_included(&relative) {
+            summary.filtered += 1;
+            continue;
+        }
+
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                tracing::warn!("Skipping {} during ingestion: {}", entry.path().display(), e);
+                continue;
+            }
+        };
+        let size = metadata.len();
+        if options.min_size.is_some_and(|min| size < min) || options.max_size.is_some_and(|max| size > max) {
+            summary.filtered += 1;
+            continue;
+        }
+
+        let Some(mime_type) = filter.mime_type(entry.path()) else {
+            summary.filtered += 1;
+            continue;
+        };
+
+        let modified_ns = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_nanos() as u64);
+
+        summary.matched += 1;
+        candidates.push(Candidate {
+            path: entry.into_path(),
+            relative,
+            size,
+            modified_ns,
+            mime_type,
+        });
+    }
+    candidates
+}
+
+/// `path` relative to `root`, `/`-separated.
+pub(super) fn relative_path(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Compiled include/exclude patterns and MIME filters.
+pub(super) struct FileFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+    mime_types: Vec<String>,
+}
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+impl FileFilter {
+    pub(super) fn new(include: &[String], exclude: &[String], mime_types: &[String]) -> Result<Self> {
+        let compile = |patterns: &[String]| -> Result<Vec<Pattern>> {
+            patterns
+                .iter()
+                .map(|p| {
+                    Pattern::new(p)
+                        .map_err(|e| KreuzbergError::validation(format!("Invalid glob pattern '{}': {}", p, e)))
+                })
+                .collect()
+        };
+        Ok(Self {
+            include: compile(include)?,
+            exclude: compile(exclude)?,
+            mime_types: mime_types.iter().map(|m| m.to_ascii_lowercase()).collect(),
+        })
+    }
+
+    pub(super) fn is_included(&self, relative: &str) -> bool {
+        (self.include.is_empty() || Self::matches_any(&self.include, relative)) && !self.is_excluded(relative)
+    }
+
+    pub(super) fn is_excluded(&self, relative: &str) -> bool {
+        Self::matches_any(&self.exclude, relative)
+    }
+
+    fn matches_any(patterns: &[Pattern], relative: &str) -> bool {
+        let name = relative.rsplit('/').next().unwrap_or(relative);
+        patterns.iter().any(|pattern| {
+            let target = if pattern.as_str().contains('/') { relative } else { name };
+            pattern.matches_with(target, MATCH_OPTIONS)
+        })
+    }
+
+    /// The detected MIME type of `path` if an extractor supports it and it passes the MIME filter.
+    pub(super) fn mime_type(&self, path: &Path) -> Option<String> {
+        detect_mime_type(path, false)
+            .and_then(|m| validate_mime_type(&m))
+            .ok()
+            .filter(|mime_type| self.accepts_mime(mime_type))
+    }
+
+    fn accepts_mime(&self, mime_type: &str) -> bool {
+        let mime_type = mime_type.to_ascii_lowercase();
+        self.mime_types.is_empty()
+            || self
+                .mime_types
+                .iter()
+                .any(|accepted| match accepted.strip_suffix("/*") {
+                    Some(top_level) => mime_type.split('/').next() == Some(top_level),
+                    None => *accepted == mime_type,
+                })
+    }
+}
+
+enum RecordSink {
+    Jsonl { path: PathBuf, writer: BufWriter<File> },
+    Directory(PathBuf),
+}
+
+impl RecordSink {
+    fn open(output: &IngestOutput) -> Result<Self> {
+        match output {
+            IngestOutput::JsonlFile(path) => Ok(Self::Jsonl {
+                path: path.clone(),
+                writer: BufWriter::new(open_append(path)?),
+            }),
+            IngestOutput::Directory(dir) => {
+                std::fs::create_dir_all(dir)?;
+                Ok(Self::Directory(dir.clone()))
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        match self {
+            Self::Jsonl { path, .. } => path,
+            Self::Directory(dir) => dir,
+        }
+    }
+
+    fn write(&mut self, record: &IngestRecord) -> Result<()> {
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        match self {
+            Self::Jsonl { writer, .. } => {
+                writer.write_all(line.as_bytes())?;
+                writer.flush()?;
+            }
+            Self::Directory(dir) => {
+                let target = dir.join(format!("{}.json", record.path));
+                if let Some(parent) = target.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(target, line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Open a line-oriented file for appending, terminating a line cut off by an earlier crash.
+fn open_append(path: &Path) -> Result<File> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().read(true).append(true).create(true).open(path)?;
+    if file.metadata()?.len() > 0 {
+        let mut last = [0u8; 1];
+        file.seek(SeekFrom::End(-1))?;
+        file.read_exact(&mut last)?;
+        if last[0] != b'\n' {
+            file.write_all(b"\n")?;
+        }
+    }
+    Ok(file)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CheckpointEntry {
+    path: String,
+    size: u64,
+    modified_ns: u64,
+    sha256: String,
+}
+
+/// Append-only log of ingested files; later lines win.
+struct Checkpoint {
+    entries: HashMap<String, CheckpointEntry>,
+    writer: BufWriter<File>,
+}
+
+impl Checkpoint {
+    fn open(path: &Path) -> Result<Self> {
+        let mut entries = HashMap::new();
+        if path.exists() {
+            for line in BufReader::new(File::open(path)?).lines() {
+                // A line cut off by a crash is skipped; that file is simply ingested again
+                if let Ok(entry) = serde_json::from_str::<CheckpointEntry>(&line?) {
+                    entries.insert(entry.path.clone(), entry);
+                }
+            }
+        }
+        Ok(Self {
+            entries,
+            writer: BufWriter::new(open_append(path)?),
+        })
+    }
+
+    fn get(&self, path: &str) -> Option<&CheckpointEntry> {
+        self.entries.get(path)
+    }
+
+    fn record(&mut self, entry: CheckpointEntry) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, &entry)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+        self.entries.insert(entry.path.clone(), entry);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn write(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn read_records(path: &Path) -> Vec<IngestRecord> {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn sorted_paths(records: &[IngestRecord]) -> Vec<String> {
+        let mut paths: Vec<String> = records.iter().map(|r| r.path.clone()).collect();
+        paths.sort();
+        paths
+    }
+
+    fn config() -> ExtractionConfig {
+        ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ingest_filters() {
+        let input = tempdir().unwrap();
+        write(input.path(), "a.txt", "alpha");
+        write(input.path(), "notes/b.md", "# beta");
+        write(input.path(), "notes/big.txt", &"x".repeat(2048));
+        write(input.path(), "skip/c.txt", "gamma");
+        write(input.path(), "data.unknownext", "??");
+
+        let out = tempdir().unwrap();
+        let jsonl = out.path().join("out.jsonl");
+        let mut options = IngestOptions::new(IngestOutput::JsonlFile(jsonl.clone()));
+        options.exclude = vec!["skip".to_string()];
+        options.max_size = Some(1024);
+        options.mime_types = vec!["text/*".to_string()];
+
+        let summary = ingest_directory(input.path(), &options, &config()).await.unwrap();
+        assert_eq!(summary.matched, 2);
+        assert_eq!(summary.succeeded, 2);
+        assert_eq!(summary.filtered, 2);
+
+        let records = read_records(&jsonl);
+        assert_eq!(sorted_paths(&records), vec!["a.txt", "notes/b.md"]);
+        let a = records.iter().find(|r| r.path == "a.txt").unwrap();
+        assert_eq!(a.result.as_ref().unwrap().content.trim(), "alpha");
+        assert_eq!(a.sha256.as_ref().unwrap().len(), 64);
+
+        let out_dir = tempdir().unwrap();
+        let mut options = IngestOptions::new(IngestOutput::Directory(out_dir.path().to_path_buf()));
+        options.include = vec!["notes/*.md".to_string()];
+        let summary = ingest_directory(input.path(), &options, &config()).await.unwrap();
+        assert_eq!(summary.succeeded, 1);
+        assert_eq!(read_records(&out_dir.path().join("notes/b.md.json")).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_ingest_resumes_from_checkpoint() {
+        let input = tempdir().unwrap();
+        write(input.path(), "one.txt", "first");
+        write(input.path(), "two.txt", "second");
+
+        let out = tempdir().unwrap();
+        let jsonl = out.path().join("out.jsonl");
+        let mut options = IngestOptions::new(IngestOutput::JsonlFile(jsonl.clone()));
+        options.checkpoint = Some(out.path().join("checkpoint.jsonl"));
+
+        let summary = ingest_directory(input.path(), &options, &config()).await.unwrap();
+        assert_eq!((summary.succeeded, summary.unchanged), (2, 0));
+
+        let summary = ingest_directory(input.path(), &options, &config()).await.unwrap();
+        assert_eq!((summary.succeeded, summary.unchanged), (0, 2));
+
+        // Same content with a new modification time is recognised by its hash
+        let one = input.path().join("one.txt");
+        let later = fs::metadata(&one).unwrap().modified().unwrap() + std::time::Duration::from_secs(60);
+        File::options()
+            .write(true)
+            .open(&one)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        write(input.path(), "two.txt", "second, edited");
+
+        let summary = ingest_directory(input.path(), &options, &config()).await.unwrap();
+        assert_eq!((summary.succeeded, summary.unchanged), (1, 1));
+        let records = read_records(&jsonl);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2].path, "two.txt");
+    }
+
+    #[tokio::test]
+    async fn test_ingest_skips_own_output_and_torn_lines() {
+        let input = tempdir().unwrap();
+        write(input.path(), "doc.txt", "content");
+        let jsonl = input.path().join("out.jsonl");
+        fs::write(&jsonl, "{\"path\": \"cut-off").unwrap();
+
+        let options = IngestOptions::new(IngestOutput::JsonlFile(jsonl.clone()));
+        let summary = ingest_directory(input.path(), &options, &config()).await.unwrap();
+        assert_eq!(summary.matched, 1);
+
+        let content = fs::read_to_string(&jsonl).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(serde_json::from_str::<IngestRecord>(lines[1]).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_ingest_rejects_invalid_input() {
+        let out = tempdir().unwrap();
+        let options = IngestOptions::new(IngestOutput::JsonlFile(out.path().join("out.jsonl")));
+        let missing = ingest_directory(out.path().join("missing"), &options, &config()).await;
+        assert!(matches!(missing, Err(KreuzbergError::Validation { .. })));
+
+        let mut options = options;
+        options.include = vec!["[".to_string()];
+        let invalid = ingest_directory(out.path(), &options, &config()).await;
+        assert!(matches!(invalid, Err(KreuzbergError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_filter_patterns() {
+        let mut options = IngestOptions::new(IngestOutput::Directory(PathBuf::from("out")));
+        options.include = vec!["*.pdf".to_string(), "reports/**/*.docx".to_string()];
+        options.exclude = vec!["drafts".to_string()];
+        let filter = FileFilter::new(&options.include, &options.exclude, &options.mime_types).unwrap();
+
+        assert!(filter.is_included("a.pdf"));
+        assert!(filter.is_included("deep/nested/b.pdf"));
+        assert!(filter.is_included("reports/2024/q1/c.docx"));
+        assert!(!filter.is_included("other/c.docx"));
+        assert!(filter.is_excluded("reports/drafts"));
+    }
+}
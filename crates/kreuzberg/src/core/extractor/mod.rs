@@ -10,6 +10,9 @@
 //! - [`extract_bytes`] - Extract content from a byte array
 //! - [`batch_extract_file`] - Extract content from multiple files concurrently
 //! - [`batch_extract_bytes`] - Extract content from multiple byte arrays concurrently
+//! - [`ingest_directory`] - Extract every matching file below a directory, resumably
+//! - [`watch_directory`] - Re-extract files below a directory as they change
+//! - [`create_searchable_pdf`] - Write a scan as a PDF with an invisible OCR text layer
 
 mod bytes;
 mod file;
@@ -19,6 +22,12 @@ mod sync;
 
 #[cfg(feature = "tokio-runtime")]
 mod batch;
+#[cfg(feature = "tokio-runtime")]
+mod ingest;
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+mod searchable;
+#[cfg(feature = "watch")]
+mod watch;
 
 // Re-export public API
 pub use bytes::extract_bytes;
@@ -29,10 +38,22 @@ pub use sync::{batch_extract_bytes_sync, extract_bytes_sync};
 #[cfg(feature = "tokio-runtime")]
 pub use sync::extract_file_sync;
 
+#[cfg(feature = "api")]
+pub(crate) use batch::max_concurrent_extractions;
 #[cfg(feature = "tokio-runtime")]
 pub use batch::{batch_extract_bytes, batch_extract_file};
 #[cfg(feature = "tokio-runtime")]
-pub use sync::batch_extract_file_sync;
+pub use ingest::{IngestOptions, IngestOutput, IngestRecord, IngestSummary, ingest_directory};
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+pub use searchable::{create_searchable_pdf, create_searchable_pdf_file};
+#[cfg(feature = "watch")]
+pub use sync::watch_directory_sync;
+#[cfg(feature = "tokio-runtime")]
+pub use sync::{batch_extract_file_sync, ingest_directory_sync};
+#[cfg(all(feature = "pdf", feature = "ocr", feature = "tokio-runtime"))]
+pub use sync::{create_searchable_pdf_file_sync, create_searchable_pdf_sync};
+#[cfg(feature = "watch")]
+pub use watch::{WatchEvent, WatchEventKind, WatchOptions, watch_directory};
 
 #[cfg(test)]
 mod tests {
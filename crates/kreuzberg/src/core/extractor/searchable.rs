@@ -0,0 +1,250 @@
+//! Searchable PDF generation.
+//!
+//! Turns a scanned PDF or an image into a PDF/A-2b document that shows the
+//! original page images and carries an invisible OCR text layer, so the output
+//! looks like the scan but can be searched and copied from.
+
+use crate::core::config::ExtractionConfig;
+use crate::core::mime::{PDF_MIME_TYPE, detect_or_validate, validate_mime_type};
+use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
+use crate::pdf::searchable::{SearchablePdfPage, write_searchable_pdf};
+use crate::plugins::registry::get_ocr_backend_registry;
+use crate::types::{OcrElementConfig, OcrElementLevel, TesseractConfig};
+use crate::{KreuzbergError, OcrConfig, Result};
+use image::DynamicImage;
+use std::io::Cursor;
+use std::path::Path;
+
+/// Resolution assumed for image inputs when sizing their PDF pages.
+const IMAGE_DPI: f64 = 300.0;
+
+const PDF_POINTS_PER_INCH: f64 = 72.0;
+
+/// Create a searchable PDF from a scanned PDF or an image.
+///
+/// Every PDF page is rendered to an image and OCR'd; an image input becomes a
+/// single page (the first frame of a multi-frame TIFF) sized at 300 DPI. The
+/// output keeps those images and adds an invisible text layer positioned from the
+/// recognized words, or lines for backends that only report lines.
+///
+/// OCR settings come from `config.ocr` (defaults when unset). Image preprocessing
+/// is disabled for this call so element geometry lines up with the embedded
+/// image.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::UnsupportedFormat` for inputs other than PDFs and
+/// images, and propagates rendering, OCR and PDF writing errors.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::{ExtractionConfig, create_searchable_pdf};
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let scan = std::fs::read("scan.pdf")?;
+/// let pdf = create_searchable_pdf(&scan, "application/pdf", &ExtractionConfig::default()).await?;
+/// std::fs::write("scan.searchable.pdf", pdf)?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_searchable_pdf(content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<Vec<u8>> {
+    let mime_type = validate_mime_type(mime_type)?;
+    let page_images = if mime_type == PDF_MIME_TYPE {
+        render_pdf_pages(content, config)?
+    } else if mime_type.starts_with("image/") {
+        vec![decode_image_page(content)?]
+    } else {
+        return Err(KreuzbergError::UnsupportedFormat(format!(
+            "Searchable PDF output requires a PDF or image input, got {}",
+            mime_type
+        )));
+    };
+
+    let ocr_config = searchable_ocr_config(config);
+    let backend = {
+        let registry = get_ocr_backend_registry();
+        let registry = registry.read().map_err(|e| KreuzbergError::Plugin {
+            message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+            plugin_name: "ocr-registry".to_string(),
+        })?;
+        registry.get(&ocr_config.backend)?
+    };
+
+    let mut pages = Vec::with_capacity(page_images.len());
+    for page in page_images {
+        let image_bytes = match page.encoded {
+            Some(bytes) => bytes,
+            None => encode_png(&page.image)?,
+        };
+        let ocr_result = crate::ocr::auto_language::process_image(&*backend, &image_bytes, &ocr_config).await?;
+        crate::metrics::metrics().record_ocr_pages(&ocr_config.backend, 1);
+
+        pages.push(SearchablePdfPage {
+            image: page.image,
+            width_points: page.width_points,
+            height_points: page.height_points,
+            elements: ocr_result.ocr_elements.unwrap_or_default(),
+        });
+    }
+
+    Ok(write_searchable_pdf(&pages)?)
+}
+
+/// Create a searchable PDF from a file.
+///
+/// The MIME type is detected from the path when `mime_type` is `None`. See
+/// [`create_searchable_pdf`] for details.
+pub async fn create_searchable_pdf_file(
+    path: impl AsRef<Path>,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+) -> Result<Vec<u8>> {
+    let path = path.as_ref();
+    let mime_type = detect_or_validate(Some(path), mime_type)?;
+    let content = tokio::fs::read(path).await?;
+    create_searchable_pdf(&content, &mime_type, config).await
+}
+
+/// A page image waiting for OCR.
+struct PageImage {
+    image: DynamicImage,
+    /// Original encoded bytes, passed to OCR as-is when available.
+    encoded: Option<Vec<u8>>,
+    width_points: f64,
+    height_points: f64,
+}
+
+fn render_pdf_pages(content: &[u8], config: &ExtractionConfig) -> Result<Vec<PageImage>> {
+    let password = config
+        .pdf_options
+        .as_ref()
+        .and_then(|options| options.passwords.as_ref())
+        .and_then(|passwords| passwords.first())
+        .map(String::as_str);
+
+    let renderer = PdfRenderer::new().map_err(|e| KreuzbergError::Parsing {
+        message: format!("Failed to initialize PDF renderer: {}", e),
+        source: None,
+    })?;
+    let sizes = renderer.page_sizes(content, password)?;
+    let render_options = PageRenderOptions::default();
+
+    sizes
+        .into_iter()
+        .enumerate()
+        .map(|(page_index, (width_points, height_points))| {
+            let image = renderer
+                .render_page_to_image_with_password(content, page_index, &render_options, password)
+                .map_err(|e| KreuzbergError::Parsing {
+                    message: format!("Failed to render PDF page {}: {}", page_index + 1, e),
+                    source: None,
+                })?;
+            Ok(PageImage {
+                image,
+                encoded: None,
+                width_points,
+                height_points,
+            })
+        })
+        .collect()
+}
+
+fn decode_image_page(content: &[u8]) -> Result<PageImage> {
+    use crate::extraction::image::{decode_jbig2_to_gray, decode_jp2_to_rgb, is_j2k, is_jbig2, is_jp2};
+
+    let image = if is_jp2(content) || is_j2k(content) {
+        DynamicImage::ImageRgb8(decode_jp2_to_rgb(content)?)
+    } else if is_jbig2(content) {
+        DynamicImage::ImageLuma8(decode_jbig2_to_gray(content)?)
+    } else {
+        image::load_from_memory(content).map_err(|e| KreuzbergError::Parsing {
+            message: format!("Failed to decode image: {}", e),
+            source: None,
+        })?
+    };
+
+    let to_points = PDF_POINTS_PER_INCH / IMAGE_DPI;
+    Ok(PageImage {
+        width_points: image.width() as f64 * to_points,
+        height_points: image.height() as f64 * to_points,
+        image,
+        encoded: Some(content.to_vec()),
+    })
+}
+
+fn encode_png(image: &DynamicImage) -> Result<Vec<u8>> {
+    let mut bytes = Cursor::new(Vec::new());
+    image
+        .write_to(&mut bytes, image::ImageFormat::Png)
+        .map_err(|e| KreuzbergError::Parsing {
+            message: format!("Failed to encode image: {}", e),
+            source: None,
+        })?;
+    Ok(bytes.into_inner())
+}
+
+/// OCR settings that yield word geometry matching the page image.
+fn searchable_ocr_config(config: &ExtractionConfig) -> OcrConfig {
+    let mut ocr_config = config.ocr.clone().unwrap_or_default();
+
+    let mut tesseract_config = ocr_config.tesseract_config.take().unwrap_or_else(|| TesseractConfig {
+        language: ocr_config.language.clone(),
+        ..Default::default()
+    });
+    // TSV output carries word boxes; preprocessing would move them off the image.
+    tesseract_config.output_format = "tsv".to_string();
+    tesseract_config.enable_table_detection = false;
+    tesseract_config.preprocessing = None;
+    ocr_config.tesseract_config = Some(tesseract_config);
+    ocr_config.output_format = None;
+
+    ocr_config.element_config = Some(OcrElementConfig {
+        include_elements: true,
+        min_level: OcrElementLevel::Word,
+        ..ocr_config.element_config.unwrap_or_default()
+    });
+    ocr_config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+
+    #[test]
+    fn test_searchable_ocr_config_requests_word_geometry() {
+        let config = ExtractionConfig {
+            ocr: Some(OcrConfig {
+                language: "deu".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let ocr_config = searchable_ocr_config(&config);
+
+        let tesseract = ocr_config.tesseract_config.unwrap();
+        assert_eq!(tesseract.language, "deu");
+        assert_eq!(tesseract.output_format, "tsv");
+        assert!(tesseract.preprocessing.is_none());
+        let elements = ocr_config.element_config.unwrap();
+        assert!(elements.include_elements);
+        assert_eq!(elements.min_level, OcrElementLevel::Word);
+    }
+
+    #[test]
+    fn test_decode_image_page_sizes_at_300_dpi() {
+        let image = DynamicImage::ImageRgb8(RgbImage::new(600, 300));
+        let page = decode_image_page(&encode_png(&image).unwrap()).unwrap();
+
+        assert_eq!(page.width_points, 144.0);
+        assert_eq!(page.height_points, 72.0);
+        assert!(page.encoded.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_create_searchable_pdf_rejects_non_scan_input() {
+        let result = create_searchable_pdf(b"hello", "text/plain", &ExtractionConfig::default()).await;
+        assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat(_))));
+    }
+}
@@ -19,6 +19,12 @@ use super::batch::{batch_extract_bytes, batch_extract_file};
 use super::bytes::extract_bytes;
 #[cfg(feature = "tokio-runtime")]
 use super::file::extract_file;
+#[cfg(feature = "tokio-runtime")]
+use super::ingest::{IngestOptions, IngestSummary, ingest_directory};
+#[cfg(all(feature = "pdf", feature = "ocr", feature = "tokio-runtime"))]
+use super::searchable::{create_searchable_pdf, create_searchable_pdf_file};
+#[cfg(feature = "watch")]
+use super::watch::{WatchEvent, WatchOptions, watch_directory};
 
 /// Global Tokio runtime for synchronous operations.
 ///
@@ -138,6 +144,93 @@ pub fn batch_extract_file_sync(
     GLOBAL_RUNTIME.block_on(batch_extract_file(paths, config))
 }
 
+/// Synchronous wrapper for `ingest_directory`.
+///
+/// Uses the global Tokio runtime.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::{IngestOptions, IngestOutput, ingest_directory_sync};
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// let options = IngestOptions::new(IngestOutput::JsonlFile("docs.jsonl".into()));
+/// let summary = ingest_directory_sync("docs", &options, &ExtractionConfig::default())?;
+/// println!("Extracted {} files", summary.succeeded);
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+#[cfg(feature = "tokio-runtime")]
+pub fn ingest_directory_sync(
+    root: impl AsRef<Path>,
+    options: &IngestOptions,
+    config: &ExtractionConfig,
+) -> Result<IngestSummary> {
+    GLOBAL_RUNTIME.block_on(ingest_directory(root, options, config))
+}
+
+/// Synchronous wrapper for `watch_directory`.
+///
+/// Uses the global Tokio runtime. Blocks until `on_event` returns
+/// [`ControlFlow::Break`](std::ops::ControlFlow::Break).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::{WatchOptions, watch_directory_sync};
+/// use kreuzberg::core::config::ExtractionConfig;
+/// use std::ops::ControlFlow;
+///
+/// watch_directory_sync("docs", &WatchOptions::default(), &ExtractionConfig::default(), |event| {
+///     println!("{:?} {}", event.event, event.path);
+///     ControlFlow::Continue(())
+/// })?;
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+#[cfg(feature = "watch")]
+pub fn watch_directory_sync<F>(
+    root: impl AsRef<Path>,
+    options: &WatchOptions,
+    config: &ExtractionConfig,
+    on_event: F,
+) -> Result<()>
+where
+    F: FnMut(WatchEvent) -> std::ops::ControlFlow<()>,
+{
+    GLOBAL_RUNTIME.block_on(watch_directory(root, options, config, on_event))
+}
+
+/// Synchronous wrapper for `create_searchable_pdf`.
+///
+/// Uses the global Tokio runtime.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::create_searchable_pdf_sync;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// let scan = std::fs::read("scan.png")?;
+/// let pdf = create_searchable_pdf_sync(&scan, "image/png", &ExtractionConfig::default())?;
+/// std::fs::write("scan.pdf", pdf)?;
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+#[cfg(all(feature = "pdf", feature = "ocr", feature = "tokio-runtime"))]
+pub fn create_searchable_pdf_sync(content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<Vec<u8>> {
+    GLOBAL_RUNTIME.block_on(create_searchable_pdf(content, mime_type, config))
+}
+
+/// Synchronous wrapper for `create_searchable_pdf_file`.
+///
+/// Uses the global Tokio runtime.
+#[cfg(all(feature = "pdf", feature = "ocr", feature = "tokio-runtime"))]
+pub fn create_searchable_pdf_file_sync(
+    path: impl AsRef<Path>,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+) -> Result<Vec<u8>> {
+    GLOBAL_RUNTIME.block_on(create_searchable_pdf_file(path, mime_type, config))
+}
+
 /// Synchronous wrapper for `batch_extract_bytes`.
 ///
 /// Uses the global Tokio runtime for 100x+ performance improvement over creating
@@ -197,6 +290,7 @@ pub fn batch_extract_bytes_sync(
             },
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -208,6 +302,7 @@ pub fn batch_extract_bytes_sync(
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         }));
     }
     Ok(results)
@@ -0,0 +1,517 @@
+//! Directory watching with re-extraction of changed files.
+//!
+//! [`watch_directory`] subscribes to file system notifications (inotify on
+//! Linux) for a directory tree, debounces them per path and compares the
+//! settled state of each path with what it has seen before. New files are
+//! reported as added, files whose content hash changed as updated and deleted
+//! files as removed; a rename is a removal of the old path and an addition of
+//! the new one. Files whose content is unchanged, e.g. after a `touch`, are not
+//! reported. Extraction goes through the result cache, so a renamed or
+//! restored file with known content is not extracted again.
+
+use crate::cache::digest_file;
+use crate::core::config::ExtractionConfig;
+use crate::types::{ErrorMetadata, ExtractionResult};
+use crate::{KreuzbergError, Result};
+use notify::event::{AccessKind, AccessMode};
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use walkdir::WalkDir;
+
+use super::batch::max_concurrent_extractions;
+use super::file::extract_file;
+use super::ingest::{FileFilter, relative_path, split_result};
+
+/// Options for [`watch_directory`].
+///
+/// Patterns follow [`IngestOptions`](super::IngestOptions): globs without a
+/// `/` match the file name, others the path relative to the watched directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchOptions {
+    /// Quiet period after the last notification for a path before it is processed
+    pub debounce: Duration,
+    /// Report files present at startup as added; otherwise they are only tracked
+    pub initial_scan: bool,
+    /// Glob patterns of files to watch (all files when empty)
+    pub include: Vec<String>,
+    /// Glob patterns of files and directories to ignore
+    pub exclude: Vec<String>,
+    /// MIME types to watch, `type/*` matching a whole type (all supported types when empty)
+    pub mime_types: Vec<String>,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(500),
+            initial_scan: false,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            mime_types: Vec::new(),
+        }
+    }
+}
+
+/// What happened to a watched file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WatchEventKind {
+    /// A file appeared, by creation, copy or rename
+    Added,
+    /// The content of a known file changed
+    Updated,
+    /// A known file was deleted or renamed away
+    Removed,
+}
+
+/// A change reported by [`watch_directory`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchEvent {
+    /// Kind of change
+    pub event: WatchEventKind,
+    /// Path relative to the watched directory, `/`-separated
+    pub path: String,
+    /// Detected MIME type, absent for removals
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Hex SHA-256 of the new content, absent for removals
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Extraction result of the new content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<ExtractionResult>,
+    /// Extraction error, if the new content could not be extracted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorMetadata>,
+}
+
+impl WatchEvent {
+    fn removed(path: String) -> Self {
+        Self {
+            event: WatchEventKind::Removed,
+            path,
+            mime_type: None,
+            sha256: None,
+            result: None,
+            error: None,
+        }
+    }
+}
+
+/// Watch `root` and call `on_event` for every added, updated or removed file.
+///
+/// Changed files are extracted concurrently, bounded like
+/// [`batch_extract_file`](super::batch_extract_file), and events are delivered
+/// in completion order. Watching continues until `on_event` returns
+/// [`ControlFlow::Break`].
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if `root` is not a directory or a
+/// pattern is invalid, and `KreuzbergError::Io` if the directory cannot be
+/// watched or the notification stream ends.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::{WatchOptions, watch_directory};
+/// use kreuzberg::ExtractionConfig;
+/// use std::ops::ControlFlow;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let options = WatchOptions {
+///     include: vec!["*.pdf".to_string()],
+///     ..Default::default()
+/// };
+/// watch_directory("/srv/knowledge-base", &options, &ExtractionConfig::default(), |event| {
+///     println!("{:?} {}", event.event, event.path);
+///     ControlFlow::Continue(())
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn watch_directory<F>(
+    root: impl AsRef<Path>,
+    options: &WatchOptions,
+    config: &ExtractionConfig,
+    mut on_event: F,
+) -> Result<()>
+where
+    F: FnMut(WatchEvent) -> ControlFlow<()>,
+{
+    let root = root.as_ref();
+    if !root.is_dir() {
+        return Err(KreuzbergError::validation(format!(
+            "Watch path is not a directory: {}",
+            root.display()
+        )));
+    }
+    let root = root.canonicalize()?;
+    let filter = FileFilter::new(&options.include, &options.exclude, &options.mime_types)?;
+
+    let (sender, mut notifications) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        // The receiver is only dropped when watching stops
+        let _ = sender.send(event);
+    })
+    .map_err(watch_error)?;
+    watcher.watch(&root, RecursiveMode::Recursive).map_err(watch_error)?;
+
+    let mut state = WatchState {
+        root,
+        filter,
+        known: HashMap::new(),
+    };
+    let mut ready = Vec::new();
+    for (relative, path) in state.files_below(&state.root) {
+        if options.initial_scan {
+            ready.push((relative, path));
+        } else {
+            state.known.insert(relative, None);
+        }
+    }
+
+    let config = Arc::new(config.clone());
+    let max_concurrent = max_concurrent_extractions(&config);
+    let mut pending: HashMap<PathBuf, Instant> = HashMap::new();
+    let mut in_flight = HashSet::new();
+    let mut tasks = JoinSet::new();
+
+    loop {
+        while tasks.len() < max_concurrent {
+            let Some((relative, path)) = ready.pop() else {
+                break;
+            };
+            if !in_flight.insert(relative.clone()) {
+                // Processed again once the running extraction has finished
+                pending.insert(path, Instant::now() + options.debounce);
+                continue;
+            }
+            let known_digest = state.known.get(&relative).cloned().flatten();
+            tasks.spawn(process_file(relative, path, known_digest, Arc::clone(&config)));
+        }
+
+        let deadline = pending.values().min().copied();
+        tokio::select! {
+            notification = notifications.recv() => match notification {
+                Some(Ok(event)) => {
+                    let settles = Instant::now() + options.debounce;
+                    if is_relevant(&event.kind) {
+                        pending.extend(event.paths.into_iter().map(|path| (path, settles)));
+                    }
+                }
+                Some(Err(e)) => tracing::warn!("File watcher error: {}", e),
+                None => return Err(KreuzbergError::Io(std::io::Error::other("File watcher stopped"))),
+            },
+            Some(joined) = tasks.join_next(), if !tasks.is_empty() => {
+                let outcome = joined.map_err(|e| KreuzbergError::Other(format!("Task panicked: {}", e)))?;
+                in_flight.remove(&outcome.relative);
+                if let Some(event) = state.apply(outcome)
+                    && on_event(event).is_break()
+                {
+                    return Ok(());
+                }
+            },
+            _ = tokio::time::sleep_until(deadline.unwrap_or_else(Instant::now).into()), if deadline.is_some() => {
+                let now = Instant::now();
+                let settled: Vec<PathBuf> = pending
+                    .iter()
+                    .filter(|(_, settles)| **settles <= now)
+                    .map(|(path, _)| path.clone())
+                    .collect();
+                for path in settled {
+                    pending.remove(&path);
+                    for event in state.settle(&path, &mut ready) {
+                        if on_event(event).is_break() {
+                            return Ok(());
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+fn watch_error(e: notify::Error) -> KreuzbergError {
+    KreuzbergError::Io(std::io::Error::other(format!("Failed to watch directory: {}", e)))
+}
+
+/// Opening or reading a file, including our own reads during extraction, is not a change.
+fn is_relevant(kind: &EventKind) -> bool {
+    match kind {
+        EventKind::Access(access) => matches!(access, AccessKind::Close(AccessMode::Write)),
+        _ => true,
+    }
+}
+
+/// Files seen so far and the filters deciding which paths are watched.
+struct WatchState {
+    root: PathBuf,
+    filter: FileFilter,
+    /// Relative path to content digest, `None` for files present at startup and not yet hashed
+    known: HashMap<String, Option<String>>,
+}
+
+impl WatchState {
+    /// Reconcile a path whose notifications have settled, queueing files to
+    /// (re-)extract in `ready` and returning removals.
+    fn settle(&mut self, path: &Path, ready: &mut Vec<(String, PathBuf)>) -> Vec<WatchEvent> {
+        let Ok(relative_to_root) = path.strip_prefix(&self.root) else {
+            return Vec::new();
+        };
+        if relative_to_root.as_os_str().is_empty() {
+            return Vec::new();
+        }
+        let relative = relative_path(&self.root, path);
+
+        if path.is_file() {
+            if self.is_watched(&relative) && self.filter.mime_type(path).is_some() {
+                ready.push((relative, path.to_path_buf()));
+            }
+            Vec::new()
+        } else if path.is_dir() {
+            // A directory moved into the tree produces no notifications for its contents
+            ready.extend(self.files_below(path));
+            Vec::new()
+        } else {
+            let prefix = format!("{}/", relative);
+            let mut removed: Vec<String> = self
+                .known
+                .keys()
+                .filter(|known| **known == relative || known.starts_with(&prefix))
+                .cloned()
+                .collect();
+            removed.sort();
+            for path in &removed {
+                self.known.remove(path);
+            }
+            removed.into_iter().map(WatchEvent::removed).collect()
+        }
+    }
+
+    /// Record the outcome of processing a file, returning the event to report.
+    fn apply(&mut self, outcome: FileOutcome) -> Option<WatchEvent> {
+        let FileOutcome {
+            relative,
+            mime_type,
+            digest,
+            result,
+        } = outcome;
+
+        let Some(digest) = digest else {
+            // The file disappeared before it could be read; its removal has its own notification
+            return None;
+        };
+        let previous = self.known.insert(relative.clone(), Some(digest.clone()));
+        let result = result?;
+
+        let (result, error) = split_result(*result);
+        Some(WatchEvent {
+            event: if previous.is_some() {
+                WatchEventKind::Updated
+            } else {
+                WatchEventKind::Added
+            },
+            path: relative,
+            mime_type: Some(mime_type),
+            sha256: Some(digest),
+            result,
+            error,
+        })
+    }
+
+    /// Watched files below `dir`, with their paths relative to the root.
+    fn files_below(&self, dir: &Path) -> Vec<(String, PathBuf)> {
+        WalkDir::new(dir)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                entry.depth() == 0 || !self.filter.is_excluded(&relative_path(&self.root, entry.path()))
+            })
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| {
+                let relative = relative_path(&self.root, entry.path());
+                (self.is_watched(&relative) && self.filter.mime_type(entry.path()).is_some())
+                    .then(|| (relative, entry.into_path()))
+            })
+            .collect()
+    }
+
+    /// Whether the file passes the glob filters, including excludes of any parent directory.
+    fn is_watched(&self, relative: &str) -> bool {
+        self.filter.is_included(relative)
+            && !relative
+                .match_indices('/')
+                .any(|(end, _)| self.filter.is_excluded(&relative[..end]))
+    }
+}
+
+struct FileOutcome {
+    relative: String,
+    mime_type: String,
+    /// Content digest, `None` if the file could not be read
+    digest: Option<String>,
+    /// Extraction result, `None` if the content is unchanged
+    result: Option<Box<Result<ExtractionResult>>>,
+}
+
+async fn process_file(
+    relative: String,
+    path: PathBuf,
+    known_digest: Option<String>,
+    config: Arc<ExtractionConfig>,
+) -> FileOutcome {
+    let hashed = path.clone();
+    let digest = match tokio::task::spawn_blocking(move || digest_file(&hashed)).await {
+        Ok(Ok(digest)) => Some(digest),
+        Ok(Err(_)) | Err(_) => None,
+    };
+    let mime_type = crate::core::mime::detect_mime_type(&path, false).unwrap_or_default();
+
+    let result = match &digest {
+        Some(digest) if known_digest.as_deref() != Some(digest.as_str()) => {
+            let start = Instant::now();
+            let mut result = crate::core::batch_mode::with_batch_mode(async {
+                extract_file(&path, Some(&mime_type), &config).await
+            })
+            .await;
+            if let Ok(result) = result.as_mut() {
+                result.metadata.extraction_duration_ms = Some(start.elapsed().as_millis() as u64);
+            }
+            Some(Box::new(result))
+        }
+        _ => None,
+    };
+
+    FileOutcome {
+        relative,
+        mime_type,
+        digest,
+        result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn options() -> WatchOptions {
+        WatchOptions {
+            debounce: Duration::from_millis(100),
+            ..Default::default()
+        }
+    }
+
+    fn config() -> ExtractionConfig {
+        ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        }
+    }
+
+    /// Run the watcher while `changes` edits the directory, collecting `count` events.
+    async fn collect_events(
+        root: &Path,
+        options: &WatchOptions,
+        count: usize,
+        changes: impl FnOnce(PathBuf) + Send + 'static,
+    ) -> Vec<WatchEvent> {
+        let dir = root.to_path_buf();
+        let editor = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            tokio::task::spawn_blocking(move || changes(dir)).await.unwrap();
+        });
+
+        let config = config();
+        let mut events = Vec::new();
+        let watching = watch_directory(root, options, &config, |event| {
+            events.push(event);
+            if events.len() == count {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        tokio::time::timeout(Duration::from_secs(20), watching)
+            .await
+            .expect("timed out waiting for watch events")
+            .unwrap();
+        editor.await.unwrap();
+        events
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_watch_reports_added_updated_removed() {
+        let root = tempdir().unwrap();
+        fs::write(root.path().join("existing.txt"), "before").unwrap();
+
+        let events = collect_events(root.path(), &options(), 3, |dir| {
+            fs::write(dir.join("new.txt"), "fresh content").unwrap();
+            std::thread::sleep(Duration::from_millis(400));
+            // Rewriting identical content is not reported
+            fs::write(dir.join("new.txt"), "fresh content").unwrap();
+            fs::write(dir.join("existing.txt"), "after").unwrap();
+            std::thread::sleep(Duration::from_millis(400));
+            fs::remove_file(dir.join("new.txt")).unwrap();
+        })
+        .await;
+
+        let summary: Vec<(WatchEventKind, &str)> = events.iter().map(|e| (e.event, e.path.as_str())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (WatchEventKind::Added, "new.txt"),
+                (WatchEventKind::Updated, "existing.txt"),
+                (WatchEventKind::Removed, "new.txt"),
+            ]
+        );
+        assert_eq!(events[0].result.as_ref().unwrap().content.trim(), "fresh content");
+        assert_eq!(events[1].result.as_ref().unwrap().content.trim(), "after");
+        assert!(events[2].result.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_watch_initial_scan_and_filters() {
+        let root = tempdir().unwrap();
+        fs::create_dir(root.path().join("skip")).unwrap();
+        fs::write(root.path().join("a.txt"), "alpha").unwrap();
+        fs::write(root.path().join("skip/b.txt"), "beta").unwrap();
+
+        let options = WatchOptions {
+            initial_scan: true,
+            exclude: vec!["skip".to_string()],
+            ..options()
+        };
+        let events = collect_events(root.path(), &options, 2, |dir| {
+            fs::write(dir.join("skip/c.txt"), "ignored").unwrap();
+            fs::write(dir.join("d.md"), "# delta").unwrap();
+        })
+        .await;
+
+        let mut paths: Vec<&str> = events.iter().map(|e| e.path.as_str()).collect();
+        paths.sort();
+        assert_eq!(paths, vec!["a.txt", "d.md"]);
+        assert!(events.iter().all(|e| e.event == WatchEventKind::Added));
+    }
+
+    #[tokio::test]
+    async fn test_watch_rejects_missing_directory() {
+        let root = tempdir().unwrap();
+        let (options, config) = (options(), config());
+        let result = watch_directory(root.path().join("missing"), &options, &config, |_| {
+            ControlFlow::Continue(())
+        })
+        .await;
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+    }
+}
@@ -19,6 +19,7 @@ pub const LEGACY_POWERPOINT_MIME_TYPE: &str = "application/vnd.ms-powerpoint";
 
 pub const EML_MIME_TYPE: &str = "message/rfc822";
 pub const MSG_MIME_TYPE: &str = "application/vnd.ms-outlook";
+pub const MBOX_MIME_TYPE: &str = "application/mbox";
 pub const JSON_MIME_TYPE: &str = "application/json";
 pub const YAML_MIME_TYPE: &str = "application/x-yaml";
 pub const TOML_MIME_TYPE: &str = "application/toml";
@@ -100,6 +101,8 @@ static EXT_TO_MIME: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
 
     m.insert("eml", EML_MIME_TYPE);
     m.insert("msg", MSG_MIME_TYPE);
+    m.insert("mbox", MBOX_MIME_TYPE);
+    m.insert("mbx", MBOX_MIME_TYPE);
 
     m.insert("zip", "application/zip");
     m.insert("tar", "application/x-tar");
@@ -212,6 +215,7 @@ static SUPPORTED_MIME_TYPES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     set.insert(HTML_MIME_TYPE);
     set.insert(EML_MIME_TYPE);
     set.insert(MSG_MIME_TYPE);
+    set.insert(MBOX_MIME_TYPE);
     set.insert(JSON_MIME_TYPE);
     set.insert("text/json");
     set.insert(YAML_MIME_TYPE);
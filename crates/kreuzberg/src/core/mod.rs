@@ -38,6 +38,8 @@ pub mod extractor;
 pub mod formats;
 pub mod io;
 pub mod mime;
+#[cfg(feature = "api")]
+pub(crate) mod page_stream;
 pub mod pipeline;
 pub mod server_config;
 
@@ -53,7 +55,9 @@ pub use config_validation::{
     validate_token_reduction_level,
 };
 pub use formats::{KNOWN_FORMATS, is_valid_format_field};
-pub use server_config::ServerConfig;
+pub use server_config::{
+    ApiKeyConfig, ApiScope, AuthConfig, JobsConfig, JwtAlgorithm, JwtConfig, QuotaConfig, ServerConfig,
+};
 
 #[cfg(feature = "tokio-runtime")]
 pub use batch_optimizations::{BatchProcessor, BatchProcessorConfig};
@@ -0,0 +1,105 @@
+//! Internal per-page result streaming using tokio task-local storage.
+//!
+//! The streaming extract endpoint runs an extraction inside [`with_page_sink`].
+//! Extractors that produce pages one at a time (native PDF text and PDF OCR)
+//! report each finished page to the [`PageSink`] in scope, and stop early once
+//! the receiving side is gone, which is how a client disconnect cancels the
+//! remaining work.
+
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task_local;
+
+use crate::types::PageContent;
+
+task_local! {
+    /// Task-local sink receiving pages as they finish.
+    static PAGE_SINK: PageSink;
+}
+
+/// Receiver of pages produced during an extraction.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "pdf"), allow(dead_code))]
+pub(crate) struct PageSink {
+    sender: UnboundedSender<PageContent>,
+}
+
+impl PageSink {
+    pub(crate) fn new(sender: UnboundedSender<PageContent>) -> Self {
+        Self { sender }
+    }
+
+    /// Report a finished page.
+    ///
+    /// Returns `false` once the receiver has been dropped; callers should then
+    /// abandon the extraction.
+    #[cfg_attr(not(feature = "pdf"), allow(dead_code))]
+    pub(crate) fn send(&self, page: PageContent) -> bool {
+        self.sender.send(page).is_ok()
+    }
+}
+
+/// The page sink of the current task, if the extraction is being streamed.
+#[cfg_attr(not(feature = "pdf"), allow(dead_code))]
+pub(crate) fn current_page_sink() -> Option<PageSink> {
+    PAGE_SINK.try_with(Clone::clone).ok()
+}
+
+/// Run a future with `sink` receiving the pages it produces.
+pub(crate) async fn with_page_sink<F>(sink: PageSink, future: F) -> F::Output
+where
+    F: std::future::Future,
+{
+    PAGE_SINK.scope(sink, future).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    fn page(page_number: usize) -> PageContent {
+        PageContent {
+            page_number,
+            content: format!("Page {}", page_number),
+            tables: Vec::new(),
+            images: Vec::new(),
+            hierarchy: None,
+            is_blank: Some(false),
+            language: None,
+            language_confidence: None,
+            text_source: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_sink_by_default() {
+        assert!(current_page_sink().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pages_reach_receiver() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let sent = with_page_sink(PageSink::new(sender), async {
+            let sink = current_page_sink().expect("sink should be in scope");
+            sink.send(page(1)) && sink.send(page(2))
+        })
+        .await;
+
+        assert!(sent);
+        assert_eq!(receiver.recv().await.unwrap().page_number, 1);
+        assert_eq!(receiver.recv().await.unwrap().page_number, 2);
+        assert!(receiver.recv().await.is_none(), "sink should be dropped with its scope");
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_after_receiver_dropped() {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        drop(receiver);
+
+        let sent = with_page_sink(PageSink::new(sender), async {
+            current_page_sink().unwrap().send(page(1))
+        })
+        .await;
+        assert!(!sent);
+    }
+}
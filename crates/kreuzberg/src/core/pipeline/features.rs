@@ -8,13 +8,27 @@ use crate::core::config::ExtractionConfig;
 use crate::types::{ExtractionResult, ProcessingWarning};
 use std::borrow::Cow;
 
+/// Merge tables continued across page breaks in paginated (PDF and OCR) results.
+pub(super) fn execute_table_stitching(result: &mut ExtractionResult) {
+    if result.mime_type == "application/pdf" || result.mime_type.starts_with("image/") {
+        crate::extraction::table_stitching::stitch_tables(result);
+    }
+}
+
 /// Execute chunking if configured.
 pub(super) fn execute_chunking(result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
     #[cfg(feature = "chunking")]
     if let Some(ref chunking_config) = config.chunking {
         let page_boundaries = result.metadata.pages.as_ref().and_then(|ps| ps.boundaries.as_deref());
 
-        match crate::chunking::chunk_text(&result.content, chunking_config, page_boundaries) {
+        let chunked = match chunking_config.chunker_type {
+            crate::chunking::ChunkerType::Structure => {
+                crate::chunking::chunk_extraction_result(result, chunking_config)
+            }
+            _ => crate::chunking::chunk_text(&result.content, chunking_config, page_boundaries),
+        };
+
+        match chunked {
             Ok(chunking_result) => {
                 result.chunks = Some(chunking_result.chunks);
 
@@ -41,7 +55,9 @@ pub(super) fn execute_chunking(result: &mut ExtractionResult, config: &Extractio
                                 .insert(Cow::Borrowed("embeddings_generated"), serde_json::Value::Bool(true));
                         }
                         Err(e) => {
-                            tracing::warn!("Embedding generation failed: {e}. Check that ONNX Runtime is installed.");
+                            tracing::warn!(
+                                "Embedding generation failed: {e}. Check that ONNX Runtime is installed or the embedding API is reachable."
+                            );
                             let error_msg = e.to_string();
                             result.processing_warnings.push(ProcessingWarning {
                                 source: "embedding".to_string(),
@@ -112,6 +128,7 @@ pub(super) fn execute_language_detection(result: &mut ExtractionResult, config:
         match crate::language_detection::detect_languages(&result.content, lang_config) {
             Ok(detected) => {
                 result.detected_languages = detected;
+                crate::language_detection::annotate_languages(result, lang_config);
             }
             Err(e) => {
                 let error_msg = e.to_string();
@@ -1,10 +1,12 @@
 //! Output format conversion for extraction results.
 //!
 //! This module handles conversion of extraction results to various output formats
-//! (Plain, Djot, Markdown, HTML) with proper error handling and metadata recording.
+//! (Plain, Djot, Markdown, HTML, and the OCR layout formats ALTO, PAGE XML and
+//! hOCR) with proper error handling and metadata recording.
 
 use crate::core::config::OutputFormat;
-use crate::types::{ExtractionResult, ProcessingWarning};
+use crate::extraction::ocr_export::{OcrPageDimensions, to_alto_xml, to_hocr, to_page_xml};
+use crate::types::{ExtractionResult, FormatMetadata, ProcessingWarning};
 use std::borrow::Cow;
 
 /// Apply output format conversion to the extraction result.
@@ -14,6 +16,8 @@ use std::borrow::Cow;
 /// - `Djot`: Use djot_content if available, otherwise keep plain text
 /// - `Markdown`: Convert to Markdown format (uses djot as it's similar)
 /// - `Html`: Convert to HTML format
+/// - `Alto`, `PageXml`, `Hocr`: Serialize the result's OCR elements; content is kept
+///   with a processing warning when there are none
 ///
 /// Skips conversion if content was already formatted during extraction (e.g., HTML extractor
 /// already produced djot or markdown output).
@@ -37,6 +41,9 @@ pub fn apply_output_format(result: &mut ExtractionResult, output_format: OutputF
         OutputFormat::Djot => "djot",
         OutputFormat::Html => "html",
         OutputFormat::Structured => "structured",
+        OutputFormat::Alto => "alto",
+        OutputFormat::PageXml => "pagexml",
+        OutputFormat::Hocr => "hocr",
     };
     result.metadata.output_format = Some(format_name.to_string());
     // DEPRECATED: kept for backward compatibility; will be removed in next major version.
@@ -158,9 +165,59 @@ pub fn apply_output_format(result: &mut ExtractionResult, output_format: OutputF
             // and update the mime_type to indicate structured output.
             // (output_format metadata already set above)
         }
+        OutputFormat::Alto | OutputFormat::PageXml | OutputFormat::Hocr => {
+            if let Err(message) = apply_ocr_layout_format(result, output_format) {
+                result.processing_warnings.push(ProcessingWarning {
+                    source: "output_format".to_string(),
+                    message,
+                });
+            }
+        }
     }
 }
 
+/// Replace the content with an ALTO, PAGE XML or hOCR serialization of the OCR elements.
+///
+/// Page dimensions come from image metadata when present; otherwise they are
+/// derived from the element extents.
+fn apply_ocr_layout_format(result: &mut ExtractionResult, output_format: OutputFormat) -> Result<(), String> {
+    let elements = match result.ocr_elements.as_deref() {
+        Some(elements) if !elements.is_empty() => elements,
+        _ => {
+            return Err(format!(
+                "No OCR elements available for {} output; content left unchanged",
+                output_format
+            ));
+        }
+    };
+
+    let pages = match &result.metadata.format {
+        Some(FormatMetadata::Image(image)) => vec![OcrPageDimensions {
+            page_number: 1,
+            width: image.width,
+            height: image.height,
+        }],
+        _ => Vec::new(),
+    };
+
+    result.content = match output_format {
+        OutputFormat::Alto => to_alto_xml(elements, &pages),
+        OutputFormat::Hocr => to_hocr(elements, &pages),
+        OutputFormat::PageXml => {
+            let mut documents = to_page_xml(elements, &pages);
+            if documents.len() != 1 {
+                return Err(format!(
+                    "PAGE XML holds a single page but the OCR result has {} pages; content left unchanged",
+                    documents.len()
+                ));
+            }
+            documents.remove(0)
+        }
+        other => return Err(format!("{} is not an OCR layout format", other)),
+    };
+    Ok(())
+}
+
 /// Escape HTML special characters in a string.
 fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -322,6 +379,8 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |".to_string(),
             page_number: 1,
+            grid: None,
+            page_end: None,
         };
 
         let mut result = ExtractionResult {
@@ -337,6 +396,121 @@ mod tests {
         assert_eq!(result.tables[0].cells[0][0], "A");
     }
 
+    fn ocr_result() -> ExtractionResult {
+        use crate::types::{ImageMetadata, OcrBoundingGeometry, OcrConfidence, OcrElement, OcrElementLevel};
+
+        let word = |text: &str, left: u32| {
+            OcrElement::new(
+                text,
+                OcrBoundingGeometry::Rectangle {
+                    left,
+                    top: 20,
+                    width: 60,
+                    height: 25,
+                },
+                OcrConfidence::from_tesseract(92.0),
+            )
+            .with_level(OcrElementLevel::Word)
+            .with_parent_id("p1_b1_par1_l1")
+        };
+        ExtractionResult {
+            content: "Hello world".to_string(),
+            mime_type: Cow::Borrowed("image/png"),
+            metadata: Metadata {
+                format: Some(FormatMetadata::Image(ImageMetadata {
+                    width: 640,
+                    height: 480,
+                    format: "PNG".to_string(),
+                    exif: Default::default(),
+                })),
+                ..Default::default()
+            },
+            ocr_elements: Some(vec![word("Hello", 10), word("world", 90)]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_apply_output_format_alto() {
+        let mut result = ocr_result();
+
+        apply_output_format(&mut result, OutputFormat::Alto);
+
+        assert!(
+            result
+                .content
+                .contains("<alto xmlns=\"http://www.loc.gov/standards/alto/ns-v4#\"")
+        );
+        assert!(result.content.contains("WIDTH=\"640\" HEIGHT=\"480\""));
+        assert!(result.content.contains("CONTENT=\"world\""));
+        assert_eq!(result.metadata.output_format, Some("alto".to_string()));
+        assert!(result.processing_warnings.is_empty());
+    }
+
+    #[test]
+    fn test_apply_output_format_page_xml_and_hocr() {
+        let mut page_xml = ocr_result();
+        apply_output_format(&mut page_xml, OutputFormat::PageXml);
+        assert!(page_xml.content.contains("<PcGts"));
+        assert!(page_xml.content.contains("imageWidth=\"640\""));
+
+        let mut hocr = ocr_result();
+        apply_output_format(&mut hocr, OutputFormat::Hocr);
+        assert!(hocr.content.contains("class=\"ocr_page\""));
+        assert!(hocr.content.contains("bbox 0 0 640 480"));
+    }
+
+    #[test]
+    fn test_apply_output_format_ocr_layout_without_elements_warns() {
+        let mut result = ExtractionResult {
+            content: "Hello World".to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            ..Default::default()
+        };
+
+        apply_output_format(&mut result, OutputFormat::Hocr);
+
+        assert_eq!(result.content, "Hello World");
+        assert_eq!(result.processing_warnings.len(), 1);
+        assert_eq!(result.processing_warnings[0].source, "output_format");
+    }
+
+    #[test]
+    fn test_apply_output_format_page_xml_rejects_multiple_pages() {
+        let mut result = ocr_result();
+        let mut second_page = result.ocr_elements.clone().unwrap();
+        for element in &mut second_page {
+            element.page_number = 2;
+        }
+        result.ocr_elements.as_mut().unwrap().extend(second_page);
+
+        apply_output_format(&mut result, OutputFormat::PageXml);
+
+        assert_eq!(result.content, "Hello world");
+        assert!(result.processing_warnings[0].message.contains("2 pages"));
+    }
+
+    #[test]
+    fn test_effective_output_format_uses_ocr_layout_format() {
+        use crate::core::config::{ExtractionConfig, OcrConfig};
+
+        let mut config = ExtractionConfig {
+            ocr: Some(OcrConfig {
+                output_format: Some(OutputFormat::Alto),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(config.effective_output_format(), OutputFormat::Alto);
+
+        config.output_format = OutputFormat::Markdown;
+        assert_eq!(config.effective_output_format(), OutputFormat::Markdown);
+
+        config.output_format = OutputFormat::Plain;
+        config.ocr.as_mut().unwrap().output_format = Some(OutputFormat::Markdown);
+        assert_eq!(config.effective_output_format(), OutputFormat::Plain);
+    }
+
     #[test]
     fn test_apply_output_format_preserves_djot_content() {
         use crate::types::{BlockType, DjotContent, FormattedBlock, InlineElement, InlineType};
@@ -20,7 +20,7 @@ use crate::core::config::ExtractionConfig;
 use crate::types::ExtractionResult;
 
 use execution::{execute_processors, execute_validators};
-use features::{execute_chunking, execute_language_detection};
+use features::{execute_chunking, execute_language_detection, execute_table_stitching};
 use initialization::{get_processors_from_cache, initialize_features, initialize_processor_cache};
 
 /// Run the post-processing pipeline on an extraction result.
@@ -28,8 +28,9 @@ use initialization::{get_processors_from_cache, initialize_features, initialize_
 /// Executes post-processing in the following order:
 /// 1. Post-Processors - Execute by stage (Early, Middle, Late) to modify/enhance the result
 /// 2. Quality Processing - Text cleaning and quality scoring
-/// 3. Chunking - Text splitting if enabled
-/// 4. Validators - Run validation hooks on the processed result (can fail fast)
+/// 3. Table Stitching - Merge PDF/OCR tables that continue across page breaks
+/// 4. Chunking - Text splitting if enabled
+/// 5. Validators - Run validation hooks on the processed result (can fail fast)
 ///
 /// # Arguments
 ///
@@ -73,6 +74,7 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
         .await?;
     }
 
+    execute_table_stitching(&mut result);
     execute_chunking(&mut result, config)?;
     execute_language_detection(&mut result, config)?;
     execute_validators(&result, config).await?;
@@ -90,7 +92,7 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
     }
 
     // Apply output format conversion as the final step
-    apply_output_format(&mut result, config.output_format);
+    apply_output_format(&mut result, config.effective_output_format());
 
     Ok(result)
 }
@@ -115,6 +117,7 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 /// This function is only available when the `tokio-runtime` feature is disabled.
 /// It handles:
 /// - Quality processing (if enabled)
+/// - Table stitching (PDF and OCR results)
 /// - Chunking (if enabled)
 /// - Language detection (if enabled)
 ///
@@ -123,6 +126,7 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 /// - Async validators
 #[cfg(not(feature = "tokio-runtime"))]
 pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    execute_table_stitching(&mut result);
     execute_chunking(&mut result, config)?;
     execute_language_detection(&mut result, config)?;
 
@@ -139,7 +143,7 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
     }
 
     // Apply output format conversion as the final step
-    apply_output_format(&mut result, config.output_format);
+    apply_output_format(&mut result, config.effective_output_format());
 
     Ok(result)
 }
@@ -24,6 +24,7 @@ async fn test_run_pipeline_basic() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -35,6 +36,7 @@ async fn test_run_pipeline_basic() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     result.metadata.additional.insert(
         Cow::Borrowed(VALIDATION_MARKER_KEY),
@@ -55,6 +57,7 @@ async fn test_pipeline_with_quality_processing() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -66,6 +69,7 @@ async fn test_pipeline_with_quality_processing() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     let config = ExtractionConfig {
         enable_quality_processing: true,
@@ -84,6 +88,7 @@ async fn test_pipeline_without_quality_processing() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -95,6 +100,7 @@ async fn test_pipeline_without_quality_processing() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     let config = ExtractionConfig {
         enable_quality_processing: false,
@@ -114,6 +120,7 @@ async fn test_pipeline_with_chunking() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -125,6 +132,7 @@ async fn test_pipeline_with_chunking() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     let config = ExtractionConfig {
         chunking: Some(crate::ChunkingConfig {
@@ -134,6 +142,9 @@ async fn test_pipeline_with_chunking() {
             chunker_type: crate::ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: Default::default(),
+            prepend_heading_context: false,
+            semantic: Default::default(),
         }),
         ..Default::default()
     };
@@ -144,6 +155,33 @@ async fn test_pipeline_with_chunking() {
     assert!(chunk_count.as_u64().unwrap() > 1);
 }
 
+#[tokio::test]
+#[cfg(feature = "chunking")]
+async fn test_pipeline_with_structure_chunking() {
+    let result = ExtractionResult {
+        content: "First paragraph of the document.\n\nSecond paragraph of the document.".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        ..Default::default()
+    };
+    let config = ExtractionConfig {
+        chunking: Some(crate::ChunkingConfig {
+            chunker_type: crate::ChunkerType::Structure,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let processed = run_pipeline(result, &config).await.unwrap();
+    let chunks = processed.chunks.expect("structure chunker should produce chunks");
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].metadata.element_types, vec!["paragraph", "paragraph"]);
+    assert_eq!(chunks[0].metadata.node_ids.len(), 2);
+    assert!(
+        processed.document.is_none(),
+        "the derived tree is not stored unless requested"
+    );
+}
+
 #[tokio::test]
 async fn test_pipeline_without_chunking() {
     let result = ExtractionResult {
@@ -152,6 +190,7 @@ async fn test_pipeline_without_chunking() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -163,6 +202,7 @@ async fn test_pipeline_without_chunking() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     let config = ExtractionConfig {
         chunking: None,
@@ -190,6 +230,7 @@ async fn test_pipeline_preserves_metadata() {
         pages: None,
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -200,6 +241,7 @@ async fn test_pipeline_preserves_metadata() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     let config = ExtractionConfig::default();
 
@@ -222,6 +264,8 @@ async fn test_pipeline_preserves_tables() {
         cells: vec![vec!["A".to_string(), "B".to_string()]],
         markdown: "| A | B |".to_string(),
         page_number: 0,
+        grid: None,
+        page_end: None,
     };
 
     let result = ExtractionResult {
@@ -230,6 +274,7 @@ async fn test_pipeline_preserves_tables() {
         metadata: Metadata::default(),
         tables: vec![table],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -241,6 +286,7 @@ async fn test_pipeline_preserves_tables() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     let config = ExtractionConfig::default();
 
@@ -249,6 +295,44 @@ async fn test_pipeline_preserves_tables() {
     assert_eq!(processed.tables[0].cells.len(), 1);
 }
 
+#[tokio::test]
+async fn test_pipeline_stitches_pdf_tables_across_pages() {
+    use crate::types::Table;
+
+    let page_table = |page_number: usize, row: &str| Table {
+        cells: vec![
+            vec!["Year".to_string(), "Revenue".to_string()],
+            vec![row.to_string(), "10".to_string()],
+        ],
+        markdown: format!("| Year | Revenue |\n|------|------|\n| {row} | 10 |\n"),
+        page_number,
+        grid: None,
+        page_end: None,
+    };
+    let tables = vec![page_table(1, "2020"), page_table(2, "2021")];
+    let config = ExtractionConfig::default();
+
+    let pdf = ExtractionResult {
+        content: "test".to_string(),
+        mime_type: Cow::Borrowed("application/pdf"),
+        tables: tables.clone(),
+        ..Default::default()
+    };
+    let processed = run_pipeline(pdf, &config).await.unwrap();
+    assert_eq!(processed.tables.len(), 1);
+    assert_eq!(processed.tables[0].cells.len(), 3);
+    assert_eq!(processed.tables[0].page_end, Some(2));
+
+    let text = ExtractionResult {
+        content: "test".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        tables,
+        ..Default::default()
+    };
+    let processed = run_pipeline(text, &config).await.unwrap();
+    assert_eq!(processed.tables.len(), 2);
+}
+
 #[tokio::test]
 async fn test_pipeline_empty_content() {
     let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
@@ -268,6 +352,7 @@ async fn test_pipeline_empty_content() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -279,6 +364,7 @@ async fn test_pipeline_empty_content() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     let config = ExtractionConfig::default();
 
@@ -297,6 +383,7 @@ async fn test_pipeline_with_all_features() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -308,6 +395,7 @@ async fn test_pipeline_with_all_features() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     let config = ExtractionConfig {
         enable_quality_processing: true,
@@ -318,6 +406,9 @@ async fn test_pipeline_with_all_features() {
             chunker_type: crate::ChunkerType::Text,
             embedding: None,
             preset: None,
+            sizing: Default::default(),
+            prepend_heading_context: false,
+            semantic: Default::default(),
         }),
         ..Default::default()
     };
@@ -358,6 +449,7 @@ Natural language processing enables computers to understand human language.
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -369,6 +461,7 @@ Natural language processing enables computers to understand human language.
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
 
     #[cfg(feature = "keywords-yake")]
@@ -411,6 +504,7 @@ async fn test_pipeline_without_keyword_config() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -422,6 +516,7 @@ async fn test_pipeline_without_keyword_config() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
 
     let config = ExtractionConfig {
@@ -455,6 +550,7 @@ async fn test_pipeline_keyword_extraction_short_content() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -466,6 +562,7 @@ async fn test_pipeline_keyword_extraction_short_content() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
 
     #[cfg(feature = "keywords-yake")]
@@ -598,6 +695,7 @@ async fn test_postprocessor_runs_before_validator() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -609,6 +707,7 @@ async fn test_postprocessor_runs_before_validator() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     result.metadata.additional.insert(
         Cow::Borrowed(VALIDATION_MARKER_KEY),
@@ -701,6 +800,7 @@ async fn test_quality_processing_runs_before_validator() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -712,6 +812,7 @@ async fn test_quality_processing_runs_before_validator() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
     result.metadata.additional.insert(
         Cow::Borrowed(VALIDATION_MARKER_KEY),
@@ -905,6 +1006,7 @@ async fn test_multiple_postprocessors_run_before_validator() {
         metadata: Metadata::default(),
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         djot_content: None,
@@ -916,6 +1018,7 @@ async fn test_multiple_postprocessors_run_before_validator() {
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     };
 
     let config = ExtractionConfig::default();
@@ -0,0 +1,378 @@
+//! Authentication and quota settings for the API server.
+//!
+//! Authentication is enabled as soon as any credential source is configured:
+//! static API keys, an API key file, or a JWT secret. Without one, the server
+//! accepts unauthenticated requests as before.
+
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+
+/// Authentication configuration for the API server.
+///
+/// Clients authenticate with `Authorization: Bearer <key or JWT>` or
+/// `X-API-Key: <key>`. `GET /health` and `GET /openapi.json` stay public.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthConfig {
+    /// Static API keys
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub api_keys: Vec<ApiKeyConfig>,
+
+    /// File with additional API keys, one per line (see [`parse_api_keys_file`])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_keys_file: Option<PathBuf>,
+
+    /// Validation of HMAC-signed JWT bearer tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt: Option<JwtConfig>,
+
+    /// Quota for API keys without their own quota and for JWT subjects
+    pub default_quota: QuotaConfig,
+}
+
+impl AuthConfig {
+    /// Check whether any credential source is configured.
+    pub fn is_enabled(&self) -> bool {
+        !self.api_keys.is_empty() || self.api_keys_file.is_some() || self.jwt.is_some()
+    }
+
+    /// Collect the configured API keys and the keys from `api_keys_file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if the key file cannot be read or
+    /// contains an unknown scope, or if a configured key is empty.
+    pub fn resolve_api_keys(&self) -> Result<Vec<ApiKeyConfig>> {
+        let mut keys = self.api_keys.clone();
+
+        if let Some(path) = &self.api_keys_file {
+            let content = std::fs::read_to_string(path).map_err(|e| {
+                KreuzbergError::validation(format!("Failed to read API key file {}: {}", path.display(), e))
+            })?;
+            keys.extend(parse_api_keys_file(&content)?);
+        }
+
+        if keys.iter().any(|key| key.key.trim().is_empty()) {
+            return Err(KreuzbergError::validation("API keys must not be empty"));
+        }
+
+        Ok(keys)
+    }
+}
+
+/// Permission level of an authenticated client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiScope {
+    /// Extraction, embedding, chunking and read-only endpoints
+    User,
+    /// Everything `user` may do, plus administrative endpoints such as `/cache/clear`
+    Admin,
+}
+
+impl ApiScope {
+    /// Check whether a client holding `scopes` may access endpoints requiring `self`.
+    pub fn is_granted_by(self, scopes: &[ApiScope]) -> bool {
+        scopes.iter().any(|&scope| scope == self || scope == ApiScope::Admin)
+    }
+}
+
+impl std::str::FromStr for ApiScope {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "user" => Ok(ApiScope::User),
+            "admin" => Ok(ApiScope::Admin),
+            other => Err(KreuzbergError::validation(format!(
+                "Unknown API scope '{}', expected 'user' or 'admin'",
+                other
+            ))),
+        }
+    }
+}
+
+fn default_scopes() -> Vec<ApiScope> {
+    vec![ApiScope::User]
+}
+
+/// A static API key.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ApiKeyConfig {
+    /// The key presented by clients
+    pub key: String,
+
+    /// Name identifying the key holder in logs (default: derived from the key hash)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Granted scopes (default: `["user"]`)
+    #[serde(default = "default_scopes")]
+    pub scopes: Vec<ApiScope>,
+
+    /// Quota for this key (default: `AuthConfig.default_quota`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota: Option<QuotaConfig>,
+}
+
+impl ApiKeyConfig {
+    /// Create a key with the given scopes and the default quota.
+    pub fn new(key: impl Into<String>, scopes: Vec<ApiScope>) -> Self {
+        Self {
+            key: key.into(),
+            name: None,
+            scopes,
+            quota: None,
+        }
+    }
+}
+
+impl fmt::Debug for ApiKeyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKeyConfig")
+            .field("key", &"<redacted>")
+            .field("name", &self.name)
+            .field("scopes", &self.scopes)
+            .field("quota", &self.quota)
+            .finish()
+    }
+}
+
+/// Request quota of one client.
+///
+/// `None` leaves the respective dimension unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaConfig {
+    /// Sustained request rate; bursts of up to this many requests are allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests_per_minute: Option<u32>,
+
+    /// Maximum number of requests processed at the same time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_requests: Option<usize>,
+}
+
+/// HMAC algorithm used to sign JWTs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JwtAlgorithm {
+    #[default]
+    HS256,
+    HS384,
+    HS512,
+}
+
+impl JwtAlgorithm {
+    /// Name of the algorithm in the JWT `alg` header.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JwtAlgorithm::HS256 => "HS256",
+            JwtAlgorithm::HS384 => "HS384",
+            JwtAlgorithm::HS512 => "HS512",
+        }
+    }
+}
+
+/// Validation settings for HMAC-signed JWT bearer tokens.
+///
+/// The token subject (`sub`) identifies the client for quotas. Scopes are read
+/// from `scope_claim`, either as a space-separated string or as an array;
+/// tokens without known scopes get the `user` scope.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct JwtConfig {
+    /// Shared HMAC secret
+    pub secret: String,
+
+    /// Expected signing algorithm (default: HS256)
+    #[serde(default)]
+    pub algorithm: JwtAlgorithm,
+
+    /// Required `iss` claim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+
+    /// Required `aud` claim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+
+    /// Clock skew tolerated when checking `exp` and `nbf` (default: 60 seconds)
+    #[serde(default = "default_leeway_seconds")]
+    pub leeway_seconds: u64,
+
+    /// Claim holding the granted scopes (default: `scope`)
+    #[serde(default = "default_scope_claim")]
+    pub scope_claim: String,
+}
+
+impl JwtConfig {
+    /// Create a configuration for HS256 tokens signed with `secret`.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            algorithm: JwtAlgorithm::default(),
+            issuer: None,
+            audience: None,
+            leeway_seconds: default_leeway_seconds(),
+            scope_claim: default_scope_claim(),
+        }
+    }
+}
+
+impl fmt::Debug for JwtConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwtConfig")
+            .field("secret", &"<redacted>")
+            .field("algorithm", &self.algorithm)
+            .field("issuer", &self.issuer)
+            .field("audience", &self.audience)
+            .field("leeway_seconds", &self.leeway_seconds)
+            .field("scope_claim", &self.scope_claim)
+            .finish()
+    }
+}
+
+fn default_leeway_seconds() -> u64 {
+    60
+}
+
+fn default_scope_claim() -> String {
+    "scope".to_string()
+}
+
+/// Parse an API key file.
+///
+/// Each non-empty line holds a key, optionally followed by whitespace and a
+/// comma-separated scope list (`user` if omitted). Lines starting with `#`
+/// are comments:
+///
+/// ```text
+/// # CI pipeline
+/// 3f9c2a7e0b1d4c58
+/// # operations team
+/// 8e41d0c6a9f27b35 admin
+/// ```
+pub fn parse_api_keys_file(content: &str) -> Result<Vec<ApiKeyConfig>> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let key = parts.next().unwrap_or_default();
+            let scopes = match parts.next() {
+                Some(scopes) => scopes
+                    .split(',')
+                    .filter(|scope| !scope.is_empty())
+                    .map(str::parse)
+                    .collect::<Result<Vec<_>>>()?,
+                None => default_scopes(),
+            };
+            Ok(ApiKeyConfig::new(key, scopes))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_disabled_by_default() {
+        assert!(!AuthConfig::default().is_enabled());
+    }
+
+    #[test]
+    fn test_scope_grants() {
+        assert!(ApiScope::User.is_granted_by(&[ApiScope::User]));
+        assert!(ApiScope::User.is_granted_by(&[ApiScope::Admin]));
+        assert!(!ApiScope::Admin.is_granted_by(&[ApiScope::User]));
+        assert!(!ApiScope::User.is_granted_by(&[]));
+    }
+
+    #[test]
+    fn test_parse_api_keys_file() {
+        let keys = parse_api_keys_file("# comment\n\nkey-one\nkey-two admin\nkey-three user,admin\n").unwrap();
+
+        assert_eq!(keys.len(), 3);
+        assert_eq!(keys[0].key, "key-one");
+        assert_eq!(keys[0].scopes, vec![ApiScope::User]);
+        assert_eq!(keys[1].scopes, vec![ApiScope::Admin]);
+        assert_eq!(keys[2].scopes, vec![ApiScope::User, ApiScope::Admin]);
+    }
+
+    #[test]
+    fn test_parse_api_keys_file_rejects_unknown_scope() {
+        assert!(parse_api_keys_file("key-one superuser").is_err());
+    }
+
+    #[test]
+    fn test_resolve_api_keys_reads_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.txt");
+        std::fs::write(&path, "from-file admin\n").unwrap();
+
+        let config = AuthConfig {
+            api_keys: vec![ApiKeyConfig::new("inline", vec![ApiScope::User])],
+            api_keys_file: Some(path),
+            ..Default::default()
+        };
+        let keys = config.resolve_api_keys().unwrap();
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[1].key, "from-file");
+    }
+
+    #[test]
+    fn test_resolve_api_keys_missing_file() {
+        let config = AuthConfig {
+            api_keys_file: Some(PathBuf::from("/nonexistent/kreuzberg-keys.txt")),
+            ..Default::default()
+        };
+        assert!(config.resolve_api_keys().is_err());
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let key = ApiKeyConfig::new("super-secret-key", vec![ApiScope::User]);
+        let jwt = JwtConfig::new("super-secret-hmac");
+
+        assert!(!format!("{:?}", key).contains("super-secret"));
+        assert!(!format!("{:?}", jwt).contains("super-secret"));
+    }
+
+    #[test]
+    fn test_auth_config_deserialize() {
+        let config: AuthConfig = toml::from_str(
+            r#"
+            api_keys_file = "/etc/kreuzberg/keys"
+
+            [[api_keys]]
+            key = "abc"
+            name = "ops"
+            scopes = ["admin"]
+            quota = { requests_per_minute = 10 }
+
+            [jwt]
+            secret = "s3cret"
+            algorithm = "HS512"
+            issuer = "https://auth.internal"
+
+            [default_quota]
+            requests_per_minute = 60
+            max_concurrent_requests = 4
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.is_enabled());
+        assert_eq!(config.api_keys[0].scopes, vec![ApiScope::Admin]);
+        assert_eq!(config.api_keys[0].quota.unwrap().requests_per_minute, Some(10));
+        let jwt = config.jwt.unwrap();
+        assert_eq!(jwt.algorithm, JwtAlgorithm::HS512);
+        assert_eq!(jwt.leeway_seconds, 60);
+        assert_eq!(jwt.scope_claim, "scope");
+        assert_eq!(config.default_quota.max_concurrent_requests, Some(4));
+    }
+}
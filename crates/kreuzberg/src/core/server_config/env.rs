@@ -3,7 +3,11 @@
 //! This module provides functionality to override server configuration values
 //! using environment variables. All settings can be overridden at runtime.
 
+use super::auth::{ApiKeyConfig, ApiScope, AuthConfig, JwtConfig};
+use super::jobs::JobsConfig;
+use crate::core::config::CacheBackend;
 use crate::{KreuzbergError, Result};
+use std::path::PathBuf;
 
 /// Apply environment variable overrides to a ServerConfig.
 ///
@@ -88,3 +92,110 @@ pub fn apply_env_overrides(
 
     Ok(())
 }
+
+/// Apply environment variable overrides to the authentication settings.
+///
+/// Reads the following environment variables:
+///
+/// - `KREUZBERG_API_KEYS` - Comma-separated API keys with the `user` scope
+/// - `KREUZBERG_ADMIN_API_KEYS` - Comma-separated API keys with the `admin` scope
+/// - `KREUZBERG_API_KEYS_FILE` - Path to an API key file
+/// - `KREUZBERG_JWT_SECRET` - HMAC secret for JWT bearer tokens (keeps other JWT settings)
+/// - `KREUZBERG_RATE_LIMIT_PER_MINUTE` - Default per-client request rate
+/// - `KREUZBERG_MAX_CONCURRENT_REQUESTS` - Default per-client concurrency limit
+///
+/// Keys from the environment are added to the keys from the configuration file.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if:
+/// - `KREUZBERG_RATE_LIMIT_PER_MINUTE` cannot be parsed as u32
+/// - `KREUZBERG_MAX_CONCURRENT_REQUESTS` cannot be parsed as usize
+pub fn apply_auth_env_overrides(auth: &mut AuthConfig) -> Result<()> {
+    for (var, scope) in [
+        ("KREUZBERG_API_KEYS", ApiScope::User),
+        ("KREUZBERG_ADMIN_API_KEYS", ApiScope::Admin),
+    ] {
+        if let Ok(keys_str) = std::env::var(var) {
+            auth.api_keys.extend(
+                keys_str
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|key| !key.is_empty())
+                    .map(|key| ApiKeyConfig::new(key, vec![scope])),
+            );
+        }
+    }
+
+    if let Ok(path) = std::env::var("KREUZBERG_API_KEYS_FILE") {
+        auth.api_keys_file = Some(PathBuf::from(path));
+    }
+
+    if let Ok(secret) = std::env::var("KREUZBERG_JWT_SECRET") {
+        match auth.jwt.as_mut() {
+            Some(jwt) => jwt.secret = secret,
+            None => auth.jwt = Some(JwtConfig::new(secret)),
+        }
+    }
+
+    if let Ok(rate_str) = std::env::var("KREUZBERG_RATE_LIMIT_PER_MINUTE") {
+        let rate = rate_str.parse::<u32>().map_err(|e| {
+            KreuzbergError::validation(format!(
+                "KREUZBERG_RATE_LIMIT_PER_MINUTE must be a valid u32, got '{}': {}",
+                rate_str, e
+            ))
+        })?;
+        auth.default_quota.requests_per_minute = Some(rate);
+    }
+
+    if let Ok(concurrency_str) = std::env::var("KREUZBERG_MAX_CONCURRENT_REQUESTS") {
+        let concurrency = concurrency_str.parse::<usize>().map_err(|e| {
+            KreuzbergError::validation(format!(
+                "KREUZBERG_MAX_CONCURRENT_REQUESTS must be a valid usize, got '{}': {}",
+                concurrency_str, e
+            ))
+        })?;
+        auth.default_quota.max_concurrent_requests = Some(concurrency);
+    }
+
+    Ok(())
+}
+
+/// Apply environment variable overrides to the job settings.
+///
+/// - `KREUZBERG_JOB_WORKERS` - Number of concurrently processed jobs
+/// - `KREUZBERG_MAX_QUEUED_JOBS` - Maximum number of queued jobs
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if either variable cannot be parsed as usize.
+pub fn apply_jobs_env_overrides(jobs: &mut JobsConfig) -> Result<()> {
+    for (var, target) in [
+        ("KREUZBERG_JOB_WORKERS", &mut jobs.workers),
+        ("KREUZBERG_MAX_QUEUED_JOBS", &mut jobs.max_queued_jobs),
+    ] {
+        if let Ok(value_str) = std::env::var(var) {
+            *target = value_str.parse::<usize>().map_err(|e| {
+                KreuzbergError::validation(format!("{} must be a valid usize, got '{}': {}", var, value_str, e))
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply the `KREUZBERG_CACHE_BACKEND` override to the result cache backend.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the variable names no known backend.
+pub fn apply_cache_env_overrides(cache_backend: &mut Option<CacheBackend>) -> Result<()> {
+    if let Ok(backend_str) = std::env::var("KREUZBERG_CACHE_BACKEND") {
+        let backend = backend_str
+            .parse::<CacheBackend>()
+            .map_err(|e| KreuzbergError::validation(format!("KREUZBERG_CACHE_BACKEND: {}", e)))?;
+        *cache_backend = Some(backend);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! Settings for asynchronous extraction jobs.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Default number of jobs processed concurrently
+const DEFAULT_WORKERS: usize = 2;
+
+/// Default number of jobs waiting for a worker before submissions are rejected
+const DEFAULT_MAX_QUEUED_JOBS: usize = 100;
+
+/// Default time finished jobs are kept: 24 hours
+const DEFAULT_RETENTION_HOURS: u64 = 24;
+
+/// Default timeout for completion webhook requests
+const DEFAULT_WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// Configuration of the asynchronous job API (`/jobs`).
+///
+/// Job records and results are stored as JSON files in `directory`, so
+/// finished jobs survive a server restart. Jobs that were queued or running
+/// when the server stopped are reported as failed, since their uploads are
+/// not persisted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobsConfig {
+    /// Number of jobs processed concurrently (default: 2)
+    pub workers: usize,
+
+    /// Maximum number of queued jobs; further submissions get `503` (default: 100)
+    pub max_queued_jobs: usize,
+
+    /// Directory for job records and results (default: `.kreuzberg/jobs` in the working directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directory: Option<PathBuf>,
+
+    /// Hours finished jobs are kept before they are removed (default: 24)
+    pub retention_hours: u64,
+
+    /// Timeout for completion webhook requests in seconds (default: 10)
+    pub webhook_timeout_secs: u64,
+}
+
+impl Default for JobsConfig {
+    fn default() -> Self {
+        Self {
+            workers: DEFAULT_WORKERS,
+            max_queued_jobs: DEFAULT_MAX_QUEUED_JOBS,
+            directory: None,
+            retention_hours: DEFAULT_RETENTION_HOURS,
+            webhook_timeout_secs: DEFAULT_WEBHOOK_TIMEOUT_SECS,
+        }
+    }
+}
+
+impl JobsConfig {
+    /// Directory job records and results are stored in.
+    pub fn resolved_directory(&self) -> PathBuf {
+        self.directory.clone().unwrap_or_else(|| {
+            std::env::current_dir()
+                .unwrap_or_else(|_| PathBuf::from("."))
+                .join(".kreuzberg")
+                .join("jobs")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jobs_config_defaults() {
+        let config = JobsConfig::default();
+        assert_eq!(config.workers, 2);
+        assert_eq!(config.max_queued_jobs, 100);
+        assert!(config.resolved_directory().ends_with(".kreuzberg/jobs"));
+    }
+
+    #[test]
+    fn test_jobs_config_deserialize_partial() {
+        let config: JobsConfig = toml::from_str("workers = 8\ndirectory = \"/var/lib/kreuzberg/jobs\"").unwrap();
+        assert_eq!(config.workers, 8);
+        assert_eq!(config.max_queued_jobs, 100);
+        assert_eq!(config.resolved_directory(), PathBuf::from("/var/lib/kreuzberg/jobs"));
+    }
+}
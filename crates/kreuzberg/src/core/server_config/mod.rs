@@ -32,13 +32,19 @@
 //! ```
 
 use crate::Result;
+use crate::core::config::CacheBackend;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+pub mod auth;
 mod env;
+pub mod jobs;
 mod loader;
 mod validation;
 
+pub use auth::{ApiKeyConfig, ApiScope, AuthConfig, JwtAlgorithm, JwtConfig, QuotaConfig};
+pub use jobs::JobsConfig;
+
 #[cfg(test)]
 mod tests;
 
@@ -67,6 +73,9 @@ const DEFAULT_MAX_MULTIPART_FIELD_BYTES: usize = 104_857_600;
 /// - `max_request_body_bytes`: 104_857_600 (100 MB)
 /// - `max_multipart_field_bytes`: 104_857_600 (100 MB)
 /// - `max_upload_mb`: None (legacy field, not used if other fields set)
+/// - `auth`: disabled (no API keys or JWT secret configured)
+/// - `jobs`: 2 workers, 100 queued jobs, stored in `.kreuzberg/jobs`
+/// - `cache_backend`: None (use the extraction configuration's backend)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ServerConfig {
@@ -101,6 +110,19 @@ pub struct ServerConfig {
     /// New configurations should use `max_multipart_field_bytes` directly.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_upload_mb: Option<usize>,
+
+    /// Authentication and per-client quotas (default: disabled)
+    #[serde(default)]
+    pub auth: AuthConfig,
+
+    /// Asynchronous job processing (`/jobs` endpoints)
+    #[serde(default)]
+    pub jobs: JobsConfig,
+
+    /// Result cache storage backend for all requests, overriding the
+    /// extraction configuration's `cache_backend` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_backend: Option<CacheBackend>,
 }
 
 impl Default for ServerConfig {
@@ -112,6 +134,9 @@ impl Default for ServerConfig {
             max_request_body_bytes: default_max_request_body_bytes(),
             max_multipart_field_bytes: default_max_multipart_field_bytes(),
             max_upload_mb: None,
+            auth: AuthConfig::default(),
+            jobs: JobsConfig::default(),
+            cache_backend: None,
         }
     }
 }
@@ -248,6 +273,15 @@ impl ServerConfig {
     /// - `KREUZBERG_MAX_REQUEST_BODY_BYTES` - Max request body size in bytes
     /// - `KREUZBERG_MAX_MULTIPART_FIELD_BYTES` - Max multipart field size in bytes
     /// - `KREUZBERG_MAX_UPLOAD_SIZE_MB` - Max upload size in MB (legacy)
+    /// - `KREUZBERG_API_KEYS` - Comma-separated API keys with the `user` scope
+    /// - `KREUZBERG_ADMIN_API_KEYS` - Comma-separated API keys with the `admin` scope
+    /// - `KREUZBERG_API_KEYS_FILE` - Path to an API key file
+    /// - `KREUZBERG_JWT_SECRET` - HMAC secret for JWT bearer tokens
+    /// - `KREUZBERG_RATE_LIMIT_PER_MINUTE` - Default per-client request rate
+    /// - `KREUZBERG_MAX_CONCURRENT_REQUESTS` - Default per-client concurrency limit
+    /// - `KREUZBERG_JOB_WORKERS` - Number of concurrently processed jobs
+    /// - `KREUZBERG_MAX_QUEUED_JOBS` - Maximum number of queued jobs
+    /// - `KREUZBERG_CACHE_BACKEND` - Result cache backend (`filesystem`, `sqlite` or `memory`)
     ///
     /// # Errors
     ///
@@ -256,6 +290,10 @@ impl ServerConfig {
     /// - `KREUZBERG_MAX_REQUEST_BODY_BYTES` cannot be parsed as usize
     /// - `KREUZBERG_MAX_MULTIPART_FIELD_BYTES` cannot be parsed as usize
     /// - `KREUZBERG_MAX_UPLOAD_SIZE_MB` cannot be parsed as usize
+    /// - `KREUZBERG_RATE_LIMIT_PER_MINUTE` cannot be parsed as u32
+    /// - `KREUZBERG_MAX_CONCURRENT_REQUESTS` cannot be parsed as usize
+    /// - `KREUZBERG_JOB_WORKERS` or `KREUZBERG_MAX_QUEUED_JOBS` cannot be parsed as usize
+    /// - `KREUZBERG_CACHE_BACKEND` is not a known backend
     ///
     /// # Example
     ///
@@ -285,6 +323,9 @@ impl ServerConfig {
             &mut self.max_multipart_field_bytes,
             &mut self.max_upload_mb,
         )?;
+        env::apply_auth_env_overrides(&mut self.auth)?;
+        env::apply_jobs_env_overrides(&mut self.jobs)?;
+        env::apply_cache_env_overrides(&mut self.cache_backend)?;
 
         // Apply legacy field normalization
         self.normalize_legacy_fields();
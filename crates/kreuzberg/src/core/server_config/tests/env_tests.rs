@@ -3,6 +3,7 @@
 #![allow(unsafe_code)]
 
 use crate::core::ServerConfig;
+use crate::core::config::CacheBackend;
 
 #[serial_test::serial]
 #[test]
@@ -214,3 +215,153 @@ fn test_apply_env_multiple_overrides() {
         }
     }
 }
+
+#[serial_test::serial]
+#[test]
+fn test_apply_env_api_keys_override() {
+    let original_user = std::env::var("KREUZBERG_API_KEYS").ok();
+    let original_admin = std::env::var("KREUZBERG_ADMIN_API_KEYS").ok();
+    unsafe {
+        std::env::set_var("KREUZBERG_API_KEYS", "user-key-1, user-key-2");
+        std::env::set_var("KREUZBERG_ADMIN_API_KEYS", "admin-key");
+    }
+
+    let mut config = ServerConfig::default();
+    config.apply_env_overrides().unwrap();
+
+    assert!(config.auth.is_enabled());
+    let keys: Vec<_> = config.auth.api_keys.iter().map(|k| k.key.as_str()).collect();
+    assert_eq!(keys, vec!["user-key-1", "user-key-2", "admin-key"]);
+    assert_eq!(config.auth.api_keys[2].scopes, vec![crate::core::ApiScope::Admin]);
+
+    // Cleanup
+    unsafe {
+        if let Some(orig) = original_user {
+            std::env::set_var("KREUZBERG_API_KEYS", orig);
+        } else {
+            std::env::remove_var("KREUZBERG_API_KEYS");
+        }
+        if let Some(orig) = original_admin {
+            std::env::set_var("KREUZBERG_ADMIN_API_KEYS", orig);
+        } else {
+            std::env::remove_var("KREUZBERG_ADMIN_API_KEYS");
+        }
+    }
+}
+
+#[serial_test::serial]
+#[test]
+fn test_apply_env_jwt_and_quota_override() {
+    let original_secret = std::env::var("KREUZBERG_JWT_SECRET").ok();
+    let original_rate = std::env::var("KREUZBERG_RATE_LIMIT_PER_MINUTE").ok();
+    let original_concurrency = std::env::var("KREUZBERG_MAX_CONCURRENT_REQUESTS").ok();
+    unsafe {
+        std::env::set_var("KREUZBERG_JWT_SECRET", "env-secret");
+        std::env::set_var("KREUZBERG_RATE_LIMIT_PER_MINUTE", "120");
+        std::env::set_var("KREUZBERG_MAX_CONCURRENT_REQUESTS", "3");
+    }
+
+    let mut config = ServerConfig::default();
+    config.apply_env_overrides().unwrap();
+
+    assert_eq!(config.auth.jwt.as_ref().unwrap().secret, "env-secret");
+    assert_eq!(config.auth.default_quota.requests_per_minute, Some(120));
+    assert_eq!(config.auth.default_quota.max_concurrent_requests, Some(3));
+
+    // Cleanup
+    unsafe {
+        for (var, orig) in [
+            ("KREUZBERG_JWT_SECRET", original_secret),
+            ("KREUZBERG_RATE_LIMIT_PER_MINUTE", original_rate),
+            ("KREUZBERG_MAX_CONCURRENT_REQUESTS", original_concurrency),
+        ] {
+            if let Some(orig) = orig {
+                std::env::set_var(var, orig);
+            } else {
+                std::env::remove_var(var);
+            }
+        }
+    }
+}
+
+#[serial_test::serial]
+#[test]
+fn test_apply_env_rate_limit_invalid() {
+    let original = std::env::var("KREUZBERG_RATE_LIMIT_PER_MINUTE").ok();
+    unsafe {
+        std::env::set_var("KREUZBERG_RATE_LIMIT_PER_MINUTE", "fast");
+    }
+
+    let mut config = ServerConfig::default();
+    let result = config.apply_env_overrides();
+
+    assert!(result.is_err());
+
+    // Cleanup
+    unsafe {
+        if let Some(orig) = original {
+            std::env::set_var("KREUZBERG_RATE_LIMIT_PER_MINUTE", orig);
+        } else {
+            std::env::remove_var("KREUZBERG_RATE_LIMIT_PER_MINUTE");
+        }
+    }
+}
+
+#[serial_test::serial]
+#[test]
+fn test_apply_env_jobs_override() {
+    let original_workers = std::env::var("KREUZBERG_JOB_WORKERS").ok();
+    let original_queue = std::env::var("KREUZBERG_MAX_QUEUED_JOBS").ok();
+    unsafe {
+        std::env::set_var("KREUZBERG_JOB_WORKERS", "6");
+        std::env::set_var("KREUZBERG_MAX_QUEUED_JOBS", "500");
+    }
+
+    let mut config = ServerConfig::default();
+    config.apply_env_overrides().unwrap();
+
+    assert_eq!(config.jobs.workers, 6);
+    assert_eq!(config.jobs.max_queued_jobs, 500);
+
+    // Cleanup
+    unsafe {
+        for (var, orig) in [
+            ("KREUZBERG_JOB_WORKERS", original_workers),
+            ("KREUZBERG_MAX_QUEUED_JOBS", original_queue),
+        ] {
+            if let Some(orig) = orig {
+                std::env::set_var(var, orig);
+            } else {
+                std::env::remove_var(var);
+            }
+        }
+    }
+}
+
+#[serial_test::serial]
+#[test]
+fn test_apply_env_cache_backend_override() {
+    let original = std::env::var("KREUZBERG_CACHE_BACKEND").ok();
+    unsafe {
+        std::env::set_var("KREUZBERG_CACHE_BACKEND", "memory");
+    }
+
+    let mut config = ServerConfig::default();
+    config.apply_env_overrides().unwrap();
+    assert_eq!(config.cache_backend, Some(CacheBackend::Memory));
+
+    unsafe {
+        std::env::set_var("KREUZBERG_CACHE_BACKEND", "redis");
+    }
+    let result = ServerConfig::default().apply_env_overrides();
+    assert!(result.unwrap_err().to_string().contains("KREUZBERG_CACHE_BACKEND"));
+
+    // Cleanup
+    unsafe {
+        if let Some(orig) = original {
+            std::env::set_var("KREUZBERG_CACHE_BACKEND", orig);
+        } else {
+            std::env::remove_var("KREUZBERG_CACHE_BACKEND");
+        }
+    }
+}
@@ -0,0 +1,795 @@
+//! Comparison of two extraction results.
+//!
+//! Used to see what changed when a corpus is re-extracted after an upgrade or
+//! a configuration change. [`diff_results`] compares content line by line
+//! (with word-level changes for modified lines and an overall similarity
+//! score), tables cell by cell, metadata field by field, and chunk counts and
+//! boundaries. The [`ResultDiff`] serializes to JSON and its `Display`
+//! implementation renders a text report.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::ExtractionResult;
+//! use kreuzberg::diff::diff_results;
+//!
+//! let old = ExtractionResult {
+//!     content: "Invoice 2024-001\nTotal: 120 EUR".to_string(),
+//!     ..Default::default()
+//! };
+//! let new = ExtractionResult {
+//!     content: "Invoice 2024-001\nTotal: 125 EUR".to_string(),
+//!     ..Default::default()
+//! };
+//!
+//! let diff = diff_results(&old, &new);
+//! assert_eq!(diff.text.lines_modified, 1);
+//! assert!(diff.text.similarity < 1.0);
+//! ```
+
+mod report;
+mod sequence;
+
+use crate::types::{Chunk, ExtractionResult, Table};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use sequence::Aligned;
+
+/// Minimum word similarity for a removed and an added line to count as one modified line.
+const MODIFIED_LINE_SIMILARITY: f64 = 0.5;
+
+/// Options for [`diff_results_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffOptions {
+    /// Unchanged lines kept around each text change (default: 3)
+    pub context_lines: usize,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self { context_lines: 3 }
+    }
+}
+
+/// Differences between two extraction results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultDiff {
+    /// Content differences
+    pub text: TextDiff,
+    /// Tables that were added, removed or changed
+    pub tables: Vec<TableDiff>,
+    /// Changed metadata fields, including `mime_type`
+    pub metadata: Vec<MetadataChange>,
+    /// Chunk differences, if either result was chunked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunks: Option<ChunkDiff>,
+}
+
+impl ResultDiff {
+    /// Whether the two results have no differences.
+    pub fn is_identical(&self) -> bool {
+        self.text.hunks.is_empty()
+            && self.tables.is_empty()
+            && self.metadata.is_empty()
+            && self.chunks.as_ref().is_none_or(ChunkDiff::is_identical)
+    }
+}
+
+/// How an item differs between the old and the new result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Present in both, unchanged
+    Equal,
+    /// Only in the new result
+    Added,
+    /// Only in the old result
+    Removed,
+    /// Present in both, changed
+    Modified,
+}
+
+/// Content differences.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextDiff {
+    /// Share of words the two contents have in common (0.0-1.0)
+    pub similarity: f64,
+    /// Lines only in the new content
+    pub lines_added: usize,
+    /// Lines only in the old content
+    pub lines_removed: usize,
+    /// Lines present in both with word changes
+    pub lines_modified: usize,
+    /// Changed regions with surrounding context
+    pub hunks: Vec<TextHunk>,
+}
+
+/// A changed region of the content, like a unified diff hunk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextHunk {
+    /// First old line in the hunk (1-indexed)
+    pub old_start: usize,
+    /// Number of old lines in the hunk
+    pub old_lines: usize,
+    /// First new line in the hunk (1-indexed)
+    pub new_start: usize,
+    /// Number of new lines in the hunk
+    pub new_lines: usize,
+    /// Lines of the hunk in order
+    pub changes: Vec<LineChange>,
+}
+
+/// One line of a [`TextHunk`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineChange {
+    /// `equal` for context lines
+    pub kind: ChangeKind,
+    /// Old line number (1-indexed), absent for added lines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_line: Option<usize>,
+    /// New line number (1-indexed), absent for removed lines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_line: Option<usize>,
+    /// Old text, absent for added lines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_text: Option<String>,
+    /// New text, absent for removed lines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_text: Option<String>,
+    /// Word-level changes of a modified line
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordChange>,
+}
+
+/// A run of words in a modified line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordChange {
+    /// `equal`, `added` or `removed`
+    pub kind: ChangeKind,
+    /// The words, separated by single spaces
+    pub text: String,
+}
+
+/// Differences of one table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableDiff {
+    /// `added`, `removed` or `modified`
+    pub kind: ChangeKind,
+    /// Index in the old result's tables
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_index: Option<usize>,
+    /// Index in the new result's tables
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_index: Option<usize>,
+    /// Page of the old table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_page: Option<usize>,
+    /// Page of the new table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_page: Option<usize>,
+    /// Rows and columns of the old table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_shape: Option<TableShape>,
+    /// Rows and columns of the new table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_shape: Option<TableShape>,
+    /// Changed cells of a modified table
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cells: Vec<CellChange>,
+}
+
+/// Table dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableShape {
+    pub rows: usize,
+    pub columns: usize,
+}
+
+/// A cell whose content differs, by position.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellChange {
+    /// Row index (0-indexed)
+    pub row: usize,
+    /// Column index (0-indexed)
+    pub column: usize,
+    /// Old content, absent if the cell did not exist
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    /// New content, absent if the cell no longer exists
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+}
+
+/// A metadata field whose value differs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataChange {
+    /// Field path, with nested objects joined by `.` (e.g. `pages.total_count`)
+    pub field: String,
+    /// Old value, absent if the field was added
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+    /// New value, absent if the field was removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+}
+
+/// Chunk count and boundary differences.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkDiff {
+    /// Number of old chunks
+    pub old_count: usize,
+    /// Number of new chunks
+    pub new_count: usize,
+    /// Chunks with the same text and byte range in both results
+    pub unchanged: usize,
+    /// Chunks that were added, removed, changed, or kept their text but moved
+    pub changes: Vec<ChunkChange>,
+}
+
+impl ChunkDiff {
+    fn is_identical(&self) -> bool {
+        self.old_count == self.new_count && self.changes.is_empty()
+    }
+}
+
+/// Differences of one chunk.
+///
+/// `equal` entries are chunks whose text is unchanged but whose byte range
+/// shifted; `modified` entries pair an old and a new chunk whose text differs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkChange {
+    pub kind: ChangeKind,
+    /// Index in the old result's chunks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_index: Option<usize>,
+    /// Index in the new result's chunks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_index: Option<usize>,
+    /// Old `[byte_start, byte_end]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_bytes: Option<(usize, usize)>,
+    /// New `[byte_start, byte_end]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_bytes: Option<(usize, usize)>,
+    /// Movement of the start boundary in bytes (new minus old)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_shift: Option<i64>,
+    /// Movement of the end boundary in bytes (new minus old)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_shift: Option<i64>,
+}
+
+/// Compare two extraction results with default options.
+pub fn diff_results(old: &ExtractionResult, new: &ExtractionResult) -> ResultDiff {
+    diff_results_with_options(old, new, &DiffOptions::default())
+}
+
+/// Compare two extraction results.
+pub fn diff_results_with_options(old: &ExtractionResult, new: &ExtractionResult, options: &DiffOptions) -> ResultDiff {
+    let chunks = if old.chunks.is_some() || new.chunks.is_some() {
+        Some(diff_chunks(
+            old.chunks.as_deref().unwrap_or_default(),
+            new.chunks.as_deref().unwrap_or_default(),
+        ))
+    } else {
+        None
+    };
+
+    ResultDiff {
+        text: diff_text(&old.content, &new.content, options.context_lines),
+        tables: diff_tables(&old.tables, &new.tables),
+        metadata: diff_metadata(old, new),
+        chunks,
+    }
+}
+
+fn diff_text(old: &str, new: &str, context_lines: usize) -> TextDiff {
+    let old_words: Vec<&str> = old.split_whitespace().collect();
+    let new_words: Vec<&str> = new.split_whitespace().collect();
+    let similarity = sequence::similarity(
+        &sequence::diff(&old_words, &new_words),
+        old_words.len(),
+        new_words.len(),
+    );
+
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = sequence::diff(&old_lines, &new_lines);
+    let aligned = sequence::pair_changes(&ops, |o, n| {
+        line_similarity(old_lines[o], new_lines[n]) >= MODIFIED_LINE_SIMILARITY
+    });
+
+    let mut diff = TextDiff {
+        similarity,
+        lines_added: 0,
+        lines_removed: 0,
+        lines_modified: 0,
+        hunks: Vec::new(),
+    };
+
+    let changed: Vec<usize> = aligned
+        .iter()
+        .enumerate()
+        .filter(|(_, step)| !matches!(step, Aligned::Equal { .. }))
+        .map(|(k, _)| k)
+        .collect();
+
+    // Old and new lines preceding each step, for the start of hunks without lines on one side.
+    let mut preceding = Vec::with_capacity(aligned.len());
+    let (mut old_seen, mut new_seen) = (0, 0);
+    for step in &aligned {
+        preceding.push((old_seen, new_seen));
+        match step {
+            Aligned::Equal { .. } | Aligned::Modified { .. } => {
+                old_seen += 1;
+                new_seen += 1;
+            }
+            Aligned::Removed { .. } => old_seen += 1,
+            Aligned::Added { .. } => new_seen += 1,
+        }
+    }
+
+    let mut k = 0;
+    while k < changed.len() {
+        let start = changed[k].saturating_sub(context_lines);
+        let mut last = changed[k];
+        while k + 1 < changed.len() && changed[k + 1] <= last + 2 * context_lines + 1 {
+            k += 1;
+            last = changed[k];
+        }
+        let end = (last + context_lines + 1).min(aligned.len());
+        let hunk = build_hunk(
+            &aligned[start..end],
+            preceding[start],
+            &old_lines,
+            &new_lines,
+            &mut diff,
+        );
+        diff.hunks.push(hunk);
+        k += 1;
+    }
+
+    diff
+}
+
+fn build_hunk(
+    steps: &[Aligned],
+    (old_before, new_before): (usize, usize),
+    old_lines: &[&str],
+    new_lines: &[&str],
+    totals: &mut TextDiff,
+) -> TextHunk {
+    let mut changes = Vec::with_capacity(steps.len());
+    let (mut old_count, mut new_count) = (0, 0);
+
+    for step in steps {
+        let (old, new) = match *step {
+            Aligned::Equal { old, new } | Aligned::Modified { old, new } => (Some(old), Some(new)),
+            Aligned::Removed { old } => (Some(old), None),
+            Aligned::Added { new } => (None, Some(new)),
+        };
+        old_count += usize::from(old.is_some());
+        new_count += usize::from(new.is_some());
+
+        let (kind, words) = match step {
+            Aligned::Equal { .. } => (ChangeKind::Equal, Vec::new()),
+            Aligned::Removed { .. } => {
+                totals.lines_removed += 1;
+                (ChangeKind::Removed, Vec::new())
+            }
+            Aligned::Added { .. } => {
+                totals.lines_added += 1;
+                (ChangeKind::Added, Vec::new())
+            }
+            Aligned::Modified { old, new } => {
+                totals.lines_modified += 1;
+                (ChangeKind::Modified, diff_words(old_lines[*old], new_lines[*new]))
+            }
+        };
+
+        changes.push(LineChange {
+            kind,
+            old_line: old.map(|i| i + 1),
+            new_line: new.map(|j| j + 1),
+            old_text: old.map(|i| old_lines[i].to_string()),
+            new_text: new.map(|j| new_lines[j].to_string()),
+            words,
+        });
+    }
+
+    // As in unified diffs, a side without lines starts at the line it follows.
+    TextHunk {
+        old_start: old_before + usize::from(old_count > 0),
+        old_lines: old_count,
+        new_start: new_before + usize::from(new_count > 0),
+        new_lines: new_count,
+        changes,
+    }
+}
+
+fn line_similarity(old: &str, new: &str) -> f64 {
+    let old_words: Vec<&str> = old.split_whitespace().collect();
+    let new_words: Vec<&str> = new.split_whitespace().collect();
+    sequence::similarity(
+        &sequence::diff(&old_words, &new_words),
+        old_words.len(),
+        new_words.len(),
+    )
+}
+
+fn diff_words(old: &str, new: &str) -> Vec<WordChange> {
+    let old_words: Vec<&str> = old.split_whitespace().collect();
+    let new_words: Vec<&str> = new.split_whitespace().collect();
+    let mut runs: Vec<WordChange> = Vec::new();
+
+    for op in sequence::diff(&old_words, &new_words) {
+        let (kind, word) = match op {
+            sequence::Op::Equal { old, .. } => (ChangeKind::Equal, old_words[old]),
+            sequence::Op::Delete { old } => (ChangeKind::Removed, old_words[old]),
+            sequence::Op::Insert { new } => (ChangeKind::Added, new_words[new]),
+        };
+        match runs.last_mut() {
+            Some(run) if run.kind == kind => {
+                run.text.push(' ');
+                run.text.push_str(word);
+            }
+            _ => runs.push(WordChange {
+                kind,
+                text: word.to_string(),
+            }),
+        }
+    }
+    runs
+}
+
+fn diff_tables(old: &[Table], new: &[Table]) -> Vec<TableDiff> {
+    let old_cells: Vec<&Vec<Vec<String>>> = old.iter().map(|t| &t.cells).collect();
+    let new_cells: Vec<&Vec<Vec<String>>> = new.iter().map(|t| &t.cells).collect();
+    let ops = sequence::diff(&old_cells, &new_cells);
+
+    sequence::pair_changes(&ops, |_, _| true)
+        .into_iter()
+        .filter_map(|step| {
+            let (kind, old_index, new_index) = match step {
+                Aligned::Equal { .. } => return None,
+                Aligned::Removed { old } => (ChangeKind::Removed, Some(old), None),
+                Aligned::Added { new } => (ChangeKind::Added, None, Some(new)),
+                Aligned::Modified { old, new } => (ChangeKind::Modified, Some(old), Some(new)),
+            };
+            let (o, n) = (old_index.map(|i| &old[i]), new_index.map(|j| &new[j]));
+            let cells = match (o, n) {
+                (Some(o), Some(n)) => diff_cells(&o.cells, &n.cells),
+                _ => Vec::new(),
+            };
+            Some(TableDiff {
+                kind,
+                old_index,
+                new_index,
+                old_page: o.map(|t| t.page_number),
+                new_page: n.map(|t| t.page_number),
+                old_shape: o.map(table_shape),
+                new_shape: n.map(table_shape),
+                cells,
+            })
+        })
+        .collect()
+}
+
+fn table_shape(table: &Table) -> TableShape {
+    TableShape {
+        rows: table.cells.len(),
+        columns: table.cells.iter().map(Vec::len).max().unwrap_or(0),
+    }
+}
+
+fn diff_cells(old: &[Vec<String>], new: &[Vec<String>]) -> Vec<CellChange> {
+    let cell = |rows: &[Vec<String>], r: usize, c: usize| rows.get(r).and_then(|row| row.get(c)).cloned();
+    let rows = old.len().max(new.len());
+    let mut changes = Vec::new();
+
+    for row in 0..rows {
+        let columns = old.get(row).map_or(0, Vec::len).max(new.get(row).map_or(0, Vec::len));
+        for column in 0..columns {
+            let (old_cell, new_cell) = (cell(old, row, column), cell(new, row, column));
+            if old_cell != new_cell {
+                changes.push(CellChange {
+                    row,
+                    column,
+                    old: old_cell,
+                    new: new_cell,
+                });
+            }
+        }
+    }
+    changes
+}
+
+fn diff_metadata(old: &ExtractionResult, new: &ExtractionResult) -> Vec<MetadataChange> {
+    let old_fields = metadata_fields(old);
+    let new_fields = metadata_fields(new);
+
+    let mut fields: Vec<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let (o, n) = (old_fields.get(field), new_fields.get(field));
+            (o != n).then(|| MetadataChange {
+                field: field.clone(),
+                old: o.cloned(),
+                new: n.cloned(),
+            })
+        })
+        .collect()
+}
+
+/// `mime_type` and the serialized metadata, flattened to dotted paths.
+fn metadata_fields(result: &ExtractionResult) -> BTreeMap<String, Value> {
+    let mut fields = BTreeMap::new();
+    fields.insert("mime_type".to_string(), Value::String(result.mime_type.to_string()));
+    if let Ok(metadata) = serde_json::to_value(&result.metadata) {
+        flatten_value(String::new(), metadata, &mut fields);
+    }
+    fields
+}
+
+fn flatten_value(path: String, value: Value, fields: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                let path = if path.is_empty() { key } else { format!("{path}.{key}") };
+                flatten_value(path, value, fields);
+            }
+        }
+        Value::Null => {}
+        value if !path.is_empty() => {
+            fields.insert(path, value);
+        }
+        _ => {}
+    }
+}
+
+fn diff_chunks(old: &[Chunk], new: &[Chunk]) -> ChunkDiff {
+    let old_texts: Vec<&str> = old.iter().map(|c| c.content.as_str()).collect();
+    let new_texts: Vec<&str> = new.iter().map(|c| c.content.as_str()).collect();
+    let ops = sequence::diff(&old_texts, &new_texts);
+
+    let bytes = |chunk: &Chunk| (chunk.metadata.byte_start, chunk.metadata.byte_end);
+    let shift = |from: usize, to: usize| to as i64 - from as i64;
+
+    let mut diff = ChunkDiff {
+        old_count: old.len(),
+        new_count: new.len(),
+        unchanged: 0,
+        changes: Vec::new(),
+    };
+
+    for step in sequence::pair_changes(&ops, |_, _| true) {
+        let (kind, o, n) = match step {
+            Aligned::Equal { old: o, new: n } => {
+                if bytes(&old[o]) == bytes(&new[n]) {
+                    diff.unchanged += 1;
+                    continue;
+                }
+                (ChangeKind::Equal, Some(o), Some(n))
+            }
+            Aligned::Modified { old: o, new: n } => (ChangeKind::Modified, Some(o), Some(n)),
+            Aligned::Removed { old: o } => (ChangeKind::Removed, Some(o), None),
+            Aligned::Added { new: n } => (ChangeKind::Added, None, Some(n)),
+        };
+        let old_bytes = o.map(|o| bytes(&old[o]));
+        let new_bytes = n.map(|n| bytes(&new[n]));
+        let (start_shift, end_shift) = match (old_bytes, new_bytes) {
+            (Some(ob), Some(nb)) => (Some(shift(ob.0, nb.0)), Some(shift(ob.1, nb.1))),
+            _ => (None, None),
+        };
+        diff.changes.push(ChunkChange {
+            kind,
+            old_index: o,
+            new_index: n,
+            old_bytes,
+            new_bytes,
+            start_shift,
+            end_shift,
+        });
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ChunkMetadata;
+
+    fn result(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".into(),
+            ..Default::default()
+        }
+    }
+
+    fn table(cells: &[&[&str]], page_number: usize) -> Table {
+        Table {
+            cells: cells
+                .iter()
+                .map(|row| row.iter().map(|c| c.to_string()).collect())
+                .collect(),
+            markdown: String::new(),
+            page_number,
+            grid: None,
+            page_end: None,
+        }
+    }
+
+    fn chunk(content: &str, byte_start: usize, index: usize) -> Chunk {
+        Chunk {
+            content: content.to_string(),
+            embedding: None,
+            metadata: ChunkMetadata {
+                byte_start,
+                byte_end: byte_start + content.len(),
+                token_count: None,
+                chunk_index: index,
+                total_chunks: 0,
+                first_page: None,
+                last_page: None,
+                heading_path: Vec::new(),
+                element_types: Vec::new(),
+                node_ids: Vec::new(),
+                language: None,
+                language_confidence: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_identical_results() {
+        let a = result("one\ntwo\nthree");
+        let diff = diff_results(&a, &a.clone());
+        assert!(diff.is_identical());
+        assert_eq!(diff.text.similarity, 1.0);
+        assert!(diff.chunks.is_none());
+    }
+
+    #[test]
+    fn test_text_lines_and_words() {
+        let old = result("Title\nThe total amount is 120 EUR.\nobsolete line\nFooter");
+        let new = result("Title\nThe total amount is 125 EUR.\nFooter\nAppendix");
+        let diff = diff_results(&old, &new);
+
+        assert_eq!(diff.text.lines_modified, 1);
+        assert_eq!(diff.text.lines_removed, 1);
+        assert_eq!(diff.text.lines_added, 1);
+        assert!(diff.text.similarity > 0.6 && diff.text.similarity < 1.0);
+        assert_eq!(diff.text.hunks.len(), 1);
+
+        let hunk = &diff.text.hunks[0];
+        assert_eq!((hunk.old_start, hunk.old_lines), (1, 4));
+        assert_eq!((hunk.new_start, hunk.new_lines), (1, 4));
+
+        let modified = hunk.changes.iter().find(|c| c.kind == ChangeKind::Modified).unwrap();
+        assert_eq!(modified.old_line, Some(2));
+        assert_eq!(
+            modified.words,
+            vec![
+                WordChange {
+                    kind: ChangeKind::Equal,
+                    text: "The total amount is".to_string()
+                },
+                WordChange {
+                    kind: ChangeKind::Removed,
+                    text: "120".to_string()
+                },
+                WordChange {
+                    kind: ChangeKind::Added,
+                    text: "125".to_string()
+                },
+                WordChange {
+                    kind: ChangeKind::Equal,
+                    text: "EUR.".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hunks_are_split_by_context() {
+        let old_lines: Vec<String> = (0..20).map(|i| format!("line {i}")).collect();
+        let mut new_lines = old_lines.clone();
+        new_lines[2] = "changed early".to_string();
+        new_lines[17] = "changed late".to_string();
+
+        let diff = diff_results_with_options(
+            &result(&old_lines.join("\n")),
+            &result(&new_lines.join("\n")),
+            &DiffOptions { context_lines: 2 },
+        );
+        assert_eq!(diff.text.hunks.len(), 2);
+        assert_eq!(diff.text.hunks[0].old_start, 1);
+        assert_eq!(diff.text.hunks[0].old_lines, 5);
+        assert_eq!(diff.text.hunks[1].old_start, 16);
+        assert_eq!(diff.text.hunks[1].old_lines, 5);
+    }
+
+    #[test]
+    fn test_tables_by_cell() {
+        let mut old = result("");
+        old.tables = vec![table(&[&["Item", "Price"], &["Tea", "3"]], 1), table(&[&["Legacy"]], 2)];
+        let mut new = result("");
+        new.tables = vec![table(&[&["Item", "Price"], &["Tea", "4"], &["Cake", "5"]], 1)];
+
+        let diff = diff_results(&old, &new);
+        assert_eq!(diff.tables.len(), 2);
+
+        let modified = &diff.tables[0];
+        assert_eq!(modified.kind, ChangeKind::Modified);
+        assert_eq!(modified.new_shape, Some(TableShape { rows: 3, columns: 2 }));
+        assert_eq!(
+            modified.cells[0],
+            CellChange {
+                row: 1,
+                column: 1,
+                old: Some("3".to_string()),
+                new: Some("4".to_string())
+            }
+        );
+        assert_eq!(modified.cells.len(), 3);
+        assert_eq!(diff.tables[1].kind, ChangeKind::Removed);
+        assert_eq!(diff.tables[1].old_index, Some(1));
+    }
+
+    #[test]
+    fn test_metadata_fields() {
+        let mut old = result("");
+        old.metadata.title = Some("Draft".to_string());
+        old.metadata.language = Some("en".to_string());
+        let mut new = result("");
+        new.mime_type = "application/pdf".into();
+        new.metadata.title = Some("Final".to_string());
+        new.metadata
+            .additional
+            .insert("reviewer".into(), serde_json::json!({"name": "Ana"}));
+
+        let diff = diff_results(&old, &new);
+        let fields: Vec<&str> = diff.metadata.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["language", "mime_type", "reviewer.name", "title"]);
+        assert_eq!(diff.metadata[0].new, None);
+        assert_eq!(diff.metadata[3].new, Some(Value::String("Final".to_string())));
+    }
+
+    #[test]
+    fn test_chunk_boundaries() {
+        let mut old = result("");
+        old.chunks = Some(vec![chunk("alpha", 0, 0), chunk("beta", 6, 1), chunk("gamma", 11, 2)]);
+        let mut new = result("");
+        new.chunks = Some(vec![
+            chunk("alpha", 0, 0),
+            chunk("beta and more", 6, 1),
+            chunk("gamma", 20, 2),
+            chunk("delta", 26, 3),
+        ]);
+
+        let chunks = diff_results(&old, &new).chunks.unwrap();
+        assert_eq!((chunks.old_count, chunks.new_count, chunks.unchanged), (3, 4, 1));
+        assert_eq!(chunks.changes.len(), 3);
+        assert_eq!(chunks.changes[0].kind, ChangeKind::Modified);
+        assert_eq!(chunks.changes[0].end_shift, Some(9));
+        assert_eq!(chunks.changes[1].kind, ChangeKind::Equal);
+        assert_eq!(chunks.changes[1].start_shift, Some(9));
+        assert_eq!(chunks.changes[2].kind, ChangeKind::Added);
+    }
+
+    #[test]
+    fn test_round_trip_json() {
+        let diff = diff_results(&result("a\nb"), &result("a\nc"));
+        let json = serde_json::to_string(&diff).unwrap();
+        let parsed: ResultDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, diff);
+    }
+}
@@ -0,0 +1,210 @@
+//! Text report for [`ResultDiff`].
+
+use super::{ChangeKind, ChunkDiff, LineChange, MetadataChange, ResultDiff, TableDiff, TableShape, TextDiff};
+use serde_json::Value;
+use std::fmt::{self, Display, Formatter, Write};
+
+impl Display for ResultDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_identical() {
+            return writeln!(f, "Results are identical");
+        }
+        write_text(f, &self.text)?;
+        write_tables(f, &self.tables)?;
+        write_metadata(f, &self.metadata)?;
+        if let Some(chunks) = &self.chunks {
+            write_chunks(f, chunks)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_text(f: &mut Formatter<'_>, text: &TextDiff) -> fmt::Result {
+    writeln!(
+        f,
+        "Text: {:.1}% similar, {} line(s) added, {} removed, {} modified",
+        text.similarity * 100.0,
+        text.lines_added,
+        text.lines_removed,
+        text.lines_modified
+    )?;
+    for hunk in &text.hunks {
+        writeln!(
+            f,
+            "@@ -{},{} +{},{} @@",
+            hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines
+        )?;
+        for change in &hunk.changes {
+            write_line(f, change)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_line(f: &mut Formatter<'_>, change: &LineChange) -> fmt::Result {
+    let old = change.old_text.as_deref().unwrap_or_default();
+    let new = change.new_text.as_deref().unwrap_or_default();
+    match change.kind {
+        ChangeKind::Equal => writeln!(f, "  {new}"),
+        ChangeKind::Removed => writeln!(f, "- {old}"),
+        ChangeKind::Added => writeln!(f, "+ {new}"),
+        ChangeKind::Modified => {
+            let mut words = String::new();
+            for word in &change.words {
+                if !words.is_empty() {
+                    words.push(' ');
+                }
+                match word.kind {
+                    ChangeKind::Removed => write!(words, "[-{}-]", word.text)?,
+                    ChangeKind::Added => write!(words, "{{+{}+}}", word.text)?,
+                    _ => words.push_str(&word.text),
+                }
+            }
+            writeln!(f, "~ {words}")
+        }
+    }
+}
+
+fn write_tables(f: &mut Formatter<'_>, tables: &[TableDiff]) -> fmt::Result {
+    if tables.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "Tables: {} changed", tables.len())?;
+    for table in tables {
+        let page = table.new_page.or(table.old_page).unwrap_or_default();
+        match table.kind {
+            ChangeKind::Added => writeln!(
+                f,
+                "  + table {} (page {page}, {})",
+                table.new_index.unwrap_or_default(),
+                shape(table.new_shape)
+            )?,
+            ChangeKind::Removed => writeln!(
+                f,
+                "  - table {} (page {page}, {})",
+                table.old_index.unwrap_or_default(),
+                shape(table.old_shape)
+            )?,
+            _ => {
+                write!(
+                    f,
+                    "  ~ table {} -> {} (page {page}): {} cell(s) changed",
+                    table.old_index.unwrap_or_default(),
+                    table.new_index.unwrap_or_default(),
+                    table.cells.len()
+                )?;
+                if table.old_shape != table.new_shape {
+                    write!(f, ", {} -> {}", shape(table.old_shape), shape(table.new_shape))?;
+                }
+                writeln!(f)?;
+                for cell in &table.cells {
+                    writeln!(
+                        f,
+                        "    [{},{}] {} -> {}",
+                        cell.row,
+                        cell.column,
+                        quoted(cell.old.as_deref()),
+                        quoted(cell.new.as_deref())
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn shape(shape: Option<TableShape>) -> String {
+    shape.map_or_else(String::new, |s| format!("{}x{}", s.rows, s.columns))
+}
+
+fn quoted(cell: Option<&str>) -> String {
+    cell.map_or_else(|| "(none)".to_string(), |c| format!("{c:?}"))
+}
+
+fn write_metadata(f: &mut Formatter<'_>, metadata: &[MetadataChange]) -> fmt::Result {
+    if metadata.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "Metadata: {} field(s) changed", metadata.len())?;
+    for change in metadata {
+        writeln!(
+            f,
+            "  {}: {} -> {}",
+            change.field,
+            value(change.old.as_ref()),
+            value(change.new.as_ref())
+        )?;
+    }
+    Ok(())
+}
+
+fn value(value: Option<&Value>) -> String {
+    value.map_or_else(|| "(none)".to_string(), Value::to_string)
+}
+
+fn write_chunks(f: &mut Formatter<'_>, chunks: &ChunkDiff) -> fmt::Result {
+    if chunks.is_identical() {
+        return Ok(());
+    }
+    writeln!(
+        f,
+        "Chunks: {} -> {} ({} unchanged)",
+        chunks.old_count, chunks.new_count, chunks.unchanged
+    )?;
+    for change in &chunks.changes {
+        let range = |bytes: Option<(usize, usize)>| bytes.map_or_else(String::new, |(s, e)| format!("{s}..{e}"));
+        match change.kind {
+            ChangeKind::Added => writeln!(
+                f,
+                "  + chunk {} bytes {}",
+                change.new_index.unwrap_or_default(),
+                range(change.new_bytes)
+            )?,
+            ChangeKind::Removed => writeln!(
+                f,
+                "  - chunk {} bytes {}",
+                change.old_index.unwrap_or_default(),
+                range(change.old_bytes)
+            )?,
+            kind => writeln!(
+                f,
+                "  {} chunk {} -> {} bytes {} -> {} (start {:+}, end {:+})",
+                if kind == ChangeKind::Equal { "moved" } else { "~" },
+                change.old_index.unwrap_or_default(),
+                change.new_index.unwrap_or_default(),
+                range(change.old_bytes),
+                range(change.new_bytes),
+                change.start_shift.unwrap_or_default(),
+                change.end_shift.unwrap_or_default()
+            )?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diff::diff_results;
+    use crate::types::ExtractionResult;
+
+    #[test]
+    fn test_text_report() {
+        let old = ExtractionResult {
+            content: "Heading\nPrice: 10 EUR\nEnd".to_string(),
+            ..Default::default()
+        };
+        let mut new = ExtractionResult {
+            content: "Heading\nPrice: 12 EUR\nEnd".to_string(),
+            ..Default::default()
+        };
+        new.metadata.title = Some("Report".to_string());
+
+        let report = diff_results(&old, &new).to_string();
+        assert!(report.contains("1 modified"), "{report}");
+        assert!(report.contains("@@ -1,3 +1,3 @@"), "{report}");
+        assert!(report.contains("~ Price: [-10-] {+12+} EUR"), "{report}");
+        assert!(report.contains("title: (none) -> \"Report\""), "{report}");
+
+        assert_eq!(diff_results(&old, &old).to_string(), "Results are identical\n");
+    }
+}
@@ -0,0 +1,301 @@
+//! Sequence alignment for result diffs.
+//!
+//! Uses patience diff: elements that occur exactly once on both sides anchor
+//! the alignment, and the gaps between anchors are aligned with a longest
+//! common subsequence table. Gaps too large for the table are reported as a
+//! deletion followed by an insertion.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+/// Largest gap (old × new elements) aligned with the LCS table.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// One step of an alignment, indexing into the old and new sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Op {
+    Equal { old: usize, new: usize },
+    Delete { old: usize },
+    Insert { new: usize },
+}
+
+/// An alignment step after pairing deletions with insertions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Aligned {
+    Equal { old: usize, new: usize },
+    Removed { old: usize },
+    Added { new: usize },
+    Modified { old: usize, new: usize },
+}
+
+/// Align `old` with `new`, returning the steps in order.
+pub(super) fn diff<T: Eq + Hash>(old: &[T], new: &[T]) -> Vec<Op> {
+    let mut ops = Vec::with_capacity(old.len().max(new.len()));
+    diff_range(old, new, 0..old.len(), 0..new.len(), &mut ops);
+    ops
+}
+
+/// Share of elements the two sides have in common (0.0-1.0).
+pub(super) fn similarity(ops: &[Op], old_len: usize, new_len: usize) -> f64 {
+    if old_len + new_len == 0 {
+        return 1.0;
+    }
+    let equal = ops.iter().filter(|op| matches!(op, Op::Equal { .. })).count();
+    (2 * equal) as f64 / (old_len + new_len) as f64
+}
+
+/// Pair the deletions and insertions of each changed run in order.
+///
+/// `should_pair` decides whether a deleted and an inserted element are two
+/// versions of the same thing; unpaired elements stay removed or added.
+pub(super) fn pair_changes(ops: &[Op], mut should_pair: impl FnMut(usize, usize) -> bool) -> Vec<Aligned> {
+    let mut aligned = Vec::with_capacity(ops.len());
+    let mut deleted = Vec::new();
+    let mut inserted = Vec::new();
+
+    let mut flush = |deleted: &mut Vec<usize>, inserted: &mut Vec<usize>, aligned: &mut Vec<Aligned>| {
+        for k in 0..deleted.len().max(inserted.len()) {
+            match (deleted.get(k), inserted.get(k)) {
+                (Some(&old), Some(&new)) if should_pair(old, new) => aligned.push(Aligned::Modified { old, new }),
+                (old, new) => {
+                    if let Some(&old) = old {
+                        aligned.push(Aligned::Removed { old });
+                    }
+                    if let Some(&new) = new {
+                        aligned.push(Aligned::Added { new });
+                    }
+                }
+            }
+        }
+        deleted.clear();
+        inserted.clear();
+    };
+
+    for op in ops {
+        match *op {
+            Op::Equal { old, new } => {
+                flush(&mut deleted, &mut inserted, &mut aligned);
+                aligned.push(Aligned::Equal { old, new });
+            }
+            Op::Delete { old } => deleted.push(old),
+            Op::Insert { new } => inserted.push(new),
+        }
+    }
+    flush(&mut deleted, &mut inserted, &mut aligned);
+    aligned
+}
+
+fn diff_range<T: Eq + Hash>(old: &[T], new: &[T], mut o: Range<usize>, mut n: Range<usize>, ops: &mut Vec<Op>) {
+    while o.start < o.end && n.start < n.end && old[o.start] == new[n.start] {
+        ops.push(Op::Equal {
+            old: o.start,
+            new: n.start,
+        });
+        o.start += 1;
+        n.start += 1;
+    }
+
+    let mut suffix = 0;
+    while o.end - suffix > o.start && n.end - suffix > n.start && old[o.end - suffix - 1] == new[n.end - suffix - 1] {
+        suffix += 1;
+    }
+    o.end -= suffix;
+    n.end -= suffix;
+
+    if o.is_empty() || n.is_empty() {
+        ops.extend(o.clone().map(|old| Op::Delete { old }));
+        ops.extend(n.clone().map(|new| Op::Insert { new }));
+    } else {
+        let anchors = unique_anchors(old, new, o.clone(), n.clone());
+        if anchors.is_empty() {
+            lcs_range(old, new, o.clone(), n.clone(), ops);
+        } else {
+            let (mut old_pos, mut new_pos) = (o.start, n.start);
+            for (anchor_old, anchor_new) in anchors {
+                diff_range(old, new, old_pos..anchor_old, new_pos..anchor_new, ops);
+                ops.push(Op::Equal {
+                    old: anchor_old,
+                    new: anchor_new,
+                });
+                old_pos = anchor_old + 1;
+                new_pos = anchor_new + 1;
+            }
+            diff_range(old, new, old_pos..o.end, new_pos..n.end, ops);
+        }
+    }
+
+    ops.extend((0..suffix).map(|k| Op::Equal {
+        old: o.end + k,
+        new: n.end + k,
+    }));
+}
+
+/// Elements unique on both sides, as the longest run that keeps their order.
+fn unique_anchors<T: Eq + Hash>(old: &[T], new: &[T], o: Range<usize>, n: Range<usize>) -> Vec<(usize, usize)> {
+    let mut counts: HashMap<&T, (usize, usize, usize, usize)> = HashMap::new();
+    for i in o {
+        let entry = counts.entry(&old[i]).or_insert((0, 0, i, 0));
+        entry.0 += 1;
+    }
+    for j in n {
+        if let Some(entry) = counts.get_mut(&new[j]) {
+            entry.1 += 1;
+            entry.3 = j;
+        }
+    }
+
+    let mut candidates: Vec<(usize, usize)> = counts
+        .into_values()
+        .filter(|&(old_count, new_count, _, _)| old_count == 1 && new_count == 1)
+        .map(|(_, _, i, j)| (i, j))
+        .collect();
+    candidates.sort_unstable();
+
+    longest_increasing_by_new(&candidates)
+}
+
+/// Longest subsequence of `pairs` (sorted by old index) with increasing new indices.
+fn longest_increasing_by_new(pairs: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous: Vec<Option<usize>> = vec![None; pairs.len()];
+
+    for (k, &(_, new)) in pairs.iter().enumerate() {
+        let position = tails.partition_point(|&t| pairs[t].1 < new);
+        if position > 0 {
+            previous[k] = Some(tails[position - 1]);
+        }
+        if position == tails.len() {
+            tails.push(k);
+        } else {
+            tails[position] = k;
+        }
+    }
+
+    let mut result = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(k) = current {
+        result.push(pairs[k]);
+        current = previous[k];
+    }
+    result.reverse();
+    result
+}
+
+fn lcs_range<T: Eq>(old: &[T], new: &[T], o: Range<usize>, n: Range<usize>, ops: &mut Vec<Op>) {
+    let (rows, cols) = (o.len(), n.len());
+    if rows.saturating_mul(cols) > MAX_LCS_CELLS {
+        ops.extend(o.map(|old| Op::Delete { old }));
+        ops.extend(n.map(|new| Op::Insert { new }));
+        return;
+    }
+
+    // table[i * (cols + 1) + j] = LCS length of old[o.start + i..] and new[n.start + j..]
+    let width = cols + 1;
+    let mut table = vec![0u32; (rows + 1) * width];
+    for i in (0..rows).rev() {
+        for j in (0..cols).rev() {
+            table[i * width + j] = if old[o.start + i] == new[n.start + j] {
+                table[(i + 1) * width + j + 1] + 1
+            } else {
+                table[(i + 1) * width + j].max(table[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < rows && j < cols {
+        if old[o.start + i] == new[n.start + j] {
+            ops.push(Op::Equal {
+                old: o.start + i,
+                new: n.start + j,
+            });
+            i += 1;
+            j += 1;
+        } else if table[(i + 1) * width + j] >= table[i * width + j + 1] {
+            ops.push(Op::Delete { old: o.start + i });
+            i += 1;
+        } else {
+            ops.push(Op::Insert { new: n.start + j });
+            j += 1;
+        }
+    }
+    ops.extend((o.start + i..o.end).map(|old| Op::Delete { old }));
+    ops.extend((n.start + j..n.end).map(|new| Op::Insert { new }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(old: &[&str], new: &[&str], ops: &[Op]) -> (Vec<String>, Vec<String>) {
+        let mut rebuilt_old = Vec::new();
+        let mut rebuilt_new = Vec::new();
+        for op in ops {
+            match *op {
+                Op::Equal { old: i, new: j } => {
+                    assert_eq!(old[i], new[j]);
+                    rebuilt_old.push(old[i].to_string());
+                    rebuilt_new.push(new[j].to_string());
+                }
+                Op::Delete { old: i } => rebuilt_old.push(old[i].to_string()),
+                Op::Insert { new: j } => rebuilt_new.push(new[j].to_string()),
+            }
+        }
+        (rebuilt_old, rebuilt_new)
+    }
+
+    #[test]
+    fn test_diff_covers_both_sequences_in_order() {
+        let old = ["a", "b", "c", "d", "e", "b"];
+        let new = ["a", "x", "c", "e", "b", "y"];
+        let ops = diff(&old, &new);
+        let (rebuilt_old, rebuilt_new) = apply(&old, &new, &ops);
+        assert_eq!(rebuilt_old, old);
+        assert_eq!(rebuilt_new, new);
+        let equal = ops.iter().filter(|op| matches!(op, Op::Equal { .. })).count();
+        assert_eq!(equal, 4);
+    }
+
+    #[test]
+    fn test_diff_identical_and_empty() {
+        let lines = ["one", "two"];
+        assert!(diff(&lines, &lines).iter().all(|op| matches!(op, Op::Equal { .. })));
+        assert_eq!(diff::<&str>(&[], &["x"]), vec![Op::Insert { new: 0 }]);
+        assert_eq!(similarity(&[], 0, 0), 1.0);
+    }
+
+    #[test]
+    fn test_similarity() {
+        let old = ["a", "b", "c", "d"];
+        let new = ["a", "b", "x", "d"];
+        let ops = diff(&old, &new);
+        assert!((similarity(&ops, old.len(), new.len()) - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pair_changes() {
+        let old = ["keep", "old one", "gone"];
+        let new = ["keep", "new one"];
+        let ops = diff(&old, &new);
+        let aligned = pair_changes(&ops, |_, _| true);
+        assert_eq!(
+            aligned,
+            vec![
+                Aligned::Equal { old: 0, new: 0 },
+                Aligned::Modified { old: 1, new: 1 },
+                Aligned::Removed { old: 2 },
+            ]
+        );
+        let unpaired = pair_changes(&ops, |_, _| false);
+        assert_eq!(
+            unpaired,
+            vec![
+                Aligned::Equal { old: 0, new: 0 },
+                Aligned::Removed { old: 1 },
+                Aligned::Added { new: 1 },
+                Aligned::Removed { old: 2 },
+            ]
+        );
+    }
+}
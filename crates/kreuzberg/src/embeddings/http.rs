@@ -0,0 +1,434 @@
+//! Embedding providers backed by remote HTTP APIs.
+//!
+//! Two wire formats are supported: the OpenAI `/embeddings` API, which most
+//! hosted and self-hosted embedding servers implement, and Ollama's `/api/embed`.
+//! Connection errors, timeouts, `429 Too Many Requests` and `5xx` responses are
+//! retried with exponential backoff, honouring `Retry-After` when present.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use super::EmbeddingProvider;
+use crate::error::{KreuzbergError, Result};
+
+/// Delay before the first retry, doubled after every further attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+
+/// Upper bound for a single retry delay, including `Retry-After`.
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+
+/// Wire format of a remote embedding API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpEmbeddingApi {
+    /// `POST {base_url}/embeddings`, answered with `{"data": [{"embedding": [...], "index": 0}]}`
+    OpenAi,
+    /// `POST {base_url}/api/embed`, answered with `{"embeddings": [[...]]}`
+    Ollama,
+}
+
+/// Embedding provider calling a remote HTTP API.
+///
+/// Each batch is sent as a single request with `{"model": ..., "input": [...]}`.
+pub struct HttpEmbeddingProvider {
+    /// Wire format of the API
+    pub api: HttpEmbeddingApi,
+    /// API base URL, without the endpoint path
+    pub base_url: String,
+    /// Model name sent with every request
+    pub model: String,
+    /// Bearer token sent in the `Authorization` header
+    pub api_key: Option<String>,
+    /// Expected vector size
+    pub dimensions: Option<usize>,
+    /// Retries after the first failed attempt
+    pub max_retries: u32,
+    /// Timeout of a single request
+    pub timeout: Duration,
+}
+
+#[derive(Deserialize)]
+struct OpenAiResponse {
+    data: Vec<OpenAiEmbedding>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiEmbedding {
+    embedding: Vec<f32>,
+    #[serde(default)]
+    index: usize,
+}
+
+#[derive(Deserialize)]
+struct OllamaResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
+impl HttpEmbeddingProvider {
+    fn endpoint(&self) -> String {
+        let base_url = self.base_url.trim_end_matches('/');
+        match self.api {
+            HttpEmbeddingApi::OpenAi => format!("{}/embeddings", base_url),
+            HttpEmbeddingApi::Ollama => format!("{}/api/embed", base_url),
+        }
+    }
+
+    fn request(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| plugin_error(format!("Failed to create HTTP client: {}", e)))?;
+        let url = self.endpoint();
+        let body = json!({ "model": self.model, "input": texts });
+
+        let mut backoff = INITIAL_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            let mut request = client.post(&url).json(&body);
+            if let Some(api_key) = &self.api_key {
+                request = request.bearer_auth(api_key);
+            }
+
+            let (error, retry_after) = match request.send() {
+                Ok(response) if response.status().is_success() => return self.parse(response),
+                Ok(response) => {
+                    let status = response.status();
+                    let retry_after = retry_after(&response);
+                    let detail = response.text().unwrap_or_default();
+                    let error = format!("{} returned {}: {}", url, status, detail.trim());
+                    if status != reqwest::StatusCode::TOO_MANY_REQUESTS && !status.is_server_error() {
+                        return Err(plugin_error(error));
+                    }
+                    (error, retry_after)
+                }
+                Err(e) => (format!("Request to {} failed: {}", url, e), None),
+            };
+
+            if attempt >= self.max_retries {
+                return Err(plugin_error(format!("{} (after {} attempts)", error, attempt + 1)));
+            }
+            attempt += 1;
+            let delay = retry_after.unwrap_or(backoff).min(MAX_BACKOFF);
+            tracing::debug!("Embedding request failed, retrying in {:?}: {}", delay, error);
+            std::thread::sleep(delay);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    fn parse(&self, response: reqwest::blocking::Response) -> Result<Vec<Vec<f32>>> {
+        let invalid = |e: reqwest::Error| plugin_error(format!("Invalid response from embedding API: {}", e));
+        match self.api {
+            HttpEmbeddingApi::OpenAi => {
+                let mut data = response.json::<OpenAiResponse>().map_err(invalid)?.data;
+                data.sort_by_key(|item| item.index);
+                Ok(data.into_iter().map(|item| item.embedding).collect())
+            }
+            HttpEmbeddingApi::Ollama => Ok(response.json::<OllamaResponse>().map_err(invalid)?.embeddings),
+        }
+    }
+}
+
+impl EmbeddingProvider for HttpEmbeddingProvider {
+    fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    fn dimensions(&self) -> Option<usize> {
+        self.dimensions
+    }
+
+    fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        // The blocking client panics when created or dropped on an async runtime thread,
+        // and embeddings are generated from both sync and async code.
+        std::thread::scope(|scope| scope.spawn(|| self.request(texts)).join())
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+}
+
+/// Read the API key from the environment variable `variable`, if one is configured.
+pub(super) fn api_key_from_env(variable: Option<&str>) -> Result<Option<String>> {
+    let Some(variable) = variable else {
+        return Ok(None);
+    };
+    std::env::var(variable).map(Some).map_err(|_| {
+        KreuzbergError::validation(format!(
+            "Environment variable '{}' with the embedding API key is not set",
+            variable
+        ))
+    })
+}
+
+fn retry_after(response: &reqwest::blocking::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+fn plugin_error(message: String) -> KreuzbergError {
+    KreuzbergError::Plugin {
+        message,
+        plugin_name: "embeddings".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{EmbeddingConfig, EmbeddingModelType};
+    use crate::embeddings::{embed_with_provider, embedding_provider, generate_embeddings_for_chunks};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    struct Recorded {
+        path: String,
+        authorization: Option<String>,
+        body: serde_json::Value,
+    }
+
+    /// Minimal HTTP server answering every request with `respond(request_number, body)`.
+    fn mock_server<F>(respond: F) -> (String, Arc<Mutex<Vec<Recorded>>>)
+    where
+        F: Fn(usize, &serde_json::Value) -> (u16, String) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let path = request_line.split_whitespace().nth(1).unwrap_or_default().to_string();
+
+                let (mut content_length, mut authorization) = (0, None);
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    let (name, value) = line.split_once(':').unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "content-length" => content_length = value.trim().parse().unwrap(),
+                        "authorization" => authorization = Some(value.trim().to_string()),
+                        _ => {}
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+                let number = {
+                    let mut requests = recorded.lock().unwrap();
+                    requests.push(Recorded {
+                        path,
+                        authorization,
+                        body: body.clone(),
+                    });
+                    requests.len() - 1
+                };
+                let (status, response) = respond(number, &body);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    response.len(),
+                    response
+                );
+            }
+        });
+
+        (url, requests)
+    }
+
+    /// Embed "t<i>" as `[i, 1]`, listing the items in reverse order.
+    fn openai_response(body: &serde_json::Value) -> String {
+        let data: Vec<serde_json::Value> = body["input"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, text)| {
+                let value: f32 = text.as_str().unwrap()[1..].parse().unwrap();
+                json!({ "object": "embedding", "index": index, "embedding": [value, 1.0] })
+            })
+            .collect();
+        json!({ "object": "list", "data": data }).to_string()
+    }
+
+    fn provider(api: HttpEmbeddingApi, base_url: String) -> HttpEmbeddingProvider {
+        HttpEmbeddingProvider {
+            api,
+            base_url,
+            model: "test-embed".to_string(),
+            api_key: None,
+            dimensions: None,
+            max_retries: 2,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    fn config(batch_size: usize) -> EmbeddingConfig {
+        EmbeddingConfig {
+            batch_size,
+            normalize: false,
+            ..Default::default()
+        }
+    }
+
+    fn texts(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("t{}", i)).collect()
+    }
+
+    #[test]
+    fn test_openai_batches_in_input_order() {
+        let (url, requests) = mock_server(|_, body| (200, openai_response(body)));
+        let mut provider = provider(HttpEmbeddingApi::OpenAi, format!("{}/v1/", url));
+        provider.api_key = Some("secret".to_string());
+
+        let embeddings = embed_with_provider(&provider, &texts(5), &config(2)).unwrap();
+
+        let expected: Vec<Vec<f32>> = (0..5).map(|i| vec![i as f32, 1.0]).collect();
+        assert_eq!(embeddings, expected);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].path, "/v1/embeddings");
+        assert_eq!(requests[0].authorization.as_deref(), Some("Bearer secret"));
+        assert_eq!(requests[0].body["model"], "test-embed");
+        assert_eq!(requests[2].body["input"], json!(["t4"]));
+    }
+
+    #[test]
+    fn test_ollama_format() {
+        let (url, requests) = mock_server(|_, body| {
+            let count = body["input"].as_array().unwrap().len();
+            (200, json!({ "embeddings": vec![[0.5, 0.5]; count] }).to_string())
+        });
+        let provider = provider(HttpEmbeddingApi::Ollama, url);
+
+        let embeddings = embed_with_provider(&provider, &texts(3), &config(32)).unwrap();
+
+        assert_eq!(embeddings.len(), 3);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/api/embed");
+        assert_eq!(requests[0].authorization, None);
+    }
+
+    #[test]
+    fn test_retries_rate_limits_and_server_errors() {
+        let (url, requests) = mock_server(|number, body| match number {
+            0 => (503, "overloaded".to_string()),
+            1 => (429, "slow down".to_string()),
+            _ => (200, openai_response(body)),
+        });
+        let provider = provider(HttpEmbeddingApi::OpenAi, url);
+
+        let embeddings = provider.embed_batch(&texts(2)).unwrap();
+
+        assert_eq!(embeddings, vec![vec![0.0, 1.0], vec![1.0, 1.0]]);
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_retries() {
+        let (url, requests) = mock_server(|_, _| (500, "broken".to_string()));
+        let mut provider = provider(HttpEmbeddingApi::OpenAi, url);
+        provider.max_retries = 1;
+
+        let error = provider.embed_batch(&texts(1)).unwrap_err();
+
+        assert!(matches!(error, KreuzbergError::Plugin { .. }));
+        assert!(error.to_string().contains("after 2 attempts"));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_client_errors_are_not_retried() {
+        let (url, requests) = mock_server(|_, _| (401, r#"{"error": "invalid key"}"#.to_string()));
+        let provider = provider(HttpEmbeddingApi::OpenAi, url);
+
+        let error = provider.embed_batch(&texts(1)).unwrap_err();
+
+        assert!(error.to_string().contains("401"));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_dimension_check() {
+        let (url, _) = mock_server(|_, body| (200, openai_response(body)));
+        let mut provider = provider(HttpEmbeddingApi::OpenAi, url);
+        provider.dimensions = Some(3);
+
+        let result = embed_with_provider(&provider, &texts(2), &config(32));
+
+        assert!(matches!(result, Err(KreuzbergError::Plugin { .. })));
+    }
+
+    #[test]
+    fn test_missing_api_key_variable() {
+        assert!(api_key_from_env(None).unwrap().is_none());
+        assert!(matches!(
+            api_key_from_env(Some("KREUZBERG_TEST_UNSET_EMBEDDING_KEY")),
+            Err(KreuzbergError::Validation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_chunk_embeddings_from_async_context() {
+        let (url, _) = mock_server(|_, body| (200, openai_response(body)));
+        let config = EmbeddingConfig {
+            model: EmbeddingModelType::OpenAi {
+                base_url: url,
+                model: "test-embed".to_string(),
+                api_key_env: None,
+                dimensions: Some(2),
+                max_retries: 0,
+                timeout_secs: 5,
+            },
+            ..Default::default()
+        };
+        assert_eq!(embedding_provider(&config).unwrap().model_name(), "test-embed");
+
+        let mut chunks: Vec<crate::types::Chunk> = texts(2)
+            .into_iter()
+            .enumerate()
+            .map(|(chunk_index, content)| crate::types::Chunk {
+                metadata: crate::types::ChunkMetadata {
+                    byte_start: 0,
+                    byte_end: content.len(),
+                    token_count: None,
+                    chunk_index,
+                    total_chunks: 2,
+                    first_page: None,
+                    last_page: None,
+                    heading_path: Vec::new(),
+                    element_types: Vec::new(),
+                    node_ids: Vec::new(),
+                    language: None,
+                    language_confidence: None,
+                },
+                content,
+                embedding: None,
+            })
+            .collect();
+        generate_embeddings_for_chunks(&mut chunks, &config).unwrap();
+
+        // [1, 1] normalized
+        let embedding = chunks[1].embedding.as_ref().unwrap();
+        assert!((embedding[0] - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+    }
+}
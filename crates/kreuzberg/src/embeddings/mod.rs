@@ -1,6 +1,7 @@
 //! Embedding generation support for RAG (Retrieval-Augmented Generation) systems.
 //!
-//! This module provides text embedding generation using ONNX models via fastembed-rs.
+//! This module provides text embedding generation using ONNX models via fastembed-rs,
+//! or remote OpenAI-compatible and Ollama embedding APIs.
 //! Embeddings can be generated for text chunks to enable semantic search and RAG pipelines.
 //!
 //! # Features
@@ -9,6 +10,7 @@
 //! - Preset configurations for common RAG scenarios
 //! - Full customization of model location and parameters
 //! - Batch processing for efficient embedding generation
+//! - Remote providers over HTTP with retries and dimension checks
 //! - Optional GPU acceleration via ONNX Runtime execution providers
 //!
 //! # ONNX Runtime Requirement
@@ -72,6 +74,12 @@ use std::mem::ManuallyDrop;
 #[cfg(feature = "embeddings")]
 use once_cell::sync::Lazy;
 
+#[cfg(feature = "embeddings")]
+mod http;
+
+#[cfg(feature = "embeddings")]
+pub use http::{HttpEmbeddingApi, HttpEmbeddingProvider};
+
 /// Wrapper for TextEmbedding that prevents cleanup during process shutdown.
 ///
 /// # Problem
@@ -398,16 +406,97 @@ pub fn generate_embeddings_for_chunks(
         return Ok(());
     }
 
+    let texts: Vec<String> = chunks.iter().map(|chunk| chunk.content.clone()).collect();
+    let embeddings = embed_texts(texts, config)?;
+
+    for (chunk, embedding) in chunks.iter_mut().zip(embeddings.into_iter()) {
+        chunk.embedding = Some(embedding);
+    }
+
+    Ok(())
+}
+
+/// Source of embedding vectors.
+///
+/// Implemented by the local ONNX models ([`LocalEmbeddingProvider`]) and by
+/// remote HTTP APIs ([`HttpEmbeddingProvider`]). Use [`embedding_provider`] to
+/// get the provider for an [`EmbeddingConfig`](crate::core::config::EmbeddingConfig).
+#[cfg(feature = "embeddings")]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Model name reported in results.
+    fn model_name(&self) -> &str;
+
+    /// Expected vector size, if known; vectors of any other size are rejected.
+    fn dimensions(&self) -> Option<usize> {
+        None
+    }
+
+    /// Embed one batch of texts, returning one vector per text in input order.
+    fn embed_batch(&self, texts: &[String]) -> crate::Result<Vec<Vec<f32>>>;
+}
+
+/// Local ONNX model run through fastembed.
+#[cfg(feature = "embeddings")]
+pub struct LocalEmbeddingProvider {
+    model: CachedEmbedding,
+    name: String,
+}
+
+#[cfg(feature = "embeddings")]
+impl LocalEmbeddingProvider {
+    /// Load (or reuse) a fastembed model, downloading it into `cache_dir` if needed.
+    pub fn new(model: EmbeddingModel, cache_dir: Option<std::path::PathBuf>) -> crate::Result<Self> {
+        let name = format!("{:?}", model);
+        Ok(Self {
+            model: get_or_init_model(model, cache_dir)?,
+            name,
+        })
+    }
+}
+
+#[cfg(feature = "embeddings")]
+impl EmbeddingProvider for LocalEmbeddingProvider {
+    fn model_name(&self) -> &str {
+        &self.name
+    }
+
+    fn embed_batch(&self, texts: &[String]) -> crate::Result<Vec<Vec<f32>>> {
+        let locked_model = self.model.lock().map_err(|e| crate::KreuzbergError::Plugin {
+            message: format!("Failed to acquire model lock: {}", e),
+            plugin_name: "embeddings".to_string(),
+        })?;
+
+        #[allow(unsafe_code)]
+        let model_mut = unsafe { locked_model.get_mut() };
+
+        model_mut
+            .embed(texts, Some(texts.len()))
+            .map_err(|e| crate::KreuzbergError::Plugin {
+                message: format!("Failed to generate embeddings: {}", e),
+                plugin_name: "embeddings".to_string(),
+            })
+    }
+}
+
+/// Create the embedding provider selected by `config.model`.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Plugin` for unknown presets or models and
+/// `KreuzbergError::Validation` if the API key variable of a remote provider is not set.
+#[cfg(feature = "embeddings")]
+pub fn embedding_provider(config: &crate::core::config::EmbeddingConfig) -> crate::Result<Box<dyn EmbeddingProvider>> {
+    use crate::core::config::EmbeddingModelType;
+
     let fastembed_model = match &config.model {
-        crate::core::config::EmbeddingModelType::Preset { name } => {
+        EmbeddingModelType::Preset { name } => {
             let preset = get_preset(name).ok_or_else(|| crate::KreuzbergError::Plugin {
                 message: format!("Unknown embedding preset: {}", name),
                 plugin_name: "embeddings".to_string(),
             })?;
             preset.model.clone()
         }
-        #[cfg(feature = "embeddings")]
-        crate::core::config::EmbeddingModelType::FastEmbed { model, .. } => match model.as_str() {
+        EmbeddingModelType::FastEmbed { model, .. } => match model.as_str() {
             "AllMiniLML6V2Q" => fastembed::EmbeddingModel::AllMiniLML6V2Q,
             "BGEBaseENV15" => fastembed::EmbeddingModel::BGEBaseENV15,
             "BGELargeENV15" => fastembed::EmbeddingModel::BGELargeENV15,
@@ -419,47 +508,117 @@ pub fn generate_embeddings_for_chunks(
                 });
             }
         },
-        crate::core::config::EmbeddingModelType::Custom { .. } => {
+        EmbeddingModelType::Custom { .. } => {
             return Err(crate::KreuzbergError::Plugin {
                 message: "Custom ONNX models are not yet supported for embedding generation".to_string(),
                 plugin_name: "embeddings".to_string(),
             });
         }
+        EmbeddingModelType::OpenAi {
+            base_url,
+            model,
+            api_key_env,
+            dimensions,
+            max_retries,
+            timeout_secs,
+        } => {
+            return Ok(Box::new(HttpEmbeddingProvider {
+                api: HttpEmbeddingApi::OpenAi,
+                base_url: base_url.clone(),
+                model: model.clone(),
+                api_key: http::api_key_from_env(api_key_env.as_deref())?,
+                dimensions: *dimensions,
+                max_retries: *max_retries,
+                timeout: std::time::Duration::from_secs(*timeout_secs),
+            }));
+        }
+        EmbeddingModelType::Ollama {
+            base_url,
+            model,
+            api_key_env,
+            dimensions,
+            max_retries,
+            timeout_secs,
+        } => {
+            return Ok(Box::new(HttpEmbeddingProvider {
+                api: HttpEmbeddingApi::Ollama,
+                base_url: base_url.clone(),
+                model: model.clone(),
+                api_key: http::api_key_from_env(api_key_env.as_deref())?,
+                dimensions: *dimensions,
+                max_retries: *max_retries,
+                timeout: std::time::Duration::from_secs(*timeout_secs),
+            }));
+        }
     };
 
-    let model = get_or_init_model(fastembed_model, config.cache_dir.clone())?;
-
-    let texts: Vec<String> = chunks.iter().map(|chunk| chunk.content.clone()).collect();
+    Ok(Box::new(LocalEmbeddingProvider::new(
+        fastembed_model,
+        config.cache_dir.clone(),
+    )?))
+}
 
-    let embeddings_result = {
-        let locked_model = model.lock().map_err(|e| crate::KreuzbergError::Plugin {
-            message: format!("Failed to acquire model lock: {}", e),
-            plugin_name: "embeddings".to_string(),
-        })?;
+/// Generate one embedding per input text, normalized if `config.normalize` is set.
+///
+/// Texts are sent to the provider in batches of `config.batch_size`. Shared by
+/// chunk embedding, the `/embed` endpoint and the semantic chunker.
+#[cfg(feature = "embeddings")]
+pub fn embed_texts(texts: Vec<String>, config: &crate::core::config::EmbeddingConfig) -> crate::Result<Vec<Vec<f32>>> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
 
-        #[allow(unsafe_code)]
-        let model_mut = unsafe { locked_model.get_mut() };
+    let provider = embedding_provider(config)?;
+    embed_with_provider(provider.as_ref(), &texts, config)
+}
 
-        model_mut
-            .embed(texts, Some(config.batch_size))
-            .map_err(|e| crate::KreuzbergError::Plugin {
-                message: format!("Failed to generate embeddings: {}", e),
+/// Embed `texts` with an explicit provider, batching and normalizing per `config`.
+#[cfg(feature = "embeddings")]
+pub fn embed_with_provider(
+    provider: &dyn EmbeddingProvider,
+    texts: &[String],
+    config: &crate::core::config::EmbeddingConfig,
+) -> crate::Result<Vec<Vec<f32>>> {
+    let mut embeddings = Vec::with_capacity(texts.len());
+    for batch in texts.chunks(config.batch_size.max(1)) {
+        let vectors = provider.embed_batch(batch)?;
+        if vectors.len() != batch.len() {
+            return Err(crate::KreuzbergError::Plugin {
+                message: format!(
+                    "Embedding model '{}' returned {} vectors for {} texts",
+                    provider.model_name(),
+                    vectors.len(),
+                    batch.len()
+                ),
                 plugin_name: "embeddings".to_string(),
-            })?
-    };
+            });
+        }
+        if let Some(expected) = provider.dimensions()
+            && let Some(vector) = vectors.iter().find(|vector| vector.len() != expected)
+        {
+            return Err(crate::KreuzbergError::Plugin {
+                message: format!(
+                    "Embedding model '{}' returned {}-dimensional vectors, expected {}",
+                    provider.model_name(),
+                    vector.len(),
+                    expected
+                ),
+                plugin_name: "embeddings".to_string(),
+            });
+        }
+        embeddings.extend(vectors);
+    }
 
-    for (chunk, mut embedding) in chunks.iter_mut().zip(embeddings_result.into_iter()) {
-        if config.normalize {
+    if config.normalize {
+        for embedding in &mut embeddings {
             let magnitude: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
             if magnitude > 0.0 {
                 embedding.iter_mut().for_each(|x| *x /= magnitude);
             }
         }
-
-        chunk.embedding = Some(embedding);
     }
 
-    Ok(())
+    Ok(embeddings)
 }
 
 #[cfg(test)]
@@ -7,7 +7,7 @@
 //! this module automatically detects the TAR format and delegates
 //! to the TAR extraction functions.
 
-use super::{ArchiveEntry, ArchiveMetadata};
+use super::{ArchiveEntry, ArchiveMember, ArchiveMetadata};
 use crate::error::{KreuzbergError, Result};
 use crate::extractors::security::SecurityLimits;
 use flate2::read::GzDecoder;
@@ -165,3 +165,30 @@ pub fn extract_gzip_text_content(bytes: &[u8], limits: &SecurityLimits) -> Resul
 
     Ok(contents)
 }
+
+/// Decompress a gzip file into members for recursive extraction.
+///
+/// A gzip-compressed TAR archive yields its TAR members; any other payload
+/// yields a single member named after the original filename from the gzip header.
+pub fn extract_gzip_members(bytes: &[u8], limits: &SecurityLimits) -> Result<Vec<ArchiveMember>> {
+    let decompressed = decompress_gzip_limited(bytes, limits.max_archive_size as u64)?;
+
+    if is_tar_archive(&decompressed) {
+        return super::tar::extract_tar_members(&decompressed, limits);
+    }
+
+    let mut decoder = GzDecoder::new(bytes);
+    let mut _discard = [0u8; 1];
+    let _ = decoder.read(&mut _discard);
+    let filename = decoder
+        .header()
+        .and_then(|h| h.filename())
+        .and_then(|f| std::str::from_utf8(f).ok())
+        .unwrap_or("compressed_content")
+        .to_string();
+
+    Ok(vec![ArchiveMember {
+        path: filename,
+        data: decompressed,
+    }])
+}
@@ -14,11 +14,15 @@ mod sevenz;
 mod tar;
 mod zip;
 
+use crate::error::{KreuzbergError, Result};
+use crate::extractors::security::SecurityLimits;
+use std::io::Read;
+
 // Re-export all public functions for backward compatibility
-pub use gzip::{decompress_gzip, extract_gzip, extract_gzip_metadata, extract_gzip_text_content};
-pub use sevenz::{extract_7z_metadata, extract_7z_text_content};
-pub use tar::{extract_tar_metadata, extract_tar_text_content};
-pub use zip::{extract_zip_metadata, extract_zip_text_content};
+pub use gzip::{decompress_gzip, extract_gzip, extract_gzip_members, extract_gzip_metadata, extract_gzip_text_content};
+pub use sevenz::{extract_7z_members, extract_7z_metadata, extract_7z_text_content};
+pub use tar::{extract_tar_members, extract_tar_metadata, extract_tar_text_content};
+pub use zip::{extract_zip_members, extract_zip_metadata, extract_zip_text_content};
 
 /// Archive metadata extracted from an archive file.
 #[derive(Debug, Clone)]
@@ -44,6 +48,53 @@ pub struct ArchiveEntry {
     pub is_dir: bool,
 }
 
+/// A file read from an archive for recursive extraction.
+#[derive(Debug, Clone)]
+pub struct ArchiveMember {
+    /// File path within the archive
+    pub path: String,
+    /// Uncompressed file contents
+    pub data: Vec<u8>,
+}
+
+/// Tracks uncompressed bytes read across members against `max_archive_size`.
+///
+/// Entry headers can lie about sizes, so the actual number of bytes read is
+/// what counts.
+pub(crate) struct MemberBudget {
+    format: &'static str,
+    remaining: u64,
+    max: usize,
+}
+
+impl MemberBudget {
+    pub(crate) fn new(format: &'static str, limits: &SecurityLimits) -> Self {
+        Self {
+            format,
+            remaining: limits.max_archive_size as u64,
+            max: limits.max_archive_size,
+        }
+    }
+
+    /// Read a member to the end, failing once the archive-wide budget is exhausted.
+    pub(crate) fn read(&mut self, reader: impl Read) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        reader
+            .take(self.remaining + 1)
+            .read_to_end(&mut data)
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read {} entry: {}", self.format, e)))?;
+
+        if data.len() as u64 > self.remaining {
+            return Err(KreuzbergError::validation(format!(
+                "{} archive total uncompressed size exceeds limit (max: {} bytes)",
+                self.format, self.max
+            )));
+        }
+        self.remaining -= data.len() as u64;
+        Ok(data)
+    }
+}
+
 /// Common text file extensions that should be extracted from archives.
 pub(crate) const TEXT_EXTENSIONS: &[&str] = &[
     ".txt", ".md", ".json", ".xml", ".html", ".csv", ".log", ".yaml", ".toml",
@@ -1000,4 +1051,96 @@ mod tests {
         assert_eq!(metadata.file_count, 1);
         assert_eq!(contents.get("combined.txt").unwrap(), "Combined test content");
     }
+
+    #[test]
+    fn test_extract_zip_members_reads_all_files() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.add_directory("docs/", options).unwrap();
+            zip.start_file("docs/report.pdf", options).unwrap();
+            zip.write_all(b"%PDF-1.7 binary").unwrap();
+            zip.start_file("notes.txt", options).unwrap();
+            zip.write_all(b"notes").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let members = extract_zip_members(&cursor.into_inner(), &default_limits()).unwrap();
+
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].path, "docs/report.pdf");
+        assert_eq!(members[0].data, b"%PDF-1.7 binary");
+        assert_eq!(members[1].path, "notes.txt");
+    }
+
+    #[test]
+    fn test_extract_tar_members_skips_directories() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+
+            let mut dir = ::tar::Header::new_gnu();
+            dir.set_path("dir/").unwrap();
+            dir.set_entry_type(::tar::EntryType::Directory);
+            dir.set_size(0);
+            dir.set_cksum();
+            tar.append(&dir, &[][..]).unwrap();
+
+            let data = b"spreadsheet";
+            let mut header = ::tar::Header::new_gnu();
+            header.set_path("dir/sheet.xlsx").unwrap();
+            header.set_size(data.len() as u64);
+            header.set_cksum();
+            tar.append(&header, &data[..]).unwrap();
+
+            tar.finish().unwrap();
+        }
+
+        let members = extract_tar_members(&cursor.into_inner(), &default_limits()).unwrap();
+
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].path, "dir/sheet.xlsx");
+        assert_eq!(members[0].data, b"spreadsheet");
+    }
+
+    #[test]
+    fn test_extract_members_enforces_size_limit() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+            zip.start_file("a.bin", options).unwrap();
+            zip.write_all(&[0u8; 64]).unwrap();
+            zip.start_file("b.bin", options).unwrap();
+            zip.write_all(&[0u8; 64]).unwrap();
+            zip.finish().unwrap();
+        }
+
+        let limits = SecurityLimits {
+            max_archive_size: 100,
+            ..Default::default()
+        };
+        let result = extract_zip_members(&cursor.into_inner(), &limits);
+
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_extract_gzip_members_single_stream() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"Hello from gzip!").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let members = extract_gzip_members(&compressed, &default_limits()).unwrap();
+
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].path, "compressed_content");
+        assert_eq!(members[0].data, b"Hello from gzip!");
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! Provides functions for extracting metadata and text content from 7Z archives.
 
-use super::{ArchiveEntry, ArchiveMetadata, TEXT_EXTENSIONS};
+use super::{ArchiveEntry, ArchiveMember, ArchiveMetadata, MemberBudget, TEXT_EXTENSIONS};
 use crate::error::{KreuzbergError, Result};
 use crate::extractors::security::SecurityLimits;
 use sevenz_rust2::{ArchiveReader, Password};
@@ -136,3 +136,57 @@ pub fn extract_7z_text_content(bytes: &[u8], limits: &SecurityLimits) -> Result<
 
     Ok(contents)
 }
+
+/// Read every file in a 7z archive for recursive extraction.
+///
+/// Directories are skipped. The total number of bytes read is checked against
+/// `max_archive_size` while reading.
+///
+/// # Errors
+///
+/// Returns an error if the 7z archive cannot be read or parsed,
+/// or if security limits are exceeded.
+pub fn extract_7z_members(bytes: &[u8], limits: &SecurityLimits) -> Result<Vec<ArchiveMember>> {
+    let cursor = Cursor::new(bytes);
+    let mut archive = ArchiveReader::new(cursor, Password::empty())
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read 7z archive: {}", e)))?;
+
+    let file_count = archive.archive().files.len();
+    if file_count > limits.max_files_in_archive {
+        return Err(KreuzbergError::validation(format!(
+            "7z archive has too many files: {} (max: {})",
+            file_count, limits.max_files_in_archive
+        )));
+    }
+
+    let mut budget = MemberBudget::new("7z", limits);
+    let mut members = Vec::with_capacity(file_count);
+    let mut budget_error = None;
+
+    archive
+        .for_each_entries(|entry, reader| {
+            if entry.is_directory() {
+                return Ok(true);
+            }
+            match budget.read(reader) {
+                Ok(data) => {
+                    members.push(ArchiveMember {
+                        path: entry.name().to_string(),
+                        data,
+                    });
+                    Ok(true)
+                }
+                Err(e) => {
+                    budget_error = Some(e);
+                    Ok(false)
+                }
+            }
+        })
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read 7z entries: {}", e)))?;
+
+    if let Some(e) = budget_error {
+        return Err(e);
+    }
+
+    Ok(members)
+}
@@ -3,7 +3,7 @@
 //! Provides functions for extracting metadata and text content from TAR archives.
 //! Supports plain TAR as well as compressed variants (TAR.GZ, TAR.BZ2).
 
-use super::{ArchiveEntry, ArchiveMetadata, TEXT_EXTENSIONS};
+use super::{ArchiveEntry, ArchiveMember, ArchiveMetadata, MemberBudget, TEXT_EXTENSIONS};
 use crate::error::{KreuzbergError, Result};
 use crate::extractors::security::SecurityLimits;
 use std::collections::HashMap;
@@ -151,3 +151,51 @@ pub fn extract_tar_text_content(bytes: &[u8], limits: &SecurityLimits) -> Result
 
     Ok(contents)
 }
+
+/// Read every regular file in a TAR archive for recursive extraction.
+///
+/// Directories, links and other special entries are skipped. The total number
+/// of bytes read is checked against `max_archive_size` while reading.
+///
+/// # Errors
+///
+/// Returns an error if the TAR archive cannot be read or parsed,
+/// or if security limits are exceeded.
+pub fn extract_tar_members(bytes: &[u8], limits: &SecurityLimits) -> Result<Vec<ArchiveMember>> {
+    let cursor = Cursor::new(bytes);
+    let mut archive = TarArchive::new(cursor);
+
+    let mut budget = MemberBudget::new("TAR", limits);
+    let mut members = Vec::new();
+    let mut file_count = 0usize;
+
+    let entries = archive
+        .entries()
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR archive: {}", e)))?;
+
+    for entry_result in entries {
+        let entry = entry_result.map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR entry: {}", e)))?;
+
+        file_count += 1;
+        if file_count > limits.max_files_in_archive {
+            return Err(KreuzbergError::validation(format!(
+                "TAR archive has too many files: {} (max: {})",
+                file_count, limits.max_files_in_archive
+            )));
+        }
+
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let path = entry
+            .path()
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR entry path: {}", e)))?
+            .to_string_lossy()
+            .to_string();
+        let data = budget.read(entry)?;
+        members.push(ArchiveMember { path, data });
+    }
+
+    Ok(members)
+}
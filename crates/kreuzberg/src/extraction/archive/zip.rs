@@ -2,7 +2,7 @@
 //!
 //! Provides functions for extracting metadata and text content from ZIP archives.
 
-use super::{ArchiveEntry, ArchiveMetadata, TEXT_EXTENSIONS};
+use super::{ArchiveEntry, ArchiveMember, ArchiveMetadata, MemberBudget, TEXT_EXTENSIONS};
 use crate::error::{KreuzbergError, Result};
 use crate::extractors::security::SecurityLimits;
 use std::collections::HashMap;
@@ -133,3 +133,45 @@ pub fn extract_zip_text_content(bytes: &[u8], limits: &SecurityLimits) -> Result
 
     Ok(contents)
 }
+
+/// Read every file in a ZIP archive for recursive extraction.
+///
+/// Directories are skipped. The total number of bytes read is checked against
+/// `max_archive_size` while reading, independent of the sizes in the entry headers.
+///
+/// # Errors
+///
+/// Returns an error if the ZIP archive cannot be read or parsed,
+/// or if security limits are exceeded.
+pub fn extract_zip_members(bytes: &[u8], limits: &SecurityLimits) -> Result<Vec<ArchiveMember>> {
+    let cursor = Cursor::new(bytes);
+    let mut archive =
+        ZipArchive::new(cursor).map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP archive: {}", e)))?;
+
+    if archive.len() > limits.max_files_in_archive {
+        return Err(KreuzbergError::validation(format!(
+            "ZIP archive has too many files: {} (max: {})",
+            archive.len(),
+            limits.max_files_in_archive
+        )));
+    }
+
+    let mut budget = MemberBudget::new("ZIP", limits);
+    let mut members = Vec::with_capacity(archive.len());
+
+    for i in 0..archive.len() {
+        let file = archive
+            .by_index(i)
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?;
+
+        if file.is_dir() {
+            continue;
+        }
+
+        let path = file.name().to_string();
+        let data = budget.read(file)?;
+        members.push(ArchiveMember { path, data });
+    }
+
+    Ok(members)
+}
@@ -15,6 +15,8 @@ use std::io::{Cursor, Read, Seek};
 use quick_xml::Reader;
 use quick_xml::events::{BytesStart, Event};
 
+use crate::core::config::RevisionMode;
+
 // --- Types ---
 
 /// Tracks document element ordering (paragraphs, tables, and drawings interleaved).
@@ -46,6 +48,8 @@ pub struct Document {
     pub drawings: Vec<super::drawing::Drawing>,
     /// Image relationships (rId → target path) for image extraction.
     pub image_relationships: HashMap<String, String>,
+    /// Reviewer comments parsed from `word/comments.xml`.
+    pub comments: Vec<Comment>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -54,6 +58,8 @@ pub struct Paragraph {
     pub style: Option<String>,
     pub numbering_id: Option<i64>,
     pub numbering_level: Option<i64>,
+    /// Comment range boundaries, in document order.
+    pub comment_marks: Vec<CommentMark>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -64,6 +70,48 @@ pub struct Run {
     pub underline: bool,
     pub strikethrough: bool,
     pub hyperlink_url: Option<String>,
+    /// Tracked change this run belongs to, if any.
+    pub revision: Option<Revision>,
+}
+
+/// Kind of tracked change (`w:ins`, `w:del`, `w:moveTo`, `w:moveFrom`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RevisionKind {
+    Insertion,
+    Deletion,
+    MoveTo,
+    MoveFrom,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Revision {
+    pub kind: RevisionKind,
+    pub author: Option<String>,
+    pub date: Option<String>,
+}
+
+impl Revision {
+    /// Whether the revision adds content (insertion or move destination).
+    pub fn is_insertion(&self) -> bool {
+        matches!(self.kind, RevisionKind::Insertion | RevisionKind::MoveTo)
+    }
+}
+
+/// Start or end of a comment's anchored range, located before run `run_index` of its paragraph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentMark {
+    pub id: String,
+    pub run_index: usize,
+    pub is_start: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Comment {
+    pub id: String,
+    pub author: Option<String>,
+    pub initials: Option<String>,
+    pub date: Option<String>,
+    pub paragraphs: Vec<Paragraph>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -158,6 +206,32 @@ fn get_val_attr_string(e: &BytesStart) -> Option<String> {
     None
 }
 
+/// Read an arbitrary attribute as String.
+fn get_attr_string(e: &BytesStart, key: &[u8]) -> Option<String> {
+    for attr in e.attributes().flatten() {
+        if attr.key.as_ref() == key {
+            return attr.unescape_value().ok().map(|v| v.into_owned());
+        }
+    }
+    None
+}
+
+/// Build a revision from a `<w:ins>`, `<w:del>`, `<w:moveTo>` or `<w:moveFrom>` element.
+fn revision_from_element(e: &BytesStart) -> Option<Revision> {
+    let kind = match e.name().as_ref() {
+        b"w:ins" => RevisionKind::Insertion,
+        b"w:del" => RevisionKind::Deletion,
+        b"w:moveTo" => RevisionKind::MoveTo,
+        b"w:moveFrom" => RevisionKind::MoveFrom,
+        _ => return None,
+    };
+    Some(Revision {
+        kind,
+        author: get_attr_string(e, b"w:author"),
+        date: get_attr_string(e, b"w:date"),
+    })
+}
+
 /// Map heading style name to markdown heading level (fallback for docs without styles.xml).
 fn heading_level_from_style_name(style: &str) -> Option<u8> {
     match style {
@@ -260,6 +334,88 @@ impl Document {
         text
     }
 
+    /// Resolve tracked changes in every part of the document.
+    ///
+    /// `Accept` and `Reject` drop the runs of the losing side and clear the
+    /// revision of the remaining ones; `Markup` keeps everything so that
+    /// rendering shows both sides.
+    pub fn apply_revision_mode(&mut self, mode: RevisionMode) {
+        if mode == RevisionMode::Markup {
+            return;
+        }
+        let mut tables: Vec<&mut Table> = self.tables.iter_mut().collect();
+        let mut paragraphs: Vec<&mut Paragraph> = self.paragraphs.iter_mut().collect();
+        for hf in self.headers.iter_mut().chain(self.footers.iter_mut()) {
+            tables.extend(hf.tables.iter_mut());
+            paragraphs.extend(hf.paragraphs.iter_mut());
+        }
+        let table_paragraphs = tables
+            .into_iter()
+            .flat_map(|t| t.rows.iter_mut())
+            .flat_map(|r| r.cells.iter_mut())
+            .flat_map(|c| c.paragraphs.iter_mut());
+        let paragraphs = paragraphs
+            .into_iter()
+            .chain(table_paragraphs)
+            .chain(self.footnotes.iter_mut().flat_map(|n| n.paragraphs.iter_mut()))
+            .chain(self.endnotes.iter_mut().flat_map(|n| n.paragraphs.iter_mut()))
+            .chain(self.comments.iter_mut().flat_map(|c| c.paragraphs.iter_mut()));
+        for paragraph in paragraphs {
+            paragraph.resolve_revisions(mode == RevisionMode::Accept);
+        }
+    }
+
+    /// Plain text each comment is anchored to, keyed by comment id.
+    ///
+    /// Walks the body in document order (table cells included); ranges that
+    /// span paragraphs are joined with newlines.
+    pub fn comment_anchors(&self) -> HashMap<String, String> {
+        let mut anchors: HashMap<String, String> = HashMap::new();
+        let mut open = Vec::new();
+        for paragraph in self.body_paragraphs() {
+            let (text, offsets) = paragraph.render_runs(Run::to_text);
+            for (id, start, end) in paragraph.comment_ranges(&offsets, &mut open) {
+                let slice = &text[start..end];
+                match anchors.entry(id) {
+                    std::collections::hash_map::Entry::Occupied(mut entry) => {
+                        entry.get_mut().push('\n');
+                        entry.get_mut().push_str(slice);
+                    }
+                    std::collections::hash_map::Entry::Vacant(entry) => {
+                        entry.insert(slice.to_string());
+                    }
+                }
+            }
+        }
+        for anchor in anchors.values_mut() {
+            let trimmed = anchor.trim();
+            if trimmed.len() != anchor.len() {
+                *anchor = trimmed.to_string();
+            }
+        }
+        anchors
+    }
+
+    /// Body paragraphs in document order, including those inside table cells.
+    pub fn body_paragraphs(&self) -> impl Iterator<Item = &Paragraph> {
+        self.elements
+            .iter()
+            .flat_map(|element| -> Box<dyn Iterator<Item = &Paragraph> + '_> {
+                match element {
+                    DocumentElement::Paragraph(idx) => Box::new(self.paragraphs.get(*idx).into_iter()),
+                    DocumentElement::Table(idx) => Box::new(
+                        self.tables
+                            .get(*idx)
+                            .into_iter()
+                            .flat_map(|t| t.rows.iter())
+                            .flat_map(|r| r.cells.iter())
+                            .flat_map(|c| c.paragraphs.iter()),
+                    ),
+                    DocumentElement::Drawing(_) => Box::new(std::iter::empty()),
+                }
+            })
+    }
+
     /// Render header/footer content as markdown text.
     fn header_footer_to_markdown(hf: &HeaderFooter) -> String {
         let mut parts = Vec::new();
@@ -437,7 +593,7 @@ impl Paragraph {
     pub fn to_text(&self) -> String {
         let mut text = String::new();
         for run in &self.runs {
-            text.push_str(&run.text);
+            text.push_str(&run.to_text());
         }
         text
     }
@@ -494,6 +650,65 @@ impl Paragraph {
     pub fn add_run(&mut self, run: Run) {
         self.runs.push(run);
     }
+
+    /// Render runs with `render`, returning the text and the byte offset at
+    /// which each run starts, followed by the end offset of the text.
+    pub fn render_runs(&self, render: impl Fn(&Run) -> String) -> (String, Vec<usize>) {
+        let mut text = String::new();
+        let mut offsets = Vec::with_capacity(self.runs.len() + 1);
+        for run in &self.runs {
+            offsets.push(text.len());
+            text.push_str(&render(run));
+        }
+        offsets.push(text.len());
+        (text, offsets)
+    }
+
+    /// Locate comment ranges in text rendered by [`Paragraph::render_runs`].
+    ///
+    /// `open` holds the ids of ranges that started in an earlier paragraph and
+    /// is updated in place. Ranges still open at the end of the paragraph
+    /// extend to its end. Returns `(id, start, end)` byte ranges.
+    pub fn comment_ranges(&self, offsets: &[usize], open: &mut Vec<String>) -> Vec<(String, usize, usize)> {
+        let end_of_text = offsets.last().copied().unwrap_or(0);
+        let mut started: Vec<(String, usize)> = open.drain(..).map(|id| (id, 0)).collect();
+        let mut ranges = Vec::new();
+
+        for mark in &self.comment_marks {
+            let offset = offsets.get(mark.run_index).copied().unwrap_or(end_of_text);
+            if mark.is_start {
+                started.push((mark.id.clone(), offset));
+            } else if let Some(pos) = started.iter().position(|(id, _)| *id == mark.id) {
+                let (id, start) = started.remove(pos);
+                ranges.push((id, start, offset));
+            }
+        }
+
+        for (id, start) in started {
+            ranges.push((id.clone(), start, end_of_text));
+            open.push(id);
+        }
+        ranges
+    }
+
+    /// Drop the runs of rejected changes and clear the revision of the rest.
+    fn resolve_revisions(&mut self, accept: bool) {
+        if self.runs.iter().all(|run| run.revision.is_none()) {
+            return;
+        }
+        // Number of kept runs before each original run index, for remapping comment marks.
+        let mut kept_before = Vec::with_capacity(self.runs.len() + 1);
+        for mut run in std::mem::take(&mut self.runs) {
+            kept_before.push(self.runs.len());
+            if run.revision.take().is_none_or(|rev| rev.is_insertion() == accept) {
+                self.runs.push(run);
+            }
+        }
+        kept_before.push(self.runs.len());
+        for mark in &mut self.comment_marks {
+            mark.run_index = kept_before[mark.run_index.min(kept_before.len() - 1)];
+        }
+    }
 }
 
 impl Run {
@@ -504,6 +719,25 @@ impl Run {
         }
     }
 
+    /// CriticMarkup delimiters for runs that are part of a tracked change.
+    fn revision_markers(&self) -> Option<(&'static str, &'static str)> {
+        self.revision.as_ref().map(|rev| {
+            if rev.is_insertion() {
+                ("{++", "++}")
+            } else {
+                ("{--", "--}")
+            }
+        })
+    }
+
+    /// Plain run text, wrapped in revision markers if the run is a tracked change.
+    pub fn to_text(&self) -> String {
+        match self.revision_markers() {
+            Some((open, close)) if !self.text.is_empty() => format!("{}{}{}", open, self.text, close),
+            _ => self.text.clone(),
+        }
+    }
+
     /// Render this run as markdown with formatting markers.
     pub fn to_markdown(&self) -> String {
         if self.text.is_empty() {
@@ -518,9 +752,14 @@ impl Run {
             0
         }) + (if self.strikethrough { 4 } else { 0 })
             + (if self.underline { 7 } else { 0 })
-            + self.hyperlink_url.as_ref().map_or(0, |u| u.len() + 4);
+            + self.hyperlink_url.as_ref().map_or(0, |u| u.len() + 4)
+            + if self.revision.is_some() { 6 } else { 0 };
         let mut out = String::with_capacity(self.text.len() + extra);
+        let markers = self.revision_markers();
 
+        if let Some((open, _)) = markers {
+            out.push_str(open);
+        }
         if self.hyperlink_url.is_some() {
             out.push('[');
         }
@@ -558,11 +797,26 @@ impl Run {
             out.push_str(url);
             out.push(')');
         }
+        if let Some((_, close)) = markers {
+            out.push_str(close);
+        }
 
         out
     }
 }
 
+impl Comment {
+    /// Comment text, one line per non-empty paragraph.
+    pub fn to_text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(|p| p.to_text())
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 impl Table {
     pub fn new() -> Self {
         Self::default()
@@ -861,6 +1115,10 @@ impl<R: Read + Seek> DocxParser<R> {
             self.parse_notes(&endnotes_xml, &mut document.endnotes, NoteType::Endnote)?;
         }
 
+        if let Ok(comments_xml) = self.read_file("word/comments.xml") {
+            document.comments = self.parse_comments(&comments_xml)?;
+        }
+
         document.style_catalog = self.styles.take();
         document.theme = self.theme.take();
         // Filter to only image relationships (exclude hyperlinks)
@@ -942,28 +1200,45 @@ impl<R: Read + Seek> DocxParser<R> {
         let mut current_run: Option<Run> = None;
         let mut in_text = false;
         let mut current_hyperlink_url: Option<String> = None;
+        let mut current_revision: Option<Revision> = None;
+        // Comment range markers seen outside a paragraph; attached to the start of the next one.
+        let mut pending_comment_marks: Vec<CommentMark> = Vec::new();
         let mut table_stack: Vec<TableContext> = Vec::new();
 
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) => match e.name().as_ref() {
                     b"w:p" => {
+                        let paragraph = Paragraph {
+                            comment_marks: std::mem::take(&mut pending_comment_marks),
+                            ..Default::default()
+                        };
                         if let Some(ctx) = table_stack.last_mut() {
-                            ctx.paragraph = Some(Paragraph::new());
+                            ctx.paragraph = Some(paragraph);
                         } else {
-                            current_paragraph = Some(Paragraph::new());
+                            current_paragraph = Some(paragraph);
                         }
                     }
                     b"w:r" => {
-                        let mut run = Run::default();
+                        let mut run = Run {
+                            revision: current_revision.clone(),
+                            ..Default::default()
+                        };
                         if let Some(ref url) = current_hyperlink_url {
                             run.hyperlink_url = Some(url.clone());
                         }
                         current_run = Some(run);
                     }
-                    b"w:t" => {
+                    b"w:t" | b"w:delText" => {
                         in_text = true;
                     }
+                    b"w:ins" | b"w:del" | b"w:moveTo" | b"w:moveFrom" => {
+                        current_revision = revision_from_element(e);
+                    }
+                    b"w:rPrChange" | b"w:pPrChange" => {
+                        // Formatting before the change; skip so it does not override the current properties.
+                        reader.read_to_end(e.name())?;
+                    }
                     b"w:tbl" => {
                         table_stack.push(TableContext::new());
                     }
@@ -1035,6 +1310,28 @@ impl<R: Read + Seek> DocxParser<R> {
                     b"w:pStyle" | b"w:ilvl" | b"w:numId" => {
                         apply_paragraph_property(e, &mut table_stack, &mut current_paragraph);
                     }
+                    b"w:commentRangeStart" | b"w:commentRangeEnd" => {
+                        if let Some(id) = get_attr_string(e, b"w:id") {
+                            let paragraph = if let Some(ctx) = table_stack.last_mut() {
+                                ctx.paragraph.as_mut()
+                            } else {
+                                current_paragraph.as_mut()
+                            };
+                            let is_start = e.name().as_ref() == b"w:commentRangeStart";
+                            match paragraph {
+                                Some(para) => para.comment_marks.push(CommentMark {
+                                    id,
+                                    run_index: para.runs.len(),
+                                    is_start,
+                                }),
+                                None => pending_comment_marks.push(CommentMark {
+                                    id,
+                                    run_index: 0,
+                                    is_start,
+                                }),
+                            }
+                        }
+                    }
                     b"w:footnoteReference" | b"w:endnoteReference" => {
                         // Insert inline footnote/endnote reference marker [^N]
                         if let Some(ref mut run) = current_run {
@@ -1087,9 +1384,12 @@ impl<R: Read + Seek> DocxParser<R> {
                     }
                 }
                 Ok(Event::End(ref e)) => match e.name().as_ref() {
-                    b"w:t" => {
+                    b"w:t" | b"w:delText" => {
                         in_text = false;
                     }
+                    b"w:ins" | b"w:del" | b"w:moveTo" | b"w:moveFrom" => {
+                        current_revision = None;
+                    }
                     b"w:r" => {
                         if let Some(run) = current_run.take() {
                             if let Some(ctx) = table_stack.last_mut() {
@@ -1314,14 +1614,21 @@ impl<R: Read + Seek> DocxParser<R> {
         let mut buf = Vec::new();
         let mut current_paragraph: Option<Paragraph> = None;
         let mut current_run: Option<Run> = None;
+        let mut current_revision: Option<Revision> = None;
         let mut in_text = false;
 
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) => match e.name().as_ref() {
                     b"w:p" => current_paragraph = Some(Paragraph::new()),
-                    b"w:r" => current_run = Some(Run::default()),
-                    b"w:t" => in_text = true,
+                    b"w:r" => {
+                        current_run = Some(Run {
+                            revision: current_revision.clone(),
+                            ..Default::default()
+                        })
+                    }
+                    b"w:t" | b"w:delText" => in_text = true,
+                    b"w:ins" | b"w:del" | b"w:moveTo" | b"w:moveFrom" => current_revision = revision_from_element(e),
                     b"w:b" => {
                         if let Some(ref mut run) = current_run {
                             run.bold = is_format_enabled(e);
@@ -1364,7 +1671,8 @@ impl<R: Read + Seek> DocxParser<R> {
                     }
                 }
                 Ok(Event::End(ref e)) => match e.name().as_ref() {
-                    b"w:t" => in_text = false,
+                    b"w:t" | b"w:delText" => in_text = false,
+                    b"w:ins" | b"w:del" | b"w:moveTo" | b"w:moveFrom" => current_revision = None,
                     b"w:r" => {
                         if let Some(run) = current_run.take()
                             && let Some(ref mut para) = current_paragraph
@@ -1396,6 +1704,7 @@ impl<R: Read + Seek> DocxParser<R> {
         let mut current_note: Option<Note> = None;
         let mut current_paragraph: Option<Paragraph> = None;
         let mut current_run: Option<Run> = None;
+        let mut current_revision: Option<Revision> = None;
         let mut in_text = false;
 
         loop {
@@ -1415,8 +1724,14 @@ impl<R: Read + Seek> DocxParser<R> {
                         });
                     }
                     b"w:p" => current_paragraph = Some(Paragraph::new()),
-                    b"w:r" => current_run = Some(Run::default()),
-                    b"w:t" => in_text = true,
+                    b"w:r" => {
+                        current_run = Some(Run {
+                            revision: current_revision.clone(),
+                            ..Default::default()
+                        })
+                    }
+                    b"w:t" | b"w:delText" => in_text = true,
+                    b"w:ins" | b"w:del" | b"w:moveTo" | b"w:moveFrom" => current_revision = revision_from_element(e),
                     b"w:b" => {
                         if let Some(ref mut run) = current_run {
                             run.bold = is_format_enabled(e);
@@ -1449,7 +1764,8 @@ impl<R: Read + Seek> DocxParser<R> {
                     }
                 }
                 Ok(Event::End(ref e)) => match e.name().as_ref() {
-                    b"w:t" => in_text = false,
+                    b"w:t" | b"w:delText" => in_text = false,
+                    b"w:ins" | b"w:del" | b"w:moveTo" | b"w:moveFrom" => current_revision = None,
                     b"w:r" => {
                         if let Some(run) = current_run.take()
                             && let Some(ref mut para) = current_paragraph
@@ -1484,6 +1800,89 @@ impl<R: Read + Seek> DocxParser<R> {
 
         Ok(())
     }
+
+    fn parse_comments(&self, xml: &str) -> Result<Vec<Comment>, DocxParseError> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(false);
+
+        let mut buf = Vec::new();
+        let mut comments = Vec::new();
+        let mut current_comment: Option<Comment> = None;
+        let mut current_paragraph: Option<Paragraph> = None;
+        let mut current_run: Option<Run> = None;
+        let mut current_revision: Option<Revision> = None;
+        let mut in_text = false;
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => match e.name().as_ref() {
+                    b"w:comment" => {
+                        current_comment = Some(Comment {
+                            id: get_attr_string(e, b"w:id").unwrap_or_default(),
+                            author: get_attr_string(e, b"w:author"),
+                            initials: get_attr_string(e, b"w:initials"),
+                            date: get_attr_string(e, b"w:date"),
+                            paragraphs: Vec::new(),
+                        });
+                    }
+                    b"w:p" => current_paragraph = Some(Paragraph::new()),
+                    b"w:r" => {
+                        current_run = Some(Run {
+                            revision: current_revision.clone(),
+                            ..Default::default()
+                        })
+                    }
+                    b"w:t" | b"w:delText" => in_text = true,
+                    b"w:ins" | b"w:del" | b"w:moveTo" | b"w:moveFrom" => current_revision = revision_from_element(e),
+                    b"w:b" | b"w:i" | b"w:u" | b"w:strike" | b"w:dstrike" => {
+                        apply_run_formatting(e, &mut current_run);
+                    }
+                    _ => {}
+                },
+                Ok(Event::Empty(ref e)) => {
+                    if let b"w:b" | b"w:i" | b"w:u" | b"w:strike" | b"w:dstrike" = e.name().as_ref() {
+                        apply_run_formatting(e, &mut current_run);
+                    }
+                }
+                Ok(Event::Text(e)) => {
+                    if in_text && let Some(ref mut run) = current_run {
+                        let text = e.decode()?;
+                        run.text.push_str(&text);
+                    }
+                }
+                Ok(Event::End(ref e)) => match e.name().as_ref() {
+                    b"w:t" | b"w:delText" => in_text = false,
+                    b"w:ins" | b"w:del" | b"w:moveTo" | b"w:moveFrom" => current_revision = None,
+                    b"w:r" => {
+                        if let Some(run) = current_run.take()
+                            && let Some(ref mut para) = current_paragraph
+                        {
+                            para.add_run(run);
+                        }
+                    }
+                    b"w:p" => {
+                        if let Some(para) = current_paragraph.take()
+                            && let Some(ref mut comment) = current_comment
+                        {
+                            comment.paragraphs.push(para);
+                        }
+                    }
+                    b"w:comment" => {
+                        if let Some(comment) = current_comment.take() {
+                            comments.push(comment);
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(Event::Eof) => break,
+                Err(e) => return Err(e.into()),
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(comments)
+    }
 }
 
 // --- Error ---
@@ -1516,13 +1915,22 @@ impl From<quick_xml::encoding::EncodingError> for DocxParseError {
 // --- Public API ---
 
 /// Parse a DOCX document from bytes and return the structured document.
+///
+/// Tracked changes are accepted; see [`parse_document_with_revisions`].
 pub fn parse_document(bytes: &[u8]) -> crate::error::Result<Document> {
+    parse_document_with_revisions(bytes, RevisionMode::Accept)
+}
+
+/// Parse a DOCX document from bytes, resolving tracked changes with `mode`.
+pub fn parse_document_with_revisions(bytes: &[u8], mode: RevisionMode) -> crate::error::Result<Document> {
     let cursor = Cursor::new(bytes);
     let parser = DocxParser::new(cursor)
         .map_err(|e| crate::error::KreuzbergError::parsing(format!("DOCX parsing failed: {}", e)))?;
-    parser
+    let mut document = parser
         .parse()
-        .map_err(|e| crate::error::KreuzbergError::parsing(format!("DOCX parsing failed: {}", e)))
+        .map_err(|e| crate::error::KreuzbergError::parsing(format!("DOCX parsing failed: {}", e)))?;
+    document.apply_revision_mode(mode);
+    Ok(document)
 }
 
 /// Extract text from DOCX bytes.
@@ -2405,4 +2813,156 @@ mod tests {
             "All rows should have same column count in markdown"
         );
     }
+
+    const TRACKED_CHANGES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p>
+      <w:r><w:t xml:space="preserve">The </w:t></w:r>
+      <w:ins w:id="1" w:author="Alice" w:date="2024-03-01T10:00:00Z"><w:r><w:t xml:space="preserve">quick </w:t></w:r></w:ins>
+      <w:del w:id="2" w:author="Bob" w:date="2024-03-02T10:00:00Z"><w:r><w:delText xml:space="preserve">slow </w:delText></w:r></w:del>
+      <w:r><w:t xml:space="preserve">fox</w:t></w:r>
+      <w:moveFrom w:id="3" w:author="Bob"><w:r><w:t xml:space="preserve"> jumps</w:t></w:r></w:moveFrom>
+      <w:r><w:t>.</w:t></w:r>
+    </w:p>
+  </w:body>
+</w:document>"#;
+
+    #[test]
+    fn test_tracked_changes_accept() {
+        let doc = parse_document(&create_test_docx(TRACKED_CHANGES_XML)).unwrap();
+        assert_eq!(doc.to_markdown(), "The quick fox.");
+        assert!(doc.paragraphs[0].runs.iter().all(|r| r.revision.is_none()));
+    }
+
+    #[test]
+    fn test_tracked_changes_reject() {
+        let bytes = create_test_docx(TRACKED_CHANGES_XML);
+        let doc = parse_document_with_revisions(&bytes, RevisionMode::Reject).unwrap();
+        assert_eq!(doc.to_markdown(), "The slow fox jumps.");
+    }
+
+    #[test]
+    fn test_tracked_changes_markup() {
+        let bytes = create_test_docx(TRACKED_CHANGES_XML);
+        let doc = parse_document_with_revisions(&bytes, RevisionMode::Markup).unwrap();
+        assert_eq!(doc.to_markdown(), "The {++quick ++}{--slow --}fox{-- jumps--}.");
+
+        let inserted = doc.paragraphs[0].runs[1].revision.as_ref().unwrap();
+        assert_eq!(inserted.kind, RevisionKind::Insertion);
+        assert_eq!(inserted.author.as_deref(), Some("Alice"));
+        assert_eq!(inserted.date.as_deref(), Some("2024-03-01T10:00:00Z"));
+        assert_eq!(
+            doc.paragraphs[0].runs[4].revision.as_ref().unwrap().kind,
+            RevisionKind::MoveFrom
+        );
+    }
+
+    #[test]
+    fn test_property_changes_do_not_override_formatting() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p>
+      <w:pPr><w:pStyle w:val="Heading1"/><w:pPrChange w:id="1" w:author="A"><w:pPr><w:pStyle w:val="Normal"/></w:pPr></w:pPrChange></w:pPr>
+      <w:r><w:rPr><w:i/><w:rPrChange w:id="2" w:author="A"><w:rPr><w:b/></w:rPr></w:rPrChange></w:rPr><w:t>Title</w:t></w:r>
+    </w:p>
+  </w:body>
+</w:document>"#;
+        let doc = parse_document(&create_test_docx(xml)).unwrap();
+        let para = &doc.paragraphs[0];
+        assert_eq!(para.style.as_deref(), Some("Heading1"));
+        assert!(para.runs[0].italic);
+        assert!(!para.runs[0].bold);
+    }
+
+    fn create_test_docx_with_comments(document_xml: &str, comments_xml: &str) -> Vec<u8> {
+        use std::io::{Cursor, Write};
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        zip.start_file("word/document.xml", options).unwrap();
+        zip.write_all(document_xml.as_bytes()).unwrap();
+        zip.start_file("word/comments.xml", options).unwrap();
+        zip.write_all(comments_xml.as_bytes()).unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    const COMMENTS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:comments xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:comment w:id="0" w:author="Carol" w:date="2024-04-01T09:30:00Z" w:initials="CR">
+    <w:p><w:r><w:t>Define this term.</w:t></w:r></w:p>
+  </w:comment>
+  <w:comment w:id="1" w:author="Dan">
+    <w:p><w:r><w:t>Spans two paragraphs.</w:t></w:r></w:p>
+    <w:p><w:r><w:t>Second line.</w:t></w:r></w:p>
+  </w:comment>
+</w:comments>"#;
+
+    #[test]
+    fn test_comments_parsed_with_anchor_ranges() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p>
+      <w:r><w:t xml:space="preserve">The </w:t></w:r>
+      <w:commentRangeStart w:id="0"/>
+      <w:r><w:t>Licensee</w:t></w:r>
+      <w:commentRangeEnd w:id="0"/>
+      <w:r><w:commentReference w:id="0"/></w:r>
+      <w:r><w:t xml:space="preserve"> agrees.</w:t></w:r>
+      <w:commentRangeStart w:id="1"/>
+      <w:r><w:t xml:space="preserve"> First</w:t></w:r>
+    </w:p>
+    <w:p>
+      <w:r><w:t>Second</w:t></w:r>
+      <w:commentRangeEnd w:id="1"/>
+      <w:r><w:t xml:space="preserve"> outside</w:t></w:r>
+    </w:p>
+  </w:body>
+</w:document>"#;
+        let doc = parse_document(&create_test_docx_with_comments(xml, COMMENTS_XML)).unwrap();
+
+        assert_eq!(doc.comments.len(), 2);
+        let first = &doc.comments[0];
+        assert_eq!(first.id, "0");
+        assert_eq!(first.author.as_deref(), Some("Carol"));
+        assert_eq!(first.initials.as_deref(), Some("CR"));
+        assert_eq!(first.date.as_deref(), Some("2024-04-01T09:30:00Z"));
+        assert_eq!(first.to_text(), "Define this term.");
+        assert_eq!(doc.comments[1].to_text(), "Spans two paragraphs.\nSecond line.");
+
+        let anchors = doc.comment_anchors();
+        assert_eq!(anchors.get("0").map(String::as_str), Some("Licensee"));
+        assert_eq!(anchors.get("1").map(String::as_str), Some("First\nSecond"));
+    }
+
+    #[test]
+    fn test_comment_marks_follow_resolved_revisions() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:commentRangeStart w:id="0"/>
+    <w:p>
+      <w:del w:id="5" w:author="Bob"><w:r><w:delText xml:space="preserve">old </w:delText></w:r></w:del>
+      <w:r><w:t>term</w:t></w:r>
+      <w:commentRangeEnd w:id="0"/>
+      <w:r><w:t xml:space="preserve"> rest</w:t></w:r>
+    </w:p>
+  </w:body>
+</w:document>"#;
+        let bytes = create_test_docx_with_comments(xml, COMMENTS_XML);
+
+        let accepted = parse_document(&bytes).unwrap();
+        assert_eq!(accepted.comment_anchors().get("0").map(String::as_str), Some("term"));
+
+        let rejected = parse_document_with_revisions(&bytes, RevisionMode::Reject).unwrap();
+        assert_eq!(
+            rejected.comment_anchors().get("0").map(String::as_str),
+            Some("old term")
+        );
+
+        let markup = parse_document_with_revisions(&bytes, RevisionMode::Markup).unwrap();
+        assert_eq!(
+            markup.comment_anchors().get("0").map(String::as_str),
+            Some("{--old --}term")
+        );
+    }
 }
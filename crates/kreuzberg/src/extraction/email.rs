@@ -8,7 +8,9 @@
 //! - **EML support**: RFC822 format parsing
 //! - **HTML to text**: Strips HTML tags from HTML email bodies
 //! - **Metadata extraction**: Sender, recipients, subject, message ID
-//! - **Attachment list**: Names of all attachments (content not extracted)
+//! - **Attachment list**: Names of all attachments
+//! - **Attachment extraction**: Optionally, attachment contents via the extractor
+//!   registry ([`extract_email_attachments`]), including attached messages
 //!
 //! # Example
 //!
@@ -26,7 +28,10 @@
 //! ```
 use bytes::Bytes;
 
+use crate::core::config::ExtractionConfig;
+use crate::core::mime::{detect_mime_type, get_extensions_for_mime};
 use crate::error::{KreuzbergError, Result};
+use crate::extraction::embedded::{EmbeddedDocument, extract_embedded_documents};
 use crate::types::{EmailAttachment, EmailExtractionResult};
 use mail_parser::MimeHeaders;
 use regex::Regex;
@@ -150,6 +155,14 @@ pub fn parse_eml_content(data: &[u8]) -> Result<EmailExtractionResult> {
 
     let message_id = message.message_id().map(|id| id.to_string());
 
+    let in_reply_to = message.in_reply_to().as_text().map(|id| id.to_string());
+
+    let references: Vec<String> = message
+        .references()
+        .as_text_list()
+        .map(|ids| ids.iter().map(|id| id.to_string()).collect())
+        .unwrap_or_default();
+
     let plain_text = message.body_text(0).map(|s| s.to_string());
 
     let html_content = message.body_html(0).map(|s| s.to_string());
@@ -208,11 +221,14 @@ pub fn parse_eml_content(data: &[u8]) -> Result<EmailExtractionResult> {
         bcc_emails,
         date,
         message_id,
+        in_reply_to,
+        references,
         plain_text,
         html_content,
         cleaned_text,
         attachments,
         metadata,
+        children: None,
     })
 }
 
@@ -311,6 +327,11 @@ fn extract_msg_from_cfb<F: std::io::Read + std::io::Seek>(
     let message_id = read_msg_string_prop(comp, "", 0x1035) // PR_INTERNET_MESSAGE_ID
         .filter(|s| !s.is_empty());
     let headers = read_msg_string_prop(comp, "", 0x007D); // PR_TRANSPORT_MESSAGE_HEADERS
+    let in_reply_to = read_msg_string_prop(comp, "", 0x1042) // PR_IN_REPLY_TO_ID
+        .filter(|s| !s.is_empty());
+    let references: Vec<String> = read_msg_string_prop(comp, "", 0x1039) // PR_INTERNET_REFERENCES
+        .map(|refs| split_message_ids(&refs))
+        .unwrap_or_default();
 
     // Parse date from transport headers (e.g. "Date: Mon, 1 Jan 2024 …").
     let date = headers.as_ref().and_then(|h| {
@@ -336,8 +357,9 @@ fn extract_msg_from_cfb<F: std::io::Read + std::io::Seek>(
 
     // --- attachment storages -----------------------------------------------
 
+    // Only direct children: attachments of embedded messages belong to those messages.
     let attach_paths: Vec<String> = comp
-        .walk()
+        .read_root_storage()
         .filter(|e| e.is_storage() && e.name().starts_with("__attach_"))
         .map(|e| e.path().to_string_lossy().into_owned())
         .collect();
@@ -355,15 +377,26 @@ fn extract_msg_from_cfb<F: std::io::Read + std::io::Seek>(
             .or_else(|| display_name.clone())
             .or_else(|| extension.map(|ext| format!("attachment{ext}")));
 
-        // Read binary attachment data directly — no hex encoding.
+        // Read binary attachment data directly — no hex encoding. Attached
+        // messages are stored as a sub-storage instead (PR_ATTACH_DATA_OBJ)
+        // and are copied out into a standalone MSG file.
         let bin_path = format!("{path}/__substg1.0_37010102");
-        let binary_data = read_msg_stream(comp, &bin_path);
+        let object_path = format!("{path}/__substg1.0_3701000D");
+        let (binary_data, is_embedded_message) = match read_msg_stream(comp, &bin_path) {
+            Some(data) => (Some(data), false),
+            None if comp.is_storage(&object_path) => (copy_msg_storage(comp, &object_path), true),
+            None => (None, false),
+        };
         let size = binary_data.as_ref().map(Vec::len);
         let att_data = binary_data.map(Bytes::from);
 
-        let mime_type = mime_tag
-            .filter(|s| !s.is_empty())
-            .or_else(|| Some("application/octet-stream".to_string()));
+        let mime_type = if is_embedded_message {
+            Some("application/vnd.ms-outlook".to_string())
+        } else {
+            mime_tag
+                .filter(|s| !s.is_empty())
+                .or_else(|| Some("application/octet-stream".to_string()))
+        };
         let is_image = mime_type.as_ref().map(|m| is_image_mime_type(m)).unwrap_or(false);
 
         attachments.push(EmailAttachment {
@@ -414,11 +447,14 @@ fn extract_msg_from_cfb<F: std::io::Read + std::io::Seek>(
         bcc_emails,
         date,
         message_id,
+        in_reply_to,
+        references,
         plain_text,
         html_content,
         cleaned_text,
         attachments,
         metadata,
+        children: None,
     })
 }
 
@@ -433,6 +469,43 @@ fn read_msg_stream<F: std::io::Read + std::io::Seek>(comp: &mut cfb::CompoundFil
     if buf.is_empty() { None } else { Some(buf) }
 }
 
+/// Copy a storage subtree into a new compound file.
+///
+/// Used for embedded message attachments, whose storage has the same layout
+/// as a top-level MSG file.
+fn copy_msg_storage<F: std::io::Read + std::io::Seek>(
+    comp: &mut cfb::CompoundFile<F>,
+    storage: &str,
+) -> Option<Vec<u8>> {
+    use std::io::{Cursor, Read, Write};
+    use std::path::{Path, PathBuf};
+
+    let entries: Vec<(PathBuf, bool)> = comp
+        .walk_storage(storage)
+        .ok()?
+        .map(|e| (e.path().to_path_buf(), e.is_stream()))
+        .collect();
+
+    let mut copy = cfb::CompoundFile::create(Cursor::new(Vec::new())).ok()?;
+    for (path, is_stream) in entries {
+        let relative = path.strip_prefix(storage).ok()?;
+        if relative.as_os_str().is_empty() {
+            continue;
+        }
+        let target = Path::new("/").join(relative);
+        if is_stream {
+            let mut buf = Vec::new();
+            comp.open_stream(&path).ok()?.read_to_end(&mut buf).ok()?;
+            copy.create_stream(&target).ok()?.write_all(&buf).ok()?;
+        } else {
+            copy.create_storage(&target).ok()?;
+        }
+    }
+    copy.flush().ok()?;
+
+    Some(copy.into_inner().into_inner())
+}
+
 /// Read a MAPI string property (tries PT_UNICODE then PT_STRING8).
 fn read_msg_string_prop<F: std::io::Read + std::io::Seek>(
     comp: &mut cfb::CompoundFile<F>,
@@ -455,6 +528,14 @@ fn decode_utf16le_bytes(data: &[u8]) -> String {
     String::from_utf16_lossy(&u16s).trim_end_matches('\0').to_string()
 }
 
+/// Split a whitespace/comma-separated list of Message-IDs (References header).
+fn split_message_ids(ids: &str) -> Vec<String> {
+    ids.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 /// Split semicolon/comma-separated display addresses into individual strings.
 fn split_display_addresses(display: &Option<String>) -> Vec<String> {
     display
@@ -482,6 +563,59 @@ pub fn extract_email_content(data: &[u8], mime_type: &str) -> Result<EmailExtrac
     }
 }
 
+/// Extract the contents of all attachments through the extractor registry.
+///
+/// Every attachment that carries data is MIME-detected and extracted with
+/// [`extract_embedded_documents`], and the results are stored in
+/// `result.children` in attachment order. Attached messages (.eml/.msg) are
+/// extracted with one level less of `EmailConfig::max_attachment_depth`, so
+/// their own attachments are only expanded while depth remains.
+pub async fn extract_email_attachments(result: &mut EmailExtractionResult, config: &ExtractionConfig) {
+    let documents = result
+        .attachments
+        .iter()
+        .enumerate()
+        .filter_map(|(index, att)| {
+            att.data.as_ref().map(|data| EmbeddedDocument {
+                path: attachment_path(att, index),
+                data: data.to_vec(),
+            })
+        })
+        .collect();
+
+    let mut child_config = config.clone();
+    let mut email_options = config.email_options.clone().unwrap_or_default();
+    email_options.max_attachment_depth = email_options.max_attachment_depth.saturating_sub(1);
+    child_config.email_options = Some(email_options);
+
+    result.children = Some(extract_embedded_documents(documents, &child_config).await);
+}
+
+/// Name under which an attachment is extracted.
+///
+/// Attachments without a recognizable extension get one derived from their
+/// declared MIME type, so that MIME detection does not have to guess from the
+/// bytes (attached messages in particular have no magic number).
+fn attachment_path(attachment: &EmailAttachment, index: usize) -> String {
+    let name = attachment
+        .filename
+        .clone()
+        .or_else(|| attachment.name.clone())
+        .unwrap_or_else(|| format!("attachment-{}", index + 1));
+
+    if detect_mime_type(&name, false).is_ok() {
+        return name;
+    }
+
+    attachment
+        .mime_type
+        .as_deref()
+        .and_then(|mime_type| get_extensions_for_mime(mime_type).ok())
+        .and_then(|extensions| extensions.into_iter().next())
+        .map(|ext| format!("{name}.{ext}"))
+        .unwrap_or(name)
+}
+
 /// Build text output from email extraction result
 pub fn build_email_text_output(result: &EmailExtractionResult) -> String {
     let mut text_parts = Vec::with_capacity(10);
@@ -524,6 +658,20 @@ pub fn build_email_text_output(result: &EmailExtractionResult) -> String {
         }
     }
 
+    let mut contents = result
+        .children
+        .iter()
+        .flatten()
+        .filter_map(|child| child.result.as_ref().map(|r| (&child.path, &r.content)))
+        .peekable();
+    if contents.peek().is_some() {
+        let mut section = String::from("\nAttachment Contents:\n");
+        for (path, content) in contents {
+            section.push_str(&format!("\n=== {} ===\n{}\n", path, content));
+        }
+        text_parts.push(section);
+    }
+
     text_parts.join("\n")
 }
 
@@ -612,6 +760,7 @@ fn build_metadata(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ChildResult;
 
     #[test]
     fn test_clean_html_content() {
@@ -709,11 +858,14 @@ mod tests {
             bcc_emails: vec![],
             date: None,
             message_id: None,
+            in_reply_to: None,
+            references: vec![],
             plain_text: None,
             html_content: None,
             cleaned_text: "Hello World".to_string(),
             attachments: vec![],
             metadata: HashMap::new(),
+            children: None,
         };
 
         let output = build_email_text_output(&result);
@@ -733,6 +885,8 @@ mod tests {
             bcc_emails: vec![],
             date: None,
             message_id: None,
+            in_reply_to: None,
+            references: vec![],
             plain_text: None,
             html_content: None,
             cleaned_text: "Hello World".to_string(),
@@ -745,6 +899,7 @@ mod tests {
                 data: None,
             }],
             metadata: HashMap::new(),
+            children: None,
         };
 
         let output = build_email_text_output(&result);
@@ -903,11 +1058,14 @@ mod tests {
             bcc_emails: vec!["bcc@example.com".to_string()],
             date: Some("2024-01-01T12:00:00Z".to_string()),
             message_id: Some("<msg123@example.com>".to_string()),
+            in_reply_to: None,
+            references: vec![],
             plain_text: Some("Plain text body".to_string()),
             html_content: Some("<html><body>HTML body</body></html>".to_string()),
             cleaned_text: "Cleaned body text".to_string(),
             attachments: vec![],
             metadata: HashMap::new(),
+            children: None,
         };
 
         let output = build_email_text_output(&result);
@@ -930,6 +1088,8 @@ mod tests {
             bcc_emails: vec![],
             date: None,
             message_id: None,
+            in_reply_to: None,
+            references: vec![],
             plain_text: None,
             html_content: None,
             cleaned_text: "Body".to_string(),
@@ -942,6 +1102,7 @@ mod tests {
                 data: None,
             }],
             metadata: HashMap::new(),
+            children: None,
         };
 
         let output = build_email_text_output(&result);
@@ -1012,4 +1173,113 @@ mod tests {
         let _ = style_regex();
         let _ = whitespace_regex();
     }
+
+    fn utf16le(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(|unit| unit.to_le_bytes()).collect()
+    }
+
+    /// Minimal MSG file with one attached message that carries a text attachment.
+    fn msg_with_embedded_message() -> Vec<u8> {
+        use std::io::{Cursor, Write};
+
+        let mut comp = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        let write = |comp: &mut cfb::CompoundFile<Cursor<Vec<u8>>>, path: &str, data: &[u8]| {
+            comp.create_stream(path).unwrap().write_all(data).unwrap();
+        };
+
+        write(&mut comp, "/__substg1.0_0037001F", &utf16le("Outer"));
+        write(&mut comp, "/__substg1.0_1000001F", &utf16le("Outer body"));
+
+        let attach = "/__attach_version1.0_#00000000";
+        let inner = format!("{attach}/__substg1.0_3701000D");
+        comp.create_storage(attach).unwrap();
+        write(&mut comp, &format!("{attach}/__substg1.0_3001001F"), &utf16le("Inner"));
+        comp.create_storage(&inner).unwrap();
+        write(&mut comp, &format!("{inner}/__substg1.0_0037001F"), &utf16le("Inner"));
+        write(
+            &mut comp,
+            &format!("{inner}/__substg1.0_1000001F"),
+            &utf16le("Inner body"),
+        );
+
+        let inner_attach = format!("{inner}/__attach_version1.0_#00000000");
+        comp.create_storage(&inner_attach).unwrap();
+        write(
+            &mut comp,
+            &format!("{inner_attach}/__substg1.0_3707001F"),
+            &utf16le("notes.txt"),
+        );
+        write(
+            &mut comp,
+            &format!("{inner_attach}/__substg1.0_37010102"),
+            b"inner notes",
+        );
+
+        comp.flush().unwrap();
+        comp.into_inner().into_inner()
+    }
+
+    #[test]
+    fn test_msg_embedded_message_is_copied_out() {
+        let result = parse_msg_content(&msg_with_embedded_message()).unwrap();
+        assert_eq!(result.subject.as_deref(), Some("Outer"));
+        assert_eq!(result.attachments.len(), 1);
+
+        let attachment = &result.attachments[0];
+        assert_eq!(attachment.name.as_deref(), Some("Inner"));
+        assert_eq!(attachment.mime_type.as_deref(), Some("application/vnd.ms-outlook"));
+
+        let inner = parse_msg_content(attachment.data.as_ref().unwrap()).unwrap();
+        assert_eq!(inner.subject.as_deref(), Some("Inner"));
+        assert_eq!(inner.cleaned_text, "Inner body");
+        assert_eq!(inner.attachments.len(), 1);
+        assert_eq!(inner.attachments[0].filename.as_deref(), Some("notes.txt"));
+    }
+
+    #[test]
+    fn test_attachment_path() {
+        let attachment = |filename: Option<&str>, mime_type: &str| EmailAttachment {
+            name: None,
+            filename: filename.map(str::to_string),
+            mime_type: Some(mime_type.to_string()),
+            size: None,
+            is_image: false,
+            data: None,
+        };
+
+        assert_eq!(
+            attachment_path(&attachment(Some("report.pdf"), "application/pdf"), 0),
+            "report.pdf"
+        );
+        assert_eq!(
+            attachment_path(&attachment(Some("Forwarded"), "message/rfc822"), 0),
+            "Forwarded.eml"
+        );
+        assert_eq!(attachment_path(&attachment(None, "text/csv"), 1), "attachment-2.csv");
+    }
+
+    #[test]
+    fn test_build_email_text_output_with_attachment_contents() {
+        let mut result = parse_eml_content(b"Subject: Test\r\n\r\nBody").unwrap();
+        result.children = Some(vec![
+            ChildResult {
+                path: "a.txt".to_string(),
+                result: Some(Box::new(crate::types::ExtractionResult {
+                    content: "alpha".to_string(),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            ChildResult {
+                path: "broken.bin".to_string(),
+                error: Some("unsupported".to_string()),
+                ..Default::default()
+            },
+        ]);
+
+        let output = build_email_text_output(&result);
+        assert!(output.contains("Attachment Contents:"));
+        assert!(output.contains("=== a.txt ===\nalpha"));
+        assert!(!output.contains("broken.bin"));
+    }
 }
@@ -0,0 +1,263 @@
+//! Centralized extraction of embedded documents.
+//!
+//! Containers such as archives hold complete documents of their own. This
+//! module runs those documents back through the extractor registry so that
+//! each one gets a full `ExtractionResult`, returned as a [`ChildResult`].
+//!
+//! With the `archives` feature, nested containers are bounded by
+//! `SecurityLimits`: every level consumes one unit of `max_archive_depth`, and
+//! the uncompressed size and file count of all levels together stay within
+//! `max_archive_size` and `max_files_in_archive`.
+
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::core::mime::{detect_mime_type, detect_mime_type_from_bytes, validate_mime_type};
+#[cfg(feature = "archives")]
+use crate::extractors::security::SecurityLimits;
+use crate::types::ChildResult;
+#[cfg(feature = "archives")]
+use crate::types::ExtractionResult;
+
+/// A document embedded in a container (archive member, attachment).
+#[derive(Debug, Clone)]
+pub struct EmbeddedDocument {
+    /// Path or filename of the document inside its container
+    pub path: String,
+    /// Raw document bytes
+    pub data: Vec<u8>,
+}
+
+/// Detect the MIME type of an embedded document.
+///
+/// The file extension is tried first because it separates formats that look
+/// alike on the byte level (CSV vs. plain text, DOCX vs. ZIP); magic bytes are
+/// the fallback for members without a usable extension.
+pub fn detect_embedded_mime_type(path: &str, data: &[u8]) -> Result<String> {
+    if let Ok(mime_type) = detect_mime_type(path, false)
+        && let Ok(validated) = validate_mime_type(&mime_type)
+    {
+        return Ok(validated);
+    }
+
+    let mime_type = detect_mime_type_from_bytes(data)?;
+    validate_mime_type(&mime_type)
+}
+
+/// Extract embedded documents through the extractor registry.
+///
+/// Each document is MIME-detected and passed to `extract_bytes` with the
+/// parent's configuration. Nested containers receive what is left of the
+/// parent's budget: one level less of `max_archive_depth`, and the part of
+/// `max_archive_size` and `max_files_in_archive` not yet used by this
+/// container's documents and by the expansions of earlier siblings.
+///
+/// Failures are recorded on the individual [`ChildResult`] and never abort
+/// the parent extraction.
+pub async fn extract_embedded_documents(
+    documents: Vec<EmbeddedDocument>,
+    config: &ExtractionConfig,
+) -> Vec<ChildResult> {
+    #[cfg(feature = "archives")]
+    let mut budget = EmbeddedBudget::new(&documents, config);
+
+    let mut children = Vec::with_capacity(documents.len());
+
+    for doc in documents {
+        let size = doc.data.len() as u64;
+
+        let mime_type = match detect_embedded_mime_type(&doc.path, &doc.data) {
+            Ok(mime_type) => mime_type,
+            Err(e) => {
+                children.push(ChildResult {
+                    path: doc.path,
+                    mime_type: None,
+                    size,
+                    result: None,
+                    error: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+
+        #[cfg(feature = "archives")]
+        let child_config = &budget.child_config(config);
+        #[cfg(not(feature = "archives"))]
+        let child_config = config;
+
+        let child = match Box::pin(crate::core::extractor::extract_bytes(
+            &doc.data,
+            &mime_type,
+            child_config,
+        ))
+        .await
+        {
+            Ok(result) => {
+                #[cfg(feature = "archives")]
+                budget.consume(&result);
+                ChildResult {
+                    path: doc.path,
+                    mime_type: Some(mime_type),
+                    size,
+                    result: Some(Box::new(result)),
+                    error: None,
+                }
+            }
+            Err(e) => ChildResult {
+                path: doc.path,
+                mime_type: Some(mime_type),
+                size,
+                result: None,
+                error: Some(e.to_string()),
+            },
+        };
+        children.push(child);
+    }
+
+    children
+}
+
+/// Share of `SecurityLimits` still available to the documents of one container.
+#[cfg(feature = "archives")]
+struct EmbeddedBudget {
+    limits: SecurityLimits,
+    bytes_remaining: u64,
+    files_remaining: usize,
+}
+
+#[cfg(feature = "archives")]
+impl EmbeddedBudget {
+    fn new(documents: &[EmbeddedDocument], config: &ExtractionConfig) -> Self {
+        let limits = config.security_limits.clone().unwrap_or_default();
+        let own_size: u64 = documents.iter().map(|doc| doc.data.len() as u64).sum();
+        Self {
+            bytes_remaining: (limits.max_archive_size as u64).saturating_sub(own_size),
+            files_remaining: limits.max_files_in_archive.saturating_sub(documents.len()),
+            limits,
+        }
+    }
+
+    /// Configuration for the next child, carrying the remaining budget.
+    fn child_config(&self, config: &ExtractionConfig) -> ExtractionConfig {
+        let mut child_config = config.clone();
+        child_config.security_limits = Some(SecurityLimits {
+            max_archive_depth: self.limits.max_archive_depth.saturating_sub(1),
+            max_archive_size: self.bytes_remaining as usize,
+            max_files_in_archive: self.files_remaining,
+            ..self.limits.clone()
+        });
+        child_config
+    }
+
+    /// Charge everything a child expanded into against the budget.
+    fn consume(&mut self, result: &ExtractionResult) {
+        let (nested_bytes, nested_files) = expanded_totals(result);
+        self.bytes_remaining = self.bytes_remaining.saturating_sub(nested_bytes);
+        self.files_remaining = self.files_remaining.saturating_sub(nested_files);
+    }
+}
+
+/// Total size and count of all documents nested below `result`, at any depth.
+#[cfg(feature = "archives")]
+fn expanded_totals(result: &ExtractionResult) -> (u64, usize) {
+    let Some(children) = &result.children else {
+        return (0, 0);
+    };
+
+    children.iter().fold((0, 0), |(bytes, files), child| {
+        let (nested_bytes, nested_files) = child.result.as_deref().map(expanded_totals).unwrap_or((0, 0));
+        (bytes + child.size + nested_bytes, files + 1 + nested_files)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(path: &str, data: &[u8]) -> EmbeddedDocument {
+        EmbeddedDocument {
+            path: path.to_string(),
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_detect_embedded_mime_type_prefers_extension() {
+        assert_eq!(detect_embedded_mime_type("data.csv", b"a,b\n1,2").unwrap(), "text/csv");
+        assert_eq!(
+            detect_embedded_mime_type("notes.md", b"# Title").unwrap(),
+            "text/markdown"
+        );
+    }
+
+    #[test]
+    fn test_detect_embedded_mime_type_falls_back_to_bytes() {
+        assert_eq!(
+            detect_embedded_mime_type("README", b"plain words").unwrap(),
+            "text/plain"
+        );
+        assert_eq!(
+            detect_embedded_mime_type("blob.unknownext", b"%PDF-1.7\n").unwrap(),
+            "application/pdf"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_embedded_documents() {
+        let config = ExtractionConfig::default();
+        let children = extract_embedded_documents(
+            vec![doc("a.txt", b"alpha"), doc("b.json", br#"{"key": "beta"}"#)],
+            &config,
+        )
+        .await;
+
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].path, "a.txt");
+        assert_eq!(children[0].mime_type.as_deref(), Some("text/plain"));
+        assert_eq!(children[0].size, 5);
+        assert!(children[0].result.as_ref().unwrap().content.contains("alpha"));
+        assert!(children[1].result.as_ref().unwrap().content.contains("beta"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_embedded_documents_records_errors() {
+        let config = ExtractionConfig::default();
+        let children = extract_embedded_documents(vec![doc("broken.json", b"{not json")], &config).await;
+
+        assert_eq!(children.len(), 1);
+        assert!(children[0].result.is_none());
+        assert!(children[0].error.is_some());
+    }
+
+    #[cfg(feature = "archives")]
+    #[test]
+    fn test_expanded_totals() {
+        let grandchild = ChildResult {
+            path: "inner/c.txt".to_string(),
+            size: 3,
+            ..Default::default()
+        };
+        let nested = ExtractionResult {
+            children: Some(vec![grandchild]),
+            ..Default::default()
+        };
+        let parent = ExtractionResult {
+            children: Some(vec![
+                ChildResult {
+                    path: "inner.zip".to_string(),
+                    size: 100,
+                    result: Some(Box::new(nested)),
+                    ..Default::default()
+                },
+                ChildResult {
+                    path: "b.txt".to_string(),
+                    size: 7,
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(expanded_totals(&parent), (110, 3));
+        assert_eq!(expanded_totals(&ExtractionResult::default()), (0, 0));
+    }
+}
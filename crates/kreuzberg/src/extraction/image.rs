@@ -517,6 +517,9 @@ pub fn extract_text_from_image_with_ocr(
             images: vec![],
             hierarchy: None,
             is_blank: Some(crate::extraction::blank_detection::is_page_text_blank(frame_text)),
+            language: None,
+            language_confidence: None,
+            text_source: None,
         });
 
         byte_offset = frame_end;
@@ -55,6 +55,7 @@ pub async fn process_images_with_ocr(
                     metadata: Metadata::default(),
                     tables: vec![],
                     detected_languages: None,
+                    language_spans: None,
                     chunks: None,
                     images: None,
                     djot_content: None,
@@ -66,6 +67,7 @@ pub async fn process_images_with_ocr(
                     extracted_keywords: None,
                     quality_score: None,
                     processing_warnings: Vec::new(),
+                    children: None,
                 };
                 image.ocr_result = Some(Box::new(extraction_result));
             }
@@ -0,0 +1,449 @@
+//! Mailbox extraction functions.
+//!
+//! Splits mbox files and walks Maildir directories into individual RFC822
+//! messages, which are then parsed with [`parse_eml_content`](super::email::parse_eml_content).
+//!
+//! # Features
+//!
+//! - **mbox splitting**: Streams `From `-separated messages without loading the whole file
+//! - **mboxrd/mboxo unquoting**: Restores body lines that were quoted as `>From `
+//! - **Maildir walking**: Messages in `new/` and `cur/`, including Maildir++ subfolders
+//! - **Thread reconstruction**: Groups messages into conversations via `In-Reply-To`/`References`
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::core::config::MboxFormat;
+//! use kreuzberg::extraction::mailbox::MboxReader;
+//! use std::io::BufReader;
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let file = std::fs::File::open("archive.mbox")?;
+//! for message in MboxReader::new(BufReader::new(file), MboxFormat::Mboxrd) {
+//!     let message = kreuzberg::extraction::email::parse_eml_content(&message?)?;
+//!     println!("Subject: {:?}", message.subject);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::core::config::MboxFormat;
+use crate::error::Result;
+use crate::types::{ChildResult, EmailThread, FormatMetadata, ThreadMessage};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+/// MIME type reported for Maildir directories.
+pub const MAILDIR_MIME_TYPE: &str = "application/x-maildir";
+
+/// Streaming iterator over the messages of an mbox file.
+///
+/// A line starting with `From ` begins a new message when it is the first
+/// line of the file or follows an empty line; the separator line itself is
+/// not part of the message. Only one message is held in memory at a time.
+pub struct MboxReader<R> {
+    reader: R,
+    format: MboxFormat,
+    line: Vec<u8>,
+    in_message: bool,
+    previous_blank: bool,
+    done: bool,
+}
+
+impl<R: BufRead> MboxReader<R> {
+    /// Create a reader over mbox data using the given quoting convention.
+    pub fn new(reader: R, format: MboxFormat) -> Self {
+        Self {
+            reader,
+            format,
+            line: Vec::new(),
+            in_message: false,
+            previous_blank: true,
+            done: false,
+        }
+    }
+
+    fn unquote<'a>(&self, line: &'a [u8]) -> &'a [u8] {
+        let quoted = match self.format {
+            MboxFormat::Mboxrd => {
+                let depth = line.iter().take_while(|&&b| b == b'>').count();
+                depth > 0 && line[depth..].starts_with(b"From ")
+            }
+            MboxFormat::Mboxo => line.starts_with(b">From "),
+        };
+        if quoted { &line[1..] } else { line }
+    }
+}
+
+impl<R: BufRead> Iterator for MboxReader<R> {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut message = Vec::new();
+        loop {
+            self.line.clear();
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+
+            let blank = self.line == b"\n" || self.line == b"\r\n";
+            if self.line.starts_with(b"From ") && self.previous_blank {
+                self.previous_blank = false;
+                if self.in_message {
+                    return Some(Ok(finish_message(message)));
+                }
+                self.in_message = true;
+                continue;
+            }
+
+            self.previous_blank = blank;
+            if !self.in_message {
+                // Tolerate leading blank lines and files that lack the first separator.
+                if blank {
+                    continue;
+                }
+                self.in_message = true;
+            }
+            message.extend_from_slice(self.unquote(&self.line));
+        }
+
+        if self.in_message && !message.is_empty() {
+            Some(Ok(finish_message(message)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Drop the empty line that mbox writers put in front of each separator.
+fn finish_message(mut message: Vec<u8>) -> Vec<u8> {
+    if message.ends_with(b"\r\n\r\n") {
+        message.truncate(message.len() - 2);
+    } else if message.ends_with(b"\n\n") {
+        message.truncate(message.len() - 1);
+    }
+    message
+}
+
+/// Whether `path` is a Maildir (a directory with `cur/` and `new/` subdirectories).
+pub fn is_maildir(path: &Path) -> bool {
+    path.join("cur").is_dir() && path.join("new").is_dir()
+}
+
+/// List the message files of a Maildir.
+///
+/// Messages are taken from `new/` and `cur/` (`tmp/` holds deliveries in
+/// progress and is skipped), sorted by file name, which starts with the
+/// delivery timestamp. Maildir++ subfolders (`.Sent`, `.Archive.2024`, ...)
+/// follow the top-level folder in name order.
+pub fn maildir_message_paths(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut folders = vec![root.to_path_buf()];
+    let mut subfolders: Vec<PathBuf> = std::fs::read_dir(root)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with('.') && name.len() > 1)
+                && is_maildir(path)
+        })
+        .collect();
+    subfolders.sort();
+    folders.extend(subfolders);
+
+    let mut paths = Vec::new();
+    for folder in folders {
+        let mut messages = Vec::new();
+        for sub in ["new", "cur"] {
+            let dir = folder.join(sub);
+            if !dir.is_dir() {
+                continue;
+            }
+            for entry in std::fs::read_dir(&dir)? {
+                let path = entry?.path();
+                let hidden = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_none_or(|name| name.starts_with('.'));
+                if path.is_file() && !hidden {
+                    messages.push(path);
+                }
+            }
+        }
+        messages.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+        paths.extend(messages);
+    }
+
+    Ok(paths)
+}
+
+/// Strip surrounding whitespace and angle brackets from a Message-ID.
+///
+/// `mail-parser` returns bare IDs for .eml files while MSG properties keep the
+/// brackets; both forms must compare equal when threading.
+fn normalize_message_id(id: &str) -> &str {
+    id.trim().trim_start_matches('<').trim_end_matches('>').trim()
+}
+
+/// Group mailbox messages into conversation threads.
+///
+/// A message's parent is its `In-Reply-To` target, or else the nearest
+/// `References` entry, provided that message is part of the mailbox. Messages
+/// that share any ancestor ID end up in the same thread, even when the common
+/// ancestor itself is missing from the mailbox. Every message belongs to
+/// exactly one thread; threads are ordered by their first message.
+pub fn build_threads(messages: &[ChildResult]) -> Vec<EmailThread> {
+    struct Headers<'a> {
+        id: Option<&'a str>,
+        ancestors: Vec<&'a str>,
+        subject: Option<&'a String>,
+    }
+
+    let headers: Vec<Headers<'_>> = messages
+        .iter()
+        .map(|child| {
+            let result = child.result.as_deref();
+            let email = result.and_then(|r| match &r.metadata.format {
+                Some(FormatMetadata::Email(meta)) => Some(meta),
+                _ => None,
+            });
+            let Some(email) = email else {
+                return Headers {
+                    id: None,
+                    ancestors: Vec::new(),
+                    subject: None,
+                };
+            };
+            // Closest ancestor first: In-Reply-To, then References newest to oldest.
+            let ancestors = email
+                .in_reply_to
+                .iter()
+                .chain(email.references.iter().rev())
+                .map(|id| normalize_message_id(id))
+                .filter(|id| !id.is_empty())
+                .collect();
+            Headers {
+                id: email
+                    .message_id
+                    .as_deref()
+                    .map(normalize_message_id)
+                    .filter(|id| !id.is_empty()),
+                ancestors,
+                subject: result.and_then(|r| r.metadata.subject.as_ref()),
+            }
+        })
+        .collect();
+
+    let mut index_by_id: HashMap<&str, usize> = HashMap::new();
+    for (index, h) in headers.iter().enumerate() {
+        if let Some(id) = h.id {
+            index_by_id.entry(id).or_insert(index);
+        }
+    }
+
+    // Union-find over messages plus referenced IDs that are not in the mailbox.
+    let mut nodes: HashMap<&str, usize> = HashMap::new();
+    let mut parent_of: Vec<usize> = (0..headers.len()).collect();
+    fn find(parent_of: &mut [usize], mut node: usize) -> usize {
+        while parent_of[node] != node {
+            parent_of[node] = parent_of[parent_of[node]];
+            node = parent_of[node];
+        }
+        node
+    }
+
+    for (index, h) in headers.iter().enumerate() {
+        for &id in h.id.iter().chain(h.ancestors.iter()) {
+            let node = match index_by_id.get(id) {
+                Some(&other) => other,
+                None => *nodes.entry(id).or_insert_with(|| {
+                    parent_of.push(parent_of.len());
+                    parent_of.len() - 1
+                }),
+            };
+            let (a, b) = (find(&mut parent_of, index), find(&mut parent_of, node));
+            if a != b {
+                parent_of[a.max(b)] = a.min(b);
+            }
+        }
+    }
+
+    let mut threads: Vec<EmailThread> = Vec::new();
+    let mut thread_by_root: HashMap<usize, usize> = HashMap::new();
+    for (index, h) in headers.iter().enumerate() {
+        let root = find(&mut parent_of, index);
+        let parent = h
+            .ancestors
+            .iter()
+            .find_map(|id| index_by_id.get(id).copied())
+            .filter(|&p| p != index);
+
+        let thread = *thread_by_root.entry(root).or_insert_with(|| {
+            threads.push(EmailThread {
+                subject: h.subject.cloned(),
+                messages: Vec::new(),
+            });
+            threads.len() - 1
+        });
+        threads[thread].messages.push(ThreadMessage { index, parent });
+    }
+
+    threads
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EmailMetadata, ExtractionResult, Metadata};
+    use std::io::Cursor;
+
+    fn split(data: &[u8], format: MboxFormat) -> Vec<String> {
+        MboxReader::new(Cursor::new(data), format)
+            .map(|m| String::from_utf8(m.unwrap()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_mbox_reader_splits_messages() {
+        let mbox = b"From a@example.com Mon Jan  1 00:00:00 2024\nSubject: One\n\nBody one\n\nFrom b@example.com Mon Jan  1 00:00:01 2024\nSubject: Two\n\nBody two\n";
+        let messages = split(mbox, MboxFormat::Mboxrd);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0], "Subject: One\n\nBody one\n");
+        assert_eq!(messages[1], "Subject: Two\n\nBody two\n");
+    }
+
+    #[test]
+    fn test_mbox_reader_requires_blank_line_before_separator() {
+        let mbox =
+            b"From a@example.com Mon Jan  1 00:00:00 2024\nSubject: One\n\nBody\nFrom here on it is still body\n";
+        let messages = split(mbox, MboxFormat::Mboxrd);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("From here on it is still body"));
+    }
+
+    #[test]
+    fn test_mbox_reader_unquoting() {
+        let mbox = b"From a@example.com Mon Jan  1 00:00:00 2024\nSubject: Q\n\n>From the start\n>>From deeper\n> From not quoted\n";
+        let rd = split(mbox, MboxFormat::Mboxrd);
+        assert!(rd[0].contains("\nFrom the start\n>From deeper\n> From not quoted\n"));
+
+        let o = split(mbox, MboxFormat::Mboxo);
+        assert!(o[0].contains("\nFrom the start\n>>From deeper\n> From not quoted\n"));
+    }
+
+    #[test]
+    fn test_mbox_reader_crlf_and_missing_separator() {
+        let mbox = b"\r\nSubject: Bare\r\n\r\nBody\r\n\r\nFrom b@example.com Mon Jan  1 00:00:01 2024\r\nSubject: Two\r\n\r\nBody two\r\n";
+        let messages = split(mbox, MboxFormat::Mboxrd);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0], "Subject: Bare\r\n\r\nBody\r\n");
+    }
+
+    #[test]
+    fn test_mbox_reader_empty() {
+        assert!(split(b"", MboxFormat::Mboxrd).is_empty());
+        assert!(split(b"\n\n", MboxFormat::Mboxrd).is_empty());
+    }
+
+    #[test]
+    fn test_maildir_message_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        for sub in ["cur", "new", "tmp", ".Sent/cur", ".Sent/new", ".Sent/tmp"] {
+            std::fs::create_dir_all(root.join(sub)).unwrap();
+        }
+        std::fs::write(root.join("cur/2.host:2,S"), "b").unwrap();
+        std::fs::write(root.join("new/1.host"), "a").unwrap();
+        std::fs::write(root.join("tmp/3.host"), "partial").unwrap();
+        std::fs::write(root.join("cur/.hidden"), "x").unwrap();
+        std::fs::write(root.join(".Sent/cur/0.host:2,S"), "c").unwrap();
+
+        assert!(is_maildir(root));
+        assert!(!is_maildir(&root.join("cur")));
+
+        let names: Vec<String> = maildir_message_paths(root)
+            .unwrap()
+            .iter()
+            .map(|p| p.strip_prefix(root).unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, vec!["new/1.host", "cur/2.host:2,S", ".Sent/cur/0.host:2,S"]);
+    }
+
+    fn message(id: &str, in_reply_to: Option<&str>, references: &[&str]) -> ChildResult {
+        ChildResult {
+            path: id.to_string(),
+            result: Some(Box::new(ExtractionResult {
+                metadata: Metadata {
+                    subject: Some(format!("Subject {id}")),
+                    format: Some(FormatMetadata::Email(EmailMetadata {
+                        from_email: None,
+                        from_name: None,
+                        to_emails: vec![],
+                        cc_emails: vec![],
+                        bcc_emails: vec![],
+                        message_id: Some(id.to_string()),
+                        in_reply_to: in_reply_to.map(str::to_string),
+                        references: references.iter().map(|r| r.to_string()).collect(),
+                        attachments: vec![],
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_threads() {
+        let messages = vec![
+            message("a@x", None, &[]),
+            message("b@x", Some("<a@x>"), &["<a@x>"]),
+            message("other@x", None, &[]),
+            message("c@x", None, &["a@x", "b@x"]),
+            // Replies to the same message that is not part of the mailbox.
+            message("d@x", Some("missing@x"), &[]),
+            message("e@x", None, &["missing@x"]),
+        ];
+
+        let threads = build_threads(&messages);
+        assert_eq!(threads.len(), 3);
+
+        assert_eq!(threads[0].subject.as_deref(), Some("Subject a@x"));
+        let first: Vec<(usize, Option<usize>)> = threads[0].messages.iter().map(|m| (m.index, m.parent)).collect();
+        assert_eq!(first, vec![(0, None), (1, Some(0)), (3, Some(1))]);
+
+        assert_eq!(threads[1].messages.len(), 1);
+        assert_eq!(threads[1].messages[0].index, 2);
+
+        let orphans: Vec<(usize, Option<usize>)> = threads[2].messages.iter().map(|m| (m.index, m.parent)).collect();
+        assert_eq!(orphans, vec![(4, None), (5, None)]);
+    }
+
+    #[test]
+    fn test_build_threads_without_email_metadata() {
+        let messages = vec![
+            ChildResult {
+                path: "broken".to_string(),
+                error: Some("parse error".to_string()),
+                ..Default::default()
+            },
+            message("a@x", None, &[]),
+        ];
+        let threads = build_threads(&messages);
+        assert_eq!(threads.len(), 2);
+        assert!(threads[0].subject.is_none());
+    }
+}
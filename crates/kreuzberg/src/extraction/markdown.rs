@@ -1,7 +1,7 @@
 //! Markdown table formatting utilities
 //!
 //! This module provides utilities for converting tabular data into GitHub-Flavored Markdown (GFM) tables.
-//! It's used by multiple extractors (DOCX, HTML) that need to represent structured table data in markdown format.
+//! It's used by multiple extractors (DOCX, HTML, PDF) that need to represent structured table data in markdown format.
 
 use crate::extraction::capacity;
 
@@ -1,5 +1,7 @@
 pub mod blank_detection;
+pub mod ocr_export;
 pub mod structured;
+pub mod table_stitching;
 pub mod text;
 pub mod transform;
 
@@ -19,6 +21,9 @@ pub mod archive;
 #[cfg(feature = "email")]
 pub mod email;
 
+#[cfg(feature = "email")]
+pub mod mailbox;
+
 #[cfg(feature = "excel")]
 pub mod excel;
 
@@ -43,6 +48,9 @@ pub mod image_format;
 #[cfg(all(feature = "ocr", feature = "tokio-runtime"))]
 pub mod image_ocr;
 
+#[cfg(any(feature = "archives", feature = "email", feature = "pdf"))]
+pub mod embedded;
+
 #[cfg(feature = "office")]
 pub mod ppt;
 
@@ -55,10 +63,12 @@ pub mod table;
 #[cfg(feature = "xml")]
 pub mod xml;
 
-#[cfg(any(feature = "office", feature = "html", feature = "xml"))]
+#[cfg(any(feature = "office", feature = "html", feature = "xml", feature = "pdf"))]
 pub mod markdown;
 
+pub use ocr_export::{OcrPageDimensions, to_alto_xml, to_hocr, to_page_xml};
 pub use structured::{JsonExtractionConfig, StructuredDataResult, parse_json, parse_toml, parse_yaml};
+pub use table_stitching::stitch_tables;
 pub use text::parse_text;
 pub use transform::{
     ListItemMetadata, ListType, detect_list_items, generate_element_id, transform_extraction_result_to_elements,
@@ -70,12 +80,15 @@ pub use image::{ImageMetadata, extract_image_metadata};
 
 #[cfg(feature = "archives")]
 pub use archive::{
-    ArchiveEntry, ArchiveMetadata, extract_7z_metadata, extract_7z_text_content, extract_tar_metadata,
-    extract_tar_text_content, extract_zip_metadata, extract_zip_text_content,
+    ArchiveEntry, ArchiveMember, ArchiveMetadata, extract_7z_members, extract_7z_metadata, extract_7z_text_content,
+    extract_tar_members, extract_tar_metadata, extract_tar_text_content, extract_zip_members, extract_zip_metadata,
+    extract_zip_text_content,
 };
 
 #[cfg(feature = "email")]
-pub use email::{build_email_text_output, extract_email_content, parse_eml_content, parse_msg_content};
+pub use email::{
+    build_email_text_output, extract_email_attachments, extract_email_content, parse_eml_content, parse_msg_content,
+};
 
 #[cfg(feature = "excel")]
 pub use excel::{excel_to_markdown, read_excel_bytes, read_excel_file};
@@ -105,7 +118,7 @@ pub use table::table_from_arrow_to_markdown;
 #[cfg(feature = "xml")]
 pub use xml::parse_xml;
 
-#[cfg(any(feature = "office", feature = "html", feature = "xml"))]
+#[cfg(any(feature = "office", feature = "html", feature = "xml", feature = "pdf"))]
 pub use markdown::cells_to_markdown;
 
 pub use capacity::{
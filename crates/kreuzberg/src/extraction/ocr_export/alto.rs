@@ -0,0 +1,263 @@
+//! ALTO XML v4 writer.
+
+use super::{LayoutPage, OcrPageDimensions, build_layout, counterclockwise, escape_xml, format_number};
+use crate::types::OcrElement;
+use std::fmt::Write;
+
+const ALTO_NAMESPACE: &str = "http://www.loc.gov/standards/alto/ns-v4#";
+const ALTO_SCHEMA_LOCATION: &str = "http://www.loc.gov/standards/alto/v4/alto-4-4.xsd";
+
+/// Serialize OCR elements to an ALTO v4 document.
+///
+/// Every page becomes a `Page` with a `PrintSpace` holding `TextBlock`,
+/// `TextLine`, `String` and `SP` elements. Positions are in pixels
+/// (`MeasurementUnit` is `pixel`) and `WC` carries the word confidence.
+pub fn to_alto_xml(elements: &[OcrElement], pages: &[OcrPageDimensions]) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<alto xmlns=\"{ns}\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:schemaLocation=\"{ns} {schema}\">",
+        ns = ALTO_NAMESPACE,
+        schema = ALTO_SCHEMA_LOCATION
+    );
+    xml.push_str("  <Description>\n");
+    xml.push_str("    <MeasurementUnit>pixel</MeasurementUnit>\n");
+    xml.push_str("    <Processing ID=\"processing_1\">\n");
+    xml.push_str("      <processingSoftware>\n");
+    xml.push_str("        <softwareName>kreuzberg</softwareName>\n");
+    let _ = writeln!(
+        xml,
+        "        <softwareVersion>{}</softwareVersion>",
+        env!("CARGO_PKG_VERSION")
+    );
+    xml.push_str("      </processingSoftware>\n");
+    xml.push_str("    </Processing>\n");
+    xml.push_str("  </Description>\n");
+    xml.push_str("  <Layout>\n");
+    for page in build_layout(elements, pages) {
+        write_page(&mut xml, &page);
+    }
+    xml.push_str("  </Layout>\n");
+    xml.push_str("</alto>\n");
+    xml
+}
+
+fn write_page(xml: &mut String, page: &LayoutPage) {
+    let n = page.number;
+    let _ = writeln!(
+        xml,
+        "    <Page ID=\"p{n}\" PHYSICAL_IMG_NR=\"{n}\" WIDTH=\"{}\" HEIGHT=\"{}\">",
+        page.width, page.height
+    );
+    let _ = writeln!(
+        xml,
+        "      <PrintSpace ID=\"p{n}_ps\" HPOS=\"0\" VPOS=\"0\" WIDTH=\"{}\" HEIGHT=\"{}\">",
+        page.width, page.height
+    );
+
+    for (b, block) in page.blocks.iter().enumerate() {
+        let block_id = format!("p{n}_b{}", b + 1);
+        let rotation = block
+            .angle()
+            .map(|angle| format!(" ROTATION=\"{}\"", format_number(counterclockwise(angle))))
+            .unwrap_or_default();
+        let _ = writeln!(
+            xml,
+            "        <TextBlock ID=\"{block_id}\" HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\" HEIGHT=\"{}\"{rotation}>",
+            block.bbox.left,
+            block.bbox.top,
+            block.bbox.width(),
+            block.bbox.height()
+        );
+
+        for (l, line) in block.lines.iter().enumerate() {
+            let line_id = format!("{block_id}_l{}", l + 1);
+            let _ = writeln!(
+                xml,
+                "          <TextLine ID=\"{line_id}\" HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\" HEIGHT=\"{}\">",
+                line.bbox.left,
+                line.bbox.top,
+                line.bbox.width(),
+                line.bbox.height()
+            );
+
+            for (w, word) in line.words.iter().enumerate() {
+                if w > 0 {
+                    let previous = &line.words[w - 1].bbox;
+                    let _ = writeln!(
+                        xml,
+                        "            <SP HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\"/>",
+                        previous.right,
+                        previous.top,
+                        word.bbox.left.saturating_sub(previous.right)
+                    );
+                }
+                let _ = writeln!(
+                    xml,
+                    "            <String ID=\"{line_id}_w{}\" HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\" HEIGHT=\"{}\" WC=\"{}\" CONTENT=\"{}\"/>",
+                    w + 1,
+                    word.bbox.left,
+                    word.bbox.top,
+                    word.bbox.width(),
+                    word.bbox.height(),
+                    format_number(word.confidence.clamp(0.0, 1.0)),
+                    escape_xml(&word.text)
+                );
+            }
+
+            xml.push_str("          </TextLine>\n");
+        }
+
+        xml.push_str("        </TextBlock>\n");
+    }
+
+    xml.push_str("      </PrintSpace>\n");
+    xml.push_str("    </Page>\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrBoundingGeometry, OcrConfidence, OcrElementLevel, OcrRotation};
+    use std::collections::HashSet;
+
+    fn word(text: &str, left: u32, top: u32, confidence: f64) -> OcrElement {
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Rectangle {
+                left,
+                top,
+                width: 80,
+                height: 30,
+            },
+            OcrConfidence::from_tesseract(confidence),
+        )
+        .with_level(OcrElementLevel::Word)
+        .with_parent_id("p1_b1_par1_l1")
+    }
+
+    fn sample_elements() -> Vec<OcrElement> {
+        vec![
+            OcrElement::new(
+                "Fish & Chips",
+                OcrBoundingGeometry::Rectangle {
+                    left: 10,
+                    top: 20,
+                    width: 300,
+                    height: 30,
+                },
+                OcrConfidence::from_tesseract(90.0),
+            )
+            .with_level(OcrElementLevel::Line)
+            .with_parent_id("p1_b1_par1"),
+            word("Fish", 10, 20, 96.0),
+            word("&", 120, 20, 88.0),
+            word("Chips", 230, 20, 91.0),
+        ]
+    }
+
+    fn pages() -> Vec<OcrPageDimensions> {
+        vec![OcrPageDimensions {
+            page_number: 1,
+            width: 1000,
+            height: 1400,
+        }]
+    }
+
+    #[test]
+    fn test_to_alto_xml_structure() {
+        let xml = to_alto_xml(&sample_elements(), &pages());
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+        let root = doc.root_element();
+
+        assert_eq!(root.tag_name().name(), "alto");
+        assert_eq!(root.tag_name().namespace(), Some(ALTO_NAMESPACE));
+        let top_level: Vec<&str> = root
+            .children()
+            .filter(|n| n.is_element())
+            .map(|n| n.tag_name().name())
+            .collect();
+        assert_eq!(top_level, ["Description", "Layout"]);
+
+        let unit = doc.descendants().find(|n| n.has_tag_name("MeasurementUnit")).unwrap();
+        assert_eq!(unit.text(), Some("pixel"));
+
+        let page = doc.descendants().find(|n| n.has_tag_name("Page")).unwrap();
+        assert_eq!(page.attribute("WIDTH"), Some("1000"));
+        assert_eq!(page.attribute("HEIGHT"), Some("1400"));
+        assert_eq!(page.attribute("PHYSICAL_IMG_NR"), Some("1"));
+        assert_eq!(page.first_element_child().unwrap().tag_name().name(), "PrintSpace");
+
+        let line = doc.descendants().find(|n| n.has_tag_name("TextLine")).unwrap();
+        let children: Vec<&str> = line
+            .children()
+            .filter(|n| n.is_element())
+            .map(|n| n.tag_name().name())
+            .collect();
+        assert_eq!(children, ["String", "SP", "String", "SP", "String"]);
+    }
+
+    #[test]
+    fn test_to_alto_xml_strings_carry_content_and_confidence() {
+        let xml = to_alto_xml(&sample_elements(), &pages());
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+
+        let strings: Vec<_> = doc.descendants().filter(|n| n.has_tag_name("String")).collect();
+        assert_eq!(strings.len(), 3);
+        assert_eq!(strings[0].attribute("CONTENT"), Some("Fish"));
+        assert_eq!(strings[0].attribute("WC"), Some("0.96"));
+        assert_eq!(strings[1].attribute("CONTENT"), Some("&"));
+        assert_eq!(strings[2].attribute("HPOS"), Some("230"));
+        assert_eq!(strings[2].attribute("WIDTH"), Some("80"));
+
+        let space = doc.descendants().find(|n| n.has_tag_name("SP")).unwrap();
+        assert_eq!(space.attribute("HPOS"), Some("90"));
+        assert_eq!(space.attribute("WIDTH"), Some("30"));
+    }
+
+    #[test]
+    fn test_to_alto_xml_ids_are_unique() {
+        let mut elements = sample_elements();
+        elements.extend(sample_elements().into_iter().map(|e| e.with_page_number(2)));
+        let xml = to_alto_xml(&elements, &pages());
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+
+        let ids: Vec<&str> = doc.descendants().filter_map(|n| n.attribute("ID")).collect();
+        let unique: HashSet<&str> = ids.iter().copied().collect();
+        assert_eq!(ids.len(), unique.len());
+        assert_eq!(doc.descendants().filter(|n| n.has_tag_name("Page")).count(), 2);
+    }
+
+    #[test]
+    fn test_to_alto_xml_block_rotation_is_counterclockwise() {
+        let line = OcrElement::new(
+            "sideways",
+            OcrBoundingGeometry::Rectangle {
+                left: 10,
+                top: 10,
+                width: 30,
+                height: 200,
+            },
+            OcrConfidence::from_paddle(0.9, 0.9),
+        )
+        .with_rotation(OcrRotation {
+            angle_degrees: 90.0,
+            confidence: None,
+        });
+        let xml = to_alto_xml(&[line], &[]);
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+
+        let block = doc.descendants().find(|n| n.has_tag_name("TextBlock")).unwrap();
+        assert_eq!(block.attribute("ROTATION"), Some("270"));
+    }
+
+    #[test]
+    fn test_to_alto_xml_empty_input_is_well_formed() {
+        let xml = to_alto_xml(&[], &pages());
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+
+        let print_space = doc.descendants().find(|n| n.has_tag_name("PrintSpace")).unwrap();
+        assert_eq!(print_space.children().filter(|n| n.is_element()).count(), 0);
+    }
+}
@@ -0,0 +1,213 @@
+//! hOCR 1.2 writer.
+
+use super::{
+    LayoutPage, LayoutWord, OcrPageDimensions, Rect, build_layout, counterclockwise, escape_xml, format_number,
+};
+use crate::types::OcrElement;
+use std::fmt::Write;
+
+/// Serialize OCR elements to an hOCR document.
+///
+/// Produces XHTML in the shape Tesseract writes: one `ocr_page` per page with
+/// `ocr_carea`, `ocr_par`, `ocr_line` and `ocrx_word` elements carrying `bbox`
+/// properties, `x_wconf` word confidences (0-100) and `textangle` for rotated
+/// lines.
+pub fn to_hocr(elements: &[OcrElement], pages: &[OcrPageDimensions]) -> String {
+    let mut html = String::new();
+    html.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    html.push_str("<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">\n");
+    html.push_str("<html xmlns=\"http://www.w3.org/1999/xhtml\">\n");
+    html.push_str(" <head>\n");
+    html.push_str("  <title></title>\n");
+    html.push_str("  <meta http-equiv=\"Content-Type\" content=\"text/html;charset=utf-8\"/>\n");
+    let _ = writeln!(
+        html,
+        "  <meta name=\"ocr-system\" content=\"kreuzberg {}\"/>",
+        env!("CARGO_PKG_VERSION")
+    );
+    html.push_str("  <meta name=\"ocr-capabilities\" content=\"ocr_page ocr_carea ocr_par ocr_line ocrx_word\"/>\n");
+    html.push_str(" </head>\n");
+    html.push_str(" <body>\n");
+    for page in build_layout(elements, pages) {
+        write_page(&mut html, &page);
+    }
+    html.push_str(" </body>\n");
+    html.push_str("</html>\n");
+    html
+}
+
+fn write_page(html: &mut String, page: &LayoutPage) {
+    let n = page.number;
+    let _ = writeln!(
+        html,
+        "  <div class=\"ocr_page\" id=\"page_{n}\" title=\"bbox 0 0 {} {}; ppageno {}\">",
+        page.width,
+        page.height,
+        n.saturating_sub(1)
+    );
+
+    for (b, block) in page.blocks.iter().enumerate() {
+        let block_id = format!("{n}_{}", b + 1);
+        let _ = writeln!(
+            html,
+            "   <div class=\"ocr_carea\" id=\"block_{block_id}\" title=\"{}\">",
+            bbox(&block.bbox)
+        );
+        let _ = writeln!(
+            html,
+            "    <p class=\"ocr_par\" id=\"par_{block_id}\" title=\"{}\">",
+            bbox(&block.bbox)
+        );
+
+        for (l, line) in block.lines.iter().enumerate() {
+            let line_id = format!("{block_id}_{}", l + 1);
+            let textangle = if line.angle != 0.0 {
+                format!("; textangle {}", format_number(counterclockwise(line.angle)))
+            } else {
+                String::new()
+            };
+            let _ = writeln!(
+                html,
+                "     <span class=\"ocr_line\" id=\"line_{line_id}\" title=\"{}{textangle}\">",
+                bbox(&line.bbox)
+            );
+            for (w, word) in line.words.iter().enumerate() {
+                let _ = writeln!(
+                    html,
+                    "      <span class=\"ocrx_word\" id=\"word_{line_id}_{}\" title=\"{}\">{}</span>",
+                    w + 1,
+                    word_title(word),
+                    escape_xml(&word.text)
+                );
+            }
+            html.push_str("     </span>\n");
+        }
+
+        html.push_str("    </p>\n");
+        html.push_str("   </div>\n");
+    }
+
+    html.push_str("  </div>\n");
+}
+
+fn bbox(rect: &Rect) -> String {
+    format!("bbox {} {} {} {}", rect.left, rect.top, rect.right, rect.bottom)
+}
+
+fn word_title(word: &LayoutWord) -> String {
+    format!(
+        "{}; x_wconf {}",
+        bbox(&word.bbox),
+        (word.confidence.clamp(0.0, 1.0) * 100.0).round() as u32
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrBoundingGeometry, OcrConfidence, OcrElementLevel, OcrRotation};
+    use std::collections::HashSet;
+
+    const XHTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+    fn parse(html: &str) -> roxmltree::Document<'_> {
+        let options = roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        };
+        roxmltree::Document::parse_with_options(html, options).unwrap()
+    }
+
+    fn class_of<'a>(node: &roxmltree::Node<'a, 'a>) -> Option<&'a str> {
+        node.attribute("class")
+    }
+
+    fn sample_elements() -> Vec<OcrElement> {
+        let word = |text: &str, left: u32, confidence: f64| {
+            OcrElement::new(
+                text,
+                OcrBoundingGeometry::Rectangle {
+                    left,
+                    top: 40,
+                    width: 90,
+                    height: 30,
+                },
+                OcrConfidence::from_tesseract(confidence),
+            )
+            .with_level(OcrElementLevel::Word)
+            .with_parent_id("p1_b1_par1_l1")
+        };
+        vec![word("Total:", 10, 93.4), word("<42>", 120, 71.0)]
+    }
+
+    #[test]
+    fn test_to_hocr_structure() {
+        let html = to_hocr(
+            &sample_elements(),
+            &[OcrPageDimensions {
+                page_number: 1,
+                width: 800,
+                height: 600,
+            }],
+        );
+        let doc = parse(&html);
+        let root = doc.root_element();
+        assert_eq!(root.tag_name().name(), "html");
+        assert_eq!(root.tag_name().namespace(), Some(XHTML_NAMESPACE));
+
+        let capabilities = doc
+            .descendants()
+            .find(|n| n.attribute("name") == Some("ocr-capabilities"))
+            .unwrap();
+        assert!(capabilities.attribute("content").unwrap().contains("ocrx_word"));
+
+        let page = doc.descendants().find(|n| class_of(n) == Some("ocr_page")).unwrap();
+        assert_eq!(page.attribute("title"), Some("bbox 0 0 800 600; ppageno 0"));
+
+        // ocr_page > ocr_carea > ocr_par > ocr_line > ocrx_word
+        let mut node = page;
+        for class in ["ocr_carea", "ocr_par", "ocr_line", "ocrx_word"] {
+            node = node.first_element_child().unwrap();
+            assert_eq!(class_of(&node), Some(class));
+        }
+    }
+
+    #[test]
+    fn test_to_hocr_word_properties() {
+        let html = to_hocr(&sample_elements(), &[]);
+        let doc = parse(&html);
+
+        let words: Vec<_> = doc.descendants().filter(|n| class_of(n) == Some("ocrx_word")).collect();
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].text(), Some("Total:"));
+        assert_eq!(words[0].attribute("title"), Some("bbox 10 40 100 70; x_wconf 93"));
+        assert_eq!(words[1].text(), Some("<42>"));
+
+        let ids: Vec<&str> = doc.descendants().filter_map(|n| n.attribute("id")).collect();
+        let unique: HashSet<&str> = ids.iter().copied().collect();
+        assert_eq!(ids.len(), unique.len());
+    }
+
+    #[test]
+    fn test_to_hocr_rotated_line_has_textangle() {
+        let line = OcrElement::new(
+            "vertical",
+            OcrBoundingGeometry::Rectangle {
+                left: 10,
+                top: 10,
+                width: 30,
+                height: 200,
+            },
+            OcrConfidence::from_paddle(0.9, 0.9),
+        )
+        .with_rotation(OcrRotation {
+            angle_degrees: 270.0,
+            confidence: None,
+        });
+        let html = to_hocr(&[line], &[]);
+        let doc = parse(&html);
+
+        let line = doc.descendants().find(|n| class_of(n) == Some("ocr_line")).unwrap();
+        assert_eq!(line.attribute("title"), Some("bbox 10 10 40 210; textangle 90"));
+    }
+}
@@ -0,0 +1,521 @@
+//! Export of OCR results to layout XML formats.
+//!
+//! Serializes [`OcrElement`]s with their page dimensions into the formats used by
+//! digitization pipelines:
+//!
+//! - [ALTO XML](https://www.loc.gov/standards/alto/) v4
+//! - [PRImA PAGE XML](https://github.com/PRImA-Research-Lab/PAGE-XML) 2019-07-15
+//! - [hOCR](http://kba.github.io/hocr-spec/1.2/) 1.2
+//!
+//! All three share one layout model: elements are grouped per page into text
+//! blocks, lines and words. Word elements are attached to the line whose box
+//! contains them; lines without words (PaddleOCR) are split into words on
+//! whitespace. Coordinates are image pixels.
+
+mod alto;
+mod hocr;
+mod page_xml;
+
+pub use alto::to_alto_xml;
+pub use hocr::to_hocr;
+pub use page_xml::to_page_xml;
+
+use crate::types::{OcrBoundingGeometry, OcrElement, OcrElementLevel};
+use ahash::AHashMap;
+
+/// Pixel dimensions of an OCR'd page image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OcrPageDimensions {
+    /// Page number (1-indexed), matching [`OcrElement::page_number`]
+    pub page_number: usize,
+    /// Image width in pixels
+    pub width: u32,
+    /// Image height in pixels
+    pub height: u32,
+}
+
+/// Axis-aligned box in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rect {
+    left: u32,
+    top: u32,
+    right: u32,
+    bottom: u32,
+}
+
+impl Rect {
+    fn from_geometry(geometry: &OcrBoundingGeometry) -> Self {
+        let (left, top, width, height) = geometry.to_aabb();
+        Self {
+            left,
+            top,
+            right: left.saturating_add(width),
+            bottom: top.saturating_add(height),
+        }
+    }
+
+    fn width(&self) -> u32 {
+        self.right - self.left
+    }
+
+    fn height(&self) -> u32 {
+        self.bottom - self.top
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        Self {
+            left: self.left.min(other.left),
+            top: self.top.min(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+        }
+    }
+
+    fn contains_point(&self, (x, y): (f64, f64)) -> bool {
+        x >= self.left as f64 && x <= self.right as f64 && y >= self.top as f64 && y <= self.bottom as f64
+    }
+
+    fn corners(&self) -> [(u32, u32); 4] {
+        [
+            (self.left, self.top),
+            (self.right, self.top),
+            (self.right, self.bottom),
+            (self.left, self.bottom),
+        ]
+    }
+
+    fn enclosing<'a>(rects: impl IntoIterator<Item = &'a Rect>) -> Option<Rect> {
+        rects.into_iter().copied().reduce(|acc, rect| acc.union(&rect))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LayoutWord {
+    text: String,
+    bbox: Rect,
+    /// Recognition confidence (0.0-1.0)
+    confidence: f64,
+}
+
+#[derive(Debug, Clone)]
+struct LayoutLine {
+    bbox: Rect,
+    /// Outline of the line; a quadrilateral for rotated text
+    polygon: [(u32, u32); 4],
+    /// Clockwise text rotation in degrees
+    angle: f64,
+    words: Vec<LayoutWord>,
+}
+
+impl LayoutLine {
+    fn text(&self) -> String {
+        self.words
+            .iter()
+            .map(|word| word.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LayoutBlock {
+    bbox: Rect,
+    lines: Vec<LayoutLine>,
+}
+
+impl LayoutBlock {
+    /// Clockwise rotation shared by every line, if any.
+    fn angle(&self) -> Option<f64> {
+        let first = self.lines.first()?.angle;
+        (first != 0.0 && self.lines.iter().all(|line| line.angle == first)).then_some(first)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LayoutPage {
+    number: usize,
+    width: u32,
+    height: u32,
+    blocks: Vec<LayoutBlock>,
+}
+
+/// Group elements into pages of blocks, lines and words, ordered by page number.
+///
+/// Pages listed in `pages` are always present, even without text. Pages that
+/// only appear in `elements` are sized to fit their elements.
+fn build_layout(elements: &[OcrElement], pages: &[OcrPageDimensions]) -> Vec<LayoutPage> {
+    let mut page_numbers: Vec<usize> = pages
+        .iter()
+        .map(|page| page.page_number)
+        .chain(
+            elements
+                .iter()
+                .filter(|element| element.level != OcrElementLevel::Page)
+                .map(|element| element.page_number),
+        )
+        .collect();
+    page_numbers.sort_unstable();
+    page_numbers.dedup();
+
+    page_numbers
+        .into_iter()
+        .map(|number| {
+            let page_elements: Vec<&OcrElement> = elements
+                .iter()
+                .filter(|element| element.page_number == number && element.level != OcrElementLevel::Page)
+                .collect();
+            let blocks = build_blocks(&page_elements);
+
+            let (width, height) = match pages.iter().find(|page| page.page_number == number) {
+                Some(page) => (page.width, page.height),
+                None => {
+                    let extent = Rect::enclosing(blocks.iter().map(|block| &block.bbox));
+                    extent.map_or((0, 0), |rect| (rect.right, rect.bottom))
+                }
+            };
+
+            LayoutPage {
+                number,
+                width,
+                height,
+                blocks,
+            }
+        })
+        .collect()
+}
+
+fn build_blocks(elements: &[&OcrElement]) -> Vec<LayoutBlock> {
+    let line_elements: Vec<&OcrElement> = elements
+        .iter()
+        .copied()
+        .filter(|element| element.level != OcrElementLevel::Word)
+        .collect();
+
+    // Attach each word to the first line whose box contains the word's centre.
+    let mut line_words: Vec<Vec<&OcrElement>> = vec![Vec::new(); line_elements.len()];
+    let mut orphan_words: Vec<&OcrElement> = Vec::new();
+    for word in elements.iter().copied().filter(|e| e.level == OcrElementLevel::Word) {
+        let center = word.geometry.center();
+        match line_elements
+            .iter()
+            .position(|line| Rect::from_geometry(&line.geometry).contains_point(center))
+        {
+            Some(index) => line_words[index].push(word),
+            None => orphan_words.push(word),
+        }
+    }
+
+    // (block key, line) in reading order; lines share a block when they share a parent.
+    let mut keyed_lines: Vec<(Option<&str>, LayoutLine)> = line_elements
+        .iter()
+        .zip(line_words)
+        .filter_map(|(line, words)| {
+            let layout_line = if words.is_empty() {
+                split_line(line)
+            } else {
+                line_from_words(&words, Some(line))
+            }?;
+            Some((line.parent_id.as_deref(), layout_line))
+        })
+        .collect();
+
+    // Words outside every line are grouped into lines by their parent id.
+    let mut orphan_groups: Vec<(Option<&str>, Vec<&OcrElement>)> = Vec::new();
+    for word in orphan_words {
+        let key = word.parent_id.as_deref();
+        match orphan_groups
+            .iter_mut()
+            .find(|(group_key, _)| key.is_some() && *group_key == key)
+        {
+            Some((_, words)) => words.push(word),
+            None => orphan_groups.push((key, vec![word])),
+        }
+    }
+    for (key, words) in orphan_groups {
+        if let Some(line) = line_from_words(&words, None) {
+            keyed_lines.push((key.map(line_parent_key), line));
+        }
+    }
+
+    let mut blocks: Vec<LayoutBlock> = Vec::new();
+    let mut block_index: AHashMap<&str, usize> = AHashMap::new();
+    for (key, line) in keyed_lines {
+        let existing = key.and_then(|key| block_index.get(key).copied());
+        match existing {
+            Some(index) => {
+                let block = &mut blocks[index];
+                block.bbox = block.bbox.union(&line.bbox);
+                block.lines.push(line);
+            }
+            None => {
+                if let Some(key) = key {
+                    block_index.insert(key, blocks.len());
+                }
+                blocks.push(LayoutBlock {
+                    bbox: line.bbox,
+                    lines: vec![line],
+                });
+            }
+        }
+    }
+    blocks
+}
+
+/// Paragraph key of a word's parent line id (`p1_b1_par1_l2` -> `p1_b1_par1`).
+fn line_parent_key(line_id: &str) -> &str {
+    match line_id.rfind("_l") {
+        Some(index) => &line_id[..index],
+        None => line_id,
+    }
+}
+
+fn line_from_words(words: &[&OcrElement], line: Option<&OcrElement>) -> Option<LayoutLine> {
+    let words: Vec<LayoutWord> = words
+        .iter()
+        .filter(|word| !word.text.trim().is_empty())
+        .map(|word| LayoutWord {
+            text: word.text.trim().to_string(),
+            bbox: Rect::from_geometry(&word.geometry),
+            confidence: word.confidence.recognition,
+        })
+        .collect();
+    let words_box = Rect::enclosing(words.iter().map(|word| &word.bbox))?;
+
+    let bbox = line.map_or(words_box, |line| Rect::from_geometry(&line.geometry).union(&words_box));
+    let polygon = match line.map(|line| &line.geometry) {
+        Some(OcrBoundingGeometry::Quadrilateral { points }) => *points,
+        _ => bbox.corners(),
+    };
+    Some(LayoutLine {
+        bbox,
+        polygon,
+        angle: line.map_or(0.0, element_angle),
+        words,
+    })
+}
+
+/// Split a line without word elements into words on whitespace.
+///
+/// Horizontal lines get word boxes proportional to character counts; rotated
+/// lines keep the whole text as one word spanning the line.
+fn split_line(line: &OcrElement) -> Option<LayoutLine> {
+    let bbox = Rect::from_geometry(&line.geometry);
+    let angle = element_angle(line);
+    let tokens: Vec<&str> = line.text.split_whitespace().collect();
+    if tokens.is_empty() {
+        return None;
+    }
+    let confidence = line.confidence.recognition;
+
+    let words = if angle != 0.0 || tokens.len() == 1 {
+        vec![LayoutWord {
+            text: tokens.join(" "),
+            bbox,
+            confidence,
+        }]
+    } else {
+        // One character of spacing between tokens.
+        let total_chars: usize = tokens.iter().map(|token| token.chars().count()).sum::<usize>() + tokens.len() - 1;
+        let char_width = bbox.width() as f64 / total_chars as f64;
+        let mut offset = 0usize;
+        tokens
+            .iter()
+            .map(|token| {
+                let chars = token.chars().count();
+                let left = bbox.left + (offset as f64 * char_width).round() as u32;
+                let right = (bbox.left + ((offset + chars) as f64 * char_width).round() as u32).min(bbox.right);
+                offset += chars + 1;
+                LayoutWord {
+                    text: (*token).to_string(),
+                    bbox: Rect {
+                        left,
+                        top: bbox.top,
+                        right: right.max(left),
+                        bottom: bbox.bottom,
+                    },
+                    confidence,
+                }
+            })
+            .collect()
+    };
+
+    let polygon = match &line.geometry {
+        OcrBoundingGeometry::Quadrilateral { points } => *points,
+        OcrBoundingGeometry::Rectangle { .. } => bbox.corners(),
+    };
+    Some(LayoutLine {
+        bbox,
+        polygon,
+        angle,
+        words,
+    })
+}
+
+/// Clockwise rotation of an element in degrees, normalized to `[0, 360)`.
+fn element_angle(element: &OcrElement) -> f64 {
+    element
+        .rotation
+        .as_ref()
+        .map_or(0.0, |rotation| rotation.angle_degrees.rem_euclid(360.0))
+}
+
+/// Counterclockwise rotation in degrees for a clockwise `angle`, as ALTO and hOCR expect.
+fn counterclockwise(angle: f64) -> f64 {
+    (360.0 - angle).rem_euclid(360.0)
+}
+
+/// Escape text for XML element content and attribute values.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Characters not allowed in XML 1.0 documents
+            '\t' | '\n' | '\r' => escaped.push(ch),
+            c if (c as u32) < 0x20 || c == '\u{FFFE}' || c == '\u{FFFF}' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Format a float without a trailing `.0` for whole numbers.
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        let formatted = format!("{:.2}", value);
+        formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrConfidence, OcrRotation};
+
+    fn rect(left: u32, top: u32, width: u32, height: u32) -> OcrBoundingGeometry {
+        OcrBoundingGeometry::Rectangle {
+            left,
+            top,
+            width,
+            height,
+        }
+    }
+
+    fn tesseract_page() -> Vec<OcrElement> {
+        let line = |text: &str, top: u32, par: u32| {
+            OcrElement::new(text, rect(40, top, 300, 30), OcrConfidence::from_tesseract(90.0))
+                .with_level(OcrElementLevel::Line)
+                .with_parent_id(format!("p1_b1_par{}", par))
+        };
+        let word = |text: &str, left: u32, top: u32, line: u32| {
+            OcrElement::new(text, rect(left, top, 100, 30), OcrConfidence::from_tesseract(95.0))
+                .with_level(OcrElementLevel::Word)
+                .with_parent_id(format!("p1_b1_par1_l{}", line))
+        };
+        vec![
+            line("Hello world", 50, 1),
+            word("Hello", 40, 50, 1),
+            word("world", 200, 50, 1),
+            line("Second line", 100, 1),
+            word("Second", 40, 100, 2),
+            word("line", 200, 100, 2),
+            line("Next paragraph", 200, 2),
+        ]
+    }
+
+    #[test]
+    fn test_build_layout_groups_words_into_lines_and_blocks() {
+        let pages = build_layout(&tesseract_page(), &[]);
+
+        assert_eq!(pages.len(), 1);
+        let page = &pages[0];
+        assert_eq!(page.blocks.len(), 2);
+        assert_eq!(page.blocks[0].lines.len(), 2);
+        assert_eq!(page.blocks[0].lines[0].text(), "Hello world");
+        assert_eq!(page.blocks[0].lines[1].words.len(), 2);
+        assert_eq!(page.blocks[0].bbox.bottom, 130);
+        // Line without words is split into word boxes
+        assert_eq!(page.blocks[1].lines[0].words.len(), 2);
+        assert_eq!(page.blocks[1].lines[0].words[1].text, "paragraph");
+        // Page size falls back to the element extent
+        assert_eq!((page.width, page.height), (340, 230));
+    }
+
+    #[test]
+    fn test_build_layout_uses_given_page_dimensions() {
+        let pages = build_layout(
+            &tesseract_page(),
+            &[
+                OcrPageDimensions {
+                    page_number: 1,
+                    width: 2480,
+                    height: 3508,
+                },
+                OcrPageDimensions {
+                    page_number: 2,
+                    width: 2480,
+                    height: 3508,
+                },
+            ],
+        );
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!((pages[0].width, pages[0].height), (2480, 3508));
+        assert!(pages[1].blocks.is_empty());
+    }
+
+    #[test]
+    fn test_build_layout_groups_orphan_words_by_parent() {
+        let words: Vec<OcrElement> = tesseract_page()
+            .into_iter()
+            .filter(|element| element.level == OcrElementLevel::Word)
+            .collect();
+        let pages = build_layout(&words, &[]);
+
+        assert_eq!(pages[0].blocks.len(), 1);
+        assert_eq!(pages[0].blocks[0].lines.len(), 2);
+        assert_eq!(pages[0].blocks[0].lines[1].text(), "Second line");
+    }
+
+    #[test]
+    fn test_split_line_keeps_rotated_text_whole() {
+        let line = OcrElement::new(
+            "upside down",
+            OcrBoundingGeometry::Quadrilateral {
+                points: [(300, 80), (100, 80), (100, 50), (300, 50)],
+            },
+            OcrConfidence::from_paddle(0.9, 0.8),
+        )
+        .with_rotation(OcrRotation {
+            angle_degrees: 180.0,
+            confidence: None,
+        });
+        let layout = split_line(&line).unwrap();
+
+        assert_eq!(layout.words.len(), 1);
+        assert_eq!(layout.words[0].text, "upside down");
+        assert_eq!(layout.angle, 180.0);
+        assert_eq!(layout.polygon[0], (300, 80));
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("a<b & \"c\"'"), "a&lt;b &amp; &quot;c&quot;&apos;");
+        assert_eq!(escape_xml("bell\u{7}"), "bell");
+    }
+
+    #[test]
+    fn test_counterclockwise_and_format_number() {
+        assert_eq!(counterclockwise(90.0), 270.0);
+        assert_eq!(counterclockwise(0.0), 0.0);
+        assert_eq!(format_number(0.95), "0.95");
+        assert_eq!(format_number(0.9), "0.9");
+        assert_eq!(format_number(90.0), "90");
+    }
+}
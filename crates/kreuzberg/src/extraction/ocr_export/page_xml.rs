@@ -0,0 +1,275 @@
+//! PRImA PAGE XML (2019-07-15) writer.
+
+use super::{LayoutPage, OcrPageDimensions, build_layout, escape_xml, format_number};
+use crate::types::OcrElement;
+use std::fmt::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const PAGE_NAMESPACE: &str = "http://schema.primaresearch.org/PAGE/gts/pagecontent/2019-07-15";
+const PAGE_SCHEMA_LOCATION: &str = "http://schema.primaresearch.org/PAGE/gts/pagecontent/2019-07-15/pagecontent.xsd";
+
+/// Serialize OCR elements to PAGE XML, one document per page.
+///
+/// PAGE allows a single `Page` per `PcGts` document, so the result holds one
+/// document per page in page order. Blocks become `TextRegion`s containing
+/// `TextLine` and `Word` elements with `Coords` polygons and `TextEquiv` text;
+/// confidences go in the `conf` attribute of `TextEquiv`.
+///
+/// `imageFilename` is required by the schema but unknown here; it is set to
+/// `page_<n>` and can be replaced by the caller.
+pub fn to_page_xml(elements: &[OcrElement], pages: &[OcrPageDimensions]) -> Vec<String> {
+    let timestamp = xsd_date_time(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    );
+    build_layout(elements, pages)
+        .iter()
+        .map(|page| write_document(page, &timestamp))
+        .collect()
+}
+
+fn write_document(page: &LayoutPage, timestamp: &str) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<PcGts xmlns=\"{ns}\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:schemaLocation=\"{ns} {schema}\">",
+        ns = PAGE_NAMESPACE,
+        schema = PAGE_SCHEMA_LOCATION
+    );
+    xml.push_str("  <Metadata>\n");
+    let _ = writeln!(xml, "    <Creator>kreuzberg {}</Creator>", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(xml, "    <Created>{timestamp}</Created>");
+    let _ = writeln!(xml, "    <LastChange>{timestamp}</LastChange>");
+    xml.push_str("  </Metadata>\n");
+    let _ = writeln!(
+        xml,
+        "  <Page imageFilename=\"page_{}\" imageWidth=\"{}\" imageHeight=\"{}\">",
+        page.number, page.width, page.height
+    );
+
+    for (b, block) in page.blocks.iter().enumerate() {
+        let region_id = format!("r{}", b + 1);
+        // PAGE orientation is the clockwise rotation that corrects the text, in (-180, 180].
+        let orientation = block
+            .angle()
+            .map(|angle| {
+                let correction = -angle;
+                let correction = if correction <= -180.0 {
+                    correction + 360.0
+                } else {
+                    correction
+                };
+                format!(" orientation=\"{}\"", format_number(correction))
+            })
+            .unwrap_or_default();
+        let _ = writeln!(xml, "    <TextRegion id=\"{region_id}\"{orientation}>");
+        let _ = writeln!(xml, "      <Coords points=\"{}\"/>", points(&block.bbox.corners()));
+
+        for (l, line) in block.lines.iter().enumerate() {
+            let line_id = format!("{region_id}_l{}", l + 1);
+            let _ = writeln!(xml, "      <TextLine id=\"{line_id}\">");
+            let _ = writeln!(xml, "        <Coords points=\"{}\"/>", points(&line.polygon));
+
+            for (w, word) in line.words.iter().enumerate() {
+                let _ = writeln!(xml, "        <Word id=\"{line_id}_w{}\">", w + 1);
+                let _ = writeln!(xml, "          <Coords points=\"{}\"/>", points(&word.bbox.corners()));
+                let _ = writeln!(
+                    xml,
+                    "          <TextEquiv conf=\"{}\"><Unicode>{}</Unicode></TextEquiv>",
+                    format_number(word.confidence.clamp(0.0, 1.0)),
+                    escape_xml(&word.text)
+                );
+                xml.push_str("        </Word>\n");
+            }
+
+            let _ = writeln!(
+                xml,
+                "        <TextEquiv><Unicode>{}</Unicode></TextEquiv>",
+                escape_xml(&line.text())
+            );
+            xml.push_str("      </TextLine>\n");
+        }
+
+        let region_text = block
+            .lines
+            .iter()
+            .map(|line| line.text())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let _ = writeln!(
+            xml,
+            "      <TextEquiv><Unicode>{}</Unicode></TextEquiv>",
+            escape_xml(&region_text)
+        );
+        xml.push_str("    </TextRegion>\n");
+    }
+
+    xml.push_str("  </Page>\n");
+    xml.push_str("</PcGts>\n");
+    xml
+}
+
+fn points(corners: &[(u32, u32); 4]) -> String {
+    corners
+        .iter()
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Format seconds since the Unix epoch as an `xsd:dateTime` in UTC.
+fn xsd_date_time(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let seconds_of_day = secs % 86_400;
+
+    // Civil date from days since 1970-01-01 (proleptic Gregorian calendar).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrBoundingGeometry, OcrConfidence, OcrElementLevel, OcrRotation};
+    use std::collections::HashSet;
+
+    fn line(text: &str, top: u32, page: usize) -> OcrElement {
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Rectangle {
+                left: 20,
+                top,
+                width: 400,
+                height: 40,
+            },
+            OcrConfidence::from_paddle(0.95, 0.87),
+        )
+        .with_level(OcrElementLevel::Line)
+        .with_page_number(page)
+    }
+
+    fn children<'a>(node: roxmltree::Node<'a, 'a>) -> Vec<&'a str> {
+        node.children()
+            .filter(|n| n.is_element())
+            .map(|n| n.tag_name().name())
+            .collect()
+    }
+
+    #[test]
+    fn test_to_page_xml_structure() {
+        let documents = to_page_xml(
+            &[line("Dear <Sir>", 30, 1), line("Regards", 100, 1)],
+            &[OcrPageDimensions {
+                page_number: 1,
+                width: 1240,
+                height: 1754,
+            }],
+        );
+        assert_eq!(documents.len(), 1);
+
+        let doc = roxmltree::Document::parse(&documents[0]).unwrap();
+        let root = doc.root_element();
+        assert_eq!(root.tag_name().name(), "PcGts");
+        assert_eq!(root.tag_name().namespace(), Some(PAGE_NAMESPACE));
+        assert_eq!(children(root), ["Metadata", "Page"]);
+
+        let metadata = root.first_element_child().unwrap();
+        assert_eq!(children(metadata), ["Creator", "Created", "LastChange"]);
+
+        let page = doc.descendants().find(|n| n.has_tag_name("Page")).unwrap();
+        assert_eq!(page.attribute("imageWidth"), Some("1240"));
+        assert_eq!(page.attribute("imageHeight"), Some("1754"));
+        assert!(page.attribute("imageFilename").is_some());
+
+        let regions: Vec<_> = page.children().filter(|n| n.has_tag_name("TextRegion")).collect();
+        assert_eq!(regions.len(), 2);
+        assert_eq!(children(regions[0]), ["Coords", "TextLine", "TextEquiv"]);
+
+        let text_line = regions[0].children().find(|n| n.has_tag_name("TextLine")).unwrap();
+        assert_eq!(children(text_line), ["Coords", "Word", "Word", "TextEquiv"]);
+        let word = text_line.children().find(|n| n.has_tag_name("Word")).unwrap();
+        assert_eq!(children(word), ["Coords", "TextEquiv"]);
+    }
+
+    #[test]
+    fn test_to_page_xml_text_and_coordinates() {
+        let documents = to_page_xml(&[line("Dear <Sir>", 30, 1)], &[]);
+        let doc = roxmltree::Document::parse(&documents[0]).unwrap();
+
+        let text_line = doc.descendants().find(|n| n.has_tag_name("TextLine")).unwrap();
+        let coords = text_line.first_element_child().unwrap();
+        assert_eq!(coords.attribute("points"), Some("20,30 420,30 420,70 20,70"));
+
+        let unicode: Vec<&str> = doc
+            .descendants()
+            .filter(|n| n.has_tag_name("Unicode"))
+            .filter_map(|n| n.text())
+            .collect();
+        assert_eq!(unicode, ["Dear", "<Sir>", "Dear <Sir>", "Dear <Sir>"]);
+
+        let word_equiv = doc.descendants().find(|n| n.has_tag_name("TextEquiv")).unwrap();
+        assert_eq!(word_equiv.attribute("conf"), Some("0.87"));
+    }
+
+    #[test]
+    fn test_to_page_xml_one_document_per_page_with_unique_ids() {
+        let documents = to_page_xml(&[line("one", 10, 1), line("two", 10, 2), line("three", 60, 2)], &[]);
+        assert_eq!(documents.len(), 2);
+
+        let doc = roxmltree::Document::parse(&documents[1]).unwrap();
+        let ids: Vec<&str> = doc.descendants().filter_map(|n| n.attribute("id")).collect();
+        let unique: HashSet<&str> = ids.iter().copied().collect();
+        assert_eq!(ids.len(), unique.len());
+        assert_eq!(doc.descendants().filter(|n| n.has_tag_name("TextRegion")).count(), 2);
+    }
+
+    #[test]
+    fn test_to_page_xml_orientation_corrects_rotation() {
+        let rotated = line("tilted", 10, 1).with_rotation(OcrRotation {
+            angle_degrees: 90.0,
+            confidence: None,
+        });
+        let upside_down = line("flipped", 200, 1)
+            .with_rotation(OcrRotation {
+                angle_degrees: 180.0,
+                confidence: None,
+            })
+            .with_parent_id("other");
+        let documents = to_page_xml(&[rotated, upside_down], &[]);
+        let doc = roxmltree::Document::parse(&documents[0]).unwrap();
+
+        let orientations: Vec<&str> = doc
+            .descendants()
+            .filter(|n| n.has_tag_name("TextRegion"))
+            .filter_map(|n| n.attribute("orientation"))
+            .collect();
+        assert_eq!(orientations, ["-90", "180"]);
+    }
+
+    #[test]
+    fn test_xsd_date_time() {
+        assert_eq!(xsd_date_time(0), "1970-01-01T00:00:00Z");
+        assert_eq!(xsd_date_time(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(xsd_date_time(1_700_000_000), "2023-11-14T22:13:20Z");
+    }
+}
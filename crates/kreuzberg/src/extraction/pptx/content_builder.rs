@@ -68,6 +68,9 @@ impl ContentBuilder {
                 images: Vec::new(),
                 hierarchy: None,
                 is_blank,
+                language: None,
+                language_confidence: None,
+                text_source: None,
             });
         }
     }
@@ -0,0 +1,576 @@
+//! Cross-page table stitching.
+//!
+//! Paginated extractors (PDF, OCR) report tables per page, so a long table comes
+//! back as one fragment per page. [`stitch_tables`] joins a table with the first
+//! table on the following page when that one continues it:
+//!
+//! - both have the same number of columns,
+//! - their column boundaries line up (when both carry cell geometry) or the
+//!   continuation repeats the header rows,
+//! - no heading appears after the table on its page or before the continuation
+//!   on the next page.
+//!
+//! The merged table keeps the first fragment's `page_number` and records the last
+//! page in `page_end`. Repeated header rows are dropped. Cell bounding boxes of
+//! continued rows are cleared, as they are relative to a different page.
+
+use crate::types::{ExtractionResult, GridCell, Table, TableGrid};
+use std::sync::Arc;
+
+/// Maximum difference between column boundaries of two fragments, in PDF points.
+const COLUMN_POSITION_TOLERANCE: f64 = 3.0;
+
+/// Text of one page and the headings found on it.
+#[derive(Debug)]
+struct PageText<'a> {
+    page_number: usize,
+    text: &'a str,
+    headings: Vec<&'a str>,
+}
+
+/// Merge tables in `result.tables` that continue across page breaks.
+///
+/// Per-page `tables` in `result.pages` are updated to match: the merged table is
+/// listed on its first page and no longer on the pages it continues onto.
+/// Tables of results without page information are only checked for column and
+/// header continuity.
+pub fn stitch_tables(result: &mut ExtractionResult) {
+    if result.tables.len() < 2 {
+        return;
+    }
+
+    let tables = std::mem::take(&mut result.tables);
+    let original_count = tables.len();
+    let stitched = {
+        let pages = page_texts(result);
+        merge_continued_tables(tables, &pages)
+    };
+
+    if stitched.len() != original_count
+        && let Some(pages) = result.pages.as_mut()
+    {
+        for page in pages.iter_mut() {
+            page.tables = stitched
+                .iter()
+                .filter(|table| table.page_number == page.page_number)
+                .map(|table| Arc::new(table.clone()))
+                .collect();
+        }
+    }
+    result.tables = stitched;
+}
+
+fn merge_continued_tables(mut tables: Vec<Table>, pages: &[PageText<'_>]) -> Vec<Table> {
+    tables.sort_by_key(|table| table.page_number);
+
+    let mut stitched: Vec<Table> = Vec::with_capacity(tables.len());
+    let mut previous_page = None;
+    for table in tables {
+        let first_on_page = previous_page != Some(table.page_number);
+        previous_page = Some(table.page_number);
+
+        if first_on_page
+            && let Some(head) = stitched.last_mut()
+            && let Some(repeated_header_rows) = continuation(head, &table, pages)
+        {
+            append_fragment(head, table, repeated_header_rows);
+            continue;
+        }
+        stitched.push(table);
+    }
+    stitched
+}
+
+/// If `next` continues `previous`, the number of repeated header rows to drop.
+fn continuation(previous: &Table, next: &Table, pages: &[PageText<'_>]) -> Option<usize> {
+    let last_page = previous.page_end.unwrap_or(previous.page_number);
+    if next.page_number != last_page + 1 {
+        return None;
+    }
+
+    let columns = column_count(previous);
+    if columns == 0 || column_count(next) != columns {
+        return None;
+    }
+
+    let header_rows = header_row_count(previous);
+    let repeated = header_rows > 0
+        && next.cells.len() > header_rows
+        && previous.cells[..header_rows]
+            .iter()
+            .zip(&next.cells[..header_rows])
+            .all(|(a, b)| same_row(a, b));
+    if !repeated && !columns_aligned(previous, next) {
+        return None;
+    }
+
+    if heading_between(previous, next, last_page, pages) {
+        return None;
+    }
+
+    Some(if repeated { header_rows } else { 0 })
+}
+
+fn column_count(table: &Table) -> usize {
+    table
+        .grid
+        .as_ref()
+        .map(|grid| grid.cols as usize)
+        .unwrap_or_else(|| table.cells.iter().map(Vec::len).max().unwrap_or(0))
+}
+
+/// Header rows: those covered by header cells of the grid, else the first row.
+fn header_row_count(table: &Table) -> usize {
+    match &table.grid {
+        Some(grid) => grid
+            .cells
+            .iter()
+            .filter(|cell| cell.is_header)
+            .map(|cell| (cell.row + cell.row_span) as usize)
+            .max()
+            .unwrap_or(0),
+        None => usize::from(!table.cells.is_empty()),
+    }
+}
+
+fn same_row(a: &[String], b: &[String]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| normalize(a) == normalize(b))
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Whether both tables have cell geometry with matching column boundaries.
+fn columns_aligned(previous: &Table, next: &Table) -> bool {
+    let (Some(a), Some(b)) = (
+        previous.grid.as_ref().and_then(column_edges),
+        next.grid.as_ref().and_then(column_edges),
+    ) else {
+        return false;
+    };
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let pairs: Vec<(f64, f64)> = a.iter().zip(&b).filter_map(|(a, b)| Some(((*a)?, (*b)?))).collect();
+    pairs.len() >= 2 && pairs.iter().all(|(a, b)| (a - b).abs() <= COLUMN_POSITION_TOLERANCE)
+}
+
+/// Left edge of every column plus the right edge of the last one, where known.
+fn column_edges(grid: &TableGrid) -> Option<Vec<Option<f64>>> {
+    let mut edges = vec![None; grid.cols as usize + 1];
+    for cell in &grid.cells {
+        let Some(bbox) = cell.bbox else {
+            continue;
+        };
+        let (start, end) = (cell.col as usize, (cell.col + cell.col_span) as usize);
+        if end < edges.len() {
+            edges[start].get_or_insert(bbox.x0);
+            edges[end].get_or_insert(bbox.x1);
+        }
+    }
+    edges.iter().any(Option::is_some).then_some(edges)
+}
+
+/// Whether a heading follows `previous` on its last page or precedes `next` on its page.
+///
+/// Positions are found by searching the page text for the first cell text of
+/// the table row next to the page break; when it cannot be found, that page is
+/// not checked.
+fn heading_between(previous: &Table, next: &Table, last_page: usize, pages: &[PageText<'_>]) -> bool {
+    let page = |number: usize| pages.iter().find(|page| page.page_number == number);
+
+    if let Some(page) = page(last_page)
+        && let Some(anchor) = previous
+            .cells
+            .last()
+            .and_then(|row| row_anchor(row))
+            .and_then(|text| page.text.rfind(text))
+        && page
+            .headings
+            .iter()
+            .any(|heading| page.text.rfind(heading).is_some_and(|position| position > anchor))
+    {
+        return true;
+    }
+
+    if let Some(page) = page(next.page_number)
+        && let Some(anchor) = next
+            .cells
+            .first()
+            .and_then(|row| row_anchor(row))
+            .and_then(|text| page.text.find(text))
+        && page
+            .headings
+            .iter()
+            .any(|heading| page.text.find(heading).is_some_and(|position| position < anchor))
+    {
+        return true;
+    }
+
+    false
+}
+
+fn row_anchor(row: &[String]) -> Option<&str> {
+    row.iter().map(|cell| cell.trim()).find(|text| !text.is_empty())
+}
+
+fn append_fragment(head: &mut Table, tail: Table, repeated_header_rows: usize) {
+    head.markdown = append_markdown_rows(&head.markdown, &tail.markdown, repeated_header_rows);
+
+    head.grid = match (head.grid.take(), tail.grid) {
+        (Some(mut grid), Some(tail_grid)) => {
+            let skip = repeated_header_rows as u32;
+            let offset = grid.rows;
+            grid.cells.extend(
+                tail_grid
+                    .cells
+                    .into_iter()
+                    .filter(|cell| cell.row >= skip)
+                    .map(|cell| GridCell {
+                        row: cell.row - skip + offset,
+                        is_header: false,
+                        bbox: None,
+                        ..cell
+                    }),
+            );
+            grid.rows += tail_grid.rows.saturating_sub(skip);
+            Some(grid)
+        }
+        _ => None,
+    };
+
+    head.cells.extend(tail.cells.into_iter().skip(repeated_header_rows));
+    head.page_end = Some(tail.page_end.unwrap_or(tail.page_number));
+}
+
+/// Append the rows of the Markdown table `tail` to `head`, skipping its first `skip_rows` rows.
+fn append_markdown_rows(head: &str, tail: &str, skip_rows: usize) -> String {
+    let lines: Vec<&str> = tail.lines().filter(|line| !line.trim().is_empty()).collect();
+    let has_separator = lines
+        .get(1)
+        .is_some_and(|line| line.contains('-') && line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t')));
+
+    let mut markdown = head.trim_end().to_string();
+    for (index, line) in lines.iter().enumerate() {
+        if has_separator && index == 1 {
+            continue;
+        }
+        let row = if has_separator && index > 1 { index - 1 } else { index };
+        if row < skip_rows {
+            continue;
+        }
+        markdown.push('\n');
+        markdown.push_str(line);
+    }
+    if head.ends_with('\n') {
+        markdown.push('\n');
+    }
+    markdown
+}
+
+/// Page texts from `result.pages`, or from `content` split at the page boundaries.
+fn page_texts(result: &ExtractionResult) -> Vec<PageText<'_>> {
+    if let Some(pages) = &result.pages {
+        return pages
+            .iter()
+            .map(|page| {
+                let mut headings = markdown_headings(&page.content);
+                if let Some(hierarchy) = &page.hierarchy {
+                    headings.extend(
+                        hierarchy
+                            .blocks
+                            .iter()
+                            .filter(|block| is_heading_level(&block.level))
+                            .map(|block| block.text.trim())
+                            .filter(|text| !text.is_empty()),
+                    );
+                }
+                PageText {
+                    page_number: page.page_number,
+                    text: &page.content,
+                    headings,
+                }
+            })
+            .collect();
+    }
+
+    let Some(boundaries) = result
+        .metadata
+        .pages
+        .as_ref()
+        .and_then(|pages| pages.boundaries.as_ref())
+    else {
+        return Vec::new();
+    };
+    boundaries
+        .iter()
+        .filter_map(|boundary| {
+            let text = result.content.get(boundary.byte_start..boundary.byte_end)?;
+            Some(PageText {
+                page_number: boundary.page_number,
+                text,
+                headings: markdown_headings(text),
+            })
+        })
+        .collect()
+}
+
+fn is_heading_level(level: &str) -> bool {
+    matches!(level, "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+}
+
+/// ATX heading lines (`# Title`) in Markdown page content.
+fn markdown_headings(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| {
+            let hashes = line.chars().take_while(|&c| c == '#').count();
+            (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') && !line[hashes..].trim().is_empty()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BoundingBox, HierarchicalBlock, PageContent, PageHierarchy};
+
+    fn rows(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect()
+    }
+
+    fn markdown(cells: &[Vec<String>]) -> String {
+        let mut markdown = String::new();
+        for (index, row) in cells.iter().enumerate() {
+            markdown.push_str(&format!("| {} |\n", row.join(" | ")));
+            if index == 0 {
+                markdown.push_str(&format!("|{}\n", "------|".repeat(row.len())));
+            }
+        }
+        markdown
+    }
+
+    fn table(page_number: usize, cells: &[&[&str]]) -> Table {
+        let cells = rows(cells);
+        Table {
+            markdown: markdown(&cells),
+            cells,
+            page_number,
+            grid: None,
+            page_end: None,
+        }
+    }
+
+    /// A table with a grid whose columns start at the given x positions.
+    fn ruled_table(page_number: usize, xs: &[f64], cells: &[&[&str]]) -> Table {
+        let mut table = table(page_number, cells);
+        let grid_cells = table
+            .cells
+            .iter()
+            .enumerate()
+            .flat_map(|(row, cells)| {
+                cells.iter().enumerate().map(move |(col, content)| GridCell {
+                    content: content.clone(),
+                    row: row as u32,
+                    col: col as u32,
+                    row_span: 1,
+                    col_span: 1,
+                    is_header: row == 0,
+                    bbox: Some(BoundingBox {
+                        x0: xs[col],
+                        y0: 700.0 - 20.0 * (row as f64 + 1.0),
+                        x1: xs[col + 1],
+                        y1: 700.0 - 20.0 * row as f64,
+                    }),
+                })
+            })
+            .collect();
+        table.grid = Some(TableGrid {
+            rows: table.cells.len() as u32,
+            cols: (xs.len() - 1) as u32,
+            cells: grid_cells,
+        });
+        table
+    }
+
+    fn page(page_number: usize, content: &str, headings: &[&str]) -> PageContent {
+        PageContent {
+            page_number,
+            content: content.to_string(),
+            tables: Vec::new(),
+            images: Vec::new(),
+            hierarchy: Some(PageHierarchy {
+                block_count: headings.len(),
+                blocks: headings
+                    .iter()
+                    .map(|text| HierarchicalBlock {
+                        text: text.to_string(),
+                        font_size: 18.0,
+                        level: "h2".to_string(),
+                        bbox: None,
+                    })
+                    .collect(),
+            }),
+            is_blank: None,
+            language: None,
+            language_confidence: None,
+            text_source: None,
+        }
+    }
+
+    #[test]
+    fn test_stitch_tables_drops_repeated_header() {
+        let mut result = ExtractionResult {
+            tables: vec![
+                table(1, &[&["Year", "Revenue"], &["2020", "10"]]),
+                table(2, &[&["Year", "Revenue"], &["2021", "12"]]),
+                table(3, &[&["Year", "Revenue"], &["2022", "15"]]),
+            ],
+            ..Default::default()
+        };
+        stitch_tables(&mut result);
+
+        assert_eq!(result.tables.len(), 1);
+        let table = &result.tables[0];
+        assert_eq!((table.page_number, table.page_end), (1, Some(3)));
+        assert_eq!(
+            table.cells,
+            rows(&[&["Year", "Revenue"], &["2020", "10"], &["2021", "12"], &["2022", "15"]])
+        );
+        assert_eq!(table.markdown, markdown(&table.cells));
+    }
+
+    #[test]
+    fn test_stitch_tables_aligned_columns_without_header() {
+        let xs = [50.0, 200.0, 400.0];
+        let mut result = ExtractionResult {
+            tables: vec![
+                ruled_table(4, &xs, &[&["Item", "Amount"], &["Rent", "900"]]),
+                ruled_table(5, &[51.0, 199.5, 401.0], &[&["Power", "80"], &["Water", "30"]]),
+            ],
+            ..Default::default()
+        };
+        stitch_tables(&mut result);
+
+        assert_eq!(result.tables.len(), 1);
+        let grid = result.tables[0].grid.as_ref().unwrap();
+        assert_eq!((grid.rows, grid.cols), (4, 2));
+        let continued: Vec<_> = grid.cells.iter().filter(|cell| cell.row >= 2).collect();
+        assert_eq!(continued.len(), 4);
+        assert_eq!(continued[0].content, "Power");
+        assert!(continued.iter().all(|cell| !cell.is_header && cell.bbox.is_none()));
+        assert!(grid.cells[0].bbox.is_some());
+    }
+
+    #[test]
+    fn test_stitch_tables_requires_matching_columns() {
+        let mut result = ExtractionResult {
+            tables: vec![
+                ruled_table(1, &[50.0, 200.0, 400.0], &[&["A", "B"], &["1", "2"]]),
+                ruled_table(2, &[50.0, 300.0, 400.0], &[&["3", "4"]]),
+                table(3, &[&["A", "B", "C"], &["5", "6", "7"]]),
+            ],
+            ..Default::default()
+        };
+        stitch_tables(&mut result);
+        assert_eq!(result.tables.len(), 3);
+        assert!(result.tables.iter().all(|table| table.page_end.is_none()));
+    }
+
+    #[test]
+    fn test_stitch_tables_only_adjacent_pages_and_edge_tables() {
+        let mut result = ExtractionResult {
+            tables: vec![
+                table(1, &[&["A", "B"], &["1", "2"]]),
+                table(3, &[&["A", "B"], &["3", "4"]]),
+                table(3, &[&["A", "B"], &["5", "6"]]),
+            ],
+            ..Default::default()
+        };
+        stitch_tables(&mut result);
+        assert_eq!(result.tables.len(), 3);
+    }
+
+    #[test]
+    fn test_stitch_tables_stops_at_heading() {
+        let tables = vec![
+            table(1, &[&["Year", "Revenue"], &["2020", "10"]]),
+            table(2, &[&["Year", "Revenue"], &["2021", "12"]]),
+        ];
+        let mut result = ExtractionResult {
+            tables: tables.clone(),
+            pages: Some(vec![
+                page(1, "Year Revenue\n2020 10\n", &[]),
+                page(2, "Appendix B\nYear Revenue\n2021 12\n", &["Appendix B"]),
+            ]),
+            ..Default::default()
+        };
+        stitch_tables(&mut result);
+        assert_eq!(result.tables.len(), 2);
+
+        // A heading after the continuation does not matter.
+        let mut result = ExtractionResult {
+            tables,
+            pages: Some(vec![
+                page(1, "# Results\nYear Revenue\n2020 10\n", &[]),
+                page(2, "Year Revenue\n2021 12\nAppendix B\n", &["Appendix B"]),
+            ]),
+            ..Default::default()
+        };
+        stitch_tables(&mut result);
+        assert_eq!(result.tables.len(), 1);
+        let pages = result.pages.unwrap();
+        assert_eq!(pages[0].tables.len(), 1);
+        assert_eq!(pages[0].tables[0].page_end, Some(2));
+        assert!(pages[1].tables.is_empty());
+    }
+
+    #[test]
+    fn test_stitch_tables_heading_after_table_on_first_page() {
+        let mut result = ExtractionResult {
+            content: "Year Revenue\n2020 10\n## Costs\nYear Revenue\n2021 12\n".to_string(),
+            tables: vec![
+                table(1, &[&["Year", "Revenue"], &["2020", "10"]]),
+                table(2, &[&["Year", "Revenue"], &["2021", "12"]]),
+            ],
+            ..Default::default()
+        };
+        result.metadata.pages = Some(crate::types::PageStructure {
+            total_count: 2,
+            unit_type: crate::types::PageUnitType::Page,
+            boundaries: Some(vec![
+                crate::types::PageBoundary {
+                    byte_start: 0,
+                    byte_end: 30,
+                    page_number: 1,
+                },
+                crate::types::PageBoundary {
+                    byte_start: 30,
+                    byte_end: 51,
+                    page_number: 2,
+                },
+            ]),
+            pages: None,
+        });
+        stitch_tables(&mut result);
+        assert_eq!(result.tables.len(), 2);
+    }
+
+    #[test]
+    fn test_append_markdown_rows() {
+        let head = "| A | B |\n|---|---|\n| 1 | 2 |\n";
+        let tail = "| A | B |\n|---|---|\n| 3 | 4 |\n";
+        assert_eq!(
+            append_markdown_rows(head, tail, 1),
+            "| A | B |\n|---|---|\n| 1 | 2 |\n| 3 | 4 |\n"
+        );
+        assert_eq!(
+            append_markdown_rows(head, "| 5 | 6 |\n|---|---|\n| 7 | 8 |", 0),
+            "| A | B |\n|---|---|\n| 1 | 2 |\n| 5 | 6 |\n| 7 | 8 |\n"
+        );
+    }
+}
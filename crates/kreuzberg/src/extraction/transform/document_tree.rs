@@ -7,7 +7,7 @@
 use crate::types::document_structure::GridCell;
 use crate::types::{
     BoundingBox, ContentLayer, DocumentNode, DocumentStructure, ExtractionResult, NodeContent, NodeId, NodeIndex,
-    TableGrid,
+    Table, TableGrid,
 };
 
 use super::elements::detect_list_items;
@@ -58,14 +58,7 @@ pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStr
             // Process tables
             for table_arc in &page.tables {
                 let table = table_arc.as_ref();
-                let grid = table_cells_to_grid(&table.cells);
-                push_content_node(
-                    &mut doc,
-                    &section_stack,
-                    NodeContent::Table { grid },
-                    Some(page_num),
-                    None,
-                );
+                push_table_node(&mut doc, &section_stack, table, page_num);
             }
 
             // Process images
@@ -101,14 +94,7 @@ pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStr
 
         // Process global tables
         for table in &result.tables {
-            let grid = table_cells_to_grid(&table.cells);
-            push_content_node(
-                &mut doc,
-                &section_stack,
-                NodeContent::Table { grid },
-                Some(table.page_number as u32),
-                None,
-            );
+            push_table_node(&mut doc, &section_stack, table, table.page_number as u32);
         }
 
         // Process global images
@@ -350,6 +336,18 @@ fn add_paragraphs(doc: &mut DocumentStructure, section_stack: &[(u8, NodeIndex)]
 // Table Conversion
 // ============================================================================
 
+/// Push a table node, spanning to `page_end` for tables continued across pages.
+fn push_table_node(doc: &mut DocumentStructure, section_stack: &[(u8, NodeIndex)], table: &Table, page: u32) {
+    let grid = table_grid(table);
+    let index = push_content_node(doc, section_stack, NodeContent::Table { grid }, Some(page), None);
+    doc.nodes[index.0 as usize].page_end = table.page_end.map(|page_end| page_end as u32);
+}
+
+/// Use the table's own grid when the extractor recovered one, else derive it from the cells.
+fn table_grid(table: &Table) -> TableGrid {
+    table.grid.clone().unwrap_or_else(|| table_cells_to_grid(&table.cells))
+}
+
 /// Convert a `Vec<Vec<String>>` cell grid into a `TableGrid`.
 fn table_cells_to_grid(cells: &[Vec<String>]) -> TableGrid {
     let rows = cells.len() as u32;
@@ -484,6 +482,7 @@ mod tests {
             metadata: test_metadata(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -495,6 +494,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         }
     }
 
@@ -565,6 +565,9 @@ mod tests {
                     ],
                 }),
                 is_blank: None,
+                language: None,
+                language_confidence: None,
+                text_source: None,
             }]),
             ..test_result("")
         };
@@ -618,6 +621,9 @@ mod tests {
                     ],
                 }),
                 is_blank: None,
+                language: None,
+                language_confidence: None,
+                text_source: None,
             }]),
             ..test_result("")
         };
@@ -657,6 +663,9 @@ mod tests {
                     ],
                 }),
                 is_blank: None,
+                language: None,
+                language_confidence: None,
+                text_source: None,
             }]),
             ..test_result("")
         };
@@ -694,6 +703,8 @@ mod tests {
                 ],
                 markdown: "| Name | Age |\n|---|---|\n| Alice | 30 |".to_string(),
                 page_number: 1,
+                grid: None,
+                page_end: None,
             }],
             ..test_result("Some content")
         };
@@ -717,6 +728,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_table_node_uses_table_grid_and_page_end() {
+        let grid = TableGrid {
+            rows: 2,
+            cols: 2,
+            cells: vec![GridCell {
+                content: "Merged".to_string(),
+                row: 0,
+                col: 0,
+                row_span: 2,
+                col_span: 2,
+                is_header: false,
+                bbox: None,
+            }],
+        };
+        let result = ExtractionResult {
+            tables: vec![Table {
+                cells: vec![
+                    vec!["Merged".to_string(), String::new()],
+                    vec![String::new(), String::new()],
+                ],
+                markdown: String::new(),
+                page_number: 2,
+                grid: Some(grid.clone()),
+                page_end: Some(4),
+            }],
+            ..test_result("Some content")
+        };
+
+        let doc = transform_to_document_structure(&result);
+        let table_node = doc
+            .nodes
+            .iter()
+            .find(|n| matches!(n.content, NodeContent::Table { .. }))
+            .unwrap();
+        assert_eq!(table_node.content, NodeContent::Table { grid });
+        assert_eq!((table_node.page, table_node.page_end), (Some(2), Some(4)));
+    }
+
     #[test]
     fn test_serde_roundtrip() {
         let result = test_result("Hello world.\n\n- Item 1\n- Item 2");
@@ -277,6 +277,7 @@ mod tests {
             metadata: test_metadata(Some("Test Document".to_string())),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -304,6 +305,9 @@ mod tests {
                         ],
                     }),
                     is_blank: None,
+                    language: None,
+                    language_confidence: None,
+                    text_source: None,
                 },
                 PageContent {
                     page_number: 2,
@@ -312,6 +316,9 @@ mod tests {
                     images: vec![],
                     hierarchy: None,
                     is_blank: None,
+                    language: None,
+                    language_confidence: None,
+                    text_source: None,
                 },
             ]),
             elements: None,
@@ -321,6 +328,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let elements = transform_extraction_result_to_elements(&result);
@@ -374,6 +382,8 @@ mod tests {
             ],
             markdown: "| Header1 | Header2 |\n| Cell1 | Cell2 |".to_string(),
             page_number: 1,
+            grid: None,
+            page_end: None,
         };
 
         let image = ExtractedImage {
@@ -396,6 +406,7 @@ mod tests {
             metadata: test_metadata(Some("Test".to_string())),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -406,6 +417,9 @@ mod tests {
                 images: vec![Arc::new(image)],
                 hierarchy: None,
                 is_blank: None,
+                language: None,
+                language_confidence: None,
+                text_source: None,
             }]),
             elements: None,
             ocr_elements: None,
@@ -414,6 +428,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let elements = transform_extraction_result_to_elements(&result);
@@ -451,6 +466,7 @@ mod tests {
             metadata: test_metadata(Some("Simple Doc".to_string())),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -462,6 +478,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let elements = transform_extraction_result_to_elements(&result);
@@ -489,6 +506,7 @@ mod tests {
             metadata: test_metadata(None),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -500,6 +518,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let elements = transform_extraction_result_to_elements(&result);
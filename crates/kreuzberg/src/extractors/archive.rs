@@ -3,16 +3,18 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::archive::{
-    ArchiveMetadata as ExtractedMetadata, extract_7z_metadata, extract_7z_text_content, extract_gzip,
-    extract_tar_metadata, extract_tar_text_content, extract_zip_metadata, extract_zip_text_content,
+    ArchiveMember, ArchiveMetadata as ExtractedMetadata, extract_7z_members, extract_7z_metadata,
+    extract_7z_text_content, extract_gzip, extract_gzip_members, extract_gzip_metadata, extract_tar_members,
+    extract_tar_metadata, extract_tar_text_content, extract_zip_members, extract_zip_metadata,
+    extract_zip_text_content,
 };
-use crate::extractors::security::ZipBombValidator;
+use crate::extraction::embedded::{EmbeddedDocument, extract_embedded_documents};
+use crate::extractors::security::{SecurityLimits, ZipBombValidator};
 use crate::plugins::{DocumentExtractor, Plugin};
 use crate::types::{ArchiveMetadata, ExtractionResult, Metadata};
 use ahash::AHashMap;
 use async_trait::async_trait;
 use std::borrow::Cow;
-use std::collections::HashMap;
 use std::io::Cursor;
 
 /// Build an ExtractionResult from archive metadata and text contents.
@@ -21,7 +23,8 @@ use std::io::Cursor;
 /// the logic for transforming extracted metadata into the final result structure.
 fn build_archive_result(
     extraction_metadata: ExtractedMetadata,
-    text_contents: HashMap<String, String>,
+    text_contents: impl IntoIterator<Item = (String, String)>,
+    contents_heading: &str,
     format_name: &'static str,
     mime_type: &str,
 ) -> ExtractionResult {
@@ -62,8 +65,9 @@ fn build_archive_result(
         output.push_str(&format!("- {} ({} bytes)\n", entry.path, entry.size));
     }
 
-    if !text_contents.is_empty() {
-        output.push_str("\n\nText File Contents:\n\n");
+    let mut text_contents = text_contents.into_iter().peekable();
+    if text_contents.peek().is_some() {
+        output.push_str(&format!("\n\n{}:\n\n", contents_heading));
         for (path, content) in text_contents {
             output.push_str(&format!("=== {} ===\n{}\n\n", path, content));
         }
@@ -79,6 +83,7 @@ fn build_archive_result(
         },
         tables: vec![],
         detected_languages: None,
+        language_spans: None,
         chunks: None,
         images: None,
         pages: None,
@@ -90,9 +95,75 @@ fn build_archive_result(
         extracted_keywords: None,
         quality_score: None,
         processing_warnings: Vec::new(),
+        children: None,
     }
 }
 
+/// Heading for text read directly from text-like members.
+const TEXT_CONTENTS_HEADING: &str = "Text File Contents";
+
+/// Heading for content of members extracted through the registry.
+const EXTRACTED_CONTENTS_HEADING: &str = "Extracted Contents";
+
+/// Whether archive members should be extracted through the extractor registry.
+fn is_recursive(config: &ExtractionConfig) -> bool {
+    config.archive_options.as_ref().is_some_and(|options| options.recursive)
+}
+
+/// Reject archives nested deeper than `max_archive_depth`.
+///
+/// Nested archives receive a configuration with one level less of depth
+/// budget, so an exhausted budget arrives here as zero.
+fn check_archive_depth(limits: &SecurityLimits) -> Result<()> {
+    if limits.max_archive_depth == 0 {
+        return Err(crate::error::KreuzbergError::validation(
+            "Archive nesting depth limit reached".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Build an ExtractionResult whose members were extracted through the extractor registry.
+///
+/// Every member becomes a child result; the content of successfully extracted
+/// members is also appended to the archive's own content so it stays searchable.
+async fn build_recursive_archive_result(
+    extraction_metadata: ExtractedMetadata,
+    members: Vec<ArchiveMember>,
+    format_name: &'static str,
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> ExtractionResult {
+    let documents = members
+        .into_iter()
+        .map(|member| EmbeddedDocument {
+            path: member.path,
+            data: member.data,
+        })
+        .collect();
+    let children = extract_embedded_documents(documents, config).await;
+
+    let contents: Vec<(String, String)> = children
+        .iter()
+        .filter_map(|child| {
+            child
+                .result
+                .as_ref()
+                .map(|result| (child.path.clone(), result.content.clone()))
+        })
+        .collect();
+
+    let mut result = build_archive_result(
+        extraction_metadata,
+        contents,
+        EXTRACTED_CONTENTS_HEADING,
+        format_name,
+        mime_type,
+    );
+    result.children = Some(children);
+    result
+}
+
 /// ZIP archive extractor.
 ///
 /// Extracts file lists and text content from ZIP archives.
@@ -164,10 +235,17 @@ impl DocumentExtractor for ZipExtractor {
             .map_err(|e| crate::error::KreuzbergError::validation(e.to_string()))?;
 
         let extraction_metadata = extract_zip_metadata(content, &limits)?;
+        if is_recursive(config) {
+            check_archive_depth(&limits)?;
+            let members = extract_zip_members(content, &limits)?;
+            return Ok(build_recursive_archive_result(extraction_metadata, members, "ZIP", mime_type, config).await);
+        }
+
         let text_contents = extract_zip_text_content(content, &limits)?;
         Ok(build_archive_result(
             extraction_metadata,
             text_contents,
+            TEXT_CONTENTS_HEADING,
             "ZIP",
             mime_type,
         ))
@@ -243,10 +321,17 @@ impl DocumentExtractor for TarExtractor {
     ) -> Result<ExtractionResult> {
         let limits = config.security_limits.clone().unwrap_or_default();
         let extraction_metadata = extract_tar_metadata(content, &limits)?;
+        if is_recursive(config) {
+            check_archive_depth(&limits)?;
+            let members = extract_tar_members(content, &limits)?;
+            return Ok(build_recursive_archive_result(extraction_metadata, members, "TAR", mime_type, config).await);
+        }
+
         let text_contents = extract_tar_text_content(content, &limits)?;
         Ok(build_archive_result(
             extraction_metadata,
             text_contents,
+            TEXT_CONTENTS_HEADING,
             "TAR",
             mime_type,
         ))
@@ -327,10 +412,17 @@ impl DocumentExtractor for SevenZExtractor {
     ) -> Result<ExtractionResult> {
         let limits = config.security_limits.clone().unwrap_or_default();
         let extraction_metadata = extract_7z_metadata(content, &limits)?;
+        if is_recursive(config) {
+            check_archive_depth(&limits)?;
+            let members = extract_7z_members(content, &limits)?;
+            return Ok(build_recursive_archive_result(extraction_metadata, members, "7Z", mime_type, config).await);
+        }
+
         let text_contents = extract_7z_text_content(content, &limits)?;
         Ok(build_archive_result(
             extraction_metadata,
             text_contents,
+            TEXT_CONTENTS_HEADING,
             "7Z",
             mime_type,
         ))
@@ -405,10 +497,18 @@ impl DocumentExtractor for GzipExtractor {
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let limits = config.security_limits.clone().unwrap_or_default();
+        if is_recursive(config) {
+            check_archive_depth(&limits)?;
+            let extraction_metadata = extract_gzip_metadata(content, &limits)?;
+            let members = extract_gzip_members(content, &limits)?;
+            return Ok(build_recursive_archive_result(extraction_metadata, members, "GZIP", mime_type, config).await);
+        }
+
         let (extraction_metadata, text_contents) = extract_gzip(content, &limits)?;
         Ok(build_archive_result(
             extraction_metadata,
             text_contents,
+            TEXT_CONTENTS_HEADING,
             "GZIP",
             mime_type,
         ))
@@ -585,4 +685,143 @@ mod tests {
             .await;
         assert!(result.is_err());
     }
+
+    fn zip_bytes(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+            for (name, data) in files {
+                zip.start_file(*name, options).unwrap();
+                zip.write_all(data).unwrap();
+            }
+            zip.finish().unwrap();
+        }
+        cursor.into_inner()
+    }
+
+    fn recursive_config() -> ExtractionConfig {
+        ExtractionConfig {
+            archive_options: Some(crate::core::config::ArchiveConfig { recursive: true }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zip_extractor_recursive() {
+        let inner = zip_bytes(&[("inner/deep.txt", b"Deep content")]);
+        let outer = zip_bytes(&[
+            ("readme.txt", b"Top level"),
+            ("data.json", br#"{"name": "kreuzberg"}"#),
+            ("nested.zip", &inner),
+        ]);
+
+        let result = ZipExtractor::new()
+            .extract_bytes(&outer, "application/zip", &recursive_config())
+            .await
+            .unwrap();
+
+        let children = result.children.as_ref().expect("children should be populated");
+        assert_eq!(children.len(), 3);
+        assert_eq!(children[0].path, "readme.txt");
+        assert_eq!(children[1].mime_type.as_deref(), Some("application/json"));
+
+        let nested = children[2].result.as_ref().expect("nested archive should be extracted");
+        assert_eq!(nested.mime_type, "application/zip");
+        let grandchildren = nested.children.as_ref().unwrap();
+        assert_eq!(grandchildren[0].path, "inner/deep.txt");
+        assert!(
+            grandchildren[0]
+                .result
+                .as_ref()
+                .unwrap()
+                .content
+                .contains("Deep content")
+        );
+
+        assert!(result.content.contains("Extracted Contents"));
+        assert!(result.content.contains("Top level"));
+        assert!(result.content.contains("Deep content"));
+    }
+
+    #[tokio::test]
+    async fn test_zip_extractor_non_recursive_has_no_children() {
+        let bytes = zip_bytes(&[("a.txt", b"alpha")]);
+        let result = ZipExtractor::new()
+            .extract_bytes(&bytes, "application/zip", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        assert!(result.children.is_none());
+        assert!(result.content.contains("Text File Contents"));
+    }
+
+    #[tokio::test]
+    async fn test_recursive_extraction_respects_depth_limit() {
+        let inner = zip_bytes(&[("deep.txt", b"Deep content")]);
+        let outer = zip_bytes(&[("top.txt", b"Top level"), ("nested.zip", &inner)]);
+
+        let mut config = recursive_config();
+        config.security_limits = Some(SecurityLimits {
+            max_archive_depth: 1,
+            ..Default::default()
+        });
+
+        let result = ZipExtractor::new()
+            .extract_bytes(&outer, "application/zip", &config)
+            .await
+            .unwrap();
+
+        let children = result.children.unwrap();
+        assert!(children[0].result.is_some());
+        assert!(children[1].result.is_none());
+        assert!(children[1].error.as_ref().unwrap().contains("depth"));
+    }
+
+    #[tokio::test]
+    async fn test_recursive_extraction_shares_size_budget() {
+        let inner = zip_bytes(&[("big.txt", &[b'x'; 4096])]);
+        let outer = zip_bytes(&[("nested.zip", &inner)]);
+
+        let mut config = recursive_config();
+        config.security_limits = Some(SecurityLimits {
+            max_archive_size: inner.len() + 1024,
+            max_compression_ratio: usize::MAX,
+            ..Default::default()
+        });
+
+        let result = ZipExtractor::new()
+            .extract_bytes(&outer, "application/zip", &config)
+            .await
+            .unwrap();
+
+        let children = result.children.unwrap();
+        assert!(children[0].result.is_none());
+        assert!(children[0].error.as_ref().unwrap().contains("too large"));
+    }
+
+    #[tokio::test]
+    async fn test_tar_extractor_recursive() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+            let data = br#"{"key": "value"}"#;
+            let mut header = tar::Header::new_gnu();
+            header.set_path("config.json").unwrap();
+            header.set_size(data.len() as u64);
+            header.set_cksum();
+            tar.append(&header, &data[..]).unwrap();
+            tar.finish().unwrap();
+        }
+
+        let result = TarExtractor::new()
+            .extract_bytes(&cursor.into_inner(), "application/x-tar", &recursive_config())
+            .await
+            .unwrap();
+
+        let children = result.children.unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].path, "config.json");
+        assert_eq!(children[0].mime_type.as_deref(), Some("application/json"));
+        assert!(children[0].result.is_some());
+    }
 }
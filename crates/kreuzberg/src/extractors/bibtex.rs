@@ -172,6 +172,7 @@ impl DocumentExtractor for BibtexExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -182,6 +183,7 @@ impl DocumentExtractor for BibtexExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -106,6 +106,7 @@ impl DocumentExtractor for CitationExtractor {
                     pages: None,
                     tables: vec![],
                     detected_languages: None,
+                    language_spans: None,
                     chunks: None,
                     images: None,
                     djot_content: None,
@@ -116,6 +117,7 @@ impl DocumentExtractor for CitationExtractor {
                     extracted_keywords: None,
                     quality_score: None,
                     processing_warnings: Vec::new(),
+                    children: None,
                 });
             }
         };
@@ -270,6 +272,7 @@ impl DocumentExtractor for CitationExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -280,6 +283,7 @@ impl DocumentExtractor for CitationExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -91,6 +91,8 @@ impl DocumentExtractor for CsvExtractor {
             cells: rows.clone(),
             markdown,
             page_number: 1,
+            grid: None,
+            page_end: None,
         };
 
         let row_count = rows.len();
@@ -120,6 +122,7 @@ impl DocumentExtractor for CsvExtractor {
             pages: None,
             tables: vec![table],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -130,6 +133,7 @@ impl DocumentExtractor for CsvExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
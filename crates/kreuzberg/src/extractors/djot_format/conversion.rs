@@ -156,6 +156,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -189,6 +190,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let markup = extraction_result_to_djot(&result).expect("Should convert");
@@ -203,6 +205,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -214,6 +217,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let markup = extraction_result_to_djot(&result).expect("Should convert");
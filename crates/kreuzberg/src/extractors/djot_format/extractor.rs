@@ -113,6 +113,7 @@ impl DocumentExtractor for DjotExtractor {
             metadata,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -124,6 +125,7 @@ impl DocumentExtractor for DjotExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -56,6 +56,8 @@ pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
                         cells,
                         markdown,
                         page_number: idx + 1,
+                        grid: None,
+                        page_end: None,
                     });
                     table_index += 1;
                 }
@@ -122,6 +122,7 @@ impl DocumentExtractor for DocExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: Some(vec![]),
             djot_content: None,
@@ -132,6 +133,7 @@ impl DocumentExtractor for DocExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -242,6 +242,8 @@ fn parse_docbook_single_pass(content: &str) -> Result<DocBookParseResult> {
                                 cells: current_table.clone(),
                                 markdown,
                                 page_number: table_index + 1,
+                                grid: None,
+                                page_end: None,
                             });
                             table_index += 1;
                             current_table.clear();
@@ -402,6 +404,7 @@ impl DocumentExtractor for DocbookExtractor {
             metadata,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -413,6 +416,7 @@ impl DocumentExtractor for DocbookExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
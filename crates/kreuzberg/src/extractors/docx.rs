@@ -5,13 +5,13 @@
 //! Supports: Microsoft Word (.docx)
 
 use crate::Result;
-use crate::core::config::ExtractionConfig;
+use crate::core::config::{ExtractionConfig, RevisionMode};
 use crate::extraction::{cells_to_markdown, office_metadata};
 use crate::plugins::{DocumentExtractor, Plugin};
 use crate::types::ExtractedImage;
 use crate::types::{
-    DocxMetadata, ExtractionResult, FormatMetadata, Metadata, PageBoundary, PageContent, PageInfo, PageStructure,
-    PageUnitType, Table,
+    DocxComment, DocxMetadata, ExtractionResult, FormatMetadata, Metadata, PageBoundary, PageContent, PageInfo,
+    PageStructure, PageUnitType, Table, TextAnnotation,
 };
 use ahash::AHashMap;
 use async_trait::async_trait;
@@ -41,11 +41,65 @@ impl Default for DocxExtractor {
     }
 }
 
+/// Build comment and tracked-change annotations for a paragraph.
+///
+/// `offsets` come from rendering the paragraph with `Run::to_markdown`, so the
+/// annotations index into the node text. `open_comments` carries comment
+/// ranges across paragraphs.
+fn paragraph_annotations(
+    paragraph: &crate::extraction::docx::parser::Paragraph,
+    offsets: &[usize],
+    open_comments: &mut Vec<String>,
+    comments: &HashMap<&str, &crate::extraction::docx::parser::Comment>,
+) -> Vec<TextAnnotation> {
+    use crate::types::AnnotationKind;
+
+    let mut annotations = Vec::new();
+    for (i, run) in paragraph.runs.iter().enumerate() {
+        if let Some(ref revision) = run.revision
+            && offsets[i + 1] > offsets[i]
+        {
+            let author = revision.author.clone();
+            let date = revision.date.clone();
+            annotations.push(TextAnnotation {
+                start: offsets[i] as u32,
+                end: offsets[i + 1] as u32,
+                kind: if revision.is_insertion() {
+                    AnnotationKind::Insertion { author, date }
+                } else {
+                    AnnotationKind::Deletion { author, date }
+                },
+            });
+        }
+    }
+    for (id, start, end) in paragraph.comment_ranges(offsets, open_comments) {
+        if end > start
+            && let Some(comment) = comments.get(id.as_str())
+        {
+            annotations.push(TextAnnotation {
+                start: start as u32,
+                end: end as u32,
+                kind: AnnotationKind::Comment {
+                    id,
+                    author: comment.author.clone(),
+                    date: comment.date.clone(),
+                    text: comment.to_text(),
+                },
+            });
+        }
+    }
+    annotations.sort_by_key(|a| (a.start, std::cmp::Reverse(a.end)));
+    annotations
+}
+
 /// Build a DocumentStructure from parsed DOCX data.
 ///
 /// Creates a hierarchical tree with heading-based sections, paragraphs,
-/// lists, tables, images, headers/footers, and footnotes/endnotes.
+/// lists, tables, images, headers/footers, and footnotes/endnotes. Body
+/// paragraphs carry comment annotations, and tracked-change annotations when
+/// revisions are rendered as markup.
 fn build_document_structure(doc: &crate::extraction::docx::parser::Document) -> crate::types::DocumentStructure {
+    use crate::extraction::docx::parser::Run;
     use crate::types::{
         ContentLayer, DocumentNode, DocumentStructure, GridCell, NodeContent, NodeId, NodeIndex, TableGrid,
     };
@@ -55,6 +109,9 @@ fn build_document_structure(doc: &crate::extraction::docx::parser::Document) ->
     );
     let mut section_stack: Vec<(u8, NodeIndex)> = Vec::new();
     let mut node_count: u32 = 0;
+    let comments: HashMap<&str, &crate::extraction::docx::parser::Comment> =
+        doc.comments.iter().map(|c| (c.id.as_str(), c)).collect();
+    let mut open_comments: Vec<String> = Vec::new();
 
     // Helper to find the current parent based on section_stack
     let current_parent = |stack: &[(u8, NodeIndex)]| -> Option<NodeIndex> { stack.last().map(|(_, idx)| *idx) };
@@ -64,7 +121,8 @@ fn build_document_structure(doc: &crate::extraction::docx::parser::Document) ->
         match element {
             crate::extraction::docx::parser::DocumentElement::Paragraph(idx) => {
                 let paragraph = &doc.paragraphs[*idx];
-                let text = paragraph.runs_to_markdown();
+                let (text, offsets) = paragraph.render_runs(Run::to_markdown);
+                let annotations = paragraph_annotations(paragraph, &offsets, &mut open_comments, &comments);
                 if text.is_empty() {
                     continue;
                 }
@@ -110,7 +168,7 @@ fn build_document_structure(doc: &crate::extraction::docx::parser::Document) ->
                         page: None,
                         page_end: None,
                         bbox: None,
-                        annotations: Vec::new(),
+                        annotations,
                     };
                     node_count += 1;
                     let heading_idx = structure.push_node(heading);
@@ -128,7 +186,7 @@ fn build_document_structure(doc: &crate::extraction::docx::parser::Document) ->
                         page: None,
                         page_end: None,
                         bbox: None,
-                        annotations: Vec::new(),
+                        annotations,
                     };
                     node_count += 1;
                     let idx = structure.push_node(node);
@@ -146,7 +204,7 @@ fn build_document_structure(doc: &crate::extraction::docx::parser::Document) ->
                         page: None,
                         page_end: None,
                         bbox: None,
-                        annotations: Vec::new(),
+                        annotations,
                     };
                     node_count += 1;
                     let idx = structure.push_node(node);
@@ -157,6 +215,15 @@ fn build_document_structure(doc: &crate::extraction::docx::parser::Document) ->
             }
             crate::extraction::docx::parser::DocumentElement::Table(idx) => {
                 let table = &doc.tables[*idx];
+                // Table cells carry no annotations; only track comment ranges crossing the table.
+                for paragraph in table
+                    .rows
+                    .iter()
+                    .flat_map(|r| r.cells.iter())
+                    .flat_map(|c| c.paragraphs.iter())
+                {
+                    paragraph.comment_ranges(&[], &mut open_comments);
+                }
                 let rows = table.rows.len() as u32;
                 let cols = table.rows.first().map_or(0, |r| r.cells.len()) as u32;
                 let mut cells = Vec::new();
@@ -298,11 +365,16 @@ type DocxParseResult = (
     Vec<crate::extraction::docx::drawing::Drawing>,
     HashMap<String, String>,
     Option<crate::types::DocumentStructure>,
+    Vec<DocxComment>,
 );
 
-/// Parse DOCX document content and extract text, tables, page boundaries, drawings, image relationships, and optional document structure.
-fn parse_docx_core(content: &[u8], include_doc_structure: bool) -> crate::error::Result<DocxParseResult> {
-    let doc = crate::extraction::docx::parser::parse_document(content)?;
+/// Parse DOCX document content and extract text, tables, page boundaries, drawings, image relationships, optional document structure, and comments.
+fn parse_docx_core(
+    content: &[u8],
+    include_doc_structure: bool,
+    revision_mode: RevisionMode,
+) -> crate::error::Result<DocxParseResult> {
+    let doc = crate::extraction::docx::parser::parse_document_with_revisions(content, revision_mode)?;
     let text = doc.to_markdown();
     let tables: Vec<Table> = doc
         .tables
@@ -318,7 +390,35 @@ fn parse_docx_core(content: &[u8], include_doc_structure: bool) -> crate::error:
     } else {
         None
     };
-    Ok((text, tables, page_boundaries, drawings, image_rels, doc_structure))
+    let comments = convert_docx_comments(&doc);
+    Ok((
+        text,
+        tables,
+        page_boundaries,
+        drawings,
+        image_rels,
+        doc_structure,
+        comments,
+    ))
+}
+
+/// Convert parsed DOCX comments to metadata entries, resolving their anchored text.
+fn convert_docx_comments(doc: &crate::extraction::docx::parser::Document) -> Vec<DocxComment> {
+    if doc.comments.is_empty() {
+        return Vec::new();
+    }
+    let mut anchors = doc.comment_anchors();
+    doc.comments
+        .iter()
+        .map(|comment| DocxComment {
+            id: comment.id.clone(),
+            author: comment.author.clone(),
+            initials: comment.initials.clone(),
+            date: comment.date.clone(),
+            text: comment.to_text(),
+            anchor_text: anchors.remove(&comment.id),
+        })
+        .collect()
 }
 
 impl Plugin for DocxExtractor {
@@ -388,6 +488,8 @@ fn convert_docx_table_to_table(docx_table: &crate::extraction::docx::parser::Tab
         cells,
         markdown,
         page_number: table_index + 1,
+        grid: None,
+        page_end: None,
     }
 }
 
@@ -407,24 +509,29 @@ impl DocumentExtractor for DocxExtractor {
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let include_doc_structure = config.include_document_structure;
+        let revision_mode = config
+            .docx_options
+            .as_ref()
+            .map(|options| options.revision_mode)
+            .unwrap_or_default();
 
-        let (text, tables, page_boundaries, drawings, image_rels, doc_structure) = {
+        let (text, tables, page_boundaries, drawings, image_rels, doc_structure, comments) = {
             #[cfg(feature = "tokio-runtime")]
             if crate::core::batch_mode::is_batch_mode() {
                 let content_owned = content.to_vec();
                 let span = tracing::Span::current();
                 tokio::task::spawn_blocking(move || {
                     let _guard = span.entered();
-                    parse_docx_core(&content_owned, include_doc_structure)
+                    parse_docx_core(&content_owned, include_doc_structure, revision_mode)
                 })
                 .await
                 .map_err(|e| crate::error::KreuzbergError::parsing(format!("DOCX extraction task failed: {}", e)))??
             } else {
-                parse_docx_core(content, include_doc_structure)?
+                parse_docx_core(content, include_doc_structure, revision_mode)?
             }
 
             #[cfg(not(feature = "tokio-runtime"))]
-            parse_docx_core(content, include_doc_structure)?
+            parse_docx_core(content, include_doc_structure, revision_mode)?
         };
 
         let mut archive = {
@@ -733,6 +840,9 @@ impl DocumentExtractor for DocxExtractor {
                         images: page_images,
                         hierarchy: None,
                         is_blank: Some(is_blank),
+                        language: None,
+                        language_confidence: None,
+                        text_source: None,
                     });
                 }
                 Some(pages)
@@ -745,6 +855,9 @@ impl DocumentExtractor for DocxExtractor {
                     images: arc_images,
                     hierarchy: None,
                     is_blank: Some(text.chars().filter(|c| !c.is_whitespace()).count() < 3),
+                    language: None,
+                    language_confidence: None,
+                    text_source: None,
                 }])
             }
         };
@@ -794,6 +907,7 @@ impl DocumentExtractor for DocxExtractor {
                     core_properties: docx_core_properties,
                     app_properties: docx_app_properties,
                     custom_properties: docx_custom_properties,
+                    comments,
                 }))),
                 additional: metadata_map,
                 ..Default::default()
@@ -801,6 +915,7 @@ impl DocumentExtractor for DocxExtractor {
             pages: page_contents,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: if extracted_images.is_empty() {
                 None
@@ -815,6 +930,7 @@ impl DocumentExtractor for DocxExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -1722,4 +1838,131 @@ mod tests {
             _ => panic!("Expected FormatMetadata::Docx"),
         }
     }
+
+    fn build_test_docx_with_comments(document_xml: &str, comments_xml: &str) -> Vec<u8> {
+        use std::io::Write;
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options: zip::write::FileOptions<()> = zip::write::FileOptions::default();
+        zip.start_file("word/document.xml", options).unwrap();
+        zip.write_all(document_xml.as_bytes()).unwrap();
+        zip.start_file("word/comments.xml", options).unwrap();
+        zip.write_all(comments_xml.as_bytes()).unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    const REVIEWED_DOCUMENT_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p>
+      <w:r><w:t xml:space="preserve">Payment is due in </w:t></w:r>
+      <w:commentRangeStart w:id="7"/>
+      <w:del w:id="1" w:author="Bob" w:date="2024-05-01T08:00:00Z"><w:r><w:delText>30</w:delText></w:r></w:del>
+      <w:ins w:id="2" w:author="Alice" w:date="2024-05-02T08:00:00Z"><w:r><w:t>60</w:t></w:r></w:ins>
+      <w:r><w:t xml:space="preserve"> days</w:t></w:r>
+      <w:commentRangeEnd w:id="7"/>
+      <w:r><w:t>.</w:t></w:r>
+    </w:p>
+  </w:body>
+</w:document>"#;
+
+    const REVIEWED_COMMENTS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<w:comments xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:comment w:id="7" w:author="Legal" w:date="2024-05-03T12:00:00Z" w:initials="LG">
+    <w:p><w:r><w:t>Confirm with finance.</w:t></w:r></w:p>
+  </w:comment>
+</w:comments>"#;
+
+    async fn extract_reviewed_document(revision_mode: RevisionMode) -> ExtractionResult {
+        let data = build_test_docx_with_comments(REVIEWED_DOCUMENT_XML, REVIEWED_COMMENTS_XML);
+        let config = ExtractionConfig {
+            include_document_structure: true,
+            docx_options: Some(crate::core::config::DocxConfig { revision_mode }),
+            ..Default::default()
+        };
+        DocxExtractor::new()
+            .extract_bytes(
+                &data,
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+                &config,
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_revision_modes_in_content() {
+        let accepted = extract_reviewed_document(RevisionMode::Accept).await;
+        assert_eq!(accepted.content, "Payment is due in 60 days.");
+
+        let rejected = extract_reviewed_document(RevisionMode::Reject).await;
+        assert_eq!(rejected.content, "Payment is due in 30 days.");
+
+        let markup = extract_reviewed_document(RevisionMode::Markup).await;
+        assert_eq!(markup.content, "Payment is due in {--30--}{++60++} days.");
+    }
+
+    #[tokio::test]
+    async fn test_comments_in_metadata() {
+        let result = extract_reviewed_document(RevisionMode::Accept).await;
+        let Some(FormatMetadata::Docx(docx)) = result.metadata.format else {
+            panic!("expected DOCX metadata");
+        };
+        assert_eq!(
+            docx.comments,
+            vec![DocxComment {
+                id: "7".to_string(),
+                author: Some("Legal".to_string()),
+                initials: Some("LG".to_string()),
+                date: Some("2024-05-03T12:00:00Z".to_string()),
+                text: "Confirm with finance.".to_string(),
+                anchor_text: Some("60 days".to_string()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_comment_and_revision_annotations() {
+        use crate::types::{AnnotationKind, NodeContent};
+
+        let result = extract_reviewed_document(RevisionMode::Markup).await;
+        let doc = result.document.expect("document structure");
+        let node = doc
+            .nodes
+            .iter()
+            .find(|n| matches!(n.content, NodeContent::Paragraph { .. }))
+            .unwrap();
+        let text = node.content.text().unwrap();
+        let spans: Vec<(&str, &AnnotationKind)> = node
+            .annotations
+            .iter()
+            .map(|a| (&text[a.start as usize..a.end as usize], &a.kind))
+            .collect();
+
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].0, "{--30--}{++60++} days");
+        assert!(matches!(
+            spans[0].1,
+            AnnotationKind::Comment { id, author, text, .. }
+                if id == "7" && author.as_deref() == Some("Legal") && text == "Confirm with finance."
+        ));
+        assert_eq!(spans[1].0, "{--30--}");
+        assert!(matches!(spans[1].1, AnnotationKind::Deletion { author, .. } if author.as_deref() == Some("Bob")));
+        assert_eq!(spans[2].0, "{++60++}");
+        assert!(matches!(spans[2].1, AnnotationKind::Insertion { author, .. } if author.as_deref() == Some("Alice")));
+
+        // Without markup, only the comment remains and it covers the accepted text.
+        let accepted = extract_reviewed_document(RevisionMode::Accept).await;
+        let doc = accepted.document.expect("document structure");
+        let node = doc
+            .nodes
+            .iter()
+            .find(|n| matches!(n.content, NodeContent::Paragraph { .. }))
+            .unwrap();
+        assert_eq!(node.annotations.len(), 1);
+        let annotation = &node.annotations[0];
+        assert_eq!(
+            &node.content.text().unwrap()[annotation.start as usize..annotation.end as usize],
+            "60 days"
+        );
+    }
 }
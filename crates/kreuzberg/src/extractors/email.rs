@@ -4,7 +4,7 @@ use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extractors::SyncExtractor;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{EmailMetadata, ExtractionResult, Metadata};
+use crate::types::{EmailExtractionResult, EmailMetadata, ExtractionResult, Metadata, ProcessingWarning};
 use ahash::AHashMap;
 use async_trait::async_trait;
 use std::borrow::Cow;
@@ -22,12 +22,6 @@ impl Default for EmailExtractor {
     }
 }
 
-impl EmailExtractor {
-    pub fn new() -> Self {
-        Self
-    }
-}
-
 impl Plugin for EmailExtractor {
     fn name(&self) -> &str {
         "email-extractor"
@@ -46,10 +40,40 @@ impl Plugin for EmailExtractor {
     }
 }
 
-impl SyncExtractor for EmailExtractor {
-    fn extract_sync(&self, content: &[u8], mime_type: &str, _config: &ExtractionConfig) -> Result<ExtractionResult> {
-        let email_result = crate::extraction::email::extract_email_content(content, mime_type)?;
+impl EmailExtractor {
+    pub fn new() -> Self {
+        Self
+    }
 
+    /// Extract the attachments of a parsed message, then convert it with [`Self::build_result`].
+    ///
+    /// Callers check `EmailConfig::extract_attachments` first. Once
+    /// `max_attachment_depth` is used up, attachments are only listed and a
+    /// processing warning is recorded.
+    pub(crate) async fn build_result_with_attachments(
+        mut email_result: EmailExtractionResult,
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> ExtractionResult {
+        let options = config.email_options.clone().unwrap_or_default();
+        if options.max_attachment_depth > 0 {
+            crate::extraction::email::extract_email_attachments(&mut email_result, config).await;
+            return Self::build_result(email_result, mime_type);
+        }
+
+        let has_attachment_data = email_result.attachments.iter().any(|att| att.data.is_some());
+        let mut result = Self::build_result(email_result, mime_type);
+        if has_attachment_data {
+            result.processing_warnings.push(ProcessingWarning {
+                source: "email".to_string(),
+                message: "Attachment depth limit reached; attachments were not extracted".to_string(),
+            });
+        }
+        result
+    }
+
+    /// Convert a parsed message into an `ExtractionResult`, moving over any attachment results.
+    pub(crate) fn build_result(email_result: EmailExtractionResult, mime_type: &str) -> ExtractionResult {
         let text = crate::extraction::email::build_email_text_output(&email_result);
 
         let attachment_names: Vec<String> = email_result
@@ -65,6 +89,8 @@ impl SyncExtractor for EmailExtractor {
             cc_emails: email_result.cc_emails.clone(),
             bcc_emails: email_result.bcc_emails.clone(),
             message_id: email_result.message_id.clone(),
+            in_reply_to: email_result.in_reply_to.clone(),
+            references: email_result.references.clone(),
             attachments: attachment_names,
         };
 
@@ -77,6 +103,8 @@ impl SyncExtractor for EmailExtractor {
             "cc_emails",
             "bcc_emails",
             "message_id",
+            "in_reply_to",
+            "references",
             "attachments",
             "subject",
             "date",
@@ -88,7 +116,7 @@ impl SyncExtractor for EmailExtractor {
             }
         }
 
-        Ok(ExtractionResult {
+        ExtractionResult {
             content: text,
             mime_type: mime_type.to_string().into(),
             metadata: Metadata {
@@ -100,6 +128,7 @@ impl SyncExtractor for EmailExtractor {
             },
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -111,7 +140,15 @@ impl SyncExtractor for EmailExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
-        })
+            children: email_result.children,
+        }
+    }
+}
+
+impl SyncExtractor for EmailExtractor {
+    fn extract_sync(&self, content: &[u8], mime_type: &str, _config: &ExtractionConfig) -> Result<ExtractionResult> {
+        let email_result = crate::extraction::email::extract_email_content(content, mime_type)?;
+        Ok(Self::build_result(email_result, mime_type))
     }
 }
 
@@ -130,7 +167,13 @@ impl DocumentExtractor for EmailExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        self.extract_sync(content, mime_type, config)
+        let options = config.email_options.clone().unwrap_or_default();
+        if !options.extract_attachments {
+            return self.extract_sync(content, mime_type, config);
+        }
+
+        let email_result = crate::extraction::email::extract_email_content(content, mime_type)?;
+        Ok(Self::build_result_with_attachments(email_result, mime_type, config).await)
     }
 
     #[cfg(feature = "tokio-runtime")]
@@ -179,4 +222,116 @@ mod tests {
         assert!(mime_types.contains(&"message/rfc822"));
         assert!(mime_types.contains(&"application/vnd.ms-outlook"));
     }
+    /// Message with a text attachment and an attached message that has its own attachment.
+    fn message_with_attachments() -> Vec<u8> {
+        let inner = "From: inner@example.com\r\n\
+Subject: Inner\r\n\
+Content-Type: multipart/mixed; boundary=\"inner\"\r\n\r\n\
+--inner\r\n\
+Content-Type: text/plain\r\n\r\n\
+Inner body\r\n\
+--inner\r\n\
+Content-Type: text/plain\r\n\
+Content-Disposition: attachment; filename=\"deep.txt\"\r\n\r\n\
+deep attachment text\r\n\
+--inner--\r\n";
+
+        format!(
+            "From: outer@example.com\r\n\
+Subject: Outer\r\n\
+Content-Type: multipart/mixed; boundary=\"outer\"\r\n\r\n\
+--outer\r\n\
+Content-Type: text/plain\r\n\r\n\
+Outer body\r\n\
+--outer\r\n\
+Content-Type: text/plain\r\n\
+Content-Disposition: attachment; filename=\"notes.txt\"\r\n\r\n\
+attached notes\r\n\
+--outer\r\n\
+Content-Type: message/rfc822\r\n\
+Content-Disposition: attachment; filename=\"forwarded.eml\"\r\n\r\n\
+{inner}\
+--outer--\r\n"
+        )
+        .into_bytes()
+    }
+
+    fn attachment_config(max_attachment_depth: usize) -> ExtractionConfig {
+        ExtractionConfig {
+            email_options: Some(crate::core::config::EmailConfig {
+                extract_attachments: true,
+                max_attachment_depth,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_email_attachments_not_extracted_by_default() {
+        let extractor = EmailExtractor::new();
+        let result = extractor
+            .extract_bytes(
+                &message_with_attachments(),
+                "message/rfc822",
+                &ExtractionConfig::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.children.is_none());
+        assert!(result.content.contains("Attachments: notes.txt, forwarded.eml"));
+        assert!(!result.content.contains("attached notes"));
+    }
+
+    #[tokio::test]
+    async fn test_email_attachments_extracted_recursively() {
+        let extractor = EmailExtractor::new();
+        let result = extractor
+            .extract_bytes(&message_with_attachments(), "message/rfc822", &attachment_config(3))
+            .await
+            .unwrap();
+
+        let children = result.children.as_ref().unwrap();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].path, "notes.txt");
+        assert_eq!(children[0].mime_type.as_deref(), Some("text/plain"));
+        assert!(children[0].result.as_ref().unwrap().content.contains("attached notes"));
+
+        assert_eq!(children[1].path, "forwarded.eml");
+        assert_eq!(children[1].mime_type.as_deref(), Some("message/rfc822"));
+        let forwarded = children[1].result.as_ref().unwrap();
+        assert!(forwarded.content.contains("Inner body"));
+
+        let nested = forwarded.children.as_ref().unwrap();
+        assert_eq!(nested.len(), 1);
+        assert_eq!(nested[0].path, "deep.txt");
+        assert!(
+            nested[0]
+                .result
+                .as_ref()
+                .unwrap()
+                .content
+                .contains("deep attachment text")
+        );
+
+        assert!(result.content.contains("=== notes.txt ===\nattached notes"));
+        assert!(result.content.contains("deep attachment text"));
+    }
+
+    #[tokio::test]
+    async fn test_email_attachment_depth_limit() {
+        let extractor = EmailExtractor::new();
+        let result = extractor
+            .extract_bytes(&message_with_attachments(), "message/rfc822", &attachment_config(1))
+            .await
+            .unwrap();
+
+        let children = result.children.as_ref().unwrap();
+        let forwarded = children[1].result.as_ref().unwrap();
+        assert!(forwarded.content.contains("Inner body"));
+        assert!(forwarded.children.is_none());
+        assert_eq!(forwarded.processing_warnings.len(), 1);
+        assert_eq!(forwarded.processing_warnings[0].source, "email");
+    }
 }
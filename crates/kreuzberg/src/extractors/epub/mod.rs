@@ -133,6 +133,7 @@ impl DocumentExtractor for EpubExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -143,6 +144,7 @@ impl DocumentExtractor for EpubExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
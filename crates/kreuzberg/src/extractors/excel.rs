@@ -46,6 +46,8 @@ impl ExcelExtractor {
                     cells: cells.clone(),
                     markdown: sheet.markdown.clone(),
                     page_number: sheet_index + 1,
+                    grid: None,
+                    page_end: None,
                 });
             }
         }
@@ -140,6 +142,7 @@ impl DocumentExtractor for ExcelExtractor {
             pages: None,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -150,6 +153,7 @@ impl DocumentExtractor for ExcelExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -192,6 +196,7 @@ impl DocumentExtractor for ExcelExtractor {
             pages: None,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -202,6 +207,7 @@ impl DocumentExtractor for ExcelExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -438,6 +438,7 @@ impl DocumentExtractor for FictionBookExtractor {
             metadata,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -449,6 +450,7 @@ impl DocumentExtractor for FictionBookExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
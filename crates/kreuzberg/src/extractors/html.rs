@@ -63,6 +63,8 @@ fn parse_markdown_tables(markdown: &str) -> Vec<Table> {
                 cells,
                 markdown: markdown_table,
                 page_number: table_index + 1,
+                grid: None,
+                page_end: None,
             });
             table_index += 1;
             i = end_idx;
@@ -227,6 +229,7 @@ impl SyncExtractor for HtmlExtractor {
             pages: None,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -237,6 +240,7 @@ impl SyncExtractor for HtmlExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 }
@@ -46,9 +46,10 @@ impl ImageExtractor {
 
         // Thread output_format from ExtractionConfig to OcrConfig
         let mut ocr_config_with_format = ocr_config.clone();
-        ocr_config_with_format.output_format = Some(config.output_format);
+        ocr_config_with_format.output_format = Some(config.effective_output_format());
 
-        let ocr_result = backend.process_image(content, &ocr_config_with_format).await?;
+        let ocr_result = crate::ocr::auto_language::process_image(&*backend, content, &ocr_config_with_format).await?;
+        crate::metrics::metrics().record_ocr_pages(&ocr_config.backend, 1);
 
         let ocr_text = ocr_result.content.clone();
         let ocr_extraction_result = crate::extraction::image::extract_text_from_image_with_ocr(
@@ -149,6 +150,7 @@ impl DocumentExtractor for ImageExtractor {
                     pages: None,
                     tables: vec![],
                     detected_languages: None,
+                    language_spans: None,
                     chunks: None,
                     images: None,
                     djot_content: None,
@@ -159,6 +161,7 @@ impl DocumentExtractor for ImageExtractor {
                     extracted_keywords: None,
                     quality_score: None,
                     processing_warnings: Vec::new(),
+                    children: None,
                 });
             }
         }
@@ -176,6 +179,7 @@ impl DocumentExtractor for ImageExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -186,6 +190,7 @@ impl DocumentExtractor for ImageExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -163,6 +163,7 @@ impl DocumentExtractor for JatsExtractor {
             metadata,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -174,6 +175,7 @@ impl DocumentExtractor for JatsExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -443,6 +443,7 @@ impl DocumentExtractor for JupyterExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images,
             djot_content: None,
@@ -453,6 +454,7 @@ impl DocumentExtractor for JupyterExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
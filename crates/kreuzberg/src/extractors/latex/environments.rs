@@ -129,6 +129,8 @@ pub fn process_table(content: &str, output: &mut String, tables: &mut Vec<Table>
             cells: rows,
             markdown: markdown.clone(),
             page_number: 1,
+            grid: None,
+            page_end: None,
         };
         tables.push(table);
     }
@@ -99,6 +99,7 @@ impl DocumentExtractor for LatexExtractor {
             metadata,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -110,6 +111,7 @@ impl DocumentExtractor for LatexExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -0,0 +1,376 @@
+//! Mailbox extractors for mbox files and Maildir directories.
+
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::core::mime::EML_MIME_TYPE;
+use crate::extraction::email::parse_eml_content;
+use crate::extraction::mailbox::{MAILDIR_MIME_TYPE, MboxReader, build_threads, maildir_message_paths};
+use crate::extractors::email::EmailExtractor;
+use crate::plugins::{DocumentExtractor, Plugin};
+use crate::types::{ChildResult, ExtractionResult, MailboxMetadata, Metadata};
+use async_trait::async_trait;
+use std::borrow::Cow;
+#[cfg(feature = "tokio-runtime")]
+use std::io::BufReader;
+use std::io::{BufRead, Cursor};
+use std::path::Path;
+
+/// Parse one mailbox message into a [`ChildResult`].
+///
+/// Attachments are extracted when `EmailConfig.extract_attachments` is set,
+/// exactly as for a standalone .eml file.
+async fn extract_message(path: String, raw: &[u8], config: &ExtractionConfig) -> ChildResult {
+    let size = raw.len() as u64;
+    let email_result = match parse_eml_content(raw) {
+        Ok(email_result) => email_result,
+        Err(e) => {
+            return ChildResult {
+                path,
+                mime_type: Some(EML_MIME_TYPE.to_string()),
+                size,
+                result: None,
+                error: Some(e.to_string()),
+            };
+        }
+    };
+
+    let extract_attachments = config.email_options.as_ref().is_some_and(|o| o.extract_attachments);
+    let result = if extract_attachments {
+        EmailExtractor::build_result_with_attachments(email_result, EML_MIME_TYPE, config).await
+    } else {
+        EmailExtractor::build_result(email_result, EML_MIME_TYPE)
+    };
+
+    ChildResult {
+        path,
+        mime_type: Some(EML_MIME_TYPE.to_string()),
+        size,
+        result: Some(Box::new(result)),
+        error: None,
+    }
+}
+
+/// Split an mbox stream and extract every message.
+async fn extract_mbox_messages<R: BufRead>(reader: R, config: &ExtractionConfig) -> Result<Vec<ChildResult>> {
+    let format = config.email_options.as_ref().map(|o| o.mbox_format).unwrap_or_default();
+
+    let mut messages = Vec::new();
+    for (index, raw) in MboxReader::new(reader, format).enumerate() {
+        let raw = raw?;
+        messages.push(extract_message(format!("message-{}", index + 1), &raw, config).await);
+    }
+    Ok(messages)
+}
+
+/// Build the mailbox result: a message overview as content, one child per message.
+fn build_mailbox_result(format_name: &'static str, mime_type: &str, messages: Vec<ChildResult>) -> ExtractionResult {
+    let threads = build_threads(&messages);
+
+    let mut output = format!("Mailbox ({} messages, {} threads)\n\n", messages.len(), threads.len());
+    for message in &messages {
+        match (&message.result, &message.error) {
+            (Some(result), _) => output.push_str(&format!("=== {} ===\n{}\n\n", message.path, result.content)),
+            (None, Some(error)) => output.push_str(&format!("=== {} ===\n[error: {}]\n\n", message.path, error)),
+            (None, None) => {}
+        }
+    }
+
+    let mailbox_metadata = MailboxMetadata {
+        format: Cow::Borrowed(format_name),
+        message_count: messages.len(),
+        threads,
+    };
+
+    ExtractionResult {
+        content: output,
+        mime_type: mime_type.to_string().into(),
+        metadata: Metadata {
+            format: Some(crate::types::FormatMetadata::Mailbox(mailbox_metadata)),
+            ..Default::default()
+        },
+        tables: vec![],
+        detected_languages: None,
+        language_spans: None,
+        chunks: None,
+        images: None,
+        pages: None,
+        djot_content: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+        extracted_keywords: None,
+        quality_score: None,
+        processing_warnings: Vec::new(),
+        children: Some(messages),
+    }
+}
+
+/// Extract every message of a Maildir directory.
+///
+/// Each message file is parsed like a standalone .eml file and returned in
+/// `ExtractionResult.children` under its path relative to `path`
+/// (e.g. `cur/1700000000.M1P2.host:2,S`). Threads are reconstructed across
+/// all folders, including Maildir++ subfolders.
+///
+/// # Errors
+///
+/// Returns an IO error if the directory cannot be read. Messages that fail to
+/// parse are recorded on their [`ChildResult`] instead.
+pub async fn extract_maildir(path: &Path, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    let mut messages = Vec::new();
+    for file in maildir_message_paths(path)? {
+        let relative = file
+            .strip_prefix(path)
+            .unwrap_or(&file)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let raw = std::fs::read(&file)?;
+        messages.push(extract_message(relative, &raw, config).await);
+    }
+
+    Ok(build_mailbox_result("maildir", MAILDIR_MIME_TYPE, messages))
+}
+
+/// Mbox mailbox extractor.
+///
+/// Supports: .mbox, .mbx (mboxrd and mboxo). Files are read as a stream, so
+/// only one raw message is held in memory at a time.
+pub struct MboxExtractor;
+
+impl Default for MboxExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MboxExtractor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Plugin for MboxExtractor {
+    fn name(&self) -> &str {
+        "mbox-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DocumentExtractor for MboxExtractor {
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, content, config),
+        fields(
+            extractor.name = self.name(),
+            content.size_bytes = content.len(),
+        )
+    ))]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let messages = extract_mbox_messages(Cursor::new(content), config).await?;
+        Ok(build_mailbox_result("mbox", mime_type, messages))
+    }
+
+    #[cfg(feature = "tokio-runtime")]
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, path, config),
+        fields(
+            extractor.name = self.name(),
+        )
+    ))]
+    async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+        let file = std::fs::File::open(path)?;
+        let messages = extract_mbox_messages(BufReader::new(file), config).await?;
+        Ok(build_mailbox_result("mbox", mime_type, messages))
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &["application/mbox"]
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::EmailConfig;
+    use crate::types::FormatMetadata;
+
+    const MBOX: &[u8] = b"From alice@example.com Mon Jan  1 10:00:00 2024\n\
+From: alice@example.com\n\
+To: bob@example.com\n\
+Subject: Plans\n\
+Message-ID: <1@example.com>\n\
+\n\
+Shall we meet?\n\
+>From my side, any day works.\n\
+\n\
+From bob@example.com Mon Jan  1 11:00:00 2024\n\
+From: bob@example.com\n\
+To: alice@example.com\n\
+Subject: Re: Plans\n\
+Message-ID: <2@example.com>\n\
+In-Reply-To: <1@example.com>\n\
+References: <1@example.com>\n\
+\n\
+Tuesday it is.\n\
+\n\
+From carol@example.com Mon Jan  1 12:00:00 2024\n\
+From: carol@example.com\n\
+Subject: Unrelated\n\
+Message-ID: <3@example.com>\n\
+\n\
+Hello.\n";
+
+    fn mailbox_metadata(result: &ExtractionResult) -> &MailboxMetadata {
+        match result.metadata.format.as_ref().unwrap() {
+            FormatMetadata::Mailbox(meta) => meta,
+            _ => panic!("expected mailbox metadata"),
+        }
+    }
+
+    #[test]
+    fn test_mbox_extractor_plugin_interface() {
+        let extractor = MboxExtractor::new();
+        assert_eq!(extractor.name(), "mbox-extractor");
+        assert_eq!(extractor.supported_mime_types(), &["application/mbox"]);
+    }
+
+    #[tokio::test]
+    async fn test_mbox_extract_bytes() {
+        let extractor = MboxExtractor::new();
+        let result = extractor
+            .extract_bytes(MBOX, "application/mbox", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        let messages = result.children.as_ref().unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0].path, "message-1");
+
+        let first = messages[0].result.as_ref().unwrap();
+        assert_eq!(first.metadata.subject.as_deref(), Some("Plans"));
+        assert!(first.content.contains("\nFrom my side, any day works."));
+        match first.metadata.format.as_ref().unwrap() {
+            FormatMetadata::Email(email) => assert_eq!(email.message_id.as_deref(), Some("1@example.com")),
+            _ => panic!("expected email metadata"),
+        }
+
+        let meta = mailbox_metadata(&result);
+        assert_eq!(meta.format, "mbox");
+        assert_eq!(meta.message_count, 3);
+        assert_eq!(meta.threads.len(), 2);
+        assert_eq!(meta.threads[0].messages.len(), 2);
+        assert_eq!(meta.threads[0].messages[1].parent, Some(0));
+
+        assert!(result.content.starts_with("Mailbox (3 messages, 2 threads)"));
+        assert!(result.content.contains("=== message-2 ===\nSubject: Re: Plans"));
+    }
+
+    #[tokio::test]
+    async fn test_mbox_extract_file_streams_from_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("archive.mbox");
+        std::fs::write(&path, MBOX).unwrap();
+
+        let extractor = MboxExtractor::new();
+        let result = extractor
+            .extract_file(&path, "application/mbox", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(result.children.as_ref().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_mbox_messages_with_attachments() {
+        let mbox = b"From a@example.com Mon Jan  1 10:00:00 2024\n\
+From: a@example.com\n\
+Subject: With attachment\n\
+Content-Type: multipart/mixed; boundary=\"b\"\n\
+\n\
+--b\n\
+Content-Type: text/plain\n\
+\n\
+See attached.\n\
+--b\n\
+Content-Type: text/plain\n\
+Content-Disposition: attachment; filename=\"notes.txt\"\n\
+\n\
+attached notes\n\
+--b--\n";
+        let config = ExtractionConfig {
+            email_options: Some(EmailConfig {
+                extract_attachments: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let result = MboxExtractor::new()
+            .extract_bytes(mbox, "application/mbox", &config)
+            .await
+            .unwrap();
+        let message = result.children.as_ref().unwrap()[0].result.as_ref().unwrap();
+        let attachments = message.children.as_ref().unwrap();
+        assert_eq!(attachments[0].path, "notes.txt");
+        assert!(
+            attachments[0]
+                .result
+                .as_ref()
+                .unwrap()
+                .content
+                .contains("attached notes")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_maildir() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        for sub in ["cur", "new", "tmp"] {
+            std::fs::create_dir_all(root.join(sub)).unwrap();
+        }
+        std::fs::write(
+            root.join("cur/1700000000.1.host:2,S"),
+            "From: a@example.com\r\nSubject: First\r\nMessage-ID: <m1@example.com>\r\n\r\nHello",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("new/1700000001.2.host"),
+            "From: b@example.com\r\nSubject: Re: First\r\nMessage-ID: <m2@example.com>\r\nIn-Reply-To: <m1@example.com>\r\n\r\nHi",
+        )
+        .unwrap();
+
+        let result = extract_maildir(root, &ExtractionConfig::default()).await.unwrap();
+        assert_eq!(result.mime_type, MAILDIR_MIME_TYPE);
+
+        let messages = result.children.as_ref().unwrap();
+        let paths: Vec<&str> = messages.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(paths, vec!["cur/1700000000.1.host:2,S", "new/1700000001.2.host"]);
+
+        let meta = mailbox_metadata(&result);
+        assert_eq!(meta.format, "maildir");
+        assert_eq!(meta.threads.len(), 1);
+        assert_eq!(meta.threads[0].subject.as_deref(), Some("First"));
+        assert_eq!(meta.threads[0].messages[1].parent, Some(0));
+    }
+}
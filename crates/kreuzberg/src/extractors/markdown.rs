@@ -255,6 +255,8 @@ impl MarkdownExtractor {
                             cells,
                             markdown,
                             page_number: idx + 1,
+                            grid: None,
+                            page_end: None,
                         });
                         table_index += 1;
                     }
@@ -367,6 +369,7 @@ impl DocumentExtractor for MarkdownExtractor {
             metadata,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images,
             djot_content: None,
@@ -378,6 +381,7 @@ impl DocumentExtractor for MarkdownExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -80,6 +80,9 @@ pub mod archive;
 #[cfg(feature = "email")]
 pub mod email;
 
+#[cfg(feature = "email")]
+pub mod mailbox;
+
 #[cfg(feature = "excel")]
 pub mod excel;
 
@@ -162,6 +165,9 @@ pub use archive::{GzipExtractor, SevenZExtractor, TarExtractor, ZipExtractor};
 #[cfg(feature = "email")]
 pub use email::EmailExtractor;
 
+#[cfg(feature = "email")]
+pub use mailbox::{MboxExtractor, extract_maildir};
+
 #[cfg(feature = "excel")]
 pub use excel::ExcelExtractor;
 
@@ -335,7 +341,10 @@ pub fn register_default_extractors() -> Result<()> {
     }
 
     #[cfg(feature = "email")]
-    registry.register(Arc::new(EmailExtractor::new()))?;
+    {
+        registry.register(Arc::new(EmailExtractor::new()))?;
+        registry.register(Arc::new(MboxExtractor::new()))?;
+    }
 
     #[cfg(feature = "html")]
     registry.register(Arc::new(HtmlExtractor::new()))?;
@@ -435,8 +444,9 @@ mod tests {
 
         #[cfg(feature = "email")]
         {
-            expected_count += 1;
+            expected_count += 2;
             assert!(extractor_names.contains(&"email-extractor".to_string()));
+            assert!(extractor_names.contains(&"mbox-extractor".to_string()));
         }
 
         #[cfg(feature = "html")]
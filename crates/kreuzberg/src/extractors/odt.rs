@@ -442,6 +442,8 @@ fn parse_odt_table(table_node: roxmltree::Node, table_index: usize) -> Option<Ta
         cells,
         markdown,
         page_number: table_index + 1,
+        grid: None,
+        page_end: None,
     })
 }
 
@@ -647,6 +649,7 @@ impl DocumentExtractor for OdtExtractor {
             pages: None,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -657,6 +660,7 @@ impl DocumentExtractor for OdtExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -87,6 +87,7 @@ impl DocumentExtractor for OpmlExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -97,6 +98,7 @@ impl DocumentExtractor for OpmlExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -183,6 +183,8 @@ impl OrgModeExtractor {
                             cells: current_table.clone(),
                             markdown,
                             page_number: 1,
+                            grid: None,
+                            page_end: None,
                         });
                         current_table.clear();
                     }
@@ -196,6 +198,8 @@ impl OrgModeExtractor {
                     cells: current_table,
                     markdown,
                     page_number: 1,
+                    grid: None,
+                    page_end: None,
                 });
             }
         }
@@ -304,6 +308,7 @@ impl DocumentExtractor for OrgModeExtractor {
             metadata,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -315,6 +320,7 @@ impl DocumentExtractor for OrgModeExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -40,7 +40,7 @@ pub(crate) type PdfExtractionPhaseResult = (
 /// A tuple containing:
 /// - PDF metadata (title, authors, dates, page structure, etc.)
 /// - Native extracted text (or empty if using OCR)
-/// - Extracted tables (if OCR feature enabled)
+/// - Extracted tables (ruled tables; word-position tables if OCR feature enabled)
 /// - Per-page content (if page extraction configured)
 /// - Page boundaries for per-page OCR evaluation
 #[cfg(feature = "pdf")]
@@ -56,53 +56,70 @@ pub(crate) fn extract_all_from_document(
     Ok((pdf_metadata, native_text, tables, page_contents, boundaries))
 }
 
-/// Extract tables from PDF document using native text positions.
+/// Extract tables from PDF document.
 ///
-/// This function converts PDF character positions to HocrWord format,
-/// then uses the existing table reconstruction logic to detect tables.
+/// Tables drawn with borders are detected from the page's vector graphics,
+/// which recovers merged cells and cell bounding boxes. With the `ocr` feature,
+/// pages without ruled tables fall back to reconstructing tables from the
+/// positions of native text words.
 ///
 /// Uses the shared PdfDocument reference (wrapped in Arc<RwLock<>> for thread-safety).
-#[cfg(all(feature = "pdf", feature = "ocr"))]
+#[cfg(feature = "pdf")]
 fn extract_tables_from_document(
     document: &PdfDocument,
     _metadata: &crate::pdf::metadata::PdfExtractionMetadata,
 ) -> Result<Vec<Table>> {
-    use crate::ocr::table::{reconstruct_table, table_to_markdown};
-    use crate::pdf::table::extract_words_from_page;
+    use crate::pdf::ruled_table::extract_ruled_tables;
 
     let mut all_tables = Vec::new();
 
     for (page_index, page) in document.pages().iter().enumerate() {
-        let words = extract_words_from_page(&page, 0.0)?;
-
-        if words.is_empty() {
+        let ruled_tables = extract_ruled_tables(&page, page_index + 1)?;
+        if !ruled_tables.is_empty() {
+            all_tables.extend(ruled_tables);
             continue;
         }
 
-        let column_threshold = 50;
-        let row_threshold_ratio = 0.5;
-
-        let table_cells = reconstruct_table(&words, column_threshold, row_threshold_ratio);
-
-        if !table_cells.is_empty() {
-            let markdown = table_to_markdown(&table_cells);
-
-            all_tables.push(Table {
-                cells: table_cells,
-                markdown,
-                page_number: page_index + 1,
-            });
+        #[cfg(feature = "ocr")]
+        if let Some(table) = extract_word_table_from_page(&page, page_index + 1)? {
+            all_tables.push(table);
         }
     }
 
     Ok(all_tables)
 }
 
-/// Fallback for when OCR feature is not enabled - returns empty tables.
-#[cfg(all(feature = "pdf", not(feature = "ocr")))]
-fn extract_tables_from_document(
-    _document: &PdfDocument,
-    _metadata: &crate::pdf::metadata::PdfExtractionMetadata,
-) -> Result<Vec<crate::types::Table>> {
-    Ok(vec![])
+/// Reconstruct a table from word positions on a page without ruled tables.
+///
+/// This function converts PDF character positions to HocrWord format,
+/// then uses the existing table reconstruction logic to detect tables.
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+fn extract_word_table_from_page(page: &PdfPage, page_number: usize) -> Result<Option<Table>> {
+    use crate::ocr::table::{reconstruct_table, table_to_markdown};
+    use crate::pdf::table::extract_words_from_page;
+
+    let words = extract_words_from_page(page, 0.0)?;
+
+    if words.is_empty() {
+        return Ok(None);
+    }
+
+    let column_threshold = 50;
+    let row_threshold_ratio = 0.5;
+
+    let table_cells = reconstruct_table(&words, column_threshold, row_threshold_ratio);
+
+    if table_cells.is_empty() {
+        return Ok(None);
+    }
+
+    let markdown = table_to_markdown(&table_cells);
+
+    Ok(Some(Table {
+        cells: table_cells,
+        markdown,
+        page_number,
+        grid: None,
+        page_end: None,
+    }))
 }
@@ -12,7 +12,7 @@ use bytes::Bytes;
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata};
+use crate::types::{ExtractionResult, Metadata, ProcessingWarning};
 use async_trait::async_trait;
 #[cfg(feature = "tokio-runtime")]
 use std::path::Path;
@@ -26,8 +26,12 @@ pub use ocr::{NativeTextStats, OcrFallbackDecision, evaluate_native_text_for_ocr
 
 use extraction::extract_all_from_document;
 #[cfg(feature = "ocr")]
-use ocr::extract_with_ocr;
+use ocr::extract_pages_with_ocr;
 use pages::assign_tables_and_images_to_pages;
+#[cfg(feature = "ocr")]
+use pages::{apply_ocr_pages, merge_ocr_pages};
+#[cfg(feature = "ocr")]
+use std::collections::HashMap;
 
 /// PDF document extractor using pypdfium2 and playa-pdf.
 pub struct PdfExtractor;
@@ -202,24 +206,20 @@ impl DocumentExtractor for PdfExtractor {
         };
 
         #[cfg(feature = "ocr")]
-        let text = if config.force_ocr {
-            if config.ocr.is_some() {
-                extract_with_ocr(content, config).await?
+        let (text, page_contents) = if config.ocr.is_some() {
+            let boundaries = _boundaries.as_deref().unwrap_or_default();
+            let ocr_page_numbers: Vec<usize> = if config.force_ocr {
+                boundaries.iter().map(|boundary| boundary.page_number).collect()
             } else {
-                native_text
-            }
-        } else if config.ocr.is_some() {
-            let decision = ocr::evaluate_per_page_ocr(
-                &native_text,
-                _boundaries.as_deref(),
-                pdf_metadata.pdf_specific.page_count,
-            );
+                ocr::pages_needing_ocr(&native_text, boundaries)
+            };
 
             if std::env::var("KREUZBERG_DEBUG_OCR").is_ok() {
+                let decision = ocr::evaluate_native_text_for_ocr(&native_text, pdf_metadata.pdf_specific.page_count);
                 eprintln!(
-                    "[kreuzberg::pdf::ocr] fallback={} non_whitespace={} alnum={} meaningful_words={} \
+                    "[kreuzberg::pdf::ocr] ocr_pages={:?} non_whitespace={} alnum={} meaningful_words={} \
                      avg_non_whitespace={:.2} avg_alnum={:.2} alnum_ratio={:.3}",
-                    decision.fallback,
+                    ocr_page_numbers,
                     decision.stats.non_whitespace,
                     decision.stats.alnum,
                     decision.stats.meaningful_words,
@@ -229,13 +229,33 @@ impl DocumentExtractor for PdfExtractor {
                 );
             }
 
-            if decision.fallback {
-                extract_with_ocr(content, config).await?
+            if ocr_page_numbers.is_empty() {
+                (native_text, page_contents)
             } else {
-                native_text
+                let ocr_texts = extract_pages_with_ocr(content, &ocr_page_numbers, config).await?;
+                let ocr_pages: HashMap<usize, String> = ocr_page_numbers.into_iter().zip(ocr_texts).collect();
+                let (merged_text, merged_boundaries) = merge_ocr_pages(&native_text, boundaries, &ocr_pages);
+
+                if let Some(page_structure) = &mut pdf_metadata.page_structure {
+                    if let Some(ref mut page_infos) = page_structure.pages {
+                        for page_info in page_infos.iter_mut() {
+                            if let Some(ocr_text) = ocr_pages.get(&page_info.number) {
+                                page_info.is_blank =
+                                    Some(crate::extraction::blank_detection::is_page_text_blank(ocr_text));
+                            }
+                        }
+                    }
+                    page_structure.boundaries = Some(merged_boundaries);
+                }
+
+                let mut page_contents = page_contents;
+                if let Some(ref mut pages) = page_contents {
+                    apply_ocr_pages(pages, &ocr_pages);
+                }
+                (merged_text, page_contents)
             }
         } else {
-            native_text
+            (native_text, page_contents)
         };
 
         #[cfg(not(feature = "ocr"))]
@@ -306,6 +326,26 @@ impl DocumentExtractor for PdfExtractor {
             }
         }
 
+        let mut processing_warnings = Vec::new();
+        let extract_embedded = config
+            .pdf_options
+            .as_ref()
+            .is_some_and(|pdf| pdf.extract_embedded_files);
+        let children = if extract_embedded && !pdf_metadata.pdf_specific.embedded_files.is_empty() {
+            match crate::pdf::attachments::extract_embedded_files(content) {
+                Ok(documents) => Some(crate::extraction::embedded::extract_embedded_documents(documents, config).await),
+                Err(e) => {
+                    processing_warnings.push(ProcessingWarning {
+                        source: "pdf".to_string(),
+                        message: format!("Failed to read embedded files: {}", e),
+                    });
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(ExtractionResult {
             content: text,
             mime_type: mime_type.to_string().into(),
@@ -333,6 +373,7 @@ impl DocumentExtractor for PdfExtractor {
             pages: final_pages,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images,
             djot_content: None,
@@ -342,7 +383,8 @@ impl DocumentExtractor for PdfExtractor {
             #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
             extracted_keywords: None,
             quality_score: None,
-            processing_warnings: Vec::new(),
+            processing_warnings,
+            children,
         })
     }
 
@@ -560,6 +602,108 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "ocr")]
+    fn marked_pages(pages: &[&str]) -> (String, Vec<crate::types::PageBoundary>) {
+        let mut text = String::new();
+        let mut boundaries = Vec::new();
+        for (idx, page) in pages.iter().enumerate() {
+            text.push_str(&format!("\n\n<!-- PAGE {} -->\n\n", idx + 1));
+            let byte_start = text.len();
+            text.push_str(page);
+            boundaries.push(crate::types::PageBoundary {
+                byte_start,
+                byte_end: text.len(),
+                page_number: idx + 1,
+            });
+        }
+        (text, boundaries)
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_pages_needing_ocr_only_reports_bad_pages() {
+        let good_page = "This page has plenty of meaningful searchable text content for extraction.";
+        let (text, boundaries) = marked_pages(&[good_page, " . ; ", good_page, ""]);
+
+        assert_eq!(ocr::pages_needing_ocr(&text, &boundaries), vec![2, 4]);
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_pages_needing_ocr_skips_invalid_boundaries() {
+        let text = "This page has plenty of meaningful searchable text content for extraction.";
+        let boundaries = vec![crate::types::PageBoundary {
+            byte_start: 999,
+            byte_end: 9999,
+            page_number: 1,
+        }];
+
+        assert!(ocr::pages_needing_ocr(text, &boundaries).is_empty());
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_merge_ocr_pages_keeps_native_pages_and_markers() {
+        let (text, boundaries) = marked_pages(&["first native page", "", "third native page"]);
+        let ocr_pages = HashMap::from([(2, "recognized second page".to_string())]);
+
+        let (merged, merged_boundaries) = merge_ocr_pages(&text, &boundaries, &ocr_pages);
+
+        let (expected, expected_boundaries) =
+            marked_pages(&["first native page", "recognized second page", "third native page"]);
+        assert_eq!(merged, expected);
+        assert_eq!(merged_boundaries.len(), 3);
+        for (actual, expected) in merged_boundaries.iter().zip(&expected_boundaries) {
+            assert_eq!(actual.page_number, expected.page_number);
+            assert_eq!(actual.byte_start, expected.byte_start);
+            assert_eq!(actual.byte_end, expected.byte_end);
+        }
+        assert_eq!(
+            &merged[merged_boundaries[1].byte_start..merged_boundaries[1].byte_end],
+            "recognized second page"
+        );
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_merge_ocr_pages_without_ocr_is_identity() {
+        let (text, boundaries) = marked_pages(&["first native page", "second native page"]);
+
+        let (merged, merged_boundaries) = merge_ocr_pages(&text, &boundaries, &HashMap::new());
+
+        assert_eq!(merged, text);
+        assert_eq!(merged_boundaries[1].byte_start, boundaries[1].byte_start);
+        assert_eq!(merged_boundaries[1].byte_end, boundaries[1].byte_end);
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_apply_ocr_pages_marks_text_source() {
+        use crate::types::{PageContent, PageTextSource};
+
+        let page = |page_number: usize, content: &str| PageContent {
+            page_number,
+            content: content.to_string(),
+            tables: Vec::new(),
+            images: Vec::new(),
+            hierarchy: None,
+            is_blank: Some(content.trim().is_empty()),
+            language: None,
+            language_confidence: None,
+            text_source: Some(PageTextSource::TextLayer),
+        };
+        let mut pages = vec![page(1, "native text"), page(2, "")];
+        let ocr_pages = HashMap::from([(2, "recognized text".to_string())]);
+
+        apply_ocr_pages(&mut pages, &ocr_pages);
+
+        assert_eq!(pages[0].content, "native text");
+        assert_eq!(pages[0].text_source, Some(PageTextSource::TextLayer));
+        assert_eq!(pages[1].content, "recognized text");
+        assert_eq!(pages[1].text_source, Some(PageTextSource::Ocr));
+        assert_eq!(pages[1].is_blank, Some(false));
+    }
+
     #[tokio::test]
     #[cfg(feature = "pdf")]
     async fn test_pdf_batch_mode_validates_page_config_enabled() {
@@ -166,20 +166,48 @@ pub fn evaluate_per_page_ocr(
     document_decision
 }
 
-/// Extract text from PDF using OCR.
+/// Find the pages whose native text layer is missing or unusable.
 ///
-/// Renders all pages to images and processes them with OCR backend.
+/// Each page is evaluated on its own with [`evaluate_native_text_for_ocr`], so a
+/// scanned page in an otherwise digital document is reported without affecting
+/// its neighbours.
+///
+/// # Returns
+///
+/// 1-indexed page numbers in document order
+#[cfg(feature = "ocr")]
+pub(crate) fn pages_needing_ocr(native_text: &str, boundaries: &[crate::types::PageBoundary]) -> Vec<usize> {
+    boundaries
+        .iter()
+        .filter(|boundary| {
+            // Boundaries that don't fit the text are skipped, as in `evaluate_per_page_ocr`
+            native_text
+                .get(boundary.byte_start..boundary.byte_end)
+                .is_some_and(|page_text| evaluate_native_text_for_ocr(page_text, Some(1)).fallback)
+        })
+        .map(|boundary| boundary.page_number)
+        .collect()
+}
+
+/// Extract text from selected PDF pages using OCR.
+///
+/// Renders only the requested pages to images and processes them with the OCR backend.
 ///
 /// # Arguments
 ///
 /// * `content` - Raw PDF bytes
+/// * `page_numbers` - 1-indexed pages to OCR
 /// * `config` - Extraction configuration including OCR settings
 ///
 /// # Returns
 ///
-/// Concatenated text from all pages, separated by double newlines
+/// OCR text for each requested page, in the order given
 #[cfg(feature = "ocr")]
-pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig) -> crate::Result<String> {
+pub(crate) async fn extract_pages_with_ocr(
+    content: &[u8],
+    page_numbers: &[usize],
+    config: &ExtractionConfig,
+) -> crate::Result<Vec<String>> {
     use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
     use crate::plugins::registry::get_ocr_backend_registry;
     use image::ImageEncoder;
@@ -187,7 +215,7 @@ pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig)
     use std::io::Cursor;
 
     let ocr_config = config.ocr.as_ref().ok_or_else(|| crate::KreuzbergError::Parsing {
-        message: "OCR config required for force_ocr".to_string(),
+        message: "OCR config required for PDF OCR".to_string(),
         source: None,
     })?;
 
@@ -207,17 +235,25 @@ pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig)
             source: None,
         })?;
 
-        renderer
-            .render_all_pages(content, &render_options)
-            .map_err(|e| crate::KreuzbergError::Parsing {
-                message: format!("Failed to render PDF pages: {}", e),
-                source: None,
-            })?
+        page_numbers
+            .iter()
+            .map(|&page_number| {
+                renderer
+                    .render_page_to_image(content, page_number.saturating_sub(1), &render_options)
+                    .map_err(|e| crate::KreuzbergError::Parsing {
+                        message: format!("Failed to render PDF page {}: {}", page_number, e),
+                        source: None,
+                    })
+            })
+            .collect::<crate::Result<Vec<_>>>()?
     };
 
     let mut page_texts = Vec::with_capacity(images.len());
 
-    for image in images {
+    #[cfg(feature = "api")]
+    let page_sink = crate::core::page_stream::current_page_sink();
+
+    for (&page_number, image) in page_numbers.iter().zip(images) {
         let rgb_image = image.to_rgb8();
         let (width, height) = rgb_image.dimensions();
 
@@ -232,10 +268,35 @@ pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig)
 
         let image_data = image_bytes.into_inner();
 
-        let ocr_result = backend.process_image(&image_data, ocr_config).await?;
+        let ocr_result = crate::ocr::auto_language::process_image(&*backend, &image_data, ocr_config).await?;
+        crate::metrics::metrics().record_ocr_pages(&ocr_config.backend, 1);
+
+        #[cfg(feature = "api")]
+        if let Some(ref sink) = page_sink {
+            let page = crate::types::PageContent {
+                page_number,
+                content: ocr_result.content.clone(),
+                tables: Vec::new(),
+                images: Vec::new(),
+                hierarchy: None,
+                is_blank: Some(crate::extraction::blank_detection::is_page_text_blank(
+                    &ocr_result.content,
+                )),
+                language: None,
+                language_confidence: None,
+                text_source: Some(crate::types::PageTextSource::Ocr),
+            };
+            if !sink.send(page) {
+                return Err(crate::KreuzbergError::Other(
+                    "Extraction cancelled by the page stream consumer".to_string(),
+                ));
+            }
+        }
+        #[cfg(not(feature = "api"))]
+        let _ = page_number;
 
         page_texts.push(ocr_result.content);
     }
 
-    Ok(page_texts.join("\n\n"))
+    Ok(page_texts)
 }
@@ -1,8 +1,13 @@
 //! Page content management for PDF extraction.
 //!
-//! Handles assignment of tables and images to specific pages.
+//! Handles assignment of tables and images to specific pages, and merging
+//! OCR text for individual pages back into the native extraction.
 
 use crate::types::PageContent;
+#[cfg(feature = "ocr")]
+use crate::types::{PageBoundary, PageTextSource};
+#[cfg(feature = "ocr")]
+use std::collections::HashMap;
 
 /// Helper function to assign tables and images to pages.
 ///
@@ -56,3 +61,64 @@ pub(crate) fn assign_tables_and_images_to_pages(
 
     Some(updated_pages)
 }
+
+/// Splice OCR text for individual pages into the native text.
+///
+/// Text outside the page boundaries (page markers and separators) is kept as is,
+/// so the merged content has the same layout as the native extraction. Pages
+/// without an entry in `ocr_pages` keep their native text.
+///
+/// # Returns
+///
+/// The merged content and its page boundaries
+#[cfg(feature = "ocr")]
+pub(crate) fn merge_ocr_pages(
+    native_text: &str,
+    boundaries: &[PageBoundary],
+    ocr_pages: &HashMap<usize, String>,
+) -> (String, Vec<PageBoundary>) {
+    let ocr_len: usize = ocr_pages.values().map(String::len).sum();
+    let mut content = String::with_capacity(native_text.len() + ocr_len);
+    let mut merged_boundaries = Vec::with_capacity(boundaries.len());
+    let mut cursor = 0;
+
+    for boundary in boundaries {
+        if boundary.byte_start < cursor || native_text.get(boundary.byte_start..boundary.byte_end).is_none() {
+            continue;
+        }
+
+        content.push_str(&native_text[cursor..boundary.byte_start]);
+        let byte_start = content.len();
+        match ocr_pages.get(&boundary.page_number) {
+            Some(ocr_text) => content.push_str(ocr_text),
+            None => content.push_str(&native_text[boundary.byte_start..boundary.byte_end]),
+        }
+        merged_boundaries.push(PageBoundary {
+            byte_start,
+            byte_end: content.len(),
+            page_number: boundary.page_number,
+        });
+        cursor = boundary.byte_end;
+    }
+    content.push_str(&native_text[cursor..]);
+
+    (content, merged_boundaries)
+}
+
+/// Replace the text of OCR'd pages and record where each page's text came from.
+///
+/// Hierarchy is dropped for OCR'd pages since it was derived from the text layer.
+#[cfg(feature = "ocr")]
+pub(crate) fn apply_ocr_pages(page_contents: &mut [PageContent], ocr_pages: &HashMap<usize, String>) {
+    for page in page_contents {
+        match ocr_pages.get(&page.page_number) {
+            Some(ocr_text) => {
+                page.content = ocr_text.clone();
+                page.hierarchy = None;
+                page.is_blank = Some(crate::extraction::blank_detection::is_page_text_blank(ocr_text));
+                page.text_source = Some(PageTextSource::Ocr);
+            }
+            None => page.text_source = Some(PageTextSource::TextLayer),
+        }
+    }
+}
@@ -147,6 +147,7 @@ impl DocumentExtractor for PptExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: Some(vec![]),
             djot_content: None,
@@ -157,6 +158,7 @@ impl DocumentExtractor for PptExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
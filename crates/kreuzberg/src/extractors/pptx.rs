@@ -136,6 +136,7 @@ impl DocumentExtractor for PptxExtractor {
             pages: pptx_result.page_contents,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images,
             djot_content: None,
@@ -146,6 +147,7 @@ impl DocumentExtractor for PptxExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -208,6 +210,7 @@ impl DocumentExtractor for PptxExtractor {
             pages: pptx_result.page_contents,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images,
             djot_content: None,
@@ -218,6 +221,7 @@ impl DocumentExtractor for PptxExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -347,6 +347,8 @@ impl RstExtractor {
             cells,
             markdown,
             page_number: 1,
+            grid: None,
+            page_end: None,
         })
     }
 
@@ -453,6 +455,7 @@ impl DocumentExtractor for RstExtractor {
             metadata,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -464,6 +467,7 @@ impl DocumentExtractor for RstExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
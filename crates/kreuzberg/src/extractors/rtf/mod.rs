@@ -103,6 +103,7 @@ impl DocumentExtractor for RtfExtractor {
             pages: None,
             tables,
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -113,6 +114,7 @@ impl DocumentExtractor for RtfExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -72,6 +72,8 @@ impl TableState {
             cells: self.rows,
             markdown,
             page_number: 1,
+            grid: None,
+            page_end: None,
         })
     }
 }
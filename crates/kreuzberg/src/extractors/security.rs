@@ -43,6 +43,11 @@ pub struct SecurityLimits {
 
     /// Maximum cells per table (100,000)
     pub max_table_cells: usize,
+
+    /// Maximum nesting depth for recursive archive extraction (10)
+    ///
+    /// Counts container levels, so an archive inside an archive has depth 2.
+    pub max_archive_depth: usize,
 }
 
 impl Default for SecurityLimits {
@@ -57,6 +62,7 @@ impl Default for SecurityLimits {
             max_iterations: 10_000_000,
             max_xml_depth: 100,
             max_table_cells: 100_000,
+            max_archive_depth: 10,
         }
     }
 }
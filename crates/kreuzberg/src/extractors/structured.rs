@@ -93,6 +93,7 @@ impl DocumentExtractor for StructuredExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             elements: None,
@@ -103,6 +104,7 @@ impl DocumentExtractor for StructuredExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
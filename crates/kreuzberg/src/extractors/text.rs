@@ -89,6 +89,7 @@ impl DocumentExtractor for PlainTextExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             elements: None,
@@ -99,6 +100,7 @@ impl DocumentExtractor for PlainTextExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -196,6 +198,7 @@ impl DocumentExtractor for MarkdownExtractor {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             elements: None,
@@ -206,6 +209,7 @@ impl DocumentExtractor for MarkdownExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
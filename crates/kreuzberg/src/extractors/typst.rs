@@ -110,6 +110,7 @@ impl DocumentExtractor for TypstExtractor {
             metadata,
             tables: Vec::new(),
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -121,6 +122,7 @@ impl DocumentExtractor for TypstExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -68,6 +68,7 @@ impl SyncExtractor for XmlExtractor {
             },
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -79,6 +80,7 @@ impl SyncExtractor for XmlExtractor {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 }
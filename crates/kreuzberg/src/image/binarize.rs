@@ -0,0 +1,255 @@
+//! Binarization of grayscale images for OCR.
+//!
+//! Provides a global Otsu threshold and two local (window-based) methods:
+//! Sauvola, which adapts to local contrast and copes well with uneven
+//! illumination and stained paper, and a simple mean-adaptive threshold.
+//! Local methods are computed in O(1) per pixel using integral images.
+
+use image::{GrayImage, Luma};
+
+/// Default Sauvola sensitivity parameter `k`.
+const SAUVOLA_K: f64 = 0.34;
+
+/// Dynamic range of the standard deviation for 8-bit images.
+const SAUVOLA_R: f64 = 128.0;
+
+/// Offset subtracted from the local mean in mean-adaptive thresholding.
+const ADAPTIVE_OFFSET: f64 = 10.0;
+
+/// Compute the 256-bin intensity histogram of a grayscale image.
+pub fn histogram(image: &GrayImage) -> [u64; 256] {
+    let mut hist = [0u64; 256];
+    for pixel in image.as_raw() {
+        hist[*pixel as usize] += 1;
+    }
+    hist
+}
+
+/// Compute the Otsu threshold that maximizes between-class variance.
+///
+/// Pixels with intensity `<= threshold` belong to the dark (foreground) class.
+pub fn otsu_threshold(image: &GrayImage) -> u8 {
+    let hist = histogram(image);
+    let total: u64 = hist.iter().sum();
+    if total == 0 {
+        return 127;
+    }
+
+    let sum_all: f64 = hist.iter().enumerate().map(|(i, &c)| i as f64 * c as f64).sum();
+
+    let mut sum_background = 0.0;
+    let mut weight_background = 0u64;
+    let mut best_variance = -1.0;
+    let mut best_threshold = 0u8;
+
+    for (t, &count) in hist.iter().enumerate() {
+        weight_background += count;
+        if weight_background == 0 {
+            continue;
+        }
+        let weight_foreground = total - weight_background;
+        if weight_foreground == 0 {
+            break;
+        }
+
+        sum_background += t as f64 * count as f64;
+        let mean_background = sum_background / weight_background as f64;
+        let mean_foreground = (sum_all - sum_background) / weight_foreground as f64;
+        let diff = mean_background - mean_foreground;
+        let variance = weight_background as f64 * weight_foreground as f64 * diff * diff;
+
+        if variance > best_variance {
+            best_variance = variance;
+            best_threshold = t as u8;
+        }
+    }
+
+    best_threshold
+}
+
+/// Binarize using a global Otsu threshold.
+///
+/// Returns the binarized image (text black, background white) and the threshold used.
+pub fn binarize_otsu(image: &GrayImage) -> (GrayImage, u8) {
+    let threshold = otsu_threshold(image);
+    (apply_global_threshold(image, threshold), threshold)
+}
+
+/// Apply a fixed global threshold: `<= threshold` becomes black, everything else white.
+pub fn apply_global_threshold(image: &GrayImage, threshold: u8) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let data = image
+        .as_raw()
+        .iter()
+        .map(|&p| if p <= threshold { 0 } else { 255 })
+        .collect();
+    GrayImage::from_raw(width, height, data).unwrap_or_else(|| GrayImage::new(width, height))
+}
+
+/// Binarize using Sauvola's local threshold `T = m * (1 + k * (s / R - 1))`.
+///
+/// `window` is the side length of the square neighbourhood; it is forced to be odd.
+pub fn binarize_sauvola(image: &GrayImage, window: u32) -> GrayImage {
+    local_threshold(image, window, |mean, std_dev| {
+        mean * (1.0 + SAUVOLA_K * (std_dev / SAUVOLA_R - 1.0))
+    })
+}
+
+/// Binarize using a mean-adaptive threshold `T = m - C`.
+pub fn binarize_adaptive(image: &GrayImage, window: u32) -> GrayImage {
+    local_threshold(image, window, |mean, _| mean - ADAPTIVE_OFFSET)
+}
+
+/// Pick a local window size proportional to the image size (roughly one text line high).
+pub fn default_window_size(width: u32, height: u32) -> u32 {
+    let base = (width.min(height) / 40).clamp(15, 101);
+    base | 1
+}
+
+fn local_threshold<F>(image: &GrayImage, window: u32, threshold_fn: F) -> GrayImage
+where
+    F: Fn(f64, f64) -> f64,
+{
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return image.clone();
+    }
+
+    let integrals = IntegralImages::new(image);
+    let half = (window.max(3) | 1) / 2;
+    let mut output = GrayImage::new(width, height);
+
+    for y in 0..height {
+        let y0 = y.saturating_sub(half);
+        let y1 = (y + half).min(height - 1);
+        for x in 0..width {
+            let x0 = x.saturating_sub(half);
+            let x1 = (x + half).min(width - 1);
+
+            let (mean, std_dev) = integrals.mean_std(x0, y0, x1, y1);
+            let threshold = threshold_fn(mean, std_dev);
+            let value = image.get_pixel(x, y).0[0];
+            let out = if f64::from(value) <= threshold { 0 } else { 255 };
+            output.put_pixel(x, y, Luma([out]));
+        }
+    }
+
+    output
+}
+
+/// Summed-area tables of pixel values and squared pixel values.
+pub(crate) struct IntegralImages {
+    width: usize,
+    sum: Vec<f64>,
+    sum_sq: Vec<f64>,
+}
+
+impl IntegralImages {
+    pub(crate) fn new(image: &GrayImage) -> Self {
+        let (width, height) = (image.width() as usize, image.height() as usize);
+        let stride = width + 1;
+        let mut sum = vec![0.0; stride * (height + 1)];
+        let mut sum_sq = vec![0.0; stride * (height + 1)];
+        let raw = image.as_raw();
+
+        for y in 0..height {
+            let mut row_sum = 0.0;
+            let mut row_sum_sq = 0.0;
+            for x in 0..width {
+                let v = f64::from(raw[y * width + x]);
+                row_sum += v;
+                row_sum_sq += v * v;
+                let idx = (y + 1) * stride + (x + 1);
+                sum[idx] = sum[idx - stride] + row_sum;
+                sum_sq[idx] = sum_sq[idx - stride] + row_sum_sq;
+            }
+        }
+
+        Self {
+            width: stride,
+            sum,
+            sum_sq,
+        }
+    }
+
+    /// Mean and standard deviation over the inclusive rectangle `(x0, y0)..=(x1, y1)`.
+    pub(crate) fn mean_std(&self, x0: u32, y0: u32, x1: u32, y1: u32) -> (f64, f64) {
+        let (x0, y0, x1, y1) = (x0 as usize, y0 as usize, x1 as usize + 1, y1 as usize + 1);
+        let area = ((x1 - x0) * (y1 - y0)) as f64;
+        let rect = |table: &[f64]| {
+            table[y1 * self.width + x1] - table[y0 * self.width + x1] - table[y1 * self.width + x0]
+                + table[y0 * self.width + x0]
+        };
+        let mean = rect(&self.sum) / area;
+        let variance = (rect(&self.sum_sq) / area - mean * mean).max(0.0);
+        (mean, variance.sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bimodal_image() -> GrayImage {
+        GrayImage::from_fn(40, 40, |x, _| if x < 20 { Luma([40]) } else { Luma([210]) })
+    }
+
+    #[test]
+    fn test_otsu_threshold_separates_modes() {
+        let threshold = otsu_threshold(&bimodal_image());
+        assert!((40..210).contains(&threshold));
+    }
+
+    #[test]
+    fn test_binarize_otsu_produces_two_levels() {
+        let (binary, _) = binarize_otsu(&bimodal_image());
+        assert!(binary.as_raw().iter().all(|&p| p == 0 || p == 255));
+        assert_eq!(binary.get_pixel(0, 0).0[0], 0);
+        assert_eq!(binary.get_pixel(39, 0).0[0], 255);
+    }
+
+    #[test]
+    fn test_sauvola_handles_gradient_background() {
+        // Dark text stroke on a background that fades from light to mid gray.
+        let image = GrayImage::from_fn(120, 60, |x, y| {
+            if (28..32).contains(&y) {
+                Luma([20])
+            } else {
+                Luma([230 - (x as u8 / 2)])
+            }
+        });
+
+        let binary = binarize_sauvola(&image, 15);
+        assert_eq!(binary.get_pixel(100, 30).0[0], 0);
+        assert_eq!(binary.get_pixel(100, 5).0[0], 255);
+        assert_eq!(binary.get_pixel(5, 5).0[0], 255);
+    }
+
+    #[test]
+    fn test_adaptive_threshold_marks_text() {
+        let image = GrayImage::from_fn(
+            60,
+            60,
+            |x, _| if (28..32).contains(&x) { Luma([30]) } else { Luma([200]) },
+        );
+        let binary = binarize_adaptive(&image, 15);
+        assert_eq!(binary.get_pixel(30, 30).0[0], 0);
+        assert_eq!(binary.get_pixel(5, 30).0[0], 255);
+    }
+
+    #[test]
+    fn test_integral_mean_std() {
+        let image = GrayImage::from_fn(4, 4, |x, _| Luma([if x % 2 == 0 { 0 } else { 100 }]));
+        let integrals = IntegralImages::new(&image);
+        let (mean, std_dev) = integrals.mean_std(0, 0, 3, 3);
+        assert!((mean - 50.0).abs() < 1e-9);
+        assert!((std_dev - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_default_window_size_is_odd() {
+        assert_eq!(default_window_size(100, 100) % 2, 1);
+        assert_eq!(default_window_size(2480, 3508) % 2, 1);
+        assert!(default_window_size(2480, 3508) >= 15);
+    }
+}
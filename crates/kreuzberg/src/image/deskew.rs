@@ -0,0 +1,243 @@
+//! Skew estimation and correction.
+//!
+//! The skew angle is estimated with a projection-profile search: dark (ink)
+//! pixels are projected onto the vertical axis for a range of candidate
+//! angles, and the angle whose profile has the highest energy (sum of squared
+//! bin counts) wins, because text lines collapse into sharp peaks only when
+//! the projection direction is parallel to them. A coarse 1° sweep is followed
+//! by a 0.1° refinement around the best candidate.
+
+use image::{GrayImage, Luma, imageops};
+
+use super::binarize::otsu_threshold;
+
+/// Largest skew (in degrees, either direction) that is searched.
+pub const MAX_SKEW_DEGREES: f64 = 15.0;
+
+/// Skew angles below this magnitude are not worth correcting.
+pub const MIN_SKEW_DEGREES: f64 = 0.1;
+
+/// Longest side of the downscaled image used for analysis.
+const ANALYSIS_MAX_DIMENSION: u32 = 1600;
+
+/// Upper bound on ink samples used for the projection search.
+const MAX_INK_SAMPLES: usize = 200_000;
+
+/// Collect coordinates of ink (dark) pixels from a downscaled copy of `image`.
+///
+/// Returns the sampled points together with the analysis image dimensions.
+pub(crate) fn ink_points(image: &GrayImage) -> (Vec<(f64, f64)>, (u32, u32)) {
+    let analysis = downscale_for_analysis(image);
+    let threshold = otsu_threshold(&analysis);
+    let (width, height) = analysis.dimensions();
+
+    let total_ink = analysis.as_raw().iter().filter(|&&p| p <= threshold).count();
+    let step = (total_ink / MAX_INK_SAMPLES).max(1);
+
+    let mut points = Vec::with_capacity(total_ink.min(MAX_INK_SAMPLES) + 1);
+    let mut seen = 0usize;
+    for (x, y, pixel) in analysis.enumerate_pixels() {
+        if pixel.0[0] <= threshold {
+            if seen.is_multiple_of(step) {
+                points.push((f64::from(x), f64::from(y)));
+            }
+            seen += 1;
+        }
+    }
+
+    (points, (width, height))
+}
+
+pub(crate) fn downscale_for_analysis(image: &GrayImage) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let longest = width.max(height);
+    if longest <= ANALYSIS_MAX_DIMENSION || longest == 0 {
+        return image.clone();
+    }
+    let scale = f64::from(ANALYSIS_MAX_DIMENSION) / f64::from(longest);
+    let new_width = ((f64::from(width) * scale).round() as u32).max(1);
+    let new_height = ((f64::from(height) * scale).round() as u32).max(1);
+    imageops::resize(image, new_width, new_height, imageops::FilterType::Triangle)
+}
+
+/// Energy of the horizontal projection profile after rotating points by `angle_degrees`.
+pub(crate) fn projection_energy(points: &[(f64, f64)], angle_degrees: f64, height: u32, width: u32) -> f64 {
+    let (sin, cos) = angle_degrees.to_radians().sin_cos();
+    // Rotated y can range over [-width, height + width]; offset into positive bins.
+    let offset = f64::from(width);
+    let bins = (height + 2 * width + 1) as usize;
+    let mut profile = vec![0u32; bins];
+
+    for &(x, y) in points {
+        let projected = y * cos + x * sin + offset;
+        if projected >= 0.0 {
+            let bin = projected as usize;
+            if bin < bins {
+                profile[bin] += 1;
+            }
+        }
+    }
+
+    profile.iter().map(|&c| f64::from(c) * f64::from(c)).sum()
+}
+
+/// Estimate the skew angle of text lines in degrees.
+///
+/// Positive angles mean the lines rise to the right (the page is rotated
+/// counter-clockwise). Returns `None` when there is too little ink to decide.
+pub fn estimate_skew_angle(image: &GrayImage) -> Option<f64> {
+    let (points, (width, height)) = ink_points(image);
+    if points.len() < 50 {
+        return None;
+    }
+
+    let search = |from: f64, to: f64, step: f64| {
+        let mut best_angle = 0.0;
+        let mut best_energy = f64::MIN;
+        let steps = ((to - from) / step).round() as i64;
+        for i in 0..=steps {
+            let angle = from + i as f64 * step;
+            let energy = projection_energy(&points, angle, height, width);
+            // Prefer the smaller correction on ties.
+            if energy > best_energy || (energy == best_energy && angle.abs() < f64::abs(best_angle)) {
+                best_energy = energy;
+                best_angle = angle;
+            }
+        }
+        best_angle
+    };
+
+    let coarse = search(-MAX_SKEW_DEGREES, MAX_SKEW_DEGREES, 1.0);
+    let fine = search(
+        (coarse - 1.0).max(-MAX_SKEW_DEGREES),
+        (coarse + 1.0).min(MAX_SKEW_DEGREES),
+        0.1,
+    );
+
+    Some((fine * 10.0).round() / 10.0)
+}
+
+/// Rotate an image counter-clockwise by `angle_degrees` around its center.
+///
+/// The canvas grows so no content is clipped; uncovered areas are filled with
+/// `fill`. Sampling is bilinear.
+pub fn rotate_gray(image: &GrayImage, angle_degrees: f64, fill: u8) -> GrayImage {
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 || angle_degrees == 0.0 {
+        return image.clone();
+    }
+
+    let (sin, cos) = angle_degrees.to_radians().sin_cos();
+    let (w, h) = (f64::from(width), f64::from(height));
+    // Trim floating-point noise so quarter turns keep exact dimensions.
+    let new_width = (w * cos.abs() + h * sin.abs() - 1e-6).ceil().max(1.0) as u32;
+    let new_height = (w * sin.abs() + h * cos.abs() - 1e-6).ceil().max(1.0) as u32;
+
+    let (cx, cy) = (w / 2.0, h / 2.0);
+    let (ncx, ncy) = (f64::from(new_width) / 2.0, f64::from(new_height) / 2.0);
+
+    let mut output = GrayImage::from_pixel(new_width, new_height, Luma([fill]));
+    for oy in 0..new_height {
+        for ox in 0..new_width {
+            let dx = f64::from(ox) + 0.5 - ncx;
+            let dy = f64::from(oy) + 0.5 - ncy;
+            // Inverse mapping (y axis points down, so CCW on screen is CW in math terms).
+            let sx = dx * cos - dy * sin + cx - 0.5;
+            let sy = dx * sin + dy * cos + cy - 0.5;
+            if let Some(value) = sample_bilinear(image, sx, sy) {
+                output.put_pixel(ox, oy, Luma([value]));
+            }
+        }
+    }
+
+    output
+}
+
+fn sample_bilinear(image: &GrayImage, x: f64, y: f64) -> Option<u8> {
+    let (width, height) = image.dimensions();
+    if x < -0.5 || y < -0.5 || x > f64::from(width) - 0.5 || y > f64::from(height) - 0.5 {
+        return None;
+    }
+
+    let x = x.clamp(0.0, f64::from(width - 1));
+    let y = y.clamp(0.0, f64::from(height - 1));
+    let x0 = x.floor() as u32;
+    let y0 = y.floor() as u32;
+    let x1 = (x0 + 1).min(width - 1);
+    let y1 = (y0 + 1).min(height - 1);
+    let fx = x - f64::from(x0);
+    let fy = y - f64::from(y0);
+
+    let p = |px: u32, py: u32| f64::from(image.get_pixel(px, py).0[0]);
+    let top = p(x0, y0) * (1.0 - fx) + p(x1, y0) * fx;
+    let bottom = p(x0, y1) * (1.0 - fx) + p(x1, y1) * fx;
+    Some((top * (1.0 - fy) + bottom * fy).round().clamp(0.0, 255.0) as u8)
+}
+
+/// Estimate and correct skew.
+///
+/// Returns the corrected image and the detected angle, or `None` for the
+/// angle when no correction was applied.
+pub fn deskew(image: &GrayImage) -> (GrayImage, Option<f64>) {
+    match estimate_skew_angle(image) {
+        Some(angle) if angle.abs() >= MIN_SKEW_DEGREES => (rotate_gray(image, -angle, 255), Some(angle)),
+        _ => (image.clone(), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// White page with several thick horizontal "text lines".
+    fn lined_page(width: u32, height: u32) -> GrayImage {
+        GrayImage::from_fn(width, height, |x, y| {
+            let in_line = (y % 40) < 8 && y > 20 && y + 20 < height;
+            let in_margin = x < 20 || x + 20 > width;
+            if in_line && !in_margin { Luma([0]) } else { Luma([255]) }
+        })
+    }
+
+    #[test]
+    fn test_estimate_skew_straight_page() {
+        let angle = estimate_skew_angle(&lined_page(400, 400)).unwrap();
+        assert!(angle.abs() < 0.3, "angle was {angle}");
+    }
+
+    #[test]
+    fn test_estimate_skew_rotated_page() {
+        let rotated = rotate_gray(&lined_page(400, 400), 3.0, 255);
+        let angle = estimate_skew_angle(&rotated).unwrap();
+        assert!((angle - 3.0).abs() < 0.5, "angle was {angle}");
+
+        let rotated = rotate_gray(&lined_page(400, 400), -4.0, 255);
+        let angle = estimate_skew_angle(&rotated).unwrap();
+        assert!((angle + 4.0).abs() < 0.5, "angle was {angle}");
+    }
+
+    #[test]
+    fn test_deskew_corrects_rotation() {
+        let rotated = rotate_gray(&lined_page(400, 400), 5.0, 255);
+        let (corrected, angle) = deskew(&rotated);
+        assert!(angle.is_some());
+        let residual = estimate_skew_angle(&corrected).unwrap();
+        assert!(residual.abs() < 0.5, "residual was {residual}");
+    }
+
+    #[test]
+    fn test_deskew_blank_page() {
+        let blank = GrayImage::from_pixel(100, 100, Luma([255]));
+        let (corrected, angle) = deskew(&blank);
+        assert!(angle.is_none());
+        assert_eq!(corrected.dimensions(), (100, 100));
+    }
+
+    #[test]
+    fn test_rotate_gray_expands_canvas() {
+        let image = GrayImage::from_pixel(100, 50, Luma([0]));
+        let rotated = rotate_gray(&image, 90.0, 255);
+        assert_eq!(rotated.dimensions(), (50, 100));
+        let rotated = rotate_gray(&image, 10.0, 255);
+        assert!(rotated.width() > 100 && rotated.height() > 50);
+    }
+}
@@ -0,0 +1,296 @@
+//! Grayscale enhancement filters used ahead of OCR.
+//!
+//! - Median filter: removes salt-and-pepper speckle typical of faxes and bilevel scans.
+//! - Bilateral filter: smooths paper texture and JPEG noise while keeping stroke edges.
+//! - CLAHE: contrast-limited adaptive histogram equalization for faded or unevenly lit pages.
+//! - Inversion: turns light-on-dark text into dark-on-light.
+
+use image::{GrayImage, Luma};
+
+/// Default CLAHE tile grid (tiles per axis).
+const CLAHE_TILES: u32 = 8;
+
+/// Default CLAHE clip limit, as a multiple of the mean histogram bin height.
+const CLAHE_CLIP_LIMIT: f64 = 2.0;
+
+/// Apply a square median filter with the given radius (radius 1 = 3x3 window).
+pub fn median_filter(image: &GrayImage, radius: u32) -> GrayImage {
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 || radius == 0 {
+        return image.clone();
+    }
+
+    let mut output = GrayImage::new(width, height);
+    let mut window = Vec::with_capacity(((2 * radius + 1) * (2 * radius + 1)) as usize);
+
+    for y in 0..height {
+        let y0 = y.saturating_sub(radius);
+        let y1 = (y + radius).min(height - 1);
+        for x in 0..width {
+            let x0 = x.saturating_sub(radius);
+            let x1 = (x + radius).min(width - 1);
+
+            window.clear();
+            for wy in y0..=y1 {
+                for wx in x0..=x1 {
+                    window.push(image.get_pixel(wx, wy).0[0]);
+                }
+            }
+            let mid = window.len() / 2;
+            let (_, median, _) = window.select_nth_unstable(mid);
+            output.put_pixel(x, y, Luma([*median]));
+        }
+    }
+
+    output
+}
+
+/// Apply an edge-preserving bilateral filter.
+///
+/// `radius` is the window radius, `sigma_space` the spatial Gaussian width in
+/// pixels and `sigma_range` the intensity Gaussian width in gray levels.
+pub fn bilateral_filter(image: &GrayImage, radius: u32, sigma_space: f64, sigma_range: f64) -> GrayImage {
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 || radius == 0 {
+        return image.clone();
+    }
+
+    let r = radius as i64;
+    let size = (2 * r + 1) as usize;
+    let mut spatial = vec![0.0; size * size];
+    for dy in -r..=r {
+        for dx in -r..=r {
+            let d2 = (dx * dx + dy * dy) as f64;
+            spatial[((dy + r) as usize) * size + (dx + r) as usize] = (-d2 / (2.0 * sigma_space * sigma_space)).exp();
+        }
+    }
+    let range: Vec<f64> = (0..256)
+        .map(|d| {
+            let d = d as f64;
+            (-(d * d) / (2.0 * sigma_range * sigma_range)).exp()
+        })
+        .collect();
+
+    let mut output = GrayImage::new(width, height);
+    let (w, h) = (width as i64, height as i64);
+
+    for y in 0..h {
+        for x in 0..w {
+            let center = image.get_pixel(x as u32, y as u32).0[0];
+            let mut weighted = 0.0;
+            let mut total = 0.0;
+            for dy in -r..=r {
+                let ny = y + dy;
+                if ny < 0 || ny >= h {
+                    continue;
+                }
+                for dx in -r..=r {
+                    let nx = x + dx;
+                    if nx < 0 || nx >= w {
+                        continue;
+                    }
+                    let value = image.get_pixel(nx as u32, ny as u32).0[0];
+                    let weight = spatial[((dy + r) as usize) * size + (dx + r) as usize]
+                        * range[(i16::from(value) - i16::from(center)).unsigned_abs() as usize];
+                    weighted += weight * f64::from(value);
+                    total += weight;
+                }
+            }
+            let value = if total > 0.0 {
+                weighted / total
+            } else {
+                f64::from(center)
+            };
+            output.put_pixel(x as u32, y as u32, Luma([value.round().clamp(0.0, 255.0) as u8]));
+        }
+    }
+
+    output
+}
+
+/// Apply CLAHE with the default 8x8 tile grid and clip limit 2.0.
+pub fn clahe(image: &GrayImage) -> GrayImage {
+    clahe_with_params(image, CLAHE_TILES, CLAHE_CLIP_LIMIT)
+}
+
+/// Apply contrast-limited adaptive histogram equalization.
+///
+/// The image is divided into `tiles x tiles` regions; each region gets its own
+/// clipped equalization mapping, and pixels are remapped by bilinearly
+/// interpolating between the four nearest tile mappings to avoid block seams.
+pub fn clahe_with_params(image: &GrayImage, tiles: u32, clip_limit: f64) -> GrayImage {
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return image.clone();
+    }
+
+    let tiles_x = tiles.clamp(1, width);
+    let tiles_y = tiles.clamp(1, height);
+    let tile_w = width.div_ceil(tiles_x);
+    let tile_h = height.div_ceil(tiles_y);
+
+    let mut maps = vec![[0u8; 256]; (tiles_x * tiles_y) as usize];
+    for ty in 0..tiles_y {
+        for tx in 0..tiles_x {
+            let x0 = tx * tile_w;
+            let y0 = ty * tile_h;
+            let x1 = (x0 + tile_w).min(width);
+            let y1 = (y0 + tile_h).min(height);
+            maps[(ty * tiles_x + tx) as usize] = tile_mapping(image, x0, y0, x1, y1, clip_limit);
+        }
+    }
+
+    let mut output = GrayImage::new(width, height);
+    for y in 0..height {
+        // Position relative to tile centers.
+        let fy = (f64::from(y) + 0.5) / f64::from(tile_h) - 0.5;
+        let ty0 = fy.floor().clamp(0.0, f64::from(tiles_y - 1)) as u32;
+        let ty1 = (ty0 + 1).min(tiles_y - 1);
+        let wy = (fy - f64::from(ty0)).clamp(0.0, 1.0);
+
+        for x in 0..width {
+            let fx = (f64::from(x) + 0.5) / f64::from(tile_w) - 0.5;
+            let tx0 = fx.floor().clamp(0.0, f64::from(tiles_x - 1)) as u32;
+            let tx1 = (tx0 + 1).min(tiles_x - 1);
+            let wx = (fx - f64::from(tx0)).clamp(0.0, 1.0);
+
+            let v = image.get_pixel(x, y).0[0] as usize;
+            let m = |tx: u32, ty: u32| f64::from(maps[(ty * tiles_x + tx) as usize][v]);
+            let top = m(tx0, ty0) * (1.0 - wx) + m(tx1, ty0) * wx;
+            let bottom = m(tx0, ty1) * (1.0 - wx) + m(tx1, ty1) * wx;
+            let value = top * (1.0 - wy) + bottom * wy;
+            output.put_pixel(x, y, Luma([value.round().clamp(0.0, 255.0) as u8]));
+        }
+    }
+
+    output
+}
+
+fn tile_mapping(image: &GrayImage, x0: u32, y0: u32, x1: u32, y1: u32, clip_limit: f64) -> [u8; 256] {
+    let mut hist = [0u32; 256];
+    for y in y0..y1 {
+        for x in x0..x1 {
+            hist[image.get_pixel(x, y).0[0] as usize] += 1;
+        }
+    }
+
+    let pixels = (x1 - x0) * (y1 - y0);
+    let mut mapping = [0u8; 256];
+    if pixels == 0 {
+        for (i, m) in mapping.iter_mut().enumerate() {
+            *m = i as u8;
+        }
+        return mapping;
+    }
+
+    let limit = ((clip_limit * f64::from(pixels) / 256.0).ceil() as u32).max(1);
+    let mut excess = 0u32;
+    for bin in hist.iter_mut() {
+        if *bin > limit {
+            excess += *bin - limit;
+            *bin = limit;
+        }
+    }
+    let bonus = excess / 256;
+    let remainder = (excess % 256) as usize;
+    // Spread the leftover evenly over the range so no end of the histogram is favoured.
+    let step = if remainder > 0 { (256 / remainder).max(1) } else { 256 };
+    for (i, bin) in hist.iter_mut().enumerate() {
+        *bin += bonus + u32::from(i.is_multiple_of(step) && i / step < remainder);
+    }
+
+    let mut cumulative = 0u32;
+    for (i, bin) in hist.iter().enumerate() {
+        cumulative += bin;
+        mapping[i] = ((f64::from(cumulative) * 255.0) / f64::from(pixels)).round().min(255.0) as u8;
+    }
+    mapping
+}
+
+/// Invert pixel intensities (`255 - v`).
+pub fn invert(image: &GrayImage) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let data = image.as_raw().iter().map(|&p| 255 - p).collect();
+    GrayImage::from_raw(width, height, data).unwrap_or_else(|| GrayImage::new(width, height))
+}
+
+/// Fraction of pixels in the mid-tone range (64..192).
+///
+/// Scans of photographs or textured paper have many mid-tones and benefit
+/// from bilateral smoothing; fax-like bilevel images do not and are better
+/// served by a median filter.
+pub fn midtone_ratio(image: &GrayImage) -> f64 {
+    let raw = image.as_raw();
+    if raw.is_empty() {
+        return 0.0;
+    }
+    let midtones = raw.iter().filter(|&&p| (64..192).contains(&p)).count();
+    midtones as f64 / raw.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median_filter_removes_speckle() {
+        let mut image = GrayImage::from_pixel(9, 9, Luma([255]));
+        image.put_pixel(4, 4, Luma([0]));
+        let filtered = median_filter(&image, 1);
+        assert_eq!(filtered.get_pixel(4, 4).0[0], 255);
+    }
+
+    #[test]
+    fn test_median_filter_keeps_solid_regions() {
+        let image = GrayImage::from_fn(10, 10, |x, _| if x < 5 { Luma([0]) } else { Luma([255]) });
+        let filtered = median_filter(&image, 1);
+        assert_eq!(filtered.get_pixel(1, 5).0[0], 0);
+        assert_eq!(filtered.get_pixel(8, 5).0[0], 255);
+    }
+
+    #[test]
+    fn test_bilateral_filter_preserves_edges() {
+        let image = GrayImage::from_fn(20, 20, |x, _| if x < 10 { Luma([20]) } else { Luma([230]) });
+        let filtered = bilateral_filter(&image, 2, 2.0, 25.0);
+        assert!(filtered.get_pixel(9, 10).0[0] < 40);
+        assert!(filtered.get_pixel(10, 10).0[0] > 210);
+    }
+
+    #[test]
+    fn test_bilateral_filter_smooths_noise() {
+        let image = GrayImage::from_fn(20, 20, |x, y| Luma([if (x + y) % 2 == 0 { 120 } else { 136 }]));
+        let filtered = bilateral_filter(&image, 2, 2.0, 25.0);
+        let v = filtered.get_pixel(10, 10).0[0];
+        assert!((124..=132).contains(&v));
+    }
+
+    #[test]
+    fn test_clahe_stretches_low_contrast() {
+        let image = GrayImage::from_fn(64, 64, |x, _| Luma([110 + (x % 2) as u8 * 20]));
+        let enhanced = clahe(&image);
+        let min = *enhanced.as_raw().iter().min().unwrap();
+        let max = *enhanced.as_raw().iter().max().unwrap();
+        assert!(max - min > 20);
+    }
+
+    #[test]
+    fn test_clahe_small_image() {
+        let image = GrayImage::from_pixel(3, 2, Luma([128]));
+        let enhanced = clahe(&image);
+        assert_eq!(enhanced.dimensions(), (3, 2));
+    }
+
+    #[test]
+    fn test_invert() {
+        let image = GrayImage::from_raw(2, 1, vec![0, 200]).unwrap();
+        assert_eq!(invert(&image).as_raw(), &vec![255, 55]);
+    }
+
+    #[test]
+    fn test_midtone_ratio() {
+        let bilevel = GrayImage::from_fn(10, 10, |x, _| Luma([if x < 5 { 0 } else { 255 }]));
+        assert_eq!(midtone_ratio(&bilevel), 0.0);
+        let gray = GrayImage::from_pixel(10, 10, Luma([128]));
+        assert_eq!(midtone_ratio(&gray), 1.0);
+    }
+}
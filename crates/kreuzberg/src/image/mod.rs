@@ -1,6 +1,12 @@
+pub mod binarize;
+pub mod deskew;
 pub mod dpi;
+pub mod filters;
+pub mod orientation;
+pub mod pipeline;
 pub mod preprocessing;
 pub mod resize;
 
 pub use dpi::calculate_optimal_dpi;
+pub use pipeline::{PreprocessResult, preprocess_for_ocr};
 pub use preprocessing::{NormalizeResult, normalize_image_dpi};
@@ -0,0 +1,241 @@
+//! Page orientation detection (0°, 90°, 180°, 270°).
+//!
+//! Two cheap signals are combined:
+//!
+//! 1. **Line direction**: horizontal text produces a row profile with strong
+//!    gaps between lines, while its column profile is comparatively flat. If
+//!    the column profile varies much more than the row profile, the text runs
+//!    vertically and the page is turned by a quarter.
+//! 2. **Ascender/descender balance**: in Latin-like scripts, ink above the
+//!    x-height band (ascenders, capitals, digits) outweighs ink below the
+//!    baseline (descenders). A page where the lower side dominates is upside down.
+
+use image::{GrayImage, imageops};
+
+use super::binarize::otsu_threshold;
+use super::deskew::downscale_for_analysis;
+
+/// How much flatter the row profile must be than the column profile before
+/// text is considered vertical.
+const VERTICAL_RATIO: f64 = 1.3;
+
+/// How much descender ink must outweigh ascender ink before a page is considered upside down.
+const UPSIDE_DOWN_RATIO: f64 = 1.25;
+
+/// Minimum number of text lines needed to make a flip decision.
+const MIN_LINES: usize = 2;
+
+/// Detect the clockwise rotation (0, 90, 180 or 270 degrees) needed to make the page upright.
+///
+/// Returns `None` when the image does not contain enough text to decide.
+pub fn detect_orientation(image: &GrayImage) -> Option<u16> {
+    let analysis = downscale_for_analysis(image);
+    let threshold = otsu_threshold(&analysis);
+    let mask = InkMask::new(&analysis, threshold)?;
+
+    let rows_cv = coefficient_of_variation(&mask.row_profile());
+    let cols_cv = coefficient_of_variation(&mask.column_profile());
+
+    let (base, horizontal) = if cols_cv > rows_cv * VERTICAL_RATIO {
+        (90, InkMask::new(&imageops::rotate90(&analysis), threshold)?)
+    } else {
+        (0, mask)
+    };
+
+    match horizontal.is_upside_down() {
+        Some(true) => Some((base + 180) % 360),
+        Some(false) => Some(base),
+        None if base != 0 => Some(base),
+        None => None,
+    }
+}
+
+/// Rotate an image clockwise by a multiple of 90 degrees.
+pub fn apply_orientation(image: &GrayImage, degrees: u16) -> GrayImage {
+    match degrees % 360 {
+        90 => imageops::rotate90(image),
+        180 => imageops::rotate180(image),
+        270 => imageops::rotate270(image),
+        _ => image.clone(),
+    }
+}
+
+/// Ink pixels cropped to their bounding box.
+struct InkMask {
+    width: usize,
+    height: usize,
+    ink: Vec<bool>,
+}
+
+impl InkMask {
+    fn new(image: &GrayImage, threshold: u8) -> Option<Self> {
+        let (width, height) = image.dimensions();
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (u32::MAX, u32::MAX, 0, 0);
+        let mut count = 0usize;
+        for (x, y, pixel) in image.enumerate_pixels() {
+            if pixel.0[0] <= threshold {
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+                count += 1;
+            }
+        }
+
+        // A blank page or a page that is almost entirely dark carries no orientation signal.
+        let total = (width as usize) * (height as usize);
+        if count < 50 || count * 2 > total {
+            return None;
+        }
+
+        let crop_w = (max_x - min_x + 1) as usize;
+        let crop_h = (max_y - min_y + 1) as usize;
+        let mut ink = vec![false; crop_w * crop_h];
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                if image.get_pixel(x, y).0[0] <= threshold {
+                    ink[(y - min_y) as usize * crop_w + (x - min_x) as usize] = true;
+                }
+            }
+        }
+
+        Some(Self {
+            width: crop_w,
+            height: crop_h,
+            ink,
+        })
+    }
+
+    fn row_profile(&self) -> Vec<u32> {
+        self.ink
+            .chunks(self.width)
+            .map(|row| row.iter().filter(|&&v| v).count() as u32)
+            .collect()
+    }
+
+    fn column_profile(&self) -> Vec<u32> {
+        let mut profile = vec![0u32; self.width];
+        for row in self.ink.chunks(self.width) {
+            for (x, &v) in row.iter().enumerate() {
+                profile[x] += u32::from(v);
+            }
+        }
+        profile
+    }
+
+    /// Compare ink above the x-height band with ink below it across all text lines.
+    fn is_upside_down(&self) -> Option<bool> {
+        let profile = self.row_profile();
+        let mut above = 0u64;
+        let mut below = 0u64;
+        let mut lines = 0usize;
+
+        let mut y = 0;
+        while y < self.height {
+            if profile[y] == 0 {
+                y += 1;
+                continue;
+            }
+            let start = y;
+            while y < self.height && profile[y] > 0 {
+                y += 1;
+            }
+            let line = &profile[start..y];
+            if line.len() < 4 {
+                continue;
+            }
+
+            let peak = *line.iter().max().unwrap_or(&0);
+            let core_threshold = peak / 2;
+            let core_top = line.iter().position(|&c| c >= core_threshold).unwrap_or(0);
+            let core_bottom = line
+                .iter()
+                .rposition(|&c| c >= core_threshold)
+                .unwrap_or(line.len() - 1);
+
+            above += line[..core_top].iter().map(|&c| u64::from(c)).sum::<u64>();
+            below += line[core_bottom + 1..].iter().map(|&c| u64::from(c)).sum::<u64>();
+            lines += 1;
+        }
+
+        if lines < MIN_LINES || above + below == 0 {
+            return None;
+        }
+
+        Some(below as f64 > above as f64 * UPSIDE_DOWN_RATIO)
+    }
+}
+
+fn coefficient_of_variation(profile: &[u32]) -> f64 {
+    if profile.is_empty() {
+        return 0.0;
+    }
+    let n = profile.len() as f64;
+    let mean = profile.iter().map(|&v| f64::from(v)).sum::<f64>() / n;
+    if mean == 0.0 {
+        return 0.0;
+    }
+    let variance = profile.iter().map(|&v| (f64::from(v) - mean).powi(2)).sum::<f64>() / n;
+    variance.sqrt() / mean
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Luma;
+
+    /// Synthetic upright page: each line has an x-height band of "letters",
+    /// frequent ascender strokes above it and rare descender strokes below.
+    fn upright_page() -> GrayImage {
+        let (width, height) = (400, 300);
+        GrayImage::from_fn(width, height, |x, y| {
+            if !(20..380).contains(&x) || !(20..280).contains(&y) {
+                return Luma([255]);
+            }
+            let line = (y - 20) / 30;
+            let offset = y - 20 - line * 30;
+            let col = (x + line * 5) % 12;
+            let is_letter = col < 8;
+            let dark = match offset {
+                0..=5 => is_letter && col < 2 && (x / 12) % 2 == 0,
+                6..=15 => is_letter,
+                16..=19 => is_letter && col < 2 && (x / 12) % 7 == 0,
+                _ => false,
+            };
+            if dark { Luma([0]) } else { Luma([255]) }
+        })
+    }
+
+    #[test]
+    fn test_detect_orientation_upright() {
+        assert_eq!(detect_orientation(&upright_page()), Some(0));
+    }
+
+    #[test]
+    fn test_detect_orientation_upside_down() {
+        let flipped = imageops::rotate180(&upright_page());
+        assert_eq!(detect_orientation(&flipped), Some(180));
+    }
+
+    #[test]
+    fn test_detect_orientation_quarter_turns() {
+        let page = upright_page();
+        assert_eq!(detect_orientation(&imageops::rotate90(&page)), Some(270));
+        assert_eq!(detect_orientation(&imageops::rotate270(&page)), Some(90));
+    }
+
+    #[test]
+    fn test_detect_orientation_blank_page() {
+        let blank = GrayImage::from_pixel(100, 100, Luma([255]));
+        assert_eq!(detect_orientation(&blank), None);
+    }
+
+    #[test]
+    fn test_apply_orientation_round_trip() {
+        let page = upright_page();
+        let turned = imageops::rotate90(&page);
+        let restored = apply_orientation(&turned, 270);
+        assert_eq!(restored.dimensions(), page.dimensions());
+        assert_eq!(restored.as_raw(), page.as_raw());
+    }
+}
@@ -0,0 +1,207 @@
+//! OCR image preprocessing pipeline driven by [`ImagePreprocessingConfig`].
+//!
+//! Steps run in a fixed order, each only when enabled:
+//!
+//! 1. Grayscale conversion (always)
+//! 2. Color inversion (`invert_colors`)
+//! 3. Orientation detection and correction (`auto_rotate`)
+//! 4. Skew estimation and correction (`deskew`)
+//! 5. Noise removal (`denoise`): median for bilevel scans, bilateral otherwise
+//! 6. Contrast enhancement with CLAHE (`contrast_enhance`)
+//! 7. Binarization (`binarization_method`: "otsu", "sauvola" or "adaptive")
+//!
+//! Every step taken is recorded in [`ImagePreprocessingMetadata::steps_applied`].
+
+use crate::core::config_validation::validate_binarization_method;
+use crate::error::Result;
+use crate::types::{ImagePreprocessingConfig, ImagePreprocessingMetadata};
+use image::{DynamicImage, GrayImage};
+
+use super::binarize::{binarize_adaptive, binarize_otsu, binarize_sauvola, default_window_size};
+use super::deskew::deskew;
+use super::filters::{bilateral_filter, clahe, invert, median_filter, midtone_ratio};
+use super::orientation::{apply_orientation, detect_orientation};
+
+/// Mid-tone fraction above which bilateral filtering is preferred over a median filter.
+const BILATERAL_MIDTONE_RATIO: f64 = 0.15;
+
+/// Result of the preprocessing pipeline.
+pub struct PreprocessResult {
+    /// Preprocessed grayscale image
+    pub image: GrayImage,
+    /// Record of the steps taken
+    pub metadata: ImagePreprocessingMetadata,
+}
+
+/// Run the preprocessing pipeline on a decoded image.
+///
+/// No DPI resampling happens here (the source DPI is unknown at this point),
+/// so the DPI fields of the returned metadata reflect the configured target.
+///
+/// # Errors
+///
+/// Returns a validation error if `binarization_method` is not recognized.
+pub fn preprocess_for_ocr(image: &DynamicImage, config: &ImagePreprocessingConfig) -> Result<PreprocessResult> {
+    validate_binarization_method(&config.binarization_method)?;
+
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let mut gray = image.to_luma8();
+    let mut metadata = ImagePreprocessingMetadata {
+        original_dimensions: (width, height),
+        original_dpi: (f64::from(config.target_dpi), f64::from(config.target_dpi)),
+        target_dpi: config.target_dpi,
+        scale_factor: 1.0,
+        auto_adjusted: false,
+        final_dpi: config.target_dpi,
+        new_dimensions: None,
+        resample_method: "NONE".to_string(),
+        dimension_clamped: false,
+        calculated_dpi: None,
+        skipped_resize: true,
+        resize_error: None,
+        steps_applied: vec!["grayscale".to_string()],
+        detected_orientation: None,
+        skew_angle: None,
+        binarization_threshold: None,
+    };
+
+    if config.invert_colors {
+        gray = invert(&gray);
+        metadata.steps_applied.push("invert".to_string());
+    }
+
+    if config.auto_rotate
+        && let Some(degrees) = detect_orientation(&gray)
+    {
+        metadata.detected_orientation = Some(degrees);
+        if degrees != 0 {
+            gray = apply_orientation(&gray, degrees);
+            metadata.steps_applied.push(format!("orientation:{degrees}"));
+        }
+    }
+
+    if config.deskew {
+        let (corrected, angle) = deskew(&gray);
+        if let Some(angle) = angle {
+            gray = corrected;
+            metadata.skew_angle = Some(angle);
+            metadata.steps_applied.push("deskew".to_string());
+        }
+    }
+
+    if config.denoise {
+        if midtone_ratio(&gray) > BILATERAL_MIDTONE_RATIO {
+            gray = bilateral_filter(&gray, 2, 2.0, 25.0);
+            metadata.steps_applied.push("denoise:bilateral".to_string());
+        } else {
+            gray = median_filter(&gray, 1);
+            metadata.steps_applied.push("denoise:median".to_string());
+        }
+    }
+
+    if config.contrast_enhance {
+        gray = clahe(&gray);
+        metadata.steps_applied.push("contrast:clahe".to_string());
+    }
+
+    let window = default_window_size(gray.width(), gray.height());
+    gray = match config.binarization_method.as_str() {
+        "sauvola" => binarize_sauvola(&gray, window),
+        "adaptive" => binarize_adaptive(&gray, window),
+        _ => {
+            let (binary, threshold) = binarize_otsu(&gray);
+            metadata.binarization_threshold = Some(threshold);
+            binary
+        }
+    };
+    metadata
+        .steps_applied
+        .push(format!("binarize:{}", config.binarization_method));
+
+    let final_dimensions = (gray.width() as usize, gray.height() as usize);
+    if final_dimensions != (width, height) {
+        metadata.new_dimensions = Some(final_dimensions);
+    }
+
+    Ok(PreprocessResult { image: gray, metadata })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Luma, Rgb, RgbImage};
+
+    fn text_like_image() -> DynamicImage {
+        let image = RgbImage::from_fn(200, 120, |x, y| {
+            if (y % 20) < 6 && (20..180).contains(&x) && (10..110).contains(&y) {
+                Rgb([30, 30, 30])
+            } else {
+                Rgb([235, 230, 220])
+            }
+        });
+        DynamicImage::ImageRgb8(image)
+    }
+
+    fn minimal_config() -> ImagePreprocessingConfig {
+        ImagePreprocessingConfig {
+            auto_rotate: false,
+            deskew: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_preprocess_binarizes_and_records_steps() {
+        let result = preprocess_for_ocr(&text_like_image(), &minimal_config()).unwrap();
+        assert!(result.image.as_raw().iter().all(|&p| p == 0 || p == 255));
+        assert_eq!(result.metadata.steps_applied, vec!["grayscale", "binarize:otsu"]);
+        assert!(result.metadata.binarization_threshold.is_some());
+        assert_eq!(result.metadata.original_dimensions, (200, 120));
+    }
+
+    #[test]
+    fn test_preprocess_all_steps() {
+        let config = ImagePreprocessingConfig {
+            denoise: true,
+            contrast_enhance: true,
+            invert_colors: true,
+            binarization_method: "sauvola".to_string(),
+            ..Default::default()
+        };
+        let result = preprocess_for_ocr(&text_like_image(), &config).unwrap();
+        let steps = &result.metadata.steps_applied;
+        assert!(steps.contains(&"invert".to_string()));
+        assert!(steps.iter().any(|s| s.starts_with("denoise:")));
+        assert!(steps.contains(&"contrast:clahe".to_string()));
+        assert_eq!(steps.last().unwrap(), "binarize:sauvola");
+        assert!(result.metadata.binarization_threshold.is_none());
+    }
+
+    #[test]
+    fn test_preprocess_invert_restores_dark_text() {
+        // White text on black background.
+        let image = GrayImage::from_fn(100, 60, |x, y| {
+            if (y % 20) < 6 && (10..90).contains(&x) {
+                Luma([250])
+            } else {
+                Luma([10])
+            }
+        });
+        let config = ImagePreprocessingConfig {
+            invert_colors: true,
+            ..minimal_config()
+        };
+        let result = preprocess_for_ocr(&DynamicImage::ImageLuma8(image), &config).unwrap();
+        assert_eq!(result.image.get_pixel(50, 2).0[0], 0);
+        assert_eq!(result.image.get_pixel(50, 10).0[0], 255);
+    }
+
+    #[test]
+    fn test_preprocess_rejects_unknown_binarization() {
+        let config = ImagePreprocessingConfig {
+            binarization_method: "magic".to_string(),
+            ..minimal_config()
+        };
+        assert!(preprocess_for_ocr(&text_like_image(), &config).is_err());
+    }
+}
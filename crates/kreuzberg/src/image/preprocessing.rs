@@ -185,6 +185,10 @@ fn create_skip_result(
             calculated_dpi,
             skipped_resize: true,
             resize_error: None,
+            steps_applied: Vec::new(),
+            detected_orientation: None,
+            skew_angle: None,
+            binarization_threshold: None,
         },
     }
 }
@@ -233,6 +237,10 @@ fn perform_resize(
         calculated_dpi,
         skipped_resize: false,
         resize_error: None,
+        steps_applied: Vec::new(),
+        detected_orientation: None,
+        skew_angle: None,
+        binarization_threshold: None,
     };
 
     Ok(NormalizeResult {
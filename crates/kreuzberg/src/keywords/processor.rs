@@ -114,6 +114,7 @@ machine learning that uses neural networks with multiple layers.
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -125,6 +126,7 @@ machine learning that uses neural networks with multiple layers.
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -151,6 +153,7 @@ machine learning that uses neural networks with multiple layers.
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -162,6 +165,7 @@ machine learning that uses neural networks with multiple layers.
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -184,6 +188,7 @@ machine learning that uses neural networks with multiple layers.
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -195,6 +200,7 @@ machine learning that uses neural networks with multiple layers.
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -217,6 +223,7 @@ machine learning that uses neural networks with multiple layers.
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -228,6 +235,7 @@ machine learning that uses neural networks with multiple layers.
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -261,6 +269,7 @@ machine learning that uses neural networks with multiple layers.
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -272,6 +281,7 @@ machine learning that uses neural networks with multiple layers.
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let config_with_keywords = ExtractionConfig {
@@ -294,6 +304,7 @@ machine learning that uses neural networks with multiple layers.
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -305,6 +316,7 @@ machine learning that uses neural networks with multiple layers.
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let long_result = ExtractionResult {
@@ -313,6 +325,7 @@ machine learning that uses neural networks with multiple layers.
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -324,6 +337,7 @@ machine learning that uses neural networks with multiple layers.
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -4,9 +4,22 @@
 
 use crate::Result;
 use crate::core::config::LanguageDetectionConfig;
+use crate::types::{ExtractionResult, LanguageSpan};
 use once_cell::sync::Lazy;
+use std::collections::HashMap;
 use std::sync::Arc;
-use whatlang::{Lang, detect};
+use whatlang::{Lang, Script, detect, detect_script};
+
+/// Letters a segment needs before its language is detected on its own;
+/// shorter segments take the language of their neighbours.
+const MIN_SEGMENT_LETTERS: usize = 24;
+
+/// Letters after which a segment ends at the next line or sentence end.
+const TARGET_SEGMENT_LETTERS: usize = 200;
+
+/// Upper bound for the confidence required of a single segment. Short
+/// segments rarely reach document-level confidence.
+const MAX_SEGMENT_CONFIDENCE: f64 = 0.35;
 
 pub mod processor;
 pub use processor::LanguageDetector;
@@ -106,6 +119,302 @@ fn detect_multiple_languages(text: &str, config: &LanguageDetectionConfig) -> Re
     Ok(Some(languages))
 }
 
+/// Detect the dominant language of `text`.
+///
+/// Returns the ISO 639-3 code of the language covering the most bytes in
+/// [`detect_language_spans`], with the byte-weighted confidence of its spans.
+/// Returns `None` when detection is disabled or no span was detected.
+pub fn detect_primary_language(text: &str, config: &LanguageDetectionConfig) -> Option<(String, f64)> {
+    let mut totals: HashMap<String, (usize, f64)> = HashMap::new();
+    for span in detect_language_spans(text, config) {
+        let bytes = span.byte_end - span.byte_start;
+        let total = totals.entry(span.language).or_default();
+        total.0 += bytes;
+        total.1 += span.confidence * bytes as f64;
+    }
+
+    totals
+        .into_iter()
+        .max_by(|a, b| a.1.0.cmp(&b.1.0).then_with(|| b.0.cmp(&a.0)))
+        .map(|(language, (bytes, weighted))| (language, weighted / bytes.max(1) as f64))
+}
+
+/// Split `text` into runs of a single language and script.
+///
+/// The text is first cut into script runs (Latin, Cyrillic, Han and kana, ...),
+/// absorbing runs too short to classify into their neighbours. Each script run
+/// is then cut into segments at line and sentence ends, the language of each
+/// segment is detected, and neighbouring segments in the same language are
+/// merged. Segments too short or too ambiguous to classify take the language
+/// of the preceding segment (or the following one at the start of a run).
+///
+/// Span offsets are byte offsets into `text`, trimmed of surrounding whitespace.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::language_detection::detect_language_spans;
+/// use kreuzberg::core::config::LanguageDetectionConfig;
+///
+/// let text = "This study examines how public libraries adapted their services during the pandemic. \
+///             We surveyed librarians across the country and found that most branches expanded their lending.\n\n\
+///             Die Untersuchung zeigt, dass die meisten Bibliotheken ihre Öffnungszeiten verkürzen mussten. \
+///             Gleichzeitig wurde das Angebot an elektronischen Büchern deutlich erweitert.";
+/// let config = LanguageDetectionConfig {
+///     enabled: true,
+///     min_confidence: 0.8,
+///     detect_multiple: false,
+/// };
+/// let spans = detect_language_spans(text, &config);
+/// assert_eq!(spans.len(), 2);
+/// assert_eq!(spans[0].language, "eng");
+/// assert_eq!(spans[1].language, "deu");
+/// ```
+pub fn detect_language_spans(text: &str, config: &LanguageDetectionConfig) -> Vec<LanguageSpan> {
+    if !config.enabled {
+        return Vec::new();
+    }
+
+    let threshold = config.min_confidence.min(MAX_SEGMENT_CONFIDENCE);
+    let mut spans = Vec::new();
+
+    for run in script_runs(text) {
+        let segments = segments(text, run.start, run.end);
+        let detected: Vec<Option<(Lang, f64)>> = segments
+            .iter()
+            .map(|segment| {
+                if segment.letters < MIN_SEGMENT_LETTERS {
+                    return None;
+                }
+                detect(&text[segment.start..segment.end])
+                    .filter(|info| info.confidence() >= threshold)
+                    .map(|info| (info.lang(), info.confidence()))
+            })
+            .collect();
+
+        let Some(first_language) = detected.iter().flatten().map(|(lang, _)| *lang).next() else {
+            // No segment is conclusive on its own; classify the run as a whole.
+            if let Some(info) = detect(&text[run.start..run.end]).filter(|info| info.confidence() >= threshold) {
+                push_span(
+                    &mut spans,
+                    text,
+                    run.start,
+                    run.end,
+                    info.lang(),
+                    info.script(),
+                    info.confidence(),
+                );
+            }
+            continue;
+        };
+
+        let script = detect_script(&text[run.start..run.end]).unwrap_or(run.script);
+        let mut current = first_language;
+        let mut span_start = run.start;
+        let (mut weighted, mut weight) = (0.0, 0usize);
+
+        for (segment, detection) in segments.iter().zip(&detected) {
+            if let Some((lang, confidence)) = *detection {
+                if lang != current {
+                    let span_confidence = weighted / weight.max(1) as f64;
+                    push_span(
+                        &mut spans,
+                        text,
+                        span_start,
+                        segment.start,
+                        current,
+                        script,
+                        span_confidence,
+                    );
+                    current = lang;
+                    span_start = segment.start;
+                    (weighted, weight) = (0.0, 0);
+                }
+                weighted += confidence * segment.letters as f64;
+                weight += segment.letters;
+            }
+        }
+        push_span(
+            &mut spans,
+            text,
+            span_start,
+            run.end,
+            current,
+            script,
+            weighted / weight.max(1) as f64,
+        );
+    }
+
+    spans
+}
+
+/// Record the languages of `result`: language spans of its content and the
+/// dominant language of each page and chunk.
+pub fn annotate_languages(result: &mut ExtractionResult, config: &LanguageDetectionConfig) {
+    if !config.enabled {
+        return;
+    }
+
+    let spans = detect_language_spans(&result.content, config);
+    result.language_spans = (!spans.is_empty()).then_some(spans);
+
+    for page in result.pages.iter_mut().flatten() {
+        let detected = detect_primary_language(&page.content, config);
+        page.language_confidence = detected.as_ref().map(|(_, confidence)| *confidence);
+        page.language = detected.map(|(language, _)| language);
+    }
+
+    for chunk in result.chunks.iter_mut().flatten() {
+        let detected = detect_primary_language(&chunk.content, config);
+        chunk.metadata.language_confidence = detected.as_ref().map(|(_, confidence)| *confidence);
+        chunk.metadata.language = detected.map(|(language, _)| language);
+    }
+}
+
+struct ScriptRun {
+    start: usize,
+    end: usize,
+    script: Script,
+    letters: usize,
+}
+
+struct Segment {
+    start: usize,
+    end: usize,
+    letters: usize,
+}
+
+/// Script of a letter, with Japanese kana folded into Han so that mixed
+/// Japanese text stays in one run.
+fn letter_script(ch: char) -> Option<Script> {
+    if !ch.is_alphabetic() {
+        return None;
+    }
+    let mut buffer = [0; 4];
+    match detect_script(ch.encode_utf8(&mut buffer))? {
+        Script::Hiragana | Script::Katakana => Some(Script::Mandarin),
+        script => Some(script),
+    }
+}
+
+/// Cut `text` into maximal runs of one script, covering all of `text`.
+///
+/// Characters without a script (digits, punctuation, whitespace) belong to the
+/// preceding run. Runs with fewer than [`MIN_SEGMENT_LETTERS`] letters, such as
+/// a brand name inside Cyrillic text, are merged into the preceding run, or into
+/// the following one at the start of the text.
+fn script_runs(text: &str) -> Vec<ScriptRun> {
+    let mut runs: Vec<ScriptRun> = Vec::new();
+    for (index, ch) in text.char_indices() {
+        let Some(script) = letter_script(ch) else { continue };
+        match runs.last_mut() {
+            Some(run) if run.script == script => run.letters += 1,
+            Some(run) => {
+                run.end = index;
+                runs.push(ScriptRun {
+                    start: index,
+                    end: text.len(),
+                    script,
+                    letters: 1,
+                });
+            }
+            None => runs.push(ScriptRun {
+                start: 0,
+                end: text.len(),
+                script,
+                letters: 1,
+            }),
+        }
+    }
+
+    let mut merged: Vec<ScriptRun> = Vec::with_capacity(runs.len());
+    for run in runs {
+        match merged.last_mut() {
+            Some(previous) if previous.script == run.script || run.letters < MIN_SEGMENT_LETTERS => {
+                previous.end = run.end;
+                previous.letters += run.letters;
+            }
+            Some(previous) if previous.letters < MIN_SEGMENT_LETTERS => {
+                *previous = ScriptRun {
+                    start: previous.start,
+                    letters: previous.letters + run.letters,
+                    ..run
+                };
+            }
+            _ => merged.push(run),
+        }
+    }
+    merged
+}
+
+/// Cut `text[start..end]` into segments of about [`TARGET_SEGMENT_LETTERS`]
+/// letters, ending at blank lines and, once long enough, at line or sentence ends.
+fn segments(text: &str, start: usize, end: usize) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut segment_start = start;
+    let mut letters = 0;
+    let mut line_has_text = false;
+    let mut previous = '\0';
+
+    for (offset, ch) in text[start..end].char_indices() {
+        let index = start + offset;
+        let boundary = if ch == '\n' {
+            let blank_line = !line_has_text && letters > 0;
+            line_has_text = false;
+            blank_line || letters >= TARGET_SEGMENT_LETTERS
+        } else {
+            line_has_text |= !ch.is_whitespace();
+            matches!(previous, '.' | '!' | '?') && ch.is_whitespace() && letters >= TARGET_SEGMENT_LETTERS
+        };
+        if ch.is_alphabetic() {
+            letters += 1;
+        }
+        previous = ch;
+
+        if boundary {
+            let segment_end = index + ch.len_utf8();
+            segments.push(Segment {
+                start: segment_start,
+                end: segment_end,
+                letters,
+            });
+            segment_start = segment_end;
+            letters = 0;
+        }
+    }
+    if segment_start < end {
+        segments.push(Segment {
+            start: segment_start,
+            end,
+            letters,
+        });
+    }
+    segments
+}
+
+fn push_span(
+    spans: &mut Vec<LanguageSpan>,
+    text: &str,
+    start: usize,
+    end: usize,
+    lang: Lang,
+    script: Script,
+    confidence: f64,
+) {
+    let slice = &text[start..end];
+    let byte_start = start + (slice.len() - slice.trim_start().len());
+    let byte_end = end - (slice.len() - slice.trim_end().len());
+    if byte_start < byte_end {
+        spans.push(LanguageSpan {
+            byte_start,
+            byte_end,
+            language: lang_to_iso639_3(lang),
+            script: script.name().to_string(),
+            confidence,
+        });
+    }
+}
+
 /// Convert whatlang Lang enum to ISO 639-3 language code.
 ///
 /// Maps whatlang's language codes to standardized ISO 639-3 codes.
@@ -982,4 +1291,139 @@ mod tests {
         let langs = result.unwrap();
         assert_eq!(langs[0], "eng");
     }
+
+    fn span_config() -> LanguageDetectionConfig {
+        LanguageDetectionConfig {
+            enabled: true,
+            min_confidence: 0.8,
+            detect_multiple: false,
+        }
+    }
+
+    const ENGLISH: &str = "This study examines how public libraries adapted their services during the pandemic. \
+        We surveyed librarians across the country and found that most branches expanded their digital lending.";
+    const GERMAN: &str = "Die Untersuchung zeigt, dass die meisten Bibliotheken ihre Öffnungszeiten verkürzen mussten. \
+        Gleichzeitig wurde das Angebot an elektronischen Büchern und Zeitschriften deutlich erweitert.";
+    const RUSSIAN: &str = "Исследование показывает, что большинство библиотек сократили часы работы \
+        и одновременно расширили предложение электронных книг и журналов для читателей.";
+
+    #[test]
+    fn test_language_spans_english_abstract_german_body() {
+        let text = format!("Abstract\n\n{}\n\n{}\n", ENGLISH, GERMAN);
+        let spans = detect_language_spans(&text, &span_config());
+
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].language, "eng");
+        assert_eq!(spans[0].script, "Latin");
+        assert_eq!(spans[0].byte_start, 0);
+        assert!(text[spans[0].byte_start..spans[0].byte_end].ends_with("digital lending."));
+        assert_eq!(spans[1].language, "deu");
+        assert_eq!(&text[spans[1].byte_start..spans[1].byte_end], GERMAN);
+        assert!(spans.iter().all(|span| span.confidence > 0.0 && span.confidence <= 1.0));
+    }
+
+    #[test]
+    fn test_language_spans_split_on_script_change() {
+        let text = format!("{} {}", ENGLISH, RUSSIAN);
+        let spans = detect_language_spans(&text, &span_config());
+
+        assert_eq!(spans.len(), 2);
+        assert_eq!((spans[0].language.as_str(), spans[0].script.as_str()), ("eng", "Latin"));
+        assert_eq!(
+            (spans[1].language.as_str(), spans[1].script.as_str()),
+            ("rus", "Cyrillic")
+        );
+        assert_eq!(&text[spans[1].byte_start..spans[1].byte_end], RUSSIAN);
+    }
+
+    #[test]
+    fn test_language_spans_absorb_short_script_runs() {
+        let text = "Вчера я купил новый iPhone в магазине электроники, и теперь я очень доволен своей покупкой.";
+        let spans = detect_language_spans(text, &span_config());
+
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].language, "rus");
+        assert_eq!((spans[0].byte_start, spans[0].byte_end), (0, text.len()));
+    }
+
+    #[test]
+    fn test_language_spans_keep_japanese_in_one_run() {
+        let text = "日本語の文章は、ひらがなとカタカナと漢字を組み合わせて書かれています。毎日たくさんの人がこの言葉を使っています。";
+        let spans = detect_language_spans(text, &span_config());
+
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].language, "jpn");
+    }
+
+    #[test]
+    fn test_language_spans_disabled_or_empty() {
+        let disabled = LanguageDetectionConfig {
+            enabled: false,
+            ..span_config()
+        };
+        assert!(detect_language_spans(ENGLISH, &disabled).is_empty());
+        assert!(detect_language_spans("", &span_config()).is_empty());
+        assert!(detect_language_spans("12 34 -- 56", &span_config()).is_empty());
+    }
+
+    #[test]
+    fn test_detect_primary_language_majority() {
+        let text = format!("{}\n\n{}\n\n{}", ENGLISH, GERMAN, GERMAN);
+        let (language, confidence) = detect_primary_language(&text, &span_config()).unwrap();
+        assert_eq!(language, "deu");
+        assert!(confidence > 0.0);
+        assert!(detect_primary_language("", &span_config()).is_none());
+    }
+
+    #[test]
+    fn test_annotate_languages_pages_and_chunks() {
+        use crate::types::{Chunk, ChunkMetadata, PageContent};
+
+        let page = |page_number: usize, content: &str| PageContent {
+            page_number,
+            content: content.to_string(),
+            tables: Vec::new(),
+            images: Vec::new(),
+            hierarchy: None,
+            is_blank: None,
+            language: None,
+            language_confidence: None,
+            text_source: None,
+        };
+        let chunk = |chunk_index: usize, content: &str| Chunk {
+            content: content.to_string(),
+            embedding: None,
+            metadata: ChunkMetadata {
+                byte_start: 0,
+                byte_end: content.len(),
+                token_count: None,
+                chunk_index,
+                total_chunks: 2,
+                first_page: None,
+                last_page: None,
+                heading_path: Vec::new(),
+                element_types: Vec::new(),
+                node_ids: Vec::new(),
+                language: None,
+                language_confidence: None,
+            },
+        };
+
+        let mut result = ExtractionResult {
+            content: format!("{}\n\n{}", ENGLISH, GERMAN),
+            pages: Some(vec![page(1, ENGLISH), page(2, GERMAN)]),
+            chunks: Some(vec![chunk(0, ENGLISH), chunk(1, GERMAN)]),
+            ..Default::default()
+        };
+        annotate_languages(&mut result, &span_config());
+
+        let pages = result.pages.unwrap();
+        assert_eq!(pages[0].language.as_deref(), Some("eng"));
+        assert_eq!(pages[1].language.as_deref(), Some("deu"));
+        assert!(pages[1].language_confidence.is_some());
+        let chunks = result.chunks.unwrap();
+        assert_eq!(chunks[0].metadata.language.as_deref(), Some("eng"));
+        assert_eq!(chunks[1].metadata.language.as_deref(), Some("deu"));
+        assert_eq!(result.language_spans.map(|spans| spans.len()), Some(2));
+    }
 }
@@ -36,9 +36,11 @@
 
 pub mod cache;
 pub mod core;
+pub mod diff;
 pub mod error;
 pub mod extraction;
 pub mod extractors;
+pub mod metrics;
 pub mod panic_context;
 pub mod plugins;
 pub mod text;
@@ -91,11 +93,22 @@ pub use core::extractor::{extract_bytes, extract_file};
 pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_sync};
 
 #[cfg(feature = "tokio-runtime")]
-pub use core::extractor::{batch_extract_file_sync, extract_file_sync};
+pub use core::extractor::{IngestOptions, IngestOutput, IngestRecord, IngestSummary, ingest_directory};
+#[cfg(feature = "tokio-runtime")]
+pub use core::extractor::{batch_extract_file_sync, extract_file_sync, ingest_directory_sync};
+
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+pub use core::extractor::{create_searchable_pdf, create_searchable_pdf_file};
+#[cfg(all(feature = "pdf", feature = "ocr", feature = "tokio-runtime"))]
+pub use core::extractor::{create_searchable_pdf_file_sync, create_searchable_pdf_sync};
+
+#[cfg(feature = "watch")]
+pub use core::extractor::{WatchEvent, WatchEventKind, WatchOptions, watch_directory, watch_directory_sync};
 
 pub use core::config::{
-    ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig,
-    LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PostProcessorConfig, TokenReductionConfig,
+    CacheBackend, ChunkSizing, ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig,
+    ImageExtractionConfig, LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PostProcessorConfig,
+    SemanticChunkingConfig, SemanticThreshold, TokenReductionConfig, TokenizerFormat,
 };
 
 #[cfg(feature = "api")]
@@ -104,6 +117,15 @@ pub use core::server_config::ServerConfig;
 #[cfg(feature = "pdf")]
 pub use core::config::{HierarchyConfig, PdfConfig};
 
+#[cfg(feature = "archives")]
+pub use core::config::ArchiveConfig;
+
+#[cfg(feature = "office")]
+pub use core::config::{DocxConfig, RevisionMode};
+
+#[cfg(feature = "email")]
+pub use core::config::{EmailConfig, MboxFormat};
+
 #[cfg(feature = "paddle-ocr")]
 pub use paddle_ocr::{CacheStats, ModelManager, ModelPaths, PaddleLanguage, PaddleOcrBackend, PaddleOcrConfig};
 
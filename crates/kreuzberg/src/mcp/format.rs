@@ -295,6 +295,7 @@ mod tests {
             metadata: crate::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -306,6 +307,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let formatted = format_extraction_result(&result);
@@ -329,8 +331,11 @@ mod tests {
                 ],
                 page_number: 1,
                 markdown: "| Col1 | Col2 |\n|------|------|\n| A    | B    |".to_string(),
+                grid: None,
+                page_end: None,
             }],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -342,6 +347,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let formatted = format_extraction_result(&result);
@@ -359,6 +365,7 @@ mod tests {
             metadata: crate::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: Some(vec![crate::Chunk {
                 content: "Chunk 1".to_string(),
                 embedding: None,
@@ -370,6 +377,11 @@ mod tests {
                     total_chunks: 1,
                     first_page: None,
                     last_page: None,
+                    heading_path: Vec::new(),
+                    element_types: Vec::new(),
+                    node_ids: Vec::new(),
+                    language: None,
+                    language_confidence: None,
                 },
             }]),
             images: None,
@@ -382,6 +394,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let formatted = format_extraction_result(&result);
@@ -399,6 +412,7 @@ mod tests {
             metadata: crate::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             pages: None,
@@ -410,6 +424,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let formatted = format_extraction_result(&result);
@@ -0,0 +1,420 @@
+//! Process-wide runtime metrics in the Prometheus text exposition format.
+//!
+//! The extraction pipeline, the OCR backends, the caches and the API server record
+//! into a single global registry, which the API server exposes on `GET /metrics`.
+//! Applications embedding Kreuzberg can serve the same data from their own
+//! endpoint with [`render`].
+//!
+//! # Exported metrics
+//!
+//! | Metric | Type | Labels |
+//! |--------|------|--------|
+//! | `kreuzberg_http_requests_total` | counter | `method`, `route`, `status` |
+//! | `kreuzberg_http_request_duration_seconds` | histogram | `method`, `route` |
+//! | `kreuzberg_extractions_total` | counter | `extractor`, `mime_type`, `status` |
+//! | `kreuzberg_extraction_duration_seconds` | histogram | `extractor`, `mime_type` |
+//! | `kreuzberg_extractions_in_flight` | gauge | |
+//! | `kreuzberg_max_concurrent_extractions` | gauge | |
+//! | `kreuzberg_ocr_pages_total` | counter | `backend` |
+//! | `kreuzberg_cache_requests_total` | counter | `cache`, `result` |
+//!
+//! With the `pool-metrics` feature, the counters of [`crate::utils::pool`] and the
+//! global string buffer pool are exported as `kreuzberg_pool_*` as well.
+//!
+//! # Example
+//!
+//! ```rust
+//! let text = kreuzberg::metrics::render();
+//! assert!(text.contains("# TYPE kreuzberg_extractions_total counter"));
+//! ```
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Histogram bucket upper bounds in seconds, shared by all latency histograms.
+const LATENCY_BUCKETS: [f64; 14] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0,
+];
+
+static METRICS: Lazy<Metrics> = Lazy::new(Metrics::new);
+
+/// Global metrics registry.
+pub(crate) fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+/// Render all metrics in the Prometheus text exposition format (version 0.0.4).
+pub fn render() -> String {
+    METRICS.render()
+}
+
+/// Observations of a latency distribution.
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Non-cumulative count per bucket of [`LATENCY_BUCKETS`]
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    count: u64,
+    sum: f64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        if let Some(index) = LATENCY_BUCKETS.iter().position(|bound| seconds <= *bound) {
+            self.buckets[index] += 1;
+        }
+        self.count += 1;
+        self.sum += seconds;
+    }
+}
+
+/// A metric with one value per combination of label values.
+struct Family<T> {
+    name: &'static str,
+    help: &'static str,
+    labels: &'static [&'static str],
+    values: Mutex<BTreeMap<Vec<String>, T>>,
+}
+
+impl<T: Default> Family<T> {
+    fn new(name: &'static str, help: &'static str, labels: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            help,
+            labels,
+            values: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn update(&self, label_values: &[&str], f: impl FnOnce(&mut T)) {
+        debug_assert_eq!(label_values.len(), self.labels.len());
+        let key = label_values.iter().map(|value| value.to_string()).collect();
+        f(self.values.lock().entry(key).or_default());
+    }
+
+    fn label_set(&self, label_values: &[String], extra: Option<(&str, &str)>) -> String {
+        let mut pairs: Vec<String> = self
+            .labels
+            .iter()
+            .zip(label_values)
+            .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+            .collect();
+        if let Some((name, value)) = extra {
+            pairs.push(format!("{}=\"{}\"", name, value));
+        }
+        if pairs.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", pairs.join(","))
+        }
+    }
+}
+
+impl Family<u64> {
+    fn increment(&self, label_values: &[&str], amount: u64) {
+        self.update(label_values, |value| *value += amount);
+    }
+
+    fn render(&self, out: &mut String) {
+        write_header(out, self.name, self.help, "counter");
+        for (label_values, value) in self.values.lock().iter() {
+            let _ = writeln!(out, "{}{} {}", self.name, self.label_set(label_values, None), value);
+        }
+    }
+}
+
+impl Family<Histogram> {
+    fn observe(&self, label_values: &[&str], duration: Duration) {
+        self.update(label_values, |histogram| histogram.observe(duration.as_secs_f64()));
+    }
+
+    fn render(&self, out: &mut String) {
+        write_header(out, self.name, self.help, "histogram");
+        for (label_values, histogram) in self.values.lock().iter() {
+            let mut cumulative = 0;
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(histogram.buckets) {
+                cumulative += count;
+                let labels = self.label_set(label_values, Some(("le", &bound.to_string())));
+                let _ = writeln!(out, "{}_bucket{} {}", self.name, labels, cumulative);
+            }
+            let labels = self.label_set(label_values, Some(("le", "+Inf")));
+            let _ = writeln!(out, "{}_bucket{} {}", self.name, labels, histogram.count);
+            let labels = self.label_set(label_values, None);
+            let _ = writeln!(out, "{}_sum{} {}", self.name, labels, histogram.sum);
+            let _ = writeln!(out, "{}_count{} {}", self.name, labels, histogram.count);
+        }
+    }
+}
+
+fn write_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn write_gauge(out: &mut String, name: &str, help: &str, value: usize) {
+    write_header(out, name, help, "gauge");
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Registry of all Kreuzberg metrics.
+pub(crate) struct Metrics {
+    http_requests: Family<u64>,
+    http_request_duration: Family<Histogram>,
+    extractions: Family<u64>,
+    extraction_duration: Family<Histogram>,
+    ocr_pages: Family<u64>,
+    cache_requests: Family<u64>,
+    extractions_in_flight: AtomicUsize,
+    max_concurrent_extractions: AtomicUsize,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        Self {
+            http_requests: Family::new(
+                "kreuzberg_http_requests_total",
+                "HTTP requests handled by the API server.",
+                &["method", "route", "status"],
+            ),
+            http_request_duration: Family::new(
+                "kreuzberg_http_request_duration_seconds",
+                "HTTP request latency in seconds.",
+                &["method", "route"],
+            ),
+            extractions: Family::new(
+                "kreuzberg_extractions_total",
+                "Document extractions by extractor, MIME type and outcome.",
+                &["extractor", "mime_type", "status"],
+            ),
+            extraction_duration: Family::new(
+                "kreuzberg_extraction_duration_seconds",
+                "Document extraction duration in seconds, including post-processing.",
+                &["extractor", "mime_type"],
+            ),
+            ocr_pages: Family::new(
+                "kreuzberg_ocr_pages_total",
+                "Pages or images processed by OCR backends.",
+                &["backend"],
+            ),
+            cache_requests: Family::new(
+                "kreuzberg_cache_requests_total",
+                "Cache lookups by cache and result (hit or miss).",
+                &["cache", "result"],
+            ),
+            extractions_in_flight: AtomicUsize::new(0),
+            max_concurrent_extractions: AtomicUsize::new(0),
+        }
+    }
+
+    /// Record a completed HTTP request. `route` is the matched route template.
+    #[cfg(feature = "api")]
+    pub(crate) fn record_http_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        self.http_requests.increment(&[method, route, &status.to_string()], 1);
+        self.http_request_duration.observe(&[method, route], duration);
+    }
+
+    /// Start timing an extraction; the returned guard counts it as in flight until dropped.
+    pub(crate) fn start_extraction(&'static self, extractor: &str, mime_type: &str) -> ExtractionTimer {
+        self.extractions_in_flight.fetch_add(1, Ordering::Relaxed);
+        ExtractionTimer {
+            metrics: self,
+            extractor: extractor.to_string(),
+            mime_type: mime_type.to_string(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Set the concurrency limit in-flight extractions are compared against.
+    #[cfg(feature = "tokio-runtime")]
+    pub(crate) fn set_max_concurrent_extractions(&self, limit: usize) {
+        self.max_concurrent_extractions.store(limit, Ordering::Relaxed);
+    }
+
+    /// Count pages (or standalone images) passed to an OCR backend.
+    #[cfg(feature = "ocr")]
+    pub(crate) fn record_ocr_pages(&self, backend: &str, pages: usize) {
+        self.ocr_pages.increment(&[backend], pages as u64);
+    }
+
+    /// Count a cache lookup.
+    pub(crate) fn record_cache_lookup(&self, cache: &str, hit: bool) {
+        self.cache_requests
+            .increment(&[cache, if hit { "hit" } else { "miss" }], 1);
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::new();
+        self.http_requests.render(&mut out);
+        self.http_request_duration.render(&mut out);
+        self.extractions.render(&mut out);
+        self.extraction_duration.render(&mut out);
+        write_gauge(
+            &mut out,
+            "kreuzberg_extractions_in_flight",
+            "Extractions currently running.",
+            self.extractions_in_flight.load(Ordering::Relaxed),
+        );
+        write_gauge(
+            &mut out,
+            "kreuzberg_max_concurrent_extractions",
+            "Configured max_concurrent_extractions of the most recent batch or server configuration.",
+            self.max_concurrent_extractions.load(Ordering::Relaxed),
+        );
+        self.ocr_pages.render(&mut out);
+        self.cache_requests.render(&mut out);
+        #[cfg(feature = "pool-metrics")]
+        render_pool_metrics(&mut out);
+        out
+    }
+}
+
+#[cfg(feature = "pool-metrics")]
+fn render_pool_metrics(out: &mut String) {
+    let pools = crate::utils::pool::GLOBAL_POOL_METRICS.snapshot();
+    let strings = crate::utils::string_pool::STRING_BUFFER_POOL.metrics();
+
+    write_header(
+        out,
+        "kreuzberg_pool_acquires_total",
+        "Objects acquired from pools.",
+        "counter",
+    );
+    let _ = writeln!(
+        out,
+        "kreuzberg_pool_acquires_total{{pool=\"batch\"}} {}",
+        pools.total_acquires
+    );
+    let _ = writeln!(
+        out,
+        "kreuzberg_pool_acquires_total{{pool=\"string_buffer\"}} {}",
+        strings.total_acquires
+    );
+    write_header(
+        out,
+        "kreuzberg_pool_reuses_total",
+        "Acquisitions served by reusing a pooled object.",
+        "counter",
+    );
+    let _ = writeln!(
+        out,
+        "kreuzberg_pool_reuses_total{{pool=\"batch\"}} {}",
+        pools.total_cache_hits
+    );
+    let _ = writeln!(
+        out,
+        "kreuzberg_pool_reuses_total{{pool=\"string_buffer\"}} {}",
+        strings.total_reuses
+    );
+    write_header(
+        out,
+        "kreuzberg_pool_creations_total",
+        "Objects created because a pool was empty.",
+        "counter",
+    );
+    let _ = writeln!(
+        out,
+        "kreuzberg_pool_creations_total{{pool=\"batch\"}} {}",
+        pools.total_creations
+    );
+    write_gauge(
+        out,
+        "kreuzberg_pool_peak_items_stored",
+        "Largest number of objects held by a single batch pool.",
+        pools.peak_items_stored,
+    );
+}
+
+/// Times one extraction and tracks it as in flight until dropped.
+pub(crate) struct ExtractionTimer {
+    metrics: &'static Metrics,
+    extractor: String,
+    mime_type: String,
+    started: Instant,
+}
+
+impl ExtractionTimer {
+    /// Record the outcome and duration of the extraction.
+    pub(crate) fn finish<T, E>(self, result: &std::result::Result<T, E>) {
+        let labels = [self.extractor.as_str(), self.mime_type.as_str()];
+        let status = if result.is_ok() { "success" } else { "error" };
+        self.metrics.extractions.increment(&[labels[0], labels[1], status], 1);
+        self.metrics
+            .extraction_duration
+            .observe(&labels, self.started.elapsed());
+    }
+}
+
+impl Drop for ExtractionTimer {
+    fn drop(&mut self) {
+        self.metrics.extractions_in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let family: Family<Histogram> = Family::new("test_seconds", "Test.", &["route"]);
+        family.observe(&["/a"], Duration::from_millis(3));
+        family.observe(&["/a"], Duration::from_millis(300));
+        family.observe(&["/a"], Duration::from_secs(600));
+
+        let mut out = String::new();
+        family.render(&mut out);
+        assert!(out.contains("# TYPE test_seconds histogram"));
+        assert!(out.contains("test_seconds_bucket{route=\"/a\",le=\"0.005\"} 1"));
+        assert!(out.contains("test_seconds_bucket{route=\"/a\",le=\"0.5\"} 2"));
+        assert!(out.contains("test_seconds_bucket{route=\"/a\",le=\"120\"} 2"));
+        assert!(out.contains("test_seconds_bucket{route=\"/a\",le=\"+Inf\"} 3"));
+        assert!(out.contains("test_seconds_count{route=\"/a\"} 3"));
+    }
+
+    #[test]
+    fn test_counter_label_escaping() {
+        let family: Family<u64> = Family::new("test_total", "Test.", &["mime_type"]);
+        family.increment(&["text/\"odd\"\\"], 2);
+
+        let mut out = String::new();
+        family.render(&mut out);
+        assert!(out.contains("test_total{mime_type=\"text/\\\"odd\\\"\\\\\"} 2"));
+    }
+
+    #[test]
+    fn test_extraction_timer_tracks_in_flight() {
+        let metrics: &'static Metrics = Box::leak(Box::new(Metrics::new()));
+        let timer = metrics.start_extraction("plain-text", "text/plain");
+        assert_eq!(metrics.extractions_in_flight.load(Ordering::Relaxed), 1);
+        timer.finish::<(), ()>(&Err(()));
+        assert_eq!(metrics.extractions_in_flight.load(Ordering::Relaxed), 0);
+
+        let out = metrics.render();
+        assert!(out.contains(
+            "kreuzberg_extractions_total{extractor=\"plain-text\",mime_type=\"text/plain\",status=\"error\"} 1"
+        ));
+        assert!(out.contains(
+            "kreuzberg_extraction_duration_seconds_count{extractor=\"plain-text\",mime_type=\"text/plain\"} 1"
+        ));
+    }
+
+    #[cfg(feature = "pool-metrics")]
+    #[test]
+    fn test_pool_metrics_exported() {
+        let pool = crate::utils::pool::create_string_buffer_pool(2, 16);
+        drop(pool.acquire().unwrap());
+        drop(pool.acquire().unwrap());
+
+        let out = render();
+        assert!(out.contains("# TYPE kreuzberg_pool_acquires_total counter"));
+        assert!(out.contains("kreuzberg_pool_reuses_total{pool=\"string_buffer\"}"));
+        assert!(crate::utils::pool::GLOBAL_POOL_METRICS.snapshot().total_cache_hits >= 1);
+    }
+}
@@ -0,0 +1,123 @@
+//! Automatic OCR language selection.
+//!
+//! With `OcrConfig.language = "auto"` each page is recognized with English
+//! first. When language detection finds another language in the recognized
+//! text and the backend has a model for it, the page is recognized again with
+//! that language plus English.
+
+use crate::core::config::OcrConfig;
+use crate::plugins::OcrBackend;
+use crate::types::ExtractionResult;
+
+/// `OcrConfig.language` value that enables automatic language selection.
+pub const AUTO_LANGUAGE: &str = "auto";
+
+/// Language of the first recognition pass, kept as a secondary language afterwards.
+#[cfg(feature = "language-detection")]
+const BASE_LANGUAGE: &str = "eng";
+
+/// Whether `language` requests automatic language selection.
+pub fn is_auto_language(language: &str) -> bool {
+    language.eq_ignore_ascii_case(AUTO_LANGUAGE)
+}
+
+/// Run OCR on one page image, resolving `language = "auto"` from the recognized text.
+///
+/// Other language settings are passed to the backend unchanged.
+pub(crate) async fn process_image(
+    backend: &dyn OcrBackend,
+    image_bytes: &[u8],
+    config: &OcrConfig,
+) -> crate::Result<ExtractionResult> {
+    if !is_auto_language(&config.language) {
+        return backend.process_image(image_bytes, config).await;
+    }
+
+    #[cfg(not(feature = "language-detection"))]
+    {
+        Err(crate::KreuzbergError::validation(
+            "OCR language 'auto' requires the 'language-detection' feature",
+        ))
+    }
+
+    #[cfg(feature = "language-detection")]
+    {
+        let mut base_config = config.clone();
+        base_config.language = BASE_LANGUAGE.to_string();
+        let base_result = backend.process_image(image_bytes, &base_config).await?;
+
+        let Some(language) = detected_ocr_language(&base_result.content) else {
+            return Ok(base_result);
+        };
+        if language == BASE_LANGUAGE || !backend.supports_language(language) {
+            return Ok(base_result);
+        }
+
+        let mut detected_config = config.clone();
+        detected_config.language = format!("{}+{}", language, BASE_LANGUAGE);
+        match backend.process_image(image_bytes, &detected_config).await {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                tracing::debug!(
+                    "OCR with detected language '{}' failed, keeping '{}' result: {}",
+                    detected_config.language,
+                    BASE_LANGUAGE,
+                    e
+                );
+                Ok(base_result)
+            }
+        }
+    }
+}
+
+/// Tesseract language code of the dominant language in `text`, if Tesseract has one.
+#[cfg(feature = "language-detection")]
+fn detected_ocr_language(text: &str) -> Option<&'static str> {
+    let config = crate::core::config::LanguageDetectionConfig {
+        enabled: true,
+        min_confidence: 0.5,
+        detect_multiple: false,
+    };
+    let (language, _) = crate::language_detection::detect_primary_language(text, &config)?;
+    tesseract_language(&language)
+}
+
+/// Map an ISO 639-3 code to the Tesseract model name.
+#[cfg(feature = "language-detection")]
+fn tesseract_language(iso639_3: &str) -> Option<&'static str> {
+    let code = match iso639_3 {
+        "cmn" => "chi_sim",
+        "nob" => "nor",
+        "pes" => "fas",
+        other => other,
+    };
+    super::validation::TESSERACT_SUPPORTED_LANGUAGE_CODES.get(code).copied()
+}
+
+#[cfg(all(test, feature = "language-detection"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_auto_language() {
+        assert!(is_auto_language("auto"));
+        assert!(is_auto_language("AUTO"));
+        assert!(!is_auto_language("eng"));
+    }
+
+    #[test]
+    fn test_tesseract_language_mapping() {
+        assert_eq!(tesseract_language("deu"), Some("deu"));
+        assert_eq!(tesseract_language("cmn"), Some("chi_sim"));
+        assert_eq!(tesseract_language("pes"), Some("fas"));
+        assert_eq!(tesseract_language("sna"), None);
+    }
+
+    #[test]
+    fn test_detected_ocr_language() {
+        let text = "Die Verwaltung hat beschlossen, die Öffnungszeiten der Bibliothek ab dem nächsten Monat \
+                    zu verlängern, damit mehr Studierende die Räume nutzen können.";
+        assert_eq!(detected_ocr_language(text), Some("deu"));
+        assert_eq!(detected_ocr_language("1234 5678"), None);
+    }
+}
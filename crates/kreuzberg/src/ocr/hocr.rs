@@ -23,10 +23,13 @@ pub fn convert_hocr_to_markdown(
     opts.output_format = match format {
         KreuzbergOutputFormat::Markdown => LibOutputFormat::Markdown,
         KreuzbergOutputFormat::Djot => LibOutputFormat::Djot,
-        // Plain, Html, and Structured default to Markdown for hOCR conversion
-        KreuzbergOutputFormat::Plain | KreuzbergOutputFormat::Html | KreuzbergOutputFormat::Structured => {
-            LibOutputFormat::Markdown
-        }
+        // Plain, Html, Structured and the OCR layout formats default to Markdown for hOCR conversion
+        KreuzbergOutputFormat::Plain
+        | KreuzbergOutputFormat::Html
+        | KreuzbergOutputFormat::Structured
+        | KreuzbergOutputFormat::Alto
+        | KreuzbergOutputFormat::PageXml
+        | KreuzbergOutputFormat::Hocr => LibOutputFormat::Markdown,
     };
 
     convert(hocr_html, Some(opts)).map_err(|e| OcrError::ProcessingFailed(format!("hOCR conversion failed: {}", e)))
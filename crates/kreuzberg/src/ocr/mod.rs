@@ -37,6 +37,7 @@
 //! [dependencies]
 //! kreuzberg = { version = "4.0", features = ["ocr"] }
 //! ```
+pub mod auto_language;
 mod backends;
 pub mod cache;
 pub mod conversion;
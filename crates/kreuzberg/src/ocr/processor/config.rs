@@ -40,6 +40,15 @@ pub(super) fn hash_config(config: &TesseractConfig) -> String {
     config.tessedit_use_primary_params_model.hash(&mut hasher);
     config.textord_space_size_is_variable.hash(&mut hasher);
     config.thresholding_method.hash(&mut hasher);
+    if let Some(preprocessing) = &config.preprocessing {
+        preprocessing.target_dpi.hash(&mut hasher);
+        preprocessing.auto_rotate.hash(&mut hasher);
+        preprocessing.deskew.hash(&mut hasher);
+        preprocessing.denoise.hash(&mut hasher);
+        preprocessing.contrast_enhance.hash(&mut hasher);
+        preprocessing.binarization_method.hash(&mut hasher);
+        preprocessing.invert_colors.hash(&mut hasher);
+    }
 
     format!("{:016x}", hasher.finish())
 }
@@ -200,4 +209,25 @@ mod tests {
 
         assert_ne!(hash1, hash2);
     }
+
+    #[test]
+    fn test_hash_config_preprocessing() {
+        let config1 = create_test_config();
+
+        let mut config2 = create_test_config();
+        config2.preprocessing = Some(crate::types::ImagePreprocessingConfig::default());
+
+        let mut config3 = create_test_config();
+        config3.preprocessing = Some(crate::types::ImagePreprocessingConfig {
+            binarization_method: "sauvola".to_string(),
+            ..Default::default()
+        });
+
+        let hash1 = hash_config(&config1);
+        let hash2 = hash_config(&config2);
+        let hash3 = hash_config(&config3);
+
+        assert_ne!(hash1, hash2);
+        assert_ne!(hash2, hash3);
+    }
 }
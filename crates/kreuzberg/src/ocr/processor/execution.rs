@@ -14,6 +14,7 @@ use crate::ocr::error::OcrError;
 use crate::ocr::hocr::convert_hocr_to_markdown;
 use crate::ocr::table::{extract_words_from_tsv, reconstruct_table, table_to_markdown};
 use crate::ocr::types::{BatchItemResult, TesseractConfig};
+use crate::ocr::utils::{TSV_MIN_FIELDS, TSV_WORD_LEVEL};
 use crate::types::{OcrExtractionResult, OcrTable};
 use kreuzberg_tesseract::{TessPageSegMode, TesseractAPI};
 use std::collections::HashMap;
@@ -26,6 +27,11 @@ use crate::types::OcrElement;
 ///
 /// TSV format columns: level, page_num, block_num, par_num, line_num, word_num, left, top, width, height, conf, text
 ///
+/// Tesseract reports text and confidence only on word rows (level 5); line rows
+/// (level 4) carry the line box alone. Each line is emitted as a line element whose
+/// text joins its words and whose confidence is their mean, followed by the words
+/// themselves.
+///
 /// # Arguments
 ///
 /// * `tsv_data` - Raw TSV output from Tesseract
@@ -36,60 +42,88 @@ use crate::types::OcrElement;
 /// Vector of OcrElements for word-level and line-level entries
 fn parse_tsv_to_elements(tsv_data: &str, min_confidence: f64) -> Vec<OcrElement> {
     let mut elements = Vec::new();
+    let mut line: Option<(TsvRow, Vec<TsvRow>)> = None;
 
-    for line in tsv_data.lines().skip(1) {
+    for row in tsv_data.lines().skip(1) {
         // Skip header row
-        let fields: Vec<&str> = line.split('\t').collect();
-        if fields.len() < 12 {
+        let fields: Vec<&str> = row.split('\t').collect();
+        if fields.len() < TSV_MIN_FIELDS {
             continue;
         }
 
         // Parse fields
-        let level = fields[0].parse::<i32>().unwrap_or(0);
-        let page_num = fields[1].parse::<i32>().unwrap_or(1);
-        let block_num = fields[2].parse::<i32>().unwrap_or(0);
-        let par_num = fields[3].parse::<i32>().unwrap_or(0);
-        let line_num = fields[4].parse::<i32>().unwrap_or(0);
-        let word_num = fields[5].parse::<i32>().unwrap_or(0);
-        let left = fields[6].parse::<u32>().unwrap_or(0);
-        let top = fields[7].parse::<u32>().unwrap_or(0);
-        let width = fields[8].parse::<u32>().unwrap_or(0);
-        let height = fields[9].parse::<u32>().unwrap_or(0);
-        let conf = fields[10].parse::<f64>().unwrap_or(-1.0);
-        let text = fields[11].to_string();
-
-        // Skip low-confidence or empty entries
-        // Tesseract uses -1 for non-text levels
-        if conf < 0.0 || conf < min_confidence || text.trim().is_empty() {
+        let level = fields[0].parse::<u32>().unwrap_or(0);
+        let row = TsvRow {
+            level: 0,
+            page_num: fields[1].parse::<i32>().unwrap_or(1),
+            block_num: fields[2].parse::<i32>().unwrap_or(0),
+            par_num: fields[3].parse::<i32>().unwrap_or(0),
+            line_num: fields[4].parse::<i32>().unwrap_or(0),
+            word_num: fields[5].parse::<i32>().unwrap_or(0),
+            left: fields[6].parse::<u32>().unwrap_or(0),
+            top: fields[7].parse::<u32>().unwrap_or(0),
+            width: fields[8].parse::<u32>().unwrap_or(0),
+            height: fields[9].parse::<u32>().unwrap_or(0),
+            conf: fields[10].parse::<f64>().unwrap_or(-1.0),
+            text: fields[11].to_string(),
+        };
+
+        if level == TSV_WORD_LEVEL - 1 {
+            flush_tsv_line(line.take(), &mut elements);
+            line = Some((TsvRow { level: 3, ..row }, Vec::new()));
             continue;
         }
-
-        // Only include word-level (4) and line-level (3) entries
-        if level != 3 && level != 4 {
+        if level != TSV_WORD_LEVEL {
             continue;
         }
 
-        let tsv_row = TsvRow {
-            level,
-            page_num,
-            block_num,
-            par_num,
-            line_num,
-            word_num,
-            left,
-            top,
-            width,
-            height,
-            conf,
-            text,
-        };
+        // Skip low-confidence or empty words
+        // Tesseract uses -1 for rows without recognized text
+        if row.conf < 0.0 || row.conf < min_confidence || row.text.trim().is_empty() {
+            continue;
+        }
 
-        elements.push(tsv_row_to_element(&tsv_row));
+        let word = TsvRow { level: 4, ..row };
+        match line.as_mut() {
+            Some((line_row, words)) if same_tsv_line(line_row, &word) => words.push(word),
+            _ => {
+                // Word without a preceding line row: use the word box as the line box
+                flush_tsv_line(line.take(), &mut elements);
+                line = Some((
+                    TsvRow {
+                        level: 3,
+                        word_num: 0,
+                        ..word.clone()
+                    },
+                    vec![word],
+                ));
+            }
+        }
     }
+    flush_tsv_line(line, &mut elements);
 
     elements
 }
 
+fn same_tsv_line(a: &TsvRow, b: &TsvRow) -> bool {
+    (a.page_num, a.block_num, a.par_num, a.line_num) == (b.page_num, b.block_num, b.par_num, b.line_num)
+}
+
+/// Emit a line element followed by its words; lines without words are dropped.
+fn flush_tsv_line(line: Option<(TsvRow, Vec<TsvRow>)>, elements: &mut Vec<OcrElement>) {
+    let Some((mut line_row, words)) = line else {
+        return;
+    };
+    if words.is_empty() {
+        return;
+    }
+
+    line_row.text = words.iter().map(|word| word.text.trim()).collect::<Vec<_>>().join(" ");
+    line_row.conf = words.iter().map(|word| word.conf).sum::<f64>() / words.len() as f64;
+    elements.push(tsv_row_to_element(&line_row));
+    elements.extend(words.iter().map(tsv_row_to_element));
+}
+
 /// CI debug logging utility.
 ///
 /// Logs debug messages when KREUZBERG_CI_DEBUG environment variable is set.
@@ -159,15 +193,32 @@ pub(super) fn perform_ocr(
         }
     };
 
-    let rgb_image = img.to_rgb8();
-    let (width, height) = rgb_image.dimensions();
-    let bytes_per_pixel = 3;
+    // Run the configured preprocessing pipeline; it yields a binarized grayscale image.
+    let (pixels, bytes_per_pixel, preprocessing_metadata) = match &config.preprocessing {
+        Some(preprocessing) => {
+            let processed = crate::image::preprocess_for_ocr(&img, preprocessing)
+                .map_err(|e| OcrError::ImageProcessingFailed(format!("Image preprocessing failed: {}", e)))?;
+            log_ci_debug(ci_debug_enabled, "preprocess", || {
+                format!("steps={:?}", processed.metadata.steps_applied)
+            });
+            (
+                image::DynamicImage::ImageLuma8(processed.image),
+                1,
+                Some(processed.metadata),
+            )
+        }
+        None => (image::DynamicImage::ImageRgb8(img.to_rgb8()), 3, None),
+    };
+    let (width, height) = (pixels.width(), pixels.height());
     let bytes_per_line = width * bytes_per_pixel;
 
     log_ci_debug(ci_debug_enabled, "image", || {
         format!(
-            "dimensions={}x{} bytes_per_line={} color_type=RGB8",
-            width, height, bytes_per_line
+            "dimensions={}x{} bytes_per_line={} color_type={}",
+            width,
+            height,
+            bytes_per_line,
+            if bytes_per_pixel == 1 { "L8" } else { "RGB8" }
         )
     });
 
@@ -244,7 +295,7 @@ pub(super) fn perform_ocr(
     apply_tesseract_variables(&api, config)?;
 
     api.set_image(
-        rgb_image.as_raw(),
+        pixels.as_bytes(),
         width as i32,
         height as i32,
         bytes_per_pixel as i32,
@@ -264,7 +315,9 @@ pub(super) fn perform_ocr(
 
     log_ci_debug(ci_debug_enabled, "recognize", || "completed".to_string());
 
-    let tsv_data_for_tables = if config.enable_table_detection || config.output_format == "tsv" {
+    // OCR layout output formats are serialized from the TSV-derived elements.
+    let wants_elements = extraction_config.is_some_and(|c| c.output_format.is_ocr_layout());
+    let tsv_data_for_tables = if config.enable_table_detection || config.output_format == "tsv" || wants_elements {
         Some(
             api.get_tsv_text(0)
                 .map_err(|e| OcrError::ProcessingFailed(format!("Failed to extract TSV: {}", e)))?,
@@ -341,6 +394,11 @@ pub(super) fn perform_ocr(
             serde_json::Value::String("hocr".to_string()),
         );
     }
+    if let Some(preprocessing_metadata) = preprocessing_metadata
+        && let Ok(value) = serde_json::to_value(preprocessing_metadata)
+    {
+        metadata.insert("image_preprocessing".to_string(), value);
+    }
 
     let mut tables = Vec::new();
     let mut ocr_elements = None;
@@ -489,18 +547,26 @@ fn process_image_resolved(
     image_bytes.hash(&mut hasher);
     let image_hash = format!("{:016x}", hasher.finish());
 
-    let config_str = hash_config(config);
+    let mut config_str = hash_config(config);
+    // Layout output formats need OCR elements, which other runs may not have produced.
+    if output_format.is_some_and(|format| format.is_ocr_layout()) {
+        config_str.push_str("_elements");
+    }
 
     if config.use_cache
         && let Some(cached_result) = cache.get_cached_result(&image_hash, "tesseract", &config_str)?
     {
         #[cfg(feature = "otel")]
         tracing::Span::current().record("cache.hit", true);
+        crate::metrics::metrics().record_cache_lookup("ocr", true);
         return Ok(cached_result);
     }
 
     #[cfg(feature = "otel")]
     tracing::Span::current().record("cache.hit", false);
+    if config.use_cache {
+        crate::metrics::metrics().record_cache_lookup("ocr", false);
+    }
 
     // Create minimal ExtractionConfig with just the output format if provided
     let extraction_config = output_format.map(|fmt| ExtractionConfig {
@@ -598,6 +664,34 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    #[test]
+    fn test_parse_tsv_to_elements_builds_lines_from_words() {
+        use crate::types::OcrElementLevel;
+
+        let tsv = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext\n\
+                   1\t1\t0\t0\t0\t0\t0\t0\t800\t600\t-1\t\n\
+                   2\t1\t1\t0\t0\t0\t40\t50\t300\t80\t-1\t\n\
+                   3\t1\t1\t1\t0\t0\t40\t50\t300\t80\t-1\t\n\
+                   4\t1\t1\t1\t1\t0\t40\t50\t300\t30\t-1\t\n\
+                   5\t1\t1\t1\t1\t1\t40\t50\t120\t30\t96.0\tHello\n\
+                   5\t1\t1\t1\t1\t2\t180\t52\t160\t28\t90.0\tworld\n\
+                   4\t1\t1\t1\t2\t0\t40\t100\t200\t30\t-1\t\n\
+                   5\t1\t1\t1\t2\t1\t40\t100\t200\t30\t20.0\tnoise\n\
+                   5\t1\t1\t1\t2\t2\t250\t100\t10\t30\t95.0\t \n";
+
+        let elements = parse_tsv_to_elements(tsv, 50.0);
+
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements[0].level, OcrElementLevel::Line);
+        assert_eq!(elements[0].text, "Hello world");
+        assert!((elements[0].confidence.recognition - 0.93).abs() < 1e-9);
+        assert_eq!(elements[0].parent_id.as_deref(), Some("p1_b1_par1"));
+        assert_eq!(elements[1].level, OcrElementLevel::Word);
+        assert_eq!(elements[1].text, "Hello");
+        assert_eq!(elements[1].parent_id.as_deref(), Some("p1_b1_par1_l1"));
+        assert_eq!(elements[2].text, "world");
+    }
+
     #[test]
     fn test_is_all_languages() {
         assert!(is_all_languages("all"));
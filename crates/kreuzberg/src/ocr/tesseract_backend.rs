@@ -184,7 +184,7 @@ impl OcrBackend for TesseractBackend {
         let processor = Arc::clone(&self.processor);
         let image_bytes = image_bytes.to_vec();
 
-        let ocr_result = tokio::task::spawn_blocking(move || match output_format {
+        let mut ocr_result = tokio::task::spawn_blocking(move || match output_format {
             Some(fmt) => processor.process_image_with_format(&image_bytes, &tess_config_clone, fmt),
             None => processor.process_image(&image_bytes, &tess_config_clone),
         })
@@ -206,6 +206,11 @@ impl OcrBackend for TesseractBackend {
             .unwrap_or(&tess_config.language)
             .to_string();
 
+        let image_preprocessing = ocr_result
+            .metadata
+            .remove("image_preprocessing")
+            .and_then(|value| serde_json::from_value(value).ok());
+
         // Convert HashMap<String, Value> to AHashMap<Cow<'static, str>, Value>
         let mut additional = AHashMap::new();
         for (key, value) in ocr_result.metadata {
@@ -224,6 +229,7 @@ impl OcrBackend for TesseractBackend {
                     .first()
                     .and_then(|t| t.cells.first().map(|row| row.len())),
             })),
+            image_preprocessing,
             additional,
             ..Default::default()
         };
@@ -240,9 +246,12 @@ impl OcrBackend for TesseractBackend {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number,
+                    grid: None,
+                    page_end: None,
                 })
                 .collect(),
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             elements: None,
@@ -253,6 +262,7 @@ impl OcrBackend for TesseractBackend {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -264,7 +274,7 @@ impl OcrBackend for TesseractBackend {
         let processor = Arc::clone(&self.processor);
         let path_str = path.to_string_lossy().to_string();
 
-        let ocr_result = tokio::task::spawn_blocking(move || match output_format {
+        let mut ocr_result = tokio::task::spawn_blocking(move || match output_format {
             Some(fmt) => processor.process_file_with_format(&path_str, &tess_config_clone, fmt),
             None => processor.process_file(&path_str, &tess_config_clone),
         })
@@ -286,6 +296,11 @@ impl OcrBackend for TesseractBackend {
             .unwrap_or(&tess_config.language)
             .to_string();
 
+        let image_preprocessing = ocr_result
+            .metadata
+            .remove("image_preprocessing")
+            .and_then(|value| serde_json::from_value(value).ok());
+
         // Convert HashMap<String, Value> to AHashMap<Cow<'static, str>, Value>
         let mut additional = AHashMap::new();
         for (key, value) in ocr_result.metadata {
@@ -304,6 +319,7 @@ impl OcrBackend for TesseractBackend {
                     .first()
                     .and_then(|t| t.cells.first().map(|row| row.len())),
             })),
+            image_preprocessing,
             additional,
             ..Default::default()
         };
@@ -320,9 +336,12 @@ impl OcrBackend for TesseractBackend {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number,
+                    grid: None,
+                    page_end: None,
                 })
                 .collect(),
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             elements: None,
@@ -333,6 +352,7 @@ impl OcrBackend for TesseractBackend {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
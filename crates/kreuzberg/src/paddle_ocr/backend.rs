@@ -363,6 +363,8 @@ impl OcrBackend for PaddleOcrBackend {
                         cells,
                         markdown: table_markdown,
                         page_number: 1,
+                        grid: None,
+                        page_end: None,
                     });
                 }
             }
@@ -384,7 +386,8 @@ impl OcrBackend for PaddleOcrBackend {
             ..Default::default()
         };
 
-        let include_elements = config.element_config.as_ref().is_some_and(|ec| ec.include_elements);
+        let include_elements = config.element_config.as_ref().is_some_and(|ec| ec.include_elements)
+            || config.output_format.is_some_and(|format| format.is_ocr_layout());
 
         let ocr_elements_opt = if include_elements && !ocr_elements.is_empty() {
             Some(ocr_elements)
@@ -398,6 +401,7 @@ impl OcrBackend for PaddleOcrBackend {
             metadata,
             tables,
             detected_languages: Some(vec![config.language.clone()]),
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -409,6 +413,7 @@ impl OcrBackend for PaddleOcrBackend {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         })
     }
 
@@ -0,0 +1,355 @@
+//! PDF annotation and form field extraction.
+//!
+//! Walks the annotations of every page once, collecting link and markup
+//! annotations with their page and bounding box, and the values of AcroForm
+//! fields (which are stored as widget annotations).
+
+use super::outline::destination_page_number;
+use crate::types::BoundingBox;
+use pdfium_render::prelude::PdfFormField as PdfiumFormField;
+use pdfium_render::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Type of a PDF page annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PdfAnnotationKind {
+    /// Hyperlink or internal jump
+    Link,
+    /// Highlighted text
+    Highlight,
+    /// Underlined text
+    Underline,
+    /// Struck-out text
+    StrikeOut,
+    /// Text marked with a squiggly underline
+    Squiggly,
+    /// Sticky note
+    Note,
+    /// Text placed directly on the page
+    FreeText,
+    /// Rubber stamp
+    Stamp,
+    /// Freehand drawing
+    Ink,
+    /// File attached to a page location
+    FileAttachment,
+    /// Any other annotation type (shapes, redactions, media)
+    Other,
+}
+
+/// An annotation on a PDF page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PdfAnnotation {
+    /// Annotation type
+    pub kind: PdfAnnotationKind,
+
+    /// Page the annotation is placed on (1-indexed)
+    pub page_number: usize,
+
+    /// Annotation rectangle in PDF coordinates (origin at the bottom left)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<BoundingBox>,
+
+    /// Comment text of the annotation (`/Contents`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contents: Option<String>,
+
+    /// Author of the annotation (`/T`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Page text covered by a link or text markup annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Target URI of a link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// Target page of an internal link (1-indexed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_page: Option<usize>,
+}
+
+/// Type of an AcroForm field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PdfFormFieldKind {
+    Text,
+    Checkbox,
+    RadioButton,
+    ComboBox,
+    ListBox,
+}
+
+/// An AcroForm field with its current value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PdfFormField {
+    /// Fully qualified field name
+    pub name: String,
+
+    /// Field value; checkboxes report `"true"`/`"false"`, radio groups the
+    /// export value of the selected button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Field type
+    pub kind: PdfFormFieldKind,
+
+    /// Page of the field's first widget (1-indexed)
+    pub page_number: usize,
+}
+
+/// Extract annotations and form fields from all pages.
+///
+/// Popup annotations are skipped because they only display the contents of
+/// their parent annotation. Push buttons and signature fields carry no value
+/// and are skipped as well.
+pub fn extract_annotations_and_form_fields(document: &PdfDocument<'_>) -> (Vec<PdfAnnotation>, Vec<PdfFormField>) {
+    let mut annotations = Vec::new();
+    let mut form_fields = Vec::new();
+
+    for (page_index, page) in document.pages().iter().enumerate() {
+        let page_annotations = page.annotations();
+        if page_annotations.is_empty() {
+            continue;
+        }
+
+        let page_number = page_index + 1;
+        let page_text = page.text().ok();
+
+        for annotation in page_annotations.iter() {
+            if let Some(field) = annotation.as_form_field() {
+                if let Some(form_field) = convert_form_field(field, page_number) {
+                    merge_form_field(&mut form_fields, form_field);
+                }
+                continue;
+            }
+
+            let Some(kind) = annotation_kind(annotation.annotation_type()) else {
+                continue;
+            };
+
+            let covers_text = matches!(
+                kind,
+                PdfAnnotationKind::Link
+                    | PdfAnnotationKind::Highlight
+                    | PdfAnnotationKind::Underline
+                    | PdfAnnotationKind::StrikeOut
+                    | PdfAnnotationKind::Squiggly
+            );
+            let text = if covers_text {
+                page_text
+                    .as_ref()
+                    .and_then(|text| text.for_annotation(&annotation).ok())
+                    .and_then(non_empty)
+            } else {
+                None
+            };
+
+            let (uri, target_page) = annotation
+                .as_link_annotation()
+                .and_then(|link| link.link().ok())
+                .map(|link| link_target(&link))
+                .unwrap_or_default();
+
+            annotations.push(PdfAnnotation {
+                kind,
+                page_number,
+                bbox: annotation.bounds().ok().map(|rect| BoundingBox {
+                    x0: rect.left().value as f64,
+                    y0: rect.bottom().value as f64,
+                    x1: rect.right().value as f64,
+                    y1: rect.top().value as f64,
+                }),
+                contents: annotation.contents().and_then(non_empty),
+                author: annotation.creator().and_then(non_empty),
+                text,
+                uri,
+                target_page,
+            });
+        }
+    }
+
+    (annotations, form_fields)
+}
+
+fn annotation_kind(annotation_type: PdfPageAnnotationType) -> Option<PdfAnnotationKind> {
+    let kind = match annotation_type {
+        PdfPageAnnotationType::Link => PdfAnnotationKind::Link,
+        PdfPageAnnotationType::Highlight => PdfAnnotationKind::Highlight,
+        PdfPageAnnotationType::Underline => PdfAnnotationKind::Underline,
+        PdfPageAnnotationType::Strikeout => PdfAnnotationKind::StrikeOut,
+        PdfPageAnnotationType::Squiggly => PdfAnnotationKind::Squiggly,
+        PdfPageAnnotationType::Text => PdfAnnotationKind::Note,
+        PdfPageAnnotationType::FreeText => PdfAnnotationKind::FreeText,
+        PdfPageAnnotationType::Stamp => PdfAnnotationKind::Stamp,
+        PdfPageAnnotationType::Ink => PdfAnnotationKind::Ink,
+        PdfPageAnnotationType::FileAttachment => PdfAnnotationKind::FileAttachment,
+        PdfPageAnnotationType::Popup | PdfPageAnnotationType::Widget | PdfPageAnnotationType::XfaWidget => {
+            return None;
+        }
+        _ => PdfAnnotationKind::Other,
+    };
+    Some(kind)
+}
+
+/// URI and internal target page of a link.
+fn link_target(link: &PdfLink<'_>) -> (Option<String>, Option<usize>) {
+    if let Some(destination) = link.destination() {
+        return (None, destination_page_number(&destination));
+    }
+
+    let Some(action) = link.action() else {
+        return (None, None);
+    };
+    if let Some(uri_action) = action.as_uri_action() {
+        return (uri_action.uri().ok().and_then(non_empty), None);
+    }
+    let target_page = action
+        .as_local_destination_action()
+        .and_then(|local| local.destination().ok())
+        .and_then(|destination| destination_page_number(&destination));
+    (None, target_page)
+}
+
+fn convert_form_field(field: &PdfiumFormField<'_>, page_number: usize) -> Option<PdfFormField> {
+    let name = field.name().and_then(non_empty)?;
+
+    let (kind, value) = match field.field_type() {
+        PdfFormFieldType::Text => (PdfFormFieldKind::Text, field.as_text_field()?.value()),
+        PdfFormFieldType::ComboBox => (PdfFormFieldKind::ComboBox, field.as_combo_box_field()?.value()),
+        PdfFormFieldType::ListBox => (PdfFormFieldKind::ListBox, field.as_list_box_field()?.value()),
+        PdfFormFieldType::Checkbox => {
+            let checked = field.as_checkbox_field()?.is_checked().unwrap_or(false);
+            (PdfFormFieldKind::Checkbox, Some(checked.to_string()))
+        }
+        PdfFormFieldType::RadioButton => {
+            let radio = field.as_radio_button_field()?;
+            let value = if radio.is_checked().unwrap_or(false) {
+                radio.group_value()
+            } else {
+                None
+            };
+            (PdfFormFieldKind::RadioButton, value)
+        }
+        PdfFormFieldType::PushButton | PdfFormFieldType::Signature | PdfFormFieldType::Unknown => return None,
+    };
+
+    Some(PdfFormField {
+        name,
+        value,
+        kind,
+        page_number,
+    })
+}
+
+/// Add a field, folding the widgets of a checkbox or radio group into one entry.
+///
+/// All widgets of a group share the field name; a widget that is checked
+/// (or has a value) wins over one that is not.
+fn merge_form_field(fields: &mut Vec<PdfFormField>, field: PdfFormField) {
+    let Some(existing) = fields.iter_mut().find(|existing| existing.name == field.name) else {
+        fields.push(field);
+        return;
+    };
+
+    let existing_is_set = existing.value.as_deref().is_some_and(|value| value != "false");
+    let field_is_set = field.value.as_deref().is_some_and(|value| value != "false");
+    if field_is_set && !existing_is_set {
+        existing.value = field.value;
+    }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, value: Option<&str>, kind: PdfFormFieldKind, page_number: usize) -> PdfFormField {
+        PdfFormField {
+            name: name.to_string(),
+            value: value.map(str::to_string),
+            kind,
+            page_number,
+        }
+    }
+
+    #[test]
+    fn test_annotation_kind_mapping() {
+        assert_eq!(
+            annotation_kind(PdfPageAnnotationType::Text),
+            Some(PdfAnnotationKind::Note)
+        );
+        assert_eq!(
+            annotation_kind(PdfPageAnnotationType::Strikeout),
+            Some(PdfAnnotationKind::StrikeOut)
+        );
+        assert_eq!(
+            annotation_kind(PdfPageAnnotationType::Square),
+            Some(PdfAnnotationKind::Other)
+        );
+        assert_eq!(annotation_kind(PdfPageAnnotationType::Popup), None);
+        assert_eq!(annotation_kind(PdfPageAnnotationType::Widget), None);
+    }
+
+    #[test]
+    fn test_merge_form_field_prefers_checked_widget() {
+        let mut fields = Vec::new();
+        merge_form_field(
+            &mut fields,
+            field("agree", Some("false"), PdfFormFieldKind::Checkbox, 1),
+        );
+        merge_form_field(&mut fields, field("size", None, PdfFormFieldKind::RadioButton, 1));
+        merge_form_field(
+            &mut fields,
+            field("size", Some("Large"), PdfFormFieldKind::RadioButton, 2),
+        );
+        merge_form_field(&mut fields, field("size", None, PdfFormFieldKind::RadioButton, 2));
+        merge_form_field(&mut fields, field("agree", Some("true"), PdfFormFieldKind::Checkbox, 1));
+
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].value.as_deref(), Some("true"));
+        assert_eq!(fields[1].value.as_deref(), Some("Large"));
+        assert_eq!(fields[1].page_number, 1);
+    }
+
+    #[test]
+    fn test_annotation_serialization() {
+        let annotation = PdfAnnotation {
+            kind: PdfAnnotationKind::StrikeOut,
+            page_number: 3,
+            bbox: None,
+            contents: Some("remove".to_string()),
+            author: None,
+            text: None,
+            uri: None,
+            target_page: None,
+        };
+
+        let json = serde_json::to_value(&annotation).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "kind": "strike_out", "page_number": 3, "contents": "remove" })
+        );
+    }
+
+    #[test]
+    fn test_non_empty() {
+        assert_eq!(non_empty("  note ".to_string()).as_deref(), Some("note"));
+        assert_eq!(non_empty(" \n".to_string()), None);
+    }
+}
@@ -0,0 +1,66 @@
+//! PDF embedded file extraction.
+//!
+//! Lists the files in the document's `/EmbeddedFiles` name tree (PDF
+//! portfolios, attached spreadsheets or source documents) and reads their
+//! contents for extraction through the extractor registry.
+
+use super::bindings::bind_pdfium;
+use super::error::{PdfError, Result};
+use crate::extraction::embedded::EmbeddedDocument;
+use pdfium_render::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A file embedded in a PDF document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PdfEmbeddedFile {
+    /// File name as stored in the PDF
+    pub name: String,
+
+    /// Size of the file in bytes
+    pub size: usize,
+}
+
+/// List the files embedded in a document.
+pub fn list_embedded_files(document: &PdfDocument<'_>) -> Vec<PdfEmbeddedFile> {
+    document
+        .attachments()
+        .iter()
+        .map(|attachment| PdfEmbeddedFile {
+            name: attachment.name(),
+            size: attachment.len(),
+        })
+        .collect()
+}
+
+/// Read the contents of all files embedded in a PDF.
+///
+/// Attachments whose data cannot be read are skipped.
+pub fn extract_embedded_files(pdf_bytes: &[u8]) -> Result<Vec<EmbeddedDocument>> {
+    let pdfium = bind_pdfium(PdfError::ExtractionFailed, "initialize Pdfium")?;
+
+    let document = pdfium.load_pdf_from_byte_slice(pdf_bytes, None).map_err(|e| {
+        let err_msg = super::error::format_pdfium_error(e);
+        if err_msg.contains("password") || err_msg.contains("Password") {
+            PdfError::PasswordRequired
+        } else {
+            PdfError::InvalidPdf(err_msg)
+        }
+    })?;
+
+    Ok(document
+        .attachments()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, attachment)| {
+            let data = attachment.save_to_bytes().ok()?;
+            let name = attachment.name();
+            let path = if name.trim().is_empty() {
+                format!("attachment_{}", index + 1)
+            } else {
+                name
+            };
+            Some(EmbeddedDocument { path, data })
+        })
+        .collect())
+}
@@ -12,6 +12,7 @@ pub enum PdfError {
     MetadataExtractionFailed(String),
     ExtractionFailed(String),
     FontLoadingFailed(String),
+    WriteFailed(String),
     IOError(String),
 }
 
@@ -32,6 +33,7 @@ impl fmt::Display for PdfError {
             }
             PdfError::ExtractionFailed(msg) => write!(f, "Extraction failed: {}", msg),
             PdfError::FontLoadingFailed(msg) => write!(f, "Font loading failed: {}", msg),
+            PdfError::WriteFailed(msg) => write!(f, "PDF writing failed: {}", msg),
             PdfError::IOError(msg) => write!(f, "I/O error: {}", msg),
         }
     }
@@ -128,6 +130,12 @@ mod tests {
         assert_eq!(err.to_string(), "Metadata extraction failed: invalid metadata");
     }
 
+    #[test]
+    fn test_write_failed_error() {
+        let err = PdfError::WriteFailed("stream too large".to_string());
+        assert_eq!(err.to_string(), "PDF writing failed: stream too large");
+    }
+
     #[test]
     fn test_io_error() {
         let err = PdfError::IOError("read failed".to_string());
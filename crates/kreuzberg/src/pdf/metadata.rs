@@ -1,5 +1,8 @@
+use super::annotations::{PdfAnnotation, PdfFormField};
+use super::attachments::PdfEmbeddedFile;
 use super::bindings::bind_pdfium;
 use super::error::{PdfError, Result};
+use super::outline::PdfOutlineEntry;
 use crate::types::{PageBoundary, PageInfo, PageStructure, PageUnitType};
 use pdfium_render::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -35,6 +38,22 @@ pub struct PdfMetadata {
     /// Total number of pages in the PDF document
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_count: Option<usize>,
+
+    /// Document outline (bookmark tree)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outline: Vec<PdfOutlineEntry>,
+
+    /// Link and markup annotations of all pages
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<PdfAnnotation>,
+
+    /// AcroForm fields with their current values
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub form_fields: Vec<PdfFormField>,
+
+    /// Files embedded in the document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub embedded_files: Vec<PdfEmbeddedFile>,
 }
 
 /// Complete PDF extraction metadata including common and PDF-specific fields.
@@ -82,14 +101,16 @@ pub struct PdfExtractionMetadata {
 
 /// Extract PDF-specific metadata from raw bytes.
 ///
-/// Returns only PDF-specific metadata (version, producer, encryption status, dimensions).
+/// Returns only PDF-specific metadata (version, producer, encryption status, dimensions,
+/// outline, annotations, form fields and embedded files).
 pub fn extract_metadata(pdf_bytes: &[u8]) -> Result<PdfMetadata> {
     extract_metadata_with_password(pdf_bytes, None)
 }
 
 /// Extract PDF-specific metadata from raw bytes with optional password.
 ///
-/// Returns only PDF-specific metadata (version, producer, encryption status, dimensions).
+/// Returns only PDF-specific metadata (version, producer, encryption status, dimensions,
+/// outline, annotations, form fields and embedded files).
 pub fn extract_metadata_with_password(pdf_bytes: &[u8], password: Option<&str>) -> Result<PdfMetadata> {
     let pdfium = bind_pdfium(PdfError::MetadataExtractionFailed, "metadata extraction")?;
 
@@ -184,7 +205,8 @@ pub(crate) fn extract_metadata_from_document_impl(
 
 /// Extract PDF-specific metadata from a document.
 ///
-/// Returns only PDF-specific metadata (version, producer, encryption status, dimensions).
+/// Returns only PDF-specific metadata (version, producer, encryption status, dimensions,
+/// outline, annotations, form fields and embedded files).
 fn extract_pdf_specific_metadata(document: &PdfDocument<'_>) -> Result<PdfMetadata> {
     let pdf_metadata = document.metadata();
 
@@ -213,6 +235,10 @@ fn extract_pdf_specific_metadata(document: &PdfDocument<'_>) -> Result<PdfMetada
     // Always capture page count
     metadata.page_count = Some(document.pages().len() as usize);
 
+    metadata.outline = super::outline::extract_outline(document);
+    (metadata.annotations, metadata.form_fields) = super::annotations::extract_annotations_and_form_fields(document);
+    metadata.embedded_files = super::attachments::list_embedded_files(document);
+
     Ok(metadata)
 }
 
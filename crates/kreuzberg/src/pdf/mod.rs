@@ -8,8 +8,11 @@
 //!
 //! - **Text extraction**: Extract text content from PDFs using `pdfium-render`
 //! - **Metadata extraction**: Parse PDF metadata (title, author, creation date, etc.)
+//! - **Document features**: Read the outline, annotations, form fields and embedded files
 //! - **Image extraction**: Extract embedded images from PDF pages
+//! - **Table detection**: Recover ruled tables, including merged cells, from drawn borders
 //! - **Page rendering**: Render PDF pages to images for OCR processing
+//! - **Searchable PDF output**: Write page images with an invisible OCR text layer
 //! - **Error handling**: Comprehensive PDF-specific error types
 //!
 //! # Example
@@ -36,6 +39,10 @@
 //! This module requires the `pdf` feature. The `ocr` feature enables additional
 //! functionality in the PDF extractor for rendering pages to images.
 #[cfg(feature = "pdf")]
+pub mod annotations;
+#[cfg(feature = "pdf")]
+pub mod attachments;
+#[cfg(feature = "pdf")]
 pub(crate) mod bindings;
 #[cfg(all(feature = "pdf", feature = "bundled-pdfium"))]
 pub mod bundled;
@@ -50,14 +57,24 @@ pub mod images;
 #[cfg(feature = "pdf")]
 pub mod metadata;
 #[cfg(feature = "pdf")]
+pub mod outline;
+#[cfg(feature = "pdf")]
 pub mod rendering;
 #[cfg(feature = "pdf")]
+pub mod ruled_table;
+#[cfg(feature = "pdf")]
+pub mod searchable;
+#[cfg(feature = "pdf")]
 pub mod table;
 #[cfg(feature = "pdf")]
 pub mod text;
 
 #[cfg(feature = "pdf")]
 pub use crate::core::config::HierarchyConfig;
+#[cfg(feature = "pdf")]
+pub use annotations::{PdfAnnotation, PdfAnnotationKind, PdfFormField, PdfFormFieldKind};
+#[cfg(feature = "pdf")]
+pub use attachments::PdfEmbeddedFile;
 #[cfg(all(feature = "pdf", feature = "bundled-pdfium"))]
 pub use bundled::extract_bundled_pdfium;
 #[cfg(feature = "pdf")]
@@ -74,8 +91,14 @@ pub use images::{PdfImage, PdfImageExtractor, extract_images_from_pdf};
 #[cfg(feature = "pdf")]
 pub use metadata::extract_metadata;
 #[cfg(feature = "pdf")]
+pub use outline::PdfOutlineEntry;
+#[cfg(feature = "pdf")]
 pub use rendering::{PageRenderOptions, render_page_to_image};
 #[cfg(feature = "pdf")]
+pub use ruled_table::extract_ruled_tables;
+#[cfg(feature = "pdf")]
+pub use searchable::{SearchablePdfPage, write_searchable_pdf};
+#[cfg(feature = "pdf")]
 pub use table::extract_words_from_page;
 #[cfg(feature = "pdf")]
 pub use text::extract_text_from_pdf;
@@ -0,0 +1,249 @@
+//! PDF outline (bookmark) extraction.
+//!
+//! Reads the document outline as a tree of titled entries with their target
+//! pages. The outline also feeds hierarchy detection: text blocks matching a
+//! bookmark title on the bookmark's target page take their heading level from
+//! the outline depth instead of from font size clustering.
+
+use super::hierarchy::{HierarchyBlock, HierarchyLevel};
+use pdfium_render::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Deepest outline level that is read.
+const MAX_OUTLINE_DEPTH: usize = 32;
+
+/// Upper bound on outline entries, guarding against cyclic outlines in malformed files.
+const MAX_OUTLINE_ENTRIES: usize = 10_000;
+
+/// An entry of the PDF outline (bookmark tree).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PdfOutlineEntry {
+    /// Bookmark title
+    pub title: String,
+
+    /// Target page (1-indexed), if the bookmark points into this document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<usize>,
+
+    /// Nested bookmarks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<PdfOutlineEntry>,
+}
+
+/// Heading derived from the outline, used to override hierarchy levels on one page.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OutlineHeading {
+    /// Title normalized with [`normalize_title`]
+    pub key: String,
+    pub level: HierarchyLevel,
+}
+
+/// Extract the document outline as a tree.
+///
+/// Returns an empty vector for documents without bookmarks.
+pub fn extract_outline(document: &PdfDocument<'_>) -> Vec<PdfOutlineEntry> {
+    let mut remaining = MAX_OUTLINE_ENTRIES;
+    read_outline_level(document.bookmarks().root(), 0, &mut remaining)
+}
+
+fn read_outline_level(first: Option<PdfBookmark<'_>>, depth: usize, remaining: &mut usize) -> Vec<PdfOutlineEntry> {
+    let mut entries = Vec::new();
+    let mut current = first;
+
+    while let Some(bookmark) = current {
+        if *remaining == 0 {
+            break;
+        }
+        *remaining -= 1;
+
+        let children = if depth + 1 < MAX_OUTLINE_DEPTH {
+            read_outline_level(bookmark.first_child(), depth + 1, remaining)
+        } else {
+            Vec::new()
+        };
+        let title = bookmark.title().map(|t| t.trim().to_string()).unwrap_or_default();
+
+        if !title.is_empty() || !children.is_empty() {
+            entries.push(PdfOutlineEntry {
+                title,
+                page_number: bookmark_page_number(&bookmark),
+                children,
+            });
+        }
+
+        current = bookmark.next_sibling();
+    }
+
+    entries
+}
+
+fn bookmark_page_number(bookmark: &PdfBookmark<'_>) -> Option<usize> {
+    if let Some(destination) = bookmark.destination() {
+        return destination_page_number(&destination);
+    }
+
+    let action = bookmark.action()?;
+    let local = action.as_local_destination_action()?;
+    destination_page_number(&local.destination().ok()?)
+}
+
+/// 1-indexed page number of a destination inside the document.
+pub(crate) fn destination_page_number(destination: &PdfDestination<'_>) -> Option<usize> {
+    let index = destination.page_index().ok()?;
+    usize::try_from(index).ok().map(|index| index + 1)
+}
+
+/// Group outline entries by target page as headings, with the level taken from the depth.
+///
+/// Top-level bookmarks become H1, their children H2 and so on; anything below
+/// the sixth level is treated as H6.
+pub(crate) fn outline_headings_by_page(outline: &[PdfOutlineEntry]) -> HashMap<usize, Vec<OutlineHeading>> {
+    fn collect(entries: &[PdfOutlineEntry], depth: usize, headings: &mut HashMap<usize, Vec<OutlineHeading>>) {
+        for entry in entries {
+            let key = normalize_title(&entry.title);
+            if let Some(page_number) = entry.page_number
+                && !key.is_empty()
+            {
+                headings.entry(page_number).or_default().push(OutlineHeading {
+                    key,
+                    level: HierarchyLevel::from_level((depth + 1).min(6)),
+                });
+            }
+            collect(&entry.children, depth + 1, headings);
+        }
+    }
+
+    let mut headings = HashMap::new();
+    collect(outline, 0, &mut headings);
+    headings
+}
+
+/// Give blocks whose text matches an outline heading of their page the outline level.
+///
+/// Blocks without a matching bookmark keep the level assigned by font size
+/// clustering, since many outlines only cover the top levels of a document.
+pub(crate) fn apply_outline_levels(blocks: &mut [HierarchyBlock], headings: &[OutlineHeading]) {
+    if headings.is_empty() {
+        return;
+    }
+
+    for block in blocks.iter_mut() {
+        let key = normalize_title(&block.text);
+        if let Some(heading) = headings.iter().find(|heading| heading.key == key) {
+            block.hierarchy_level = heading.level;
+        }
+    }
+}
+
+/// Lowercase a title and reduce it to alphanumeric words separated by single spaces.
+///
+/// Bookmark titles often differ from the rendered heading in punctuation and
+/// spacing ("1. Introduction" vs. "1 Introduction").
+fn normalize_title(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdf::hierarchy::BoundingBox;
+
+    fn entry(title: &str, page_number: Option<usize>, children: Vec<PdfOutlineEntry>) -> PdfOutlineEntry {
+        PdfOutlineEntry {
+            title: title.to_string(),
+            page_number,
+            children,
+        }
+    }
+
+    fn block(text: &str, level: HierarchyLevel) -> HierarchyBlock {
+        HierarchyBlock {
+            text: text.to_string(),
+            bbox: BoundingBox {
+                left: 0.0,
+                top: 0.0,
+                right: 100.0,
+                bottom: 12.0,
+            },
+            font_size: 12.0,
+            hierarchy_level: level,
+        }
+    }
+
+    #[test]
+    fn test_normalize_title() {
+        assert_eq!(normalize_title("  1. Introduction "), "1 introduction");
+        assert_eq!(normalize_title("Results—and  Discussion"), "results and discussion");
+        assert_eq!(normalize_title("..."), "");
+    }
+
+    #[test]
+    fn test_outline_headings_by_page_uses_depth() {
+        let outline = vec![
+            entry(
+                "Chapter 1",
+                Some(1),
+                vec![entry("Section 1.1", Some(2), vec![entry("Detail", Some(2), vec![])])],
+            ),
+            entry("External", None, vec![]),
+        ];
+
+        let headings = outline_headings_by_page(&outline);
+
+        assert_eq!(headings.len(), 2);
+        assert_eq!(headings[&1][0].level, HierarchyLevel::H1);
+        assert_eq!(headings[&2][0].key, "section 1 1");
+        assert_eq!(headings[&2][0].level, HierarchyLevel::H2);
+        assert_eq!(headings[&2][1].level, HierarchyLevel::H3);
+    }
+
+    #[test]
+    fn test_outline_headings_cap_at_h6() {
+        let mut outline = entry("Level 8", Some(1), vec![]);
+        for depth in (1..8).rev() {
+            outline = entry(&format!("Level {depth}"), Some(1), vec![outline]);
+        }
+
+        let headings = outline_headings_by_page(&[outline]);
+        let levels: Vec<_> = headings[&1].iter().map(|h| h.level).collect();
+
+        assert_eq!(levels[5], HierarchyLevel::H6);
+        assert_eq!(levels[7], HierarchyLevel::H6);
+    }
+
+    #[test]
+    fn test_apply_outline_levels_overrides_matching_blocks() {
+        let outline = vec![entry(
+            "Introduction",
+            Some(1),
+            vec![entry("1.1 Scope", Some(1), vec![])],
+        )];
+        let headings = outline_headings_by_page(&outline);
+        let mut blocks = vec![
+            block("INTRODUCTION", HierarchyLevel::H3),
+            block("1.1. Scope", HierarchyLevel::Body),
+            block("Some body text.", HierarchyLevel::Body),
+            block("Large caption", HierarchyLevel::H2),
+        ];
+
+        apply_outline_levels(&mut blocks, &headings[&1]);
+
+        assert_eq!(blocks[0].hierarchy_level, HierarchyLevel::H1);
+        assert_eq!(blocks[1].hierarchy_level, HierarchyLevel::H2);
+        assert_eq!(blocks[2].hierarchy_level, HierarchyLevel::Body);
+        assert_eq!(blocks[3].hierarchy_level, HierarchyLevel::H2);
+    }
+
+    #[test]
+    fn test_outline_entry_serialization_skips_empty_fields() {
+        let json = serde_json::to_value(entry("Appendix", None, vec![])).unwrap();
+        assert_eq!(json, serde_json::json!({ "title": "Appendix" }));
+    }
+}
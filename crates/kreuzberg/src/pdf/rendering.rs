@@ -134,6 +134,26 @@ impl PdfRenderer<'_> {
 
         Ok(images)
     }
+
+    /// Width and height of every page in PDF points, in page order.
+    pub fn page_sizes(&self, pdf_bytes: &[u8], password: Option<&str>) -> Result<Vec<(f64, f64)>> {
+        let document = self.pdfium.load_pdf_from_byte_slice(pdf_bytes, password).map_err(|e| {
+            let err_msg = super::error::format_pdfium_error(e);
+            if (err_msg.contains("password") || err_msg.contains("Password")) && password.is_some() {
+                PdfError::InvalidPassword
+            } else if err_msg.contains("password") || err_msg.contains("Password") {
+                PdfError::PasswordRequired
+            } else {
+                PdfError::InvalidPdf(err_msg)
+            }
+        })?;
+
+        Ok(document
+            .pages()
+            .iter()
+            .map(|page| (page.width().value as f64, page.height().value as f64))
+            .collect())
+    }
 }
 
 pub fn render_page_to_image(pdf_bytes: &[u8], page_index: usize, options: &PageRenderOptions) -> Result<DynamicImage> {
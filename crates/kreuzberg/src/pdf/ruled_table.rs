@@ -0,0 +1,860 @@
+//! Ruled table detection from PDF vector graphics.
+//!
+//! Tables with drawn borders are recognized from the line and rectangle path
+//! objects on a page rather than from gaps between words. Horizontal and
+//! vertical rules are collected (including those inside form XObjects), merged
+//! into continuous lines and grouped into connected networks. Each network
+//! defines a grid of row and column boundaries; a missing rule between two
+//! neighbouring grid cells merges them, which yields row and column spans.
+//!
+//! Cell text is read from the area inside each cell, so the result carries both
+//! the plain `cells` matrix and a [`TableGrid`] with spans and per-cell bounding
+//! boxes in PDF coordinates (points, origin at the bottom-left of the page).
+//!
+//! Unlike [`super::table`], this does not need the `ocr` feature.
+
+use super::error::{PdfError, Result};
+use crate::extraction::cells_to_markdown;
+use crate::types::{BoundingBox, GridCell, Table, TableGrid};
+use pdfium_render::prelude::*;
+
+/// Maximum distance between rule positions that are treated as the same line.
+const SNAP_TOLERANCE: f32 = 2.0;
+
+/// Maximum gap bridged when joining collinear rules or intersecting rules.
+const JOIN_TOLERANCE: f32 = 2.0;
+
+/// Maximum deviation from horizontal or vertical for a path edge to count as a rule.
+const AXIS_TOLERANCE: f32 = 0.5;
+
+/// Rules shorter than this are ignored (dots, tick marks).
+const MIN_RULE_LENGTH: f32 = 3.0;
+
+/// Filled rectangles no thicker than this are drawn rules rather than shapes.
+const MAX_RULE_THICKNESS: f32 = 3.0;
+
+/// Cell areas are shrunk by this much before reading text so border glyphs of
+/// neighbouring cells are not picked up.
+const CELL_TEXT_INSET: f32 = 1.0;
+
+/// Maximum nesting of form XObjects that is followed.
+const MAX_FORM_DEPTH: usize = 16;
+
+/// Detect ruled tables on a page.
+///
+/// Returns one [`Table`] per detected grid, ordered top to bottom. Every table
+/// has its `grid` set with row/column spans and cell bounding boxes. Pages
+/// without drawn table borders yield an empty vector.
+///
+/// # Errors
+///
+/// Returns [`PdfError::TextExtractionFailed`] if the page text cannot be read
+/// for a page that has ruled grids.
+pub fn extract_ruled_tables(page: &PdfPage, page_number: usize) -> Result<Vec<Table>> {
+    let mut rules = Rules::default();
+    let objects = page.objects();
+    for object in objects.iter() {
+        collect_object_rules(&object, &[], 0, &mut rules);
+    }
+
+    let grids = detect_grids(&rules);
+    if grids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let page_text = page
+        .text()
+        .map_err(|e| PdfError::TextExtractionFailed(format!("Failed to get page text: {}", e)))?;
+
+    Ok(grids
+        .iter()
+        .filter_map(|grid| {
+            grid.to_table(page_number, |rect| {
+                let inset = CELL_TEXT_INSET.min(rect.width() / 4.0).min(rect.height() / 4.0);
+                page_text.inside_rect(PdfRect::new_from_values(
+                    rect.bottom + inset,
+                    rect.left + inset,
+                    rect.top - inset,
+                    rect.right - inset,
+                ))
+            })
+        })
+        .collect())
+}
+
+/// Collect rules from a page object, descending into form XObjects.
+///
+/// `ancestors` holds the matrices of the enclosing forms, innermost first.
+fn collect_object_rules(object: &PdfPageObject<'_>, ancestors: &[PdfMatrix], depth: usize, rules: &mut Rules) {
+    match object {
+        PdfPageObject::Path(path) => {
+            let Ok(matrix) = path.matrix() else {
+                return;
+            };
+            let filled = path.fill_mode().is_ok_and(|mode| mode != PdfPathFillMode::None);
+            let stroked = path.is_stroked().unwrap_or(false);
+            if !filled && !stroked {
+                return;
+            }
+
+            let to_page = |x: PdfPoints, y: PdfPoints| {
+                let (mut x, mut y) = matrix.apply_to_points(x, y);
+                for ancestor in ancestors {
+                    (x, y) = ancestor.apply_to_points(x, y);
+                }
+                (x.value, y.value)
+            };
+
+            let segments = path.segments();
+            let mut subpath = Subpath::default();
+            for segment in segments.iter() {
+                let point = to_page(segment.x(), segment.y());
+                match segment.segment_type() {
+                    PdfPathSegmentType::MoveTo => {
+                        rules.add_subpath(&subpath, filled, stroked);
+                        subpath = Subpath::starting_at(point);
+                    }
+                    PdfPathSegmentType::LineTo => subpath.line_to(point),
+                    PdfPathSegmentType::BezierTo => subpath.curve_to(point),
+                    PdfPathSegmentType::Unknown => {}
+                }
+                if segment.is_close() {
+                    subpath.close();
+                }
+            }
+            rules.add_subpath(&subpath, filled, stroked);
+        }
+        PdfPageObject::XObjectForm(form) if depth < MAX_FORM_DEPTH => {
+            let Ok(matrix) = form.matrix() else {
+                return;
+            };
+            let mut chain = Vec::with_capacity(ancestors.len() + 1);
+            chain.push(matrix);
+            chain.extend_from_slice(ancestors);
+            for index in 0..form.len() {
+                if let Ok(child) = form.get(index) {
+                    collect_object_rules(&child, &chain, depth + 1, rules);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+type Point = (f32, f32);
+
+/// One subpath of a path object in page coordinates.
+#[derive(Debug, Default)]
+struct Subpath {
+    points: Vec<Point>,
+    edges: Vec<(Point, Point)>,
+    curved: bool,
+}
+
+impl Subpath {
+    fn starting_at(point: Point) -> Self {
+        Self {
+            points: vec![point],
+            ..Self::default()
+        }
+    }
+
+    fn current(&self) -> Option<Point> {
+        self.points.last().copied()
+    }
+
+    fn line_to(&mut self, point: Point) {
+        if let Some(current) = self.current() {
+            self.edges.push((current, point));
+        }
+        self.points.push(point);
+    }
+
+    fn curve_to(&mut self, point: Point) {
+        self.curved = true;
+        self.points.push(point);
+    }
+
+    fn close(&mut self) {
+        if let (Some(&first), Some(last)) = (self.points.first(), self.current())
+            && first != last
+        {
+            self.edges.push((last, first));
+        }
+    }
+
+    /// Bounds as `(left, bottom, right, top)` if the subpath is an axis-aligned rectangle.
+    fn as_rectangle(&self) -> Option<(f32, f32, f32, f32)> {
+        if self.curved || self.edges.len() < 3 || self.edges.len() > 4 {
+            return None;
+        }
+        let axis_aligned = self
+            .edges
+            .iter()
+            .all(|&((x0, y0), (x1, y1))| (x0 - x1).abs() <= AXIS_TOLERANCE || (y0 - y1).abs() <= AXIS_TOLERANCE);
+        if !axis_aligned {
+            return None;
+        }
+        let (mut left, mut bottom, mut right, mut top) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+        for &(x, y) in &self.points {
+            left = left.min(x);
+            bottom = bottom.min(y);
+            right = right.max(x);
+            top = top.max(y);
+        }
+        Some((left, bottom, right, top))
+    }
+}
+
+/// A horizontal or vertical rule.
+///
+/// `position` is the y coordinate of a horizontal rule or the x coordinate of a
+/// vertical one; `start..end` is its extent along the other axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rule {
+    position: f32,
+    start: f32,
+    end: f32,
+}
+
+impl Rule {
+    fn new(position: f32, a: f32, b: f32) -> Self {
+        Self {
+            position,
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Whether the rule runs along the whole interval `from..to`.
+    fn covers(&self, from: f32, to: f32) -> bool {
+        self.start <= from + JOIN_TOLERANCE && self.end >= to - JOIN_TOLERANCE
+    }
+
+    /// Whether a horizontal rule (`self`) crosses or touches a vertical rule.
+    fn meets(&self, vertical: &Rule) -> bool {
+        vertical.position >= self.start - JOIN_TOLERANCE
+            && vertical.position <= self.end + JOIN_TOLERANCE
+            && self.position >= vertical.start - JOIN_TOLERANCE
+            && self.position <= vertical.end + JOIN_TOLERANCE
+    }
+}
+
+#[derive(Debug, Default)]
+struct Rules {
+    horizontal: Vec<Rule>,
+    vertical: Vec<Rule>,
+}
+
+impl Rules {
+    fn add_segment(&mut self, (x0, y0): Point, (x1, y1): Point) {
+        if (y0 - y1).abs() <= AXIS_TOLERANCE && (x1 - x0).abs() >= MIN_RULE_LENGTH {
+            self.horizontal.push(Rule::new((y0 + y1) / 2.0, x0, x1));
+        } else if (x0 - x1).abs() <= AXIS_TOLERANCE && (y1 - y0).abs() >= MIN_RULE_LENGTH {
+            self.vertical.push(Rule::new((x0 + x1) / 2.0, y0, y1));
+        }
+    }
+
+    /// Add the rules drawn by one subpath.
+    ///
+    /// A filled thin rectangle is a rule along its long side; other filled
+    /// shapes are backgrounds and contribute nothing. Stroked straight edges
+    /// are rules in their own right.
+    fn add_subpath(&mut self, subpath: &Subpath, filled: bool, stroked: bool) {
+        if filled && let Some((left, bottom, right, top)) = subpath.as_rectangle() {
+            let (width, height) = (right - left, top - bottom);
+            if height <= MAX_RULE_THICKNESS && width >= height {
+                let y = (bottom + top) / 2.0;
+                self.add_segment((left, y), (right, y));
+                return;
+            }
+            if width <= MAX_RULE_THICKNESS {
+                let x = (left + right) / 2.0;
+                self.add_segment((x, bottom), (x, top));
+                return;
+            }
+        }
+        if stroked {
+            for &(from, to) in &subpath.edges {
+                self.add_segment(from, to);
+            }
+        }
+    }
+}
+
+/// A cell area in PDF coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CellRect {
+    left: f32,
+    bottom: f32,
+    right: f32,
+    top: f32,
+}
+
+impl CellRect {
+    fn width(&self) -> f32 {
+        self.right - self.left
+    }
+
+    fn height(&self) -> f32 {
+        self.top - self.bottom
+    }
+}
+
+/// A cell of a ruled grid in row/column units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GridSpan {
+    row: usize,
+    col: usize,
+    row_span: usize,
+    col_span: usize,
+}
+
+/// Geometry of one ruled table.
+#[derive(Debug, Clone, PartialEq)]
+struct RuledGrid {
+    /// Column boundaries, left to right.
+    xs: Vec<f32>,
+    /// Row boundaries, top to bottom.
+    ys: Vec<f32>,
+    /// Cells in row-major order of their top-left corner.
+    cells: Vec<GridSpan>,
+}
+
+impl RuledGrid {
+    fn rows(&self) -> usize {
+        self.ys.len() - 1
+    }
+
+    fn cols(&self) -> usize {
+        self.xs.len() - 1
+    }
+
+    fn cell_rect(&self, cell: &GridSpan) -> CellRect {
+        CellRect {
+            left: self.xs[cell.col],
+            bottom: self.ys[cell.row + cell.row_span],
+            right: self.xs[cell.col + cell.col_span],
+            top: self.ys[cell.row],
+        }
+    }
+
+    /// Build a [`Table`] reading each cell's text through `cell_text`.
+    ///
+    /// Spanned cells put their text in the top-left slot of `cells` and leave
+    /// the covered slots empty. Rows covered by the spans of the first row's
+    /// cells are marked as header rows. Grids with fewer than two non-empty
+    /// cells are not tables and yield `None`.
+    fn to_table(&self, page_number: usize, mut cell_text: impl FnMut(CellRect) -> String) -> Option<Table> {
+        let header_rows = self
+            .cells
+            .iter()
+            .filter(|cell| cell.row == 0)
+            .map(|cell| cell.row_span)
+            .max()
+            .unwrap_or(1);
+
+        let mut cells = vec![vec![String::new(); self.cols()]; self.rows()];
+        let mut grid_cells = Vec::with_capacity(self.cells.len());
+        for cell in &self.cells {
+            let rect = self.cell_rect(cell);
+            let content = cell_text(rect).split_whitespace().collect::<Vec<_>>().join(" ");
+            cells[cell.row][cell.col] = content.clone();
+            grid_cells.push(GridCell {
+                content,
+                row: cell.row as u32,
+                col: cell.col as u32,
+                row_span: cell.row_span as u32,
+                col_span: cell.col_span as u32,
+                is_header: cell.row < header_rows,
+                bbox: Some(BoundingBox {
+                    x0: f64::from(rect.left),
+                    y0: f64::from(rect.bottom),
+                    x1: f64::from(rect.right),
+                    y1: f64::from(rect.top),
+                }),
+            });
+        }
+
+        if grid_cells.iter().filter(|cell| !cell.content.is_empty()).count() < 2 {
+            return None;
+        }
+
+        Some(Table {
+            markdown: cells_to_markdown(&cells),
+            cells,
+            page_number,
+            grid: Some(TableGrid {
+                rows: self.rows() as u32,
+                cols: self.cols() as u32,
+                cells: grid_cells,
+            }),
+            page_end: None,
+        })
+    }
+}
+
+/// Find ruled grids among the collected rules, ordered top to bottom.
+fn detect_grids(rules: &Rules) -> Vec<RuledGrid> {
+    let horizontal = merge_rules(&rules.horizontal);
+    let vertical = merge_rules(&rules.vertical);
+
+    let mut components = UnionFind::new(horizontal.len() + vertical.len());
+    for (h, h_rule) in horizontal.iter().enumerate() {
+        for (v, v_rule) in vertical.iter().enumerate() {
+            if h_rule.meets(v_rule) {
+                components.union(h, horizontal.len() + v);
+            }
+        }
+    }
+
+    let mut groups: Vec<(Vec<Rule>, Vec<Rule>)> = Vec::new();
+    let mut group_of_root = std::collections::HashMap::new();
+    for index in 0..horizontal.len() + vertical.len() {
+        let root = components.find(index);
+        let group = *group_of_root.entry(root).or_insert_with(|| {
+            groups.push((Vec::new(), Vec::new()));
+            groups.len() - 1
+        });
+        if index < horizontal.len() {
+            groups[group].0.push(horizontal[index]);
+        } else {
+            groups[group].1.push(vertical[index - horizontal.len()]);
+        }
+    }
+
+    let mut grids: Vec<RuledGrid> = groups
+        .into_iter()
+        .filter(|(h, v)| h.len() >= 2 && v.len() >= 2)
+        .filter_map(|(h, v)| build_grid(&h, &v))
+        .collect();
+    grids.sort_by(|a, b| b.ys[0].total_cmp(&a.ys[0]).then(a.xs[0].total_cmp(&b.xs[0])));
+    grids
+}
+
+/// Snap rules at nearly the same position together and join overlapping ones.
+fn merge_rules(rules: &[Rule]) -> Vec<Rule> {
+    let mut sorted = rules.to_vec();
+    sorted.sort_by(|a, b| a.position.total_cmp(&b.position));
+
+    let mut merged = Vec::new();
+    let mut index = 0;
+    while index < sorted.len() {
+        let mut end = index + 1;
+        while end < sorted.len() && sorted[end].position - sorted[end - 1].position <= SNAP_TOLERANCE {
+            end += 1;
+        }
+        let cluster = &mut sorted[index..end];
+        let position = cluster.iter().map(|rule| rule.position).sum::<f32>() / cluster.len() as f32;
+        cluster.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        let mut current = Rule { position, ..cluster[0] };
+        for rule in &cluster[1..] {
+            if rule.start <= current.end + JOIN_TOLERANCE {
+                current.end = current.end.max(rule.end);
+            } else {
+                merged.push(current);
+                current = Rule { position, ..*rule };
+            }
+        }
+        merged.push(current);
+        index = end;
+    }
+    merged
+}
+
+/// Cluster positions closer than [`SNAP_TOLERANCE`], returning ascending cluster means.
+fn boundaries(rules: &[Rule]) -> Vec<f32> {
+    let mut positions: Vec<f32> = rules.iter().map(|rule| rule.position).collect();
+    positions.sort_by(f32::total_cmp);
+
+    let mut result = Vec::new();
+    let mut cluster: Vec<f32> = Vec::new();
+    for position in positions {
+        if cluster.last().is_some_and(|&last| position - last > SNAP_TOLERANCE) {
+            result.push(cluster.iter().sum::<f32>() / cluster.len() as f32);
+            cluster.clear();
+        }
+        cluster.push(position);
+    }
+    if !cluster.is_empty() {
+        result.push(cluster.iter().sum::<f32>() / cluster.len() as f32);
+    }
+    result
+}
+
+/// Build the cell grid of one connected rule network.
+fn build_grid(horizontal: &[Rule], vertical: &[Rule]) -> Option<RuledGrid> {
+    let xs = boundaries(vertical);
+    let mut ys = boundaries(horizontal);
+    ys.reverse();
+    if xs.len() < 2 || ys.len() < 2 {
+        return None;
+    }
+    let (rows, cols) = (ys.len() - 1, xs.len() - 1);
+
+    let has_rule = |rules: &[Rule], position: f32, from: f32, to: f32| {
+        rules
+            .iter()
+            .any(|rule| (rule.position - position).abs() <= SNAP_TOLERANCE && rule.covers(from, to))
+    };
+
+    // Neighbouring cells without a rule between them belong to the same cell.
+    let mut merged = UnionFind::new(rows * cols);
+    for row in 0..rows {
+        for col in 0..cols {
+            if col + 1 < cols && !has_rule(vertical, xs[col + 1], ys[row + 1], ys[row]) {
+                merged.union(row * cols + col, row * cols + col + 1);
+            }
+            if row + 1 < rows && !has_rule(horizontal, ys[row + 1], xs[col], xs[col + 1]) {
+                merged.union(row * cols + col, (row + 1) * cols + col);
+            }
+        }
+    }
+
+    let mut members: std::collections::BTreeMap<usize, Vec<usize>> = std::collections::BTreeMap::new();
+    for index in 0..rows * cols {
+        members.entry(merged.find(index)).or_default().push(index);
+    }
+
+    let mut cells = Vec::with_capacity(members.len());
+    for group in members.values() {
+        let (mut top, mut left, mut bottom, mut right) = (usize::MAX, usize::MAX, 0, 0);
+        for &index in group {
+            let (row, col) = (index / cols, index % cols);
+            top = top.min(row);
+            left = left.min(col);
+            bottom = bottom.max(row);
+            right = right.max(col);
+        }
+        let (row_span, col_span) = (bottom - top + 1, right - left + 1);
+        if row_span * col_span == group.len() {
+            cells.push(GridSpan {
+                row: top,
+                col: left,
+                row_span,
+                col_span,
+            });
+        } else {
+            // Irregular regions (broken or partial rules) fall back to single cells.
+            cells.extend(group.iter().map(|&index| GridSpan {
+                row: index / cols,
+                col: index % cols,
+                row_span: 1,
+                col_span: 1,
+            }));
+        }
+    }
+
+    compact(xs, ys, cells)
+}
+
+/// Drop boundaries no cell starts at, so every grid row and column holds a cell corner.
+fn compact(xs: Vec<f32>, ys: Vec<f32>, cells: Vec<GridSpan>) -> Option<RuledGrid> {
+    let keep = |count: usize, starts: &dyn Fn(usize) -> bool| -> Vec<Option<usize>> {
+        let mut next = 0;
+        (0..count)
+            .map(|boundary| {
+                if boundary == 0 || boundary == count - 1 || starts(boundary) {
+                    next += 1;
+                    Some(next - 1)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    };
+    let row_map = keep(ys.len(), &|row| cells.iter().any(|cell| cell.row == row));
+    let col_map = keep(xs.len(), &|col| cells.iter().any(|cell| cell.col == col));
+
+    let xs: Vec<f32> = xs
+        .iter()
+        .zip(&col_map)
+        .filter(|(_, m)| m.is_some())
+        .map(|(x, _)| *x)
+        .collect();
+    let ys: Vec<f32> = ys
+        .iter()
+        .zip(&row_map)
+        .filter(|(_, m)| m.is_some())
+        .map(|(y, _)| *y)
+        .collect();
+    if xs.len() < 2 || ys.len() < 2 {
+        return None;
+    }
+
+    let mut cells: Vec<GridSpan> = cells
+        .iter()
+        .filter_map(|cell| {
+            let row = row_map[cell.row]?;
+            let col = col_map[cell.col]?;
+            let row_end = row_map[cell.row + cell.row_span]?;
+            let col_end = col_map[cell.col + cell.col_span]?;
+            Some(GridSpan {
+                row,
+                col,
+                row_span: row_end - row,
+                col_span: col_end - col,
+            })
+        })
+        .collect();
+    cells.sort_by_key(|cell| (cell.row, cell.col));
+
+    // A single cell, or cells in only one row and one column, is a frame rather than a table.
+    let split_rows = cells.iter().any(|cell| cell.row > 0);
+    let split_cols = cells.iter().any(|cell| cell.col > 0);
+    (split_rows && split_cols).then_some(RuledGrid { xs, ys, cells })
+}
+
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = index;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a != b {
+            self.parent[a.max(b)] = a.min(b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rules for a fully ruled grid with the given boundaries.
+    fn full_grid(xs: &[f32], ys: &[f32]) -> Rules {
+        let mut rules = Rules::default();
+        for &y in ys {
+            rules.add_segment((xs[0], y), (xs[xs.len() - 1], y));
+        }
+        for &x in xs {
+            rules.add_segment((x, ys[0]), (x, ys[ys.len() - 1]));
+        }
+        rules
+    }
+
+    fn rectangle(left: f32, bottom: f32, right: f32, top: f32) -> Subpath {
+        let mut subpath = Subpath::starting_at((left, bottom));
+        subpath.line_to((right, bottom));
+        subpath.line_to((right, top));
+        subpath.line_to((left, top));
+        subpath.close();
+        subpath
+    }
+
+    fn span(row: usize, col: usize, row_span: usize, col_span: usize) -> GridSpan {
+        GridSpan {
+            row,
+            col,
+            row_span,
+            col_span,
+        }
+    }
+
+    #[test]
+    fn test_detect_grids_full_grid() {
+        let rules = full_grid(&[50.0, 150.0, 250.0, 350.0], &[700.0, 680.0, 660.0]);
+        let grids = detect_grids(&rules);
+
+        assert_eq!(grids.len(), 1);
+        let grid = &grids[0];
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.ys, [700.0, 680.0, 660.0]);
+        assert_eq!(grid.cells.len(), 6);
+        assert!(grid.cells.iter().all(|cell| cell.row_span == 1 && cell.col_span == 1));
+        assert_eq!(
+            grid.cell_rect(&grid.cells[4]),
+            CellRect {
+                left: 150.0,
+                bottom: 660.0,
+                right: 250.0,
+                top: 680.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_detect_grids_column_span() {
+        // Header cell spanning the two right-hand columns: the middle vertical rule
+        // starts below the top row.
+        let mut rules = Rules::default();
+        for y in [300.0, 280.0, 260.0] {
+            rules.add_segment((0.0, y), (300.0, y));
+        }
+        rules.add_segment((0.0, 300.0), (0.0, 260.0));
+        rules.add_segment((100.0, 300.0), (100.0, 260.0));
+        rules.add_segment((200.0, 280.0), (200.0, 260.0));
+        rules.add_segment((300.0, 300.0), (300.0, 260.0));
+
+        let grids = detect_grids(&rules);
+        assert_eq!(grids.len(), 1);
+        assert_eq!(
+            grids[0].cells,
+            [
+                span(0, 0, 1, 1),
+                span(0, 1, 1, 2),
+                span(1, 0, 1, 1),
+                span(1, 1, 1, 1),
+                span(1, 2, 1, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_grids_row_span() {
+        // First column cell spans both body rows.
+        let mut rules = Rules::default();
+        rules.add_segment((0.0, 300.0), (200.0, 300.0));
+        rules.add_segment((0.0, 280.0), (200.0, 280.0));
+        rules.add_segment((100.0, 260.0), (200.0, 260.0));
+        rules.add_segment((0.0, 240.0), (200.0, 240.0));
+        for x in [0.0, 100.0, 200.0] {
+            rules.add_segment((x, 300.0), (x, 240.0));
+        }
+
+        let grids = detect_grids(&rules);
+        assert_eq!(grids.len(), 1);
+        assert!(grids[0].cells.contains(&span(1, 0, 2, 1)));
+        assert_eq!(grids[0].cells.len(), 5);
+    }
+
+    #[test]
+    fn test_detect_grids_separate_tables() {
+        let mut rules = full_grid(&[0.0, 100.0, 200.0], &[200.0, 180.0, 160.0]);
+        let second = full_grid(&[0.0, 50.0, 100.0, 150.0], &[600.0, 580.0, 560.0, 540.0]);
+        rules.horizontal.extend(second.horizontal);
+        rules.vertical.extend(second.vertical);
+
+        let grids = detect_grids(&rules);
+        assert_eq!(grids.len(), 2);
+        assert_eq!((grids[0].rows(), grids[0].cols()), (3, 3));
+        assert_eq!((grids[1].rows(), grids[1].cols()), (2, 2));
+    }
+
+    #[test]
+    fn test_detect_grids_ignores_frames_and_lone_rules() {
+        let mut rules = full_grid(&[10.0, 500.0], &[800.0, 20.0]);
+        rules.add_segment((10.0, 400.0), (300.0, 400.0));
+        assert!(detect_grids(&rules).is_empty());
+    }
+
+    #[test]
+    fn test_merge_rules_snaps_and_joins_segments() {
+        // Per-cell border segments drawn slightly offset become one rule.
+        let merged = merge_rules(&[
+            Rule::new(100.0, 0.0, 50.0),
+            Rule::new(100.6, 50.0, 100.0),
+            Rule::new(99.8, 101.0, 150.0),
+            Rule::new(200.0, 0.0, 150.0),
+        ]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!((merged[0].start, merged[0].end), (0.0, 150.0));
+        assert!((merged[0].position - 100.133).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_add_subpath_thin_filled_rectangle_is_rule() {
+        let mut rules = Rules::default();
+        rules.add_subpath(&rectangle(10.0, 99.5, 210.0, 100.5), true, false);
+        rules.add_subpath(&rectangle(9.5, 20.0, 10.5, 100.0), true, false);
+        assert_eq!(rules.horizontal, [Rule::new(100.0, 10.0, 210.0)]);
+        assert_eq!(rules.vertical, [Rule::new(10.0, 20.0, 100.0)]);
+    }
+
+    #[test]
+    fn test_add_subpath_cell_shading_is_not_rule() {
+        let mut rules = Rules::default();
+        rules.add_subpath(&rectangle(10.0, 10.0, 110.0, 40.0), true, false);
+        assert!(rules.horizontal.is_empty() && rules.vertical.is_empty());
+
+        rules.add_subpath(&rectangle(10.0, 10.0, 110.0, 40.0), true, true);
+        assert_eq!((rules.horizontal.len(), rules.vertical.len()), (2, 2));
+    }
+
+    #[test]
+    fn test_add_subpath_ignores_diagonals_and_curves() {
+        let mut rules = Rules::default();
+        let mut diagonal = Subpath::starting_at((0.0, 0.0));
+        diagonal.line_to((100.0, 100.0));
+        rules.add_subpath(&diagonal, false, true);
+
+        let mut curve = Subpath::starting_at((0.0, 0.0));
+        curve.curve_to((100.0, 0.0));
+        rules.add_subpath(&curve, false, true);
+
+        assert!(rules.horizontal.is_empty() && rules.vertical.is_empty());
+    }
+
+    #[test]
+    fn test_to_table_fills_cells_grid_and_bboxes() {
+        let mut rules = Rules::default();
+        for y in [300.0, 280.0, 260.0] {
+            rules.add_segment((0.0, y), (200.0, y));
+        }
+        rules.add_segment((0.0, 300.0), (0.0, 260.0));
+        rules.add_segment((100.0, 280.0), (100.0, 260.0));
+        rules.add_segment((200.0, 300.0), (200.0, 260.0));
+        let grid = &detect_grids(&rules)[0];
+
+        let table = grid
+            .to_table(3, |rect| match (rect.left as u32, rect.top as u32) {
+                (0, 300) => "Quarterly\r\nresults".to_string(),
+                (0, 280) => "Q1".to_string(),
+                _ => "1 200".to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(table.page_number, 3);
+        assert_eq!(
+            table.cells,
+            [
+                vec!["Quarterly results".to_string(), String::new()],
+                vec!["Q1".to_string(), "1 200".to_string()]
+            ]
+        );
+        assert!(table.markdown.starts_with("| Quarterly results |  |"));
+
+        let grid = table.grid.unwrap();
+        assert_eq!((grid.rows, grid.cols), (2, 2));
+        let header = &grid.cells[0];
+        assert_eq!((header.col_span, header.is_header), (2, true));
+        assert_eq!(
+            header.bbox,
+            Some(BoundingBox {
+                x0: 0.0,
+                y0: 280.0,
+                x1: 200.0,
+                y1: 300.0
+            })
+        );
+        assert!(!grid.cells[1].is_header);
+    }
+
+    #[test]
+    fn test_to_table_rejects_grid_without_text() {
+        let grid = &detect_grids(&full_grid(&[0.0, 100.0, 200.0], &[200.0, 100.0, 0.0]))[0];
+        assert!(grid.to_table(1, |_| String::new()).is_none());
+    }
+}
@@ -0,0 +1,630 @@
+//! Searchable PDF writer.
+//!
+//! Builds a new PDF/A-2b document in which every page shows an original page
+//! image and carries an invisible text layer (text render mode 3) positioned from
+//! OCR element geometry. Viewers display the scan unchanged while text selection,
+//! search and copy operate on the recognized text.
+//!
+//! The text layer uses a glyph-less Type0 font with an identity CID mapping, so
+//! each UTF-16 code unit of the recognized text is written as one CID and the
+//! `ToUnicode` CMap maps it straight back. PDF/A permits an unembedded font here
+//! because text in render mode 3 is never painted.
+
+use super::error::{PdfError, Result};
+use crate::types::{OcrBoundingGeometry, OcrElement, OcrElementLevel};
+use flate2::Compression;
+use flate2::write::ZlibEncoder;
+use image::DynamicImage;
+use lopdf::content::{Content, Operation};
+use lopdf::{Document, Object, ObjectId, Stream, StringFormat, dictionary};
+use std::io::Write;
+
+/// Advance width of every glyph in the text layer font, in glyph space units.
+const GLYPH_WIDTH: f64 = 500.0;
+
+const FONT_NAME: &str = "GlyphLessFont";
+
+const OUTPUT_CONDITION: &str = "sRGB IEC61966-2.1";
+
+/// One page of a searchable PDF.
+#[derive(Debug, Clone)]
+pub struct SearchablePdfPage {
+    /// Page image drawn over the whole page.
+    pub image: DynamicImage,
+    /// Page width in PDF points (1/72 inch).
+    pub width_points: f64,
+    /// Page height in PDF points (1/72 inch).
+    pub height_points: f64,
+    /// OCR elements in image pixel coordinates.
+    ///
+    /// Word-level elements are used when present; otherwise line-level elements
+    /// make up the text layer. Elements of other levels are ignored.
+    pub elements: Vec<OcrElement>,
+}
+
+/// Write a searchable PDF/A-2b document from page images and OCR elements.
+///
+/// # Errors
+///
+/// Returns [`PdfError::WriteFailed`] when `pages` is empty, a page has a
+/// non-positive size, or the document cannot be serialized.
+pub fn write_searchable_pdf(pages: &[SearchablePdfPage]) -> Result<Vec<u8>> {
+    if pages.is_empty() {
+        return Err(PdfError::WriteFailed(
+            "a searchable PDF needs at least one page".to_string(),
+        ));
+    }
+
+    let mut doc = Document::with_version("1.7");
+    let pages_id = doc.new_object_id();
+    let font_id = add_text_layer_font(&mut doc)?;
+
+    let mut kids = Vec::with_capacity(pages.len());
+    for page in pages {
+        if !(page.width_points > 0.0 && page.height_points > 0.0) {
+            return Err(PdfError::WriteFailed(format!(
+                "invalid page size {}x{} points",
+                page.width_points, page.height_points
+            )));
+        }
+
+        let image_id = doc.add_object(image_xobject(&page.image)?);
+        let content = Content {
+            operations: page_operations(page),
+        };
+        let content_bytes = content
+            .encode()
+            .map_err(|e| PdfError::WriteFailed(format!("Failed to encode page content: {}", e)))?;
+        let content_id = doc.add_object(Stream::new(
+            dictionary! { "Filter" => "FlateDecode" },
+            deflate(&content_bytes)?,
+        ));
+
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), page.width_points.into(), page.height_points.into()],
+            "Resources" => dictionary! {
+                "Font" => dictionary! { "F1" => font_id },
+                "XObject" => dictionary! { "Im0" => image_id },
+            },
+            "Contents" => content_id,
+        });
+        kids.push(page_id.into());
+    }
+
+    let page_count = kids.len() as i64;
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => page_count,
+        }),
+    );
+
+    let producer = format!("kreuzberg {}", env!("CARGO_PKG_VERSION"));
+    let metadata_id = doc.add_object(
+        Stream::new(
+            dictionary! { "Type" => "Metadata", "Subtype" => "XML" },
+            xmp_metadata(&producer).into_bytes(),
+        )
+        .with_compression(false),
+    );
+    let icc_id = doc.add_object(Stream::new(
+        dictionary! { "N" => 3, "Filter" => "FlateDecode" },
+        deflate(&srgb_icc_profile())?,
+    ));
+    let catalog_id = doc.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => pages_id,
+        "Metadata" => metadata_id,
+        "OutputIntents" => vec![Object::Dictionary(dictionary! {
+            "Type" => "OutputIntent",
+            "S" => "GTS_PDFA1",
+            "OutputConditionIdentifier" => Object::string_literal(OUTPUT_CONDITION),
+            "Info" => Object::string_literal(OUTPUT_CONDITION),
+            "DestOutputProfile" => icc_id,
+        })],
+    });
+    let info_id = doc.add_object(dictionary! {
+        "Producer" => Object::string_literal(producer),
+    });
+
+    let file_id = uuid::Uuid::new_v4().as_bytes().to_vec();
+    doc.trailer.set("Root", catalog_id);
+    doc.trailer.set("Info", info_id);
+    doc.trailer.set(
+        "ID",
+        vec![
+            Object::String(file_id.clone(), StringFormat::Hexadecimal),
+            Object::String(file_id, StringFormat::Hexadecimal),
+        ],
+    );
+
+    let mut output = Vec::new();
+    doc.save_to(&mut output)
+        .map_err(|e| PdfError::WriteFailed(format!("Failed to serialize document: {}", e)))?;
+    Ok(output)
+}
+
+/// Position of one text run, in PDF user space.
+#[derive(Debug, Clone, PartialEq)]
+struct TextPlacement {
+    /// Text matrix `[a b c d e f]`: baseline direction and bottom-left origin.
+    matrix: [f64; 6],
+    font_size: f64,
+    /// Horizontal scaling in percent (`Tz`) that stretches the run to the box width.
+    horizontal_scale: f64,
+}
+
+/// Compute where `text` goes so that it covers the element's box.
+///
+/// `scale_x`/`scale_y` convert image pixels to points. The element's rotation,
+/// rounded to a multiple of 90°, picks which box corner is the text's top-left,
+/// so upside-down or sideways text still selects in reading order.
+fn placement(element: &OcrElement, text: &str, scale_x: f64, scale_y: f64, page_height: f64) -> Option<TextPlacement> {
+    let mut corners: [(f64, f64); 4] = match element.geometry {
+        OcrBoundingGeometry::Rectangle {
+            left,
+            top,
+            width,
+            height,
+        } => {
+            let (l, t) = (left as f64, top as f64);
+            let (r, b) = (l + width as f64, t + height as f64);
+            [(l, t), (r, t), (r, b), (l, b)]
+        }
+        OcrBoundingGeometry::Quadrilateral { points } => points.map(|(x, y)| (x as f64, y as f64)),
+    };
+    if let Some(rotation) = &element.rotation {
+        let quarter_turns = ((rotation.angle_degrees / 90.0).round() as i64).rem_euclid(4) as usize;
+        corners.rotate_left(quarter_turns);
+    }
+
+    let [top_left, _, bottom_right, bottom_left] = corners.map(|(x, y)| (x * scale_x, page_height - y * scale_y));
+    let (dx, dy) = (bottom_right.0 - bottom_left.0, bottom_right.1 - bottom_left.1);
+    let width = dx.hypot(dy);
+    let height = (top_left.0 - bottom_left.0).hypot(top_left.1 - bottom_left.1);
+    let chars = text.encode_utf16().count();
+    if width <= f64::EPSILON || height <= f64::EPSILON || chars == 0 {
+        return None;
+    }
+
+    let (cos, sin) = (dx / width, dy / width);
+    let natural_width = height * chars as f64 * GLYPH_WIDTH / 1000.0;
+    Some(TextPlacement {
+        matrix: [cos, sin, -sin, cos, bottom_left.0, bottom_left.1],
+        font_size: height,
+        horizontal_scale: 100.0 * width / natural_width,
+    })
+}
+
+/// Content stream operations for a page: the image, then the invisible text.
+fn page_operations(page: &SearchablePdfPage) -> Vec<Operation> {
+    let (width, height) = (page.width_points, page.height_points);
+    let mut operations = vec![
+        Operation::new("q", vec![]),
+        Operation::new(
+            "cm",
+            vec![width.into(), 0.into(), 0.into(), height.into(), 0.into(), 0.into()],
+        ),
+        Operation::new("Do", vec!["Im0".into()]),
+        Operation::new("Q", vec![]),
+    ];
+
+    let level = if page.elements.iter().any(|e| e.level == OcrElementLevel::Word) {
+        OcrElementLevel::Word
+    } else {
+        OcrElementLevel::Line
+    };
+    let scale_x = width / page.image.width().max(1) as f64;
+    let scale_y = height / page.image.height().max(1) as f64;
+
+    let mut text_operations = Vec::new();
+    for element in page.elements.iter().filter(|e| e.level == level) {
+        let text = element.text.trim();
+        let Some(placement) = placement(element, text, scale_x, scale_y, height) else {
+            continue;
+        };
+        // A trailing space keeps word boundaries when the layer is copied or extracted.
+        let shown = if level == OcrElementLevel::Word {
+            format!("{} ", text)
+        } else {
+            text.to_string()
+        };
+        let encoded: Vec<u8> = shown.encode_utf16().flat_map(u16::to_be_bytes).collect();
+
+        text_operations.push(Operation::new("Tf", vec!["F1".into(), placement.font_size.into()]));
+        text_operations.push(Operation::new("Tz", vec![placement.horizontal_scale.into()]));
+        text_operations.push(Operation::new(
+            "Tm",
+            placement.matrix.iter().map(|&value| value.into()).collect(),
+        ));
+        text_operations.push(Operation::new(
+            "Tj",
+            vec![Object::String(encoded, StringFormat::Hexadecimal)],
+        ));
+    }
+
+    if !text_operations.is_empty() {
+        operations.push(Operation::new("BT", vec![]));
+        operations.push(Operation::new("Tr", vec![3.into()]));
+        operations.extend(text_operations);
+        operations.push(Operation::new("ET", vec![]));
+    }
+    operations
+}
+
+fn image_xobject(image: &DynamicImage) -> Result<Stream> {
+    let (color_space, samples) = if image.color().has_color() {
+        ("DeviceRGB", image.to_rgb8().into_raw())
+    } else {
+        ("DeviceGray", image.to_luma8().into_raw())
+    };
+    Ok(Stream::new(
+        dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Image",
+            "Width" => image.width(),
+            "Height" => image.height(),
+            "ColorSpace" => color_space,
+            "BitsPerComponent" => 8,
+            "Filter" => "FlateDecode",
+        },
+        deflate(&samples)?,
+    ))
+}
+
+/// Add the Type0 text layer font and return its object id.
+fn add_text_layer_font(doc: &mut Document) -> Result<ObjectId> {
+    let descriptor_id = doc.add_object(dictionary! {
+        "Type" => "FontDescriptor",
+        "FontName" => FONT_NAME,
+        "Flags" => 5,
+        "FontBBox" => vec![0.into(), 0.into(), GLYPH_WIDTH.into(), 1000.into()],
+        "ItalicAngle" => 0,
+        "Ascent" => 1000,
+        "Descent" => 0,
+        "CapHeight" => 1000,
+        "StemV" => 80,
+    });
+    let cid_font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "CIDFontType2",
+        "BaseFont" => FONT_NAME,
+        "CIDSystemInfo" => dictionary! {
+            "Registry" => Object::string_literal("Adobe"),
+            "Ordering" => Object::string_literal("Identity"),
+            "Supplement" => 0,
+        },
+        "FontDescriptor" => descriptor_id,
+        "DW" => GLYPH_WIDTH,
+        "CIDToGIDMap" => "Identity",
+    });
+    let to_unicode_id = doc.add_object(Stream::new(
+        dictionary! { "Filter" => "FlateDecode" },
+        deflate(identity_to_unicode_cmap().as_bytes())?,
+    ));
+    Ok(doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type0",
+        "BaseFont" => FONT_NAME,
+        "Encoding" => "Identity-H",
+        "DescendantFonts" => vec![cid_font_id.into()],
+        "ToUnicode" => to_unicode_id,
+    }))
+}
+
+/// CMap mapping every two-byte code to the identical UTF-16 code unit.
+fn identity_to_unicode_cmap() -> String {
+    let mut cmap = String::from(
+        "/CIDInit /ProcSet findresource begin\n\
+         12 dict begin\n\
+         begincmap\n\
+         /CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n\
+         /CMapName /Adobe-Identity-UCS def\n\
+         /CMapType 2 def\n\
+         1 begincodespacerange\n\
+         <0000> <FFFF>\n\
+         endcodespacerange\n",
+    );
+    // A bfrange block holds at most 100 entries.
+    let ranges: Vec<u32> = (0..=0xFF).collect();
+    for block in ranges.chunks(100) {
+        cmap.push_str(&format!("{} beginbfrange\n", block.len()));
+        for high in block {
+            cmap.push_str(&format!("<{high:02X}00> <{high:02X}FF> <{high:02X}00>\n"));
+        }
+        cmap.push_str("endbfrange\n");
+    }
+    cmap.push_str("endcmap\nCMapName currentdict /CMap defineresource pop\nend\nend\n");
+    cmap
+}
+
+fn xmp_metadata(producer: &str) -> String {
+    format!(
+        "<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n\
+         <x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n\
+         <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n\
+         <rdf:Description rdf:about=\"\" xmlns:pdf=\"http://ns.adobe.com/pdf/1.3/\" \
+         xmlns:pdfaid=\"http://www.aiim.org/pdfa/ns/id/\">\n\
+         <pdf:Producer>{producer}</pdf:Producer>\n\
+         <pdfaid:part>2</pdfaid:part>\n\
+         <pdfaid:conformance>B</pdfaid:conformance>\n\
+         </rdf:Description>\n\
+         </rdf:RDF>\n\
+         </x:xmpmeta>\n\
+         <?xpacket end=\"w\"?>"
+    )
+}
+
+/// Minimal ICC v2 display profile for sRGB, used as the PDF/A output intent.
+fn srgb_icc_profile() -> Vec<u8> {
+    fn s15_fixed16(value: f64) -> [u8; 4] {
+        ((value * 65536.0).round() as i32).to_be_bytes()
+    }
+    fn xyz(x: f64, y: f64, z: f64) -> Vec<u8> {
+        let mut tag = b"XYZ \0\0\0\0".to_vec();
+        for value in [x, y, z] {
+            tag.extend_from_slice(&s15_fixed16(value));
+        }
+        tag
+    }
+
+    let description = b"sRGB IEC61966-2.1";
+    let mut desc = b"desc\0\0\0\0".to_vec();
+    desc.extend_from_slice(&(description.len() as u32 + 1).to_be_bytes());
+    desc.extend_from_slice(description);
+    desc.push(0);
+    // Empty Unicode and ScriptCode descriptions.
+    desc.extend_from_slice(&[0; 8]);
+    desc.extend_from_slice(&[0; 3]);
+    desc.extend_from_slice(&[0; 67]);
+
+    let mut cprt = b"text\0\0\0\0".to_vec();
+    cprt.extend_from_slice(b"No copyright, use freely\0");
+
+    const CURVE_POINTS: u32 = 1024;
+    let mut curve = b"curv\0\0\0\0".to_vec();
+    curve.extend_from_slice(&CURVE_POINTS.to_be_bytes());
+    for i in 0..CURVE_POINTS {
+        let encoded = i as f64 / (CURVE_POINTS - 1) as f64;
+        let linear = if encoded <= 0.04045 {
+            encoded / 12.92
+        } else {
+            ((encoded + 0.055) / 1.055).powf(2.4)
+        };
+        curve.extend_from_slice(&((linear * 65535.0).round() as u16).to_be_bytes());
+    }
+
+    let tag_data: [(&[u8; 4], Vec<u8>); 7] = [
+        (b"desc", desc),
+        (b"cprt", cprt),
+        (b"wtpt", xyz(0.9642, 1.0, 0.8249)),
+        (b"rXYZ", xyz(0.4361, 0.2225, 0.0139)),
+        (b"gXYZ", xyz(0.3851, 0.7169, 0.0971)),
+        (b"bXYZ", xyz(0.1431, 0.0606, 0.7141)),
+        (b"rTRC", curve),
+    ];
+    // gTRC and bTRC share the red curve's data.
+    let tag_count = tag_data.len() + 2;
+
+    let mut table = Vec::new();
+    let mut data = Vec::new();
+    let data_start = 128 + 4 + 12 * tag_count;
+    let mut curve_entry = (0u32, 0u32);
+    for (signature, bytes) in &tag_data {
+        let offset = (data_start + data.len()) as u32;
+        let size = bytes.len() as u32;
+        table.extend_from_slice(*signature);
+        table.extend_from_slice(&offset.to_be_bytes());
+        table.extend_from_slice(&size.to_be_bytes());
+        if *signature == b"rTRC" {
+            curve_entry = (offset, size);
+        }
+        data.extend_from_slice(bytes);
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+    }
+    for signature in [b"gTRC", b"bTRC"] {
+        table.extend_from_slice(signature);
+        table.extend_from_slice(&curve_entry.0.to_be_bytes());
+        table.extend_from_slice(&curve_entry.1.to_be_bytes());
+    }
+
+    let total_size = (data_start + data.len()) as u32;
+    let mut profile = Vec::with_capacity(total_size as usize);
+    profile.extend_from_slice(&total_size.to_be_bytes());
+    profile.extend_from_slice(&[0; 4]); // preferred CMM
+    profile.extend_from_slice(&[0x02, 0x10, 0x00, 0x00]); // version 2.1
+    profile.extend_from_slice(b"mntr");
+    profile.extend_from_slice(b"RGB ");
+    profile.extend_from_slice(b"XYZ ");
+    for field in [2000u16, 1, 1, 0, 0, 0] {
+        profile.extend_from_slice(&field.to_be_bytes());
+    }
+    profile.extend_from_slice(b"acsp");
+    profile.extend_from_slice(&[0; 24]); // platform, flags, manufacturer, model, attributes
+    profile.extend_from_slice(&0u32.to_be_bytes()); // perceptual rendering intent
+    for value in [0.9642, 1.0, 0.8249] {
+        profile.extend_from_slice(&s15_fixed16(value));
+    }
+    profile.extend_from_slice(&[0; 48]); // creator and reserved bytes
+    profile.extend_from_slice(&(tag_count as u32).to_be_bytes());
+    profile.extend_from_slice(&table);
+    profile.extend_from_slice(&data);
+    profile
+}
+
+fn deflate(bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(bytes)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| PdfError::WriteFailed(format!("Failed to compress stream: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrConfidence, OcrRotation};
+    use image::{GrayImage, RgbImage};
+
+    fn word(text: &str, left: u32, top: u32, width: u32, height: u32) -> OcrElement {
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Rectangle {
+                left,
+                top,
+                width,
+                height,
+            },
+            OcrConfidence::from_tesseract(95.0),
+        )
+        .with_level(OcrElementLevel::Word)
+    }
+
+    fn page(elements: Vec<OcrElement>) -> SearchablePdfPage {
+        SearchablePdfPage {
+            image: DynamicImage::ImageRgb8(RgbImage::from_pixel(200, 100, image::Rgb([255, 255, 255]))),
+            width_points: 144.0,
+            height_points: 72.0,
+            elements,
+        }
+    }
+
+    fn content_of(doc: &Document, page_number: u32) -> Content {
+        let page_id = doc.get_pages()[&page_number];
+        Content::decode(&doc.get_page_content(page_id).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_write_searchable_pdf_pages_and_media_box() {
+        let pdf = write_searchable_pdf(&[page(vec![]), page(vec![])]).unwrap();
+        let doc = Document::load_mem(&pdf).unwrap();
+
+        let pages = doc.get_pages();
+        assert_eq!(pages.len(), 2);
+        let page = doc.get_dictionary(pages[&1]).unwrap();
+        let media_box: Vec<f32> = page
+            .get(b"MediaBox")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| value.as_float().unwrap())
+            .collect();
+        assert_eq!(media_box, vec![0.0, 0.0, 144.0, 72.0]);
+    }
+
+    #[test]
+    fn test_write_searchable_pdf_declares_pdfa() {
+        let pdf = write_searchable_pdf(&[page(vec![])]).unwrap();
+        let doc = Document::load_mem(&pdf).unwrap();
+        let catalog = doc.catalog().unwrap();
+
+        let metadata_id = catalog.get(b"Metadata").unwrap().as_reference().unwrap();
+        let metadata = doc.get_object(metadata_id).unwrap().as_stream().unwrap();
+        let xmp = String::from_utf8_lossy(&metadata.content);
+        assert!(xmp.contains("<pdfaid:part>2</pdfaid:part>"));
+        assert!(xmp.contains("<pdfaid:conformance>B</pdfaid:conformance>"));
+
+        let intents = catalog.get(b"OutputIntents").unwrap().as_array().unwrap();
+        let intent = intents[0].as_dict().unwrap();
+        assert_eq!(intent.get(b"S").unwrap().as_name().unwrap(), b"GTS_PDFA1");
+        assert!(doc.trailer.get(b"ID").is_ok());
+    }
+
+    #[test]
+    fn test_write_searchable_pdf_text_layer_is_invisible() {
+        let pdf = write_searchable_pdf(&[page(vec![word("Hi", 10, 10, 40, 20)])]).unwrap();
+        let doc = Document::load_mem(&pdf).unwrap();
+        let content = content_of(&doc, 1);
+
+        let render_mode = content.operations.iter().find(|op| op.operator == "Tr").unwrap();
+        assert_eq!(render_mode.operands[0].as_i64().unwrap(), 3);
+
+        let shown = content.operations.iter().find(|op| op.operator == "Tj").unwrap();
+        assert_eq!(
+            shown.operands[0].as_str().unwrap(),
+            &[0x00, b'H', 0x00, b'i', 0x00, b' '][..]
+        );
+        assert_eq!(doc.extract_text(&[1]).unwrap().trim(), "Hi");
+    }
+
+    #[test]
+    fn test_write_searchable_pdf_rejects_empty_input() {
+        assert!(matches!(write_searchable_pdf(&[]), Err(PdfError::WriteFailed(_))));
+    }
+
+    #[test]
+    fn test_write_searchable_pdf_grayscale_image() {
+        let gray = SearchablePdfPage {
+            image: DynamicImage::ImageLuma8(GrayImage::new(10, 10)),
+            ..page(vec![])
+        };
+        let pdf = write_searchable_pdf(&[gray]).unwrap();
+        let doc = Document::load_mem(&pdf).unwrap();
+        let page_id = doc.get_pages()[&1];
+        let (resources, _) = doc.get_page_resources(page_id).unwrap();
+        let xobjects = resources.unwrap().get(b"XObject").unwrap().as_dict().unwrap();
+        let image_id = xobjects.get(b"Im0").unwrap().as_reference().unwrap();
+        let image = doc.get_object(image_id).unwrap().as_stream().unwrap();
+        assert_eq!(image.dict.get(b"ColorSpace").unwrap().as_name().unwrap(), b"DeviceGray");
+    }
+
+    #[test]
+    fn test_placement_maps_pixels_to_points() {
+        let element = word("abcd", 10, 10, 40, 20);
+        let placement = placement(&element, "abcd", 0.5, 0.5, 72.0).unwrap();
+
+        assert_eq!(placement.font_size, 10.0);
+        assert_eq!(placement.matrix, [1.0, 0.0, -0.0, 1.0, 5.0, 57.0]);
+        // Four glyphs at half an em each span 20pt unscaled, exactly the box width.
+        assert_eq!(placement.horizontal_scale, 100.0);
+    }
+
+    #[test]
+    fn test_placement_follows_rotation() {
+        let element = word("ab", 0, 0, 20, 10).with_rotation(OcrRotation {
+            angle_degrees: 180.0,
+            confidence: None,
+        });
+        let placement = placement(&element, "ab", 1.0, 1.0, 100.0).unwrap();
+
+        assert_eq!(placement.matrix[0], -1.0);
+        assert_eq!(placement.matrix[3], -1.0);
+        // Upside-down text starts at the box's visual top-right corner.
+        assert_eq!((placement.matrix[4], placement.matrix[5]), (20.0, 100.0));
+    }
+
+    #[test]
+    fn test_placement_skips_degenerate_boxes() {
+        assert!(placement(&word("x", 0, 0, 0, 10), "x", 1.0, 1.0, 100.0).is_none());
+        assert!(placement(&word("", 0, 0, 10, 10), "", 1.0, 1.0, 100.0).is_none());
+    }
+
+    #[test]
+    fn test_page_operations_prefers_words_over_lines() {
+        let line = word("Hello world", 0, 0, 100, 20).with_level(OcrElementLevel::Line);
+        let words = vec![line, word("Hello", 0, 0, 45, 20), word("world", 55, 0, 45, 20)];
+        let operations = page_operations(&page(words));
+        assert_eq!(operations.iter().filter(|op| op.operator == "Tj").count(), 2);
+
+        let line_only = vec![word("Hello world", 0, 0, 100, 20).with_level(OcrElementLevel::Line)];
+        let operations = page_operations(&page(line_only));
+        assert_eq!(operations.iter().filter(|op| op.operator == "Tj").count(), 1);
+    }
+
+    #[test]
+    fn test_srgb_icc_profile_header() {
+        let profile = srgb_icc_profile();
+        let declared = u32::from_be_bytes(profile[0..4].try_into().unwrap());
+        assert_eq!(declared as usize, profile.len());
+        assert_eq!(&profile[12..20], b"mntrRGB ");
+        assert_eq!(&profile[36..40], b"acsp");
+        assert_eq!(profile.len() % 4, 0);
+    }
+}
@@ -4,6 +4,8 @@
 //! allowing us to reuse the existing table reconstruction logic.
 //!
 //! Note: Table extraction requires the "ocr" feature and is not available in WASM builds.
+//! Tables with drawn borders are detected by [`super::ruled_table`] instead, which
+//! does not need the "ocr" feature.
 
 use super::error::{PdfError, Result};
 #[cfg(feature = "ocr")]
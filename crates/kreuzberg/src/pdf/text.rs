@@ -6,7 +6,7 @@ use super::bindings::{PdfiumHandle, bind_pdfium};
 use super::error::{PdfError, Result};
 use crate::core::config::PageConfig;
 use crate::pdf::metadata::PdfExtractionMetadata;
-use crate::types::{PageBoundary, PageContent};
+use crate::types::{PageBoundary, PageContent, PageTextSource};
 use pdfium_render::prelude::*;
 use std::borrow::Cow;
 
@@ -142,7 +142,9 @@ pub fn extract_text_and_metadata_from_pdf_document(
     let page_config = extraction_config.and_then(|c| c.pages.as_ref());
     let (text, boundaries, page_contents) = extract_text_from_pdf_document(document, page_config, extraction_config)?;
 
-    let metadata = crate::pdf::metadata::extract_metadata_from_document_impl(document, boundaries.as_deref(), &text)?;
+    // The fast path tracks boundaries for OCR evaluation only; page structure stays opt-in
+    let structure_boundaries = page_config.and(boundaries.as_deref());
+    let metadata = crate::pdf::metadata::extract_metadata_from_document_impl(document, structure_boundaries, &text)?;
 
     Ok((text, boundaries, page_contents, metadata))
 }
@@ -159,7 +161,7 @@ pub fn extract_text_and_metadata_from_pdf_document(
 ///
 /// A tuple containing:
 /// - The extracted text content (String)
-/// - Optional page boundaries when page tracking is enabled (Vec<PageBoundary>)
+/// - Page boundaries (Vec<PageBoundary>), tracked on both paths
 /// - Optional per-page content when extract_pages is enabled (Vec<PageContent>)
 ///
 /// # Implementation Details
@@ -169,7 +171,7 @@ pub fn extract_text_and_metadata_from_pdf_document(
 /// in memory. This approach saves 40-50MB for large documents while improving
 /// performance by 15-25% through reduced upfront work.
 ///
-/// When page_config is None, uses fast path with minimal overhead.
+/// When page_config is None, uses fast path with minimal overhead (boundaries only).
 /// When page_config is Some, tracks byte offsets using .len() for O(1) performance (UTF-8 valid boundaries).
 pub fn extract_text_from_pdf_document(
     document: &PdfDocument<'_>,
@@ -277,6 +279,7 @@ fn find_rotate_offset(bytes: &[u8], start: usize) -> Option<usize> {
 fn extract_text_lazy_fast_path(document: &PdfDocument<'_>) -> Result<PdfTextExtractionResult> {
     let page_count = document.pages().len() as usize;
     let mut content = String::new();
+    let mut boundaries = Vec::with_capacity(page_count);
     let mut total_sample_size = 0usize;
     let mut sample_count = 0;
 
@@ -292,7 +295,13 @@ fn extract_text_lazy_fast_path(document: &PdfDocument<'_>) -> Result<PdfTextExtr
             content.push_str("\n\n");
         }
 
+        let byte_start = content.len();
         content.push_str(&page_text);
+        boundaries.push(PageBoundary {
+            byte_start,
+            byte_end: content.len(),
+            page_number: page_idx + 1,
+        });
 
         if page_idx < 5 {
             total_sample_size += page_size;
@@ -306,7 +315,7 @@ fn extract_text_lazy_fast_path(document: &PdfDocument<'_>) -> Result<PdfTextExtr
         }
     }
 
-    Ok((content, None, None))
+    Ok((content, Some(boundaries), None))
 }
 
 /// Lazy extraction with page boundary and content tracking.
@@ -350,9 +359,19 @@ fn extract_text_lazy_with_tracking(
         .and_then(|pdf_cfg| pdf_cfg.hierarchy.as_ref())
         .cloned();
 
+    // Bookmarked headings override font-size levels on their target pages
+    let outline_headings = if should_extract_hierarchy {
+        crate::pdf::outline::outline_headings_by_page(&crate::pdf::outline::extract_outline(document))
+    } else {
+        Default::default()
+    };
+
     let mut total_sample_size = 0usize;
     let mut sample_count = 0;
 
+    #[cfg(feature = "api")]
+    let page_sink = crate::core::page_stream::current_page_sink();
+
     for (page_idx, page) in document.pages().iter().enumerate() {
         let page_number = page_idx + 1;
 
@@ -390,20 +409,38 @@ fn extract_text_lazy_with_tracking(
         if let Some(ref mut pages) = page_contents {
             // Extract hierarchy if enabled
             let hierarchy = if should_extract_hierarchy {
-                extract_page_hierarchy(&page, hierarchy_config.as_ref())?
+                let page_headings = outline_headings
+                    .get(&page_number)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                extract_page_hierarchy(&page, hierarchy_config.as_ref(), page_headings)?
             } else {
                 None
             };
 
             let is_blank = Some(crate::extraction::blank_detection::is_page_text_blank(&page_text_ref));
-            pages.push(PageContent {
+            let page_content = PageContent {
                 page_number,
                 content: page_text_ref.to_owned(),
                 tables: Vec::new(),
                 images: Vec::new(),
                 hierarchy,
                 is_blank,
-            });
+                language: None,
+                language_confidence: None,
+                text_source: Some(PageTextSource::TextLayer),
+            };
+
+            #[cfg(feature = "api")]
+            if let Some(ref sink) = page_sink
+                && !sink.send(page_content.clone())
+            {
+                return Err(PdfError::ExtractionFailed(
+                    "Extraction cancelled by the page stream consumer".to_string(),
+                ));
+            }
+
+            pages.push(page_content);
         }
 
         if page_idx == 4 && page_count > 5 && sample_count > 0 {
@@ -426,6 +463,8 @@ fn extract_text_lazy_with_tracking(
 ///
 /// * `page` - The PDF page to extract hierarchy from
 /// * `hierarchy_config` - Configuration for hierarchy extraction
+/// * `outline_headings` - Outline entries targeting this page, which take precedence
+///   over font size clustering for the blocks they match
 ///
 /// # Returns
 ///
@@ -433,6 +472,7 @@ fn extract_text_lazy_with_tracking(
 fn extract_page_hierarchy(
     page: &pdfium_render::prelude::PdfPage,
     hierarchy_config: Option<&crate::core::config::HierarchyConfig>,
+    outline_headings: &[crate::pdf::outline::OutlineHeading],
 ) -> Result<Option<crate::types::PageHierarchy>> {
     use crate::pdf::hierarchy::{
         HierarchyLevel, assign_hierarchy_levels, cluster_font_sizes, extract_chars_with_fonts, merge_chars_into_blocks,
@@ -487,7 +527,8 @@ fn extract_page_hierarchy(
             .collect(),
     };
 
-    let hierarchy_blocks = assign_hierarchy_levels(&text_blocks, &kmeans_result);
+    let mut hierarchy_blocks = assign_hierarchy_levels(&text_blocks, &kmeans_result);
+    crate::pdf::outline::apply_outline_levels(&mut hierarchy_blocks, outline_headings);
 
     // Convert to output format
     let blocks: Vec<HierarchicalBlock> = hierarchy_blocks
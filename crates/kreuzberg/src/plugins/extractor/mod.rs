@@ -58,6 +58,7 @@ mod tests {
                 metadata: crate::types::Metadata::default(),
                 tables: vec![],
                 detected_languages: None,
+                language_spans: None,
                 chunks: None,
                 images: None,
                 djot_content: None,
@@ -69,6 +70,7 @@ mod tests {
                 extracted_keywords: None,
                 quality_score: None,
                 processing_warnings: Vec::new(),
+                children: None,
             })
         }
 
@@ -239,6 +241,7 @@ mod tests {
                     metadata: crate::types::Metadata::default(),
                     tables: vec![],
                     detected_languages: None,
+                    language_spans: None,
                     chunks: None,
                     images: None,
                     djot_content: None,
@@ -250,6 +253,7 @@ mod tests {
                     extracted_keywords: None,
                     quality_score: None,
                     processing_warnings: Vec::new(),
+                    children: None,
                 })
             }
 
@@ -230,6 +230,7 @@ mod tests {
                 metadata: crate::types::Metadata::default(),
                 tables: vec![],
                 detected_languages: None,
+                language_spans: None,
                 chunks: None,
                 images: None,
                 djot_content: None,
@@ -241,6 +242,7 @@ mod tests {
                 extracted_keywords: None,
                 quality_score: None,
                 processing_warnings: Vec::new(),
+                children: None,
             })
         }
 
@@ -375,6 +377,7 @@ mod tests {
                     metadata: crate::types::Metadata::default(),
                     tables: vec![],
                     detected_languages: None,
+                    language_spans: None,
                     chunks: None,
                     images: None,
                     djot_content: None,
@@ -386,6 +389,7 @@ mod tests {
                     extracted_keywords: None,
                     quality_score: None,
                     processing_warnings: Vec::new(),
+                    children: None,
                 })
             }
 
@@ -429,6 +433,7 @@ mod tests {
                     metadata: crate::types::Metadata::default(),
                     tables: vec![],
                     detected_languages: None,
+                    language_spans: None,
                     chunks: None,
                     images: None,
                     djot_content: None,
@@ -440,6 +445,7 @@ mod tests {
                     extracted_keywords: None,
                     quality_score: None,
                     processing_warnings: Vec::new(),
+                    children: None,
                 })
             }
 
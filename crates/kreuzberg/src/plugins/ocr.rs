@@ -492,6 +492,7 @@ mod tests {
                 metadata: crate::types::Metadata::default(),
                 tables: vec![],
                 detected_languages: None,
+                language_spans: None,
                 chunks: None,
                 images: None,
                 djot_content: None,
@@ -503,6 +504,7 @@ mod tests {
                 extracted_keywords: None,
                 quality_score: None,
                 processing_warnings: Vec::new(),
+                children: None,
             })
         }
 
@@ -71,6 +71,7 @@ mod tests {
             metadata: crate::types::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -82,6 +83,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let config = ExtractionConfig::default();
@@ -129,6 +131,7 @@ mod tests {
             metadata: crate::types::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -140,6 +143,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let config = ExtractionConfig::default();
@@ -204,6 +208,7 @@ mod tests {
             metadata: crate::types::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -215,6 +220,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let config = ExtractionConfig::default();
@@ -243,6 +249,7 @@ mod tests {
             pages: None,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -253,6 +260,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let config = ExtractionConfig::default();
@@ -277,6 +285,7 @@ mod tests {
             metadata: crate::types::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -288,6 +297,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         assert_eq!(processor.estimated_duration_ms(&result), 0);
@@ -336,6 +346,7 @@ mod tests {
             metadata: crate::types::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -347,6 +358,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let txt_result = ExtractionResult {
@@ -355,6 +367,7 @@ mod tests {
             metadata: crate::types::Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -366,6 +379,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         assert!(processor.should_process(&pdf_result, &config));
@@ -384,6 +398,8 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |".to_string(),
             page_number: 0,
+            grid: None,
+            page_end: None,
         };
 
         let mut result = ExtractionResult {
@@ -392,6 +408,7 @@ mod tests {
             metadata: crate::types::Metadata::default(),
             tables: vec![table],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -403,6 +420,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let config = ExtractionConfig::default();
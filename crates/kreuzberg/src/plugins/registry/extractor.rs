@@ -257,6 +257,7 @@ mod tests {
                 metadata: crate::types::Metadata::default(),
                 tables: vec![],
                 detected_languages: None,
+                language_spans: None,
                 chunks: None,
                 images: None,
                 djot_content: None,
@@ -268,6 +269,7 @@ mod tests {
                 extracted_keywords: None,
                 quality_score: None,
                 processing_warnings: Vec::new(),
+                children: None,
             })
         }
 
@@ -505,6 +507,7 @@ mod tests {
                 metadata: crate::types::Metadata::default(),
                 tables: vec![],
                 detected_languages: None,
+                language_spans: None,
                 chunks: None,
                 images: None,
                 djot_content: None,
@@ -516,6 +519,7 @@ mod tests {
                 extracted_keywords: None,
                 quality_score: None,
                 processing_warnings: Vec::new(),
+                children: None,
             })
         }
 
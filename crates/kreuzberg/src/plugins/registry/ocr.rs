@@ -246,6 +246,7 @@ mod tests {
                 metadata: crate::types::Metadata::default(),
                 tables: vec![],
                 detected_languages: None,
+                language_spans: None,
                 chunks: None,
                 images: None,
                 djot_content: None,
@@ -257,6 +258,7 @@ mod tests {
                 extracted_keywords: None,
                 quality_score: None,
                 processing_warnings: Vec::new(),
+                children: None,
             })
         }
 
@@ -379,6 +381,7 @@ mod tests {
                 metadata: crate::types::Metadata::default(),
                 tables: vec![],
                 detected_languages: None,
+                language_spans: None,
                 chunks: None,
                 images: None,
                 djot_content: None,
@@ -390,6 +393,7 @@ mod tests {
                 extracted_keywords: None,
                 quality_score: None,
                 processing_warnings: Vec::new(),
+                children: None,
             })
         }
 
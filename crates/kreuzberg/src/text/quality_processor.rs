@@ -123,6 +123,7 @@ mod tests {
 	            metadata: Metadata::default(),
 	            tables: vec![],
 	            detected_languages: None,
+	            language_spans: None,
 	            chunks: None,
 	            images: None,
 	            pages: None,
@@ -134,6 +135,7 @@ mod tests {
 	            extracted_keywords: None,
 	            quality_score: None,
 	            processing_warnings: Vec::new(),
+	            children: None,
 	        };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -157,6 +159,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -168,6 +171,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -198,6 +202,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -209,6 +214,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let config_with_quality = ExtractionConfig {
@@ -234,6 +240,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -245,6 +252,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let long_result = ExtractionResult {
@@ -253,6 +261,7 @@ mod tests {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
             images: None,
             djot_content: None,
@@ -264,6 +273,7 @@ mod tests {
             extracted_keywords: None,
             quality_score: None,
             processing_warnings: Vec::new(),
+            children: None,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
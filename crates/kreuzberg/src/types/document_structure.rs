@@ -152,7 +152,7 @@ pub struct DocumentNode {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<BoundingBox>,
 
-    /// Inline annotations (formatting, links) on this node's text content.
+    /// Inline annotations (formatting, links, comments, tracked changes) on this node's text content.
     ///
     /// Only meaningful for text-carrying nodes; empty for containers.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
@@ -341,6 +341,29 @@ pub enum AnnotationKind {
         #[serde(skip_serializing_if = "Option::is_none")]
         title: Option<String>,
     },
+    /// Reviewer comment anchored to the annotated range.
+    Comment {
+        id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        author: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        date: Option<String>,
+        text: String,
+    },
+    /// Tracked insertion (revision markup).
+    Insertion {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        author: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        date: Option<String>,
+    },
+    /// Tracked deletion (revision markup).
+    Deletion {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        author: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        date: Option<String>,
+    },
 }
 
 // ============================================================================
@@ -855,6 +878,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_serde_comment_annotation_tag() {
+        let kind = AnnotationKind::Comment {
+            id: "3".to_string(),
+            author: Some("Reviewer".to_string()),
+            date: None,
+            text: "Check this".to_string(),
+        };
+        let json = serde_json::to_value(&kind).expect("serialize");
+        assert_eq!(json.get("annotation_type").unwrap(), "comment");
+        assert!(json.get("date").is_none());
+
+        let deserialized: AnnotationKind = serde_json::from_value(json).expect("deserialize");
+        assert_eq!(deserialized, kind);
+
+        let deletion: AnnotationKind = serde_json::from_str(r#"{"annotation_type":"deletion"}"#).unwrap();
+        assert_eq!(
+            deletion,
+            AnnotationKind::Deletion {
+                author: None,
+                date: None
+            }
+        );
+    }
+
     #[test]
     fn test_table_grid_serde() {
         let grid = TableGrid {
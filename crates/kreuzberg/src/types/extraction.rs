@@ -6,7 +6,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 
 use super::djot::DjotContent;
-use super::document_structure::DocumentStructure;
+use super::document_structure::{DocumentStructure, NodeId};
 use super::metadata::Metadata;
 use super::ocr_elements::OcrElement;
 use super::page::PageContent;
@@ -27,6 +27,15 @@ pub struct ExtractionResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub detected_languages: Option<Vec<String>>,
 
+    /// Runs of `content` in a single language and script.
+    ///
+    /// Populated when language detection is enabled. Spans are ordered by
+    /// position and do not overlap; text whose language could not be
+    /// determined with enough confidence is not covered by any span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub language_spans: Option<Vec<LanguageSpan>>,
+
     /// Text chunks when chunking is enabled.
     ///
     /// When chunking configuration is provided, the content is split into
@@ -134,6 +143,16 @@ pub struct ExtractionResult {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[serde(default)]
     pub processing_warnings: Vec<ProcessingWarning>,
+
+    /// Results for documents embedded in this one (archive members, attachments).
+    ///
+    /// Only populated when recursive extraction is enabled for the container
+    /// format (`ArchiveConfig.recursive`, `EmailConfig.extract_attachments`).
+    /// Each child carries its path inside the container and either its own
+    /// `ExtractionResult` or the error that prevented extraction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub children: Option<Vec<ChildResult>>,
 }
 
 /// A non-fatal warning from a processing pipeline stage.
@@ -150,6 +169,53 @@ pub struct ProcessingWarning {
     pub message: String,
 }
 
+/// A run of text in a single language and script.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct LanguageSpan {
+    /// Byte offset where this span starts in `content` (UTF-8 valid boundary).
+    pub byte_start: usize,
+
+    /// Byte offset where this span ends in `content` (UTF-8 valid boundary).
+    pub byte_end: usize,
+
+    /// Detected language (ISO 639-3 code, e.g. "eng", "deu").
+    pub language: String,
+
+    /// Writing system of the span (e.g. "Latin", "Cyrillic", "Mandarin").
+    pub script: String,
+
+    /// Detection confidence (0.0-1.0).
+    pub confidence: f64,
+}
+
+/// Extraction result for a document embedded in a container.
+///
+/// Produced for archive members and other embedded files when recursive
+/// extraction is enabled. Exactly one of `result` and `error` is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "api", schema(no_recursion))]
+pub struct ChildResult {
+    /// Path of the document inside its container (e.g. `docs/report.pdf`).
+    pub path: String,
+
+    /// Detected MIME type, if detection succeeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// Size of the embedded document in bytes.
+    pub size: u64,
+
+    /// Extraction result for the embedded document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Box<ExtractionResult>>,
+
+    /// Error message when the embedded document could not be extracted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 /// A text chunk with optional embedding and metadata.
 ///
 /// Chunks are created when chunking is enabled in `ExtractionConfig`. Each chunk
@@ -184,7 +250,8 @@ pub struct ChunkMetadata {
 
     /// Number of tokens in this chunk (if available).
     ///
-    /// This is calculated by the embedding model's tokenizer if embeddings are enabled.
+    /// Always set when chunk sizes are measured in tokens (`ChunkSizing::Tokens`);
+    /// otherwise `None`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token_count: Option<usize>,
 
@@ -205,6 +272,34 @@ pub struct ChunkMetadata {
     /// Only populated when page tracking is enabled in extraction configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_page: Option<usize>,
+
+    /// Headings of the sections enclosing this chunk, outermost first.
+    ///
+    /// Only populated by the structure chunker (`ChunkerType::Structure`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub heading_path: Vec<String>,
+
+    /// Node type of each document structure node in this chunk, parallel to `node_ids`.
+    ///
+    /// Only populated by the structure chunker.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub element_types: Vec<String>,
+
+    /// Ids of the document structure nodes this chunk was built from, in reading order.
+    ///
+    /// Only populated by the structure chunker.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub node_ids: Vec<NodeId>,
+
+    /// Detected language of this chunk (ISO 639-3 code).
+    ///
+    /// Only populated when language detection is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Confidence of the detected language (0.0-1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_confidence: Option<f64>,
 }
 
 /// Extracted image from a document.
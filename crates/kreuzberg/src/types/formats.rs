@@ -4,7 +4,7 @@ use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::extraction::ExtractedImage;
+use super::extraction::{ChildResult, ExtractedImage};
 use super::metadata::PptxMetadata;
 use super::page::{PageContent, PageStructure};
 
@@ -127,6 +127,12 @@ pub struct EmailExtractionResult {
     pub date: Option<String>,
     /// Message-ID header value
     pub message_id: Option<String>,
+    /// In-Reply-To header value (Message-ID of the parent message)
+    #[serde(default)]
+    pub in_reply_to: Option<String>,
+    /// References header values (Message-IDs of the thread ancestors, oldest first)
+    #[serde(default)]
+    pub references: Vec<String>,
     /// Plain text version of the email body
     pub plain_text: Option<String>,
     /// HTML version of the email body
@@ -137,6 +143,12 @@ pub struct EmailExtractionResult {
     pub attachments: Vec<EmailAttachment>,
     /// Additional email headers and metadata
     pub metadata: HashMap<String, String>,
+    /// Extraction results for the attachments, in attachment order.
+    ///
+    /// Only populated when `EmailConfig.extract_attachments` is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub children: Option<Vec<ChildResult>>,
 }
 
 /// Email attachment representation.
@@ -355,7 +367,8 @@ impl Default for TesseractConfig {
 /// Image preprocessing metadata.
 ///
 /// Tracks the transformations applied to an image during OCR preprocessing,
-/// including DPI normalization, resizing, and resampling.
+/// including DPI normalization, resizing, resampling, and the enhancement
+/// steps (orientation, deskew, denoise, contrast, binarization, inversion).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
 pub struct ImagePreprocessingMetadata {
@@ -383,6 +396,18 @@ pub struct ImagePreprocessingMetadata {
     pub skipped_resize: bool,
     /// Error message if resize failed
     pub resize_error: Option<String>,
+    /// Enhancement steps applied, in order (e.g. "orientation", "deskew", "binarize:otsu")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps_applied: Vec<String>,
+    /// Clockwise rotation in degrees detected to make the page upright (0, 90, 180 or 270)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detected_orientation: Option<u16>,
+    /// Estimated skew angle in degrees that was corrected (positive = counter-clockwise tilt)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skew_angle: Option<f64>,
+    /// Global threshold chosen by Otsu binarization (local methods leave this unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binarization_threshold: Option<u8>,
 }
 
 /// Image extraction configuration (internal use).
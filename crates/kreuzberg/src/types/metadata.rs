@@ -57,6 +57,7 @@ pub enum FormatMetadata {
     Docx(Box<DocxMetadata>),
     Excel(ExcelMetadata),
     Email(EmailMetadata),
+    Mailbox(MailboxMetadata),
     Pptx(PptxMetadata),
     Archive(ArchiveMetadata),
     Image(ImageMetadata),
@@ -221,6 +222,14 @@ pub struct EmailMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<String>,
 
+    /// In-Reply-To header value (Message-ID of the parent message)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_reply_to: Option<String>,
+
+    /// References header values (Message-IDs of the thread ancestors, oldest first)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<String>,
+
     /// List of attachment filenames
     pub attachments: Vec<String>,
 }
@@ -246,6 +255,44 @@ pub struct ArchiveMetadata {
     pub compressed_size: Option<usize>,
 }
 
+/// Mailbox (mbox/Maildir) metadata.
+///
+/// Each message of the mailbox is returned as an entry of
+/// `ExtractionResult.children`; threads refer to those entries by index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct MailboxMetadata {
+    /// Mailbox format ("mbox" or "maildir")
+    #[cfg_attr(feature = "api", schema(value_type = String))]
+    pub format: Cow<'static, str>,
+    /// Number of messages in the mailbox
+    pub message_count: usize,
+    /// Conversation threads reconstructed from In-Reply-To/References
+    pub threads: Vec<EmailThread>,
+}
+
+/// A conversation thread within a mailbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct EmailThread {
+    /// Subject of the first message of the thread (in mailbox order)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Messages of the thread in mailbox order
+    pub messages: Vec<ThreadMessage>,
+}
+
+/// Position of one message within an [`EmailThread`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct ThreadMessage {
+    /// Index of the message in `ExtractionResult.children`
+    pub index: usize,
+    /// Index of the message this one replies to, if it is in the mailbox
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<usize>,
+}
+
 /// Image metadata extracted from image files.
 ///
 /// Includes dimensions, format, and EXIF data.
@@ -686,6 +733,10 @@ pub struct DocxMetadata {
     /// Values can be strings, numbers, booleans, or dates.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_properties: Option<HashMap<String, serde_json::Value>>,
+
+    /// Reviewer comments from word/comments.xml
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<DocxComment>,
     // Future Week 1-21 additions (commented out for now):
     // style_catalog: OnceCell<Arc<StyleCatalog>>,       // Week 1-2: Style resolution
     // theme: OnceCell<Arc<Theme>>,                      // Week 5: Theme colors
@@ -693,3 +744,29 @@ pub struct DocxMetadata {
     // sections: Vec<SectionProperties>,                 // Week 3-4: Section properties
     // document_settings: DocumentSettings,              // Week 11: Settings.xml
 }
+
+/// Reviewer comment from a Word document.
+#[cfg(feature = "office")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct DocxComment {
+    /// Comment id (`w:id`), referenced by the anchor markers in the document body
+    pub id: String,
+    /// Comment author (`w:author`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Author initials (`w:initials`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initials: Option<String>,
+    /// Creation timestamp (`w:date`, ISO 8601)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// Comment text, paragraphs separated by newlines
+    pub text: String,
+    /// Document text between `w:commentRangeStart` and `w:commentRangeEnd`
+    ///
+    /// Rendered with the configured revision mode. `None` when the comment has
+    /// no range markers in the document body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor_text: Option<String>,
+}
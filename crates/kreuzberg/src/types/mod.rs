@@ -71,6 +71,8 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |\n|---|---|\n".to_string(),
             page_number: 1,
+            grid: None,
+            page_end: None,
         };
 
         let json = serde_json::to_value(&table).unwrap();
@@ -89,6 +91,8 @@ mod tests {
             ],
             markdown: "| X | Y |\n|---|---|\n| 1 | 2 |\n".to_string(),
             page_number: 5,
+            grid: None,
+            page_end: None,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -105,6 +109,8 @@ mod tests {
             cells: vec![vec!["shared".to_string()]],
             markdown: "| shared |".to_string(),
             page_number: 1,
+            grid: None,
+            page_end: None,
         });
 
         let tables_before = [Arc::clone(&shared_table), Arc::clone(&shared_table)].to_vec();
@@ -120,11 +126,15 @@ mod tests {
                 cells: vec![vec!["A".to_string()]],
                 markdown: "| A |".to_string(),
                 page_number: 1,
+                grid: None,
+                page_end: None,
             },
             Table {
                 cells: vec![vec!["B".to_string()]],
                 markdown: "| B |".to_string(),
                 page_number: 2,
+                grid: None,
+                page_end: None,
             },
         ];
 
@@ -147,16 +157,23 @@ mod tests {
                     cells: vec![vec!["Table1".to_string()]],
                     markdown: "| Table1 |".to_string(),
                     page_number: 3,
+                    grid: None,
+                    page_end: None,
                 }),
                 Arc::new(Table {
                     cells: vec![vec!["Table2".to_string()]],
                     markdown: "| Table2 |".to_string(),
                     page_number: 3,
+                    grid: None,
+                    page_end: None,
                 }),
             ],
             images: Vec::new(),
             hierarchy: None,
             is_blank: None,
+            language: None,
+            language_confidence: None,
+            text_source: None,
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -206,6 +223,9 @@ mod tests {
             images: vec![image1, image2],
             hierarchy: None,
             is_blank: None,
+            language: None,
+            language_confidence: None,
+            text_source: None,
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -224,6 +244,8 @@ mod tests {
             cells: vec![vec!["shared across pages".to_string()]],
             markdown: "| shared across pages |".to_string(),
             page_number: 0,
+            grid: None,
+            page_end: None,
         });
 
         let page1 = PageContent {
@@ -233,6 +255,9 @@ mod tests {
             images: Vec::new(),
             hierarchy: None,
             is_blank: None,
+            language: None,
+            language_confidence: None,
+            text_source: None,
         };
 
         let page2 = PageContent {
@@ -242,6 +267,9 @@ mod tests {
             images: Vec::new(),
             hierarchy: None,
             is_blank: None,
+            language: None,
+            language_confidence: None,
+            text_source: None,
         };
 
         assert!(Arc::ptr_eq(&page1.tables[0], &page2.tables[0]));
@@ -264,6 +292,9 @@ mod tests {
             images: Vec::new(),
             hierarchy: None,
             is_blank: None,
+            language: None,
+            language_confidence: None,
+            text_source: None,
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -280,12 +311,16 @@ mod tests {
             cells: vec![vec!["A".to_string()]],
             markdown: "| A |".to_string(),
             page_number: 1,
+            grid: None,
+            page_end: None,
         };
 
         let table2 = Table {
             cells: vec![vec!["B".to_string()]],
             markdown: "| B |".to_string(),
             page_number: 2,
+            grid: None,
+            page_end: None,
         };
 
         let json = serde_json::to_string(&vec![table1, table2]).unwrap();
@@ -50,6 +50,17 @@ pub enum PageUnitType {
     Sheet,
 }
 
+/// Where the text of a page came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub enum PageTextSource {
+    /// Text read from the document's embedded text layer
+    TextLayer,
+    /// Text recognized by OCR from the rendered page
+    Ocr,
+}
+
 /// Byte offset boundary for a page.
 ///
 /// Tracks where a specific page's content starts and ends in the main content string,
@@ -157,6 +168,22 @@ pub struct PageContent {
     /// and contains no tables or images.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_blank: Option<bool>,
+
+    /// Detected language of this page (ISO 639-3 code).
+    ///
+    /// Only populated when language detection is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Confidence of the detected language (0.0-1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_confidence: Option<f64>,
+
+    /// Whether the text of this page came from the text layer or from OCR.
+    ///
+    /// Only populated by extractors that can mix both, such as PDF.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_source: Option<PageTextSource>,
 }
 
 /// Page hierarchy structure containing heading levels and block information.
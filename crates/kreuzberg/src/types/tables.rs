@@ -1,5 +1,6 @@
 //! Table-related types for document extraction.
 
+use super::document_structure::TableGrid;
 use serde::{Deserialize, Serialize};
 
 /// Extracted table structure.
@@ -15,6 +16,14 @@ pub struct Table {
     pub markdown: String,
     /// Page number where the table was found (1-indexed)
     pub page_number: usize,
+    /// Cell grid with spans and cell bounding boxes, when the extractor recovered
+    /// the table layout (e.g. ruled PDF tables)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grid: Option<TableGrid>,
+    /// Last page of a table that continues across page breaks (1-indexed);
+    /// `None` when the table is on `page_number` only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_end: Option<usize>,
 }
 
 /// Individual table cell with content and optional styling.
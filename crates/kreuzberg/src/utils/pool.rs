@@ -54,7 +54,7 @@ pub struct PoolMetrics {
 #[cfg(feature = "pool-metrics")]
 impl PoolMetrics {
     /// Create a new metrics tracker with all counters at zero.
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         PoolMetrics {
             total_acquires: AtomicUsize::new(0),
             total_cache_hits: AtomicUsize::new(0),
@@ -98,6 +98,14 @@ impl Default for PoolMetrics {
     }
 }
 
+/// Process-wide totals across every [`Pool`], exported by the `/metrics` endpoint.
+///
+/// Pools are short-lived (typically one per batch), so their own metrics disappear
+/// with them; these counters keep accumulating for the lifetime of the process.
+/// `peak_items_stored` is the largest peak of any single pool.
+#[cfg(feature = "pool-metrics")]
+pub static GLOBAL_POOL_METRICS: PoolMetrics = PoolMetrics::new();
+
 #[cfg(feature = "pool-metrics")]
 #[derive(Debug, Clone, Copy)]
 pub struct PoolMetricsSnapshot {
@@ -176,19 +184,28 @@ impl<T: Recyclable> Pool<T> {
     /// This is a safety mechanism provided by parking_lot to prevent subtle bugs.
     pub fn acquire(&self) -> Result<PoolGuard<T>, PoolError> {
         #[cfg(feature = "pool-metrics")]
-        self.metrics.total_acquires.fetch_add(1, Ordering::Relaxed);
+        {
+            self.metrics.total_acquires.fetch_add(1, Ordering::Relaxed);
+            GLOBAL_POOL_METRICS.total_acquires.fetch_add(1, Ordering::Relaxed);
+        }
 
         let mut objects = self.objects.lock();
 
         let object = if let Some(mut obj) = objects.pop() {
             #[cfg(feature = "pool-metrics")]
-            self.metrics.total_cache_hits.fetch_add(1, Ordering::Relaxed);
+            {
+                self.metrics.total_cache_hits.fetch_add(1, Ordering::Relaxed);
+                GLOBAL_POOL_METRICS.total_cache_hits.fetch_add(1, Ordering::Relaxed);
+            }
 
             obj.reset();
             obj
         } else {
             #[cfg(feature = "pool-metrics")]
-            self.metrics.total_creations.fetch_add(1, Ordering::Relaxed);
+            {
+                self.metrics.total_creations.fetch_add(1, Ordering::Relaxed);
+                GLOBAL_POOL_METRICS.total_creations.fetch_add(1, Ordering::Relaxed);
+            }
 
             (self.factory)()
         };
@@ -260,6 +277,9 @@ impl<T: Recyclable> Drop for PoolGuard<T> {
                             .peak_items_stored
                             .store(current_size, Ordering::Relaxed);
                     }
+                    GLOBAL_POOL_METRICS
+                        .peak_items_stored
+                        .fetch_max(current_size, Ordering::Relaxed);
                 }
             }
         }
@@ -0,0 +1,186 @@
+//! Integration tests for API authentication, scopes and quotas.
+
+#![cfg(feature = "api")]
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+};
+use tower::ServiceExt;
+
+use kreuzberg::{
+    ExtractionConfig,
+    api::create_router_with_limits_and_server_config,
+    core::{ApiKeyConfig, ApiScope, AuthConfig, QuotaConfig, ServerConfig},
+};
+
+fn router_with_auth(auth: AuthConfig) -> Router {
+    let server_config = ServerConfig {
+        auth,
+        ..Default::default()
+    };
+    create_router_with_limits_and_server_config(ExtractionConfig::default(), Default::default(), server_config)
+}
+
+fn keys_auth() -> AuthConfig {
+    AuthConfig {
+        api_keys: vec![
+            ApiKeyConfig::new("user-key", vec![ApiScope::User]),
+            ApiKeyConfig::new("admin-key", vec![ApiScope::Admin]),
+        ],
+        ..Default::default()
+    }
+}
+
+fn request(method: &str, uri: &str, bearer: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().method(method).uri(uri);
+    if let Some(token) = bearer {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    builder.body(Body::empty()).expect("Failed to create HTTP request")
+}
+
+/// Requests without credentials are rejected with 401 and a JSON error body.
+#[tokio::test]
+async fn test_missing_credentials_rejected() {
+    let app = router_with_auth(keys_auth());
+
+    let response = app
+        .oneshot(request("GET", "/info", None))
+        .await
+        .expect("Failed to send HTTP request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    let error: serde_json::Value = serde_json::from_slice(&body).expect("Failed to deserialize JSON response");
+    assert_eq!(error["error_type"], "AuthenticationError");
+    assert_eq!(error["status_code"], 401);
+}
+
+/// Unknown keys are rejected.
+#[tokio::test]
+async fn test_invalid_key_rejected() {
+    let app = router_with_auth(keys_auth());
+
+    let response = app
+        .oneshot(request("GET", "/info", Some("wrong-key")))
+        .await
+        .expect("Failed to send HTTP request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Valid keys are accepted via `Authorization: Bearer` and `X-API-Key`.
+#[tokio::test]
+async fn test_valid_key_accepted() {
+    let app = router_with_auth(keys_auth());
+
+    let response = app
+        .clone()
+        .oneshot(request("GET", "/info", Some("user-key")))
+        .await
+        .expect("Failed to send HTTP request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/info")
+                .header("X-API-Key", "user-key")
+                .body(Body::empty())
+                .expect("Failed to create HTTP request"),
+        )
+        .await
+        .expect("Failed to send HTTP request");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// The health check stays reachable without credentials for load balancers.
+#[tokio::test]
+async fn test_health_is_public() {
+    let app = router_with_auth(keys_auth());
+
+    let response = app
+        .oneshot(request("GET", "/health", None))
+        .await
+        .expect("Failed to send HTTP request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// Clearing the cache requires the admin scope.
+#[tokio::test]
+async fn test_cache_clear_requires_admin_scope() {
+    let app = router_with_auth(keys_auth());
+
+    let response = app
+        .clone()
+        .oneshot(request("DELETE", "/cache/clear", Some("user-key")))
+        .await
+        .expect("Failed to send HTTP request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .oneshot(request("DELETE", "/cache/clear", Some("admin-key")))
+        .await
+        .expect("Failed to send HTTP request");
+    assert_ne!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_ne!(response.status(), StatusCode::FORBIDDEN);
+}
+
+/// Requests beyond the per-key rate are rejected with 429 and `Retry-After`.
+#[tokio::test]
+async fn test_rate_limit_per_key() {
+    let app = router_with_auth(AuthConfig {
+        default_quota: QuotaConfig {
+            requests_per_minute: Some(2),
+            max_concurrent_requests: None,
+        },
+        ..keys_auth()
+    });
+
+    for _ in 0..2 {
+        let response = app
+            .clone()
+            .oneshot(request("GET", "/info", Some("user-key")))
+            .await
+            .expect("Failed to send HTTP request");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(request("GET", "/info", Some("user-key")))
+        .await
+        .expect("Failed to send HTTP request");
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().contains_key(header::RETRY_AFTER));
+
+    // Quotas are tracked per key
+    let response = app
+        .oneshot(request("GET", "/info", Some("admin-key")))
+        .await
+        .expect("Failed to send HTTP request");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// An unreadable key file locks the server instead of leaving it open.
+#[tokio::test]
+async fn test_unreadable_key_file_fails_closed() {
+    let app = router_with_auth(AuthConfig {
+        api_keys_file: Some("/nonexistent/kreuzberg-keys.txt".into()),
+        ..keys_auth()
+    });
+
+    let response = app
+        .oneshot(request("GET", "/info", Some("user-key")))
+        .await
+        .expect("Failed to send HTTP request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
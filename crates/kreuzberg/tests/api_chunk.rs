@@ -346,3 +346,144 @@ async fn test_chunk_rejects_json_array() {
         response.status()
     );
 }
+
+#[cfg(feature = "chunking-tokens")]
+#[tokio::test]
+async fn test_chunk_token_sizing_reports_token_counts() {
+    use kreuzberg::api::ChunkResponse;
+
+    let dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let tokenizer = dir.path().join("tokenizer.json");
+    std::fs::write(
+        &tokenizer,
+        json!({
+            "version": "1.0",
+            "truncation": null,
+            "padding": null,
+            "added_tokens": [],
+            "normalizer": null,
+            "pre_tokenizer": {"type": "Whitespace"},
+            "post_processor": null,
+            "decoder": null,
+            "model": {"type": "WordLevel", "vocab": {"[UNK]": 0, "one": 1, "two": 2}, "unk_token": "[UNK]"}
+        })
+        .to_string(),
+    )
+    .expect("Failed to write tokenizer");
+
+    let app = create_router(ExtractionConfig::default());
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/chunk")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "text": "one two three four five six seven",
+                        "config": {
+                            "max_characters": 3,
+                            "overlap": 0,
+                            "sizing": {"unit": "tokens", "tokenizer": tokenizer}
+                        }
+                    })
+                    .to_string(),
+                ))
+                .expect("Operation failed"),
+        )
+        .await
+        .expect("Operation failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to convert to bytes");
+    let chunk_response: ChunkResponse = serde_json::from_slice(&body).expect("Failed to deserialize");
+
+    assert_eq!(chunk_response.chunk_count, 3);
+    let counts: Vec<Option<usize>> = chunk_response.chunks.iter().map(|chunk| chunk.token_count).collect();
+    assert_eq!(counts, vec![Some(3), Some(3), Some(1)]);
+}
+
+#[tokio::test]
+async fn test_chunk_token_sizing_with_missing_tokenizer_returns_400() {
+    let app = create_router(ExtractionConfig::default());
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/chunk")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "text": "Some text to chunk.",
+                        "config": {
+                            "max_characters": 100,
+                            "overlap": 10,
+                            "sizing": {"unit": "tokens", "tokenizer": "/nonexistent/tokenizer.json"}
+                        }
+                    })
+                    .to_string(),
+                ))
+                .expect("Operation failed"),
+        )
+        .await
+        .expect("Operation failed");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_chunk_semantic_with_invalid_threshold_returns_400() {
+    let app = create_router(ExtractionConfig::default());
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/chunk")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "text": "Cats purr. Stocks rose.",
+                        "chunker_type": "semantic",
+                        "config": {
+                            "max_characters": 500,
+                            "overlap": 0,
+                            "semantic": {"threshold": {"type": "percentile", "value": 150.0}}
+                        }
+                    })
+                    .to_string(),
+                ))
+                .expect("Operation failed"),
+        )
+        .await
+        .expect("Operation failed");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[cfg(not(feature = "embeddings"))]
+#[tokio::test]
+async fn test_chunk_semantic_without_embeddings_feature_returns_400() {
+    let app = create_router(ExtractionConfig::default());
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/chunk")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({"text": "Cats purr. Stocks rose.", "chunker_type": "semantic"}).to_string(),
+                ))
+                .expect("Operation failed"),
+        )
+        .await
+        .expect("Operation failed");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to convert to bytes");
+    assert!(String::from_utf8_lossy(&body).contains("embeddings"));
+}
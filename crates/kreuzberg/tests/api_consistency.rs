@@ -236,6 +236,7 @@ fn test_extraction_config_no_unknown_fields_in_default() {
     // These are the fields we expect (some may be null based on feature flags)
     let expected_fields = vec![
         "use_cache",
+        "cache_backend",
         "enable_quality_processing",
         "ocr",
         "force_ocr",
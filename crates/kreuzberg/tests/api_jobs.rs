@@ -0,0 +1,213 @@
+//! Integration tests for the asynchronous job API.
+
+#![cfg(feature = "api")]
+
+use std::time::Duration;
+
+use axum::{
+    Json, Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+    routing::post,
+};
+use tokio::sync::mpsc;
+use tower::ServiceExt;
+
+use kreuzberg::{
+    ExtractionConfig,
+    api::{JobResponse, JobStatus, create_router_with_limits_and_server_config},
+    core::{JobsConfig, ServerConfig},
+};
+
+const BOUNDARY: &str = "----kreuzberg-jobs";
+
+fn router(jobs_dir: &std::path::Path) -> Router {
+    let server_config = ServerConfig {
+        jobs: JobsConfig {
+            directory: Some(jobs_dir.to_path_buf()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    create_router_with_limits_and_server_config(ExtractionConfig::default(), Default::default(), server_config)
+}
+
+fn submit_request(text: &str, webhook_url: Option<&str>) -> Request<Body> {
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"files\"; filename=\"note.txt\"\r\n\
+         Content-Type: text/plain\r\n\r\n{text}\r\n"
+    );
+    if let Some(url) = webhook_url {
+        body.push_str(&format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"webhook_url\"\r\n\r\n{url}\r\n"
+        ));
+    }
+    body.push_str(&format!("--{BOUNDARY}--\r\n"));
+
+    Request::builder()
+        .method("POST")
+        .uri("/jobs")
+        .header(
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+        .body(Body::from(body))
+        .expect("Failed to create HTTP request")
+}
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, Vec<u8>) {
+    let response = app.clone().oneshot(request).await.expect("Failed to send HTTP request");
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    (status, body.to_vec())
+}
+
+async fn get(app: &Router, uri: &str) -> (StatusCode, Vec<u8>) {
+    send(
+        app,
+        Request::builder()
+            .uri(uri)
+            .body(Body::empty())
+            .expect("Failed to create HTTP request"),
+    )
+    .await
+}
+
+async fn submit(app: &Router, text: &str, webhook_url: Option<&str>) -> JobResponse {
+    let (status, body) = send(app, submit_request(text, webhook_url)).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    serde_json::from_slice(&body).expect("Failed to deserialize job")
+}
+
+async fn wait_for_job(app: &Router, id: &str) -> JobResponse {
+    for _ in 0..200 {
+        let (status, body) = get(app, &format!("/jobs/{id}")).await;
+        assert_eq!(status, StatusCode::OK);
+        let job: JobResponse = serde_json::from_slice(&body).expect("Failed to deserialize job");
+        if job.status != JobStatus::Queued && job.status != JobStatus::Running {
+            return job;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("Job {id} did not finish");
+}
+
+/// A submitted job completes and its result matches the `/extract` format.
+#[tokio::test]
+async fn test_job_lifecycle() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = router(dir.path());
+
+    let job = submit(&app, "Asynchronous extraction works.", None).await;
+    assert_eq!(job.status, JobStatus::Queued);
+    assert_eq!(job.total_files, 1);
+
+    let finished = wait_for_job(&app, &job.id).await;
+    assert_eq!(finished.status, JobStatus::Completed);
+    assert_eq!(finished.processed_files, 1);
+
+    let (status, body) = get(&app, &format!("/jobs/{}/result", job.id)).await;
+    assert_eq!(status, StatusCode::OK);
+    let results: Vec<serde_json::Value> = serde_json::from_slice(&body).expect("Failed to deserialize results");
+    assert_eq!(results.len(), 1);
+    assert!(
+        results[0]["content"]
+            .as_str()
+            .unwrap()
+            .contains("Asynchronous extraction works.")
+    );
+}
+
+/// Finished jobs are still available from a new router over the same directory.
+#[tokio::test]
+async fn test_finished_jobs_survive_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let job = {
+        let app = router(dir.path());
+        let job = submit(&app, "Persisted result.", None).await;
+        wait_for_job(&app, &job.id).await;
+        job
+    };
+
+    let restarted = router(dir.path());
+    let (status, body) = get(&restarted, &format!("/jobs/{}/result", job.id)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(String::from_utf8(body).unwrap().contains("Persisted result."));
+}
+
+/// Deleting a finished job removes it.
+#[tokio::test]
+async fn test_delete_job() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = router(dir.path());
+    let job = submit(&app, "Short-lived.", None).await;
+    wait_for_job(&app, &job.id).await;
+
+    let (status, _) = send(
+        &app,
+        Request::builder()
+            .method("DELETE")
+            .uri(format!("/jobs/{}", job.id))
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = get(&app, &format!("/jobs/{}", job.id)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+/// Unknown job ids return 404 with a JSON error.
+#[tokio::test]
+async fn test_unknown_job() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = router(dir.path());
+
+    let (status, body) = get(&app, "/jobs/does-not-exist/result").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error["error_type"], "NotFoundError");
+}
+
+/// Webhook URLs must be http(s).
+#[tokio::test]
+async fn test_invalid_webhook_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = router(dir.path());
+
+    let (status, _) = send(&app, submit_request("text", Some("ftp://example.com/hook"))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+/// The webhook receives the final job status.
+#[tokio::test]
+async fn test_completion_webhook() {
+    let (sender, mut receiver) = mpsc::channel::<JobResponse>(1);
+    let hook = Router::new().route(
+        "/hook",
+        post(move |Json(job): Json<JobResponse>| {
+            let sender = sender.clone();
+            async move {
+                sender.send(job).await.unwrap();
+                StatusCode::NO_CONTENT
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let hook_url = format!("http://{}/hook", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, hook).await.unwrap() });
+
+    let dir = tempfile::tempdir().unwrap();
+    let app = router(dir.path());
+    let job = submit(&app, "Notify me.", Some(&hook_url)).await;
+
+    let notified = tokio::time::timeout(Duration::from_secs(10), receiver.recv())
+        .await
+        .expect("Webhook was not called")
+        .unwrap();
+    assert_eq!(notified.id, job.id);
+    assert_eq!(notified.status, JobStatus::Completed);
+}
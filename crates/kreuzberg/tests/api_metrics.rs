@@ -0,0 +1,113 @@
+//! Integration tests for the Prometheus `/metrics` endpoint.
+
+#![cfg(feature = "api")]
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+};
+use tower::ServiceExt;
+
+use kreuzberg::{ExtractionConfig, api::create_router};
+
+const BOUNDARY: &str = "----kreuzberg-metrics";
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, String) {
+    let response = app.clone().oneshot(request).await.expect("Failed to send HTTP request");
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    (status, String::from_utf8(body.to_vec()).expect("Response is not UTF-8"))
+}
+
+async fn scrape(app: &Router) -> String {
+    let (status, body) = send(app, Request::builder().uri("/metrics").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+    body
+}
+
+/// Extractions are counted and timed per extractor and MIME type.
+#[tokio::test]
+async fn test_extraction_metrics() {
+    let app = create_router(ExtractionConfig {
+        max_concurrent_extractions: Some(3),
+        // A cached result from an earlier run would skip the extraction
+        use_cache: false,
+        ..Default::default()
+    });
+
+    let body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"files\"; filename=\"note.txt\"\r\n\
+         Content-Type: text/plain\r\n\r\nMeasured extraction.\r\n--{BOUNDARY}--\r\n"
+    );
+    let (status, _) = send(
+        &app,
+        Request::builder()
+            .method("POST")
+            .uri("/extract")
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={BOUNDARY}"),
+            )
+            .body(Body::from(body))
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let metrics = scrape(&app).await;
+    assert!(metrics.contains("# TYPE kreuzberg_extraction_duration_seconds histogram"));
+    assert!(
+        metrics
+            .lines()
+            .any(|line| line.starts_with("kreuzberg_extractions_total{")
+                && line.contains("mime_type=\"text/plain\",status=\"success\""))
+    );
+    assert!(metrics.contains("kreuzberg_max_concurrent_extractions 3"));
+    assert!(metrics.contains("kreuzberg_extractions_in_flight "));
+}
+
+/// Requests are labelled with the matched route template, not the raw path.
+#[tokio::test]
+async fn test_http_metrics_use_route_templates() {
+    let app = create_router(ExtractionConfig::default());
+
+    let (status, _) = send(
+        &app,
+        Request::builder()
+            .uri("/jobs/some-unknown-job")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&app, Request::builder().uri("/health").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let metrics = scrape(&app).await;
+    assert!(metrics.contains("kreuzberg_http_requests_total{method=\"GET\",route=\"/jobs/{id}\",status=\"404\"}"));
+    assert!(metrics.contains("kreuzberg_http_request_duration_seconds_count{method=\"GET\",route=\"/health\"}"));
+    assert!(!metrics.contains("some-unknown-job"));
+}
+
+/// The endpoint serves the Prometheus text format content type.
+#[tokio::test]
+async fn test_metrics_content_type() {
+    let app = create_router(ExtractionConfig::default());
+
+    let response = app
+        .oneshot(Request::builder().uri("/metrics").body(Body::empty()).unwrap())
+        .await
+        .expect("Failed to send HTTP request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain; version=0.0.4")
+    );
+}
@@ -0,0 +1,131 @@
+//! Integration tests for the streaming extraction endpoint.
+
+#![cfg(feature = "api")]
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+};
+use tower::ServiceExt;
+
+use kreuzberg::{ExtractionConfig, api::create_router};
+
+const BOUNDARY: &str = "----kreuzberg-stream";
+
+fn stream_request(files: &[&str], config: Option<&str>) -> Request<Body> {
+    let mut body = String::new();
+    for text in files {
+        body.push_str(&format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"files\"; filename=\"note.txt\"\r\n\
+             Content-Type: text/plain\r\n\r\n{text}\r\n"
+        ));
+    }
+    if let Some(config) = config {
+        body.push_str(&format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"config\"\r\n\r\n{config}\r\n"
+        ));
+    }
+    body.push_str(&format!("--{BOUNDARY}--\r\n"));
+
+    Request::builder()
+        .method("POST")
+        .uri("/extract/stream")
+        .header(
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+        .body(Body::from(body))
+        .expect("Failed to create HTTP request")
+}
+
+/// Parse a server-sent event stream into `(event, data)` pairs.
+fn parse_events(body: &str) -> Vec<(String, serde_json::Value)> {
+    body.split("\n\n")
+        .filter_map(|block| {
+            let mut name = None;
+            let mut data = None;
+            for line in block.lines() {
+                if let Some(value) = line.strip_prefix("event: ") {
+                    name = Some(value.to_string());
+                } else if let Some(value) = line.strip_prefix("data: ") {
+                    data = Some(serde_json::from_str(value).expect("Event data is not JSON"));
+                }
+            }
+            Some((name?, data?))
+        })
+        .collect()
+}
+
+async fn send(app: Router, request: Request<Body>) -> (StatusCode, String, String) {
+    let response = app.oneshot(request).await.expect("Failed to send HTTP request");
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .map(|value| value.to_str().unwrap().to_string())
+        .unwrap_or_default();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    (status, content_type, String::from_utf8(body.to_vec()).unwrap())
+}
+
+/// Chunks are streamed before the final metadata event.
+#[tokio::test]
+async fn test_stream_emits_chunks_then_metadata() {
+    let app = create_router(ExtractionConfig::default());
+    let text = "Streaming extraction sends chunks as events. ".repeat(20);
+    let config = r#"{"chunking":{"max_chars":200,"max_overlap":0}}"#;
+
+    let (status, content_type, body) = send(app, stream_request(&[&text], Some(config))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(content_type.starts_with("text/event-stream"));
+
+    let events = parse_events(&body);
+    let chunks = events.iter().filter(|(name, _)| name == "chunk").count();
+    assert!(chunks > 1, "expected several chunk events, got {}", chunks);
+
+    let (last_name, last_data) = events.last().expect("stream should not be empty");
+    assert_eq!(last_name, "metadata");
+    assert!(last_data["content"].as_str().unwrap().contains("Streaming extraction"));
+    assert!(last_data.get("chunks").is_none_or(|chunks| chunks.is_null()));
+    assert!(last_data.get("pages").is_none_or(|pages| pages.is_null()));
+}
+
+/// Extraction failures are reported as an `error` event.
+#[tokio::test]
+async fn test_stream_reports_errors_as_events() {
+    let app = create_router(ExtractionConfig::default());
+    let request = Request::builder()
+        .method("POST")
+        .uri("/extract/stream")
+        .header(
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+        .body(Body::from(format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"files\"; filename=\"x.bin\"\r\n\
+             Content-Type: application/x-unknown-kreuzberg\r\n\r\n\x01\x02\r\n--{BOUNDARY}--\r\n"
+        )))
+        .unwrap();
+
+    let (status, _, body) = send(app, request).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let events = parse_events(&body);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, "error");
+    assert!(events[0].1["error_type"].is_string());
+}
+
+/// Only a single file can be streamed.
+#[tokio::test]
+async fn test_stream_rejects_multiple_files() {
+    let app = create_router(ExtractionConfig::default());
+
+    let (status, _, body) = send(app, stream_request(&["one", "two"], None)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let error: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(error["error_type"], "ValidationError");
+}
@@ -914,6 +914,43 @@ async fn test_cache_stats_endpoint() {
     assert!(stats["directory"].is_string());
     assert!(stats["total_files"].is_number());
     assert!(stats["total_size_mb"].is_number());
+    assert_eq!(stats["backend"], "filesystem");
+}
+
+/// The server configuration selects the result cache backend.
+#[tokio::test]
+async fn test_cache_stats_reports_server_cache_backend() {
+    use kreuzberg::{
+        CacheBackend, ServerConfig,
+        api::{ApiSizeLimits, create_router_with_limits_and_server_config},
+    };
+
+    let server_config = ServerConfig {
+        cache_backend: Some(CacheBackend::Memory),
+        ..Default::default()
+    };
+    let app = create_router_with_limits_and_server_config(
+        ExtractionConfig::default(),
+        ApiSizeLimits::default(),
+        server_config,
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/cache/stats")
+                .body(Body::empty())
+                .expect("Failed to create HTTP request body"),
+        )
+        .await
+        .expect("Failed to send HTTP request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    let stats: serde_json::Value = serde_json::from_slice(&body).expect("Failed to deserialize JSON response");
+    assert_eq!(stats["backend"], "memory");
 }
 
 /// Test cache clear endpoint.
@@ -25,6 +25,9 @@ async fn test_chunking_enabled() {
             preset: None,
             trim: true,
             chunker_type: kreuzberg::chunking::ChunkerType::Text,
+            sizing: Default::default(),
+            prepend_heading_context: false,
+            semantic: Default::default(),
         }),
         ..Default::default()
     };
@@ -70,6 +73,9 @@ async fn test_chunking_with_overlap() {
             preset: None,
             trim: true,
             chunker_type: kreuzberg::chunking::ChunkerType::Text,
+            sizing: Default::default(),
+            prepend_heading_context: false,
+            semantic: Default::default(),
         }),
         ..Default::default()
     };
@@ -112,6 +118,9 @@ async fn test_chunking_custom_sizes() {
             preset: None,
             trim: true,
             chunker_type: kreuzberg::chunking::ChunkerType::Text,
+            sizing: Default::default(),
+            prepend_heading_context: false,
+            semantic: Default::default(),
         }),
         ..Default::default()
     };
@@ -524,6 +533,9 @@ async fn test_chunking_with_embeddings() {
             preset: None,
             trim: true,
             chunker_type: kreuzberg::chunking::ChunkerType::Text,
+            sizing: Default::default(),
+            prepend_heading_context: false,
+            semantic: Default::default(),
         }),
         ..Default::default()
     };
@@ -486,3 +486,75 @@ async fn test_msg_bad_outlook() {
     let result = extract_bytes(&data, "application/vnd.ms-outlook", &config).await;
     assert!(result.is_err(), "Corrupt MSG should fail gracefully");
 }
+
+/// Test that .mbox files are detected and split into one child result per message.
+#[tokio::test]
+async fn test_mbox_file_extraction() {
+    let dir = tempfile::tempdir().expect("Operation failed");
+    let path = dir.path().join("inbox.mbox");
+    std::fs::write(
+        &path,
+        "From a@example.com Mon Jan  1 10:00:00 2024\n\
+From: a@example.com\n\
+Subject: Question\n\
+Message-ID: <q@example.com>\n\
+\n\
+Any news?\n\
+\n\
+From b@example.com Mon Jan  1 11:00:00 2024\n\
+From: b@example.com\n\
+Subject: Re: Question\n\
+Message-ID: <r@example.com>\n\
+In-Reply-To: <q@example.com>\n\
+\n\
+Not yet.\n",
+    )
+    .expect("Operation failed");
+
+    let result = kreuzberg::core::extractor::extract_file(&path, None, &ExtractionConfig::default())
+        .await
+        .expect("Should extract mbox successfully");
+
+    assert_eq!(result.mime_type, "application/mbox");
+    let messages = result.children.as_ref().expect("Should have messages");
+    assert_eq!(messages.len(), 2);
+    assert!(
+        messages[1]
+            .result
+            .as_ref()
+            .expect("Operation failed")
+            .content
+            .contains("Not yet.")
+    );
+
+    match result.metadata.format.as_ref().expect("Operation failed") {
+        kreuzberg::FormatMetadata::Mailbox(meta) => {
+            assert_eq!(meta.message_count, 2);
+            assert_eq!(meta.threads.len(), 1);
+        }
+        _ => panic!("Expected Mailbox metadata"),
+    }
+}
+
+/// Test that a Maildir directory passed to `extract_file` is walked message by message.
+#[tokio::test]
+async fn test_maildir_directory_extraction() {
+    let dir = tempfile::tempdir().expect("Operation failed");
+    for sub in ["cur", "new", "tmp"] {
+        std::fs::create_dir_all(dir.path().join(sub)).expect("Operation failed");
+    }
+    std::fs::write(
+        dir.path().join("new/1700000000.1.host"),
+        "From: a@example.com\r\nSubject: Hello\r\n\r\nMaildir body",
+    )
+    .expect("Operation failed");
+
+    let result = kreuzberg::core::extractor::extract_file(dir.path(), None, &ExtractionConfig::default())
+        .await
+        .expect("Should extract Maildir successfully");
+
+    let messages = result.children.as_ref().expect("Should have messages");
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].path, "new/1700000000.1.host");
+    assert!(result.content.contains("Maildir body"));
+}
@@ -49,6 +49,7 @@ async fn test_full_hierarchy_extraction() {
                 include_bbox: true,
                 ocr_coverage_threshold: None,
             }),
+            extract_embedded_files: false,
         }),
         ..Default::default()
     };
@@ -161,6 +162,7 @@ async fn test_hierarchy_disabled() {
                 include_bbox: true,
                 ocr_coverage_threshold: None,
             }),
+            extract_embedded_files: false,
         }),
         ..Default::default()
     };
@@ -216,6 +218,7 @@ async fn test_hierarchy_with_explicit_disabled() {
                 include_bbox: true,
                 ocr_coverage_threshold: None,
             }),
+            extract_embedded_files: false,
         }),
         ..Default::default()
     };
@@ -272,6 +275,7 @@ async fn test_hierarchy_different_k_clusters() {
                     include_bbox: true,
                     ocr_coverage_threshold: None,
                 }),
+                extract_embedded_files: false,
             }),
             ..Default::default()
         };
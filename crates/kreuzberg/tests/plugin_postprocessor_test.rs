@@ -230,7 +230,11 @@ fn test_postprocessor_called_during_extraction() {
             .expect("Operation failed");
     }
 
-    let config = ExtractionConfig::default();
+    // A cached result would skip the plugins under test
+    let config = ExtractionConfig {
+        use_cache: false,
+        ..Default::default()
+    };
     let result = extract_file_sync(test_file, None, &config);
 
     assert!(result.is_ok(), "Extraction failed: {:?}", result.err());
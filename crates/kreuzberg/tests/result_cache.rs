@@ -0,0 +1,94 @@
+//! Integration tests for the extraction result cache.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use kreuzberg::{
+    CacheBackend, ExtractionConfig, OutputFormat, batch_extract_bytes, cache, extract_bytes, extract_file,
+};
+use tokio::sync::Mutex;
+
+/// The hit counter is process-wide, so tests comparing it run one at a time.
+static SERIAL: Mutex<()> = Mutex::const_new(());
+
+/// Content no earlier test run can have cached.
+fn unique_text(label: &str) -> String {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    format!("Result cache {} document {} {}.", label, std::process::id(), nanos)
+}
+
+fn hits() -> u64 {
+    cache::result_cache(CacheBackend::Filesystem)
+        .expect("result cache should be available")
+        .stats()
+        .unwrap()
+        .hits
+}
+
+/// Repeating an extraction is answered from the cache with an identical result.
+#[tokio::test]
+async fn test_repeated_extraction_hits_cache() {
+    let _serial = SERIAL.lock().await;
+    let config = ExtractionConfig::default();
+    let text = unique_text("bytes");
+
+    let first = extract_bytes(text.as_bytes(), "text/plain", &config).await.unwrap();
+    let hits_before = hits();
+    let second = extract_bytes(text.as_bytes(), "text/plain", &config).await.unwrap();
+
+    assert!(hits() > hits_before);
+    assert_eq!(first.content, second.content);
+    assert_eq!(first.metadata.output_format, second.metadata.output_format);
+    assert_eq!(
+        first.metadata.additional.get("output_format"),
+        second.metadata.additional.get("output_format")
+    );
+}
+
+/// Files are keyed by content, so a copy of a cached file is a hit too.
+#[tokio::test]
+async fn test_file_cache_is_content_addressed() {
+    let _serial = SERIAL.lock().await;
+    let dir = tempfile::tempdir().unwrap();
+    let text = unique_text("file");
+    let original = dir.path().join("original.txt");
+    let copy = dir.path().join("copy.txt");
+    std::fs::write(&original, &text).unwrap();
+    std::fs::write(&copy, &text).unwrap();
+
+    let config = ExtractionConfig::default();
+    extract_file(&original, None, &config).await.unwrap();
+    let hits_before = hits();
+    let result = extract_file(&copy, None, &config).await.unwrap();
+
+    assert!(hits() > hits_before);
+    assert_eq!(result.content.trim(), text);
+}
+
+/// Changing an output-relevant setting or disabling the cache forces a fresh extraction.
+#[tokio::test]
+async fn test_config_changes_and_opt_out_bypass_cache() {
+    let _serial = SERIAL.lock().await;
+    let text = unique_text("config");
+    let contents = vec![(text.clone().into_bytes(), "text/plain".to_string())];
+    batch_extract_bytes(contents.clone(), &ExtractionConfig::default())
+        .await
+        .unwrap();
+
+    let hits_before = hits();
+    let uncached = ExtractionConfig {
+        use_cache: false,
+        ..Default::default()
+    };
+    extract_bytes(text.as_bytes(), "text/plain", &uncached).await.unwrap();
+    let markdown = ExtractionConfig {
+        output_format: OutputFormat::Markdown,
+        ..Default::default()
+    };
+    extract_bytes(text.as_bytes(), "text/plain", &markdown).await.unwrap();
+    assert_eq!(hits(), hits_before);
+
+    batch_extract_bytes(contents, &ExtractionConfig::default())
+        .await
+        .unwrap();
+    assert!(hits() > hits_before);
+}
@@ -151,6 +151,11 @@ async fn test_generate_embeddings_for_chunks_basic() {
                 token_count: None,
                 first_page: None,
                 last_page: None,
+                heading_path: Vec::new(),
+                element_types: Vec::new(),
+                node_ids: Vec::new(),
+                language: None,
+                language_confidence: None,
             },
         },
         Chunk {
@@ -164,6 +169,11 @@ async fn test_generate_embeddings_for_chunks_basic() {
                 token_count: None,
                 first_page: None,
                 last_page: None,
+                heading_path: Vec::new(),
+                element_types: Vec::new(),
+                node_ids: Vec::new(),
+                language: None,
+                language_confidence: None,
             },
         },
         Chunk {
@@ -177,6 +187,11 @@ async fn test_generate_embeddings_for_chunks_basic() {
                 token_count: None,
                 first_page: None,
                 last_page: None,
+                heading_path: Vec::new(),
+                element_types: Vec::new(),
+                node_ids: Vec::new(),
+                language: None,
+                language_confidence: None,
             },
         },
     ];
@@ -227,6 +242,11 @@ async fn test_generate_embeddings_for_chunks_normalization() {
             token_count: None,
             first_page: None,
             last_page: None,
+            heading_path: Vec::new(),
+            element_types: Vec::new(),
+            node_ids: Vec::new(),
+            language: None,
+            language_confidence: None,
         },
     }];
 
@@ -254,6 +274,11 @@ async fn test_generate_embeddings_for_chunks_normalization() {
             token_count: None,
             first_page: None,
             last_page: None,
+            heading_path: Vec::new(),
+            element_types: Vec::new(),
+            node_ids: Vec::new(),
+            language: None,
+            language_confidence: None,
         },
     }];
 
@@ -336,6 +361,11 @@ async fn test_generate_embeddings_for_chunks_model_caching() {
             token_count: None,
             first_page: None,
             last_page: None,
+            heading_path: Vec::new(),
+            element_types: Vec::new(),
+            node_ids: Vec::new(),
+            language: None,
+            language_confidence: None,
         },
     }];
 
@@ -364,6 +394,11 @@ async fn test_generate_embeddings_for_chunks_model_caching() {
             token_count: None,
             first_page: None,
             last_page: None,
+            heading_path: Vec::new(),
+            element_types: Vec::new(),
+            node_ids: Vec::new(),
+            language: None,
+            language_confidence: None,
         },
     }];
 
@@ -398,6 +433,11 @@ async fn test_generate_embeddings_for_chunks_invalid_preset() {
             token_count: None,
             first_page: None,
             last_page: None,
+            heading_path: Vec::new(),
+            element_types: Vec::new(),
+            node_ids: Vec::new(),
+            language: None,
+            language_confidence: None,
         },
     }];
 
@@ -443,6 +483,11 @@ async fn test_generate_embeddings_for_chunks_unknown_model() {
             token_count: None,
             first_page: None,
             last_page: None,
+            heading_path: Vec::new(),
+            element_types: Vec::new(),
+            node_ids: Vec::new(),
+            language: None,
+            language_confidence: None,
         },
     }];
 
@@ -489,6 +534,11 @@ async fn test_generate_embeddings_for_chunks_custom_model_not_supported() {
             token_count: None,
             first_page: None,
             last_page: None,
+            heading_path: Vec::new(),
+            element_types: Vec::new(),
+            node_ids: Vec::new(),
+            language: None,
+            language_confidence: None,
         },
     }];
 
@@ -536,6 +586,11 @@ async fn test_generate_embeddings_for_chunks_batch_size() {
                 token_count: None,
                 first_page: None,
                 last_page: None,
+                heading_path: Vec::new(),
+                element_types: Vec::new(),
+                node_ids: Vec::new(),
+                language: None,
+                language_confidence: None,
             },
         })
         .collect();
@@ -148,6 +148,7 @@ impl DocumentExtractor for CSVExtractor {
             metadata,
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
         })
     }
@@ -209,6 +210,7 @@ impl DocumentExtractor for BinaryFormatExtractor {
             metadata: Metadata::default(),
             tables: vec![],
             detected_languages: None,
+            language_spans: None,
             chunks: None,
         })
     }
@@ -19,7 +19,7 @@ defmodule Kreuzberg.ExtractionConfig do
 
   ### Output Format Flags
 
-    * `:output_format` - Content text format (default: "plain") - "plain", "markdown", "djot", "html"
+    * `:output_format` - Content text format (default: "plain") - "plain", "markdown", "djot", "html", "alto", "pagexml", "hocr"
     * `:result_format` - Result structure format (default: "unified") - "unified", "element_based"
 
   ### Nested Configuration Maps (Optional)
@@ -260,7 +260,7 @@ defmodule Kreuzberg.ExtractionConfig do
     * `"use_cache"` - Enable caching (boolean)
     * `"enable_quality_processing"` - Enable quality processing (boolean)
     * `"force_ocr"` - Force OCR usage (boolean)
-    * `"output_format"` - Content text format (string: "plain", "markdown", "djot", "html")
+    * `"output_format"` - Content text format (string: "plain", "markdown", "djot", "html", "alto", "pagexml", "hocr")
     * `"result_format"` - Result structure format (string: "unified", "element_based")
 
   ## Examples
@@ -710,9 +710,18 @@ defmodule Kreuzberg.ExtractionConfig do
       "html" ->
         :ok
 
+      "alto" ->
+        :ok
+
+      "pagexml" ->
+        :ok
+
+      "hocr" ->
+        :ok
+
       _invalid ->
         {:error,
-         "Field 'output_format' must be one of: plain, text, markdown, md, djot, html, got: #{value}"}
+         "Field 'output_format' must be one of: plain, text, markdown, md, djot, html, alto, pagexml, hocr, got: #{value}"}
     end
   end
 
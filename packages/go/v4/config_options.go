@@ -135,7 +135,7 @@ func WithIncludeDocumentStructure(include bool) ExtractionOption {
 }
 
 // WithOutputFormat sets the content output format.
-// Options: "plain", "markdown", "djot", "html"
+// Options: "plain", "markdown", "djot", "html", "alto", "pagexml", "hocr"
 func WithOutputFormat(format string) ExtractionOption {
 	return func(c *ExtractionConfig) {
 		c.OutputFormat = format
@@ -72,12 +72,16 @@ type PageOption func(*PageConfig)
 // defaults whenever possible.
 type ExtractionConfig struct {
 	UseCache                 *bool                    `json:"use_cache,omitempty"`
+	CacheBackend             *string                  `json:"cache_backend,omitempty"`
 	EnableQualityProcessing  *bool                    `json:"enable_quality_processing,omitempty"`
 	OCR                      *OCRConfig               `json:"ocr,omitempty"`
 	ForceOCR                 *bool                    `json:"force_ocr,omitempty"`
 	Chunking                 *ChunkingConfig          `json:"chunking,omitempty"`
 	Images                   *ImageExtractionConfig   `json:"images,omitempty"`
 	PdfOptions               *PdfConfig               `json:"pdf_options,omitempty"`
+	ArchiveOptions           *ArchiveConfig           `json:"archive_options,omitempty"`
+	DocxOptions              *DocxConfig              `json:"docx_options,omitempty"`
+	EmailOptions             *EmailConfig             `json:"email_options,omitempty"`
 	TokenReduction           *TokenReductionConfig    `json:"token_reduction,omitempty"`
 	LanguageDetection        *LanguageDetectionConfig `json:"language_detection,omitempty"`
 	Keywords                 *KeywordConfig           `json:"keywords,omitempty"`
@@ -102,6 +106,24 @@ type SecurityLimitsConfig struct {
 	MaxIterations       *int `json:"max_iterations,omitempty"`
 	MaxXMLDepth         *int `json:"max_xml_depth,omitempty"`
 	MaxTableCells       *int `json:"max_table_cells,omitempty"`
+	MaxArchiveDepth     *int `json:"max_archive_depth,omitempty"`
+}
+
+// ArchiveConfig controls archive extraction behavior.
+type ArchiveConfig struct {
+	Recursive *bool `json:"recursive,omitempty"`
+}
+
+// DocxConfig controls DOCX tracked-change rendering.
+type DocxConfig struct {
+	RevisionMode string `json:"revision_mode,omitempty"`
+}
+
+// EmailConfig controls email attachment extraction.
+type EmailConfig struct {
+	ExtractAttachments *bool  `json:"extract_attachments,omitempty"`
+	MaxAttachmentDepth *int   `json:"max_attachment_depth,omitempty"`
+	MboxFormat         string `json:"mbox_format,omitempty"`
 }
 
 // OcrElementConfig controls OCR element extraction behavior.
@@ -172,13 +194,37 @@ type ImagePreprocessingConfig struct {
 
 // ChunkingConfig configures text chunking for downstream RAG/Retrieval workloads.
 type ChunkingConfig struct {
-	MaxChars     *int             `json:"max_chars,omitempty"`
-	MaxOverlap   *int             `json:"max_overlap,omitempty"`
-	ChunkSize    *int             `json:"chunk_size,omitempty"`
-	ChunkOverlap *int             `json:"chunk_overlap,omitempty"`
-	Preset       *string          `json:"preset,omitempty"`
-	Enabled      *bool            `json:"enabled,omitempty"`
-	Embedding    *EmbeddingConfig `json:"embedding,omitempty"`
+	MaxChars     *int                    `json:"max_chars,omitempty"`
+	MaxOverlap   *int                    `json:"max_overlap,omitempty"`
+	ChunkSize    *int                    `json:"chunk_size,omitempty"`
+	ChunkOverlap *int                    `json:"chunk_overlap,omitempty"`
+	Preset       *string                 `json:"preset,omitempty"`
+	Enabled      *bool                   `json:"enabled,omitempty"`
+	Embedding    *EmbeddingConfig        `json:"embedding,omitempty"`
+	Sizing       *ChunkSizing            `json:"sizing,omitempty"`
+	ChunkerType  *string                 `json:"chunker_type,omitempty"`
+	Semantic     *SemanticChunkingConfig `json:"semantic,omitempty"`
+}
+
+// ChunkSizing selects the unit of MaxChars and MaxOverlap ("characters" or "tokens").
+type ChunkSizing struct {
+	Unit      string  `json:"unit"`
+	Tokenizer *string `json:"tokenizer,omitempty"`
+	Format    *string `json:"format,omitempty"`
+	Pattern   *string `json:"pattern,omitempty"`
+}
+
+// SemanticChunkingConfig tunes the "Semantic" chunker. Threshold is
+// {"type": "percentile" | "standard_deviation", "value": <number>}.
+type SemanticChunkingConfig struct {
+	Threshold     *SemanticThreshold `json:"threshold,omitempty"`
+	MinCharacters *int               `json:"min_characters,omitempty"`
+}
+
+// SemanticThreshold selects where the semantic chunker starts a new chunk.
+type SemanticThreshold struct {
+	Type  string  `json:"type"`
+	Value float64 `json:"value"`
 }
 
 // ImageExtractionConfig controls inline image extraction from PDFs/Office docs.
@@ -199,10 +245,11 @@ type FontConfig struct {
 
 // PdfConfig exposes PDF-specific options.
 type PdfConfig struct {
-	ExtractImages   *bool       `json:"extract_images,omitempty"`
-	Passwords       []string    `json:"passwords,omitempty"`
-	ExtractMetadata *bool       `json:"extract_metadata,omitempty"`
-	FontConfig      *FontConfig `json:"font_config,omitempty"`
+	ExtractImages        *bool       `json:"extract_images,omitempty"`
+	Passwords            []string    `json:"passwords,omitempty"`
+	ExtractMetadata      *bool       `json:"extract_metadata,omitempty"`
+	FontConfig           *FontConfig `json:"font_config,omitempty"`
+	ExtractEmbeddedFiles *bool       `json:"extract_embedded_files,omitempty"`
 }
 
 // HierarchyConfig controls PDF hierarchy extraction based on font sizes.
@@ -242,11 +289,15 @@ type PostProcessorConfig struct {
 
 // EmbeddingModelType configures embedding model selection.
 type EmbeddingModelType struct {
-	Type       string `json:"type"`
-	Name       string `json:"name,omitempty"`
-	Model      string `json:"model,omitempty"`
-	ModelID    string `json:"model_id,omitempty"`
-	Dimensions *int   `json:"dimensions,omitempty"`
+	Type        string  `json:"type"`
+	Name        string  `json:"name,omitempty"`
+	Model       string  `json:"model,omitempty"`
+	ModelID     string  `json:"model_id,omitempty"`
+	Dimensions  *int    `json:"dimensions,omitempty"`
+	BaseURL     string  `json:"base_url,omitempty"`
+	APIKeyEnv   *string `json:"api_key_env,omitempty"`
+	MaxRetries  *int    `json:"max_retries,omitempty"`
+	TimeoutSecs *int    `json:"timeout_secs,omitempty"`
 }
 
 // EmbeddingConfig configures embedding generation for chunks.
@@ -331,7 +382,7 @@ type PageConfig struct {
 }
 
 // OutputFormat controls the format of extracted content.
-// Options: "plain", "text", "markdown", "md", "djot", "html"
+// Options: "plain", "text", "markdown", "md", "djot", "html", "alto", "pagexml", "hocr"
 // Default: "plain" (via Rust)
 type OutputFormat string
 
@@ -342,6 +393,9 @@ const (
 	OutputFormatMd       OutputFormat = "md" // Alias for markdown
 	OutputFormatDjot     OutputFormat = "djot"
 	OutputFormatHTML     OutputFormat = "html"
+	OutputFormatALTO     OutputFormat = "alto"
+	OutputFormatPageXML  OutputFormat = "pagexml"
+	OutputFormatHOCR     OutputFormat = "hocr"
 )
 
 // ResultFormat controls the result structure.
@@ -103,6 +103,7 @@ type ExtractionResult struct {
 	Metadata          Metadata           `json:"metadata"`
 	Tables            []Table            `json:"tables"`
 	DetectedLanguages []string           `json:"detected_languages,omitempty"`
+	LanguageSpans     []LanguageSpan     `json:"language_spans,omitempty"`
 	Chunks            []Chunk            `json:"chunks,omitempty"`
 	Images            []ExtractedImage   `json:"images,omitempty"`
 	Pages             []PageContent      `json:"pages,omitempty"`
@@ -137,13 +138,15 @@ type Chunk struct {
 
 // ChunkMetadata provides positional information for a chunk.
 type ChunkMetadata struct {
-	ByteStart   uint64  `json:"byte_start"`
-	ByteEnd     uint64  `json:"byte_end"`
-	TokenCount  *uint64 `json:"token_count,omitempty"`
-	ChunkIndex  uint64  `json:"chunk_index"`
-	TotalChunks uint64  `json:"total_chunks"`
-	FirstPage   *uint64 `json:"first_page,omitempty"`
-	LastPage    *uint64 `json:"last_page,omitempty"`
+	ByteStart          uint64   `json:"byte_start"`
+	ByteEnd            uint64   `json:"byte_end"`
+	TokenCount         *uint64  `json:"token_count,omitempty"`
+	ChunkIndex         uint64   `json:"chunk_index"`
+	TotalChunks        uint64   `json:"total_chunks"`
+	FirstPage          *uint64  `json:"first_page,omitempty"`
+	LastPage           *uint64  `json:"last_page,omitempty"`
+	Language           *string  `json:"language,omitempty"`
+	LanguageConfidence *float64 `json:"language_confidence,omitempty"`
 }
 
 // ExtractedImage represents an extracted image, optionally with nested OCR results.
@@ -470,18 +473,22 @@ type OcrMetadata struct {
 
 // ImagePreprocessingMetadata tracks OCR preprocessing steps.
 type ImagePreprocessingMetadata struct {
-	OriginalDimensions [2]uint64  `json:"original_dimensions"`
-	OriginalDPI        [2]float64 `json:"original_dpi"`
-	TargetDPI          int32      `json:"target_dpi"`
-	ScaleFactor        float64    `json:"scale_factor"`
-	AutoAdjusted       bool       `json:"auto_adjusted"`
-	FinalDPI           int32      `json:"final_dpi"`
-	NewDimensions      *[2]uint64 `json:"new_dimensions,omitempty"`
-	ResampleMethod     string     `json:"resample_method"`
-	DimensionClamped   bool       `json:"dimension_clamped"`
-	CalculatedDPI      *int32     `json:"calculated_dpi,omitempty"`
-	SkippedResize      bool       `json:"skipped_resize"`
-	ResizeError        *string    `json:"resize_error,omitempty"`
+	OriginalDimensions    [2]uint64  `json:"original_dimensions"`
+	OriginalDPI           [2]float64 `json:"original_dpi"`
+	TargetDPI             int32      `json:"target_dpi"`
+	ScaleFactor           float64    `json:"scale_factor"`
+	AutoAdjusted          bool       `json:"auto_adjusted"`
+	FinalDPI              int32      `json:"final_dpi"`
+	NewDimensions         *[2]uint64 `json:"new_dimensions,omitempty"`
+	ResampleMethod        string     `json:"resample_method"`
+	DimensionClamped      bool       `json:"dimension_clamped"`
+	CalculatedDPI         *int32     `json:"calculated_dpi,omitempty"`
+	SkippedResize         bool       `json:"skipped_resize"`
+	ResizeError           *string    `json:"resize_error,omitempty"`
+	StepsApplied          []string   `json:"steps_applied,omitempty"`
+	DetectedOrientation   *uint16    `json:"detected_orientation,omitempty"`
+	SkewAngle             *float64   `json:"skew_angle,omitempty"`
+	BinarizationThreshold *uint8     `json:"binarization_threshold,omitempty"`
 }
 
 // ErrorMetadata describes failures in batch operations.
@@ -541,12 +548,24 @@ type PageHierarchy struct {
 
 // PageContent represents extracted content for a single page.
 type PageContent struct {
-	PageNumber uint64           `json:"page_number"`
-	Content    string           `json:"content"`
-	Tables     []Table          `json:"tables,omitempty"`
-	Images     []ExtractedImage `json:"images,omitempty"`
-	Hierarchy  *PageHierarchy   `json:"hierarchy,omitempty"`
-	IsBlank    *bool            `json:"is_blank,omitempty"`
+	PageNumber         uint64           `json:"page_number"`
+	Content            string           `json:"content"`
+	Tables             []Table          `json:"tables,omitempty"`
+	Images             []ExtractedImage `json:"images,omitempty"`
+	Hierarchy          *PageHierarchy   `json:"hierarchy,omitempty"`
+	IsBlank            *bool            `json:"is_blank,omitempty"`
+	Language           *string          `json:"language,omitempty"`
+	LanguageConfidence *float64         `json:"language_confidence,omitempty"`
+	TextSource         *string          `json:"text_source,omitempty"`
+}
+
+// LanguageSpan is a contiguous run of content in a single language and script.
+type LanguageSpan struct {
+	ByteStart  uint64  `json:"byte_start"`
+	ByteEnd    uint64  `json:"byte_end"`
+	Language   string  `json:"language"`
+	Script     string  `json:"script"`
+	Confidence float64 `json:"confidence"`
 }
 
 // ElementType defines semantic classification for extracted elements.
@@ -10,7 +10,8 @@ import java.util.Locale;
  * <p>
  * Controls the format of the content field in ExtractionResult. When set to
  * Markdown, Djot, or Html, the output will be formatted accordingly. Plain
- * returns the raw extracted text.
+ * returns the raw extracted text. Alto, PageXml and Hocr serialize the OCR
+ * elements of image extractions to layout XML.
  *
  * @since 4.0.0
  */
@@ -22,7 +23,13 @@ public enum OutputFormat {
 	/** Djot markup format. */
 	DJOT("djot"),
 	/** HTML format. */
-	HTML("html");
+	HTML("html"),
+	/** ALTO XML built from OCR elements. */
+	ALTO("alto"),
+	/** PAGE XML built from OCR elements. */
+	PAGE_XML("pagexml"),
+	/** hOCR built from OCR elements. */
+	HOCR("hocr");
 
 	private final String value;
 
@@ -60,8 +67,11 @@ public enum OutputFormat {
 			case "markdown", "md" -> MARKDOWN;
 			case "djot" -> DJOT;
 			case "html" -> HTML;
-			default -> throw new IllegalArgumentException(
-					"Invalid output format: '" + value + "'. Valid formats: plain, text, markdown, md, djot, html");
+			case "alto" -> ALTO;
+			case "pagexml", "page-xml" -> PAGE_XML;
+			case "hocr" -> HOCR;
+			default -> throw new IllegalArgumentException("Invalid output format: '" + value
+					+ "'. Valid formats: plain, text, markdown, md, djot, html, alto, pagexml, hocr");
 		};
 	}
 
@@ -185,6 +185,9 @@ if not TYPE_CHECKING:
         MARKDOWN = "markdown"
         DJOT = "djot"
         HTML = "html"
+        ALTO = "alto"
+        PAGEXML = "pagexml"
+        HOCR = "hocr"
 
     class ResultFormat(str, Enum):
         """Result format controlling extraction output structure."""
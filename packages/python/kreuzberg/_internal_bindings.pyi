@@ -8,6 +8,9 @@ class OutputFormat(StrEnum):
     MARKDOWN = "markdown"
     DJOT = "djot"
     HTML = "html"
+    ALTO = "alto"
+    PAGEXML = "pagexml"
+    HOCR = "hocr"
 
 class ResultFormat(StrEnum):
     UNIFIED = "unified"
@@ -371,7 +374,7 @@ class ExtractionConfig:
 
         output_format (str): Output content format.
             Controls the format of the extracted content.
-            Values: "plain" (default), "markdown", "djot", "html". Default: "plain"
+            Values: "plain" (default), "markdown", "djot", "html", "alto", "pagexml", "hocr". Default: "plain"
 
     Example:
         Basic extraction with defaults:
@@ -524,6 +527,24 @@ class EmbeddingModelType:
     def fastembed(model: str, dimensions: int) -> EmbeddingModelType: ...
     @staticmethod
     def custom(model_id: str, dimensions: int) -> EmbeddingModelType: ...
+    @staticmethod
+    def openai(
+        base_url: str,
+        model: str,
+        api_key_env: str | None = None,
+        dimensions: int | None = None,
+        max_retries: int = 3,
+        timeout_secs: int = 60,
+    ) -> EmbeddingModelType: ...
+    @staticmethod
+    def ollama(
+        model: str,
+        base_url: str = "http://localhost:11434",
+        api_key_env: str | None = None,
+        dimensions: int | None = None,
+        max_retries: int = 3,
+        timeout_secs: int = 60,
+    ) -> EmbeddingModelType: ...
 
 class EmbeddingConfig:
     """Embedding generation configuration for text chunks.
@@ -754,6 +775,10 @@ class PdfConfig:
             for detecting document structure and organization. None = no hierarchy detection.
             Default: None
 
+        extract_embedded_files (bool): Extract files embedded in the PDF and return them
+            as child results. Embedded files are always listed in the metadata.
+            Default: False
+
     Example:
         Basic PDF configuration:
             >>> from kreuzberg import ExtractionConfig, PdfConfig
@@ -773,6 +798,7 @@ class PdfConfig:
     passwords: list[str] | None
     extract_metadata: bool
     hierarchy: HierarchyConfig | None
+    extract_embedded_files: bool
 
     def __init__(
         self,
@@ -781,6 +807,7 @@ class PdfConfig:
         passwords: list[str] | None = None,
         extract_metadata: bool | None = None,
         hierarchy: HierarchyConfig | None = None,
+        extract_embedded_files: bool | None = None,
     ) -> None: ...
 
 class HierarchyConfig:
@@ -1543,6 +1570,8 @@ class ChunkMetadata(TypedDict, total=False):
     token_count: int | None
     first_page: int
     last_page: int
+    language: str
+    language_confidence: float
 
 class Chunk:
     content: str
@@ -1804,6 +1833,9 @@ class PageContent(TypedDict):
     tables: list[ExtractedTable]
     images: list[ExtractedImage]
     is_blank: bool | None
+    language: str | None
+    language_confidence: float | None
+    text_source: Literal["text_layer", "ocr"] | None
 
 class ExtractedTable:
     cells: list[list[str]]
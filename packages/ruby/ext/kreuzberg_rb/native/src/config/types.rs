@@ -82,6 +82,9 @@ pub fn parse_ocr_config(ruby: &Ruby, hash: RHash) -> Result<OcrConfig, Error> {
             "markdown" | "Markdown" => OutputFormat::Markdown,
             "djot" | "Djot" => OutputFormat::Djot,
             "html" | "Html" => OutputFormat::Html,
+            "alto" | "Alto" => OutputFormat::Alto,
+            "pagexml" | "PageXml" => OutputFormat::PageXml,
+            "hocr" | "Hocr" => OutputFormat::Hocr,
             other => return Err(runtime_error(format!("Invalid ocr output_format: '{}'", other))),
         };
         config.output_format = Some(format);
@@ -130,6 +133,9 @@ pub fn parse_chunking_config(ruby: &Ruby, hash: RHash) -> Result<ChunkingConfig,
         chunker_type: kreuzberg::ChunkerType::Text,
         embedding,
         preset,
+        sizing: Default::default(),
+        prepend_heading_context: false,
+        semantic: Default::default(),
     };
 
     Ok(config)
@@ -240,11 +246,18 @@ pub fn parse_pdf_config(ruby: &Ruby, hash: RHash) -> Result<PdfConfig, Error> {
         None
     };
 
+    let extract_embedded_files = if let Some(val) = get_kw(ruby, hash, "extract_embedded_files") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
     let config = PdfConfig {
         extract_images,
         passwords,
         extract_metadata,
         hierarchy,
+        extract_embedded_files,
     };
 
     Ok(config)
@@ -1000,9 +1013,12 @@ pub fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extra
                 "markdown" | "Markdown" => OutputFormat::Markdown,
                 "djot" | "Djot" => OutputFormat::Djot,
                 "html" | "Html" => OutputFormat::Html,
+                "alto" | "Alto" => OutputFormat::Alto,
+                "pagexml" | "PageXml" => OutputFormat::PageXml,
+                "hocr" | "Hocr" => OutputFormat::Hocr,
                 _ => {
                     return Err(runtime_error(format!(
-                        "Invalid output_format: '{}'. Expected 'plain', 'markdown', 'djot', or 'html'",
+                        "Invalid output_format: '{}'. Expected 'plain', 'markdown', 'djot', 'html', 'alto', 'pagexml', or 'hocr'",
                         format_str
                     )))
                 }
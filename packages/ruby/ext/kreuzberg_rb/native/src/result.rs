@@ -541,6 +541,23 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
                             kind_hash.aset("title", ruby.qnil().as_value())?;
                         }
                     }
+                    AnnotationKind::Comment { id, author, date, text } => {
+                        kind_hash.aset("annotation_type", "comment")?;
+                        kind_hash.aset("id", id)?;
+                        kind_hash.aset("author", author)?;
+                        kind_hash.aset("date", date)?;
+                        kind_hash.aset("text", text)?;
+                    }
+                    AnnotationKind::Insertion { author, date } => {
+                        kind_hash.aset("annotation_type", "insertion")?;
+                        kind_hash.aset("author", author)?;
+                        kind_hash.aset("date", date)?;
+                    }
+                    AnnotationKind::Deletion { author, date } => {
+                        kind_hash.aset("annotation_type", "deletion")?;
+                        kind_hash.aset("author", author)?;
+                        kind_hash.aset("date", date)?;
+                    }
                 }
                 ann_hash.aset("kind", kind_hash)?;
                 annotations_array.push(ann_hash)?;
@@ -83,12 +83,20 @@ export interface OcrConfig {
 }
 
 export interface EmbeddingModelType {
-	/** Type of model: "preset", "fastembed", or "custom" */
+	/** Type of model: "preset", "fastembed", "custom", "openai", or "ollama" */
 	modelType: string;
-	/** For preset: preset name; for fastembed/custom: model ID */
+	/** For preset: preset name; for fastembed/custom: model ID; for openai/ollama: model name */
 	value: string;
-	/** Number of dimensions (only for fastembed/custom) */
+	/** Number of dimensions (for fastembed/custom; optional expected size for openai/ollama) */
 	dimensions?: number;
+	/** API base URL (only for openai/ollama) */
+	baseUrl?: string;
+	/** Environment variable holding the API key (only for openai/ollama) */
+	apiKeyEnv?: string;
+	/** Retries for failed requests (only for openai/ollama, default: 3) */
+	maxRetries?: number;
+	/** Request timeout in seconds (only for openai/ollama, default: 60) */
+	timeoutSecs?: number;
 }
 
 export interface EmbeddingConfig {
@@ -138,6 +146,7 @@ export interface PdfConfig {
 	passwords?: string[];
 	extractMetadata?: boolean;
 	hierarchy?: HierarchyConfig;
+	extractEmbeddedFiles?: boolean;
 }
 
 export interface ImageExtractionConfig {
@@ -338,8 +347,9 @@ export interface ExtractionConfig {
 	 * - "markdown": Markdown formatted output
 	 * - "djot": Djot markup format
 	 * - "html": HTML formatted output
+	 * - "alto", "pagexml", "hocr": OCR layout XML built from OCR elements (images with OCR)
 	 */
-	outputFormat?: "plain" | "markdown" | "djot" | "html";
+	outputFormat?: "plain" | "markdown" | "djot" | "html" | "alto" | "pagexml" | "hocr";
 	/**
 	 * Result structure format (default: Unified).
 	 * Controls whether results are returned in unified format with all
@@ -78,6 +78,7 @@ export type {
 	ExtractedImage,
 	ExtractionResult,
 	HierarchicalBlock,
+	LanguageSpan,
 	PageContent,
 	PageHierarchy,
 	ProcessingWarning,
@@ -144,6 +144,10 @@ export interface ImagePreprocessingMetadata {
 	calculatedDpi?: number | null;
 	skippedResize?: boolean;
 	resizeError?: string | null;
+	stepsApplied?: string[];
+	detectedOrientation?: number | null;
+	skewAngle?: number | null;
+	binarizationThreshold?: number | null;
 }
 
 export interface ErrorMetadata {
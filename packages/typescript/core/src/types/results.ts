@@ -31,6 +31,8 @@ export interface ChunkMetadata {
 	totalChunks: number;
 	firstPage?: number | null;
 	lastPage?: number | null;
+	language?: string | null;
+	languageConfidence?: number | null;
 }
 
 export interface Chunk {
@@ -254,6 +256,17 @@ export interface PageContent {
 	images: ExtractedImage[];
 	hierarchy?: PageHierarchy;
 	isBlank?: boolean;
+	language?: string;
+	languageConfidence?: number;
+	textSource?: "text_layer" | "ocr";
+}
+
+export interface LanguageSpan {
+	byteStart: number;
+	byteEnd: number;
+	language: string;
+	script: string;
+	confidence: number;
 }
 
 // ============================================================================
@@ -421,7 +434,10 @@ export type AnnotationKind =
 	| { annotation_type: "code" }
 	| { annotation_type: "subscript" }
 	| { annotation_type: "superscript" }
-	| { annotation_type: "link"; url: string; title?: string | null };
+	| { annotation_type: "link"; url: string; title?: string | null }
+	| { annotation_type: "comment"; id: string; author?: string | null; date?: string | null; text: string }
+	| { annotation_type: "insertion"; author?: string | null; date?: string | null }
+	| { annotation_type: "deletion"; author?: string | null; date?: string | null };
 
 /**
  * Tagged union for node content. Each variant carries only type-specific data.
@@ -494,6 +510,7 @@ export interface ExtractionResult {
 	metadata: Metadata;
 	tables: Table[];
 	detectedLanguages?: string[];
+	languageSpans?: LanguageSpan[];
 	chunks?: Chunk[];
 	images?: ExtractedImage[];
 	pages?: PageContent[];